@@ -0,0 +1,256 @@
+//! # Tauri TypeGen Macros
+//!
+//! Optional companion crate of `tauri-typegen`. `#[export]` records the signature of a
+//! Tauri command at compile time, after macro expansion, so commands the source
+//! scanner cannot see (e.g. generated by `macro_rules!`) are still generated.
+//!
+//! ```rust,ignore
+//! use tauri_typegen_macros as typegen;
+//!
+//! #[typegen::export]
+//! #[tauri::command]
+//! pub async fn get_user(id: u32) -> Result<User, String> {
+//!     // Implementation
+//! }
+//! ```
+//!
+//! Records are written to `$OUT_DIR/tauri-typegen/<build>/<module>.<command>.json`,
+//! so the crate needs a build script (every Tauri app has one). Each compilation of
+//! the crate writes into a directory of its own and removes those of earlier ones,
+//! so records of removed commands do not linger. Point the generator at them with
+//! `"macroRecords": "${outDir}/tauri-typegen"` in the `plugins.typegen` section of
+//! `tauri.conf.json`; commands without a record are scanned as before. The function
+//! itself is left unchanged.
+
+use proc_macro::TokenStream;
+use quote::ToTokens;
+use std::path::{Component, Path, PathBuf};
+use std::sync::atomic::{AtomicBool, Ordering};
+use syn::{parse_macro_input, FnArg, ItemFn, Pat, ReturnType};
+
+/// Directory below `OUT_DIR` the records are written to
+const RECORDS_DIR: &str = "tauri-typegen";
+
+/// Whether this compilation removed the records of earlier ones yet
+static CLEARED: AtomicBool = AtomicBool::new(false);
+
+/// Record the signature of a Tauri command for `tauri-typegen`
+#[proc_macro_attribute]
+pub fn export(attr: TokenStream, item: TokenStream) -> TokenStream {
+    if !attr.is_empty() {
+        return syn::Error::new(
+            proc_macro2::Span::call_site(),
+            "#[typegen::export] takes no arguments",
+        )
+        .to_compile_error()
+        .into();
+    }
+    let func = parse_macro_input!(item as ItemFn);
+
+    // Without a build script there is nowhere to record to
+    if let Ok(out_dir) = std::env::var("OUT_DIR") {
+        let module = proc_macro::Span::call_site()
+            .local_file()
+            .map(|file| {
+                let manifest_dir = std::env::var("CARGO_MANIFEST_DIR").unwrap_or_default();
+                module_path(&file, Path::new(&manifest_dir))
+            })
+            .unwrap_or_default();
+        if let Err(e) = write_record(Path::new(&out_dir), &build_dir_name(), &module, &func) {
+            let message = format!("failed to record command for tauri-typegen: {}", e);
+            return syn::Error::new_spanned(&func.sig.ident, message)
+                .to_compile_error()
+                .into();
+        }
+    }
+    func.into_token_stream().into()
+}
+
+/// Directory of the records of this compilation: the crate, as the library and binary
+/// of a package share `OUT_DIR`, and the compiler process
+fn build_dir_name() -> String {
+    let crate_name = std::env::var("CARGO_CRATE_NAME").unwrap_or_default();
+    format!("{}-{}", crate_name, std::process::id())
+}
+
+/// Module path of the file at `file`, e.g. `["commands", "user"]` for
+/// `src/commands/user.rs`; empty for the crate root. Modules declared inline are not
+/// seen, as only the file is known.
+fn module_path(file: &Path, manifest_dir: &Path) -> Vec<String> {
+    let relative = file.strip_prefix(manifest_dir).unwrap_or(file);
+    let mut segments: Vec<String> = relative
+        .with_extension("")
+        .components()
+        .filter_map(|component| match component {
+            Component::Normal(segment) => Some(segment.to_string_lossy().to_string()),
+            _ => None,
+        })
+        .collect();
+    if segments.first().is_some_and(|segment| segment == "src") {
+        segments.remove(0);
+    }
+    if segments
+        .last()
+        .is_some_and(|segment| matches!(segment.as_str(), "lib" | "main" | "mod"))
+    {
+        segments.pop();
+    }
+    segments
+}
+
+fn write_record(
+    out_dir: &Path,
+    build: &str,
+    module: &[String],
+    func: &ItemFn,
+) -> std::io::Result<()> {
+    let records_dir = out_dir.join(RECORDS_DIR);
+    let dir = records_dir.join(build);
+    if !CLEARED.swap(true, Ordering::SeqCst) {
+        clear_earlier_builds(&records_dir, build)?;
+    }
+    std::fs::create_dir_all(&dir)?;
+    let content = serde_json::to_string_pretty(&record(module, func))?;
+    std::fs::write(dir.join(record_file_name(module, func)), content)
+}
+
+/// Remove the record directories of earlier compilations of the crate of `build`
+fn clear_earlier_builds(records_dir: &Path, build: &str) -> std::io::Result<()> {
+    let Some((crate_name, _)) = build.rsplit_once('-') else {
+        return Ok(());
+    };
+    let Ok(entries) = std::fs::read_dir(records_dir) else {
+        return Ok(());
+    };
+    for entry in entries {
+        let path: PathBuf = entry?.path();
+        let name = path
+            .file_name()
+            .map(|name| name.to_string_lossy().to_string())
+            .unwrap_or_default();
+        let earlier = name != build
+            && name
+                .rsplit_once('-')
+                .is_some_and(|(other_crate, _)| other_crate == crate_name);
+        // Records of the flat layout of earlier versions are stale as well
+        if earlier && path.is_dir() {
+            std::fs::remove_dir_all(&path)?;
+        } else if path.extension().is_some_and(|ext| ext == "json") {
+            std::fs::remove_file(&path)?;
+        }
+    }
+    Ok(())
+}
+
+/// `<module>.<command>.json`, unique within the crate unless inline modules repeat names
+fn record_file_name(module: &[String], func: &ItemFn) -> String {
+    let mut segments = module.to_vec();
+    segments.push(func.sig.ident.to_string());
+    format!("{}.json", segments.join("."))
+}
+
+/// Name, module, parameters and return type of `func`, types rendered from their tokens
+fn record(module: &[String], func: &ItemFn) -> serde_json::Value {
+    let parameters: Vec<serde_json::Value> = func
+        .sig
+        .inputs
+        .iter()
+        .filter_map(|input| match input {
+            FnArg::Typed(arg) => match arg.pat.as_ref() {
+                Pat::Ident(pat) => Some(serde_json::json!({
+                    "name": pat.ident.to_string(),
+                    "rustType": arg.ty.to_token_stream().to_string(),
+                })),
+                _ => None,
+            },
+            FnArg::Receiver(_) => None,
+        })
+        .collect();
+    let return_type = match &func.sig.output {
+        ReturnType::Default => None,
+        ReturnType::Type(_, ty) => Some(ty.to_token_stream().to_string()),
+    };
+
+    serde_json::json!({
+        "name": func.sig.ident.to_string(),
+        "modulePath": module.join("::"),
+        "isAsync": func.sig.asyncness.is_some(),
+        "parameters": parameters,
+        "returnType": return_type,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_record_signature() {
+        let func: ItemFn = syn::parse_quote! {
+            #[tauri::command]
+            pub async fn get_user(state: State<'_, Db>, id: u32) -> Result<User, String> {
+                unimplemented!()
+            }
+        };
+        assert_eq!(
+            record(&["commands".to_string()], &func),
+            serde_json::json!({
+                "name": "get_user",
+                "modulePath": "commands",
+                "isAsync": true,
+                "parameters": [
+                    { "name": "state", "rustType": "State < '_ , Db >" },
+                    { "name": "id", "rustType": "u32" },
+                ],
+                "returnType": "Result < User , String >",
+            })
+        );
+    }
+
+    #[test]
+    fn test_record_unit_return() {
+        let func: ItemFn = syn::parse_quote! {
+            fn ping() {}
+        };
+        assert_eq!(record(&[], &func)["returnType"], serde_json::Value::Null);
+    }
+
+    #[test]
+    fn test_module_path_of_file() {
+        let manifest_dir = Path::new("/app/src-tauri");
+        let module = |file: &str| module_path(Path::new(file), manifest_dir).join("::");
+        assert_eq!(module("src/lib.rs"), "");
+        assert_eq!(
+            module("/app/src-tauri/src/commands/user.rs"),
+            "commands::user"
+        );
+        assert_eq!(module("src/commands/mod.rs"), "commands");
+    }
+
+    #[test]
+    fn test_records_are_keyed_by_module_and_earlier_builds_cleared() {
+        let out_dir = std::env::temp_dir().join(format!("typegen-macros-{}", std::process::id()));
+        let records_dir = out_dir.join(RECORDS_DIR);
+        std::fs::create_dir_all(records_dir.join("app_lib-1")).unwrap();
+        std::fs::create_dir_all(records_dir.join("app-1")).unwrap();
+        std::fs::write(records_dir.join("get_user.json"), "{}").unwrap();
+        let func: ItemFn = syn::parse_quote! {
+            fn get_user() {}
+        };
+
+        let module = ["users".to_string()];
+        write_record(&out_dir, "app_lib-2", &module, &func).unwrap();
+        write_record(&out_dir, "app_lib-2", &["admin".to_string()], &func).unwrap();
+
+        let mut names: Vec<String> = std::fs::read_dir(&records_dir)
+            .unwrap()
+            .map(|entry| entry.unwrap().file_name().to_string_lossy().to_string())
+            .collect();
+        names.sort();
+        assert_eq!(names, ["app-1", "app_lib-2"]);
+        let build_dir = records_dir.join("app_lib-2");
+        assert!(build_dir.join("users.get_user.json").is_file());
+        assert!(build_dir.join("admin.get_user.json").is_file());
+        std::fs::remove_dir_all(&out_dir).unwrap();
+    }
+}
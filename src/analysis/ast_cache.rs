@@ -1,5 +1,7 @@
-use std::collections::HashMap;
-use std::path::PathBuf;
+use crate::analysis::typegen_ignore::TypegenIgnore;
+use crate::interface::output::{Phase, Verbosity, MAX_VERBOSITY};
+use std::collections::{HashMap, HashSet};
+use std::path::{Path, PathBuf};
 use syn::File as SynFile;
 use walkdir::WalkDir;
 
@@ -24,38 +26,123 @@ impl ParsedFile {
 #[derive(Debug, Default)]
 pub struct AstCache {
     cache: HashMap<PathBuf, ParsedFile>,
+    /// Maximum directory depth scanned below the project path
+    max_depth: Option<usize>,
+    /// Canonical paths of the scanned files, so files reachable through several
+    /// symlinks or roots are parsed once
+    scanned: HashSet<PathBuf>,
 }
 
 impl AstCache {
     pub fn new() -> Self {
         Self {
             cache: HashMap::new(),
+            max_depth: None,
+            scanned: HashSet::new(),
         }
     }
 
+    /// Limit how deep directories below the project path are scanned
+    pub fn set_max_depth(&mut self, max_depth: Option<usize>) {
+        self.max_depth = max_depth;
+    }
+
     /// Parse and cache all Rust files in the given project path
     pub fn parse_and_cache_all_files(
         &mut self,
         project_path: &str,
         verbose: bool,
     ) -> Result<(), Box<dyn std::error::Error>> {
+        let level = if verbose { MAX_VERBOSITY } else { 0 };
+        self.parse_and_cache_all_files_with_verbosity(
+            project_path,
+            &Verbosity::new(level, Vec::new()),
+        )
+    }
+
+    /// Parse and cache all Rust files in the given project path, logging the scan
+    /// summary from verbosity 1 and each file from verbosity 3
+    pub fn parse_and_cache_all_files_with_verbosity(
+        &mut self,
+        project_path: &str,
+        verbosity: &Verbosity,
+    ) -> Result<(), Box<dyn std::error::Error>> {
+        let verbose = verbosity.enabled(Phase::Scan, 1);
+        let trace = verbosity.enabled(Phase::Scan, MAX_VERBOSITY);
         if verbose {
             println!("🔄 Parsing and caching all Rust files in: {}", project_path);
         }
 
-        for entry in WalkDir::new(project_path) {
-            let entry = entry?;
+        self.scanned.clear();
+        self.scan_root(Path::new(project_path), None, trace)?;
+
+        if verbose {
+            println!("📊 Cached {} Rust files", self.cache.len());
+        }
+        Ok(())
+    }
+
+    /// Parse and cache the Rust files below `root` matching `include`, e.g. the source
+    /// of a path dependency vendored elsewhere, as part of the same analysis. The
+    /// patterns use the `.typegenignore` syntax; an empty list includes every file.
+    pub fn parse_and_cache_additional_root(
+        &mut self,
+        root: &str,
+        include: &[String],
+        verbosity: &Verbosity,
+    ) -> Result<(), Box<dyn std::error::Error>> {
+        let trace = verbosity.enabled(Phase::Scan, MAX_VERBOSITY);
+        if verbosity.enabled(Phase::Scan, 1) {
+            println!("🔄 Parsing and caching additional Rust files in: {}", root);
+        }
+        let include = (!include.is_empty()).then(|| TypegenIgnore::from_patterns(include));
+        self.scan_root(Path::new(root), include.as_ref(), trace)
+    }
+
+    /// Parse and cache the Rust files below `root` not excluded by its `.typegenignore`,
+    /// only those matching `include` if given
+    fn scan_root(
+        &mut self,
+        root: &Path,
+        include: Option<&TypegenIgnore>,
+        trace: bool,
+    ) -> Result<(), Box<dyn std::error::Error>> {
+        let ignore = TypegenIgnore::load(root);
+        // Symlinks are followed so symlinked crates are scanned; walkdir reports cycles
+        let mut walker = WalkDir::new(root).follow_links(true);
+        if let Some(max_depth) = self.max_depth {
+            walker = walker.max_depth(max_depth);
+        }
+        let entries = walker.into_iter().filter_entry(|entry| {
+            let relative = entry.path().strip_prefix(root).unwrap_or(entry.path());
+            if entry.file_type().is_dir() {
+                entry.depth() == 0 || !ignore.is_skipped_dir(relative)
+            } else {
+                !ignore.is_ignored(relative, false)
+                    && include.map_or(true, |include| include.matches(relative, false))
+            }
+        });
+
+        for entry in entries {
+            let entry = match entry {
+                Ok(entry) => entry,
+                Err(e) if e.loop_ancestor().is_some() => {
+                    if trace {
+                        println!("🔁 Skipping symlink cycle: {}", e);
+                    }
+                    continue;
+                }
+                Err(e) => return Err(e.into()),
+            };
             let path = entry.path();
 
-            if path.is_file() && path.extension().is_some_and(|ext| ext == "rs") {
-                // Skip target directory and other build artifacts
-                if path.to_string_lossy().contains("/target/")
-                    || path.to_string_lossy().contains("/.git/")
-                {
+            if entry.file_type().is_file() && path.extension().is_some_and(|ext| ext == "rs") {
+                let canonical = std::fs::canonicalize(path).unwrap_or_else(|_| path.to_path_buf());
+                if !self.scanned.insert(canonical) {
                     continue;
                 }
 
-                if verbose {
+                if trace {
                     println!("📄 Parsing file: {}", path.display());
                 }
 
@@ -64,7 +151,7 @@ impl AstCache {
                     Ok(ast) => {
                         let parsed_file = ParsedFile::new(ast, path.to_path_buf());
                         self.cache.insert(path.to_path_buf(), parsed_file);
-                        if verbose {
+                        if trace {
                             println!("✅ Successfully parsed: {}", path.display());
                         }
                     }
@@ -75,10 +162,6 @@ impl AstCache {
                 }
             }
         }
-
-        if verbose {
-            println!("📊 Cached {} Rust files", self.cache.len());
-        }
         Ok(())
     }
 
@@ -120,6 +203,7 @@ impl AstCache {
     /// Clear the cache
     pub fn clear(&mut self) {
         self.cache.clear();
+        self.scanned.clear();
     }
 
     /// Insert a parsed file into the cache
@@ -301,6 +385,103 @@ mod tests {
             cleanup_dir(&dir);
         }
 
+        #[test]
+        fn test_parse_honors_typegenignore() {
+            let dir = temp_dir();
+            fs::create_dir_all(&dir).unwrap();
+
+            create_rust_file(&dir, "lib.rs", "pub fn hello() {}");
+            create_rust_file(&dir, "legacy/old.rs", "fn old() {}");
+            create_rust_file(&dir, "src/api_generated.rs", "fn api() {}");
+            fs::write(
+                format!("{}/.typegenignore", dir),
+                "legacy/\n*_generated.rs\n",
+            )
+            .unwrap();
+
+            let mut cache = AstCache::new();
+            cache.parse_and_cache_all_files(&dir, false).unwrap();
+
+            assert_eq!(cache.len(), 1);
+
+            cleanup_dir(&dir);
+        }
+
+        #[test]
+        fn test_parse_respects_max_depth() {
+            let dir = temp_dir();
+            fs::create_dir_all(&dir).unwrap();
+
+            create_rust_file(&dir, "lib.rs", "pub fn hello() {}");
+            create_rust_file(&dir, "a/b/deep.rs", "fn deep() {}");
+
+            let mut cache = AstCache::new();
+            cache.set_max_depth(Some(2));
+            cache.parse_and_cache_all_files(&dir, false).unwrap();
+
+            assert_eq!(cache.len(), 1);
+
+            cleanup_dir(&dir);
+        }
+
+        #[test]
+        fn test_parse_additional_root_with_include_patterns() {
+            let dir = temp_dir();
+            fs::create_dir_all(&dir).unwrap();
+
+            create_rust_file(&dir, "app/lib.rs", "pub fn hello() {}");
+            create_rust_file(&dir, "vendor/src/commands.rs", "fn command() {}");
+            create_rust_file(&dir, "vendor/src/internal.rs", "fn internal() {}");
+
+            let mut cache = AstCache::new();
+            let verbosity = Verbosity::new(0, Vec::new());
+            cache
+                .parse_and_cache_all_files_with_verbosity(&format!("{}/app", dir), &verbosity)
+                .unwrap();
+            cache
+                .parse_and_cache_additional_root(
+                    &format!("{}/vendor", dir),
+                    &["src/commands.rs".to_string()],
+                    &verbosity,
+                )
+                .unwrap();
+            // Files already scanned through another root are not parsed twice
+            cache
+                .parse_and_cache_additional_root(&format!("{}/app", dir), &[], &verbosity)
+                .unwrap();
+
+            assert_eq!(cache.len(), 2);
+            assert!(cache.contains(&PathBuf::from(format!("{}/vendor/src/commands.rs", dir))));
+
+            cleanup_dir(&dir);
+        }
+
+        #[cfg(unix)]
+        #[test]
+        fn test_parse_follows_symlinks_without_looping() {
+            let dir = temp_dir();
+            let shared = format!("{}_shared", dir);
+            fs::create_dir_all(&dir).unwrap();
+
+            create_rust_file(&dir, "lib.rs", "pub fn hello() {}");
+            create_rust_file(&shared, "shared.rs", "pub struct Shared {}");
+            let shared_path = fs::canonicalize(&shared).unwrap();
+            // A symlinked crate outside the project, linked twice
+            std::os::unix::fs::symlink(&shared_path, format!("{}/shared", dir)).unwrap();
+            std::os::unix::fs::symlink(&shared_path, format!("{}/shared_again", dir)).unwrap();
+            // A cycle back into the project
+            let dir_path = fs::canonicalize(&dir).unwrap();
+            std::os::unix::fs::symlink(&dir_path, format!("{}/loop", shared)).unwrap();
+
+            let mut cache = AstCache::new();
+            cache.parse_and_cache_all_files(&dir, false).unwrap();
+
+            assert_eq!(cache.len(), 2);
+
+            cleanup_dir(&dir);
+            cleanup_dir(&shared);
+        }
+
         #[test]
         fn test_parse_continues_on_syntax_error() {
             let dir = temp_dir();
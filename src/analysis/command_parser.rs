@@ -1,19 +1,244 @@
 use crate::analysis::serde_parser::SerdeParser;
 use crate::analysis::type_resolver::TypeResolver;
-use crate::models::{CommandInfo, ParameterInfo};
+use crate::interface::messages::{Locale, Message, MessageId};
+use crate::models::{
+    CallSerialization, CommandInfo, InjectedParameter, ParameterInfo, TypeStructure,
+};
+use std::collections::{HashMap, HashSet};
 use std::path::Path;
-use syn::{File as SynFile, FnArg, ItemFn, PatType, ReturnType, Type};
+use syn::{
+    File as SynFile, FnArg, GenericParam, ItemFn, PatType, ReturnType, Type, UseTree,
+    WherePredicate,
+};
+
+/// Parameter types Tauri supplies to commands itself
+const DEFAULT_INJECTED_TYPES: &[&str] = &[
+    "tauri::AppHandle",
+    "tauri::State",
+    "tauri::Window",
+    "tauri::Webview",
+    "tauri::WebviewWindow",
+    "tauri::ipc::Request",
+    "tauri::ipc::Channel",
+];
+
+/// Injected type names that are also common names for user types. When used
+/// unqualified without an import (e.g. through `use tauri::*`), they only match
+/// with generic arguments, like Tauri's `State<'_, T>` and `Window<R>`.
+const AMBIGUOUS_INJECTED_NAMES: &[&str] = &["Channel", "Request", "State", "Webview", "Window"];
+
+/// Return types Tauri sends to the frontend as raw bytes instead of JSON
+const RAW_RESPONSE_TYPES: &[&str] = &["tauri::ipc::Response", "tauri::ipc::InvokeResponseBody"];
+
+/// Types holding OS resources or thread handles, which have no serialized form.
+/// Matched by their last two path segments (`fs::File`), since the bare names
+/// (`File`, `Sender`) are common names for user types.
+const NON_SERIALIZABLE_TYPES: &[&str] = &[
+    "fs::File",
+    "fs::ReadDir",
+    "net::TcpStream",
+    "net::TcpListener",
+    "net::UdpSocket",
+    "process::Child",
+    "process::Command",
+    "thread::JoinHandle",
+    "task::JoinHandle",
+    "mpsc::Sender",
+    "mpsc::SyncSender",
+    "mpsc::Receiver",
+    "oneshot::Sender",
+    "oneshot::Receiver",
+];
+
+/// Paths brought into scope by the `use` items of a file, keyed by the name they
+/// are referred to by (`use tauri::State as TauriState` → `TauriState`)
+#[derive(Debug, Default)]
+struct FileImports {
+    paths: HashMap<String, Vec<String>>,
+}
+
+impl FileImports {
+    fn from_ast(ast: &SynFile) -> Self {
+        let mut imports = Self::default();
+        for item in &ast.items {
+            if let syn::Item::Use(item_use) = item {
+                imports.collect(&item_use.tree, &mut Vec::new());
+            }
+        }
+        imports
+    }
+
+    fn collect(&mut self, tree: &UseTree, prefix: &mut Vec<String>) {
+        match tree {
+            UseTree::Path(use_path) => {
+                prefix.push(use_path.ident.to_string());
+                self.collect(&use_path.tree, prefix);
+                prefix.pop();
+            }
+            UseTree::Name(use_name) => self.insert(prefix, &use_name.ident, &use_name.ident),
+            UseTree::Rename(use_rename) => {
+                self.insert(prefix, &use_rename.ident, &use_rename.rename)
+            }
+            UseTree::Group(group) => group
+                .items
+                .iter()
+                .for_each(|item| self.collect(item, prefix)),
+            // Glob imports cannot be resolved without the imported module
+            UseTree::Glob(_) => {}
+        }
+    }
+
+    fn insert(&mut self, prefix: &[String], ident: &syn::Ident, alias: &syn::Ident) {
+        let mut path = prefix.to_vec();
+        // `use tauri::ipc::{self}` imports the module itself
+        if ident != "self" {
+            path.push(ident.to_string());
+        }
+        let alias = if alias == "self" {
+            path.last().cloned().unwrap_or_default()
+        } else {
+            alias.to_string()
+        };
+        self.paths.insert(alias, path);
+    }
+
+    /// Full path of a type path whose first segment is imported
+    fn resolve(&self, segments: &[String]) -> Option<Vec<String>> {
+        let (first, rest) = segments.split_first()?;
+        let mut path = self.paths.get(first)?.clone();
+        path.extend(rest.iter().cloned());
+        Some(path)
+    }
+}
+
+/// Generic type parameters declared on a command function
+#[derive(Debug, Default)]
+struct CommandGenerics {
+    /// Parameters bound by `Runtime`, e.g. `R` in `fn cmd<R: Runtime>(app: AppHandle<R>)`
+    runtime: HashSet<String>,
+    /// Any other type parameters; their concrete type is unknown to the frontend
+    other: HashSet<String>,
+}
+
+impl CommandGenerics {
+    /// Collect type parameters and their bounds from both the parameter list and the where clause
+    fn from_generics(generics: &syn::Generics) -> Self {
+        let mut result = Self::default();
+
+        let mut runtime_bound = HashSet::new();
+        if let Some(where_clause) = &generics.where_clause {
+            for predicate in &where_clause.predicates {
+                if let WherePredicate::Type(predicate) = predicate {
+                    if Self::has_runtime_bound(&predicate.bounds) {
+                        runtime_bound.insert(CommandParser::type_to_string(&predicate.bounded_ty));
+                    }
+                }
+            }
+        }
+
+        for param in &generics.params {
+            if let GenericParam::Type(type_param) = param {
+                let name = type_param.ident.to_string();
+                if Self::has_runtime_bound(&type_param.bounds) || runtime_bound.contains(&name) {
+                    result.runtime.insert(name);
+                } else {
+                    result.other.insert(name);
+                }
+            }
+        }
+
+        result
+    }
+
+    fn has_runtime_bound(
+        bounds: &syn::punctuated::Punctuated<syn::TypeParamBound, syn::token::Plus>,
+    ) -> bool {
+        bounds.iter().any(|bound| match bound {
+            syn::TypeParamBound::Trait(trait_bound) => trait_bound
+                .path
+                .segments
+                .last()
+                .is_some_and(|segment| segment.ident == "Runtime"),
+            _ => false,
+        })
+    }
+
+    /// Whether a Rust type string mentions a `Runtime` type parameter, e.g. `Webview<R>`
+    fn references_runtime(&self, rust_type: &str) -> bool {
+        !self.runtime.is_empty()
+            && rust_type
+                .split(|c: char| !c.is_alphanumeric() && c != '_')
+                .any(|ident| self.runtime.contains(ident))
+    }
+
+    /// Replace references to unconstrained type parameters with `unknown`
+    fn erase(&self, type_structure: TypeStructure) -> TypeStructure {
+        if self.other.is_empty() {
+            return type_structure;
+        }
+        let erase_box = |inner: Box<TypeStructure>| Box::new(self.erase(*inner));
+        match type_structure {
+            TypeStructure::Custom(name) if self.other.contains(&name) => {
+                TypeStructure::Primitive("unknown".to_string())
+            }
+            TypeStructure::Array(inner) => TypeStructure::Array(erase_box(inner)),
+            TypeStructure::Set(inner) => TypeStructure::Set(erase_box(inner)),
+            TypeStructure::Optional(inner) => TypeStructure::Optional(erase_box(inner)),
+            TypeStructure::Result(inner) => TypeStructure::Result(erase_box(inner)),
+            TypeStructure::Map { key, value } => TypeStructure::Map {
+                key: erase_box(key),
+                value: erase_box(value),
+            },
+            TypeStructure::Tuple(types) => {
+                TypeStructure::Tuple(types.into_iter().map(|t| self.erase(t)).collect())
+            }
+            TypeStructure::Union(types) => {
+                TypeStructure::Union(types.into_iter().map(|t| self.erase(t)).collect())
+            }
+            other => other,
+        }
+    }
+}
 
 /// Parser for Tauri command functions
 #[derive(Debug)]
 pub struct CommandParser {
     serde_parser: SerdeParser,
+    /// Full paths of the parameter types injected by Tauri, split into segments
+    injected_types: Vec<Vec<String>>,
+    /// Language of the warnings about parameters and `serialize_calls` keys
+    locale: Locale,
 }
 
 impl CommandParser {
     pub fn new() -> Self {
-        Self {
+        let mut parser = Self {
             serde_parser: SerdeParser::new(),
+            injected_types: Vec::new(),
+            locale: Locale::default(),
+        };
+        parser.add_injected_types(DEFAULT_INJECTED_TYPES.iter().copied());
+        parser
+    }
+
+    pub fn set_locale(&mut self, locale: Locale) {
+        self.locale = locale;
+    }
+
+    /// Add parameter types injected by Tauri (e.g. by plugins), as full paths like
+    /// `tauri_plugin_store::Store`. Generic arguments are ignored.
+    pub fn add_injected_types<'a>(&mut self, type_paths: impl IntoIterator<Item = &'a str>) {
+        for type_path in type_paths {
+            let path = type_path.split('<').next().unwrap_or(type_path);
+            let segments: Vec<String> = path
+                .trim()
+                .trim_start_matches("::")
+                .split("::")
+                .map(|segment| segment.trim().to_string())
+                .collect();
+            if !self.injected_types.contains(&segments) {
+                self.injected_types.push(segments);
+            }
         }
     }
 
@@ -24,13 +249,14 @@ impl CommandParser {
         file_path: &Path,
         type_resolver: &mut TypeResolver,
     ) -> Result<Vec<CommandInfo>, Box<dyn std::error::Error>> {
+        let imports = FileImports::from_ast(ast);
         let commands = ast
             .items
             .iter()
             .filter_map(|item| {
                 if let syn::Item::Fn(func) = item {
                     if self.is_tauri_command(func) {
-                        return self.extract_command_info(func, file_path, type_resolver);
+                        return self.extract_command_info(func, file_path, &imports, type_resolver);
                     }
                 }
                 None
@@ -40,14 +266,33 @@ impl CommandParser {
         Ok(commands)
     }
 
-    /// Check if a function is a Tauri command
+    /// Check if a function is a Tauri command. Any attribute may be the command
+    /// attribute, not only the first, also when wrapped in `#[cfg_attr(...)]`.
     fn is_tauri_command(&self, func: &ItemFn) -> bool {
-        func.attrs.iter().any(|attr| {
-            attr.path().segments.len() == 2
-                && attr.path().segments[0].ident == "tauri"
-                && attr.path().segments[1].ident == "command"
-                || attr.path().is_ident("command")
-        })
+        func.attrs
+            .iter()
+            .any(|attr| Self::is_command_meta(&attr.meta))
+    }
+
+    /// `tauri::command` or `command`, with or without arguments, or a (nested)
+    /// `cfg_attr` applying one of them
+    fn is_command_meta(meta: &syn::Meta) -> bool {
+        type MetaList = syn::punctuated::Punctuated<syn::Meta, syn::Token![,]>;
+
+        let path = meta.path();
+        if path.is_ident("cfg_attr") {
+            let syn::Meta::List(list) = meta else {
+                return false;
+            };
+            // The first item is the predicate
+            return list
+                .parse_args_with(MetaList::parse_terminated)
+                .is_ok_and(|metas| metas.iter().skip(1).any(Self::is_command_meta));
+        }
+        path.is_ident("command")
+            || path.segments.len() == 2
+                && path.segments[0].ident == "tauri"
+                && path.segments[1].ident == "command"
     }
 
     /// Extract command information from a function
@@ -55,24 +300,75 @@ impl CommandParser {
         &self,
         func: &ItemFn,
         file_path: &Path,
+        imports: &FileImports,
         type_resolver: &mut TypeResolver,
     ) -> Option<CommandInfo> {
         let name = func.sig.ident.to_string();
-
-        let parameters = self.extract_parameters(&func.sig.inputs, type_resolver);
-        let return_type = self.extract_return_type(&func.sig.output);
-        let return_type_structure = type_resolver.parse_type_structure(&return_type);
-        let is_async = func.sig.asyncness.is_some();
+        let generics = CommandGenerics::from_generics(&func.sig.generics);
 
         // Get line number from the function's span
         let line_number = func.sig.ident.span().start().line;
 
+        let (parameters, injected_parameters) =
+            self.extract_parameters(&func.sig.inputs, &generics, imports, type_resolver);
+        for input in &func.sig.inputs {
+            if let FnArg::Typed(PatType { pat, ty, .. }) = input {
+                if let Some(reason) = Self::non_serializable_reason(ty) {
+                    let param = match pat.as_ref() {
+                        syn::Pat::Ident(pat_ident) => pat_ident.ident.to_string(),
+                        _ => "_".to_string(),
+                    };
+                    eprintln!(
+                        "{}",
+                        Message::new(MessageId::ParameterNotSerializable)
+                            .arg("parameter", param)
+                            .arg("command", &name)
+                            .arg(
+                                "location",
+                                format!("{}:{}", file_path.display(), line_number)
+                            )
+                            .arg("reason", reason)
+                            .render(self.locale)
+                    );
+                }
+            }
+        }
+        let return_type = self.extract_return_type(&func.sig.output);
+        let raw_response = Self::returns_raw_response(&func.sig.output, imports);
+        let return_type_structure = if raw_response {
+            Self::raw_response_structure(type_resolver.parse_type_structure(&return_type))
+        } else {
+            generics.erase(type_resolver.parse_type_structure(&return_type))
+        };
+        let is_async = func.sig.asyncness.is_some();
+
         // Parse serde rename_all attribute from function attributes
         let serde_rename_all = self
             .serde_parser
             .parse_struct_serde_attrs(&func.attrs)
             .rename_all;
 
+        let serialize_calls =
+            Self::parse_serialize_calls(&func.attrs).map(|serialization| match serialization {
+                CallSerialization::Key(ref key)
+                    if !parameters.iter().any(|param| &param.name == key) =>
+                {
+                    eprintln!(
+                        "{}",
+                        Message::new(MessageId::UnknownSerializeCallsKey)
+                            .arg("key", key)
+                            .arg("command", &name)
+                            .arg(
+                                "location",
+                                format!("{}:{}", file_path.display(), line_number)
+                            )
+                            .render(self.locale)
+                    );
+                    CallSerialization::Command
+                }
+                serialization => serialization,
+            });
+
         Some(CommandInfo {
             name,
             parameters,
@@ -83,107 +379,291 @@ impl CommandParser {
             is_async,
             channels: Vec::new(), // Will be populated by channel_parser
             serde_rename_all,
+            injected_parameters,
+            raw_response,
+            serialize_calls,
+            invalidates: Self::parse_invalidates(&func.attrs),
         })
     }
 
-    /// Extract parameters from function signature
-    fn extract_parameters(
-        &self,
-        inputs: &syn::punctuated::Punctuated<FnArg, syn::token::Comma>,
-        type_resolver: &mut TypeResolver,
-    ) -> Vec<ParameterInfo> {
-        inputs
-            .iter()
-            .filter_map(|input| {
-                if let FnArg::Typed(PatType { pat, ty, attrs, .. }) = input {
-                    if let syn::Pat::Ident(pat_ident) = pat.as_ref() {
-                        let name = pat_ident.ident.to_string();
-
-                        // Skip Tauri-specific parameters
-                        if self.is_tauri_parameter_type(ty) {
-                            return None;
-                        }
-
-                        let rust_type = Self::type_to_string(ty);
-                        let type_structure = type_resolver.parse_type_structure(&rust_type);
-                        let is_optional = self.is_optional_type(ty);
+    /// Options of `#[typegen(...)]` attributes, also when wrapped in
+    /// `#[cfg_attr(..., typegen(...))]` so the code compiles without the attribute
+    pub(crate) fn typegen_options(attrs: &[syn::Attribute]) -> Vec<syn::Meta> {
+        type MetaList = syn::punctuated::Punctuated<syn::Meta, syn::Token![,]>;
 
-                        // Parse serde rename attribute from parameter attributes
-                        let serde_rename = self.serde_parser.parse_field_serde_attrs(attrs).rename;
+        let mut options = Vec::new();
+        for attr in attrs {
+            if attr.path().is_ident("typegen") {
+                if let Ok(metas) = attr.parse_args_with(MetaList::parse_terminated) {
+                    options.extend(metas);
+                }
+            } else if attr.path().is_ident("cfg_attr") {
+                let Ok(metas) = attr.parse_args_with(MetaList::parse_terminated) else {
+                    continue;
+                };
+                // The first item is the predicate
+                for meta in metas.into_iter().skip(1) {
+                    if let syn::Meta::List(list) = meta {
+                        if list.path.is_ident("typegen") {
+                            if let Ok(metas) = list.parse_args_with(MetaList::parse_terminated) {
+                                options.extend(metas);
+                            }
+                        }
+                    }
+                }
+            }
+        }
+        options
+    }
 
-                        return Some(ParameterInfo {
-                            name,
-                            rust_type,
-                            is_optional,
-                            type_structure,
-                            serde_rename,
-                        });
+    /// Parse `#[typegen(serialize_calls)]` or `#[typegen(serialize_calls = "param")]`
+    fn parse_serialize_calls(attrs: &[syn::Attribute]) -> Option<CallSerialization> {
+        Self::typegen_options(attrs)
+            .into_iter()
+            .find_map(|meta| match meta {
+                syn::Meta::Path(path) if path.is_ident("serialize_calls") => {
+                    Some(CallSerialization::Command)
+                }
+                syn::Meta::NameValue(name_value) if name_value.path.is_ident("serialize_calls") => {
+                    match name_value.value {
+                        syn::Expr::Lit(syn::ExprLit {
+                            lit: syn::Lit::Str(key),
+                            ..
+                        }) => Some(CallSerialization::Key(key.value())),
+                        _ => None,
                     }
                 }
-                None
+                _ => None,
             })
-            .collect()
     }
 
-    /// Check if a parameter type is a Tauri-specific type that should be skipped
-    /// This checks the actual syn::Type to properly handle both imported and fully-qualified types
-    fn is_tauri_parameter_type(&self, ty: &Type) -> bool {
-        if let Type::Path(type_path) = ty {
-            let segments = &type_path.path.segments;
-
-            // Check various patterns:
-            // 1. Fully qualified: tauri::AppHandle, tauri::State<T>, tauri::ipc::Request
-            // 2. Imported: AppHandle, State<T>, Window<T>
-            if segments.len() >= 2 {
-                // Check for tauri::* or tauri::ipc::*
-                if segments[0].ident == "tauri" {
-                    if segments.len() == 2 {
-                        // tauri::AppHandle, tauri::Window, etc.
-                        let second = &segments[1].ident;
-                        return second == "AppHandle"
-                            || second == "Window"
-                            || second == "WebviewWindow"
-                            || second == "State"
-                            || second == "Manager";
-                    } else if segments.len() == 3 && segments[1].ident == "ipc" {
-                        // tauri::ipc::Request, tauri::ipc::Channel
-                        let third = &segments[2].ident;
-                        return third == "Request" || third == "Channel";
+    /// Parse `#[typegen(invalidates = "query")]`, in attribute order
+    fn parse_invalidates(attrs: &[syn::Attribute]) -> Vec<String> {
+        Self::typegen_options(attrs)
+            .into_iter()
+            .filter_map(|meta| match meta {
+                syn::Meta::NameValue(name_value) if name_value.path.is_ident("invalidates") => {
+                    match name_value.value {
+                        syn::Expr::Lit(syn::ExprLit {
+                            lit: syn::Lit::Str(query),
+                            ..
+                        }) => Some(query.value()),
+                        _ => None,
                     }
                 }
+                _ => None,
+            })
+            .collect()
+    }
+
+    /// Extract parameters from function signature, separating those injected by Tauri
+    fn extract_parameters(
+        &self,
+        inputs: &syn::punctuated::Punctuated<FnArg, syn::token::Comma>,
+        generics: &CommandGenerics,
+        imports: &FileImports,
+        type_resolver: &mut TypeResolver,
+    ) -> (Vec<ParameterInfo>, Vec<InjectedParameter>) {
+        let mut parameters = Vec::new();
+        let mut injected = Vec::new();
+
+        for (position, input) in inputs.iter().enumerate() {
+            let FnArg::Typed(PatType { pat, ty, attrs, .. }) = input else {
+                continue;
+            };
+            let rust_type = Self::type_to_string(ty);
+            let inject = |name: String| InjectedParameter {
+                name,
+                rust_type: rust_type.clone(),
+                position,
+            };
+
+            let syn::Pat::Ident(pat_ident) = pat.as_ref() else {
+                // Destructured parameters cannot be named in the invoke payload
+                injected.push(inject(String::new()));
+                continue;
+            };
+            let name = pat_ident.ident.to_string();
+
+            // Skip Tauri-specific parameters, as well as types generic over the
+            // Tauri runtime, which are injected by Tauri
+            if self.is_tauri_parameter_type(ty, imports) || generics.references_runtime(&rust_type)
+            {
+                injected.push(inject(name));
+                continue;
             }
 
-            // Check for imported types (single segment)
-            if let Some(last_segment) = segments.last() {
-                let type_ident = &last_segment.ident;
+            // Parameters that cannot be deserialized from the invoke payload have no
+            // frontend value (reported by extract_command_info)
+            if Self::non_serializable_reason(ty).is_some() {
+                injected.push(inject(name));
+                continue;
+            }
 
-                // Only match specific Tauri types that are commonly imported
-                // Be careful not to match user types with similar names
-                if type_ident == "AppHandle" || type_ident == "WebviewWindow" {
-                    return true;
-                }
+            let type_structure = generics.erase(type_resolver.parse_type_structure(&rust_type));
+            let is_optional = self.is_optional_type(ty);
 
-                // Channel should be filtered if it has generic parameters (indicating it's the Tauri IPC channel)
-                if type_ident == "Channel"
-                    && matches!(
-                        last_segment.arguments,
-                        syn::PathArguments::AngleBracketed(_)
-                    )
-                {
-                    return true;
-                }
+            // Parse serde rename attribute from parameter attributes
+            let serde_rename = self.serde_parser.parse_field_serde_attrs(attrs).rename;
 
-                // State and Window are common names, only match if they have generic params
-                // (Tauri's State and Window types always have generics like State<T>, Window<R>)
-                if (type_ident == "State" || type_ident == "Window")
-                    && !last_segment.arguments.is_empty()
-                {
-                    return true;
+            parameters.push(ParameterInfo {
+                name,
+                rust_type,
+                is_optional,
+                type_structure,
+                serde_rename,
+            });
+        }
+
+        (parameters, injected)
+    }
+
+    /// Check if a parameter type is injected by Tauri and must be skipped.
+    ///
+    /// Imported names are resolved to their full path and must match an injected type
+    /// exactly, so a project's own `State<T>` is not mistaken for Tauri's. Names that
+    /// are not imported (e.g. through a glob import) match by their trailing segments.
+    fn is_tauri_parameter_type(&self, ty: &Type, imports: &FileImports) -> bool {
+        let Type::Path(type_path) = ty else {
+            return false;
+        };
+        let segments: Vec<String> = type_path
+            .path
+            .segments
+            .iter()
+            .map(|segment| segment.ident.to_string())
+            .collect();
+
+        if let Some(path) = imports.resolve(&segments) {
+            return self.injected_types.contains(&path);
+        }
+
+        match segments.as_slice() {
+            [name] => {
+                let has_generics = type_path
+                    .path
+                    .segments
+                    .last()
+                    .is_some_and(|segment| !segment.arguments.is_empty());
+                (has_generics || !AMBIGUOUS_INJECTED_NAMES.contains(&name.as_str()))
+                    && self
+                        .injected_types
+                        .iter()
+                        .any(|injected| injected.last() == Some(name))
+            }
+            _ => self
+                .injected_types
+                .iter()
+                .any(|injected| injected.ends_with(&segments)),
+        }
+    }
+
+    /// Check if a command returns `tauri::ipc::Response` (or `InvokeResponseBody`),
+    /// directly or as the `Ok` type of a `Result`.
+    ///
+    /// Resolved like injected types, except that an unqualified `Response` without
+    /// an import is taken to be a project type.
+    fn returns_raw_response(output: &ReturnType, imports: &FileImports) -> bool {
+        let ReturnType::Type(_, ty) = output else {
+            return false;
+        };
+        let Type::Path(type_path) = ty.as_ref() else {
+            return false;
+        };
+        let ok_type = type_path.path.segments.last().and_then(|segment| {
+            if segment.ident != "Result" {
+                return None;
+            }
+            match &segment.arguments {
+                syn::PathArguments::AngleBracketed(args) => {
+                    args.args.iter().find_map(|arg| match arg {
+                        syn::GenericArgument::Type(ty) => Some(ty),
+                        _ => None,
+                    })
                 }
+                _ => None,
             }
+        });
+        let Some(Type::Path(type_path)) = ok_type.or(Some(ty.as_ref())) else {
+            return false;
+        };
+
+        let segments: Vec<String> = type_path
+            .path
+            .segments
+            .iter()
+            .map(|segment| segment.ident.to_string())
+            .collect();
+        let raw_types = RAW_RESPONSE_TYPES
+            .iter()
+            .map(|path| path.split("::").map(String::from).collect::<Vec<_>>());
+
+        if let Some(path) = imports.resolve(&segments) {
+            return raw_types.into_iter().any(|raw| raw == path);
         }
+        match segments.as_slice() {
+            [name] if name == "Response" => false,
+            _ => raw_types.into_iter().any(|raw| raw.ends_with(&segments)),
+        }
+    }
 
-        false
+    /// Return type structure of a raw response command: bytes, kept inside a `Result`
+    fn raw_response_structure(structure: TypeStructure) -> TypeStructure {
+        match structure {
+            TypeStructure::Result(_) => {
+                TypeStructure::Result(Box::new(TypeStructure::Primitive("Uint8Array".to_string())))
+            }
+            _ => TypeStructure::Primitive("Uint8Array".to_string()),
+        }
+    }
+
+    /// Why a parameter type cannot cross the IPC boundary, `None` for types that may.
+    /// Generic arguments are checked too, so `Option<std::fs::File>` is caught.
+    fn non_serializable_reason(ty: &Type) -> Option<String> {
+        match ty {
+            Type::Reference(type_ref) if type_ref.mutability.is_some() => {
+                Some("mutable references cannot be deserialized".to_string())
+            }
+            Type::Reference(type_ref) => Self::non_serializable_reason(&type_ref.elem),
+            Type::Ptr(_) => Some("raw pointers cannot be serialized".to_string()),
+            Type::BareFn(_) => Some("function pointers cannot be serialized".to_string()),
+            Type::TraitObject(_) | Type::ImplTrait(_) => {
+                Some("trait objects cannot be deserialized".to_string())
+            }
+            Type::Paren(paren) => Self::non_serializable_reason(&paren.elem),
+            Type::Tuple(tuple) => tuple.elems.iter().find_map(Self::non_serializable_reason),
+            Type::Array(array) => Self::non_serializable_reason(&array.elem),
+            Type::Slice(slice) => Self::non_serializable_reason(&slice.elem),
+            Type::Path(type_path) => {
+                let segments: Vec<String> = type_path
+                    .path
+                    .segments
+                    .iter()
+                    .map(|segment| segment.ident.to_string())
+                    .collect();
+                if let [.., module, ident] = segments.as_slice() {
+                    let suffix = format!("{}::{}", module, ident);
+                    if NON_SERIALIZABLE_TYPES.contains(&suffix.as_str()) {
+                        return Some(format!("{} has no serialized form", segments.join("::")));
+                    }
+                }
+
+                type_path
+                    .path
+                    .segments
+                    .iter()
+                    .filter_map(|segment| match &segment.arguments {
+                        syn::PathArguments::AngleBracketed(args) => Some(args),
+                        _ => None,
+                    })
+                    .flat_map(|args| args.args.iter())
+                    .find_map(|arg| match arg {
+                        syn::GenericArgument::Type(inner) => Self::non_serializable_reason(inner),
+                        _ => None,
+                    })
+            }
+            _ => None,
+        }
     }
 
     /// Extract return type from function signature - returns rust_type only
@@ -322,6 +802,55 @@ mod tests {
             assert!(!parser.is_tauri_command(&func));
         }
 
+        #[test]
+        fn test_recognizes_command_below_other_attributes() {
+            let parser = CommandParser::new();
+            let func: ItemFn = parse_quote! {
+                #[allow(clippy::too_many_arguments)]
+                #[tracing::instrument(skip(state), fields(user = %id))]
+                #[doc = "Get a user"]
+                #[tauri::command(rename_all = "snake_case")]
+                async fn get_user(state: State<'_, Db>, id: u32) -> Result<User, String> {
+                    unimplemented!()
+                }
+            };
+
+            assert!(parser.is_tauri_command(&func));
+        }
+
+        #[test]
+        fn test_recognizes_cfg_attr_command() {
+            let parser = CommandParser::new();
+            let wrapped: ItemFn = parse_quote! {
+                #[cfg_attr(feature = "desktop", tauri::command)]
+                fn greet() {}
+            };
+            let among_others: ItemFn = parse_quote! {
+                #[cfg_attr(all(feature = "desktop", not(test)), allow(unused), tauri::command(async))]
+                fn greet() {}
+            };
+            let nested: ItemFn = parse_quote! {
+                #[cfg_attr(unix, cfg_attr(feature = "desktop", command))]
+                fn greet() {}
+            };
+
+            assert!(parser.is_tauri_command(&wrapped));
+            assert!(parser.is_tauri_command(&among_others));
+            assert!(parser.is_tauri_command(&nested));
+        }
+
+        #[test]
+        fn test_rejects_cfg_attr_without_command() {
+            let parser = CommandParser::new();
+            let func: ItemFn = parse_quote! {
+                #[cfg_attr(feature = "tauri", derive(Debug))]
+                #[tracing::instrument]
+                fn greet() {}
+            };
+
+            assert!(!parser.is_tauri_command(&func));
+        }
+
         #[test]
         fn test_rejects_other_attributes() {
             let parser = CommandParser::new();
@@ -433,77 +962,87 @@ mod tests {
         fn test_recognizes_app_handle() {
             let parser = CommandParser::new();
             let ty: Type = parse_quote!(tauri::AppHandle);
-            assert!(parser.is_tauri_parameter_type(&ty));
+            assert!(parser.is_tauri_parameter_type(&ty, &FileImports::default()));
         }
 
         #[test]
         fn test_recognizes_imported_app_handle() {
             let parser = CommandParser::new();
             let ty: Type = parse_quote!(AppHandle);
-            assert!(parser.is_tauri_parameter_type(&ty));
+            assert!(parser.is_tauri_parameter_type(&ty, &FileImports::default()));
         }
 
         #[test]
         fn test_recognizes_window_with_generics() {
             let parser = CommandParser::new();
             let ty: Type = parse_quote!(Window<R>);
-            assert!(parser.is_tauri_parameter_type(&ty));
+            assert!(parser.is_tauri_parameter_type(&ty, &FileImports::default()));
         }
 
         #[test]
         fn test_recognizes_state_with_generics() {
             let parser = CommandParser::new();
             let ty: Type = parse_quote!(State<AppState>);
-            assert!(parser.is_tauri_parameter_type(&ty));
+            assert!(parser.is_tauri_parameter_type(&ty, &FileImports::default()));
         }
 
         #[test]
         fn test_recognizes_webview_window() {
             let parser = CommandParser::new();
             let ty: Type = parse_quote!(tauri::WebviewWindow);
-            assert!(parser.is_tauri_parameter_type(&ty));
+            assert!(parser.is_tauri_parameter_type(&ty, &FileImports::default()));
         }
 
         #[test]
         fn test_recognizes_imported_webview_window() {
             let parser = CommandParser::new();
             let ty: Type = parse_quote!(WebviewWindow);
-            assert!(parser.is_tauri_parameter_type(&ty));
+            assert!(parser.is_tauri_parameter_type(&ty, &FileImports::default()));
         }
 
         #[test]
         fn test_recognizes_ipc_request() {
             let parser = CommandParser::new();
             let ty: Type = parse_quote!(tauri::ipc::Request);
-            assert!(parser.is_tauri_parameter_type(&ty));
+            assert!(parser.is_tauri_parameter_type(&ty, &FileImports::default()));
         }
 
         #[test]
         fn test_recognizes_ipc_channel() {
             let parser = CommandParser::new();
             let ty: Type = parse_quote!(tauri::ipc::Channel<String>);
-            assert!(parser.is_tauri_parameter_type(&ty));
+            assert!(parser.is_tauri_parameter_type(&ty, &FileImports::default()));
         }
 
         #[test]
         fn test_recognizes_channel_with_generics() {
             let parser = CommandParser::new();
             let ty: Type = parse_quote!(Channel<ProgressUpdate>);
-            assert!(parser.is_tauri_parameter_type(&ty));
+            assert!(parser.is_tauri_parameter_type(&ty, &FileImports::default()));
         }
 
         #[test]
         fn test_rejects_user_string_type() {
             let parser = CommandParser::new();
             let ty: Type = parse_quote!(String);
-            assert!(!parser.is_tauri_parameter_type(&ty));
+            assert!(!parser.is_tauri_parameter_type(&ty, &FileImports::default()));
         }
 
         #[test]
         fn test_rejects_user_custom_type() {
             let parser = CommandParser::new();
             let ty: Type = parse_quote!(User);
-            assert!(!parser.is_tauri_parameter_type(&ty));
+            assert!(!parser.is_tauri_parameter_type(&ty, &FileImports::default()));
+        }
+
+        #[test]
+        fn test_recognizes_webview_with_generics() {
+            let parser = CommandParser::new();
+            let ty: Type = parse_quote!(Webview<R>);
+            assert!(parser.is_tauri_parameter_type(&ty, &FileImports::default()));
+
+            let ty: Type = parse_quote!(tauri::Webview<R>);
+            assert!(parser.is_tauri_parameter_type(&ty, &FileImports::default()));
         }
 
         #[test]
@@ -511,7 +1050,7 @@ mod tests {
             let parser = CommandParser::new();
             // User might have their own State type without generics
             let ty: Type = parse_quote!(State);
-            assert!(!parser.is_tauri_parameter_type(&ty));
+            assert!(!parser.is_tauri_parameter_type(&ty, &FileImports::default()));
         }
 
         #[test]
@@ -519,7 +1058,145 @@ mod tests {
             let parser = CommandParser::new();
             // User might have their own Window type without generics
             let ty: Type = parse_quote!(Window);
-            assert!(!parser.is_tauri_parameter_type(&ty));
+            assert!(!parser.is_tauri_parameter_type(&ty, &FileImports::default()));
+        }
+
+        #[test]
+        fn test_resolves_imported_paths() {
+            let parser = CommandParser::new();
+            let imports = FileImports::from_ast(&parse_quote! {
+                use crate::machine::{State, StateMachine};
+                use tauri::{ipc, Window as TauriWindow};
+            });
+
+            let ty: Type = parse_quote!(State<Transition>);
+            assert!(!parser.is_tauri_parameter_type(&ty, &imports));
+            let ty: Type = parse_quote!(StateMachine<Transition>);
+            assert!(!parser.is_tauri_parameter_type(&ty, &imports));
+            let ty: Type = parse_quote!(TauriWindow<R>);
+            assert!(parser.is_tauri_parameter_type(&ty, &imports));
+            let ty: Type = parse_quote!(ipc::Request);
+            assert!(parser.is_tauri_parameter_type(&ty, &imports));
+        }
+
+        #[test]
+        fn test_rejects_qualified_user_types() {
+            let parser = CommandParser::new();
+            let ty: Type = parse_quote!(machine::State<Transition>);
+            assert!(!parser.is_tauri_parameter_type(&ty, &FileImports::default()));
+        }
+
+        #[test]
+        fn test_recognizes_configured_types() {
+            let mut parser = CommandParser::new();
+            parser.add_injected_types(["tauri_plugin_store::Store<R>"]);
+
+            let ty: Type = parse_quote!(tauri_plugin_store::Store<R>);
+            assert!(parser.is_tauri_parameter_type(&ty, &FileImports::default()));
+
+            let imports = FileImports::from_ast(&parse_quote! {
+                use tauri_plugin_store::Store;
+            });
+            let ty: Type = parse_quote!(Store<R>);
+            assert!(parser.is_tauri_parameter_type(&ty, &imports));
+        }
+    }
+
+    // non_serializable_reason tests
+    mod non_serializable_reason {
+        use super::*;
+
+        #[test]
+        fn test_flags_mutable_references() {
+            let ty: Type = parse_quote!(&mut State<AppState>);
+            assert_eq!(
+                CommandParser::non_serializable_reason(&ty).as_deref(),
+                Some("mutable references cannot be deserialized")
+            );
+        }
+
+        #[test]
+        fn test_flags_raw_pointers() {
+            let ty: Type = parse_quote!(*const u8);
+            assert!(CommandParser::non_serializable_reason(&ty).is_some());
+        }
+
+        #[test]
+        fn test_flags_nested_os_handles() {
+            let ty: Type = parse_quote!(Option<std::fs::File>);
+            assert_eq!(
+                CommandParser::non_serializable_reason(&ty).as_deref(),
+                Some("std::fs::File has no serialized form")
+            );
+        }
+
+        #[test]
+        fn test_accepts_user_types_with_common_names() {
+            for ty in [
+                parse_quote!(File),
+                parse_quote!(Sender),
+                parse_quote!(&str),
+                parse_quote!(Vec<models::File>),
+            ] {
+                let ty: Type = ty;
+                assert_eq!(CommandParser::non_serializable_reason(&ty), None);
+            }
+        }
+    }
+
+    // returns_raw_response tests
+    mod returns_raw_response {
+        use super::*;
+
+        #[test]
+        fn test_detects_qualified_response() {
+            let output: ReturnType = parse_quote!(-> tauri::ipc::Response);
+            assert!(CommandParser::returns_raw_response(
+                &output,
+                &FileImports::default()
+            ));
+            let output: ReturnType = parse_quote!(-> Result<ipc::Response, String>);
+            assert!(CommandParser::returns_raw_response(
+                &output,
+                &FileImports::default()
+            ));
+        }
+
+        #[test]
+        fn test_resolves_imported_response() {
+            let imports = FileImports::from_ast(&parse_quote! {
+                use tauri::ipc::{InvokeResponseBody, Response};
+            });
+
+            let output: ReturnType = parse_quote!(-> Response);
+            assert!(CommandParser::returns_raw_response(&output, &imports));
+            let output: ReturnType = parse_quote!(-> Result<InvokeResponseBody, Error>);
+            assert!(CommandParser::returns_raw_response(&output, &imports));
+        }
+
+        #[test]
+        fn test_ignores_project_response_types() {
+            let output: ReturnType = parse_quote!(-> Response);
+            assert!(!CommandParser::returns_raw_response(
+                &output,
+                &FileImports::default()
+            ));
+
+            let imports = FileImports::from_ast(&parse_quote! {
+                use crate::api::Response;
+            });
+            assert!(!CommandParser::returns_raw_response(&output, &imports));
+        }
+
+        #[test]
+        fn test_raw_response_structure_keeps_result() {
+            let structure = CommandParser::raw_response_structure(TypeStructure::Result(Box::new(
+                TypeStructure::Custom("Response".to_string()),
+            )));
+            assert_eq!(
+                structure,
+                TypeStructure::Result(Box::new(TypeStructure::Primitive("Uint8Array".to_string())))
+            );
         }
     }
 
@@ -566,7 +1243,12 @@ mod tests {
             let mut type_resolver = TypeResolver::new();
             let inputs = parse_quote!(name: String);
 
-            let params = parser.extract_parameters(&inputs, &mut type_resolver);
+            let (params, _) = parser.extract_parameters(
+                &inputs,
+                &CommandGenerics::default(),
+                &FileImports::default(),
+                &mut type_resolver,
+            );
 
             assert_eq!(params.len(), 1);
             assert_eq!(params[0].name, "name");
@@ -580,7 +1262,12 @@ mod tests {
             let mut type_resolver = TypeResolver::new();
             let inputs = parse_quote!(email: Option<String>);
 
-            let params = parser.extract_parameters(&inputs, &mut type_resolver);
+            let (params, _) = parser.extract_parameters(
+                &inputs,
+                &CommandGenerics::default(),
+                &FileImports::default(),
+                &mut type_resolver,
+            );
 
             assert_eq!(params.len(), 1);
             assert_eq!(params[0].name, "email");
@@ -593,7 +1280,12 @@ mod tests {
             let mut type_resolver = TypeResolver::new();
             let inputs = parse_quote!(name: String, age: i32);
 
-            let params = parser.extract_parameters(&inputs, &mut type_resolver);
+            let (params, _) = parser.extract_parameters(
+                &inputs,
+                &CommandGenerics::default(),
+                &FileImports::default(),
+                &mut type_resolver,
+            );
 
             assert_eq!(params.len(), 2);
             assert_eq!(params[0].name, "name");
@@ -606,7 +1298,12 @@ mod tests {
             let mut type_resolver = TypeResolver::new();
             let inputs = parse_quote!(app: AppHandle, name: String);
 
-            let params = parser.extract_parameters(&inputs, &mut type_resolver);
+            let (params, _) = parser.extract_parameters(
+                &inputs,
+                &CommandGenerics::default(),
+                &FileImports::default(),
+                &mut type_resolver,
+            );
 
             // AppHandle should be filtered out
             assert_eq!(params.len(), 1);
@@ -619,11 +1316,24 @@ mod tests {
             let mut type_resolver = TypeResolver::new();
             let inputs = parse_quote!(state: State<AppState>, name: String);
 
-            let params = parser.extract_parameters(&inputs, &mut type_resolver);
+            let (params, injected) = parser.extract_parameters(
+                &inputs,
+                &CommandGenerics::default(),
+                &FileImports::default(),
+                &mut type_resolver,
+            );
 
             // State should be filtered out
             assert_eq!(params.len(), 1);
             assert_eq!(params[0].name, "name");
+            assert_eq!(
+                injected,
+                vec![InjectedParameter {
+                    name: "state".to_string(),
+                    rust_type: "State<AppState>".to_string(),
+                    position: 0,
+                }]
+            );
         }
 
         #[test]
@@ -632,7 +1342,12 @@ mod tests {
             let mut type_resolver = TypeResolver::new();
             let inputs = parse_quote!(progress: Channel<u32>, name: String);
 
-            let params = parser.extract_parameters(&inputs, &mut type_resolver);
+            let (params, _) = parser.extract_parameters(
+                &inputs,
+                &CommandGenerics::default(),
+                &FileImports::default(),
+                &mut type_resolver,
+            );
 
             // Channel should be filtered out
             assert_eq!(params.len(), 1);
@@ -645,7 +1360,12 @@ mod tests {
             let mut type_resolver = TypeResolver::new();
             let inputs = parse_quote!();
 
-            let params = parser.extract_parameters(&inputs, &mut type_resolver);
+            let (params, _) = parser.extract_parameters(
+                &inputs,
+                &CommandGenerics::default(),
+                &FileImports::default(),
+                &mut type_resolver,
+            );
 
             assert_eq!(params.len(), 0);
         }
@@ -668,7 +1388,12 @@ mod tests {
             };
             let path = PathBuf::from("test.rs");
 
-            let info = parser.extract_command_info(&func, &path, &mut type_resolver);
+            let info = parser.extract_command_info(
+                &func,
+                &path,
+                &FileImports::default(),
+                &mut type_resolver,
+            );
 
             assert!(info.is_some());
             let info = info.unwrap();
@@ -690,7 +1415,12 @@ mod tests {
             };
             let path = PathBuf::from("test.rs");
 
-            let info = parser.extract_command_info(&func, &path, &mut type_resolver);
+            let info = parser.extract_command_info(
+                &func,
+                &path,
+                &FileImports::default(),
+                &mut type_resolver,
+            );
 
             assert!(info.is_some());
             let info = info.unwrap();
@@ -710,11 +1440,182 @@ mod tests {
             };
             let path = PathBuf::from("test.rs");
 
-            let info = parser.extract_command_info(&func, &path, &mut type_resolver);
+            let info = parser.extract_command_info(
+                &func,
+                &path,
+                &FileImports::default(),
+                &mut type_resolver,
+            );
 
             assert!(info.is_some());
             let info = info.unwrap();
             assert_eq!(info.return_type, "()");
         }
+
+        #[test]
+        fn test_non_serializable_parameters_are_excluded() {
+            let parser = CommandParser::new();
+            let mut type_resolver = TypeResolver::new();
+            let func: ItemFn = parse_quote! {
+                #[tauri::command]
+                fn upload(name: String, file: std::fs::File, state: &mut State<AppState>) {}
+            };
+            let path = PathBuf::from("test.rs");
+
+            let info = parser
+                .extract_command_info(&func, &path, &FileImports::default(), &mut type_resolver)
+                .unwrap();
+
+            assert_eq!(info.parameters.len(), 1);
+            assert_eq!(info.parameters[0].name, "name");
+            let excluded: Vec<_> = info
+                .injected_parameters
+                .iter()
+                .map(|param| (param.name.as_str(), param.position))
+                .collect();
+            assert_eq!(excluded, vec![("file", 1), ("state", 2)]);
+        }
+    }
+
+    // generic command tests (patterns from the Tauri docs)
+    mod generic_commands {
+        use super::*;
+        use std::path::PathBuf;
+
+        fn extract(func: ItemFn) -> CommandInfo {
+            let parser = CommandParser::new();
+            let mut type_resolver = TypeResolver::new();
+            parser
+                .extract_command_info(
+                    &func,
+                    &PathBuf::from("test.rs"),
+                    &FileImports::default(),
+                    &mut type_resolver,
+                )
+                .unwrap()
+        }
+
+        #[test]
+        fn test_serialize_calls_attribute() {
+            let info = extract(parse_quote! {
+                #[tauri::command]
+                #[typegen(serialize_calls)]
+                fn save_settings(value: String) {}
+            });
+            assert_eq!(info.serialize_calls, Some(CallSerialization::Command));
+
+            let info = extract(parse_quote! {
+                #[tauri::command]
+                #[cfg_attr(any(), typegen(serialize_calls = "project_id"))]
+                fn save_project(project_id: u32, name: String) {}
+            });
+            assert_eq!(
+                info.serialize_calls,
+                Some(CallSerialization::Key("project_id".to_string()))
+            );
+
+            // Unknown keys fall back to queueing per command
+            let info = extract(parse_quote! {
+                #[tauri::command]
+                #[typegen(serialize_calls = "id")]
+                fn save_project(project_id: u32) {}
+            });
+            assert_eq!(info.serialize_calls, Some(CallSerialization::Command));
+
+            let info = extract(parse_quote! {
+                #[tauri::command]
+                fn load_project(project_id: u32) {}
+            });
+            assert_eq!(info.serialize_calls, None);
+        }
+
+        #[test]
+        fn test_invalidates_attribute() {
+            let info = extract(parse_quote! {
+                #[tauri::command]
+                #[cfg_attr(any(), typegen(invalidates = "get_projects", serialize_calls))]
+                #[typegen(invalidates = "get_project")]
+                fn rename_project(project_id: u32, name: String) {}
+            });
+            assert_eq!(info.invalidates, ["get_projects", "get_project"]);
+            assert_eq!(info.serialize_calls, Some(CallSerialization::Command));
+        }
+
+        #[test]
+        fn test_runtime_bound_inline() {
+            let info = extract(parse_quote! {
+                #[tauri::command]
+                async fn open_window<R: Runtime>(
+                    app: tauri::AppHandle<R>,
+                    window: tauri::Window<R>,
+                    label: String,
+                ) -> Result<(), String> {
+                    Ok(())
+                }
+            });
+
+            assert_eq!(info.parameters.len(), 1);
+            assert_eq!(info.parameters[0].name, "label");
+            assert_eq!(info.return_type, "Result<(), String>");
+        }
+
+        #[test]
+        fn test_runtime_bound_in_where_clause() {
+            let info = extract(parse_quote! {
+                #[tauri::command]
+                fn focus<R>(webview: Webview<R>, ctx: MyContext<R>, id: u32) -> bool
+                where
+                    R: tauri::Runtime,
+                {
+                    true
+                }
+            });
+
+            assert_eq!(info.parameters.len(), 1);
+            assert_eq!(info.parameters[0].name, "id");
+            assert_eq!(
+                info.return_type_structure,
+                TypeStructure::Primitive("boolean".to_string())
+            );
+        }
+
+        #[test]
+        fn test_runtime_generic_with_state_and_lifetime() {
+            let info = extract(parse_quote! {
+                #[tauri::command]
+                async fn save<R: Runtime>(
+                    app: AppHandle<R>,
+                    state: State<'_, AppState>,
+                    data: Vec<String>,
+                ) -> Result<usize, String> {
+                    Ok(data.len())
+                }
+            });
+
+            assert_eq!(info.parameters.len(), 1);
+            assert_eq!(info.parameters[0].name, "data");
+        }
+
+        #[test]
+        fn test_unconstrained_type_param_is_unknown() {
+            let info = extract(parse_quote! {
+                #[tauri::command]
+                fn echo<T: DeserializeOwned + Serialize>(payload: T, items: Vec<T>) -> Option<T> {
+                    None
+                }
+            });
+
+            let unknown = TypeStructure::Primitive("unknown".to_string());
+            assert_eq!(info.parameters.len(), 2);
+            assert_eq!(info.parameters[0].type_structure, unknown);
+            assert_eq!(
+                info.parameters[1].type_structure,
+                TypeStructure::Array(Box::new(unknown.clone()))
+            );
+            assert_eq!(
+                info.return_type_structure,
+                TypeStructure::Optional(Box::new(unknown))
+            );
+        }
     }
 }
@@ -0,0 +1,332 @@
+use crate::interface::messages::{Locale, Message, MessageId};
+use crate::models::{ConstInfo, ConstValue};
+use quote::ToTokens;
+use std::collections::HashMap;
+use std::path::Path;
+use syn::{Attribute, BinOp, Expr, File as SynFile, Item, ItemConst, Lit, UnOp};
+
+/// Nesting limit when resolving constants that reference other constants
+const MAX_REFERENCE_DEPTH: usize = 16;
+
+/// Parser for constants marked with `#[typegen(export)]`
+#[derive(Debug)]
+pub struct ConstParser {
+    locale: Locale,
+}
+
+impl ConstParser {
+    pub fn new() -> Self {
+        Self {
+            locale: Locale::default(),
+        }
+    }
+
+    /// Language of the warnings about constants that cannot be evaluated
+    pub fn set_locale(&mut self, locale: Locale) {
+        self.locale = locale;
+    }
+
+    /// Extract exported constants from a cached AST, including inline modules.
+    ///
+    /// Initializers may reference other constants of the same file
+    /// (`const MAX_UPLOAD_SIZE: usize = 10 * MB;`). Constants whose value
+    /// cannot be evaluated are skipped with a warning.
+    pub fn extract_constants_from_ast(&self, ast: &SynFile, file_path: &Path) -> Vec<ConstInfo> {
+        let mut items = Vec::new();
+        Self::collect_const_items(&ast.items, &mut items);
+
+        let lookup: HashMap<String, &Expr> = items
+            .iter()
+            .map(|item| (item.ident.to_string(), item.expr.as_ref()))
+            .collect();
+
+        items
+            .iter()
+            .filter(|item| self.has_export_attribute(&item.attrs))
+            .filter_map(|item| {
+                let name = item.ident.to_string();
+                let line_number = item.ident.span().start().line;
+                match Self::evaluate(&item.expr, &lookup, 0) {
+                    Some(value) => Some(ConstInfo {
+                        name,
+                        rust_type: item.ty.to_token_stream().to_string().replace(' ', ""),
+                        value,
+                        file_path: file_path.to_string_lossy().to_string(),
+                        line_number,
+                    }),
+                    None => {
+                        eprintln!(
+                            "{}",
+                            Message::new(MessageId::ConstantNotEvaluated)
+                                .arg("name", &name)
+                                .arg(
+                                    "location",
+                                    format!("{}:{}", file_path.display(), line_number)
+                                )
+                                .render(self.locale)
+                        );
+                        None
+                    }
+                }
+            })
+            .collect()
+    }
+
+    /// Check for `#[typegen(export)]`, also when wrapped in `#[cfg_attr(..., typegen(export))]`
+    pub fn has_export_attribute(&self, attrs: &[Attribute]) -> bool {
+        attrs.iter().any(|attr| {
+            let tokens: String = attr
+                .meta
+                .to_token_stream()
+                .to_string()
+                .split_whitespace()
+                .collect();
+            (attr.path().is_ident("typegen") && tokens == "typegen(export)")
+                || (attr.path().is_ident("cfg_attr") && tokens.contains(",typegen(export)"))
+        })
+    }
+
+    fn collect_const_items<'a>(items: &'a [Item], out: &mut Vec<&'a ItemConst>) {
+        for item in items {
+            match item {
+                Item::Const(item_const) => out.push(item_const),
+                Item::Mod(item_mod) => {
+                    if let Some((_, nested)) = &item_mod.content {
+                        Self::collect_const_items(nested, out);
+                    }
+                }
+                _ => {}
+            }
+        }
+    }
+
+    /// Evaluate a constant initializer
+    fn evaluate(expr: &Expr, lookup: &HashMap<String, &Expr>, depth: usize) -> Option<ConstValue> {
+        if depth > MAX_REFERENCE_DEPTH {
+            return None;
+        }
+
+        match expr {
+            Expr::Lit(expr_lit) => match &expr_lit.lit {
+                Lit::Int(int) => int.base10_parse::<i128>().ok().map(ConstValue::Integer),
+                Lit::Float(float) => float.base10_parse::<f64>().ok().map(ConstValue::Float),
+                Lit::Str(s) => Some(ConstValue::String(s.value())),
+                Lit::Char(c) => Some(ConstValue::String(c.value().to_string())),
+                Lit::Bool(b) => Some(ConstValue::Bool(b.value)),
+                Lit::Byte(b) => Some(ConstValue::Integer(b.value().into())),
+                _ => None,
+            },
+            Expr::Paren(paren) => Self::evaluate(&paren.expr, lookup, depth),
+            Expr::Group(group) => Self::evaluate(&group.expr, lookup, depth),
+            Expr::Reference(reference) => Self::evaluate(&reference.expr, lookup, depth),
+            Expr::Cast(cast) => match Self::evaluate(&cast.expr, lookup, depth)? {
+                // `usize::MAX as f64`-style casts change the value's kind
+                ConstValue::Integer(i) if Self::is_float_type(&cast.ty) => {
+                    Some(ConstValue::Float(i as f64))
+                }
+                ConstValue::Float(f) if !Self::is_float_type(&cast.ty) => {
+                    Some(ConstValue::Integer(f as i128))
+                }
+                value => Some(value),
+            },
+            Expr::Array(array) => array
+                .elems
+                .iter()
+                .map(|elem| Self::evaluate(elem, lookup, depth))
+                .collect::<Option<Vec<_>>>()
+                .map(ConstValue::Array),
+            Expr::Path(path) => {
+                let ident = path.path.get_ident()?.to_string();
+                Self::evaluate(lookup.get(&ident)?, lookup, depth + 1)
+            }
+            Expr::Unary(unary) => match (&unary.op, Self::evaluate(&unary.expr, lookup, depth)?) {
+                (UnOp::Neg(_), ConstValue::Integer(i)) => Some(ConstValue::Integer(-i)),
+                (UnOp::Neg(_), ConstValue::Float(f)) => Some(ConstValue::Float(-f)),
+                (UnOp::Not(_), ConstValue::Bool(b)) => Some(ConstValue::Bool(!b)),
+                _ => None,
+            },
+            Expr::Binary(binary) => {
+                let left = Self::evaluate(&binary.left, lookup, depth)?;
+                let right = Self::evaluate(&binary.right, lookup, depth)?;
+                Self::evaluate_binary(&binary.op, left, right)
+            }
+            _ => None,
+        }
+    }
+
+    fn evaluate_binary(op: &BinOp, left: ConstValue, right: ConstValue) -> Option<ConstValue> {
+        match (left, right) {
+            (ConstValue::Integer(a), ConstValue::Integer(b)) => match op {
+                BinOp::Add(_) => a.checked_add(b),
+                BinOp::Sub(_) => a.checked_sub(b),
+                BinOp::Mul(_) => a.checked_mul(b),
+                BinOp::Div(_) => a.checked_div(b),
+                BinOp::Rem(_) => a.checked_rem(b),
+                BinOp::Shl(_) => u32::try_from(b).ok().and_then(|b| a.checked_shl(b)),
+                BinOp::Shr(_) => u32::try_from(b).ok().and_then(|b| a.checked_shr(b)),
+                BinOp::BitAnd(_) => Some(a & b),
+                BinOp::BitOr(_) => Some(a | b),
+                BinOp::BitXor(_) => Some(a ^ b),
+                _ => None,
+            }
+            .map(ConstValue::Integer),
+            (ConstValue::Float(a), ConstValue::Float(b)) => match op {
+                BinOp::Add(_) => Some(a + b),
+                BinOp::Sub(_) => Some(a - b),
+                BinOp::Mul(_) => Some(a * b),
+                BinOp::Div(_) => Some(a / b),
+                _ => None,
+            }
+            .map(ConstValue::Float),
+            (ConstValue::Bool(a), ConstValue::Bool(b)) => match op {
+                BinOp::And(_) => Some(ConstValue::Bool(a && b)),
+                BinOp::Or(_) => Some(ConstValue::Bool(a || b)),
+                _ => None,
+            },
+            _ => None,
+        }
+    }
+
+    fn is_float_type(ty: &syn::Type) -> bool {
+        matches!(ty.to_token_stream().to_string().as_str(), "f32" | "f64")
+    }
+}
+
+impl Default for ConstParser {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use syn::parse_quote;
+
+    fn extract(ast: SynFile) -> Vec<ConstInfo> {
+        ConstParser::new().extract_constants_from_ast(&ast, Path::new("src/limits.rs"))
+    }
+
+    mod export_attribute {
+        use super::*;
+
+        #[test]
+        fn test_only_marked_constants_are_exported() {
+            let constants = extract(parse_quote! {
+                #[typegen(export)]
+                pub const MAX_UPLOAD_SIZE: usize = 1024;
+                pub const INTERNAL: usize = 1;
+            });
+            assert_eq!(constants.len(), 1);
+            assert_eq!(constants[0].name, "MAX_UPLOAD_SIZE");
+            assert_eq!(constants[0].rust_type, "usize");
+            assert_eq!(constants[0].value, ConstValue::Integer(1024));
+        }
+
+        #[test]
+        fn test_cfg_attr_wrapped_attribute() {
+            let constants = extract(parse_quote! {
+                #[cfg_attr(any(), typegen(export))]
+                pub const USER_LOGIN: &str = "user-login";
+            });
+            assert_eq!(constants.len(), 1);
+            assert_eq!(
+                constants[0].value,
+                ConstValue::String("user-login".to_string())
+            );
+        }
+
+        #[test]
+        fn test_other_typegen_attributes_are_ignored() {
+            let constants = extract(parse_quote! {
+                #[typegen(skip)]
+                pub const A: u32 = 1;
+            });
+            assert!(constants.is_empty());
+        }
+
+        #[test]
+        fn test_constants_in_inline_modules() {
+            let constants = extract(parse_quote! {
+                mod limits {
+                    #[typegen(export)]
+                    pub const MAX_TAGS: u8 = 16;
+                }
+            });
+            assert_eq!(constants.len(), 1);
+            assert_eq!(constants[0].name, "MAX_TAGS");
+        }
+    }
+
+    mod evaluation {
+        use super::*;
+
+        #[test]
+        fn test_arithmetic_with_references() {
+            let constants = extract(parse_quote! {
+                const KB: usize = 1_024;
+                const MB: usize = KB * KB;
+                #[typegen(export)]
+                pub const MAX_UPLOAD_SIZE: usize = 10 * MB + (1 << 4);
+            });
+            assert_eq!(
+                constants[0].value,
+                ConstValue::Integer(10 * 1024 * 1024 + 16)
+            );
+        }
+
+        #[test]
+        fn test_literals() {
+            let constants = extract(parse_quote! {
+                #[typegen(export)]
+                pub const RATIO: f64 = -0.5;
+                #[typegen(export)]
+                pub const ENABLED: bool = !false;
+                #[typegen(export)]
+                pub const SEPARATOR: char = '/';
+                #[typegen(export)]
+                pub const TIMEOUT_MS: u64 = 30u64 as u64;
+            });
+            let values: Vec<&ConstValue> = constants.iter().map(|c| &c.value).collect();
+            assert_eq!(
+                values,
+                vec![
+                    &ConstValue::Float(-0.5),
+                    &ConstValue::Bool(true),
+                    &ConstValue::String("/".to_string()),
+                    &ConstValue::Integer(30),
+                ]
+            );
+        }
+
+        #[test]
+        fn test_arrays() {
+            let constants = extract(parse_quote! {
+                const LOGIN: &str = "user-login";
+                #[typegen(export)]
+                pub const AUTH_EVENTS: &[&str] = &[LOGIN, "user-logout"];
+            });
+            assert_eq!(constants[0].rust_type, "&[&str]");
+            assert_eq!(
+                constants[0].value,
+                ConstValue::Array(vec![
+                    ConstValue::String("user-login".to_string()),
+                    ConstValue::String("user-logout".to_string()),
+                ])
+            );
+        }
+
+        #[test]
+        fn test_unsupported_initializers_are_skipped() {
+            let constants = extract(parse_quote! {
+                #[typegen(export)]
+                pub const DEFAULT_NAME: String = String::new();
+                #[typegen(export)]
+                pub const OVERFLOW: u8 = i128::MAX;
+                #[typegen(export)]
+                pub const CYCLE: u32 = CYCLE + 1;
+            });
+            assert!(constants.is_empty());
+        }
+    }
+}
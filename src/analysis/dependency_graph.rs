@@ -1,16 +1,65 @@
-use crate::models::{CommandInfo, StructInfo};
-use std::collections::{HashMap, HashSet};
+//! Dependency graph of the types found by the analysis.
+//!
+//! The graph records where each type is defined, the types its fields reference and
+//! the parsed definition of each type resolved for the commands. It can be queried
+//! without generating anything, e.g. by codegen tools needing the types in dependency
+//! order, and serializes to JSON with sorted keys.
+//!
+//! ```rust
+//! use tauri_typegen::api;
+//!
+//! let graph = api::dependency_graph_of_source(
+//!     "#[derive(serde::Serialize)] pub struct Post { pub author: User }
+//!      #[derive(serde::Serialize)] pub struct User { pub name: String }
+//!      #[tauri::command] fn get_post() -> Post { todo!() }",
+//! )?;
+//! assert_eq!(graph.ordered_types(), ["User", "Post"]);
+//! assert!(graph.dependents_of("User").contains("Post"));
+//! assert!(graph.cycles().is_empty());
+//! # Ok::<(), Box<dyn std::error::Error>>(())
+//! ```
+
+use crate::interface::messages::{Locale, Message, MessageId};
+use crate::models::{CommandInfo, StructInfo, TypeStructure};
+use serde::{Deserialize, Serialize, Serializer};
+use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet};
 use std::path::PathBuf;
 
 /// Dependency graph for lazy type resolution
-#[derive(Debug, Default)]
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
 pub struct TypeDependencyGraph {
     /// Maps type name to the files where it's defined
+    #[serde(serialize_with = "sorted_map")]
     pub type_definitions: HashMap<String, PathBuf>,
     /// Maps type name to types it depends on
+    #[serde(serialize_with = "sorted_dependencies")]
     pub dependencies: HashMap<String, HashSet<String>>,
     /// Maps type name to its resolved StructInfo
+    #[serde(serialize_with = "sorted_map")]
     pub resolved_types: HashMap<String, StructInfo>,
+    /// Language of the warnings printed while sorting and filtering the graph
+    #[serde(skip)]
+    pub locale: Locale,
+}
+
+/// Serialize a map ordered by key, so equal graphs serialize the same
+fn sorted_map<V: Serialize, S: Serializer>(
+    map: &HashMap<String, V>,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    map.iter().collect::<BTreeMap<_, _>>().serialize(serializer)
+}
+
+fn sorted_dependencies<S: Serializer>(
+    dependencies: &HashMap<String, HashSet<String>>,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    dependencies
+        .iter()
+        .map(|(name, deps)| (name, deps.iter().collect::<BTreeSet<_>>()))
+        .collect::<BTreeMap<_, _>>()
+        .serialize(serializer)
 }
 
 impl TypeDependencyGraph {
@@ -51,6 +100,141 @@ impl TypeDependencyGraph {
         self.dependencies.get(type_name)
     }
 
+    /// `type_name` and every type depending on it, directly or transitively
+    pub fn dependents(&self, type_name: &str) -> BTreeSet<String> {
+        let mut dependents = BTreeSet::from([type_name.to_string()]);
+        let mut frontier = vec![type_name.to_string()];
+        while let Some(current) = frontier.pop() {
+            for (dependent, dependencies) in &self.dependencies {
+                if dependencies.contains(&current) && dependents.insert(dependent.clone()) {
+                    frontier.push(dependent.clone());
+                }
+            }
+        }
+        dependents
+    }
+
+    /// Types `type_name` references directly, ordered by name
+    pub fn dependencies_of(&self, type_name: &str) -> BTreeSet<String> {
+        self.dependencies
+            .get(type_name)
+            .map(|deps| deps.iter().cloned().collect())
+            .unwrap_or_default()
+    }
+
+    /// Types referencing `type_name` directly, ordered by name (see [`Self::dependents`]
+    /// for the transitive ones)
+    pub fn dependents_of(&self, type_name: &str) -> BTreeSet<String> {
+        self.dependencies
+            .iter()
+            .filter(|(_, deps)| deps.contains(type_name))
+            .map(|(dependent, _)| dependent.clone())
+            .collect()
+    }
+
+    /// The resolved types with every type after the types it references, ties ordered
+    /// by name. Types in a cycle follow their first reference into it.
+    pub fn ordered_types(&self) -> Vec<String> {
+        let mut sorted = Vec::new();
+        let mut visited = HashSet::new();
+        let names: BTreeSet<&String> = self.resolved_types.keys().collect();
+        for name in &names {
+            self.ordered_visit(name, &names, &mut sorted, &mut visited);
+        }
+        sorted
+    }
+
+    fn ordered_visit(
+        &self,
+        type_name: &str,
+        names: &BTreeSet<&String>,
+        sorted: &mut Vec<String>,
+        visited: &mut HashSet<String>,
+    ) {
+        if !visited.insert(type_name.to_string()) {
+            return;
+        }
+        for dep in self.dependencies_of(type_name) {
+            if names.contains(&dep) {
+                self.ordered_visit(&dep, names, sorted, visited);
+            }
+        }
+        sorted.push(type_name.to_string());
+    }
+
+    /// Groups of types referencing each other (recursive types), each ordered by name,
+    /// ordered by their first type
+    pub fn cycles(&self) -> Vec<Vec<String>> {
+        // Tarjan's strongly connected components
+        struct Search<'a> {
+            graph: &'a TypeDependencyGraph,
+            index: HashMap<String, usize>,
+            low_link: HashMap<String, usize>,
+            stack: Vec<String>,
+            on_stack: HashSet<String>,
+            cycles: Vec<Vec<String>>,
+        }
+
+        impl Search<'_> {
+            fn connect(&mut self, type_name: &str) {
+                let index = self.index.len();
+                self.index.insert(type_name.to_string(), index);
+                self.low_link.insert(type_name.to_string(), index);
+                self.stack.push(type_name.to_string());
+                self.on_stack.insert(type_name.to_string());
+
+                for dep in self.graph.dependencies_of(type_name) {
+                    if !self.index.contains_key(&dep) {
+                        self.connect(&dep);
+                        let low = self.low_link[&dep].min(self.low_link[type_name]);
+                        self.low_link.insert(type_name.to_string(), low);
+                    } else if self.on_stack.contains(&dep) {
+                        let low = self.index[&dep].min(self.low_link[type_name]);
+                        self.low_link.insert(type_name.to_string(), low);
+                    }
+                }
+
+                if self.low_link[type_name] == self.index[type_name] {
+                    let mut component = Vec::new();
+                    while let Some(member) = self.stack.pop() {
+                        self.on_stack.remove(&member);
+                        let done = member == type_name;
+                        component.push(member);
+                        if done {
+                            break;
+                        }
+                    }
+                    let self_reference = self
+                        .graph
+                        .dependencies
+                        .get(type_name)
+                        .is_some_and(|deps| deps.contains(type_name));
+                    if component.len() > 1 || self_reference {
+                        component.sort();
+                        self.cycles.push(component);
+                    }
+                }
+            }
+        }
+
+        let mut search = Search {
+            graph: self,
+            index: HashMap::new(),
+            low_link: HashMap::new(),
+            stack: Vec::new(),
+            on_stack: HashSet::new(),
+            cycles: Vec::new(),
+        };
+        let names: BTreeSet<&String> = self.dependencies.keys().collect();
+        for name in names {
+            if !search.index.contains_key(name.as_str()) {
+                search.connect(name);
+            }
+        }
+        search.cycles.sort();
+        search.cycles
+    }
+
     /// Check if a type is defined in the graph
     pub fn has_type_definition(&self, type_name: &str) -> bool {
         self.type_definitions.contains_key(type_name)
@@ -87,8 +271,10 @@ impl TypeDependencyGraph {
         // Check for cycles
         if visiting.contains(type_name) {
             eprintln!(
-                "Warning: Circular dependency detected involving type: {}",
-                type_name
+                "{}",
+                Message::new(MessageId::CircularDependency)
+                    .arg("type", type_name)
+                    .render(self.locale)
             );
             return;
         }
@@ -113,13 +299,43 @@ impl TypeDependencyGraph {
 
     /// Build visualization of the dependency graph
     pub fn visualize_dependencies(&self, entry_commands: &[crate::models::CommandInfo]) -> String {
+        self.visualize_dependencies_filtered(entry_commands, &VizFilter::default())
+    }
+
+    /// Build visualization of the part of the dependency graph selected by `filter`
+    pub fn visualize_dependencies_filtered(
+        &self,
+        entry_commands: &[CommandInfo],
+        filter: &VizFilter,
+    ) -> String {
+        let included = self.filter_nodes(entry_commands, filter);
+        let is_included = |name: &str| included.as_ref().map_or(true, |nodes| nodes.contains(name));
+        let entry_commands: Vec<&CommandInfo> = entry_commands
+            .iter()
+            .filter(|cmd| is_included(&cmd.name))
+            .collect();
+        let mut type_names: Vec<&String> = self
+            .resolved_types
+            .keys()
+            .filter(|name| is_included(name))
+            .collect();
+        type_names.sort();
+
         let mut output = String::new();
         output.push_str("🌐 Type Dependency Graph\n");
         output.push_str("======================\n\n");
 
+        if let Some(focus) = &filter.focus {
+            output.push_str(&format!("🔍 Focused on: {}", focus));
+            if let Some(depth) = filter.depth {
+                output.push_str(&format!(" (depth {})", depth));
+            }
+            output.push_str("\n\n");
+        }
+
         // Show command entry points
         output.push_str("📋 Command Entry Points:\n");
-        for cmd in entry_commands {
+        for cmd in &entry_commands {
             output.push_str(&format!(
                 "• {} ({}:{})\n",
                 cmd.name, cmd.file_path, cmd.line_number
@@ -135,7 +351,8 @@ impl TypeDependencyGraph {
         }
 
         output.push_str("\n🏗️  Discovered Types:\n");
-        for (type_name, struct_info) in &self.resolved_types {
+        for type_name in &type_names {
+            let struct_info = &self.resolved_types[*type_name];
             let type_kind = if struct_info.is_enum {
                 "enum"
             } else {
@@ -150,9 +367,10 @@ impl TypeDependencyGraph {
             ));
 
             // Show dependencies
-            if let Some(deps) = self.dependencies.get(type_name) {
+            if let Some(deps) = self.dependencies.get(*type_name) {
                 if !deps.is_empty() {
-                    let deps_list: Vec<String> = deps.iter().cloned().collect();
+                    let mut deps_list: Vec<String> = deps.iter().cloned().collect();
+                    deps_list.sort();
                     output.push_str(&format!("  └─ depends on: {}\n", deps_list.join(", ")));
                 }
             }
@@ -160,14 +378,14 @@ impl TypeDependencyGraph {
 
         // Show dependency chains
         output.push_str("\n🔗 Dependency Chains:\n");
-        for type_name in self.resolved_types.keys() {
+        for type_name in &type_names {
             self.show_dependency_chain(type_name, &mut output, 0);
         }
 
         output.push_str(&format!(
             "\n📊 Summary:\n• {} commands analyzed\n• {} types discovered\n• {} files with type definitions\n",
             entry_commands.len(),
-            self.resolved_types.len(),
+            type_names.len(),
             self.type_definitions.len()
         ));
 
@@ -191,55 +409,344 @@ impl TypeDependencyGraph {
 
     /// Generate a DOT graph representation of the dependency graph
     pub fn generate_dot_graph(&self, commands: &[CommandInfo]) -> String {
+        self.generate_dot_graph_filtered(commands, &VizFilter::default())
+    }
+
+    /// Generate a DOT graph of the part of the dependency graph selected by `filter`.
+    ///
+    /// Nodes are clustered by the file defining them.
+    pub fn generate_dot_graph_filtered(
+        &self,
+        commands: &[CommandInfo],
+        filter: &VizFilter,
+    ) -> String {
+        let included = self.filter_nodes(commands, filter);
+        let is_included = |name: &str| included.as_ref().map_or(true, |nodes| nodes.contains(name));
+
         let mut output = String::new();
         output.push_str("digraph Dependencies {\n");
         output.push_str("  rankdir=LR;\n");
         output.push_str("  node [shape=box];\n");
+
+        // Group command and type nodes by the file defining them
+        let mut clusters: BTreeMap<String, Vec<String>> = BTreeMap::new();
+        for command in commands.iter().filter(|cmd| is_included(&cmd.name)) {
+            clusters
+                .entry(command.file_path.clone())
+                .or_default()
+                .push(format!(
+                    "\"{}\" [color=blue, style=filled, fillcolor=lightblue];",
+                    command.name
+                ));
+        }
+        let mut type_names: Vec<&String> = self
+            .resolved_types
+            .keys()
+            .filter(|name| is_included(name))
+            .collect();
+        type_names.sort();
+        for type_name in type_names {
+            let file = self
+                .type_definitions
+                .get(type_name)
+                .map(|path| path.to_string_lossy().to_string())
+                .unwrap_or_else(|| self.resolved_types[type_name].file_path.clone());
+            clusters
+                .entry(file)
+                .or_default()
+                .push(format!("\"{}\" [color=green];", type_name));
+        }
+
+        for (index, (file, nodes)) in clusters.iter().enumerate() {
+            output.push('\n');
+            output.push_str(&format!("  subgraph \"cluster_{}\" {{\n", index));
+            output.push_str(&format!("    label=\"{}\";\n", file.replace('"', "\\\"")));
+            output.push_str("    style=dashed;\n");
+            for node in nodes {
+                output.push_str(&format!("    {}\n", node));
+            }
+            output.push_str("  }\n");
+        }
         output.push('\n');
 
-        // Add command nodes
-        for command in commands {
-            output.push_str(&format!(
-                "  \"{}\" [color=blue, style=filled, fillcolor=lightblue];\n",
-                command.name
-            ));
+        // Add edges between included nodes
+        for (from, to, label) in self.edges(commands) {
+            if !is_included(&from) || !is_included(&to) {
+                continue;
+            }
+            match label {
+                Some(label) => output.push_str(&format!(
+                    "  \"{}\" -> \"{}\" [label=\"{}\"];\n",
+                    from, to, label
+                )),
+                None => output.push_str(&format!("  \"{}\" -> \"{}\";\n", from, to)),
+            }
+        }
+
+        output.push_str("}\n");
+        output
+    }
+
+    /// Generate a standalone HTML page with an interactive view of the part of the
+    /// dependency graph selected by `filter`.
+    ///
+    /// The page embeds the graph as JSON together with a small force-directed viewer,
+    /// so it can be opened in a browser without any other files.
+    pub fn generate_html_graph_filtered(
+        &self,
+        commands: &[CommandInfo],
+        filter: &VizFilter,
+    ) -> String {
+        let included = self.filter_nodes(commands, filter);
+        let is_included = |name: &str| included.as_ref().map_or(true, |nodes| nodes.contains(name));
+
+        let mut nodes = Vec::new();
+        for command in commands.iter().filter(|cmd| is_included(&cmd.name)) {
+            nodes.push(serde_json::json!({
+                "id": command.name,
+                "kind": "command",
+                "location": format!("{}:{}", command.file_path, command.line_number),
+                "members": command
+                    .parameters
+                    .iter()
+                    .map(|param| format!("{}: {}", param.name, param.rust_type))
+                    .collect::<Vec<_>>(),
+                "returns": command.return_type,
+            }));
         }
+        let mut type_names: Vec<&String> = self
+            .resolved_types
+            .keys()
+            .filter(|name| is_included(name))
+            .collect();
+        type_names.sort();
+        for type_name in type_names {
+            let struct_info = &self.resolved_types[type_name];
+            let members: Vec<String> = struct_info
+                .fields
+                .iter()
+                .map(|field| {
+                    if struct_info.is_enum {
+                        field.name.clone()
+                    } else {
+                        format!("{}: {}", field.name, field.rust_type)
+                    }
+                })
+                .collect();
+            nodes.push(serde_json::json!({
+                "id": type_name,
+                "kind": "type",
+                "typeKind": if struct_info.is_enum { "enum" } else { "struct" },
+                "location": struct_info.file_path,
+                "members": members,
+            }));
+        }
+
+        let edges: Vec<serde_json::Value> = self
+            .edges(commands)
+            .into_iter()
+            .filter(|(from, to, _)| is_included(from) && is_included(to))
+            .map(|(from, to, label)| serde_json::json!({ "from": from, "to": to, "label": label }))
+            .collect();
+
+        let data = serde_json::json!({
+            "focus": filter.focus,
+            "nodes": nodes,
+            "edges": edges,
+        });
+        // Keep `</script>` inside names or types from closing the embedding script element
+        let data = data.to_string().replace("</", "<\\/");
+
+        HTML_VIEWER.replace("__GRAPH_DATA__", &data)
+    }
 
-        // Add type nodes
-        for type_name in self.resolved_types.keys() {
-            output.push_str(&format!("  \"{}\" [color=green];\n", type_name));
+    /// Render the part of the dependency graph selected by `filter` in the given format
+    pub fn render(
+        &self,
+        commands: &[CommandInfo],
+        format: VizFormat,
+        filter: &VizFilter,
+    ) -> String {
+        match format {
+            VizFormat::Text => self.visualize_dependencies_filtered(commands, filter),
+            VizFormat::Dot => self.generate_dot_graph_filtered(commands, filter),
+            VizFormat::Html => self.generate_html_graph_filtered(commands, filter),
         }
+    }
+
+    /// Edges from commands to the resolved types they use (labeled `param`/`return`)
+    /// and between dependent types, in a stable order
+    fn edges(&self, commands: &[CommandInfo]) -> Vec<(String, String, Option<&'static str>)> {
+        let mut edges = Vec::new();
 
-        // Add edges from commands to their parameter/return types
         for command in commands {
+            let mut params = BTreeSet::new();
             for param in &command.parameters {
-                if self.resolved_types.contains_key(&param.rust_type) {
-                    output.push_str(&format!(
-                        "  \"{}\" -> \"{}\" [label=\"param\"];\n",
-                        command.name, param.rust_type
-                    ));
-                }
+                Self::referenced_types(&param.type_structure, &mut params);
             }
-            if self.resolved_types.contains_key(&command.return_type) {
-                output.push_str(&format!(
-                    "  \"{}\" -> \"{}\" [label=\"return\"];\n",
-                    command.name, command.return_type
-                ));
+            let mut returns = BTreeSet::new();
+            Self::referenced_types(&command.return_type_structure, &mut returns);
+
+            for (types, label) in [(params, "param"), (returns, "return")] {
+                edges.extend(
+                    types
+                        .into_iter()
+                        .filter(|name| self.resolved_types.contains_key(name))
+                        .map(|name| (command.name.clone(), name, Some(label))),
+                );
             }
         }
 
-        // Add type dependency edges
-        for (type_name, deps) in &self.dependencies {
-            for dep in deps {
-                output.push_str(&format!("  \"{}\" -> \"{}\";\n", type_name, dep));
+        let mut type_edges: Vec<(String, String, Option<&'static str>)> = self
+            .dependencies
+            .iter()
+            .flat_map(|(type_name, deps)| {
+                deps.iter()
+                    .map(move |dep| (type_name.clone(), dep.clone(), None))
+            })
+            .collect();
+        type_edges.sort();
+        edges.extend(type_edges);
+
+        edges
+    }
+
+    /// Collect the custom type names referenced by a type structure
+    fn referenced_types(structure: &TypeStructure, names: &mut BTreeSet<String>) {
+        match structure {
+            TypeStructure::Custom(name) => {
+                names.insert(name.clone());
+            }
+            TypeStructure::Array(inner)
+            | TypeStructure::Set(inner)
+            | TypeStructure::Optional(inner)
+            | TypeStructure::Result(inner) => Self::referenced_types(inner, names),
+            TypeStructure::Map { key, value } => {
+                Self::referenced_types(key, names);
+                Self::referenced_types(value, names);
             }
+            TypeStructure::Tuple(types) | TypeStructure::Union(types) => {
+                for inner in types {
+                    Self::referenced_types(inner, names);
+                }
+            }
+            TypeStructure::Primitive(_) => {}
+        }
+    }
+
+    /// Select the commands and types shown by a visualization.
+    ///
+    /// Returns `None` when the whole graph is shown. With a focus, nodes within
+    /// `depth` edges of the focused command or type are kept, following edges in
+    /// both directions. With only a depth, types are kept up to `depth` edges
+    /// away from the commands.
+    fn filter_nodes(
+        &self,
+        commands: &[CommandInfo],
+        filter: &VizFilter,
+    ) -> Option<HashSet<String>> {
+        if filter.focus.is_none() && filter.depth.is_none() {
+            return None;
         }
 
-        output.push_str("}\n");
-        output
+        let edges = self.edges(commands);
+        let starts: Vec<String> = match &filter.focus {
+            Some(focus) => {
+                let known = commands.iter().any(|cmd| &cmd.name == focus)
+                    || self.resolved_types.contains_key(focus);
+                if !known {
+                    eprintln!(
+                        "{}",
+                        Message::new(MessageId::UnknownVizFocus)
+                            .arg("focus", focus)
+                            .render(self.locale)
+                    );
+                    return None;
+                }
+                vec![focus.clone()]
+            }
+            None => commands.iter().map(|cmd| cmd.name.clone()).collect(),
+        };
+        let bidirectional = filter.focus.is_some();
+
+        let mut included: HashSet<String> = starts.iter().cloned().collect();
+        let mut frontier = starts;
+        let mut depth = 0;
+        while !frontier.is_empty() && filter.depth.map_or(true, |max| depth < max) {
+            let mut next = Vec::new();
+            for node in &frontier {
+                for (from, to, _) in &edges {
+                    let neighbor = if from == node {
+                        to
+                    } else if bidirectional && to == node {
+                        from
+                    } else {
+                        continue;
+                    };
+                    if included.insert(neighbor.clone()) {
+                        next.push(neighbor.clone());
+                    }
+                }
+            }
+            frontier = next;
+            depth += 1;
+        }
+
+        Some(included)
+    }
+}
+
+/// Output format of a dependency graph visualization
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum VizFormat {
+    /// Indented text listing (`dependency-graph.txt`)
+    Text,
+    /// Graphviz DOT graph (`dependency-graph.dot`)
+    Dot,
+    /// Standalone interactive HTML page (`dependency-graph.html`)
+    Html,
+}
+
+impl VizFormat {
+    /// Formats written when no format is configured
+    pub const DEFAULT: &'static [VizFormat] = &[VizFormat::Text, VizFormat::Dot];
+
+    /// All supported formats
+    pub const ALL: &'static [VizFormat] = &[VizFormat::Text, VizFormat::Dot, VizFormat::Html];
+
+    /// Parse the formats selected by a config value ("text", "dot", "html" or "all")
+    pub fn parse(value: &str) -> Option<Vec<Self>> {
+        match value.to_ascii_lowercase().as_str() {
+            "text" | "txt" => Some(vec![Self::Text]),
+            "dot" => Some(vec![Self::Dot]),
+            "html" => Some(vec![Self::Html]),
+            "all" => Some(Self::ALL.to_vec()),
+            _ => None,
+        }
+    }
+
+    /// Name of the file the visualization is written to
+    pub fn file_name(&self) -> &'static str {
+        match self {
+            Self::Text => "dependency-graph.txt",
+            Self::Dot => "dependency-graph.dot",
+            Self::Html => "dependency-graph.html",
+        }
     }
 }
 
+/// Interactive viewer page; `__GRAPH_DATA__` is replaced with the graph as JSON
+const HTML_VIEWER: &str = include_str!("dependency_graph.html");
+
+/// Selection of the dependency graph shown by visualizations
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct VizFilter {
+    /// Command or type whose neighborhood is shown
+    pub focus: Option<String>,
+    /// Maximum number of edges between shown nodes and the focus (or the commands)
+    pub depth: Option<usize>,
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -251,6 +758,13 @@ mod tests {
             file_path: file.to_string(),
             is_enum: false,
             serde_rename_all: None,
+            is_newtype: false,
+            serde_tag: None,
+            serde_content: None,
+            serde_untagged: false,
+            line_number: 0,
+            is_progress: false,
+            serde_deny_unknown_fields: false,
         }
     }
 
@@ -515,6 +1029,70 @@ mod tests {
         }
     }
 
+    mod queries {
+        use super::*;
+
+        /// Post -> User -> Team -> User, Comment -> Comment, Tag alone
+        fn graph() -> TypeDependencyGraph {
+            let mut graph = TypeDependencyGraph::new();
+            for name in ["Post", "User", "Team", "Comment", "Tag"] {
+                graph.add_resolved_type(name.to_string(), create_test_struct(name, "types.rs"));
+            }
+            graph.add_dependency("Post".to_string(), "User".to_string());
+            graph.add_dependency("Post".to_string(), "Comment".to_string());
+            graph.add_dependency("User".to_string(), "Team".to_string());
+            graph.add_dependency("Team".to_string(), "User".to_string());
+            graph.add_dependency("Comment".to_string(), "Comment".to_string());
+            graph
+        }
+
+        #[test]
+        fn test_direct_dependencies_and_dependents() {
+            let graph = graph();
+            assert_eq!(
+                graph.dependencies_of("Post"),
+                BTreeSet::from(["Comment".to_string(), "User".to_string()])
+            );
+            assert_eq!(
+                graph.dependents_of("User"),
+                BTreeSet::from(["Post".to_string(), "Team".to_string()])
+            );
+            assert!(graph.dependencies_of("Tag").is_empty());
+            assert!(graph.dependents_of("Unknown").is_empty());
+        }
+
+        #[test]
+        fn test_ordered_types() {
+            assert_eq!(
+                graph().ordered_types(),
+                ["Comment", "Team", "User", "Post", "Tag"]
+            );
+        }
+
+        #[test]
+        fn test_cycles() {
+            assert_eq!(
+                graph().cycles(),
+                vec![
+                    vec!["Comment".to_string()],
+                    vec!["Team".to_string(), "User".to_string()]
+                ]
+            );
+        }
+
+        #[test]
+        fn test_serialization_is_sorted_and_round_trips() {
+            let graph = graph();
+            let json = serde_json::to_string(&graph).unwrap();
+            assert_eq!(json, serde_json::to_string(&graph.clone()).unwrap());
+            assert!(json.find("\"Comment\"").unwrap() < json.find("\"Post\"").unwrap());
+
+            let parsed: TypeDependencyGraph = serde_json::from_str(&json).unwrap();
+            assert_eq!(parsed.ordered_types(), graph.ordered_types());
+            assert_eq!(parsed.cycles(), graph.cycles());
+        }
+    }
+
     // Integration tests
     #[test]
     fn test_full_graph_workflow() {
@@ -547,4 +1125,193 @@ mod tests {
         let sorted = graph.topological_sort_types(&types);
         assert_eq!(sorted, vec!["User", "Post"]);
     }
+
+    mod visualization_filters {
+        use super::*;
+        use crate::analysis::type_resolver::TypeResolver;
+        use crate::models::ParameterInfo;
+
+        fn command(name: &str, param_type: Option<&str>, return_type: &str) -> CommandInfo {
+            let parameters = param_type
+                .map(|rust_type| ParameterInfo {
+                    name: "input".to_string(),
+                    rust_type: rust_type.to_string(),
+                    is_optional: false,
+                    type_structure: TypeResolver::new().parse_type_structure(rust_type),
+                    serde_rename: None,
+                })
+                .into_iter()
+                .collect();
+            CommandInfo::new_for_test(
+                name,
+                "commands.rs",
+                1,
+                parameters,
+                return_type,
+                false,
+                vec![],
+            )
+        }
+
+        fn sample() -> (TypeDependencyGraph, Vec<CommandInfo>) {
+            let mut graph = TypeDependencyGraph::new();
+            for (name, file) in [
+                ("FeedQuery", "feed.rs"),
+                ("Post", "feed.rs"),
+                ("User", "user.rs"),
+                ("Profile", "user.rs"),
+                ("Settings", "settings.rs"),
+            ] {
+                graph.add_type_definition(name.to_string(), PathBuf::from(file));
+                graph.add_resolved_type(name.to_string(), create_test_struct(name, file));
+            }
+            graph.add_dependency("Post".to_string(), "User".to_string());
+            graph.add_dependency("User".to_string(), "Profile".to_string());
+
+            let commands = vec![
+                command("get_feed", Some("FeedQuery"), "Vec<Post>"),
+                command("save_settings", Some("Settings"), "()"),
+            ];
+            (graph, commands)
+        }
+
+        fn nodes(
+            graph: &TypeDependencyGraph,
+            commands: &[CommandInfo],
+            filter: VizFilter,
+        ) -> Vec<String> {
+            let mut nodes: Vec<String> = graph
+                .filter_nodes(commands, &filter)
+                .expect("filter should select nodes")
+                .into_iter()
+                .collect();
+            nodes.sort();
+            nodes
+        }
+
+        #[test]
+        fn test_no_filter_shows_everything() {
+            let (graph, commands) = sample();
+            assert!(graph
+                .filter_nodes(&commands, &VizFilter::default())
+                .is_none());
+        }
+
+        #[test]
+        fn test_focus_on_type_follows_both_directions() {
+            let (graph, commands) = sample();
+            let filter = VizFilter {
+                focus: Some("User".to_string()),
+                depth: Some(1),
+            };
+            assert_eq!(
+                nodes(&graph, &commands, filter),
+                vec!["Post", "Profile", "User"]
+            );
+        }
+
+        #[test]
+        fn test_focus_on_command_without_depth() {
+            let (graph, commands) = sample();
+            let filter = VizFilter {
+                focus: Some("get_feed".to_string()),
+                depth: None,
+            };
+            assert_eq!(
+                nodes(&graph, &commands, filter),
+                vec!["FeedQuery", "Post", "Profile", "User", "get_feed"]
+            );
+        }
+
+        #[test]
+        fn test_depth_without_focus_starts_at_commands() {
+            let (graph, commands) = sample();
+            let filter = VizFilter {
+                focus: None,
+                depth: Some(1),
+            };
+            assert_eq!(
+                nodes(&graph, &commands, filter),
+                vec!["FeedQuery", "Post", "Settings", "get_feed", "save_settings"]
+            );
+        }
+
+        #[test]
+        fn test_unknown_focus_shows_everything() {
+            let (graph, commands) = sample();
+            let filter = VizFilter {
+                focus: Some("Missing".to_string()),
+                depth: Some(2),
+            };
+            assert!(graph.filter_nodes(&commands, &filter).is_none());
+        }
+
+        #[test]
+        fn test_dot_graph_clusters_nodes_by_file() {
+            let (graph, commands) = sample();
+            let dot = graph.generate_dot_graph(&commands);
+
+            assert!(dot.contains("subgraph \"cluster_0\""));
+            assert!(dot.contains("label=\"user.rs\";"));
+            assert!(dot.contains("\"get_feed\" -> \"Post\" [label=\"return\"];"));
+            assert!(dot.contains("\"get_feed\" -> \"FeedQuery\" [label=\"param\"];"));
+            assert!(dot.contains("\"Post\" -> \"User\";"));
+            assert_eq!(dot, graph.generate_dot_graph(&commands));
+        }
+
+        #[test]
+        fn test_filtered_outputs_omit_pruned_nodes() {
+            let (graph, commands) = sample();
+            let filter = VizFilter {
+                focus: Some("Profile".to_string()),
+                depth: Some(1),
+            };
+
+            let dot = graph.generate_dot_graph_filtered(&commands, &filter);
+            assert!(dot.contains("\"User\" -> \"Profile\";"));
+            assert!(!dot.contains("Post"));
+            assert!(!dot.contains("settings.rs"));
+
+            let text = graph.visualize_dependencies_filtered(&commands, &filter);
+            assert!(text.contains("Focused on: Profile (depth 1)"));
+            assert!(text.contains("• User (struct)"));
+            assert!(!text.contains("get_feed"));
+        }
+
+        #[test]
+        fn test_html_graph_embeds_filtered_graph_data() {
+            let (graph, commands) = sample();
+            let filter = VizFilter {
+                focus: Some("get_feed".to_string()),
+                depth: Some(1),
+            };
+            let html = graph.generate_html_graph_filtered(&commands, &filter);
+
+            assert!(html.starts_with("<!DOCTYPE html>"));
+            assert!(!html.contains("__GRAPH_DATA__"));
+            assert!(html.contains(r#""focus":"get_feed""#));
+            assert!(html.contains(r#"{"from":"get_feed","label":"return","to":"Post"}"#));
+            assert!(html.contains(r#""returns":"Vec<Post>""#));
+            assert!(!html.contains(r#""id":"User""#));
+        }
+
+        #[test]
+        fn test_html_graph_escapes_closing_tags() {
+            let (graph, _) = sample();
+            let commands = vec![command("inject", None, "</script>")];
+            let html = graph.generate_html_graph_filtered(&commands, &VizFilter::default());
+
+            assert_eq!(html.matches("</script>").count(), 1);
+            assert!(html.contains(r#""returns":"<\/script>""#));
+        }
+
+        #[test]
+        fn test_viz_format_parsing() {
+            assert_eq!(VizFormat::parse("html"), Some(vec![VizFormat::Html]));
+            assert_eq!(VizFormat::parse("DOT"), Some(vec![VizFormat::Dot]));
+            assert_eq!(VizFormat::parse("all"), Some(VizFormat::ALL.to_vec()));
+            assert_eq!(VizFormat::parse("svg"), None);
+            assert_eq!(VizFormat::Html.file_name(), "dependency-graph.html");
+        }
+    }
 }
@@ -0,0 +1,401 @@
+use crate::models::{CommandInfo, StructInfo};
+use quote::ToTokens;
+use std::collections::{HashMap, HashSet, VecDeque};
+use std::fmt;
+use std::path::Path;
+use syn::{Attribute, Item};
+
+/// Serde trait Tauri requires for a type crossing the IPC boundary
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum SerdeTrait {
+    /// Required for return values, errors and channel messages
+    Serialize,
+    /// Required for command parameters
+    Deserialize,
+}
+
+impl fmt::Display for SerdeTrait {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Serialize => write!(f, "Serialize"),
+            Self::Deserialize => write!(f, "Deserialize"),
+        }
+    }
+}
+
+/// Serde derives of a type definition and where it is defined
+#[derive(Debug, Clone)]
+struct TypeDerives {
+    serialize: bool,
+    deserialize: bool,
+    file_path: String,
+    line_number: usize,
+}
+
+/// A type used by a command that lacks a serde derive Tauri requires
+#[derive(Debug, Clone, PartialEq)]
+pub struct DeriveDiagnostic {
+    pub type_name: String,
+    pub missing: SerdeTrait,
+    /// Source location (`file:line`) of the type definition
+    pub type_location: String,
+    pub command_name: String,
+    /// Source location (`file:line`) of the command
+    pub command_location: String,
+}
+
+impl fmt::Display for DeriveDiagnostic {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let usage = match self.missing {
+            SerdeTrait::Serialize => "returned by",
+            SerdeTrait::Deserialize => "a parameter of",
+        };
+        write!(
+            f,
+            "{} ({}) does not derive {} but is {} command {} ({})",
+            self.type_name,
+            self.type_location,
+            self.missing,
+            usage,
+            self.command_name,
+            self.command_location
+        )
+    }
+}
+
+/// Checks that types used by commands derive the serde traits Tauri requires.
+///
+/// Tauri rejects such commands at compile time, while the generator would
+/// otherwise produce bindings for them without complaint.
+#[derive(Debug, Default)]
+pub struct DeriveChecker {
+    definitions: HashMap<String, TypeDerives>,
+}
+
+impl DeriveChecker {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Record the serde derives of a struct or enum definition
+    pub fn index_item(&mut self, item: &Item, file_path: &Path) {
+        let (ident, attrs) = match item {
+            Item::Struct(item_struct) => (&item_struct.ident, &item_struct.attrs),
+            Item::Enum(item_enum) => (&item_enum.ident, &item_enum.attrs),
+            _ => return,
+        };
+
+        let derives = Self::derived_traits(attrs);
+        self.definitions.insert(
+            ident.to_string(),
+            TypeDerives {
+                serialize: derives.contains("Serialize"),
+                deserialize: derives.contains("Deserialize"),
+                file_path: file_path.to_string_lossy().to_string(),
+                line_number: ident.span().start().line,
+            },
+        );
+    }
+
+    /// Whether the definition of `type_name` derives `serde_trait`, `None` for types not
+    /// indexed
+    pub fn derives(&self, type_name: &str, serde_trait: SerdeTrait) -> Option<bool> {
+        self.definitions
+            .get(type_name)
+            .map(|derives| match serde_trait {
+                SerdeTrait::Serialize => derives.serialize,
+                SerdeTrait::Deserialize => derives.deserialize,
+            })
+    }
+
+    /// Check the types reachable from command parameters, return types and channels.
+    ///
+    /// `type_names` extracts the type names referenced by a Rust type string.
+    /// Only types defined in the project are checked; each missing derive is
+    /// reported once, for the first command using the type.
+    pub fn check(
+        &self,
+        commands: &[CommandInfo],
+        structs: &HashMap<String, StructInfo>,
+        type_names: impl Fn(&str) -> HashSet<String>,
+    ) -> Vec<DeriveDiagnostic> {
+        let mut diagnostics = Vec::new();
+        let mut visited: HashSet<(String, SerdeTrait)> = HashSet::new();
+
+        for command in commands {
+            let mut queue: VecDeque<(String, SerdeTrait)> = VecDeque::new();
+            let mut enqueue = |rust_type: &str, required: SerdeTrait| {
+                let mut names: Vec<String> = type_names(rust_type).into_iter().collect();
+                names.sort();
+                queue.extend(names.into_iter().map(|name| (name, required)));
+            };
+
+            for param in &command.parameters {
+                enqueue(&param.rust_type, SerdeTrait::Deserialize);
+            }
+            enqueue(&command.return_type, SerdeTrait::Serialize);
+            for channel in &command.channels {
+                enqueue(&channel.message_type, SerdeTrait::Serialize);
+            }
+
+            while let Some((type_name, required)) = queue.pop_front() {
+                if !visited.insert((type_name.clone(), required)) {
+                    continue;
+                }
+                let Some(derives) = self.definitions.get(&type_name) else {
+                    continue;
+                };
+
+                let derived = match required {
+                    SerdeTrait::Serialize => derives.serialize,
+                    SerdeTrait::Deserialize => derives.deserialize,
+                };
+                if !derived {
+                    diagnostics.push(DeriveDiagnostic {
+                        type_name: type_name.clone(),
+                        missing: required,
+                        type_location: format!("{}:{}", derives.file_path, derives.line_number),
+                        command_name: command.name.clone(),
+                        command_location: format!("{}:{}", command.file_path, command.line_number),
+                    });
+                }
+
+                // Field types need the same derive for the containing type's derive to compile
+                if let Some(struct_info) = structs.get(&type_name) {
+                    let field_types = struct_info
+                        .fields
+                        .iter()
+                        .flat_map(|field| std::iter::once(field).chain(&field.variant_fields));
+                    for field in field_types {
+                        let mut names: Vec<String> =
+                            type_names(&field.rust_type).into_iter().collect();
+                        names.sort();
+                        queue.extend(names.into_iter().map(|name| (name, required)));
+                    }
+                }
+            }
+        }
+
+        diagnostics
+    }
+
+    /// Names of the traits listed in `#[derive(...)]` attributes, without module paths
+    fn derived_traits(attrs: &[Attribute]) -> HashSet<String> {
+        attrs
+            .iter()
+            .filter(|attr| attr.path().is_ident("derive"))
+            .filter_map(|attr| attr.meta.require_list().ok())
+            .flat_map(|list| {
+                list.tokens
+                    .to_token_stream()
+                    .to_string()
+                    .split(',')
+                    .filter_map(|path| path.rsplit("::").next())
+                    .map(|name| name.trim().to_string())
+                    .collect::<Vec<_>>()
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::{ChannelInfo, ParameterInfo};
+    use syn::parse_quote;
+
+    fn checker(items: Vec<Item>) -> DeriveChecker {
+        let mut checker = DeriveChecker::new();
+        for item in &items {
+            checker.index_item(item, Path::new("models.rs"));
+        }
+        checker
+    }
+
+    fn names(rust_type: &str) -> HashSet<String> {
+        rust_type
+            .split(|c: char| !c.is_alphanumeric() && c != '_')
+            .filter(|name| name.starts_with(char::is_uppercase))
+            .filter(|name| !["Vec", "Option", "Result", "String"].contains(name))
+            .map(str::to_string)
+            .collect()
+    }
+
+    fn command(params: &[&str], return_type: &str) -> CommandInfo {
+        let parameters = params
+            .iter()
+            .map(|rust_type| ParameterInfo {
+                name: "value".to_string(),
+                rust_type: rust_type.to_string(),
+                is_optional: false,
+                type_structure: Default::default(),
+                serde_rename: None,
+            })
+            .collect();
+        CommandInfo::new_for_test("save", "lib.rs", 7, parameters, return_type, false, vec![])
+    }
+
+    #[test]
+    fn test_derive_list_parsing() {
+        let attrs: Vec<Attribute> = vec![
+            parse_quote!(#[derive(Debug, serde::Serialize)]),
+            parse_quote!(#[derive(Clone)]),
+        ];
+        let derives = DeriveChecker::derived_traits(&attrs);
+        assert!(derives.contains("Serialize"));
+        assert!(!derives.contains("Deserialize"));
+        assert!(derives.contains("Clone"));
+    }
+
+    #[test]
+    fn test_derives_of_indexed_types() {
+        let checker = checker(vec![parse_quote! {
+            #[derive(Serialize)]
+            struct User { name: String }
+        }]);
+        assert_eq!(checker.derives("User", SerdeTrait::Serialize), Some(true));
+        assert_eq!(
+            checker.derives("User", SerdeTrait::Deserialize),
+            Some(false)
+        );
+        assert_eq!(checker.derives("Unknown", SerdeTrait::Serialize), None);
+    }
+
+    #[test]
+    fn test_missing_serialize_on_return_type() {
+        let checker = checker(vec![parse_quote! {
+            #[derive(Deserialize)]
+            pub struct User { name: String }
+        }]);
+        let diagnostics = checker.check(
+            &[command(&["User"], "Result<Vec<User>, String>")],
+            &HashMap::new(),
+            names,
+        );
+
+        assert_eq!(diagnostics.len(), 1);
+        assert_eq!(diagnostics[0].type_name, "User");
+        assert_eq!(diagnostics[0].missing, SerdeTrait::Serialize);
+        assert_eq!(diagnostics[0].command_location, "lib.rs:7");
+        assert!(diagnostics[0].to_string().starts_with("User (models.rs:"));
+    }
+
+    #[test]
+    fn test_missing_deserialize_on_parameter() {
+        let checker = checker(vec![parse_quote! {
+            #[derive(Serialize)]
+            pub struct Filter { query: String }
+        }]);
+        let diagnostics = checker.check(
+            &[command(&["Option<Filter>"], "()")],
+            &HashMap::new(),
+            names,
+        );
+
+        assert_eq!(diagnostics.len(), 1);
+        assert_eq!(diagnostics[0].missing, SerdeTrait::Deserialize);
+        assert!(diagnostics[0]
+            .to_string()
+            .contains("is a parameter of command save"));
+    }
+
+    #[test]
+    fn test_nested_field_types_are_checked() {
+        let checker = checker(vec![
+            parse_quote! {
+                #[derive(Serialize)]
+                pub struct User { profile: Profile }
+            },
+            parse_quote! {
+                pub struct Profile { bio: String }
+            },
+        ]);
+        let user = StructInfo {
+            name: "User".to_string(),
+            fields: vec![crate::models::FieldInfo {
+                name: "profile".to_string(),
+                rust_type: "Profile".to_string(),
+                is_optional: false,
+                is_public: true,
+                validator_attributes: None,
+                serde_rename: None,
+                type_structure: Default::default(),
+                variant_fields: vec![],
+                literal: None,
+                flatten: false,
+                omittable: false,
+                defaulted: false,
+                skip_serializing_if: false,
+                skipped: None,
+                serde_with: None,
+            }],
+            file_path: "models.rs".to_string(),
+            is_enum: false,
+            serde_rename_all: None,
+            is_newtype: false,
+            serde_tag: None,
+            serde_content: None,
+            serde_untagged: false,
+            line_number: 0,
+            is_progress: false,
+            serde_deny_unknown_fields: false,
+        };
+        let structs = HashMap::from([("User".to_string(), user)]);
+
+        let diagnostics = checker.check(&[command(&[], "User")], &structs, names);
+        assert_eq!(diagnostics.len(), 1);
+        assert_eq!(diagnostics[0].type_name, "Profile");
+        assert_eq!(diagnostics[0].missing, SerdeTrait::Serialize);
+    }
+
+    #[test]
+    fn test_channel_messages_need_serialize() {
+        let checker = checker(vec![parse_quote! {
+            #[derive(Deserialize)]
+            pub struct Progress { percent: u8 }
+        }]);
+        let mut cmd = command(&[], "()");
+        cmd.channels = vec![ChannelInfo::new_for_test(
+            "on_progress",
+            "Progress",
+            "save",
+            "lib.rs",
+            7,
+        )];
+
+        let diagnostics = checker.check(&[cmd], &HashMap::new(), names);
+        assert_eq!(diagnostics.len(), 1);
+        assert_eq!(diagnostics[0].type_name, "Progress");
+    }
+
+    #[test]
+    fn test_external_and_complete_types_are_not_reported() {
+        let checker = checker(vec![parse_quote! {
+            #[derive(Serialize, Deserialize)]
+            pub struct User { name: String }
+        }]);
+        let diagnostics = checker.check(
+            &[command(&["User", "Uuid"], "Result<User, AppError>")],
+            &HashMap::new(),
+            names,
+        );
+        assert!(diagnostics.is_empty());
+    }
+
+    #[test]
+    fn test_each_missing_derive_is_reported_once() {
+        let checker = checker(vec![parse_quote! {
+            pub struct Opaque;
+        }]);
+        let diagnostics = checker.check(
+            &[
+                command(&["Opaque"], "Opaque"),
+                command(&["Opaque"], "Opaque"),
+            ],
+            &HashMap::new(),
+            names,
+        );
+        assert_eq!(diagnostics.len(), 2);
+        assert_ne!(diagnostics[0].missing, diagnostics[1].missing);
+    }
+}
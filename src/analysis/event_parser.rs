@@ -37,6 +37,7 @@ impl EventParser {
                 self.extract_param_types(&func.sig.inputs, &mut symbols);
 
                 // Search within function bodies with symbol context
+                let first = events.len();
                 self.extract_events_from_block(
                     &func.block.stmts,
                     file_path,
@@ -44,6 +45,9 @@ impl EventParser {
                     &mut events,
                     &mut symbols,
                 );
+                for event in &mut events[first..] {
+                    event.emitted_by = Some(func.sig.ident.to_string());
+                }
             }
         }
 
@@ -423,6 +427,7 @@ impl EventParser {
                 payload_type_structure,
                 file_path: file_path.to_string_lossy().to_string(),
                 line_number,
+                emitted_by: None,
             });
         }
     }
@@ -772,6 +777,7 @@ mod tests {
             assert_eq!(events.len(), 1);
             assert_eq!(events[0].event_name, "progress");
             assert_eq!(events[0].payload_type, "ProgressUpdate");
+            assert_eq!(events[0].emitted_by.as_deref(), Some("emit_progress"));
         }
 
         #[test]
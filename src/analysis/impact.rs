@@ -0,0 +1,228 @@
+use crate::analysis::CommandAnalyzer;
+use crate::interface::config::GenerateConfig;
+use crate::models::CommandInfo;
+use std::collections::HashSet;
+
+/// What a change to a type affects: the types containing it, the commands and
+/// events using any of them and the generated files defining or calling them
+#[derive(Debug, Clone, PartialEq)]
+pub struct Impact {
+    pub type_name: String,
+    /// The type and every type containing it, directly or transitively, by name
+    pub types: Vec<String>,
+    pub commands: Vec<ImpactedItem>,
+    pub events: Vec<ImpactedItem>,
+}
+
+/// A command or event using an affected type, with its location
+#[derive(Debug, Clone, PartialEq)]
+pub struct ImpactedItem {
+    pub name: String,
+    pub file_path: String,
+    pub line_number: usize,
+}
+
+impl Impact {
+    /// Impact of changing `type_name`, `None` if no analyzed type has that name
+    pub fn of(
+        type_name: &str,
+        analyzer: &CommandAnalyzer,
+        commands: &[CommandInfo],
+    ) -> Option<Self> {
+        if !analyzer.get_discovered_structs().contains_key(type_name) {
+            return None;
+        }
+        let types = analyzer.get_dependency_graph().dependents(type_name);
+        let uses_affected = |rust_types: &[&str]| {
+            let mut names = HashSet::new();
+            for rust_type in rust_types {
+                analyzer.extract_type_names(rust_type, &mut names);
+            }
+            names.iter().any(|name| types.contains(name))
+        };
+
+        let commands = commands
+            .iter()
+            .filter(|command| {
+                let mut rust_types: Vec<&str> = command
+                    .parameters
+                    .iter()
+                    .map(|param| param.rust_type.as_str())
+                    .collect();
+                rust_types.push(&command.return_type);
+                rust_types.extend(
+                    command
+                        .channels
+                        .iter()
+                        .map(|channel| channel.message_type.as_str()),
+                );
+                uses_affected(&rust_types)
+            })
+            .map(|command| ImpactedItem {
+                name: command.name.clone(),
+                file_path: command.file_path.clone(),
+                line_number: command.line_number,
+            })
+            .collect();
+        let events = analyzer
+            .get_discovered_events()
+            .iter()
+            .filter(|event| uses_affected(&[&event.payload_type]))
+            .map(|event| ImpactedItem {
+                name: event.event_name.clone(),
+                file_path: event.file_path.clone(),
+                line_number: event.line_number,
+            })
+            .collect();
+
+        Some(Self {
+            type_name: type_name.to_string(),
+            types: types.into_iter().collect(),
+            commands,
+            events,
+        })
+    }
+
+    /// Generated files defining or referencing the affected types, commands and
+    /// events with `config`
+    pub fn files(&self, config: &GenerateConfig, analyzer: &CommandAnalyzer) -> Vec<String> {
+        let names = config.file_names();
+        let schemas = config.uses_schemas();
+        let has_commands = !self.commands.is_empty();
+        let has_enums = self.types.iter().any(|name| {
+            analyzer
+                .get_discovered_structs()
+                .get(name)
+                .is_some_and(|info| info.is_enum)
+        });
+
+        [
+            (names.types, true),
+            (names.commands, has_commands),
+            (names.events, !self.events.is_empty()),
+            (
+                names.enums,
+                has_enums && config.should_generate_enum_helpers(),
+            ),
+            (names.globals, config.should_generate_global_declarations()),
+            (
+                names.schema_registry,
+                schemas && config.should_generate_schema_registry(),
+            ),
+            (
+                names.forms,
+                schemas && has_commands && config.should_generate_form_hooks(),
+            ),
+            (
+                names.actors,
+                has_commands && config.should_generate_xstate_actors(),
+            ),
+            (names.arbitraries, config.should_generate_arbitraries()),
+        ]
+        .into_iter()
+        .filter(|(_, affected)| *affected)
+        .map(|(name, _)| name)
+        .collect()
+    }
+
+    /// Human-readable report of the impact
+    pub fn report(&self, config: &GenerateConfig, analyzer: &CommandAnalyzer) -> String {
+        let mut output = format!("💥 Impact of changing {}\n\n", self.type_name);
+
+        output.push_str(&format!("🏗️  Types ({}):\n", self.types.len()));
+        for type_name in &self.types {
+            output.push_str(&format!("• {}\n", type_name));
+        }
+
+        for (title, items) in [("📋 Commands", &self.commands), ("📡 Events", &self.events)] {
+            output.push_str(&format!("\n{} ({}):\n", title, items.len()));
+            for item in items {
+                output.push_str(&format!(
+                    "• {} ({}:{})\n",
+                    item.name, item.file_path, item.line_number
+                ));
+            }
+        }
+
+        let files = self.files(config, analyzer);
+        output.push_str(&format!("\n📄 Generated files ({}):\n", files.len()));
+        for file in &files {
+            output.push_str(&format!("• {}\n", file));
+        }
+        output
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn analyze(source: &str) -> (CommandAnalyzer, Vec<CommandInfo>) {
+        let mut analyzer = CommandAnalyzer::new();
+        let commands = analyzer.analyze_source(source).unwrap();
+        (analyzer, commands)
+    }
+
+    const SOURCE: &str = r#"
+        use serde::{Deserialize, Serialize};
+        use tauri::Emitter;
+
+        #[derive(Clone, Serialize, Deserialize)]
+        pub struct Address {
+            pub city: String,
+        }
+
+        #[derive(Clone, Serialize, Deserialize)]
+        pub struct User {
+            pub address: Address,
+        }
+
+        #[derive(Clone, Serialize, Deserialize)]
+        pub struct Team {
+            pub members: Vec<User>,
+        }
+
+        #[derive(Clone, Serialize, Deserialize)]
+        pub struct Tag {
+            pub label: String,
+        }
+
+        #[tauri::command]
+        pub fn get_team(app: tauri::AppHandle) -> Result<Team, String> {
+            app.emit("user-updated", User { address: Address { city: String::new() } }).unwrap();
+            unimplemented!()
+        }
+
+        #[tauri::command]
+        pub fn get_tags(tag: Option<Tag>) -> Vec<Tag> {
+            unimplemented!()
+        }
+    "#;
+
+    #[test]
+    fn test_impact_follows_containing_types() {
+        let (analyzer, commands) = analyze(SOURCE);
+        let impact = Impact::of("Address", &analyzer, &commands).unwrap();
+
+        assert_eq!(impact.types, ["Address", "Team", "User"]);
+        let commands: Vec<&str> = impact.commands.iter().map(|c| c.name.as_str()).collect();
+        assert_eq!(commands, ["get_team"]);
+        let events: Vec<&str> = impact.events.iter().map(|e| e.name.as_str()).collect();
+        assert_eq!(events, ["user-updated"]);
+        assert_eq!(
+            impact.files(&GenerateConfig::default(), &analyzer),
+            ["types.ts", "commands.ts", "events.ts"]
+        );
+    }
+
+    #[test]
+    fn test_impact_of_leaf_type() {
+        let (analyzer, commands) = analyze(SOURCE);
+        let impact = Impact::of("Tag", &analyzer, &commands).unwrap();
+
+        assert_eq!(impact.types, ["Tag"]);
+        assert_eq!(impact.commands.len(), 1);
+        assert!(impact.events.is_empty());
+        assert!(Impact::of("Missing", &analyzer, &commands).is_none());
+    }
+}
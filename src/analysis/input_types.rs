@@ -0,0 +1,401 @@
+use crate::models::{CommandInfo, EventInfo, FieldInfo, SkipDirection, StructInfo, TypeStructure};
+use std::collections::{HashMap, HashSet};
+
+/// Suffix of the input twin of a struct, e.g. `UserInput` for `User`
+pub const INPUT_TYPE_SUFFIX: &str = "Input";
+
+/// Splits structs used both as command parameters and as results into an input type
+/// (`UserInput`) and an output type (`User`) when serde treats their fields
+/// differently by direction: one-way skips, `#[serde(default)]` and
+/// `skip_serializing_if`.
+///
+/// Parameters are deserialized by the backend, results, channel messages and events
+/// serialized. Structs referencing a split struct are split as well, and parameters
+/// and input-only structs are retyped with the input types.
+pub struct InputTypeSplitter<'a> {
+    structs: &'a HashMap<String, StructInfo>,
+}
+
+impl<'a> InputTypeSplitter<'a> {
+    pub fn new(structs: &'a HashMap<String, StructInfo>) -> Self {
+        Self { structs }
+    }
+
+    /// Input type names by the name of the struct they were split from
+    pub fn input_types(
+        &self,
+        commands: &[CommandInfo],
+        events: &[EventInfo],
+    ) -> HashMap<String, String> {
+        let mut inputs = HashSet::new();
+        let mut outputs = HashSet::new();
+        for command in commands {
+            for param in &command.parameters {
+                collect_custom_types(&param.type_structure, &mut inputs);
+            }
+            collect_custom_types(&command.return_type_structure, &mut outputs);
+            for channel in &command.channels {
+                collect_custom_types(&channel.message_type_structure, &mut outputs);
+            }
+        }
+        for event in events {
+            collect_custom_types(&event.payload_type_structure, &mut outputs);
+        }
+        let inputs = self.with_nested_types(inputs);
+        let outputs = self.with_nested_types(outputs);
+
+        // Structs traveling both ways whose fields differ by direction, then the
+        // structs referencing them, until no more are found
+        let both_ways = |name: &&String| {
+            inputs.contains(*name)
+                && outputs.contains(*name)
+                && self
+                    .structs
+                    .get(*name)
+                    .is_some_and(|s| !s.is_enum && !s.is_newtype)
+        };
+        let mut split: HashSet<String> = inputs
+            .iter()
+            .filter(both_ways)
+            .filter(|name| self.structs[*name].fields.iter().any(differs_by_direction))
+            .cloned()
+            .collect();
+        loop {
+            let referencing: Vec<String> = inputs
+                .iter()
+                .filter(both_ways)
+                .filter(|name| !split.contains(*name))
+                .filter(|name| {
+                    self.structs[*name].fields.iter().any(|field| {
+                        let mut types = HashSet::new();
+                        collect_custom_types(&field.type_structure, &mut types);
+                        types.iter().any(|type_name| split.contains(type_name))
+                    })
+                })
+                .cloned()
+                .collect();
+            if referencing.is_empty() {
+                break;
+            }
+            split.extend(referencing);
+        }
+
+        split
+            .into_iter()
+            .map(|name| {
+                let input_name = format!("{}{}", name, INPUT_TYPE_SUFFIX);
+                (name, input_name)
+            })
+            .filter(|(_, input_name)| !self.structs.contains_key(input_name))
+            .collect()
+    }
+
+    /// `types` and the types their fields reference, transitively
+    fn with_nested_types(&self, types: HashSet<String>) -> HashSet<String> {
+        let mut all_types = types.clone();
+        let mut to_process: Vec<String> = types.into_iter().collect();
+        while let Some(name) = to_process.pop() {
+            let Some(struct_info) = self.structs.get(&name) else {
+                continue;
+            };
+            let mut nested = HashSet::new();
+            for field in &struct_info.fields {
+                collect_custom_types(&field.type_structure, &mut nested);
+                for variant_field in &field.variant_fields {
+                    collect_custom_types(&variant_field.type_structure, &mut nested);
+                }
+            }
+            for nested_type in nested {
+                if all_types.insert(nested_type.clone()) {
+                    to_process.push(nested_type);
+                }
+            }
+        }
+        all_types
+    }
+}
+
+/// Split the structs found by [`InputTypeSplitter::input_types`]: add the input types,
+/// narrow the split structs to their output fields and retype parameters and
+/// input-only structs. Returns the names of the added and changed structs.
+pub fn split_input_types(
+    commands: &mut [CommandInfo],
+    events: &[EventInfo],
+    structs: &mut HashMap<String, StructInfo>,
+) -> Vec<String> {
+    let input_names = InputTypeSplitter::new(structs).input_types(commands, events);
+    if input_names.is_empty() {
+        return Vec::new();
+    }
+
+    // Input-only structs referencing split structs take their input types
+    let param_types: HashSet<String> = {
+        let mut types = HashSet::new();
+        for command in commands.iter() {
+            for param in &command.parameters {
+                collect_custom_types(&param.type_structure, &mut types);
+            }
+        }
+        InputTypeSplitter::new(structs).with_nested_types(types)
+    };
+    let mut changed = Vec::new();
+    for (name, struct_info) in structs.iter_mut() {
+        if !param_types.contains(name) || input_names.contains_key(name) {
+            continue;
+        }
+        let mut retyped = false;
+        for field in &mut struct_info.fields {
+            retyped |= retype_field(field, &input_names);
+        }
+        if retyped {
+            changed.push(name.clone());
+        }
+    }
+
+    for (name, input_name) in &input_names {
+        let struct_info = structs.get_mut(name).expect("split structs are known");
+        let mut input = struct_info.clone();
+        input.name = input_name.clone();
+        input.fields = input
+            .fields
+            .into_iter()
+            .filter(|field| field.skipped != Some(SkipDirection::Deserializing))
+            .map(|mut field| {
+                retype_field(&mut field, &input_names);
+                let optional = matches!(field.type_structure, TypeStructure::Optional(_));
+                field.omittable = field.defaulted && !optional;
+                field.is_optional = optional || field.omittable;
+                field.skipped = None;
+                field
+            })
+            .collect();
+
+        struct_info
+            .fields
+            .retain(|field| field.skipped != Some(SkipDirection::Serializing));
+        for field in &mut struct_info.fields {
+            let optional = matches!(field.type_structure, TypeStructure::Optional(_));
+            field.omittable = field.skip_serializing_if && !optional;
+            field.is_optional = optional || field.omittable;
+            field.skipped = None;
+        }
+
+        structs.insert(input_name.clone(), input);
+        changed.push(name.clone());
+        changed.push(input_name.clone());
+    }
+
+    for command in commands.iter_mut() {
+        for param in &mut command.parameters {
+            param.rust_type = retype(&param.rust_type, &input_names);
+            retype_structure(&mut param.type_structure, &input_names);
+        }
+    }
+
+    changed
+}
+
+/// Whether serde reads and writes the field differently
+fn differs_by_direction(field: &FieldInfo) -> bool {
+    field.skipped.is_some()
+        || (!matches!(field.type_structure, TypeStructure::Optional(_))
+            && (field.defaulted || field.skip_serializing_if))
+}
+
+/// Reference the input types in the field's type, returning whether it changed
+fn retype_field(field: &mut FieldInfo, input_names: &HashMap<String, String>) -> bool {
+    let rust_type = retype(&field.rust_type, input_names);
+    if rust_type == field.rust_type {
+        return false;
+    }
+    field.rust_type = rust_type;
+    retype_structure(&mut field.type_structure, input_names);
+    true
+}
+
+/// Replace the type names of a Rust type string by their input types
+fn retype(rust_type: &str, input_names: &HashMap<String, String>) -> String {
+    let mut result = String::with_capacity(rust_type.len());
+    let mut ident = String::new();
+    for c in rust_type.chars().chain(std::iter::once('\0')) {
+        if c.is_alphanumeric() || c == '_' {
+            ident.push(c);
+            continue;
+        }
+        result.push_str(input_names.get(&ident).unwrap_or(&ident));
+        ident.clear();
+        if c != '\0' {
+            result.push(c);
+        }
+    }
+    result
+}
+
+fn retype_structure(structure: &mut TypeStructure, input_names: &HashMap<String, String>) {
+    match structure {
+        TypeStructure::Custom(name) => {
+            if let Some(input_name) = input_names.get(name) {
+                *name = input_name.clone();
+            }
+        }
+        TypeStructure::Array(inner)
+        | TypeStructure::Set(inner)
+        | TypeStructure::Optional(inner)
+        | TypeStructure::Result(inner) => retype_structure(inner, input_names),
+        TypeStructure::Map { key, value } => {
+            retype_structure(key, input_names);
+            retype_structure(value, input_names);
+        }
+        TypeStructure::Tuple(types) | TypeStructure::Union(types) => {
+            for inner in types {
+                retype_structure(inner, input_names);
+            }
+        }
+        TypeStructure::Primitive(_) => {}
+    }
+}
+
+fn collect_custom_types(structure: &TypeStructure, types: &mut HashSet<String>) {
+    match structure {
+        TypeStructure::Custom(name) => {
+            types.insert(name.clone());
+        }
+        TypeStructure::Array(inner)
+        | TypeStructure::Set(inner)
+        | TypeStructure::Optional(inner)
+        | TypeStructure::Result(inner) => collect_custom_types(inner, types),
+        TypeStructure::Map { key, value } => {
+            collect_custom_types(key, types);
+            collect_custom_types(value, types);
+        }
+        TypeStructure::Tuple(inner) | TypeStructure::Union(inner) => {
+            for structure in inner {
+                collect_custom_types(structure, types);
+            }
+        }
+        TypeStructure::Primitive(_) => {}
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::analysis::CommandAnalyzer;
+    use crate::models::TypeStructure;
+
+    const SOURCE: &str = r#"
+        use serde::{Deserialize, Serialize};
+
+        #[derive(Serialize, Deserialize)]
+        pub struct User {
+            #[serde(skip_deserializing)]
+            pub id: u32,
+            pub name: String,
+            #[serde(skip_serializing)]
+            pub password: String,
+            #[serde(default)]
+            pub tags: Vec<String>,
+        }
+
+        #[derive(Serialize, Deserialize)]
+        pub struct Team {
+            pub members: Vec<User>,
+        }
+
+        #[derive(Serialize, Deserialize)]
+        pub struct Invite {
+            pub user: User,
+        }
+
+        #[derive(Serialize, Deserialize)]
+        pub struct Point {
+            #[serde(default)]
+            pub x: Option<i32>,
+        }
+
+        #[tauri::command]
+        pub fn save_user(user: User) -> User { todo!() }
+
+        #[tauri::command]
+        pub fn save_team(team: Team) -> Team { todo!() }
+
+        #[tauri::command]
+        pub fn invite(invite: Invite) {}
+
+        #[tauri::command]
+        pub fn move_to(point: Point) -> Point { todo!() }
+    "#;
+
+    fn analyze(separate_input_types: bool) -> CommandAnalyzer {
+        let mut analyzer = CommandAnalyzer::new();
+        analyzer.set_separate_input_types(separate_input_types);
+        analyzer.analyze_source(SOURCE).unwrap();
+        analyzer
+    }
+
+    fn field_names(analyzer: &CommandAnalyzer, name: &str) -> Vec<(String, bool)> {
+        analyzer.get_discovered_structs()[name]
+            .fields
+            .iter()
+            .map(|field| (field.name.clone(), field.is_optional))
+            .collect()
+    }
+
+    #[test]
+    fn test_splits_structs_whose_fields_differ_by_direction() {
+        let analyzer = analyze(true);
+
+        let owned = |fields: &[(&str, bool)]| -> Vec<(String, bool)> {
+            fields.iter().map(|(n, o)| (n.to_string(), *o)).collect()
+        };
+        assert_eq!(
+            field_names(&analyzer, "UserInput"),
+            owned(&[("name", false), ("password", false), ("tags", true)])
+        );
+        assert_eq!(
+            field_names(&analyzer, "User"),
+            owned(&[("id", false), ("name", false), ("tags", false)])
+        );
+    }
+
+    #[test]
+    fn test_referencing_structs_follow_the_split() {
+        let mut analyzer = CommandAnalyzer::new();
+        analyzer.set_separate_input_types(true);
+        let commands = analyzer.analyze_source(SOURCE).unwrap();
+        let structs = analyzer.get_discovered_structs();
+
+        // Team travels both ways, so it is split to reference UserInput
+        assert_eq!(structs["TeamInput"].fields[0].rust_type, "Vec<UserInput>");
+        assert_eq!(structs["Team"].fields[0].rust_type, "Vec<User>");
+        // Invite is only a parameter and is retyped in place
+        assert!(!structs.contains_key("InviteInput"));
+        assert_eq!(
+            structs["Invite"].fields[0].type_structure,
+            TypeStructure::Custom("UserInput".to_string())
+        );
+
+        let param_type = |command: &str| {
+            let command = commands.iter().find(|c| c.name == command).unwrap();
+            command.parameters[0].rust_type.clone()
+        };
+        assert_eq!(param_type("save_user"), "UserInput");
+        assert_eq!(param_type("save_team"), "TeamInput");
+        assert_eq!(param_type("invite"), "Invite");
+    }
+
+    #[test]
+    fn test_keeps_structs_reading_and_writing_alike() {
+        let analyzer = analyze(true);
+
+        // A defaulted Option field is optional either way
+        assert!(!analyzer.get_discovered_structs().contains_key("PointInput"));
+    }
+
+    #[test]
+    fn test_disabled_by_default() {
+        let analyzer = analyze(false);
+
+        assert!(!analyzer.get_discovered_structs().contains_key("UserInput"));
+        assert_eq!(field_names(&analyzer, "User").len(), 4);
+    }
+}
@@ -0,0 +1,461 @@
+use crate::models::{CommandInfo, FieldInfo, StructInfo};
+use serde::Serialize;
+use std::collections::{HashMap, HashSet};
+use std::fmt;
+
+/// Byte buffers bounded by `#[validate(length(max = ...))]` up to this size are sent inline
+pub const MAX_INLINE_BYTES: u64 = 64 * 1024;
+
+/// Collections nested this deep (`Vec<Vec<Vec<f64>>>`, a `Vec` of structs holding
+/// `Vec<Vec<_>>`, ...) are reported
+pub const MAX_COLLECTION_DEPTH: usize = 3;
+
+/// Collection types each adding a level of nesting
+const COLLECTIONS: &[&str] = &[
+    "Vec",
+    "VecDeque",
+    "LinkedList",
+    "BinaryHeap",
+    "HashSet",
+    "BTreeSet",
+    "IndexSet",
+    "SmallVec",
+    "HashMap",
+    "BTreeMap",
+    "IndexMap",
+];
+
+/// Types serialized as their single type argument
+const WRAPPERS: &[&str] = &["Option", "Box", "Arc", "Rc", "Cow", "Result"];
+
+/// Byte buffer types that are not a collection of `u8`
+const BYTE_BUFFERS: &[&str] = &["Bytes", "BytesMut", "ByteBuf"];
+
+/// Why a payload is likely too large to send as JSON through `invoke`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub enum PayloadKind {
+    /// Bytes serialized as a JSON array of numbers
+    ByteBuffer,
+    /// Collections nested this many levels deep
+    NestedCollections(usize),
+}
+
+/// Side of the command a payload is sent on
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub enum PayloadSide {
+    Parameter(String),
+    Return,
+}
+
+/// Informational lint of a command parameter or return type likely to exceed a
+/// practical IPC size
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PayloadDiagnostic {
+    pub command_name: String,
+    /// Source location (`file:line`) of the command
+    pub command_location: String,
+    pub side: PayloadSide,
+    /// `Type.field` the payload was found at, when not the parameter or return type itself
+    pub field: Option<String>,
+    pub kind: PayloadKind,
+}
+
+impl PayloadDiagnostic {
+    /// Transport better suited to the payload
+    pub fn suggestion(&self) -> &'static str {
+        match (&self.side, self.kind) {
+            (PayloadSide::Parameter(_), PayloadKind::ByteBuffer) => {
+                "send the bytes as a raw `tauri::ipc::Request` body instead"
+            }
+            (PayloadSide::Return, PayloadKind::ByteBuffer) => {
+                "return a `tauri::ipc::Response` or stream the bytes through a `Channel` instead"
+            }
+            (PayloadSide::Parameter(_), PayloadKind::NestedCollections(_)) => {
+                "consider sending the data in chunks across several calls"
+            }
+            (PayloadSide::Return, PayloadKind::NestedCollections(_)) => {
+                "consider streaming the data in chunks through a `Channel`"
+            }
+        }
+    }
+}
+
+impl fmt::Display for PayloadDiagnostic {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let side = match &self.side {
+            PayloadSide::Parameter(name) => format!("parameter `{}`", name),
+            PayloadSide::Return => "return value".to_string(),
+        };
+        let field = self
+            .field
+            .as_ref()
+            .map(|field| format!(" (field `{}`)", field))
+            .unwrap_or_default();
+        let kind = match self.kind {
+            PayloadKind::ByteBuffer => "holds bytes serialized as a JSON number array".to_string(),
+            PayloadKind::NestedCollections(depth) => {
+                format!("nests collections {} levels deep", depth)
+            }
+        };
+        write!(
+            f,
+            "{} ({}): {}{} {}; {}",
+            self.command_name,
+            self.command_location,
+            side,
+            field,
+            kind,
+            self.suggestion()
+        )
+    }
+}
+
+/// Flags command parameters and return types holding large inline data: byte buffers
+/// and deeply nested collections, followed through the fields of the project's types.
+///
+/// The sizes are not known before runtime, so this is a heuristic: byte buffers bounded
+/// by a validated maximum length up to [`MAX_INLINE_BYTES`] are not reported.
+pub struct IpcSizeLint<'a> {
+    structs: &'a HashMap<String, StructInfo>,
+}
+
+/// State of the walk over one parameter or return type
+struct Walk<'a> {
+    command: &'a CommandInfo,
+    side: PayloadSide,
+    /// Types being walked, guarding against recursive types
+    stack: Vec<String>,
+    reported: HashSet<(Option<String>, PayloadKind)>,
+    diagnostics: Vec<PayloadDiagnostic>,
+}
+
+impl<'a> IpcSizeLint<'a> {
+    pub fn new(structs: &'a HashMap<String, StructInfo>) -> Self {
+        Self { structs }
+    }
+
+    /// Check the parameters and return types of `commands`, reporting each payload once
+    /// per command side
+    pub fn check(&self, commands: &[CommandInfo]) -> Vec<PayloadDiagnostic> {
+        let mut diagnostics = Vec::new();
+        for command in commands {
+            let sides = command
+                .parameters
+                .iter()
+                .map(|param| (PayloadSide::Parameter(param.name.clone()), &param.rust_type))
+                .chain(std::iter::once((PayloadSide::Return, &command.return_type)));
+            for (side, rust_type) in sides {
+                let Ok(ty) = syn::parse_str::<syn::Type>(rust_type) else {
+                    continue;
+                };
+                let mut walk = Walk {
+                    command,
+                    side,
+                    stack: Vec::new(),
+                    reported: HashSet::new(),
+                    diagnostics: Vec::new(),
+                };
+                self.walk_type(&ty, 0, None, &mut walk);
+                diagnostics.append(&mut walk.diagnostics);
+            }
+        }
+        diagnostics
+    }
+
+    fn walk_type(&self, ty: &syn::Type, depth: usize, field: Option<&str>, walk: &mut Walk) {
+        match ty {
+            syn::Type::Reference(reference) => self.walk_type(&reference.elem, depth, field, walk),
+            syn::Type::Paren(paren) => self.walk_type(&paren.elem, depth, field, walk),
+            syn::Type::Group(group) => self.walk_type(&group.elem, depth, field, walk),
+            syn::Type::Tuple(tuple) => {
+                for elem in &tuple.elems {
+                    self.walk_type(elem, depth, field, walk);
+                }
+            }
+            syn::Type::Slice(slice) => self.walk_collection(&[&slice.elem], depth, field, walk),
+            syn::Type::Array(array) => {
+                // Fixed-size arrays are as large as declared
+                let len = match &array.len {
+                    syn::Expr::Lit(syn::ExprLit {
+                        lit: syn::Lit::Int(int),
+                        ..
+                    }) => int.base10_parse::<u64>().ok(),
+                    _ => None,
+                };
+                if Self::is_u8(&array.elem) {
+                    if len.map_or(true, |len| len > MAX_INLINE_BYTES) {
+                        walk.report(field, PayloadKind::ByteBuffer);
+                    }
+                } else {
+                    self.walk_collection(&[&array.elem], depth, field, walk);
+                }
+            }
+            syn::Type::Path(type_path) => {
+                let Some(segment) = type_path.path.segments.last() else {
+                    return;
+                };
+                let name = segment.ident.to_string();
+                let args: Vec<&syn::Type> = match &segment.arguments {
+                    syn::PathArguments::AngleBracketed(angle) => angle
+                        .args
+                        .iter()
+                        .filter_map(|arg| match arg {
+                            syn::GenericArgument::Type(ty) => Some(ty),
+                            _ => None,
+                        })
+                        .collect(),
+                    _ => Vec::new(),
+                };
+
+                if BYTE_BUFFERS.contains(&name.as_str()) {
+                    walk.report(field, PayloadKind::ByteBuffer);
+                } else if WRAPPERS.contains(&name.as_str()) {
+                    // The error of a `Result` is not part of a successful response
+                    if let Some(inner) = args.first() {
+                        self.walk_type(inner, depth, field, walk);
+                    }
+                } else if COLLECTIONS.contains(&name.as_str()) {
+                    self.walk_collection(&args, depth, field, walk);
+                } else if let Some(struct_info) = self.structs.get(&name) {
+                    if walk.stack.contains(&name) {
+                        return;
+                    }
+                    walk.stack.push(name.clone());
+                    let fields = struct_info
+                        .fields
+                        .iter()
+                        .flat_map(|field| std::iter::once(field).chain(&field.variant_fields));
+                    for struct_field in fields {
+                        self.walk_field(&name, struct_field, depth, walk);
+                    }
+                    walk.stack.pop();
+                }
+            }
+            _ => {}
+        }
+    }
+
+    fn walk_field(&self, type_name: &str, field: &FieldInfo, depth: usize, walk: &mut Walk) {
+        let Ok(ty) = syn::parse_str::<syn::Type>(&field.rust_type) else {
+            return;
+        };
+        // Validated lengths bound byte buffers to what the application expects
+        let max_length = field
+            .validator_attributes
+            .as_ref()
+            .and_then(|validator| validator.length.as_ref())
+            .and_then(|length| length.max);
+        if max_length.is_some_and(|max| max <= MAX_INLINE_BYTES) && Self::is_byte_buffer(&ty) {
+            return;
+        }
+        let path = format!("{}.{}", type_name, field.name);
+        self.walk_type(&ty, depth, Some(&path), walk);
+    }
+
+    /// Walk the element types of a collection, one level deeper, or report its nesting
+    fn walk_collection(
+        &self,
+        elements: &[&syn::Type],
+        depth: usize,
+        field: Option<&str>,
+        walk: &mut Walk,
+    ) {
+        // A sequence of bytes, e.g. `Vec<u8>` or `[u8]`
+        if let [element] = elements {
+            if Self::is_u8(element) {
+                walk.report(field, PayloadKind::ByteBuffer);
+                return;
+            }
+        }
+        let depth = depth + 1;
+        if depth >= MAX_COLLECTION_DEPTH {
+            walk.report(field, PayloadKind::NestedCollections(depth));
+            return;
+        }
+        // The values of maps, the elements of other collections
+        if let Some(element) = elements.last() {
+            self.walk_type(element, depth, field, walk);
+        }
+    }
+
+    fn is_byte_buffer(ty: &syn::Type) -> bool {
+        match ty {
+            syn::Type::Slice(slice) => Self::is_u8(&slice.elem),
+            syn::Type::Array(array) => Self::is_u8(&array.elem),
+            syn::Type::Path(type_path) => type_path.path.segments.last().is_some_and(|segment| {
+                let name = segment.ident.to_string();
+                match &segment.arguments {
+                    syn::PathArguments::AngleBracketed(angle) => {
+                        matches!(angle.args.first(), Some(syn::GenericArgument::Type(inner))
+                            if (COLLECTIONS.contains(&name.as_str()) && Self::is_u8(inner))
+                                || (WRAPPERS.contains(&name.as_str()) && Self::is_byte_buffer(inner)))
+                    }
+                    _ => BYTE_BUFFERS.contains(&name.as_str()),
+                }
+            }),
+            _ => false,
+        }
+    }
+
+    fn is_u8(ty: &syn::Type) -> bool {
+        matches!(ty, syn::Type::Path(type_path) if type_path.path.is_ident("u8"))
+    }
+}
+
+impl Walk<'_> {
+    fn report(&mut self, field: Option<&str>, kind: PayloadKind) {
+        let field = field.map(str::to_string);
+        if !self.reported.insert((field.clone(), kind)) {
+            return;
+        }
+        self.diagnostics.push(PayloadDiagnostic {
+            command_name: self.command.name.clone(),
+            command_location: format!("{}:{}", self.command.file_path, self.command.line_number),
+            side: self.side.clone(),
+            field,
+            kind,
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::{LengthConstraint, ParameterInfo, ValidatorAttributes};
+
+    fn command(params: &[(&str, &str)], return_type: &str) -> CommandInfo {
+        let parameters = params
+            .iter()
+            .map(|(name, rust_type)| ParameterInfo {
+                name: name.to_string(),
+                rust_type: rust_type.to_string(),
+                is_optional: false,
+                type_structure: Default::default(),
+                serde_rename: None,
+            })
+            .collect();
+        CommandInfo::new_for_test(
+            "upload",
+            "lib.rs",
+            3,
+            parameters,
+            return_type,
+            false,
+            vec![],
+        )
+    }
+
+    fn field(name: &str, rust_type: &str, max_length: Option<u64>) -> FieldInfo {
+        FieldInfo {
+            name: name.to_string(),
+            rust_type: rust_type.to_string(),
+            is_optional: false,
+            is_public: true,
+            validator_attributes: max_length.map(|max| ValidatorAttributes {
+                length: Some(LengthConstraint {
+                    min: None,
+                    max: Some(max),
+                    message: None,
+                }),
+                ..Default::default()
+            }),
+            serde_rename: None,
+            type_structure: Default::default(),
+            variant_fields: vec![],
+            literal: None,
+            flatten: false,
+            omittable: false,
+            defaulted: false,
+            skip_serializing_if: false,
+            skipped: None,
+            serde_with: None,
+        }
+    }
+
+    fn structs(definitions: Vec<(&str, Vec<FieldInfo>)>) -> HashMap<String, StructInfo> {
+        definitions
+            .into_iter()
+            .map(|(name, fields)| {
+                (
+                    name.to_string(),
+                    StructInfo {
+                        name: name.to_string(),
+                        fields,
+                        file_path: "models.rs".to_string(),
+                        line_number: 0,
+                        is_enum: false,
+                        serde_rename_all: None,
+                        is_newtype: false,
+                        serde_tag: None,
+                        serde_content: None,
+                        serde_untagged: false,
+                        is_progress: false,
+                        serde_deny_unknown_fields: false,
+                    },
+                )
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_byte_buffers_reported_per_side() {
+        let structs = HashMap::new();
+        let diagnostics = IpcSizeLint::new(&structs).check(&[command(
+            &[
+                ("data", "Vec<u8>"),
+                ("hash", "[u8; 32]"),
+                ("name", "String"),
+            ],
+            "Result<bytes::Bytes, String>",
+        )]);
+
+        assert_eq!(diagnostics.len(), 2);
+        assert_eq!(
+            diagnostics[0].side,
+            PayloadSide::Parameter("data".to_string())
+        );
+        assert_eq!(diagnostics[0].kind, PayloadKind::ByteBuffer);
+        assert_eq!(diagnostics[1].side, PayloadSide::Return);
+        assert_eq!(
+            diagnostics[0].to_string(),
+            "upload (lib.rs:3): parameter `data` holds bytes serialized as a JSON number array; send the bytes as a raw `tauri::ipc::Request` body instead"
+        );
+    }
+
+    #[test]
+    fn test_struct_fields_followed() {
+        let structs = structs(vec![
+            (
+                "Document",
+                vec![
+                    field("thumbnail", "Vec<u8>", Some(4096)),
+                    field("content", "Vec<u8>", Some(10_000_000)),
+                    field("pages", "Vec<Page>", None),
+                    field("parent", "Option<Box<Document>>", None),
+                ],
+            ),
+            ("Page", vec![field("cells", "Vec<Vec<f64>>", None)]),
+        ]);
+        let diagnostics = IpcSizeLint::new(&structs).check(&[command(&[], "Vec<Document>")]);
+
+        assert_eq!(diagnostics.len(), 2);
+        assert_eq!(diagnostics[0].field.as_deref(), Some("Document.content"));
+        assert_eq!(diagnostics[0].kind, PayloadKind::ByteBuffer);
+        assert_eq!(diagnostics[1].field.as_deref(), Some("Page.cells"));
+        assert_eq!(diagnostics[1].kind, PayloadKind::NestedCollections(3));
+        assert!(diagnostics[1].to_string().contains("through a `Channel`"));
+    }
+
+    #[test]
+    fn test_shallow_collections_not_reported() {
+        let structs = HashMap::new();
+        let diagnostics = IpcSizeLint::new(&structs).check(&[command(
+            &[("rows", "HashMap<String, Vec<u32>>")],
+            "Option<Vec<String>>",
+        )]);
+        assert!(diagnostics.is_empty());
+    }
+}
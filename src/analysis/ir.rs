@@ -0,0 +1,237 @@
+use crate::analysis::CommandAnalyzer;
+use crate::models::{CommandInfo, ConstInfo, EventInfo, StructInfo};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::Path;
+
+/// Version of the IR format, increased with every incompatible change to it
+///
+/// 2: fields record `flatten`, `omittable`, `defaulted`, `skipSerializingIf`,
+/// `skipped` and `serdeWith`
+pub const IR_SCHEMA_VERSION: u32 = 2;
+
+/// Analysis result saved as JSON, so bindings can be generated from it without
+/// analyzing the Rust sources again, e.g. on another CI runner or after a custom
+/// pipeline step changed it
+#[derive(Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AnalysisIr {
+    pub schema_version: u32,
+    pub commands: Vec<CommandInfo>,
+    /// Structs and enums used by the commands and events, by name
+    pub structs: BTreeMap<String, StructInfo>,
+    pub events: Vec<EventInfo>,
+    pub constants: Vec<ConstInfo>,
+}
+
+impl AnalysisIr {
+    /// IR of the commands and the types, events and constants found by `analyzer`
+    pub fn new(commands: &[CommandInfo], analyzer: &CommandAnalyzer) -> Self {
+        Self {
+            schema_version: IR_SCHEMA_VERSION,
+            commands: commands.to_vec(),
+            structs: analyzer
+                .get_discovered_structs()
+                .iter()
+                .map(|(name, struct_info)| (name.clone(), struct_info.clone()))
+                .collect(),
+            events: analyzer.get_discovered_events().to_vec(),
+            constants: analyzer.get_discovered_constants().to_vec(),
+        }
+    }
+
+    /// Parse an IR, rejecting other schema versions before reading the rest
+    pub fn from_json(json: &str) -> Result<Self, Box<dyn std::error::Error>> {
+        let value: serde_json::Value = serde_json::from_str(json)?;
+        let version = value.get("schemaVersion").and_then(|v| v.as_u64());
+        if version != Some(IR_SCHEMA_VERSION as u64) {
+            return Err(format!(
+                "Unsupported IR schema version {}, expected {}. Save the IR again with this version of tauri-typegen",
+                version.map_or("(missing)".to_string(), |v| v.to_string()),
+                IR_SCHEMA_VERSION
+            )
+            .into());
+        }
+        Ok(serde_json::from_value(value)?)
+    }
+
+    /// Load an IR saved with [`AnalysisIr::save`]
+    pub fn load(path: &Path) -> Result<Self, Box<dyn std::error::Error>> {
+        let json = fs::read_to_string(path)
+            .map_err(|e| format!("Failed to read IR {}: {}", path.display(), e))?;
+        Self::from_json(&json).map_err(|e| format!("Invalid IR {}: {}", path.display(), e).into())
+    }
+
+    /// Save the IR as pretty-printed JSON
+    pub fn save(&self, path: &Path) -> Result<(), Box<dyn std::error::Error>> {
+        if let Some(parent) = path
+            .parent()
+            .filter(|parent| !parent.as_os_str().is_empty())
+        {
+            fs::create_dir_all(parent)?;
+        }
+        fs::write(path, serde_json::to_string_pretty(self)?)?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::ConstValue;
+
+    const SOURCE: &str = r#"
+        use serde::{Deserialize, Serialize};
+        use tauri::Emitter;
+
+        #[typegen(export)]
+        pub const LIMITS: [u32; 2] = [10, 20];
+
+        #[derive(Serialize, Deserialize)]
+        #[serde(rename_all = "camelCase")]
+        pub struct User {
+            pub user_name: String,
+            pub role: Role,
+        }
+
+        #[derive(Serialize, Deserialize)]
+        pub enum Role {
+            Admin,
+            Member { since: u32 },
+        }
+
+        #[tauri::command]
+        pub fn get_user(app: tauri::AppHandle, id: u32) -> Result<User, String> {
+            app.emit("user-loaded", id).unwrap();
+            unimplemented!()
+        }
+    "#;
+
+    #[test]
+    fn test_ir_round_trip() {
+        let mut analyzer = CommandAnalyzer::new();
+        let commands = analyzer.analyze_source(SOURCE).unwrap();
+        let ir = AnalysisIr::new(&commands, &analyzer);
+
+        let json = serde_json::to_string(&ir).unwrap();
+        let loaded = AnalysisIr::from_json(&json).unwrap();
+        assert_eq!(serde_json::to_string(&loaded).unwrap(), json);
+        assert_eq!(loaded.commands[0].name, "get_user");
+        assert_eq!(loaded.events[0].event_name, "user-loaded");
+        assert_eq!(
+            loaded.constants[0].value,
+            ConstValue::Array(vec![ConstValue::Integer(10), ConstValue::Integer(20)])
+        );
+
+        let mut restored = CommandAnalyzer::new();
+        let restored_commands = restored.load_ir(loaded);
+        assert_eq!(restored_commands.len(), 1);
+        let user = &restored.get_discovered_structs()["User"];
+        assert_eq!(
+            user.serde_rename_all,
+            analyzer.get_discovered_structs()["User"].serde_rename_all
+        );
+        assert_eq!(
+            restored.topological_sort_types(&["User".to_string()].into()),
+            ["Role", "User"]
+        );
+    }
+
+    #[test]
+    fn test_ir_schema_version_check() {
+        let error = AnalysisIr::from_json(r#"{"schemaVersion": 0, "commands": []}"#)
+            .err()
+            .unwrap();
+        assert!(error
+            .to_string()
+            .contains("Unsupported IR schema version 0"));
+        assert!(AnalysisIr::from_json("{}").is_err());
+    }
+
+    #[test]
+    fn test_ir_round_trips_serde_field_attributes() {
+        let mut analyzer = CommandAnalyzer::new();
+        let commands = analyzer
+            .analyze_source(
+                r#"
+                #[derive(Serialize, Deserialize)]
+                pub struct Audit {
+                    pub by: String,
+                }
+
+                #[derive(Serialize, Deserialize)]
+                pub struct Doc {
+                    #[serde(flatten)]
+                    pub audit: Audit,
+                    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+                    pub tags: Vec<String>,
+                    #[serde(skip_deserializing)]
+                    pub revision: u32,
+                    #[serde(with = "time::serde::rfc3339")]
+                    pub created: OffsetDateTime,
+                }
+
+                #[tauri::command]
+                pub fn save(doc: Doc) {}
+            "#,
+            )
+            .unwrap();
+        let json = serde_json::to_string(&AnalysisIr::new(&commands, &analyzer)).unwrap();
+
+        let mut restored = CommandAnalyzer::new();
+        restored.load_ir(AnalysisIr::from_json(&json).unwrap());
+        let fields = |analyzer: &CommandAnalyzer| {
+            serde_json::to_value(&analyzer.get_discovered_structs()["Doc"].fields).unwrap()
+        };
+        assert_eq!(fields(&restored), fields(&analyzer));
+
+        let restored = &restored.get_discovered_structs()["Doc"].fields;
+        assert!(restored[0].flatten);
+        assert!(restored[1].omittable && restored[1].defaulted && restored[1].skip_serializing_if);
+        assert!(restored[2].skipped.is_some());
+        assert_eq!(
+            restored[3].serde_with.as_deref(),
+            Some("time::serde::rfc3339")
+        );
+    }
+    #[test]
+    fn test_ir_v1_document_is_rejected() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let path = temp_dir.path().join("analysis.json");
+        let mut analyzer = CommandAnalyzer::new();
+        let commands = analyzer.analyze_source(SOURCE).unwrap();
+        AnalysisIr::new(&commands, &analyzer).save(&path).unwrap();
+
+        // A version 1 document has no serde field attributes
+        let mut document: serde_json::Value =
+            serde_json::from_str(&fs::read_to_string(&path).unwrap()).unwrap();
+        document["schemaVersion"] = 1.into();
+        for struct_info in document["structs"].as_object_mut().unwrap().values_mut() {
+            for field in struct_info["fields"].as_array_mut().unwrap() {
+                let field = field.as_object_mut().unwrap();
+                for key in [
+                    "flatten",
+                    "omittable",
+                    "defaulted",
+                    "skipSerializingIf",
+                    "skipped",
+                    "serdeWith",
+                ] {
+                    field.remove(key);
+                }
+            }
+        }
+        fs::write(&path, serde_json::to_string_pretty(&document).unwrap()).unwrap();
+
+        let error = AnalysisIr::load(&path).err().unwrap().to_string();
+        assert!(error.contains("Unsupported IR schema version 1, expected 2"));
+        assert!(error.contains("Save the IR again"));
+
+        // Saving it again with this version round-trips
+        AnalysisIr::new(&commands, &analyzer).save(&path).unwrap();
+        let loaded = AnalysisIr::load(&path).unwrap();
+        assert_eq!(loaded.schema_version, IR_SCHEMA_VERSION);
+        assert_eq!(loaded.commands[0].name, "get_user");
+    }
+}
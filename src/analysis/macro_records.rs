@@ -0,0 +1,148 @@
+use serde::Deserialize;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// A command recorded at compile time by `#[typegen::export]` from the
+/// `tauri-typegen-macros` crate, with its types as the compiler saw them after
+/// macro expansion
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RecordedCommand {
+    pub name: String,
+    /// Module of the command within its crate, e.g. `commands::user`
+    #[serde(default)]
+    pub module_path: String,
+    #[serde(default)]
+    pub is_async: bool,
+    #[serde(default)]
+    pub parameters: Vec<RecordedParameter>,
+    /// `None` for commands returning `()`
+    #[serde(default)]
+    pub return_type: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RecordedParameter {
+    pub name: String,
+    pub rust_type: String,
+}
+
+impl RecordedCommand {
+    /// Load the records in `dir`, ordered by path. Each compilation of a crate writes
+    /// its records (`<module>.<command>.json`) into a subdirectory of its own. A missing
+    /// directory has no records, as the crate may not have been compiled yet.
+    pub fn load_dir(dir: &Path) -> Result<Vec<(PathBuf, Self)>, Box<dyn std::error::Error>> {
+        if !dir.is_dir() {
+            return Ok(Vec::new());
+        }
+        let mut paths = Vec::new();
+        for path in Self::entries(dir)? {
+            if path.is_dir() {
+                paths.extend(Self::entries(&path)?);
+            } else {
+                paths.push(path);
+            }
+        }
+        paths.retain(|path| path.extension().is_some_and(|ext| ext == "json"));
+        paths.sort();
+
+        paths
+            .into_iter()
+            .map(|path| {
+                let record = serde_json::from_str(&fs::read_to_string(&path)?)
+                    .map_err(|e| format!("Invalid macro record {}: {}", path.display(), e))?;
+                Ok((path, record))
+            })
+            .collect()
+    }
+
+    fn entries(dir: &Path) -> std::io::Result<Vec<PathBuf>> {
+        fs::read_dir(dir)?
+            .map(|entry| entry.map(|entry| entry.path()))
+            .collect()
+    }
+
+    /// Rust source of a command with the recorded signature, analyzed like a scanned one
+    pub fn to_source(&self) -> String {
+        let parameters: Vec<String> = self
+            .parameters
+            .iter()
+            .map(|param| format!("{}: {}", param.name, param.rust_type))
+            .collect();
+        let return_type = self
+            .return_type
+            .as_ref()
+            .map(|rust_type| format!(" -> {}", rust_type))
+            .unwrap_or_default();
+        format!(
+            "#[tauri::command]\npub {}fn {}({}){} {{\n    unimplemented!()\n}}\n",
+            if self.is_async { "async " } else { "" },
+            self.name,
+            parameters.join(", "),
+            return_type
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_record_to_source() {
+        let record: RecordedCommand = serde_json::from_str(
+            r#"{
+                "name": "get_user",
+                "isAsync": true,
+                "parameters": [{ "name": "id", "rustType": "u32" }],
+                "returnType": "Result < User , String >"
+            }"#,
+        )
+        .unwrap();
+        assert_eq!(
+            record.to_source(),
+            "#[tauri::command]\npub async fn get_user(id: u32) -> Result < User , String > {\n    unimplemented!()\n}\n"
+        );
+        assert!(syn::parse_file(&record.to_source()).is_ok());
+    }
+
+    #[test]
+    fn test_load_records_of_each_build() {
+        let dir = tempfile::TempDir::new().unwrap();
+        for (build, file, name) in [
+            ("app_lib-7", "users.get_user.json", "get_user"),
+            ("app_lib-7", "admin.get_user.json", "get_user"),
+            ("app-9", "ping.json", "ping"),
+        ] {
+            fs::create_dir_all(dir.path().join(build)).unwrap();
+            let module = file
+                .trim_end_matches(".json")
+                .rsplit_once('.')
+                .map(|(m, _)| m);
+            fs::write(
+                dir.path().join(build).join(file),
+                serde_json::json!({ "name": name, "modulePath": module.unwrap_or_default() })
+                    .to_string(),
+            )
+            .unwrap();
+        }
+
+        let records = RecordedCommand::load_dir(dir.path()).unwrap();
+
+        let loaded: Vec<(&str, &str)> = records
+            .iter()
+            .map(|(_, record)| (record.module_path.as_str(), record.name.as_str()))
+            .collect();
+        assert_eq!(
+            loaded,
+            [("", "ping"), ("admin", "get_user"), ("users", "get_user")]
+        );
+    }
+
+    #[test]
+    fn test_load_missing_dir() {
+        let records = RecordedCommand::load_dir(Path::new("/does/not/exist")).unwrap();
+        assert!(records.is_empty());
+    }
+}
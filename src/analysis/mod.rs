@@ -1,24 +1,46 @@
 pub mod ast_cache;
 pub mod channel_parser;
 pub mod command_parser;
+pub mod const_parser;
 pub mod dependency_graph;
+pub mod derive_checker;
 pub mod event_parser;
+pub mod impact;
+pub mod input_types;
+pub mod ipc_size;
+pub mod ir;
+pub mod macro_records;
 pub mod serde_parser;
 pub mod struct_parser;
 pub mod type_resolver;
+pub mod typegen_ignore;
+pub mod unsupported;
 pub mod validator_parser;
 
-use crate::models::{ChannelInfo, CommandInfo, EventInfo, StructInfo};
+use crate::interface::messages::{Locale, Message, MessageId};
+use crate::interface::output::{Phase, Verbosity, MAX_VERBOSITY};
+use crate::models::{ChannelInfo, CommandInfo, ConstInfo, EventInfo, StructInfo, TypeStructure};
 use std::collections::{HashMap, HashSet};
 use std::path::{Path, PathBuf};
 
 use ast_cache::AstCache;
 use channel_parser::ChannelParser;
 use command_parser::CommandParser;
+use const_parser::ConstParser;
 use dependency_graph::TypeDependencyGraph;
+use derive_checker::{DeriveChecker, DeriveDiagnostic, SerdeTrait};
 use event_parser::EventParser;
+use ipc_size::{IpcSizeLint, PayloadDiagnostic};
+use macro_records::RecordedCommand;
 use struct_parser::StructParser;
-use type_resolver::TypeResolver;
+use type_resolver::{CollectionKind, TypeResolver};
+use unsupported::{UnsupportedConstruct, UnsupportedKind};
+
+/// File name Rust source analyzed from a string is reported under
+pub const SOURCE_FILE_NAME: &str = "lib.rs";
+
+/// Name of the generated self-test command (see `selfTest`), left out of the bindings
+pub const SELF_TEST_COMMAND: &str = "__typegen_selftest";
 
 /// Analyzer that orchestrates all analysis sub-modules
 pub struct CommandAnalyzer {
@@ -30,6 +52,8 @@ pub struct CommandAnalyzer {
     channel_parser: ChannelParser,
     /// Event parser for extracting event emissions
     event_parser: EventParser,
+    /// Const parser for extracting exported constants
+    const_parser: ConstParser,
     /// Struct parser for extracting type definitions
     struct_parser: StructParser,
     /// Type resolver for Rust to TypeScript type mappings
@@ -40,6 +64,36 @@ pub struct CommandAnalyzer {
     discovered_structs: HashMap<String, StructInfo>,
     /// Discovered event emissions
     discovered_events: Vec<EventInfo>,
+    /// Discovered constants marked for export
+    discovered_constants: Vec<ConstInfo>,
+    /// Serde derive checks for types used by commands
+    derive_checker: DeriveChecker,
+    /// Types used by commands that lack serde derives Tauri requires
+    derive_diagnostics: Vec<DeriveDiagnostic>,
+    /// Fail analysis when derive diagnostics are found
+    strict_serde_derives: bool,
+    /// Check command payloads for data likely too large to send inline
+    ipc_size_lint: bool,
+    /// Command parameters and return types likely to exceed a practical IPC size
+    payload_diagnostics: Vec<PayloadDiagnostic>,
+    /// `Type.field` selectors of fields whose TypeScript type is configured
+    field_overrides: Vec<String>,
+    /// Directory of the command records written by `tauri-typegen-macros`
+    macro_records: Option<PathBuf>,
+    /// Source roots scanned besides the project path, with their include patterns
+    additional_source_paths: Vec<(String, Vec<String>)>,
+    /// Types and macros skipped because the bindings cannot represent them
+    unsupported_constructs: Vec<UnsupportedConstruct>,
+    /// Unions by name, reported when commands or their types use them
+    unions: HashMap<String, UnsupportedConstruct>,
+    /// Resolve every type defined in the scanned files, not only those commands use
+    all_types: bool,
+    /// Types resolved besides those commands and events use (e.g. settings stores)
+    root_types: Vec<String>,
+    /// Split structs used both ways into input and output types when their fields differ
+    separate_input_types: bool,
+    /// Language of the warnings printed during analysis
+    locale: Locale,
 }
 
 impl CommandAnalyzer {
@@ -49,11 +103,27 @@ impl CommandAnalyzer {
             command_parser: CommandParser::new(),
             channel_parser: ChannelParser::new(),
             event_parser: EventParser::new(),
+            const_parser: ConstParser::new(),
             struct_parser: StructParser::new(),
             type_resolver: TypeResolver::new(),
             dependency_graph: TypeDependencyGraph::new(),
             discovered_structs: HashMap::new(),
             discovered_events: Vec::new(),
+            discovered_constants: Vec::new(),
+            derive_checker: DeriveChecker::new(),
+            derive_diagnostics: Vec::new(),
+            strict_serde_derives: false,
+            ipc_size_lint: true,
+            payload_diagnostics: Vec::new(),
+            field_overrides: Vec::new(),
+            macro_records: None,
+            additional_source_paths: Vec::new(),
+            unsupported_constructs: Vec::new(),
+            unions: HashMap::new(),
+            all_types: false,
+            root_types: Vec::new(),
+            separate_input_types: false,
+            locale: Locale::default(),
         }
     }
 
@@ -65,6 +135,98 @@ impl CommandAnalyzer {
         }
     }
 
+    /// Add `serde(with)` mappings from configuration
+    pub fn add_serde_with_mappings(&mut self, mappings: &HashMap<String, String>) {
+        for (path, ts_type) in mappings {
+            self.type_resolver
+                .add_serde_with_mapping(path.clone(), ts_type.clone());
+        }
+    }
+
+    /// Fail analysis when types used by commands lack the serde derives Tauri requires
+    pub fn set_strict_serde_derives(&mut self, strict: bool) {
+        self.strict_serde_derives = strict;
+    }
+
+    /// Resolve `types` (and the types they reference) even if no command uses them
+    pub fn add_root_types<'a>(&mut self, types: impl IntoIterator<Item = &'a str>) {
+        self.root_types.extend(types.into_iter().map(String::from));
+    }
+
+    /// Leave out struct fields of zero-sized marker types like `PhantomData<T>`
+    pub fn set_skip_zero_sized_fields(&mut self, skip: bool) {
+        self.struct_parser.set_skip_zero_sized_fields(skip);
+    }
+
+    /// Check command payloads for byte buffers and deeply nested collections
+    pub fn set_ipc_size_lint(&mut self, enabled: bool) {
+        self.ipc_size_lint = enabled;
+    }
+
+    /// Type parameters with `XInput` structs where serde reads fields differently than it writes them
+    pub fn set_separate_input_types(&mut self, enabled: bool) {
+        self.separate_input_types = enabled;
+    }
+
+    /// Print the analysis warnings in `locale`
+    pub fn set_locale(&mut self, locale: Locale) {
+        self.locale = locale;
+        self.command_parser.set_locale(locale);
+        self.const_parser.set_locale(locale);
+        self.dependency_graph.locale = locale;
+    }
+
+    /// Add the commands recorded by `tauri-typegen-macros` in `dir` that the scan misses
+    pub fn set_macro_records(&mut self, dir: Option<&str>) {
+        self.macro_records = dir.map(PathBuf::from);
+    }
+
+    /// Scan the Rust files below `path` matching `include` (all when empty) along with
+    /// the project, e.g. the read-only source of a path dependency
+    pub fn add_source_path(&mut self, path: &str, include: &[String]) {
+        self.additional_source_paths
+            .push((path.to_string(), include.to_vec()));
+    }
+
+    /// Resolve every type defined in the scanned files, e.g. for a shared types package
+    pub fn set_all_types(&mut self, all_types: bool) {
+        self.all_types = all_types;
+    }
+
+    /// Add custom collection mappings from configuration (e.g. `"IndexMap": "map"`).
+    /// Entries with an unknown collection kind are ignored.
+    pub fn add_collection_mappings(&mut self, mappings: &HashMap<String, String>) {
+        for (rust_type, kind) in mappings {
+            if let Some(kind) = CollectionKind::parse(kind) {
+                self.type_resolver
+                    .add_collection_mapping(rust_type.clone(), kind);
+            }
+        }
+    }
+
+    /// Add custom union types from configuration, e.g. `["OneOf"]` maps `OneOf<A, B>` to `A | B`
+    pub fn add_union_types(&mut self, type_names: &[String]) {
+        for type_name in type_names {
+            self.type_resolver.add_union_type(type_name.clone());
+        }
+    }
+
+    /// Add parameter types injected by Tauri from configuration, e.g. `["tauri_plugin_store::Store"]`
+    pub fn add_injected_types(&mut self, type_paths: &[String]) {
+        self.command_parser
+            .add_injected_types(type_paths.iter().map(String::as_str));
+    }
+
+    /// Add `Type.field` selectors of fields with a configured TypeScript type
+    pub fn add_field_overrides<'a>(&mut self, selectors: impl IntoIterator<Item = &'a String>) {
+        self.field_overrides.extend(selectors.into_iter().cloned());
+    }
+
+    /// Limit how deep directories below the project path are scanned
+    pub fn set_max_scan_depth(&mut self, max_depth: Option<usize>) {
+        self.ast_cache.set_max_depth(max_depth);
+    }
+
     /// Analyze a complete project for Tauri commands and types
     pub fn analyze_project(
         &mut self,
@@ -78,10 +240,115 @@ impl CommandAnalyzer {
         &mut self,
         project_path: &str,
         verbose: bool,
+    ) -> Result<Vec<CommandInfo>, Box<dyn std::error::Error>> {
+        let level = if verbose { MAX_VERBOSITY } else { 0 };
+        self.analyze_project_with_verbosity(project_path, &Verbosity::new(level, Vec::new()))
+    }
+
+    /// Analyze a complete project for Tauri commands and types, logging the scan and
+    /// analysis phases as configured by `verbosity`
+    pub fn analyze_project_with_verbosity(
+        &mut self,
+        project_path: &str,
+        verbosity: &Verbosity,
     ) -> Result<Vec<CommandInfo>, Box<dyn std::error::Error>> {
         // Single pass: Parse all Rust files and cache ASTs
         self.ast_cache
-            .parse_and_cache_all_files(project_path, verbose)?;
+            .parse_and_cache_all_files_with_verbosity(project_path, verbosity)?;
+        for (path, include) in &self.additional_source_paths {
+            self.ast_cache
+                .parse_and_cache_additional_root(path, include, verbosity)?;
+        }
+        self.add_recorded_commands()?;
+
+        self.analyze_cached_files(verbosity)
+    }
+
+    /// Add the recorded commands that no parsed file defines, e.g. ones generated by
+    /// `macro_rules!`, each as a file of its own at the path of its record
+    fn add_recorded_commands(&mut self) -> Result<(), Box<dyn std::error::Error>> {
+        let Some(ref dir) = self.macro_records else {
+            return Ok(());
+        };
+        let records = RecordedCommand::load_dir(dir)?;
+
+        let defined: HashSet<String> = self
+            .ast_cache
+            .keys()
+            .filter_map(|path| self.ast_cache.get(path))
+            .flat_map(|parsed_file| &parsed_file.ast.items)
+            .filter_map(|item| match item {
+                syn::Item::Fn(func) => Some(func.sig.ident.to_string()),
+                _ => None,
+            })
+            .collect();
+
+        for (path, record) in records {
+            if defined.contains(&record.name) {
+                continue;
+            }
+            let ast = syn::parse_file(&record.to_source())
+                .map_err(|e| format!("Invalid macro record {}: {}", path.display(), e))?;
+            self.ast_cache
+                .insert(path.clone(), ast_cache::ParsedFile::new(ast, path));
+        }
+        Ok(())
+    }
+
+    /// Analyze Rust source given as a string, without reading any files, e.g. for
+    /// playgrounds and tests. The source is analyzed as a single file named
+    /// [`SOURCE_FILE_NAME`]; `mod` declarations are not followed.
+    pub fn analyze_source(
+        &mut self,
+        source: &str,
+    ) -> Result<Vec<CommandInfo>, Box<dyn std::error::Error>> {
+        let path = PathBuf::from(SOURCE_FILE_NAME);
+        let ast = syn::parse_file(source)?;
+        self.ast_cache
+            .insert(path.clone(), ast_cache::ParsedFile::new(ast, path));
+        self.analyze_cached_files(&Verbosity::default())
+    }
+
+    /// Take the commands, types, events and constants of a saved IR instead of
+    /// analyzing sources, e.g. to generate bindings where the Rust sources are missing
+    pub fn load_ir(&mut self, ir: ir::AnalysisIr) -> Vec<CommandInfo> {
+        for (name, struct_info) in ir.structs {
+            self.register_struct(name, struct_info);
+        }
+        self.discovered_events = ir.events;
+        self.discovered_constants = ir.constants;
+        ir.commands
+    }
+
+    /// Add a resolved struct to the discovered types and the dependency graph
+    fn register_struct(&mut self, name: String, struct_info: StructInfo) {
+        let mut type_dependencies = HashSet::new();
+        for field in &struct_info.fields {
+            self.extract_type_names(&field.rust_type, &mut type_dependencies);
+            for variant_field in &field.variant_fields {
+                self.extract_type_names(&variant_field.rust_type, &mut type_dependencies);
+            }
+        }
+        self.dependency_graph
+            .add_type_definition(name.clone(), PathBuf::from(&struct_info.file_path));
+        self.dependency_graph
+            .add_dependencies(name.clone(), type_dependencies);
+        self.dependency_graph
+            .add_resolved_type(name.clone(), struct_info.clone());
+        self.discovered_structs.insert(name, struct_info);
+    }
+
+    /// Extract commands, events, constants and the types they use from the parsed files
+    fn analyze_cached_files(
+        &mut self,
+        verbosity: &Verbosity,
+    ) -> Result<Vec<CommandInfo>, Box<dyn std::error::Error>> {
+        let summary = verbosity.enabled(Phase::Analysis, 1);
+        let details = verbosity.enabled(Phase::Analysis, 2);
+
+        // Every cached file is indexed again, along with its unsupported constructs
+        self.unsupported_constructs.clear();
+        self.unions.clear();
 
         // Extract commands from cached ASTs
         let file_paths: Vec<PathBuf> = self.ast_cache.keys().cloned().collect();
@@ -91,7 +358,7 @@ impl CommandAnalyzer {
         // Process each file - using functional style where possible
         for file_path in file_paths {
             if let Some(parsed_file) = self.ast_cache.get_cloned(&file_path) {
-                if verbose {
+                if details {
                     println!("🔍 Analyzing file: {}", parsed_file.path.display());
                 }
 
@@ -101,6 +368,7 @@ impl CommandAnalyzer {
                     parsed_file.path.as_path(),
                     &mut self.type_resolver,
                 )?;
+                file_commands.retain(|command| command.name != SELF_TEST_COMMAND);
 
                 // Extract channels for each command
                 for command in &mut file_commands {
@@ -145,40 +413,131 @@ impl CommandAnalyzer {
                 commands.extend(file_commands);
                 self.discovered_events.extend(file_events);
 
+                // Extract exported constants from this file's AST
+                self.discovered_constants.extend(
+                    self.const_parser
+                        .extract_constants_from_ast(&parsed_file.ast, parsed_file.path.as_path()),
+                );
+
                 // Build type definition index from this file
                 self.index_type_definitions(&parsed_file.ast, parsed_file.path.as_path());
             }
         }
 
-        if verbose {
+        // Invalidation targets must be commands, as their function names are linked
+        let command_names: HashSet<&str> = commands.iter().map(|cmd| cmd.name.as_str()).collect();
+        for command in &commands {
+            for query in &command.invalidates {
+                if !command_names.contains(query.as_str()) {
+                    eprintln!(
+                        "{}",
+                        Message::new(MessageId::UnknownInvalidatedCommand)
+                            .arg("command", &command.name)
+                            .arg(
+                                "location",
+                                format!("{}:{}", command.file_path, command.line_number)
+                            )
+                            .arg("query", query)
+                            .render(self.locale)
+                    );
+                }
+            }
+        }
+
+        for root_type in &self.root_types {
+            self.extract_type_names(root_type, &mut type_names_to_discover);
+        }
+
+        if self.all_types {
+            type_names_to_discover.extend(self.dependency_graph.type_definitions.keys().cloned());
+        }
+
+        if details {
             println!("🔍 Type names to discover: {:?}", type_names_to_discover);
         }
 
         // Lazy type resolution: Resolve types on demand using dependency graph
         self.resolve_types_lazily(&type_names_to_discover)?;
+        self.apply_field_overrides();
+        self.report_used_unions(&type_names_to_discover);
+
+        // Check that types crossing the IPC boundary derive Serialize/Deserialize
+        self.derive_diagnostics =
+            self.derive_checker
+                .check(&commands, &self.discovered_structs, |rust_type| {
+                    let mut names = HashSet::new();
+                    self.extract_type_names(rust_type, &mut names);
+                    names
+                });
+        for diagnostic in &self.derive_diagnostics {
+            eprintln!(
+                "{}",
+                Message::new(MessageId::MissingSerdeDerive)
+                    .arg("diagnostic", diagnostic)
+                    .render(self.locale)
+            );
+        }
+        if self.strict_serde_derives && !self.derive_diagnostics.is_empty() {
+            return Err(format!(
+                "{} type(s) used by commands lack serde derives required by Tauri (strictSerdeDerives is enabled)",
+                self.derive_diagnostics.len()
+            )
+            .into());
+        }
+
+        if self.ipc_size_lint {
+            self.payload_diagnostics = IpcSizeLint::new(&self.discovered_structs).check(&commands);
+        }
+
+        if self.separate_input_types {
+            let changed = input_types::split_input_types(
+                &mut commands,
+                &self.discovered_events,
+                &mut self.discovered_structs,
+            );
+            for name in changed {
+                let struct_info = self.discovered_structs[&name].clone();
+                self.register_struct(name, struct_info);
+            }
+        }
 
-        if verbose {
+        if summary {
             println!(
                 "🏗️  Discovered {} structs total",
                 self.discovered_structs.len()
             );
-            for (name, info) in &self.discovered_structs {
-                println!("  - {}: {} fields", name, info.fields.len());
+            if details {
+                for (name, info) in &self.discovered_structs {
+                    println!("  - {}: {} fields", name, info.fields.len());
+                }
             }
             println!(
                 "📡 Discovered {} events total",
                 self.discovered_events.len()
             );
-            for event in &self.discovered_events {
-                println!("  - '{}': {}", event.event_name, event.payload_type);
+            if details {
+                for event in &self.discovered_events {
+                    println!("  - '{}': {}", event.event_name, event.payload_type);
+                }
+            }
+            println!(
+                "📌 Discovered {} exported constants total",
+                self.discovered_constants.len()
+            );
+            if details {
+                for constant in &self.discovered_constants {
+                    println!("  - {}: {}", constant.name, constant.rust_type);
+                }
             }
             let all_channels = self.get_all_discovered_channels(&commands);
             println!("📞 Discovered {} channels total", all_channels.len());
-            for channel in &all_channels {
-                println!(
-                    "  - '{}' in {}: {}",
-                    channel.parameter_name, channel.command_name, channel.message_type
-                );
+            if details {
+                for channel in &all_channels {
+                    println!(
+                        "  - '{}' in {}: {}",
+                        channel.parameter_name, channel.command_name, channel.message_type
+                    );
+                }
             }
         }
 
@@ -205,6 +564,12 @@ impl CommandAnalyzer {
                     )?;
                     self.discovered_events.extend(file_events);
 
+                    // Extract exported constants
+                    self.discovered_constants.extend(
+                        self.const_parser
+                            .extract_constants_from_ast(&parsed_file.ast, path_buf.as_path()),
+                    );
+
                     // Extract commands
                     let mut commands = self.command_parser.extract_commands_from_ast(
                         &parsed_file.ast,
@@ -240,9 +605,49 @@ impl CommandAnalyzer {
         }
     }
 
+    /// Replace the type of overridden fields with a reference to their selector, which
+    /// the type visitors resolve to the configured TypeScript type. The field stays
+    /// optional if it was an `Option`.
+    fn apply_field_overrides(&mut self) {
+        for selector in &self.field_overrides {
+            let Some((type_name, field_name)) = selector.split_once('.') else {
+                continue;
+            };
+            let field = self
+                .discovered_structs
+                .get_mut(type_name)
+                .filter(|struct_info| !struct_info.is_enum)
+                .and_then(|struct_info| {
+                    struct_info
+                        .fields
+                        .iter_mut()
+                        .find(|field| field.name == field_name)
+                });
+
+            match field {
+                Some(field) => {
+                    let reference = TypeStructure::Custom(selector.clone());
+                    field.type_structure = match field.type_structure {
+                        TypeStructure::Optional(_) => TypeStructure::Optional(Box::new(reference)),
+                        _ => reference,
+                    };
+                }
+                None => eprintln!(
+                    "{}",
+                    Message::new(MessageId::UnmatchedFieldOverride)
+                        .arg("selector", selector)
+                        .render(self.locale)
+                ),
+            }
+        }
+    }
+
     /// Build an index of type definitions from an AST
+    #[allow(clippy::collapsible_match)]
     fn index_type_definitions(&mut self, ast: &syn::File, file_path: &Path) {
         for item in &ast.items {
+            self.derive_checker.index_item(item, file_path);
+            self.find_unsupported_construct(item, file_path);
             match item {
                 syn::Item::Struct(item_struct) => {
                     if self.struct_parser.should_include_struct(item_struct) {
@@ -263,6 +668,119 @@ impl CommandAnalyzer {
         }
     }
 
+    /// Record a serde type or macro the bindings cannot cover. Unions are recorded
+    /// once it is known whether anything uses them.
+    fn find_unsupported_construct(&mut self, item: &syn::Item, file_path: &Path) {
+        let is_generic = |generics: &syn::Generics| {
+            generics
+                .params
+                .iter()
+                .any(|param| !matches!(param, syn::GenericParam::Lifetime(_)))
+        };
+        let (ident, kind) = match item {
+            syn::Item::Struct(item_struct)
+                if self.struct_parser.should_include_struct(item_struct)
+                    && !self.is_configured_type(&item_struct.ident, &item_struct.generics) =>
+            {
+                if is_generic(&item_struct.generics) {
+                    (&item_struct.ident, UnsupportedKind::GenericType)
+                } else if matches!(item_struct.fields, syn::Fields::Unnamed(_))
+                    && self
+                        .struct_parser
+                        .parse_struct(item_struct, file_path, &mut self.type_resolver)
+                        .is_none()
+                {
+                    (&item_struct.ident, UnsupportedKind::TupleStruct)
+                } else {
+                    return;
+                }
+            }
+            syn::Item::Enum(item_enum)
+                if self.struct_parser.should_include_enum(item_enum)
+                    && is_generic(&item_enum.generics)
+                    && !self.is_configured_type(&item_enum.ident, &item_enum.generics) =>
+            {
+                (&item_enum.ident, UnsupportedKind::GenericType)
+            }
+            syn::Item::Union(item_union) => {
+                let name = item_union.ident.to_string();
+                let construct = UnsupportedConstruct::new(
+                    name.clone(),
+                    UnsupportedKind::Union,
+                    file_path,
+                    item_union.ident.span().start().line,
+                );
+                self.unions.insert(name, construct);
+                return;
+            }
+            syn::Item::Macro(item_macro) if item_macro.mac.path.is_ident("macro_rules") => {
+                let Some(ident) = &item_macro.ident else {
+                    return;
+                };
+                let body = item_macro.mac.tokens.to_string();
+                let generates_bindings = body.contains("tauri :: command")
+                    || body.contains("Serialize")
+                    || body.contains("Deserialize");
+                if !generates_bindings {
+                    return;
+                }
+                self.unsupported_constructs.push(UnsupportedConstruct::new(
+                    format!("{}!", ident),
+                    UnsupportedKind::MacroItems,
+                    file_path,
+                    ident.span().start().line,
+                ));
+                return;
+            }
+            _ => return,
+        };
+
+        self.unsupported_constructs.push(UnsupportedConstruct::new(
+            ident.to_string(),
+            kind,
+            file_path,
+            ident.span().start().line,
+        ));
+    }
+
+    /// Whether a type definition is covered by a configured type mapping, union type or
+    /// collection mapping instead of being generated
+    fn is_configured_type(&self, ident: &syn::Ident, generics: &syn::Generics) -> bool {
+        let name = ident.to_string();
+        let generic_type = format!("{}<{}>", name, vec!["T"; generics.params.len()].join(", "));
+        self.type_resolver.is_union_type(&name)
+            || self.type_resolver.collection_kind(&name).is_some()
+            || self.type_resolver.find_type_mapping(&name).is_some()
+            || self
+                .type_resolver
+                .find_type_mapping(&generic_type)
+                .is_some()
+    }
+
+    /// Record the unions used by commands, events or the types they use, then order all
+    /// unsupported constructs by location
+    fn report_used_unions(&mut self, command_types: &HashSet<String>) {
+        let mut used_types = command_types.clone();
+        for struct_info in self.discovered_structs.values() {
+            for field in &struct_info.fields {
+                self.extract_type_names(&field.rust_type, &mut used_types);
+                for variant_field in &field.variant_fields {
+                    self.extract_type_names(&variant_field.rust_type, &mut used_types);
+                }
+            }
+        }
+
+        let used_unions = self
+            .unions
+            .iter()
+            .filter(|(name, _)| used_types.contains(*name))
+            .map(|(_, construct)| construct.clone());
+        self.unsupported_constructs.extend(used_unions);
+        self.unsupported_constructs.sort_by(|a, b| {
+            (&a.file_path, a.line_number, &a.name).cmp(&(&b.file_path, b.line_number, &b.name))
+        });
+    }
+
     /// Lazily resolve types using the dependency graph
     fn resolve_types_lazily(
         &mut self,
@@ -296,6 +814,12 @@ impl CommandAnalyzer {
                         let mut type_dependencies = HashSet::new();
                         for field in &struct_info.fields {
                             self.extract_type_names(&field.rust_type, &mut type_dependencies);
+                            for variant_field in &field.variant_fields {
+                                self.extract_type_names(
+                                    &variant_field.rust_type,
+                                    &mut type_dependencies,
+                                );
+                            }
                         }
 
                         // Add dependencies to the resolution queue
@@ -325,6 +849,7 @@ impl CommandAnalyzer {
     }
 
     /// Extract a specific type from a cached AST
+    #[allow(clippy::collapsible_match)]
     fn extract_type_from_ast(
         &mut self,
         ast: &syn::File,
@@ -348,7 +873,11 @@ impl CommandAnalyzer {
                     if item_enum.ident == type_name
                         && self.struct_parser.should_include_enum(item_enum)
                     {
-                        return self.struct_parser.parse_enum(item_enum, file_path);
+                        return self.struct_parser.parse_enum(
+                            item_enum,
+                            file_path,
+                            &mut self.type_resolver,
+                        );
                     }
                 }
                 _ => {}
@@ -366,6 +895,18 @@ impl CommandAnalyzer {
     fn extract_type_names_recursive(&self, rust_type: &str, type_names: &mut HashSet<String>) {
         let rust_type = rust_type.trim();
 
+        // Handle configured type mappings - pass-through patterns like `Arc<*>` resolve to
+        // the wrapped type, all other mapped types need no generated definitions
+        if let Some(mapping) = self.type_resolver.find_type_mapping(rust_type) {
+            if mapping.is_pass_through() {
+                if let Some(captured) = mapping.captures.first() {
+                    self.extract_type_names_recursive(captured, type_names);
+                }
+            }
+            return;
+        }
+        let rust_type = TypeResolver::strip_std_path(rust_type);
+
         // Handle Result<T, E> - extract both T and E
         if rust_type.starts_with("Result<") {
             if let Some(inner) = rust_type
@@ -441,6 +982,25 @@ impl CommandAnalyzer {
             return;
         }
 
+        // Handle configured collection and union types like IndexMap<K, V> or Either<A, B>
+        // - extract generic arguments
+        if self.type_resolver.collection_kind(rust_type).is_some()
+            || self.type_resolver.is_union_type(rust_type)
+        {
+            if let Some(inner) = rust_type
+                .find('<')
+                .and_then(|open| rust_type[open + 1..].strip_suffix('>'))
+            {
+                for arg in TypeResolver::split_generic_args(inner) {
+                    self.extract_type_names_recursive(
+                        TypeResolver::unwrap_array_type(&arg),
+                        type_names,
+                    );
+                }
+            }
+            return;
+        }
+
         // Handle tuple types like (T, U, V)
         if rust_type.starts_with('(') && rust_type.ends_with(')') && rust_type != "()" {
             let inner = &rust_type[1..rust_type.len() - 1];
@@ -479,6 +1039,26 @@ impl CommandAnalyzer {
         &self.discovered_events
     }
 
+    /// Get types used by commands that lack serde derives Tauri requires
+    pub fn get_derive_diagnostics(&self) -> &[DeriveDiagnostic] {
+        &self.derive_diagnostics
+    }
+
+    /// Get the command payloads likely to exceed a practical IPC size
+    pub fn get_payload_diagnostics(&self) -> &[PayloadDiagnostic] {
+        &self.payload_diagnostics
+    }
+
+    /// Get the types and macros skipped because the bindings cannot represent them
+    pub fn get_unsupported_constructs(&self) -> &[UnsupportedConstruct] {
+        &self.unsupported_constructs
+    }
+
+    /// Get discovered constants marked with `#[typegen(export)]`
+    pub fn get_discovered_constants(&self) -> &[ConstInfo] {
+        &self.discovered_constants
+    }
+
     /// Get reference to the type resolver
     pub fn get_type_resolver(&self) -> std::cell::RefCell<&TypeResolver> {
         std::cell::RefCell::new(&self.type_resolver)
@@ -513,6 +1093,12 @@ impl CommandAnalyzer {
         &self.dependency_graph
     }
 
+    /// Whether a type defined in the project derives a serde trait, `None` for types
+    /// whose definition was not analyzed (external types, types loaded from an IR)
+    pub fn type_derives(&self, type_name: &str, serde_trait: SerdeTrait) -> Option<bool> {
+        self.derive_checker.derives(type_name, serde_trait)
+    }
+
     /// Sort types topologically to ensure dependencies are declared before being used
     pub fn topological_sort_types(&self, types: &HashSet<String>) -> Vec<String> {
         self.dependency_graph.topological_sort_types(types)
@@ -527,6 +1113,36 @@ impl CommandAnalyzer {
     pub fn generate_dot_graph(&self, commands: &[CommandInfo]) -> String {
         self.dependency_graph.generate_dot_graph(commands)
     }
+
+    /// Generate a text-based visualization of the part of the dependency graph selected by `filter`
+    pub fn visualize_dependencies_filtered(
+        &self,
+        commands: &[CommandInfo],
+        filter: &dependency_graph::VizFilter,
+    ) -> String {
+        self.dependency_graph
+            .visualize_dependencies_filtered(commands, filter)
+    }
+
+    /// Render the part of the dependency graph selected by `filter` in the given format
+    pub fn render_dependency_graph(
+        &self,
+        commands: &[CommandInfo],
+        format: dependency_graph::VizFormat,
+        filter: &dependency_graph::VizFilter,
+    ) -> String {
+        self.dependency_graph.render(commands, format, filter)
+    }
+
+    /// Generate a DOT graph of the part of the dependency graph selected by `filter`
+    pub fn generate_dot_graph_filtered(
+        &self,
+        commands: &[CommandInfo],
+        filter: &dependency_graph::VizFilter,
+    ) -> String {
+        self.dependency_graph
+            .generate_dot_graph_filtered(commands, filter)
+    }
 }
 
 impl Default for CommandAnalyzer {
@@ -583,6 +1199,54 @@ mod tests {
             assert!(types.contains("User"));
         }
 
+        #[test]
+        fn test_extract_collection_crate_type() {
+            let analyzer = analyzer();
+            let mut types = HashSet::new();
+            analyzer.extract_type_names("IndexMap<UserId, SmallVec<[Product; _]>>", &mut types);
+            assert_eq!(types.len(), 2);
+            assert!(types.contains("UserId"));
+            assert!(types.contains("Product"));
+        }
+
+        #[test]
+        fn test_extract_union_type() {
+            let analyzer = analyzer();
+            let mut types = HashSet::new();
+            analyzer.extract_type_names("Either<User, Vec<Product>>", &mut types);
+            assert_eq!(types.len(), 2);
+            assert!(types.contains("User"));
+            assert!(types.contains("Product"));
+        }
+
+        #[test]
+        fn test_extract_type_mapping_patterns() {
+            let mut analyzer = analyzer();
+            let mut mappings = HashMap::new();
+            mappings.insert("Arc<*>".to_string(), "*".to_string());
+            mappings.insert("DateTime<*>".to_string(), "string".to_string());
+            analyzer.add_type_mappings(&mappings);
+
+            let mut types = HashSet::new();
+            analyzer.extract_type_names("Arc<User>", &mut types);
+            analyzer.extract_type_names("Option<DateTime<Utc>>", &mut types);
+            assert_eq!(types.len(), 1);
+            assert!(types.contains("User"));
+        }
+
+        #[test]
+        fn test_extract_custom_collection_mapping() {
+            let mut analyzer = analyzer();
+            let mut mappings = HashMap::new();
+            mappings.insert("ImVector".to_string(), "array".to_string());
+            analyzer.add_collection_mappings(&mappings);
+
+            let mut types = HashSet::new();
+            analyzer.extract_type_names("ImVector<User>", &mut types);
+            assert_eq!(types.len(), 1);
+            assert!(types.contains("User"));
+        }
+
         #[test]
         fn test_extract_vec_type() {
             let analyzer = analyzer();
@@ -990,6 +1654,113 @@ mod tests {
         }
     }
 
+    mod unsupported_constructs {
+        use super::*;
+
+        #[test]
+        fn test_reports_skipped_constructs() {
+            let mut analyzer = analyzer();
+            analyzer.add_union_types(&["OneOf".to_string()]);
+            analyzer
+                .analyze_source(
+                    r#"
+                    #[derive(Serialize)]
+                    pub struct Page<'a, T> { pub items: Vec<T>, pub cursor: &'a str }
+
+                    #[derive(Serialize)]
+                    pub struct Point(pub f64, pub f64);
+
+                    #[derive(Serialize)]
+                    pub struct Tags(pub Vec<String>);
+
+                    pub union Bits { pub i: u32, pub f: f32 }
+
+                    pub union Unused { pub i: u32 }
+
+                    #[derive(Serialize)]
+                    #[serde(untagged)]
+                    pub enum OneOf<A, B> { A(A), B(B) }
+
+                    macro_rules! make_command {
+                        ($name:ident) => {
+                            #[tauri::command]
+                            pub fn $name() {}
+                        };
+                    }
+
+                    macro_rules! square {
+                        ($x:expr) => { $x * $x };
+                    }
+
+                    #[tauri::command]
+                    pub fn get_bits(tags: Tags) -> Bits { unimplemented!() }
+                "#,
+                )
+                .unwrap();
+
+            let reported: Vec<(&str, UnsupportedKind, usize)> = analyzer
+                .get_unsupported_constructs()
+                .iter()
+                .map(|construct| {
+                    (
+                        construct.name.as_str(),
+                        construct.kind,
+                        construct.line_number,
+                    )
+                })
+                .collect();
+            assert_eq!(
+                reported,
+                [
+                    ("Page", UnsupportedKind::GenericType, 3),
+                    ("Point", UnsupportedKind::TupleStruct, 6),
+                    ("Bits", UnsupportedKind::Union, 11),
+                    ("make_command!", UnsupportedKind::MacroItems, 19),
+                ]
+            );
+            assert_eq!(
+                analyzer.get_unsupported_constructs()[2].to_string(),
+                "Bits (lib.rs:11): unions are not supported, no type is generated for them"
+            );
+        }
+    }
+
+    mod ipc_size_lint {
+        use super::*;
+        use crate::analysis::ipc_size::PayloadKind;
+
+        const SOURCE: &str = r#"
+            #[derive(serde::Serialize)]
+            pub struct Export {
+                pub name: String,
+                pub contents: Vec<u8>,
+            }
+
+            #[tauri::command]
+            pub fn export_file(name: String) -> Export { unimplemented!() }
+        "#;
+
+        #[test]
+        fn test_reports_large_payloads() {
+            let mut analyzer = CommandAnalyzer::new();
+            analyzer.analyze_source(SOURCE).unwrap();
+
+            let diagnostics = analyzer.get_payload_diagnostics();
+            assert_eq!(diagnostics.len(), 1);
+            assert_eq!(diagnostics[0].command_name, "export_file");
+            assert_eq!(diagnostics[0].field.as_deref(), Some("Export.contents"));
+            assert_eq!(diagnostics[0].kind, PayloadKind::ByteBuffer);
+        }
+
+        #[test]
+        fn test_lint_can_be_disabled() {
+            let mut analyzer = CommandAnalyzer::new();
+            analyzer.set_ipc_size_lint(false);
+            analyzer.analyze_source(SOURCE).unwrap();
+            assert!(analyzer.get_payload_diagnostics().is_empty());
+        }
+    }
+
     mod visualization {
         use super::*;
 
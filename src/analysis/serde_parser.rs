@@ -11,9 +11,9 @@ impl SerdeParser {
         Self
     }
 
-    /// Parse struct-level serde attributes (e.g., rename_all)
+    /// Parse struct-level serde attributes (e.g., rename_all, deny_unknown_fields)
     pub fn parse_struct_serde_attrs(&self, attrs: &[Attribute]) -> SerdeStructAttributes {
-        let mut result = SerdeStructAttributes { rename_all: None };
+        let mut result = SerdeStructAttributes::default();
 
         for attr in attrs {
             if attr.path().is_ident("serde") {
@@ -24,6 +24,32 @@ impl SerdeParser {
                     if let Some(convention) = self.parse_rename_all(&tokens_str) {
                         result.rename_all = Some(convention);
                     }
+
+                    // Parse tag = "field" and content = "field" (enum representations)
+                    if let Some(tag) = self.parse_string_value(&tokens_str, "tag") {
+                        result.tag = Some(tag);
+                    }
+                    if let Some(content) = self.parse_string_value(&tokens_str, "content") {
+                        result.content = Some(content);
+                    }
+
+                    // Check for untagged flag
+                    if tokens_str.split(',').any(|part| part.trim() == "untagged") {
+                        result.untagged = true;
+                    }
+
+                    // Check for deny_unknown_fields flag
+                    if tokens_str
+                        .split(',')
+                        .any(|part| part.trim() == "deny_unknown_fields")
+                    {
+                        result.deny_unknown_fields = true;
+                    }
+
+                    // Check for default flag
+                    if Self::has_default(&tokens_str) {
+                        result.default = true;
+                    }
                 }
             }
         }
@@ -36,6 +62,12 @@ impl SerdeParser {
         let mut result = SerdeFieldAttributes {
             rename: None,
             skip: false,
+            flatten: false,
+            skip_serializing: false,
+            skip_deserializing: false,
+            default: false,
+            skip_serializing_if: false,
+            with: None,
         };
 
         for attr in attrs {
@@ -43,22 +75,74 @@ impl SerdeParser {
                 if let Ok(tokens) = syn::parse2::<syn::MetaList>(attr.meta.to_token_stream()) {
                     let tokens_str = tokens.tokens.to_string();
 
-                    // Check for skip flag
-                    if tokens_str.contains("skip") && !tokens_str.contains("skip_serializing") {
-                        result.skip = true;
+                    // Check for skip flags, which may apply to one direction only
+                    for part in tokens_str.split(',').map(str::trim) {
+                        match part {
+                            "skip" => result.skip = true,
+                            "skip_serializing" => result.skip_serializing = true,
+                            "skip_deserializing" => result.skip_deserializing = true,
+                            _ => {}
+                        }
+                    }
+
+                    // Check for flatten flag
+                    if tokens_str.split(',').any(|part| part.trim() == "flatten") {
+                        result.flatten = true;
+                    }
+
+                    // Check for default flag and skip_serializing_if predicate
+                    if Self::has_default(&tokens_str) {
+                        result.default = true;
+                    }
+                    if tokens_str
+                        .split(',')
+                        .any(|part| part.trim().starts_with("skip_serializing_if"))
+                    {
+                        result.skip_serializing_if = true;
                     }
 
                     // Parse rename = "value"
                     if let Some(rename) = self.parse_rename(&tokens_str) {
                         result.rename = Some(rename);
                     }
+
+                    // Parse with = "module", or the serialize_with function the output follows
+                    if let Some(with) = Self::parse_with(&tokens_str) {
+                        result.with = Some(with);
+                    }
                 }
             }
         }
 
+        // Skipped in both directions, the field is not part of the type at all
+        if result.skip_serializing && result.skip_deserializing {
+            result.skip = true;
+        }
+
         result
     }
 
+    /// Whether serde attribute tokens hold `default` or `default = "path"`
+    fn has_default(tokens: &str) -> bool {
+        tokens.split(',').any(|part| {
+            let part = part.trim();
+            part == "default" || part.starts_with("default =") || part.starts_with("default=")
+        })
+    }
+
+    /// Path of `with = "..."`, else `serialize_with = "..."`, else `deserialize_with = "..."`
+    fn parse_with(tokens: &str) -> Option<String> {
+        let value = |key: &str| {
+            tokens.split(',').find_map(|part| {
+                let (name, value) = part.split_once('=')?;
+                (name.trim() == key).then(|| value.trim().trim_matches('"').to_string())
+            })
+        };
+        value("with")
+            .or_else(|| value("serialize_with"))
+            .or_else(|| value("deserialize_with"))
+    }
+
     /// Parse rename_all value like "camelCase", "snake_case", "PascalCase", etc. to
     /// find a matching `serde_rename_rule::RenameRule`.
     fn parse_rename_all(&self, tokens: &str) -> Option<RenameRule> {
@@ -79,6 +163,29 @@ impl SerdeParser {
         None
     }
 
+    /// Parse a `key = "value"` pair where `key` is a standalone identifier
+    /// (so `tag` does not match inside `untagged`)
+    fn parse_string_value(&self, tokens: &str, key: &str) -> Option<String> {
+        let mut search_start = 0;
+        while let Some(pos) = tokens[search_start..].find(key) {
+            let abs_pos = search_start + pos;
+            search_start = abs_pos + key.len();
+
+            let is_ident_char = |c: char| c.is_alphanumeric() || c == '_';
+            let before_ok = !tokens[..abs_pos].ends_with(is_ident_char);
+            let after = tokens[search_start..].trim_start();
+            if !before_ok || !after.starts_with('=') {
+                continue;
+            }
+
+            let after_eq = after[1..].trim_start();
+            let value = after_eq.strip_prefix('"')?;
+            let quote_end = value.find('"')?;
+            return Some(value[..quote_end].to_string());
+        }
+        None
+    }
+
     /// Parse rename value from field attribute
     fn parse_rename(&self, tokens: &str) -> Option<String> {
         // Look for "rename" but not "rename_all"
@@ -123,6 +230,12 @@ impl Default for SerdeParser {
 #[derive(Debug, Default, Clone)]
 pub struct SerdeStructAttributes {
     pub rename_all: Option<RenameRule>,
+    pub tag: Option<String>,
+    pub content: Option<String>,
+    pub untagged: bool,
+    pub deny_unknown_fields: bool,
+    /// Serde default flag: every field is filled in when absent
+    pub default: bool,
 }
 
 /// Field-level serde attributes
@@ -130,6 +243,18 @@ pub struct SerdeStructAttributes {
 pub struct SerdeFieldAttributes {
     pub rename: Option<String>,
     pub skip: bool,
+    /// Serde `skip_serializing`: left out of the output, read from the input
+    pub skip_serializing: bool,
+    /// Serde `skip_deserializing`: written to the output, ignored in the input
+    pub skip_deserializing: bool,
+    /// Serde flatten flag: the field's own fields are serialized in its parent
+    pub flatten: bool,
+    /// Serde default (`default` or `default = "path"`): filled in when absent
+    pub default: bool,
+    /// Serde `skip_serializing_if`: left out of the output when the predicate holds
+    pub skip_serializing_if: bool,
+    /// Serde `with`, `serialize_with` or `deserialize_with`: path converting the field
+    pub with: Option<String>,
 }
 #[cfg(test)]
 mod tests {
@@ -237,6 +362,43 @@ mod tests {
         assert!(result.rename_all.is_none());
     }
 
+    #[test]
+    fn test_parse_struct_serde_attrs_with_tag_and_content() {
+        let parser = SerdeParser::new();
+        let attrs: Vec<Attribute> = vec![parse_quote!(#[serde(tag = "type", content = "data")])];
+
+        let result = parser.parse_struct_serde_attrs(&attrs);
+        assert_eq!(result.tag, Some("type".to_string()));
+        assert_eq!(result.content, Some("data".to_string()));
+        assert!(!result.untagged);
+    }
+
+    #[test]
+    fn test_parse_struct_serde_attrs_untagged_is_not_tag() {
+        let parser = SerdeParser::new();
+        let attrs: Vec<Attribute> = vec![parse_quote!(#[serde(untagged)])];
+
+        let result = parser.parse_struct_serde_attrs(&attrs);
+        assert!(result.tag.is_none());
+        assert!(result.content.is_none());
+        assert!(result.untagged);
+    }
+
+    #[test]
+    fn test_parse_struct_serde_attrs_deny_unknown_fields() {
+        let parser = SerdeParser::new();
+        let attrs: Vec<Attribute> =
+            vec![parse_quote!(#[serde(rename_all = "camelCase", deny_unknown_fields)])];
+
+        let result = parser.parse_struct_serde_attrs(&attrs);
+        assert!(result.deny_unknown_fields);
+        assert!(
+            !parser
+                .parse_struct_serde_attrs(&[parse_quote!(#[serde(rename_all = "camelCase")])])
+                .deny_unknown_fields
+        );
+    }
+
     #[test]
     fn test_parse_field_serde_attrs_with_rename() {
         let parser = SerdeParser::new();
@@ -257,6 +419,39 @@ mod tests {
         assert!(result.rename.is_none());
     }
 
+    #[test]
+    fn test_parse_field_serde_attrs_with_flatten() {
+        let parser = SerdeParser::new();
+        let attrs: Vec<Attribute> = vec![parse_quote!(#[serde(flatten)])];
+        assert!(parser.parse_field_serde_attrs(&attrs).flatten);
+
+        let attrs: Vec<Attribute> = vec![parse_quote!(#[serde(rename = "flatten")])];
+        assert!(!parser.parse_field_serde_attrs(&attrs).flatten);
+    }
+
+    #[test]
+    fn test_parse_field_serde_attrs_with_default() {
+        let parser = SerdeParser::new();
+        let attrs: Vec<Attribute> = vec![parse_quote!(#[serde(default)])];
+        assert!(parser.parse_field_serde_attrs(&attrs).default);
+
+        let attrs: Vec<Attribute> = vec![parse_quote!(
+            #[serde(default = "default_tags", skip_serializing_if = "Vec::is_empty")]
+        )];
+        let result = parser.parse_field_serde_attrs(&attrs);
+        assert!(result.default);
+        assert!(result.skip_serializing_if);
+        assert!(!result.skip);
+
+        let attrs: Vec<Attribute> = vec![parse_quote!(#[serde(rename = "default")])];
+        assert!(!parser.parse_field_serde_attrs(&attrs).default);
+        assert!(
+            parser
+                .parse_struct_serde_attrs(&[parse_quote!(#[serde(default)])])
+                .default
+        );
+    }
+
     #[test]
     fn test_parse_field_serde_attrs_skip_serializing_not_skip() {
         let parser = SerdeParser::new();
@@ -267,6 +462,52 @@ mod tests {
         assert!(!result.skip);
     }
 
+    #[test]
+    fn test_parse_field_serde_attrs_skip_one_direction() {
+        let parser = SerdeParser::new();
+
+        let result = parser.parse_field_serde_attrs(&[parse_quote!(#[serde(skip_serializing)])]);
+        assert!(result.skip_serializing);
+        assert!(!result.skip_deserializing);
+
+        let result = parser.parse_field_serde_attrs(&[parse_quote!(#[serde(skip_deserializing)])]);
+        assert!(result.skip_deserializing);
+        assert!(!result.skip);
+
+        // Skipping both ways is a full skip
+        let result = parser.parse_field_serde_attrs(&[parse_quote!(
+            #[serde(skip_serializing, skip_deserializing)]
+        )]);
+        assert!(result.skip);
+
+        let result = parser
+            .parse_field_serde_attrs(&[parse_quote!(#[serde(skip_serializing_if = "is_zero")])]);
+        assert!(!result.skip_serializing);
+    }
+
+    #[test]
+    fn test_parse_field_serde_attrs_with() {
+        let parser = SerdeParser::new();
+
+        let result = parser.parse_field_serde_attrs(&[parse_quote!(
+            #[serde(with = "chrono::serde::ts_seconds")]
+        )]);
+        assert_eq!(result.with.as_deref(), Some("chrono::serde::ts_seconds"));
+
+        // The output decides the generated type
+        let result = parser.parse_field_serde_attrs(&[parse_quote!(
+            #[serde(deserialize_with = "from_hex", serialize_with = "to_hex")]
+        )]);
+        assert_eq!(result.with.as_deref(), Some("to_hex"));
+
+        let result = parser
+            .parse_field_serde_attrs(&[parse_quote!(#[serde(deserialize_with = "from_hex")])]);
+        assert_eq!(result.with.as_deref(), Some("from_hex"));
+
+        let result = parser.parse_field_serde_attrs(&[parse_quote!(#[serde(rename = "with")])]);
+        assert!(result.with.is_none());
+    }
+
     #[test]
     fn test_parse_field_serde_attrs_multiple() {
         let parser = SerdeParser::new();
@@ -1,7 +1,8 @@
+use crate::analysis::command_parser::CommandParser;
 use crate::analysis::serde_parser::SerdeParser;
 use crate::analysis::type_resolver::TypeResolver;
 use crate::analysis::validator_parser::ValidatorParser;
-use crate::models::{FieldInfo, StructInfo};
+use crate::models::{FieldInfo, SkipDirection, StructInfo, TypeStructure};
 use quote::ToTokens;
 use std::path::Path;
 use syn::{Attribute, ItemEnum, ItemStruct, Type, Visibility};
@@ -11,6 +12,7 @@ use syn::{Attribute, ItemEnum, ItemStruct, Type, Visibility};
 pub struct StructParser {
     validator_parser: ValidatorParser,
     serde_parser: SerdeParser,
+    skip_zero_sized_fields: bool,
 }
 
 impl StructParser {
@@ -18,9 +20,15 @@ impl StructParser {
         Self {
             validator_parser: ValidatorParser::new(),
             serde_parser: SerdeParser::new(),
+            skip_zero_sized_fields: true,
         }
     }
 
+    /// Leave out fields of zero-sized marker types, see [`Self::is_zero_sized`]
+    pub fn set_skip_zero_sized_fields(&mut self, skip: bool) {
+        self.skip_zero_sized_fields = skip;
+    }
+
     /// Check if a struct should be included in type generation
     pub fn should_include_struct(&self, item_struct: &ItemStruct) -> bool {
         // Check if struct has Serialize or Deserialize derive
@@ -70,15 +78,27 @@ impl StructParser {
             .serde_parser
             .parse_struct_serde_attrs(&item_struct.attrs);
 
+        let mut is_newtype = false;
         let fields = match &item_struct.fields {
             syn::Fields::Named(fields_named) => fields_named
                 .named
                 .iter()
                 .filter_map(|field| self.parse_field(field, type_resolver))
+                .map(|mut field| {
+                    // A struct-level `#[serde(default)]` fills in every absent field
+                    if struct_serde_attrs.default {
+                        field.omittable = true;
+                        field.defaulted = true;
+                        field.is_optional = true;
+                    }
+                    field
+                })
                 .collect(),
-            syn::Fields::Unnamed(_) => {
-                // Handle tuple structs if needed
-                return None;
+            syn::Fields::Unnamed(fields_unnamed) => {
+                // Only newtypes over collections are supported, e.g. `struct Tags(Vec<String>)`
+                let field = self.parse_collection_newtype_field(fields_unnamed, type_resolver)?;
+                is_newtype = true;
+                vec![field]
             }
             syn::Fields::Unit => {
                 // Unit struct
@@ -90,13 +110,68 @@ impl StructParser {
             name: item_struct.ident.to_string(),
             fields,
             file_path: file_path.to_string_lossy().to_string(),
+            line_number: item_struct.ident.span().start().line,
             is_enum: false,
             serde_rename_all: struct_serde_attrs.rename_all,
+            is_newtype,
+            serde_tag: None,
+            serde_content: None,
+            serde_untagged: false,
+            serde_deny_unknown_fields: struct_serde_attrs.deny_unknown_fields,
+            is_progress: CommandParser::typegen_options(&item_struct.attrs)
+                .iter()
+                .any(|meta| meta.path().is_ident("progress")),
+        })
+    }
+
+    /// Parse the wrapped field of a single-field tuple struct over a collection.
+    /// Returns None for other tuple structs, which are not supported yet.
+    fn parse_collection_newtype_field(
+        &self,
+        fields_unnamed: &syn::FieldsUnnamed,
+        type_resolver: &mut TypeResolver,
+    ) -> Option<FieldInfo> {
+        if fields_unnamed.unnamed.len() != 1 {
+            return None;
+        }
+
+        let field = fields_unnamed.unnamed.first()?;
+        let rust_type = Self::type_to_string(&field.ty);
+        let type_structure = type_resolver.parse_type_structure(&rust_type);
+
+        if !matches!(
+            type_structure,
+            TypeStructure::Array(_) | TypeStructure::Map { .. } | TypeStructure::Set(_)
+        ) {
+            return None;
+        }
+
+        Some(FieldInfo {
+            name: "0".to_string(),
+            rust_type,
+            is_optional: false,
+            is_public: true,
+            validator_attributes: None,
+            serde_rename: None,
+            type_structure,
+            variant_fields: Vec::new(),
+            literal: None,
+            flatten: false,
+            omittable: false,
+            defaulted: false,
+            skip_serializing_if: false,
+            skipped: None,
+            serde_with: None,
         })
     }
 
     /// Parse a Rust enum into StructInfo
-    pub fn parse_enum(&self, item_enum: &ItemEnum, file_path: &Path) -> Option<StructInfo> {
+    pub fn parse_enum(
+        &self,
+        item_enum: &ItemEnum,
+        file_path: &Path,
+        type_resolver: &mut TypeResolver,
+    ) -> Option<StructInfo> {
         // Parse enum-level serde attributes
         let enum_serde_attrs = self.serde_parser.parse_struct_serde_attrs(&item_enum.attrs);
 
@@ -122,11 +197,52 @@ impl StructParser {
                             type_structure: crate::models::TypeStructure::Primitive(
                                 "string".to_string(),
                             ),
+                            variant_fields: Vec::new(),
+                            literal: None,
+                            flatten: false,
+                            omittable: false,
+                            defaulted: false,
+                            skip_serializing_if: false,
+                            skipped: None,
+                            serde_with: None,
                         }
                     }
-                    syn::Fields::Unnamed(_fields_unnamed) => {
+                    syn::Fields::Unnamed(fields_unnamed) => {
                         // Tuple variant: Variant(T, U)
-                        // Note: Complex enum variants are not fully supported yet
+                        let variant_fields: Vec<FieldInfo> = fields_unnamed
+                            .unnamed
+                            .iter()
+                            .enumerate()
+                            .map(|(index, field)| {
+                                let rust_type = Self::type_to_string(&field.ty);
+                                FieldInfo {
+                                    name: index.to_string(),
+                                    is_optional: self.is_optional_type(&field.ty),
+                                    is_public: true,
+                                    validator_attributes: None,
+                                    serde_rename: None,
+                                    type_structure: type_resolver.parse_type_structure(&rust_type),
+                                    rust_type,
+                                    variant_fields: Vec::new(),
+                                    literal: None,
+                                    flatten: false,
+                                    omittable: false,
+                                    defaulted: false,
+                                    skip_serializing_if: false,
+                                    skipped: None,
+                                    serde_with: None,
+                                }
+                            })
+                            .collect();
+
+                        // Serde serializes a single field as-is and several fields as an array
+                        let type_structure = match variant_fields.as_slice() {
+                            [single] => single.type_structure.clone(),
+                            fields => TypeStructure::Tuple(
+                                fields.iter().map(|f| f.type_structure.clone()).collect(),
+                            ),
+                        };
+
                         FieldInfo {
                             name: variant_name,
                             rust_type: "enum_variant_tuple".to_string(),
@@ -134,15 +250,30 @@ impl StructParser {
                             is_public: true,
                             validator_attributes: None,
                             serde_rename: variant_serde_attrs.rename,
-                            // For enum variants, type structure is not used by generators
-                            type_structure: crate::models::TypeStructure::Custom(
-                                "enum_variant".to_string(),
-                            ),
+                            type_structure,
+                            variant_fields,
+                            literal: None,
+                            flatten: false,
+                            omittable: false,
+                            defaulted: false,
+                            skip_serializing_if: false,
+                            skipped: None,
+                            serde_with: None,
                         }
                     }
-                    syn::Fields::Named(_fields_named) => {
+                    syn::Fields::Named(fields_named) => {
                         // Struct variant: Variant { field: T }
-                        // Note: Complex enum variants are not fully supported yet
+                        let variant_fields = fields_named
+                            .named
+                            .iter()
+                            .filter_map(|field| self.parse_field(field, type_resolver))
+                            .map(|field| FieldInfo {
+                                // Variant fields inherit the enum's visibility
+                                is_public: true,
+                                ..field
+                            })
+                            .collect();
+
                         FieldInfo {
                             name: variant_name,
                             rust_type: "enum_variant_struct".to_string(),
@@ -150,10 +281,18 @@ impl StructParser {
                             is_public: true,
                             validator_attributes: None,
                             serde_rename: variant_serde_attrs.rename,
-                            // For enum variants, type structure is not used by generators
+                            // The payload is described by variant_fields
                             type_structure: crate::models::TypeStructure::Custom(
                                 "enum_variant".to_string(),
                             ),
+                            variant_fields,
+                            literal: None,
+                            flatten: false,
+                            omittable: false,
+                            defaulted: false,
+                            skip_serializing_if: false,
+                            skipped: None,
+                            serde_with: None,
                         }
                     }
                 }
@@ -164,8 +303,15 @@ impl StructParser {
             name: item_enum.ident.to_string(),
             fields,
             file_path: file_path.to_string_lossy().to_string(),
+            line_number: item_enum.ident.span().start().line,
             is_enum: true,
             serde_rename_all: enum_serde_attrs.rename_all,
+            is_newtype: false,
+            serde_tag: enum_serde_attrs.tag,
+            serde_content: enum_serde_attrs.content,
+            serde_untagged: enum_serde_attrs.untagged,
+            is_progress: false,
+            serde_deny_unknown_fields: false,
         })
     }
 
@@ -185,10 +331,24 @@ impl StructParser {
             return None;
         }
 
+        // Skip marker fields like `PhantomData<T>`, which carry no data
+        if self.skip_zero_sized_fields && Self::is_zero_sized(&field.ty) {
+            return None;
+        }
+
         let is_public = matches!(field.vis, Visibility::Public(_));
-        let is_optional = self.is_optional_type(&field.ty);
+        let omittable = field_serde_attrs.default || field_serde_attrs.skip_serializing_if;
+        let is_optional = self.is_optional_type(&field.ty) || omittable;
         let rust_type = Self::type_to_string(&field.ty);
-        let type_structure = type_resolver.parse_type_structure(&rust_type);
+        let mut type_structure = type_resolver.parse_type_structure(&rust_type);
+        // Fields serde converts with a mapped module are typed by the mapping
+        if let Some(mapped) = field_serde_attrs
+            .with
+            .as_deref()
+            .and_then(|with| type_resolver.resolve_serde_with(with, &type_structure))
+        {
+            type_structure = mapped;
+        }
         let validator_attributes = self
             .validator_parser
             .parse_validator_attributes(&field.attrs);
@@ -201,9 +361,61 @@ impl StructParser {
             validator_attributes,
             serde_rename: field_serde_attrs.rename,
             type_structure,
+            variant_fields: Vec::new(),
+            literal: Self::parse_literal(&field.attrs),
+            flatten: field_serde_attrs.flatten,
+            omittable,
+            defaulted: field_serde_attrs.default,
+            skip_serializing_if: field_serde_attrs.skip_serializing_if,
+            skipped: if field_serde_attrs.skip_serializing {
+                Some(SkipDirection::Serializing)
+            } else if field_serde_attrs.skip_deserializing {
+                Some(SkipDirection::Deserializing)
+            } else {
+                None
+            },
+            serde_with: field_serde_attrs.with,
         })
     }
 
+    /// Parse `#[typegen(literal = "image")]` of a field always holding that value
+    fn parse_literal(attrs: &[Attribute]) -> Option<String> {
+        CommandParser::typegen_options(attrs)
+            .into_iter()
+            .find_map(|meta| match meta {
+                syn::Meta::NameValue(name_value) if name_value.path.is_ident("literal") => {
+                    match name_value.value {
+                        syn::Expr::Lit(syn::ExprLit {
+                            lit: syn::Lit::Str(literal),
+                            ..
+                        }) => Some(literal.value()),
+                        _ => None,
+                    }
+                }
+                _ => None,
+            })
+    }
+
+    /// Check if a type is a zero-sized marker: `PhantomData<T>`, `PhantomPinned`, `()`
+    /// or an array of length 0
+    fn is_zero_sized(ty: &Type) -> bool {
+        match ty {
+            Type::Path(type_path) => type_path.path.segments.last().is_some_and(|segment| {
+                segment.ident == "PhantomData" || segment.ident == "PhantomPinned"
+            }),
+            Type::Tuple(type_tuple) => type_tuple.elems.is_empty(),
+            Type::Array(type_array) => matches!(
+                &type_array.len,
+                syn::Expr::Lit(syn::ExprLit {
+                    lit: syn::Lit::Int(len),
+                    ..
+                }) if len.base10_digits() == "0"
+            ),
+            Type::Paren(type_paren) => Self::is_zero_sized(&type_paren.elem),
+            _ => false,
+        }
+    }
+
     /// Check if a type is Option<T>
     fn is_optional_type(&self, ty: &Type) -> bool {
         if let Type::Path(type_path) = ty {
@@ -409,6 +621,73 @@ mod tests {
             assert!(result.fields[1].is_optional);
         }
 
+        #[test]
+        fn test_parse_struct_with_serde_default() {
+            let parser = parser();
+            let mut resolver = type_resolver();
+            let item: ItemStruct = parse_quote! {
+                #[derive(Deserialize)]
+                pub struct Settings {
+                    pub name: String,
+                    #[serde(default)]
+                    pub retries: u32,
+                    #[serde(skip_serializing_if = "Vec::is_empty")]
+                    pub tags: Vec<String>,
+                }
+            };
+            let path = Path::new("test.rs");
+            let result = parser.parse_struct(&item, path, &mut resolver).unwrap();
+
+            assert!(!result.fields[0].omittable);
+            assert!(!result.fields[0].is_optional);
+            assert!(result.fields[1].omittable);
+            assert!(result.fields[1].is_optional);
+            assert!(result.fields[2].omittable);
+
+            let item: ItemStruct = parse_quote! {
+                #[derive(Deserialize)]
+                #[serde(default)]
+                pub struct Limits {
+                    pub max: u32,
+                }
+            };
+            let result = parser.parse_struct(&item, path, &mut resolver).unwrap();
+            assert!(result.fields[0].omittable);
+        }
+
+        #[test]
+        fn test_parse_struct_with_serde_with() {
+            let parser = parser();
+            let mut resolver = type_resolver();
+            resolver.add_serde_with_mapping(
+                "chrono::serde::ts_seconds".to_string(),
+                "number".to_string(),
+            );
+            let item: ItemStruct = parse_quote! {
+                #[derive(Serialize)]
+                pub struct Event {
+                    #[serde(with = "chrono::serde::ts_seconds")]
+                    pub at: DateTime<Utc>,
+                    #[serde(with = "unmapped")]
+                    pub name: String,
+                }
+            };
+            let path = Path::new("test.rs");
+            let result = parser.parse_struct(&item, path, &mut resolver).unwrap();
+
+            assert_eq!(
+                result.fields[0].type_structure,
+                TypeStructure::Custom("chrono::serde::ts_seconds".to_string())
+            );
+            assert_eq!(result.fields[0].rust_type, "DateTime<Utc>");
+            // Unmapped paths keep the field's type
+            assert_eq!(
+                result.fields[1].type_structure,
+                TypeStructure::Primitive("string".to_string())
+            );
+            assert_eq!(result.fields[1].serde_with.as_deref(), Some("unmapped"));
+        }
+
         #[test]
         fn test_parse_struct_with_serde_skip() {
             let parser = parser();
@@ -429,6 +708,31 @@ mod tests {
             assert_eq!(result.fields[0].name, "name");
         }
 
+        #[test]
+        fn test_parse_struct_skips_zero_sized_fields() {
+            let mut parser = parser();
+            let mut resolver = type_resolver();
+            let item: ItemStruct = parse_quote! {
+                #[derive(Serialize)]
+                pub struct Id<T> {
+                    pub value: u64,
+                    pub marker: std::marker::PhantomData<T>,
+                    pub pinned: PhantomPinned,
+                    pub unit: (),
+                    pub empty: [u8; 0],
+                    pub pair: (u8, u8),
+                }
+            };
+            let path = Path::new("test.rs");
+            let result = parser.parse_struct(&item, path, &mut resolver).unwrap();
+            let names: Vec<&str> = result.fields.iter().map(|f| f.name.as_str()).collect();
+            assert_eq!(names, ["value", "pair"]);
+
+            parser.set_skip_zero_sized_fields(false);
+            let result = parser.parse_struct(&item, path, &mut resolver).unwrap();
+            assert_eq!(result.fields.len(), 6);
+        }
+
         #[test]
         fn test_parse_struct_with_serde_rename() {
             let parser = parser();
@@ -446,6 +750,27 @@ mod tests {
             assert_eq!(result.fields[0].serde_rename, Some("userName".to_string()));
         }
 
+        #[test]
+        fn test_parse_struct_with_literal_field() {
+            let parser = parser();
+            let mut resolver = type_resolver();
+            let item: ItemStruct = parse_quote! {
+                #[derive(Serialize)]
+                pub struct ImageMedia {
+                    #[cfg_attr(any(), typegen(literal = "image"))]
+                    pub kind: &'static str,
+                    #[typegen(literal = 1)]
+                    pub width: u32,
+                }
+            };
+            let path = Path::new("test.rs");
+            let result = parser.parse_struct(&item, path, &mut resolver).unwrap();
+
+            assert_eq!(result.fields[0].literal, Some("image".to_string()));
+            // Only string literals are supported
+            assert_eq!(result.fields[1].literal, None);
+        }
+
         #[test]
         fn test_parse_struct_with_rename_all() {
             let parser = parser();
@@ -493,6 +818,58 @@ mod tests {
             assert!(result.is_none());
         }
 
+        #[test]
+        fn test_parse_vec_newtype() {
+            let parser = parser();
+            let mut resolver = type_resolver();
+            let item: ItemStruct = parse_quote! {
+                #[derive(Serialize)]
+                pub struct Tags(Vec<String>);
+            };
+            let path = Path::new("test.rs");
+            let result = parser.parse_struct(&item, path, &mut resolver).unwrap();
+
+            assert!(result.is_newtype);
+            assert_eq!(result.fields.len(), 1);
+            assert_eq!(result.fields[0].rust_type, "Vec<String>");
+            assert!(matches!(
+                result.fields[0].type_structure,
+                TypeStructure::Array(_)
+            ));
+        }
+
+        #[test]
+        fn test_parse_hashmap_newtype() {
+            let parser = parser();
+            let mut resolver = type_resolver();
+            let item: ItemStruct = parse_quote! {
+                #[derive(Serialize)]
+                pub struct Index(pub HashMap<String, u32>);
+            };
+            let path = Path::new("test.rs");
+            let result = parser.parse_struct(&item, path, &mut resolver).unwrap();
+
+            assert!(result.is_newtype);
+            assert!(matches!(
+                result.fields[0].type_structure,
+                TypeStructure::Map { .. }
+            ));
+        }
+
+        #[test]
+        fn test_parse_non_collection_newtype_returns_none() {
+            let parser = parser();
+            let mut resolver = type_resolver();
+            let item: ItemStruct = parse_quote! {
+                #[derive(Serialize)]
+                pub struct UserId(String);
+            };
+            let path = Path::new("test.rs");
+            let result = parser.parse_struct(&item, path, &mut resolver);
+
+            assert!(result.is_none());
+        }
+
         #[test]
         fn test_parse_struct_with_private_fields() {
             let parser = parser();
@@ -527,7 +904,7 @@ mod tests {
                 }
             };
             let path = Path::new("test.rs");
-            let result = parser.parse_enum(&item, path);
+            let result = parser.parse_enum(&item, path, &mut type_resolver());
 
             assert!(result.is_some());
             let enum_info = result.unwrap();
@@ -548,7 +925,9 @@ mod tests {
                 }
             };
             let path = Path::new("test.rs");
-            let result = parser.parse_enum(&item, path).unwrap();
+            let result = parser
+                .parse_enum(&item, path, &mut type_resolver())
+                .unwrap();
 
             assert_eq!(result.fields.len(), 3);
             assert_eq!(result.fields[0].name, "Active");
@@ -568,7 +947,9 @@ mod tests {
                 }
             };
             let path = Path::new("test.rs");
-            let result = parser.parse_enum(&item, path).unwrap();
+            let result = parser
+                .parse_enum(&item, path, &mut type_resolver())
+                .unwrap();
 
             assert_eq!(result.fields.len(), 2);
             assert_eq!(result.fields[0].rust_type, "enum_variant_tuple");
@@ -585,12 +966,92 @@ mod tests {
                 }
             };
             let path = Path::new("test.rs");
-            let result = parser.parse_enum(&item, path).unwrap();
+            let result = parser
+                .parse_enum(&item, path, &mut type_resolver())
+                .unwrap();
 
             assert_eq!(result.fields.len(), 1);
             assert_eq!(result.fields[0].rust_type, "enum_variant_struct");
         }
 
+        #[test]
+        fn test_parse_enum_variant_fields() {
+            let parser = parser();
+            let item: ItemEnum = parse_quote! {
+                #[derive(Serialize)]
+                pub enum Message {
+                    Text(String),
+                    Pair(i32, String),
+                    Move { x: i32, y: i32 },
+                    Quit,
+                }
+            };
+            let path = Path::new("test.rs");
+            let result = parser
+                .parse_enum(&item, path, &mut type_resolver())
+                .unwrap();
+
+            let text = &result.fields[0];
+            assert_eq!(text.variant_fields.len(), 1);
+            assert_eq!(text.variant_fields[0].name, "0");
+            assert_eq!(
+                text.type_structure,
+                TypeStructure::Primitive("string".to_string())
+            );
+
+            let pair = &result.fields[1];
+            assert_eq!(pair.variant_fields.len(), 2);
+            assert!(matches!(pair.type_structure, TypeStructure::Tuple(_)));
+
+            let mv = &result.fields[2];
+            let names: Vec<&str> = mv.variant_fields.iter().map(|f| f.name.as_str()).collect();
+            assert_eq!(names, vec!["x", "y"]);
+
+            assert!(result.fields[3].variant_fields.is_empty());
+        }
+
+        #[test]
+        fn test_parse_enum_with_tag_and_content() {
+            let parser = parser();
+            let item: ItemEnum = parse_quote! {
+                #[derive(Serialize)]
+                #[serde(tag = "type", content = "data")]
+                pub enum Message {
+                    Text(String),
+                    Quit,
+                }
+            };
+            let path = Path::new("test.rs");
+            let result = parser
+                .parse_enum(&item, path, &mut type_resolver())
+                .unwrap();
+
+            assert_eq!(result.serde_tag, Some("type".to_string()));
+            assert_eq!(result.serde_content, Some("data".to_string()));
+            assert!(result.is_adjacently_tagged());
+        }
+
+        #[test]
+        fn test_parse_enum_with_tag_only() {
+            let parser = parser();
+            let item: ItemEnum = parse_quote! {
+                #[derive(Serialize)]
+                #[serde(tag = "kind")]
+                pub enum Shape {
+                    Circle { radius: f64 },
+                    Empty,
+                }
+            };
+            let path = Path::new("test.rs");
+            let result = parser
+                .parse_enum(&item, path, &mut type_resolver())
+                .unwrap();
+
+            assert!(result.is_internally_tagged());
+            assert!(!result.is_adjacently_tagged());
+            assert_eq!(result.fields[0].variant_fields[0].name, "radius");
+        }
+
         #[test]
         fn test_parse_enum_with_serde_rename_variant() {
             let parser = parser();
@@ -604,7 +1065,9 @@ mod tests {
                 }
             };
             let path = Path::new("test.rs");
-            let result = parser.parse_enum(&item, path).unwrap();
+            let result = parser
+                .parse_enum(&item, path, &mut type_resolver())
+                .unwrap();
 
             assert_eq!(result.fields[0].serde_rename, Some("active".to_string()));
             assert_eq!(result.fields[1].serde_rename, Some("inactive".to_string()));
@@ -622,7 +1085,9 @@ mod tests {
                 }
             };
             let path = Path::new("test.rs");
-            let result = parser.parse_enum(&item, path).unwrap();
+            let result = parser
+                .parse_enum(&item, path, &mut type_resolver())
+                .unwrap();
 
             assert_eq!(result.serde_rename_all, Some(RenameRule::SnakeCase));
         }
@@ -857,7 +1322,9 @@ mod tests {
                 }
             };
             let path = Path::new("models.rs");
-            let result = parser.parse_enum(&item, path).unwrap();
+            let result = parser
+                .parse_enum(&item, path, &mut type_resolver())
+                .unwrap();
 
             assert_eq!(result.name, "Message");
             assert_eq!(result.fields.len(), 3);
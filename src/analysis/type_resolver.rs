@@ -1,11 +1,67 @@
 use crate::models::TypeStructure;
 use std::collections::{HashMap, HashSet};
 
+/// Semantics of a collection type from an external crate (e.g. `IndexMap`, `SmallVec`)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CollectionKind {
+    /// Sequence types, generated like `Vec<T>`
+    Array,
+    /// Key-value types, generated like `HashMap<K, V>`
+    Map,
+    /// Unique value types, generated like `HashSet<T>`
+    Set,
+}
+
+impl CollectionKind {
+    /// Parse a collection kind from its config representation ("array", "map" or "set")
+    pub fn parse(value: &str) -> Option<Self> {
+        match value.to_ascii_lowercase().as_str() {
+            "array" | "vec" | "list" => Some(Self::Array),
+            "map" | "record" => Some(Self::Map),
+            "set" => Some(Self::Set),
+            _ => None,
+        }
+    }
+}
+
+/// Path-like std types that serialize as plain strings
+const PATH_TYPES: &[&str] = &["PathBuf", "Path", "OsString", "OsStr"];
+
+/// `serde_json` types holding arbitrary JSON (`Value` is assumed to be `serde_json::Value`)
+const JSON_VALUE_TYPES: &[&str] = &["serde_json::Value", "Value"];
+
+/// Name of the recursive type generated for arbitrary JSON values
+pub const JSON_VALUE_TYPE: &str = "JsonValue";
+
+/// Wildcard used in type mapping patterns (`"DateTime<*>"`) and pass-through targets (`"*"`)
+pub const TYPE_MAPPING_WILDCARD: &str = "*";
+
+/// A configured type mapping rule matching a Rust type
+#[derive(Debug, Clone, PartialEq)]
+pub struct TypeMappingMatch<'a> {
+    /// The mapping key, e.g. `DateTime<Utc>` or `DateTime<*>`
+    pub rule: &'a str,
+    /// The configured target type
+    pub target: &'a str,
+    /// Rust types matched by the wildcards of a pattern rule, in order
+    pub captures: Vec<String>,
+}
+
+impl TypeMappingMatch<'_> {
+    /// Whether the rule resolves to its first captured type (`"Arc<*>": "*"`)
+    pub fn is_pass_through(&self) -> bool {
+        self.target == TYPE_MAPPING_WILDCARD
+    }
+}
+
 /// Type resolver for mapping Rust types to TypeScript types
 #[derive(Debug)]
 pub struct TypeResolver {
     type_set: HashSet<String>,
     type_mappings: HashMap<String, String>,
+    serde_with_mappings: HashMap<String, String>,
+    collection_mappings: HashMap<String, CollectionKind>,
+    union_types: HashSet<String>,
 }
 
 impl TypeResolver {
@@ -39,9 +95,33 @@ impl TypeResolver {
         type_set.insert("HashSet".to_string());
         type_set.insert("BTreeSet".to_string());
 
+        // Path-like types serialize as strings
+        for path_type in PATH_TYPES {
+            type_set.insert(path_type.to_string());
+        }
+
+        // Common collection types from std and third-party crates
+        let mut collection_mappings = HashMap::new();
+        collection_mappings.insert("IndexMap".to_string(), CollectionKind::Map);
+        collection_mappings.insert("IndexSet".to_string(), CollectionKind::Set);
+        collection_mappings.insert("SmallVec".to_string(), CollectionKind::Array);
+        collection_mappings.insert("ArrayVec".to_string(), CollectionKind::Array);
+        collection_mappings.insert("VecDeque".to_string(), CollectionKind::Array);
+        collection_mappings.insert("LinkedList".to_string(), CollectionKind::Array);
+        collection_mappings.insert("BinaryHeap".to_string(), CollectionKind::Array);
+        collection_mappings.insert("Bytes".to_string(), CollectionKind::Array);
+        collection_mappings.insert("BytesMut".to_string(), CollectionKind::Array);
+
+        // Generic types whose arguments form a union, e.g. Either<A, B> -> A | B
+        let mut union_types = HashSet::new();
+        union_types.insert("Either".to_string());
+
         Self {
             type_set,
             type_mappings: HashMap::new(),
+            serde_with_mappings: HashMap::new(),
+            collection_mappings,
+            union_types,
         }
     }
 
@@ -120,6 +200,121 @@ impl TypeResolver {
         }
     }
 
+    /// Resolve a configured collection type like `IndexMap<K, V>` or `SmallVec<[T; 4]>`.
+    /// Path prefixes (`indexmap::IndexMap`) are ignored. Non-generic byte buffers
+    /// such as `Bytes` resolve to an array of numbers.
+    fn parse_collection_type(&self, rust_type: &str) -> Option<TypeStructure> {
+        let (path, args) = match rust_type.find('<') {
+            Some(open) if rust_type.ends_with('>') => (
+                &rust_type[..open],
+                Self::split_generic_args(&rust_type[open + 1..rust_type.len() - 1]),
+            ),
+            Some(_) => return None,
+            None => (rust_type, Vec::new()),
+        };
+
+        let kind = self.collection_kind(path)?;
+
+        match (kind, args.as_slice()) {
+            (CollectionKind::Array, []) => Some(TypeStructure::Array(Box::new(
+                TypeStructure::Primitive("number".to_string()),
+            ))),
+            (CollectionKind::Array, [inner, ..]) => Some(TypeStructure::Array(Box::new(
+                self.parse_type_structure(Self::unwrap_array_type(inner)),
+            ))),
+            (CollectionKind::Set, [inner, ..]) => Some(TypeStructure::Set(Box::new(
+                self.parse_type_structure(Self::unwrap_array_type(inner)),
+            ))),
+            (CollectionKind::Map, [key, value, ..]) => Some(TypeStructure::Map {
+                key: Box::new(self.parse_type_structure(key)),
+                value: Box::new(self.parse_type_structure(value)),
+            }),
+            _ => None,
+        }
+    }
+
+    /// Resolve `serde_json::Value` to the generated `JsonValue` type and
+    /// `serde_json::Map<String, Value>` to a record of it.
+    ///
+    /// Explicit type mappings for these types take precedence.
+    fn parse_json_type(&self, rust_type: &str) -> Option<TypeStructure> {
+        if self.type_mappings.contains_key(rust_type) {
+            return None;
+        }
+
+        if JSON_VALUE_TYPES.contains(&rust_type) {
+            return Some(TypeStructure::Custom(JSON_VALUE_TYPE.to_string()));
+        }
+
+        let inner = rust_type
+            .strip_prefix("serde_json::Map<")
+            .and_then(|rest| rest.strip_suffix('>'))?;
+        match Self::split_generic_args(inner).as_slice() {
+            [key, value] => Some(TypeStructure::Map {
+                key: Box::new(self.parse_type_structure(key)),
+                value: Box::new(self.parse_type_structure(value)),
+            }),
+            _ => None,
+        }
+    }
+
+    /// Resolve a configured union type like `Either<A, B>` into a union of its generic arguments
+    fn parse_union_type(&self, rust_type: &str) -> Option<TypeStructure> {
+        if !self.is_union_type(rust_type) || !rust_type.ends_with('>') {
+            return None;
+        }
+
+        let open = rust_type.find('<')?;
+        let mut members: Vec<TypeStructure> =
+            Self::split_generic_args(&rust_type[open + 1..rust_type.len() - 1])
+                .iter()
+                .map(|arg| self.parse_type_structure(arg))
+                .collect();
+
+        match members.len() {
+            0 => None,
+            1 => members.pop(),
+            _ => Some(TypeStructure::Union(members)),
+        }
+    }
+
+    /// Unwrap inline array storage like `[T; 4]` (as used by SmallVec) to `T`
+    pub(crate) fn unwrap_array_type(rust_type: &str) -> &str {
+        rust_type
+            .strip_prefix('[')
+            .and_then(|s| s.strip_suffix(']'))
+            .and_then(|s| s.split(';').next())
+            .map(str::trim)
+            .unwrap_or(rust_type)
+    }
+
+    /// Split generic arguments at top-level commas, e.g. `K, Vec<(A, B)>` → [`K`, `Vec<(A, B)>`]
+    pub(crate) fn split_generic_args(inner: &str) -> Vec<String> {
+        let mut args = Vec::new();
+        let mut depth = 0;
+        let mut current = String::new();
+
+        for ch in inner.chars() {
+            match ch {
+                '<' | '(' | '[' => depth += 1,
+                '>' | ')' | ']' => depth -= 1,
+                ',' if depth == 0 => {
+                    args.push(current.trim().to_string());
+                    current.clear();
+                    continue;
+                }
+                _ => {}
+            }
+            current.push(ch);
+        }
+
+        if !current.trim().is_empty() {
+            args.push(current.trim().to_string());
+        }
+
+        args
+    }
+
     /// Extract types from tuple (T1, T2, ...)
     fn extract_tuple_types(&self, rust_type: &str) -> Option<Vec<String>> {
         if rust_type.starts_with('(') && rust_type.ends_with(')') {
@@ -182,6 +377,17 @@ impl TypeResolver {
             return self.parse_type_structure(&inner);
         }
 
+        // Configured type mappings take precedence over the built-in handling below.
+        // Mapped types stay custom so generators can look up the rule by name.
+        if let Some(mapping) = self.find_type_mapping(cleaned) {
+            return match mapping.captures.first() {
+                Some(captured) if mapping.is_pass_through() => self.parse_type_structure(captured),
+                _ => TypeStructure::Custom(mapping.rule.to_string()),
+            };
+        }
+
+        let cleaned = Self::strip_std_path(cleaned);
+
         // Handle Option<T> -> Optional(T)
         if let Some(inner_type) = self.extract_option_inner_type(cleaned) {
             return TypeStructure::Optional(Box::new(self.parse_type_structure(&inner_type)));
@@ -216,6 +422,16 @@ impl TypeResolver {
             return TypeStructure::Set(Box::new(self.parse_type_structure(&inner_type)));
         }
 
+        // Handle configured collection types (IndexMap, SmallVec, ...) -> Array/Map/Set
+        if let Some(collection) = self.parse_collection_type(cleaned) {
+            return collection;
+        }
+
+        // Handle configured union types (Either, ...) -> Union([A, B])
+        if let Some(union) = self.parse_union_type(cleaned) {
+            return union;
+        }
+
         // Handle tuple types (T1, T2, ...) -> Tuple([T1, T2, ...])
         if let Some(tuple_types) = self.extract_tuple_types(cleaned) {
             if tuple_types.is_empty() {
@@ -228,6 +444,16 @@ impl TypeResolver {
             return TypeStructure::Tuple(parsed_types);
         }
 
+        // Handle serde_json::Value / serde_json::Map -> JsonValue / Record<string, JsonValue>
+        if let Some(json) = self.parse_json_type(cleaned) {
+            return json;
+        }
+
+        // Handle path-like types (PathBuf, Path, OsString, OsStr) -> string
+        if Self::is_path_type(cleaned) {
+            return TypeStructure::Primitive("string".to_string());
+        }
+
         // Check if it's a primitive type and map to target primitive
         if let Some(target_primitive) = self.map_to_target_primitive(cleaned) {
             return TypeStructure::Primitive(target_primitive);
@@ -255,6 +481,55 @@ impl TypeResolver {
         }
     }
 
+    /// Strip the module path of std types written fully qualified, so
+    /// `std::collections::HashMap<K, V>` resolves like `HashMap<K, V>`.
+    /// Type arguments keep their paths and are normalized when they are resolved.
+    pub fn strip_std_path(rust_type: &str) -> &str {
+        let mut stripped = rust_type;
+        for krate in [
+            "::std::",
+            "std::",
+            "::alloc::",
+            "alloc::",
+            "::core::",
+            "core::",
+        ] {
+            if let Some(rest) = rust_type.strip_prefix(krate) {
+                stripped = rest;
+                break;
+            }
+        }
+        if stripped.len() == rust_type.len() {
+            return rust_type;
+        }
+
+        let end = stripped.find('<').unwrap_or(stripped.len());
+        match stripped[..end].rfind("::") {
+            Some(separator) => &stripped[separator + 2..],
+            None => stripped,
+        }
+    }
+
+    /// Check whether a Rust type is a path-like std type, e.g. `PathBuf`,
+    /// `&std::path::Path` or `Option<OsString>`.
+    ///
+    /// These serialize as strings using the separators of the platform the
+    /// backend runs on (`\` on Windows, `/` elsewhere).
+    pub fn is_path_type(rust_type: &str) -> bool {
+        let cleaned = rust_type.trim().trim_start_matches('&').trim();
+        let cleaned = cleaned.strip_prefix("mut ").unwrap_or(cleaned).trim();
+
+        if let Some(inner) = cleaned
+            .strip_prefix("Option<")
+            .and_then(|rest| rest.strip_suffix('>'))
+        {
+            return Self::is_path_type(inner);
+        }
+
+        let name = cleaned.rsplit("::").next().unwrap_or(cleaned);
+        PATH_TYPES.contains(&name)
+    }
+
     /// Get the type mappings
     pub fn get_type_mappings(&self) -> &HashMap<String, String> {
         &self.type_mappings
@@ -265,6 +540,85 @@ impl TypeResolver {
         self.type_mappings.insert(rust_type, typescript_type);
     }
 
+    /// Find the type mapping rule for a Rust type.
+    ///
+    /// Exact rules win over wildcard patterns like `DateTime<*>`. When several patterns
+    /// match, the most specific one (fewest wildcards, then longest) is used.
+    pub fn find_type_mapping(&self, rust_type: &str) -> Option<TypeMappingMatch<'_>> {
+        if let Some((rule, target)) = self.type_mappings.get_key_value(rust_type) {
+            return Some(TypeMappingMatch {
+                rule,
+                target,
+                captures: Vec::new(),
+            });
+        }
+
+        self.type_mappings
+            .iter()
+            .filter(|(rule, _)| Self::is_type_pattern(rule))
+            .filter_map(|(rule, target)| {
+                let mut captures = Vec::new();
+                Self::match_type_pattern(rule, rust_type, &mut captures).then_some(
+                    TypeMappingMatch {
+                        rule,
+                        target,
+                        captures,
+                    },
+                )
+            })
+            .min_by(|a, b| {
+                let wildcards = |rule: &str| rule.matches(TYPE_MAPPING_WILDCARD).count();
+                wildcards(a.rule)
+                    .cmp(&wildcards(b.rule))
+                    .then(b.rule.len().cmp(&a.rule.len()))
+                    .then(a.rule.cmp(b.rule))
+            })
+    }
+
+    /// Check whether a type mapping key is a wildcard pattern like `Arc<*>`
+    pub fn is_type_pattern(rule: &str) -> bool {
+        rule.contains(TYPE_MAPPING_WILDCARD)
+    }
+
+    /// Match a Rust type against a pattern, collecting the types matched by wildcards.
+    ///
+    /// Pattern names without a module path match qualified types too
+    /// (`DateTime<*>` matches `chrono::DateTime<Utc>`).
+    fn match_type_pattern(pattern: &str, rust_type: &str, captures: &mut Vec<String>) -> bool {
+        let pattern = pattern.trim();
+        let rust_type = rust_type.trim();
+
+        if pattern == TYPE_MAPPING_WILDCARD {
+            captures.push(rust_type.to_string());
+            return true;
+        }
+
+        let split = |ty: &str| match ty.find('<') {
+            Some(open) if ty.ends_with('>') => (
+                ty[..open].trim().to_string(),
+                Self::split_generic_args(&ty[open + 1..ty.len() - 1]),
+            ),
+            _ => (ty.to_string(), Vec::new()),
+        };
+        let (pattern_name, pattern_args) = split(pattern);
+        let (type_name, type_args) = split(rust_type);
+
+        let name_matches = if pattern_name.contains("::") {
+            pattern_name == type_name
+        } else {
+            type_name.rsplit("::").next() == Some(pattern_name.as_str())
+        };
+
+        name_matches
+            && pattern_args.len() == type_args.len()
+            && pattern_args
+                .iter()
+                .zip(&type_args)
+                .all(|(pattern_arg, type_arg)| {
+                    Self::match_type_pattern(pattern_arg, type_arg, captures)
+                })
+    }
+
     /// Apply type mappings from a HashMap (typically from config)
     pub fn apply_type_mappings(&mut self, mappings: &HashMap<String, String>) {
         for (rust_type, ts_type) in mappings {
@@ -272,6 +626,77 @@ impl TypeResolver {
                 .insert(rust_type.clone(), ts_type.clone());
         }
     }
+
+    /// Get the `serde(with)` mappings
+    pub fn get_serde_with_mappings(&self) -> &HashMap<String, String> {
+        &self.serde_with_mappings
+    }
+
+    /// Map fields converted by a `serde(with)` module or `serialize_with` function to a
+    /// TypeScript type
+    pub fn add_serde_with_mapping(&mut self, path: String, typescript_type: String) {
+        self.serde_with_mappings.insert(path, typescript_type);
+    }
+
+    /// Type of a field converted with the `serde(with)` path `with`: a reference to the
+    /// mapping rule, which generators resolve like type mappings, or `None` if unmapped.
+    ///
+    /// Paths match when equal or, if either is not qualified, by their last segment
+    /// (`ts_seconds` matches `chrono::serde::ts_seconds`). An `Option` field stays
+    /// optional.
+    pub fn resolve_serde_with(
+        &self,
+        with: &str,
+        structure: &TypeStructure,
+    ) -> Option<TypeStructure> {
+        let last_segment = |path: &str| path.rsplit("::").next().unwrap_or(path).to_string();
+        let rule = self
+            .serde_with_mappings
+            .get_key_value(with)
+            .map(|(rule, _)| rule)
+            .or_else(|| {
+                self.serde_with_mappings
+                    .keys()
+                    .filter(|rule| !rule.contains("::") || !with.contains("::"))
+                    .filter(|rule| last_segment(rule) == last_segment(with))
+                    .min()
+            })?;
+
+        let reference = TypeStructure::Custom(rule.clone());
+        Some(match structure {
+            TypeStructure::Optional(_) => TypeStructure::Optional(Box::new(reference)),
+            _ => reference,
+        })
+    }
+
+    /// Get the collection mappings
+    pub fn get_collection_mappings(&self) -> &HashMap<String, CollectionKind> {
+        &self.collection_mappings
+    }
+
+    /// Add a collection type mapping, e.g. `IndexMap` → `CollectionKind::Map`
+    pub fn add_collection_mapping(&mut self, type_name: String, kind: CollectionKind) {
+        self.collection_mappings.insert(type_name, kind);
+    }
+
+    /// Register a generic type whose arguments are generated as a union, e.g. `OneOf`
+    pub fn add_union_type(&mut self, type_name: String) {
+        self.union_types.insert(type_name);
+    }
+
+    /// Check if a type is a configured union type, ignoring path prefixes and generics
+    pub fn is_union_type(&self, rust_type: &str) -> bool {
+        let path = rust_type.split('<').next().unwrap_or(rust_type);
+        let name = path.rsplit("::").next().unwrap_or(path).trim();
+        self.union_types.contains(name)
+    }
+
+    /// Get the collection kind of a type name, ignoring path prefixes and generics
+    pub fn collection_kind(&self, rust_type: &str) -> Option<CollectionKind> {
+        let path = rust_type.split('<').next().unwrap_or(rust_type);
+        let name = path.rsplit("::").next().unwrap_or(path).trim();
+        self.collection_mappings.get(name).copied()
+    }
 }
 
 impl Default for TypeResolver {
@@ -724,6 +1149,30 @@ mod tests {
             }
         }
 
+        #[test]
+        fn test_parse_fully_qualified_std_types() {
+            let resolver = TypeResolver::new();
+            assert_eq!(
+                resolver.parse_type_structure(
+                    "std::collections::HashMap<std::string::String, ::std::vec::Vec<u32>>"
+                ),
+                resolver.parse_type_structure("HashMap<String, Vec<u32>>")
+            );
+            assert_eq!(
+                resolver
+                    .parse_type_structure("std::collections::btree_map::BTreeMap<String, User>"),
+                resolver.parse_type_structure("BTreeMap<String, User>")
+            );
+            assert_eq!(
+                resolver.parse_type_structure("core::option::Option<User>"),
+                TypeStructure::Optional(Box::new(TypeStructure::Custom("User".to_string())))
+            );
+            assert_eq!(
+                TypeResolver::strip_std_path("my_crate::HashMap<K, V>"),
+                "my_crate::HashMap<K, V>"
+            );
+        }
+
         #[test]
         fn test_parse_btreemap() {
             let resolver = TypeResolver::new();
@@ -928,4 +1377,413 @@ mod tests {
             }
         }
     }
+
+    mod collection_types {
+        use super::*;
+
+        #[test]
+        fn test_parse_indexmap() {
+            let resolver = TypeResolver::new();
+            let result = resolver.parse_type_structure("IndexMap<String, User>");
+
+            assert_eq!(
+                result,
+                TypeStructure::Map {
+                    key: Box::new(TypeStructure::Primitive("string".to_string())),
+                    value: Box::new(TypeStructure::Custom("User".to_string())),
+                }
+            );
+        }
+
+        #[test]
+        fn test_parse_indexmap_with_path_and_hasher() {
+            let resolver = TypeResolver::new();
+            let result =
+                resolver.parse_type_structure("indexmap::IndexMap<String, Vec<u32>, RandomState>");
+
+            assert_eq!(
+                result,
+                TypeStructure::Map {
+                    key: Box::new(TypeStructure::Primitive("string".to_string())),
+                    value: Box::new(TypeStructure::Array(Box::new(TypeStructure::Primitive(
+                        "number".to_string()
+                    )))),
+                }
+            );
+        }
+
+        #[test]
+        fn test_parse_indexset() {
+            let resolver = TypeResolver::new();
+            let result = resolver.parse_type_structure("IndexSet<String>");
+
+            assert_eq!(
+                result,
+                TypeStructure::Set(Box::new(TypeStructure::Primitive("string".to_string())))
+            );
+        }
+
+        #[test]
+        fn test_parse_smallvec_unwraps_inline_array() {
+            let resolver = TypeResolver::new();
+            let result = resolver.parse_type_structure("SmallVec<[User; _]>");
+
+            assert_eq!(
+                result,
+                TypeStructure::Array(Box::new(TypeStructure::Custom("User".to_string())))
+            );
+        }
+
+        #[test]
+        fn test_parse_vecdeque() {
+            let resolver = TypeResolver::new();
+            let result = resolver.parse_type_structure("VecDeque<i32>");
+
+            assert_eq!(
+                result,
+                TypeStructure::Array(Box::new(TypeStructure::Primitive("number".to_string())))
+            );
+        }
+
+        #[test]
+        fn test_parse_bytes_as_number_array() {
+            let resolver = TypeResolver::new();
+            let result = resolver.parse_type_structure("bytes::Bytes");
+
+            assert_eq!(
+                result,
+                TypeStructure::Array(Box::new(TypeStructure::Primitive("number".to_string())))
+            );
+        }
+
+        #[test]
+        fn test_custom_collection_mapping() {
+            let mut resolver = TypeResolver::new();
+            assert_eq!(
+                resolver.parse_type_structure("ImVector<String>"),
+                TypeStructure::Custom("ImVector<String>".to_string())
+            );
+
+            resolver.add_collection_mapping("ImVector".to_string(), CollectionKind::Array);
+            assert_eq!(
+                resolver.parse_type_structure("ImVector<String>"),
+                TypeStructure::Array(Box::new(TypeStructure::Primitive("string".to_string())))
+            );
+        }
+
+        #[test]
+        fn test_map_without_arguments_is_custom() {
+            let resolver = TypeResolver::new();
+            let result = resolver.parse_type_structure("IndexMap");
+
+            assert_eq!(result, TypeStructure::Custom("IndexMap".to_string()));
+        }
+
+        #[test]
+        fn test_collection_kind_parse() {
+            assert_eq!(CollectionKind::parse("array"), Some(CollectionKind::Array));
+            assert_eq!(CollectionKind::parse("Map"), Some(CollectionKind::Map));
+            assert_eq!(CollectionKind::parse("set"), Some(CollectionKind::Set));
+            assert_eq!(CollectionKind::parse("tree"), None);
+        }
+
+        #[test]
+        fn test_split_generic_args_respects_nesting() {
+            let args = TypeResolver::split_generic_args("K, HashMap<A, B>, (C, D)");
+            assert_eq!(args, vec!["K", "HashMap<A, B>", "(C, D)"]);
+        }
+    }
+
+    mod union_types {
+        use super::*;
+
+        #[test]
+        fn test_parse_either() {
+            let resolver = TypeResolver::new();
+            let result = resolver.parse_type_structure("either::Either<String, Vec<User>>");
+
+            assert_eq!(
+                result,
+                TypeStructure::Union(vec![
+                    TypeStructure::Primitive("string".to_string()),
+                    TypeStructure::Array(Box::new(TypeStructure::Custom("User".to_string()))),
+                ])
+            );
+        }
+
+        #[test]
+        fn test_custom_union_type() {
+            let mut resolver = TypeResolver::new();
+            resolver.add_union_type("OneOf".to_string());
+
+            let result = resolver.parse_type_structure("OneOf<i32, bool, Config>");
+            assert_eq!(
+                result,
+                TypeStructure::Union(vec![
+                    TypeStructure::Primitive("number".to_string()),
+                    TypeStructure::Primitive("boolean".to_string()),
+                    TypeStructure::Custom("Config".to_string()),
+                ])
+            );
+        }
+
+        #[test]
+        fn test_single_argument_union_collapses() {
+            let mut resolver = TypeResolver::new();
+            resolver.add_union_type("OneOf".to_string());
+
+            let result = resolver.parse_type_structure("OneOf<String>");
+            assert_eq!(result, TypeStructure::Primitive("string".to_string()));
+        }
+
+        #[test]
+        fn test_unregistered_generic_is_custom() {
+            let resolver = TypeResolver::new();
+            let result = resolver.parse_type_structure("OneOf<String, i32>");
+
+            assert_eq!(
+                result,
+                TypeStructure::Custom("OneOf<String, i32>".to_string())
+            );
+        }
+    }
+
+    mod path_types {
+        use super::*;
+
+        #[test]
+        fn test_path_types_map_to_string() {
+            let resolver = TypeResolver::new();
+            for rust_type in [
+                "PathBuf",
+                "&Path",
+                "std::path::PathBuf",
+                "OsString",
+                "&std::ffi::OsStr",
+            ] {
+                assert_eq!(
+                    resolver.parse_type_structure(rust_type),
+                    TypeStructure::Primitive("string".to_string()),
+                    "{} should map to string",
+                    rust_type
+                );
+            }
+        }
+
+        #[test]
+        fn test_nested_path_types() {
+            let resolver = TypeResolver::new();
+            assert_eq!(
+                resolver.parse_type_structure("Vec<PathBuf>"),
+                TypeStructure::Array(Box::new(TypeStructure::Primitive("string".to_string())))
+            );
+        }
+
+        #[test]
+        fn test_is_path_type() {
+            assert!(TypeResolver::is_path_type("PathBuf"));
+            assert!(TypeResolver::is_path_type("&Path"));
+            assert!(TypeResolver::is_path_type("Option<std::path::PathBuf>"));
+            assert!(TypeResolver::is_path_type("OsString"));
+            assert!(!TypeResolver::is_path_type("String"));
+            assert!(!TypeResolver::is_path_type("Vec<PathBuf>"));
+            assert!(!TypeResolver::is_path_type("PathBufWrapper"));
+        }
+    }
+
+    mod json_types {
+        use super::*;
+
+        fn json_value() -> TypeStructure {
+            TypeStructure::Custom(JSON_VALUE_TYPE.to_string())
+        }
+
+        #[test]
+        fn test_serde_json_value_maps_to_json_value() {
+            let resolver = TypeResolver::new();
+            assert_eq!(
+                resolver.parse_type_structure("serde_json::Value"),
+                json_value()
+            );
+            assert_eq!(resolver.parse_type_structure("Value"), json_value());
+            assert_eq!(
+                resolver.parse_type_structure("Option<serde_json::Value>"),
+                TypeStructure::Optional(Box::new(json_value()))
+            );
+        }
+
+        #[test]
+        fn test_serde_json_map() {
+            let resolver = TypeResolver::new();
+            assert_eq!(
+                resolver.parse_type_structure("serde_json::Map<String, Value>"),
+                TypeStructure::Map {
+                    key: Box::new(TypeStructure::Primitive("string".to_string())),
+                    value: Box::new(json_value()),
+                }
+            );
+        }
+
+        #[test]
+        fn test_type_mapping_overrides_json_value() {
+            let mut resolver = TypeResolver::new();
+            resolver.add_type_mapping("Value".to_string(), "unknown".to_string());
+            assert_eq!(
+                resolver.parse_type_structure("Value"),
+                TypeStructure::Custom("Value".to_string())
+            );
+        }
+    }
+
+    mod type_mapping_patterns {
+        use super::*;
+
+        fn resolver(mappings: &[(&str, &str)]) -> TypeResolver {
+            let mut resolver = TypeResolver::new();
+            for (rust_type, ts_type) in mappings {
+                resolver.add_type_mapping(rust_type.to_string(), ts_type.to_string());
+            }
+            resolver
+        }
+
+        #[test]
+        fn test_pattern_matches_all_instantiations() {
+            let resolver = resolver(&[("DateTime<*>", "string")]);
+            for rust_type in ["DateTime<Utc>", "chrono::DateTime<FixedOffset>"] {
+                assert_eq!(
+                    resolver.parse_type_structure(rust_type),
+                    TypeStructure::Custom("DateTime<*>".to_string())
+                );
+            }
+            assert_eq!(
+                resolver.parse_type_structure("Option<DateTime<Utc>>"),
+                TypeStructure::Optional(Box::new(TypeStructure::Custom("DateTime<*>".to_string())))
+            );
+        }
+
+        #[test]
+        fn test_pattern_requires_matching_arity_and_name() {
+            let resolver = resolver(&[("DateTime<*>", "string")]);
+            assert!(resolver.find_type_mapping("DateTime<Utc, Extra>").is_none());
+            assert!(resolver.find_type_mapping("DateTime").is_none());
+            assert!(resolver.find_type_mapping("MyDateTime<Utc>").is_none());
+        }
+
+        #[test]
+        fn test_qualified_pattern_only_matches_same_path() {
+            let resolver = resolver(&[("chrono::DateTime<*>", "string")]);
+            assert!(resolver
+                .find_type_mapping("chrono::DateTime<Utc>")
+                .is_some());
+            assert!(resolver.find_type_mapping("DateTime<Utc>").is_none());
+        }
+
+        #[test]
+        fn test_pass_through_resolves_wrapped_type() {
+            let resolver = resolver(&[("Arc<*>", "*")]);
+            assert_eq!(
+                resolver.parse_type_structure("Arc<User>"),
+                TypeStructure::Custom("User".to_string())
+            );
+            assert_eq!(
+                resolver.parse_type_structure("std::sync::Arc<Vec<String>>"),
+                TypeStructure::Array(Box::new(TypeStructure::Primitive("string".to_string())))
+            );
+        }
+
+        #[test]
+        fn test_nested_wildcards() {
+            let resolver = resolver(&[("Arc<Mutex<*>>", "*")]);
+            let mapping = resolver.find_type_mapping("Arc<Mutex<Config>>").unwrap();
+            assert_eq!(mapping.captures, vec!["Config".to_string()]);
+            assert!(resolver.find_type_mapping("Arc<RwLock<Config>>").is_none());
+        }
+
+        #[test]
+        fn test_exact_rule_wins_over_pattern() {
+            let resolver = resolver(&[("DateTime<*>", "string"), ("DateTime<Local>", "Date")]);
+            let mapping = resolver.find_type_mapping("DateTime<Local>").unwrap();
+            assert_eq!(mapping.rule, "DateTime<Local>");
+            assert_eq!(mapping.target, "Date");
+
+            let mapping = resolver.find_type_mapping("DateTime<Utc>").unwrap();
+            assert_eq!(mapping.rule, "DateTime<*>");
+        }
+
+        #[test]
+        fn test_most_specific_pattern_wins() {
+            let resolver = resolver(&[
+                ("Wrapper<*, *>", "unknown"),
+                ("Wrapper<String, *>", "string"),
+            ]);
+            let mapping = resolver.find_type_mapping("Wrapper<String, u32>").unwrap();
+            assert_eq!(mapping.rule, "Wrapper<String, *>");
+            assert_eq!(mapping.captures, vec!["u32".to_string()]);
+        }
+
+        #[test]
+        fn test_pattern_overrides_built_in_handling() {
+            let resolver = resolver(&[("Vec<*>", "unknown[]")]);
+            assert_eq!(
+                resolver.parse_type_structure("Vec<u8>"),
+                TypeStructure::Custom("Vec<*>".to_string())
+            );
+        }
+    }
+
+    mod serde_with_mappings {
+        use super::*;
+
+        fn resolver() -> TypeResolver {
+            let mut resolver = TypeResolver::new();
+            resolver.add_serde_with_mapping(
+                "chrono::serde::ts_seconds".to_string(),
+                "number".to_string(),
+            );
+            resolver.add_serde_with_mapping("as_hex".to_string(), "string".to_string());
+            resolver
+        }
+
+        #[test]
+        fn test_mapped_paths_reference_their_rule() {
+            let resolver = resolver();
+            let structure = resolver.parse_type_structure("DateTime<Utc>");
+            let rule = TypeStructure::Custom("chrono::serde::ts_seconds".to_string());
+
+            for with in ["chrono::serde::ts_seconds", "ts_seconds"] {
+                assert_eq!(
+                    resolver.resolve_serde_with(with, &structure),
+                    Some(rule.clone())
+                );
+            }
+            assert_eq!(
+                resolver.resolve_serde_with("util::as_hex", &structure),
+                Some(TypeStructure::Custom("as_hex".to_string()))
+            );
+        }
+
+        #[test]
+        fn test_optional_fields_stay_optional() {
+            let resolver = resolver();
+            let structure = resolver.parse_type_structure("Option<DateTime<Utc>>");
+            assert_eq!(
+                resolver.resolve_serde_with("ts_seconds", &structure),
+                Some(TypeStructure::Optional(Box::new(TypeStructure::Custom(
+                    "chrono::serde::ts_seconds".to_string()
+                ))))
+            );
+        }
+
+        #[test]
+        fn test_unmapped_and_differently_qualified_paths() {
+            let resolver = resolver();
+            let structure = resolver.parse_type_structure("DateTime<Utc>");
+            assert!(resolver
+                .resolve_serde_with("ts_milliseconds", &structure)
+                .is_none());
+            assert!(resolver
+                .resolve_serde_with("time::serde::ts_seconds", &structure)
+                .is_none());
+        }
+    }
 }
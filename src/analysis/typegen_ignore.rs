@@ -0,0 +1,191 @@
+use std::path::{Component, Path};
+
+/// Ignore file read from the root of the scanned directory
+pub const TYPEGEN_IGNORE_FILE: &str = ".typegenignore";
+
+/// Directories that never contain sources to scan
+const SKIPPED_DIRS: [&str; 4] = ["target", "node_modules", ".git", "dist"];
+
+/// Paths excluded from scanning via `.typegenignore`, a subset of the `.gitignore` syntax:
+///
+/// - blank lines and lines starting with `#` are skipped
+/// - `*` matches within a path segment, `?` a single character and `**` any number of segments
+/// - a trailing `/` only matches directories
+/// - a pattern containing a `/` is relative to the ignore file, otherwise it matches a
+///   file or directory name at any depth
+#[derive(Debug, Clone, Default)]
+pub struct TypegenIgnore {
+    patterns: Vec<Pattern>,
+}
+
+#[derive(Debug, Clone)]
+struct Pattern {
+    segments: Vec<String>,
+    anchored: bool,
+    directories_only: bool,
+}
+
+impl TypegenIgnore {
+    /// Read `.typegenignore` from `root`, empty when there is none
+    pub fn load(root: &Path) -> Self {
+        std::fs::read_to_string(root.join(TYPEGEN_IGNORE_FILE))
+            .map(|content| Self::parse(&content))
+            .unwrap_or_default()
+    }
+
+    /// Patterns given one per entry instead of read from a file, e.g. the `include`
+    /// patterns of an additional source path
+    pub fn from_patterns(patterns: &[String]) -> Self {
+        Self::parse(&patterns.join("\n"))
+    }
+
+    pub fn parse(content: &str) -> Self {
+        let patterns = content
+            .lines()
+            .map(str::trim)
+            .filter(|line| !line.is_empty() && !line.starts_with('#'))
+            .map(|line| {
+                let directories_only = line.ends_with('/');
+                let line = line.trim_end_matches('/');
+                let anchored = line.contains('/');
+                Pattern {
+                    segments: line
+                        .trim_start_matches('/')
+                        .split('/')
+                        .map(str::to_string)
+                        .collect(),
+                    anchored,
+                    directories_only,
+                }
+            })
+            .collect();
+        Self { patterns }
+    }
+
+    /// Whether `relative_path` (relative to the ignore file) is ignored
+    pub fn is_ignored(&self, relative_path: &Path, is_dir: bool) -> bool {
+        self.matches(relative_path, is_dir)
+    }
+
+    /// Whether `relative_path` matches any of the patterns
+    pub fn matches(&self, relative_path: &Path, is_dir: bool) -> bool {
+        let segments: Vec<&str> = relative_path
+            .components()
+            .filter_map(|component| match component {
+                Component::Normal(name) => name.to_str(),
+                _ => None,
+            })
+            .collect();
+        let Some(name) = segments.last() else {
+            return false;
+        };
+
+        self.patterns.iter().any(|pattern| {
+            if pattern.directories_only && !is_dir {
+                return false;
+            }
+            if pattern.anchored {
+                let pattern_segments: Vec<&str> =
+                    pattern.segments.iter().map(String::as_str).collect();
+                match_segments(&pattern_segments, &segments)
+            } else {
+                match_wildcard(&pattern.segments[0], name)
+            }
+        })
+    }
+
+    /// Whether a directory is skipped while scanning: build output, dependencies, VCS
+    /// metadata and ignored directories
+    pub fn is_skipped_dir(&self, relative_path: &Path) -> bool {
+        let skipped_name = relative_path
+            .file_name()
+            .and_then(|name| name.to_str())
+            .is_some_and(|name| SKIPPED_DIRS.contains(&name));
+        skipped_name || self.is_ignored(relative_path, true)
+    }
+}
+
+fn match_segments(pattern: &[&str], path: &[&str]) -> bool {
+    match pattern.split_first() {
+        None => path.is_empty(),
+        Some((&"**", rest)) => (0..=path.len()).any(|skip| match_segments(rest, &path[skip..])),
+        Some((segment, rest)) => path.split_first().is_some_and(|(name, path)| {
+            match_wildcard(segment, name) && match_segments(rest, path)
+        }),
+    }
+}
+
+/// Match a name against a pattern with `*` and `?` wildcards
+pub(crate) fn match_wildcard(pattern: &str, name: &str) -> bool {
+    let pattern: Vec<char> = pattern.chars().collect();
+    let name: Vec<char> = name.chars().collect();
+    let (mut p, mut n) = (0, 0);
+    let mut backtrack: Option<(usize, usize)> = None;
+
+    while n < name.len() {
+        match pattern.get(p) {
+            Some('*') => {
+                backtrack = Some((p, n));
+                p += 1;
+            }
+            Some(&c) if c == '?' || c == name[n] => {
+                p += 1;
+                n += 1;
+            }
+            _ => match backtrack {
+                // Let the last `*` consume one more character
+                Some((star, consumed)) => {
+                    p = star + 1;
+                    n = consumed + 1;
+                    backtrack = Some((star, consumed + 1));
+                }
+                None => return false,
+            },
+        }
+    }
+    pattern[p..].iter().all(|&c| c == '*')
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_name_patterns_match_at_any_depth() {
+        let ignore = TypegenIgnore::parse("# generated\n\n*_generated.rs\nfixtures/\n");
+
+        assert!(ignore.is_ignored(Path::new("src/api_generated.rs"), false));
+        assert!(ignore.is_ignored(Path::new("tests/fixtures"), true));
+        // Trailing slash: directories only
+        assert!(!ignore.is_ignored(Path::new("src/fixtures"), false));
+        assert!(!ignore.is_ignored(Path::new("src/api.rs"), false));
+    }
+
+    #[test]
+    fn test_anchored_patterns() {
+        let ignore = TypegenIgnore::parse("/src/legacy\nvendor/**/tests\n");
+
+        assert!(ignore.is_ignored(Path::new("src/legacy"), true));
+        assert!(!ignore.is_ignored(Path::new("crates/src/legacy"), true));
+        assert!(ignore.is_ignored(Path::new("vendor/tests"), true));
+        assert!(ignore.is_ignored(Path::new("vendor/a/b/tests"), true));
+    }
+
+    #[test]
+    fn test_skipped_dirs() {
+        let ignore = TypegenIgnore::default();
+
+        assert!(ignore.is_skipped_dir(Path::new("target")));
+        assert!(ignore.is_skipped_dir(Path::new("ui/node_modules")));
+        assert!(!ignore.is_skipped_dir(Path::new("src")));
+    }
+
+    #[test]
+    fn test_wildcards() {
+        assert!(match_wildcard("*.rs", "lib.rs"));
+        assert!(match_wildcard("a*b*c", "aXXbYc"));
+        assert!(match_wildcard("mod?.rs", "mod1.rs"));
+        assert!(!match_wildcard("*.rs", "lib.ts"));
+        assert!(!match_wildcard("a*c", "abcd"));
+    }
+}
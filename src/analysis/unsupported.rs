@@ -0,0 +1,76 @@
+use serde::Serialize;
+use std::fmt;
+use std::path::Path;
+
+/// Kind of construct the generator cannot represent
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub enum UnsupportedKind {
+    TupleStruct,
+    GenericType,
+    Union,
+    MacroItems,
+}
+
+impl UnsupportedKind {
+    /// Why the construct is skipped
+    pub fn reason(&self) -> &'static str {
+        match self {
+            Self::TupleStruct => {
+                "tuple structs are only supported as single-field newtypes over a collection"
+            }
+            Self::GenericType => "generic types are not supported, no type is generated for them",
+            Self::Union => "unions are not supported, no type is generated for them",
+            Self::MacroItems => {
+                "items generated by macros are not analyzed; record generated commands with tauri-typegen-macros (macroRecords)"
+            }
+        }
+    }
+}
+
+/// A type or macro skipped during analysis, so teams know what the bindings do not cover
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UnsupportedConstruct {
+    pub name: String,
+    pub kind: UnsupportedKind,
+    pub reason: &'static str,
+    pub file_path: String,
+    pub line_number: usize,
+}
+
+impl UnsupportedConstruct {
+    pub fn new(
+        name: impl Into<String>,
+        kind: UnsupportedKind,
+        file_path: &Path,
+        line_number: usize,
+    ) -> Self {
+        Self {
+            name: name.into(),
+            kind,
+            reason: kind.reason(),
+            file_path: file_path.to_string_lossy().to_string(),
+            line_number,
+        }
+    }
+
+    /// Source location (`file:line`) of the construct
+    pub fn location(&self) -> String {
+        format!("{}:{}", self.file_path, self.line_number)
+    }
+
+    /// Consolidated report of the skipped constructs, one per line
+    pub fn report(constructs: &[Self]) -> String {
+        constructs
+            .iter()
+            .map(|construct| format!("• {}\n", construct))
+            .collect()
+    }
+}
+
+impl fmt::Display for UnsupportedConstruct {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} ({}): {}", self.name, self.location(), self.reason)
+    }
+}
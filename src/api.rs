@@ -0,0 +1,285 @@
+//! Stable entry points for embedding tauri-typegen.
+//!
+//! Everything in this module follows semantic versioning: breaking changes to these
+//! functions and types only ship in major releases. The other modules (`analysis`,
+//! `generators`, `build`, `interface`) are internals of the CLI and may change in any
+//! release.
+//!
+//! Only the analysis entry points are available without the `generators` feature.
+//!
+//! The analysis types and [`TypeStructure`] are `#[non_exhaustive]`: fields and
+//! variants may be added in minor releases. Build them with their `new` constructors.
+//!
+//! ```rust,no_run
+//! # #[cfg(feature = "generators")]
+//! # fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! use tauri_typegen::api::{self, GenerateConfig};
+//!
+//! let config = GenerateConfig {
+//!     project_path: "./src-tauri".to_string(),
+//!     output_path: "./src/generated".to_string(),
+//!     ..Default::default()
+//! };
+//!
+//! let before = api::snapshot(&api::analyze(&config)?.commands, &config);
+//! api::generate(&config)?;
+//! let after = api::snapshot(&api::analyze(&config)?.commands, &config);
+//! println!("renamed: {:?}", api::diff(&before, &after).commands);
+//! # Ok(())
+//! # }
+//! # #[cfg(not(feature = "generators"))]
+//! # fn main() {}
+//! ```
+
+use crate::analysis::CommandAnalyzer;
+#[cfg(feature = "generators")]
+use crate::build::GenerationCache;
+use crate::interface::configured_analyzer;
+#[cfg(feature = "generators")]
+use std::ops::ControlFlow;
+#[cfg(feature = "generators")]
+use std::thread;
+#[cfg(feature = "generators")]
+use std::time::Duration;
+
+pub use crate::analysis::dependency_graph::TypeDependencyGraph;
+#[cfg(feature = "generators")]
+pub use crate::build::{ApiSnapshot, CommandSignature, ParameterSignature, RenameMap};
+#[cfg(feature = "generators")]
+pub use crate::generators::base::file_writer::GeneratedFile;
+#[cfg(feature = "generators")]
+pub use crate::generators::registry::BindingsGenerator;
+pub use crate::interface::config::{
+    AdditionalSourcePath, FieldOverride, GenerateConfig, GenerationHooks, OutputFileNames,
+};
+pub use crate::interface::AnalysisResult;
+pub use crate::models::{
+    ChannelInfo, CommandInfo, CommandKind, ConstInfo, ConstValue, EventInfo, FieldInfo,
+    ParameterInfo, StructInfo, TypeStructure,
+};
+
+/// Result of the entry points, failing with a displayable error
+pub type Result<T> = std::result::Result<T, Box<dyn std::error::Error>>;
+
+/// Analyze the project at `config.project_path` without generating anything
+pub fn analyze(config: &GenerateConfig) -> Result<AnalysisResult> {
+    config.validate()?;
+    let mut analyzer = configured_analyzer(config);
+    let commands = analyzer.analyze_project(&config.project_path)?;
+    Ok(AnalysisResult {
+        commands,
+        structs: analyzer.get_discovered_structs().clone(),
+        events: analyzer.get_discovered_events().to_vec(),
+        constants: analyzer.get_discovered_constants().to_vec(),
+    })
+}
+
+/// Analyze Rust source given as a string, without reading any files
+pub fn analyze_source(source: &str) -> Result<AnalysisResult> {
+    crate::interface::analyze_source(source)
+}
+
+/// Dependency graph of the types of the project at `config.project_path`, e.g. for
+/// codegen tools needing the types in dependency order
+pub fn dependency_graph(config: &GenerateConfig) -> Result<TypeDependencyGraph> {
+    config.validate()?;
+    let mut analyzer = configured_analyzer(config);
+    analyzer.analyze_project(&config.project_path)?;
+    Ok(analyzer.get_dependency_graph().clone())
+}
+
+/// Dependency graph of the types of Rust source given as a string, without reading
+/// any files
+pub fn dependency_graph_of_source(source: &str) -> Result<TypeDependencyGraph> {
+    let mut analyzer = CommandAnalyzer::new();
+    analyzer.analyze_source(source)?;
+    Ok(analyzer.get_dependency_graph().clone())
+}
+
+/// Generate the bindings of the project into `config.output_path`, returning the
+/// names of the written files
+#[cfg(feature = "generators")]
+pub fn generate(config: &GenerateConfig) -> Result<Vec<String>> {
+    crate::interface::generate_from_config(config)
+}
+
+/// Generate the bindings of Rust source given as a string, returning the files
+/// instead of writing them
+#[cfg(feature = "generators")]
+pub fn generate_source(source: &str, config: &GenerateConfig) -> Result<Vec<GeneratedFile>> {
+    crate::interface::generate_from_source(source, config)
+}
+
+/// Register a generator of another backend, used by [`generate`], [`generate_source`]
+/// and the build system for configs with `validation_library` set to `name`. The
+/// built-in `zod`, `valibot` and `none` cannot be replaced.
+///
+/// Registrations belong to the current process: register in the build script or
+/// program that generates. The `cargo tauri-typegen` binary only knows the built-in
+/// generators and rejects other names as an invalid validation library.
+///
+/// The generator receives the same analysis as [`analyze`] and returns its files,
+/// written into `config.output_path`.
+#[cfg(feature = "generators")]
+pub fn register_generator<F>(name: &str, factory: F) -> Result<()>
+where
+    F: Fn() -> Box<dyn BindingsGenerator> + Send + Sync + 'static,
+{
+    crate::generators::registry::register(name, factory).map_err(Into::into)
+}
+
+/// Frontend-facing names and signatures of `commands`, for comparing two versions
+/// of an API with [`diff`]
+#[cfg(feature = "generators")]
+pub fn snapshot(commands: &[CommandInfo], config: &GenerateConfig) -> ApiSnapshot {
+    ApiSnapshot::new(commands, config)
+}
+
+/// Commands and parameters renamed between two snapshots
+#[cfg(feature = "generators")]
+pub fn diff(previous: &ApiSnapshot, current: &ApiSnapshot) -> RenameMap {
+    RenameMap::between(previous, current)
+}
+
+/// Generate the bindings now and again whenever the analyzed project changes,
+/// checking every `interval`.
+///
+/// `on_generate` receives the result of each generation and returns
+/// [`ControlFlow::Break`] to stop watching. A failing analysis is passed to it once
+/// instead of ending the watch, so a file saved mid-edit is picked up when fixed.
+#[cfg(feature = "generators")]
+pub fn watch<F>(config: &GenerateConfig, interval: Duration, mut on_generate: F)
+where
+    F: FnMut(Result<Vec<String>>) -> ControlFlow<()>,
+{
+    let mut last_hash: Option<String> = None;
+    let mut failing = false;
+    loop {
+        let changed = analyze(config).and_then(|analysis| {
+            let hash = GenerationCache::hash_analysis(&analysis, config)?;
+            Ok(last_hash.replace(hash.clone()) != Some(hash))
+        });
+        let flow = match changed {
+            Ok(false) => ControlFlow::Continue(()),
+            Ok(true) => {
+                failing = false;
+                on_generate(generate(config))
+            }
+            Err(_) if failing => ControlFlow::Continue(()),
+            Err(e) => {
+                failing = true;
+                last_hash = None;
+                on_generate(Err(e))
+            }
+        };
+        if flow.is_break() {
+            return;
+        }
+        thread::sleep(interval);
+    }
+}
+
+#[cfg(all(test, feature = "generators"))]
+mod tests {
+    use super::*;
+    use std::fs;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_watch_regenerates_on_change() {
+        let project = TempDir::new().unwrap();
+        let output = TempDir::new().unwrap();
+        let lib = project.path().join("lib.rs");
+        fs::write(&lib, "#[tauri::command] fn ping() -> String { todo!() }").unwrap();
+        let config = GenerateConfig {
+            project_path: project.path().display().to_string(),
+            output_path: output.path().display().to_string(),
+            validation_library: "none".to_string(),
+            ..Default::default()
+        };
+
+        let mut generations = 0;
+        watch(&config, Duration::from_millis(1), |result| {
+            assert!(result.unwrap().contains(&"commands.ts".to_string()));
+            generations += 1;
+            if generations == 1 {
+                fs::write(&lib, "#[tauri::command] fn pong() -> String { todo!() }").unwrap();
+            }
+            if generations == 2 {
+                ControlFlow::Break(())
+            } else {
+                ControlFlow::Continue(())
+            }
+        });
+
+        assert_eq!(generations, 2);
+        let commands = fs::read_to_string(output.path().join("commands.ts")).unwrap();
+        assert!(commands.contains("export async function pong("));
+    }
+
+    struct Manifest;
+
+    impl BindingsGenerator for Manifest {
+        fn generate(
+            &mut self,
+            analysis: &AnalysisResult,
+            config: &GenerateConfig,
+        ) -> Result<Vec<GeneratedFile>> {
+            Ok(vec![GeneratedFile {
+                name: "manifest.json".to_string(),
+                content: format!(
+                    "{{\"backend\":\"{}\",\"commands\":{}}}",
+                    config.validation_library,
+                    analysis.commands.len()
+                ),
+            }])
+        }
+    }
+
+    #[test]
+    fn test_registered_generator_is_selected_by_validation_library() {
+        register_generator("api-manifest", || Box::new(Manifest)).unwrap();
+        assert!(register_generator("valibot", || Box::new(Manifest)).is_err());
+
+        let config = GenerateConfig {
+            validation_library: "api-manifest".to_string(),
+            ..Default::default()
+        };
+        config.validate_options().unwrap();
+        let files = generate_source(
+            "#[tauri::command] fn ping() {} #[tauri::command] fn pong() {}",
+            &config,
+        )
+        .unwrap();
+        assert_eq!(files.len(), 1);
+        assert_eq!(
+            files[0].content,
+            "{\"backend\":\"api-manifest\",\"commands\":2}"
+        );
+    }
+    #[test]
+    fn test_constructors_parse_rust_types() {
+        let field = FieldInfo::new("nickname", "Option<String>");
+        assert!(field.is_optional);
+        assert!(matches!(field.type_structure, TypeStructure::Optional(_)));
+
+        let user = StructInfo::new("User", vec![field, FieldInfo::new("id", "u32")]);
+        assert!(!user.is_enum);
+        assert_eq!(user.fields.len(), 2);
+
+        let command = CommandInfo::new(
+            "get_user",
+            vec![ParameterInfo::new("id", "u32")],
+            "Result<User, String>",
+        );
+        assert!(!command.parameters[0].is_optional);
+        assert_eq!(
+            command.return_type_structure,
+            TypeStructure::Result(Box::new(TypeStructure::Custom("User".to_string())))
+        );
+        assert_eq!(
+            EventInfo::new("user-updated", "User").payload_type_structure,
+            TypeStructure::Custom("User".to_string())
+        );
+    }
+}
@@ -1,12 +1,16 @@
 use clap::Parser;
 use std::fs;
 use std::path::PathBuf;
-use tauri_typegen::analysis::CommandAnalyzer;
-use tauri_typegen::build::GenerationCache;
-use tauri_typegen::generators::create_generator;
+use tauri_typegen::analysis::impact::Impact;
+use tauri_typegen::analysis::ir::AnalysisIr;
+use tauri_typegen::build::{ApiSnapshot, GenerationCache, HookRunner, RenameMap};
+use tauri_typegen::generators::base::context_dump::TemplateContextDump;
+use tauri_typegen::generators::base::explain::Explanation;
+use tauri_typegen::generators::{create_generator, generator_for};
 use tauri_typegen::interface::{
-    print_dependency_visualization_info, print_usage_info, CargoCli, CargoSubcommands,
-    GenerateConfig, Logger, ProgressReporter, TypegenCommands,
+    configured_analyzer, print_dependency_visualization_info, print_usage_info, CargoCli,
+    CargoSubcommands, ConfigError, GenerateConfig, Locale, LogLevel, Logger, Message, MessageId,
+    Phase, ProgressReporter, TypegenCommands, Verbosity,
 };
 
 fn main() {
@@ -20,9 +24,18 @@ fn main() {
                 return;
             }
 
+            let locale = typegen_args.output_locale.unwrap_or_default();
+            let report_error = |e: Box<dyn std::error::Error>| {
+                let message = Message::new(MessageId::Error).arg("error", e);
+                eprintln!("{}", message.render(locale));
+                std::process::exit(1);
+            };
+
             // If no subcommand provided, show error
             let Some(command) = typegen_args.command else {
-                eprintln!("Error: No subcommand provided. Use 'generate' or 'init'.");
+                eprintln!(
+                    "Error: No subcommand provided. Use 'generate', 'init', 'template-context', 'impact' or 'explain'."
+                );
                 eprintln!("Run 'cargo tauri-typegen --help' for more information.");
                 std::process::exit(1);
             };
@@ -33,21 +46,74 @@ fn main() {
                     output_path,
                     validation_library,
                     verbose,
+                    trace,
                     visualize_deps,
                     config_file,
                     force,
+                    viz_focus,
+                    viz_depth,
+                    viz_format,
+                    save_ir,
+                    from_ir,
                 } => {
                     if let Err(e) = run_generate(
                         project_path,
                         output_path,
                         validation_library,
                         verbose,
+                        trace,
                         visualize_deps,
+                        viz_focus,
+                        viz_depth,
+                        viz_format,
                         config_file,
                         force,
+                        save_ir,
+                        from_ir,
+                        locale,
+                    ) {
+                        report_error(e);
+                    }
+                }
+                TypegenCommands::TemplateContext {
+                    project_path,
+                    validation_library,
+                    config_file,
+                    check,
+                } => {
+                    if let Err(e) = run_template_context(
+                        project_path,
+                        validation_library,
+                        config_file,
+                        check,
+                        locale,
                     ) {
-                        eprintln!("Error: {}", e);
-                        std::process::exit(1);
+                        report_error(e);
+                    }
+                }
+                TypegenCommands::Impact {
+                    type_name,
+                    project_path,
+                    config_file,
+                } => {
+                    if let Err(e) = run_impact(&type_name, project_path, config_file, locale) {
+                        report_error(e);
+                    }
+                }
+                TypegenCommands::Explain {
+                    symbol,
+                    project_path,
+                    validation_library,
+                    config_file,
+                } => {
+                    if let Err(e) = run_explain(
+                        &symbol,
+                        project_path,
+                        validation_library,
+                        config_file,
+                        locale,
+                    ) {
+                        report_error(e);
                     }
                 }
                 TypegenCommands::Init {
@@ -67,9 +133,9 @@ fn main() {
                         verbose,
                         visualize_deps,
                         force,
+                        locale,
                     ) {
-                        eprintln!("Error: {}", e);
-                        std::process::exit(1);
+                        report_error(e);
                     }
                 }
             }
@@ -77,59 +143,33 @@ fn main() {
     }
 }
 
+#[allow(clippy::too_many_arguments)]
 fn run_generate(
     project_path: Option<PathBuf>,
     output_path: Option<PathBuf>,
     validation_library: Option<String>,
-    verbose: bool,
+    verbose: u8,
+    trace: Vec<String>,
     visualize_deps: bool,
+    viz_focus: Option<String>,
+    viz_depth: Option<usize>,
+    viz_format: Option<String>,
     config_file: Option<PathBuf>,
     force: bool,
+    save_ir: Option<PathBuf>,
+    from_ir: Option<PathBuf>,
+    locale: Locale,
 ) -> Result<(), Box<dyn std::error::Error>> {
-    let logger = Logger::new(verbose, false);
+    let traced = trace
+        .iter()
+        .filter_map(|phase| Phase::parse(phase))
+        .collect();
+    let logger = Logger::with_verbosity(Verbosity::new(verbose, traced)).with_locale(locale);
     let mut reporter = ProgressReporter::new(logger, 4);
 
     // Load configuration
-    reporter.start_step("Loading configuration");
-    let mut config = if let Some(config_path) = config_file {
-        // Explicit config file specified
-        if config_path.exists() {
-            GenerateConfig::from_file(config_path)?
-        } else {
-            return Err(format!("Configuration file not found: {}", config_path.display()).into());
-        }
-    } else {
-        // Try to find tauri.conf.json in common locations
-        let possible_paths = vec![
-            PathBuf::from("tauri.conf.json"),           // Current directory
-            PathBuf::from("src-tauri/tauri.conf.json"), // Common Tauri structure
-            PathBuf::from("../tauri.conf.json"),        // If running from src-tauri
-        ];
-
-        let mut config_loaded = false;
-        let mut config = GenerateConfig::default();
-
-        for path in possible_paths {
-            if path.exists() {
-                match GenerateConfig::from_tauri_config(&path) {
-                    Ok(Some(loaded_config)) => {
-                        config = loaded_config;
-                        config_loaded = true;
-                        break;
-                    }
-                    Ok(None) => break,
-                    Err(_) => continue,
-                }
-            }
-        }
-
-        if !config_loaded {
-            // No config file found, use defaults
-            config = GenerateConfig::default();
-        }
-
-        config
-    };
+    reporter.start_step(&reporter.text(Message::new(MessageId::StepLoadingConfig)));
+    let mut config = load_config(config_file)?;
 
     // CLI arguments override config file settings only when explicitly provided
     if let Some(path) = project_path {
@@ -141,84 +181,140 @@ fn run_generate(
     if let Some(validation) = validation_library {
         config.validation_library = validation;
     }
-    // For boolean flags: only override if flag was present (true)
-    if verbose {
+    // For flags: only override if present
+    if verbose > 0 {
         config.verbose = Some(true);
+        config.verbosity = Some(verbose);
+    }
+    if !trace.is_empty() {
+        config.trace = Some(trace);
     }
     if visualize_deps {
         config.visualize_deps = Some(true);
     }
+    if viz_focus.is_some() {
+        config.viz_focus = viz_focus;
+    }
+    if viz_depth.is_some() {
+        config.viz_depth = viz_depth;
+    }
+    if viz_format.is_some() {
+        config.viz_format = viz_format;
+    }
     // CLI --force flag overrides config
     if force {
         config.force = Some(true);
     }
 
-    reporter.complete_step(Some(&format!(
-        "Using {} validation",
-        config.validation_library
+    reporter.set_verbosity(config.verbosity());
+    reporter.complete_step(Some(&reporter.text(
+        Message::new(MessageId::UsingValidation).arg("library", &config.validation_library),
     )));
 
     // Validate paths and configuration
-    reporter.start_step("Validating project structure");
-    config.validate()?;
+    reporter.start_step(&reporter.text(Message::new(MessageId::StepValidatingProject)));
+    if from_ir.is_some() {
+        // The project sources are not needed when generating from an IR
+        config.validate_options()?;
+    } else {
+        config.validate()?;
+    }
     reporter.complete_step(None);
 
     // Analyze and generate
-    reporter.start_step("Analyzing Tauri commands");
-    let mut analyzer = CommandAnalyzer::new();
+    reporter.start_step(&reporter.text(Message::new(MessageId::StepAnalyzing)));
+    let mut analyzer = configured_analyzer(&config);
+    analyzer.set_locale(locale);
 
-    // Apply custom type mappings from configuration
     if let Some(ref mappings) = config.type_mappings {
-        analyzer.add_type_mappings(mappings);
-        if config.is_verbose() {
-            reporter.update_progress(&format!("Applied {} custom type mappings", mappings.len()));
-            for (rust_type, ts_type) in mappings {
-                reporter.update_progress(&format!("  {} → {}", rust_type, ts_type));
-            }
+        reporter.detail(
+            Phase::Analysis,
+            2,
+            &format!("Applied {} custom type mappings", mappings.len()),
+        );
+        for (rust_type, ts_type) in mappings {
+            reporter.detail(
+                Phase::Analysis,
+                2,
+                &format!("  {} → {}", rust_type, ts_type),
+            );
         }
     }
+    if let Some(ref mappings) = config.collection_mappings {
+        reporter.detail(
+            Phase::Analysis,
+            2,
+            &format!("Applied {} custom collection mappings", mappings.len()),
+        );
+    }
 
-    let commands =
-        analyzer.analyze_project_with_verbose(&config.project_path, config.is_verbose())?;
-
-    if config.is_verbose() {
-        reporter.update_progress(&format!("Found {} Tauri commands", commands.len()));
-        commands.iter().for_each(|cmd| {
-            reporter.update_progress(&format!("  - {} ({})", cmd.name, cmd.file_path));
-        });
-
-        let discovered_structs = analyzer.get_discovered_structs();
-        reporter.update_progress(&format!(
-            "Found {} struct definitions",
-            discovered_structs.len()
-        ));
-        discovered_structs.iter().for_each(|(name, struct_info)| {
-            let struct_type = if struct_info.is_enum {
-                "enum"
-            } else {
-                "struct"
-            };
-            reporter.update_progress(&format!(
+    let commands = match from_ir {
+        Some(ref path) => analyzer.load_ir(AnalysisIr::load(path)?),
+        None => {
+            analyzer.analyze_project_with_verbosity(&config.project_path, &config.verbosity())?
+        }
+    };
+    if let Some(ref path) = save_ir {
+        AnalysisIr::new(&commands, &analyzer).save(path)?;
+        reporter.detail(
+            Phase::Analysis,
+            1,
+            &format!("Saved analysis IR to {}", path.display()),
+        );
+    }
+
+    reporter.detail(
+        Phase::Analysis,
+        1,
+        &format!("Found {} Tauri commands", commands.len()),
+    );
+    commands.iter().for_each(|cmd| {
+        reporter.detail(
+            Phase::Analysis,
+            2,
+            &format!("  - {} ({})", cmd.name, cmd.file_path),
+        );
+    });
+
+    let discovered_structs = analyzer.get_discovered_structs();
+    reporter.detail(
+        Phase::Analysis,
+        1,
+        &format!("Found {} struct definitions", discovered_structs.len()),
+    );
+    discovered_structs.iter().for_each(|(name, struct_info)| {
+        let struct_type = if struct_info.is_enum {
+            "enum"
+        } else {
+            "struct"
+        };
+        reporter.detail(
+            Phase::Analysis,
+            2,
+            &format!(
                 "  - {} ({}) with {} fields",
                 name,
                 struct_type,
                 struct_info.fields.len()
-            ));
-        });
-    }
-    reporter.complete_step(Some(&format!("Found {} commands", commands.len())));
+            ),
+        );
+    });
+    reporter.complete_step(Some(
+        &reporter.text(Message::new(MessageId::FoundCommands).arg("count", commands.len())),
+    ));
 
-    if commands.is_empty() {
-        println!("⚠️  No Tauri commands found. Make sure your project contains functions with #[tauri::command] attributes.");
+    if commands.is_empty() && !config.should_include_all_types() {
+        println!(
+            "⚠️  {}",
+            reporter.text(Message::new(MessageId::NoCommandsFound))
+        );
         return Ok(());
     }
 
     // Check cache to see if regeneration is needed (unless force is set)
     let discovered_structs = analyzer.get_discovered_structs();
     let needs_regeneration = if config.should_force() {
-        if config.is_verbose() {
-            println!("🔄 Force flag set, regenerating bindings");
-        }
+        reporter.detail(Phase::Cache, 1, "Force flag set, regenerating bindings");
         true
     } else {
         GenerationCache::needs_regeneration(
@@ -230,26 +326,46 @@ fn run_generate(
         .unwrap_or(true) // On error, assume regeneration is needed
     };
 
+    let mut generator = generator_for(&config);
+    let language = generator.language();
+
     if !needs_regeneration {
-        if config.is_verbose() {
-            println!("✨ Cache hit - no changes detected, skipping generation");
-        }
-        println!("✅ TypeScript bindings are up to date");
+        reporter.detail(
+            Phase::Cache,
+            1,
+            "Cache hit - no changes detected, skipping generation",
+        );
+        println!(
+            "✅ {}",
+            reporter.text(Message::new(MessageId::UpToDate).arg("language", &language))
+        );
+        reporter.payload_diagnostics(analyzer.get_payload_diagnostics());
+        reporter.unsupported_constructs(analyzer.get_unsupported_constructs());
         return Ok(());
     }
 
-    if config.is_verbose() && !config.should_force() {
-        println!("🔄 Changes detected, regenerating bindings");
+    if !config.should_force() {
+        reporter.detail(Phase::Cache, 1, "Changes detected, regenerating bindings");
     }
 
     // Generate bindings
-    reporter.start_step("Generating TypeScript bindings");
-    let validation = match config.validation_library.as_str() {
-        "zod" | "none" => Some(config.validation_library.clone()),
-        _ => return Err("Invalid validation library. Use 'zod' or 'none'".into()),
-    };
+    reporter.start_step(
+        &reporter.text(Message::new(MessageId::StepGenerating).arg("language", &language)),
+    );
+    if !config.has_known_generator() {
+        return Err(
+            ConfigError::InvalidValidationLibrary(config.validation_library.clone()).into(),
+        );
+    }
+    let hooks = HookRunner::new(&config);
+    if let Some(command) = hooks.run_before()? {
+        reporter.detail(
+            Phase::Generation,
+            1,
+            &format!("Ran before hook: {}", command),
+        );
+    }
 
-    let mut generator = create_generator(validation);
     let generated_files = generator.generate_models(
         &commands,
         discovered_structs,
@@ -257,34 +373,209 @@ fn run_generate(
         &analyzer,
         &config,
     )?;
-    reporter.complete_step(Some(&format!("Generated {} files", generated_files.len())));
+    reporter.complete_step(Some(&reporter.text(
+        Message::new(MessageId::GeneratedFiles).arg("count", generated_files.len()),
+    )));
 
     // Generate dependency visualization if requested
     if config.should_visualize_deps() {
-        let text_viz = analyzer.visualize_dependencies(&commands);
-        let viz_file_path = PathBuf::from(&config.output_path).join("dependency-graph.txt");
-        fs::write(&viz_file_path, text_viz)?;
+        let viz_filter = config.viz_filter();
+        let viz_formats = config.viz_formats();
+        for format in &viz_formats {
+            let viz = analyzer.render_dependency_graph(&commands, *format, &viz_filter);
+            fs::write(
+                PathBuf::from(&config.output_path).join(format.file_name()),
+                viz,
+            )?;
+        }
 
-        let dot_viz = analyzer.generate_dot_graph(&commands);
-        let dot_file_path = PathBuf::from(&config.output_path).join("dependency-graph.dot");
-        fs::write(&dot_file_path, dot_viz)?;
+        print_dependency_visualization_info(&config.output_path, &viz_formats);
+    }
 
-        print_dependency_visualization_info(&config.output_path);
+    // Run before saving the cache, so a failed hook is retried on the next run
+    if let Some(command) = hooks.run_after(&generated_files)? {
+        reporter.detail(
+            Phase::Generation,
+            1,
+            &format!("Ran after hook: {}", command),
+        );
+    }
+
+    // Compare against the API recorded by the previous generation before replacing it
+    if config.should_generate_rename_map() {
+        if let Some(previous) = GenerationCache::load_api(&config.output_path) {
+            let renames = RenameMap::between(&previous, &ApiSnapshot::new(&commands, &config));
+            if !renames.is_empty() {
+                let path = renames.save(&config.output_path)?;
+                reporter.detail(
+                    Phase::Generation,
+                    1,
+                    &format!(
+                        "Detected {} renames, wrote {}",
+                        renames.len(),
+                        path.display()
+                    ),
+                );
+            }
+        }
     }
 
     // Save cache after successful generation
     let cache = GenerationCache::new(&commands, discovered_structs, &config)?;
     if let Err(e) = cache.save(&config.output_path) {
-        eprintln!("Warning: Failed to save generation cache: {}", e);
+        eprintln!(
+            "{}",
+            reporter.text(Message::new(MessageId::CacheSaveFailed).arg("error", e))
+        );
     }
 
     // Print summary
-    reporter.finish("Generation complete");
-    print_usage_info(&config.output_path, &generated_files, commands.len());
+    reporter.finish(&reporter.text(Message::new(MessageId::GenerationComplete)));
+    print_usage_info(
+        &config.output_path,
+        &language,
+        generator
+            .import_hint(&config.output_path, &config)
+            .as_deref(),
+        commands.len(),
+        locale,
+    );
+    if !analyzer.get_payload_diagnostics().is_empty() {
+        println!();
+        reporter.payload_diagnostics(analyzer.get_payload_diagnostics());
+    }
+    if !analyzer.get_unsupported_constructs().is_empty() {
+        println!();
+        reporter.unsupported_constructs(analyzer.get_unsupported_constructs());
+    }
+
+    Ok(())
+}
+
+fn run_template_context(
+    project_path: Option<PathBuf>,
+    validation_library: Option<String>,
+    config_file: Option<PathBuf>,
+    check: Vec<PathBuf>,
+    locale: Locale,
+) -> Result<(), Box<dyn std::error::Error>> {
+    let mut config = load_config(config_file)?;
+    if let Some(path) = project_path {
+        config.project_path = path.to_string_lossy().to_string();
+    }
+    if let Some(validation) = validation_library {
+        config.validation_library = validation;
+    }
+    config.validate()?;
+
+    let mut analyzer = configured_analyzer(&config);
+    analyzer.set_locale(locale);
+    let commands = analyzer.analyze_project(&config.project_path)?;
+    let dump = TemplateContextDump::new(&commands, &analyzer, &config);
+
+    if check.is_empty() {
+        println!("{}", serde_json::to_string_pretty(&dump)?);
+        return Ok(());
+    }
+
+    // Checked templates may include and use the filters of the built-in templates
+    let generator = create_generator(Some(config.generator_library().to_string()));
+    let mut failed = 0;
+    for path in &check {
+        let source = fs::read_to_string(path)
+            .map_err(|e| format!("Failed to read template {}: {}", path.display(), e))?;
+        match dump.check_template(generator.tera(), &path.to_string_lossy(), &source) {
+            Ok(()) => println!("✅ {}", path.display()),
+            Err(e) => {
+                eprintln!("❌ {}: {}", path.display(), e);
+                failed += 1;
+            }
+        }
+    }
+
+    if failed > 0 {
+        return Err(format!("{} of {} templates failed to render", failed, check.len()).into());
+    }
+    Ok(())
+}
+
+fn run_impact(
+    type_name: &str,
+    project_path: Option<PathBuf>,
+    config_file: Option<PathBuf>,
+    locale: Locale,
+) -> Result<(), Box<dyn std::error::Error>> {
+    let mut config = load_config(config_file)?;
+    if let Some(path) = project_path {
+        config.project_path = path.to_string_lossy().to_string();
+    }
+    config.validate()?;
+
+    let mut analyzer = configured_analyzer(&config);
+    analyzer.set_locale(locale);
+    let commands = analyzer.analyze_project(&config.project_path)?;
+    let impact = Impact::of(type_name, &analyzer, &commands)
+        .ok_or_else(|| format!("No struct or enum named '{}' was found", type_name))?;
+
+    print!("{}", impact.report(&config, &analyzer));
+    Ok(())
+}
 
+fn run_explain(
+    symbol: &str,
+    project_path: Option<PathBuf>,
+    validation_library: Option<String>,
+    config_file: Option<PathBuf>,
+    locale: Locale,
+) -> Result<(), Box<dyn std::error::Error>> {
+    let mut config = load_config(config_file)?;
+    if let Some(path) = project_path {
+        config.project_path = path.to_string_lossy().to_string();
+    }
+    if let Some(validation) = validation_library {
+        config.validation_library = validation;
+    }
+    config.validate()?;
+
+    let mut analyzer = configured_analyzer(&config);
+    analyzer.set_locale(locale);
+    let commands = analyzer.analyze_project(&config.project_path)?;
+    let explanation = Explanation::of(symbol, &commands, &analyzer, &config)?;
+
+    print!("{}", explanation.report());
     Ok(())
 }
 
+fn load_config(config_file: Option<PathBuf>) -> Result<GenerateConfig, Box<dyn std::error::Error>> {
+    let config = if let Some(config_path) = config_file {
+        // Explicit config file specified
+        if config_path.exists() {
+            GenerateConfig::from_file(config_path)?
+        } else {
+            return Err(format!("Configuration file not found: {}", config_path.display()).into());
+        }
+    } else {
+        // Try to find tauri.conf.json in common locations
+        let possible_paths = [
+            PathBuf::from("tauri.conf.json"),           // Current directory
+            PathBuf::from("src-tauri/tauri.conf.json"), // Common Tauri structure
+            PathBuf::from("../tauri.conf.json"),        // If running from src-tauri
+        ];
+
+        // The first tauri.conf.json found is used; without a typegen section or any
+        // tauri.conf.json the defaults apply
+        match possible_paths.iter().find(|path| path.exists()) {
+            Some(path) => GenerateConfig::from_tauri_config(path)
+                .map_err(|e| format!("Invalid configuration in {}: {}", path.display(), e))?
+                .unwrap_or_default(),
+            None => GenerateConfig::default(),
+        }
+    };
+
+    Ok(config)
+}
+
+#[allow(clippy::too_many_arguments)]
 fn run_init(
     project_path: Option<PathBuf>,
     generated_path: Option<PathBuf>,
@@ -293,10 +584,14 @@ fn run_init(
     verbose: bool,
     visualize_deps: bool,
     force: bool,
+    locale: Locale,
 ) -> Result<(), Box<dyn std::error::Error>> {
-    let logger = Logger::new(verbose, false);
+    let logger = Logger::new(verbose, false).with_locale(locale);
+    let info = |icon: &str, message: Message| {
+        logger.log(LogLevel::Info, &format!("{}{}", icon, logger.text(message)));
+    };
 
-    logger.info("🚀 Initializing Tauri TypeScript generation configuration");
+    info("🚀 ", Message::new(MessageId::InitStart));
 
     // Resolve paths with defaults
     let project_path = project_path.unwrap_or_else(|| PathBuf::from("./src-tauri"));
@@ -353,49 +648,57 @@ fn run_init(
         }
 
         config.save_to_tauri_config(&output_path)?;
-        logger.info(&format!(
-            "✅ Updated typegen configuration in {}",
-            output_path.display()
-        ));
+        info(
+            "✅ ",
+            Message::new(MessageId::InitUpdatedTauriConfig).arg("path", output_path.display()),
+        );
     } else {
         config.save_to_file(&output_path)?;
-        logger.info(&format!(
-            "✅ Created configuration file: {}",
-            output_path.display()
-        ));
+        info(
+            "✅ ",
+            Message::new(MessageId::InitCreatedConfig).arg("path", output_path.display()),
+        );
     }
 
     // Print configuration summary
-    logger.info("📋 Configuration summary:");
-    logger.info(&format!("  • Project path: {}", config.project_path));
-    logger.info(&format!(
-        "  • Generated files output path: {}",
-        config.output_path
-    ));
-    logger.info(&format!(
-        "  • Validation library: {}",
-        config.validation_library
-    ));
+    info("📋 ", Message::new(MessageId::InitSummary));
+    info(
+        "  • ",
+        Message::new(MessageId::InitProjectPath).arg("path", &config.project_path),
+    );
+    info(
+        "  • ",
+        Message::new(MessageId::InitOutputPath).arg("path", &config.output_path),
+    );
+    info(
+        "  • ",
+        Message::new(MessageId::InitValidation).arg("library", &config.validation_library),
+    );
 
     // Now run initial generation
     logger.info("");
-    logger.info("🔄 Running initial generation...");
+    info("🔄 ", Message::new(MessageId::InitRunning));
 
     run_generate(
         Some(project_path),
         Some(generated_path),
         Some(config.validation_library.clone()),
-        verbose,
+        u8::from(verbose),
+        Vec::new(),
         visualize_deps,
+        None,
+        None,
+        None,
         None,  // No config file since we just created one
         false, // Respect cache behavior
+        None,
+        None,
+        locale,
     )?;
 
     logger.info("");
-    logger.info(
-        "✨ Initialization complete! Your Tauri project is now set up for TypeScript generation.",
-    );
-    logger.info("💡 You can run 'cargo tauri-typegen generate' anytime to regenerate bindings.");
+    info("✨ ", Message::new(MessageId::InitComplete));
+    info("💡 ", Message::new(MessageId::InitHint));
 
     Ok(())
 }
@@ -1,7 +1,12 @@
-use crate::interface::config::GenerateConfig;
-use crate::models::{CommandInfo, StructInfo};
+use crate::build::rename_map::ApiSnapshot;
+use crate::generators::base::validation_messages::ValidationMessages;
+use crate::interface::config::{
+    CommandOptions, FieldOverride, GenerateConfig, OutputFileNames, SharedTypes,
+};
+use crate::interface::AnalysisResult;
+use crate::models::{CallSerialization, CommandInfo, StructInfo};
 use serde::{Deserialize, Serialize};
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
 use std::fs;
 use std::path::{Path, PathBuf};
 use thiserror::Error;
@@ -32,6 +37,9 @@ pub struct GenerationCache {
     config_hash: String,
     /// Combined hash for quick comparison
     combined_hash: String,
+    /// Command API of the generation, to detect renames on the next one
+    #[serde(default)]
+    api: Option<ApiSnapshot>,
 }
 
 impl GenerationCache {
@@ -54,9 +62,29 @@ impl GenerationCache {
             structs_hash,
             config_hash,
             combined_hash,
+            api: Some(ApiSnapshot::new(commands, config)),
         })
     }
 
+    /// Hash of an analysis and the configuration, covering the events and constants
+    /// besides the commands and structs the cache file records
+    pub(crate) fn hash_analysis(
+        analysis: &AnalysisResult,
+        config: &GenerateConfig,
+    ) -> Result<String, CacheError> {
+        let cache = Self::new(&analysis.commands, &analysis.structs, config)?;
+        let events_and_constants = serde_json::to_string(&(&analysis.events, &analysis.constants))?;
+        Ok(Self::compute_hash(&format!(
+            "{}{}",
+            cache.combined_hash, events_and_constants
+        )))
+    }
+
+    /// Command API recorded by the previous generation in `output_dir`, if any
+    pub fn load_api<P: AsRef<Path>>(output_dir: P) -> Option<ApiSnapshot> {
+        Self::load(output_dir).ok().and_then(|cache| cache.api)
+    }
+
     /// Load cache from file
     pub fn load<P: AsRef<Path>>(output_dir: P) -> Result<Self, CacheError> {
         let cache_path = Self::cache_path(output_dir);
@@ -123,6 +151,10 @@ impl GenerationCache {
             return_type: &'a str,
             is_async: bool,
             channels: Vec<ChannelHashData<'a>>,
+            #[serde(skip_serializing_if = "Option::is_none")]
+            serialize_calls: Option<&'a CallSerialization>,
+            #[serde(skip_serializing_if = "<[String]>::is_empty")]
+            invalidates: &'a [String],
         }
 
         #[derive(Serialize)]
@@ -162,6 +194,8 @@ impl GenerationCache {
                         message_type: &c.message_type,
                     })
                     .collect(),
+                serialize_calls: cmd.serialize_calls.as_ref(),
+                invalidates: &cmd.invalidates,
             })
             .collect();
 
@@ -220,17 +254,142 @@ impl GenerationCache {
         struct ConfigHashData<'a> {
             validation_library: &'a str,
             include_private: bool,
-            type_mappings: Option<&'a HashMap<String, String>>,
+            type_mappings: Option<BTreeMap<&'a String, &'a String>>,
+            serde_with_mappings: Option<BTreeMap<&'a String, &'a String>>,
+            collection_mappings: Option<BTreeMap<&'a String, &'a String>>,
+            union_types: Option<&'a Vec<String>>,
+            injected_types: Option<&'a Vec<String>>,
+            field_overrides: Option<BTreeMap<&'a String, &'a FieldOverride>>,
+            command_options: Option<BTreeMap<&'a String, &'a CommandOptions>>,
+            command_validation: Option<BTreeMap<&'a String, &'a String>>,
+            aliases: Option<BTreeMap<&'a String, &'a String>>,
+            range_literals: Option<BTreeMap<&'a String, &'a u32>>,
+            shared_types: Option<&'a Vec<SharedTypes>>,
+            non_empty_paths: bool,
+            enum_helpers: bool,
+            notify_commands: bool,
+            batch_notifications: bool,
+            global_declarations: bool,
+            schema_registry: bool,
+            form_hooks: bool,
+            xstate_actors: bool,
+            web_fallback: bool,
+            arbitraries: bool,
+            test_harness: bool,
+            mocks: bool,
+            binary_helpers: bool,
+            offline_queue: bool,
+            query_keys: bool,
+            source_trace: bool,
+            event_router: bool,
+            sanitize_params: bool,
+            all_types: bool,
+            error_classes: bool,
+            rename_map: bool,
+            max_commands_per_file: Option<usize>,
+            http_bridge: Option<&'a str>,
+            admin_panel: Option<&'a str>,
+            lint_config: Option<&'a str>,
+            framework: Option<&'a str>,
+            unknown_type_fallback: &'a str,
+            zod_object_mode: &'a str,
+            stores: Vec<(&'a str, &'a str)>,
+            compact_types: Vec<&'a str>,
+            compact_codec: Option<&'a str>,
+            self_test: Option<&'a str>,
+            strict_serde_derives: bool,
+            skip_zero_sized_fields: bool,
+            separate_input_types: bool,
+            target: &'a str,
+            kotlin_package: &'a str,
+            validation_messages: Option<ValidationMessages>,
+            acronyms: &'a [String],
             default_parameter_case: &'a str,
             default_field_case: &'a str,
+            output_file_names: Option<&'a OutputFileNames>,
         }
 
         let hash_data = ConfigHashData {
             validation_library: &config.validation_library,
             include_private: config.include_private.unwrap_or(false),
-            type_mappings: config.type_mappings.as_ref(),
+            // Maps are hashed in key order: `HashMap` iteration order differs per process
+            type_mappings: config
+                .type_mappings
+                .as_ref()
+                .map(|mappings| mappings.iter().collect()),
+            serde_with_mappings: config
+                .serde_with_mappings
+                .as_ref()
+                .map(|mappings| mappings.iter().collect()),
+            collection_mappings: config
+                .collection_mappings
+                .as_ref()
+                .map(|mappings| mappings.iter().collect()),
+            union_types: config.union_types.as_ref(),
+            injected_types: config.injected_types.as_ref(),
+            field_overrides: config
+                .field_overrides
+                .as_ref()
+                .map(|overrides| overrides.iter().collect()),
+            command_options: config
+                .command_options
+                .as_ref()
+                .map(|options| options.iter().collect()),
+            command_validation: config
+                .command_validation
+                .as_ref()
+                .map(|validation| validation.iter().collect()),
+            aliases: config
+                .aliases
+                .as_ref()
+                .map(|aliases| aliases.iter().collect()),
+            range_literals: config
+                .range_literals
+                .as_ref()
+                .map(|limits| limits.iter().collect()),
+            shared_types: config.shared_types.as_ref(),
+            non_empty_paths: config.should_require_non_empty_paths(),
+            enum_helpers: config.should_generate_enum_helpers(),
+            notify_commands: config.should_generate_notify_commands(),
+            batch_notifications: config.should_batch_notifications(),
+            global_declarations: config.should_generate_global_declarations(),
+            schema_registry: config.should_generate_schema_registry(),
+            form_hooks: config.should_generate_form_hooks(),
+            xstate_actors: config.should_generate_xstate_actors(),
+            web_fallback: config.should_generate_web_fallback(),
+            arbitraries: config.should_generate_arbitraries(),
+            test_harness: config.should_generate_test_harness(),
+            mocks: config.should_generate_mocks(),
+            binary_helpers: config.should_generate_binary_helpers(),
+            offline_queue: config.should_generate_offline_queue(),
+            query_keys: config.should_generate_query_keys(),
+            source_trace: config.should_generate_source_trace(),
+            event_router: config.should_generate_event_router(),
+            sanitize_params: config.should_generate_sanitize_params(),
+            all_types: config.should_include_all_types(),
+            error_classes: config.should_generate_error_classes(),
+            rename_map: config.should_generate_rename_map(),
+            max_commands_per_file: config.max_commands_per_file,
+            http_bridge: config.http_bridge.as_deref(),
+            admin_panel: config.admin_panel.as_deref(),
+            lint_config: config.lint_config.as_deref(),
+            framework: config.framework(),
+            unknown_type_fallback: config.unknown_type_fallback(),
+            zod_object_mode: config.zod_object_mode(),
+            stores: config.stores(),
+            compact_types: config.compact_types(),
+            compact_codec: config.compact_codec.as_deref(),
+            self_test: config.self_test.as_deref(),
+            strict_serde_derives: config.should_enforce_serde_derives(),
+            skip_zero_sized_fields: config.should_skip_zero_sized_fields(),
+            separate_input_types: config.should_separate_input_types(),
+            target: config.target(),
+            kotlin_package: config.kotlin_package(),
+            validation_messages: config.validation_messages().ok(),
+            acronyms: config.acronyms(),
             default_parameter_case: &config.default_parameter_case,
             default_field_case: &config.default_field_case,
+            output_file_names: config.output_file_names.as_ref(),
         };
 
         let json = serde_json::to_string(&hash_data)?;
@@ -266,14 +425,76 @@ mod tests {
             output_path: "./src/generated".to_string(),
             validation_library: "none".to_string(),
             verbose: Some(false),
+            verbosity: None,
+            trace: None,
             visualize_deps: Some(false),
             include_private: Some(false),
             type_mappings: None,
+            serde_with_mappings: None,
             exclude_patterns: None,
             include_patterns: None,
+            max_scan_depth: None,
             default_parameter_case: "camelCase".to_string(),
             default_field_case: "snake_case".to_string(),
             force: Some(false),
+            output_file_names: None,
+            collection_mappings: None,
+            union_types: None,
+            non_empty_paths: None,
+            enum_helpers: None,
+            notify_commands: None,
+            batch_notifications: None,
+            acronyms: None,
+            global_declarations: None,
+            strict_serde_derives: None,
+            skip_zero_sized_fields: None,
+            separate_input_types: None,
+            target: None,
+            kotlin_package: None,
+            viz_focus: None,
+            viz_depth: None,
+            viz_format: None,
+            validation_messages: None,
+            validation_messages_file: None,
+            schema_registry: None,
+            form_hooks: None,
+            xstate_actors: None,
+            web_fallback: None,
+            http_bridge: None,
+            admin_panel: None,
+            arbitraries: None,
+            injected_types: None,
+            field_overrides: None,
+            command_options: None,
+            hooks: None,
+            binary_helpers: None,
+            error_classes: None,
+            max_commands_per_file: None,
+            rename_map: None,
+            allow_outside_repo: None,
+            offline_queue: None,
+            query_keys: None,
+            source_trace: None,
+            macro_records: None,
+            additional_source_paths: None,
+            event_router: None,
+            sanitize_params: None,
+            command_validation: None,
+            aliases: None,
+            range_literals: None,
+            all_types: None,
+            shared_types: None,
+            lint_config: None,
+            framework: None,
+            unknown_type_fallback: None,
+            zod_object_mode: None,
+            test_harness: None,
+            mocks: None,
+            ipc_size_lint: None,
+            stores: None,
+            compact_types: None,
+            compact_codec: None,
+            self_test: None,
         }
     }
 
@@ -313,6 +534,21 @@ mod tests {
         assert_eq!(cache.structs_hash, loaded_cache.structs_hash);
     }
 
+    #[test]
+    fn test_load_api_of_previous_generation() {
+        let temp_dir = TempDir::new().unwrap();
+        let commands = vec![create_test_command("test_command")];
+        let config = create_test_config();
+
+        assert!(GenerationCache::load_api(temp_dir.path()).is_none());
+
+        let cache = GenerationCache::new(&commands, &HashMap::new(), &config).unwrap();
+        cache.save(temp_dir.path()).unwrap();
+
+        let api = GenerationCache::load_api(temp_dir.path()).unwrap();
+        assert_eq!(api.commands[0].ts_function_name, "testCommand");
+    }
+
     #[test]
     fn test_needs_regeneration_no_cache() {
         let temp_dir = TempDir::new().unwrap();
@@ -462,10 +698,25 @@ mod tests {
                 validator_attributes: None,
                 serde_rename: None,
                 type_structure: TypeStructure::Primitive("string".to_string()),
+                variant_fields: Vec::new(),
+                literal: None,
+                flatten: false,
+                omittable: false,
+                defaulted: false,
+                skip_serializing_if: false,
+                skipped: None,
+                serde_with: None,
             }],
             file_path: "test.rs".to_string(),
             is_enum: false,
             serde_rename_all: None,
+            is_newtype: false,
+            serde_tag: None,
+            serde_content: None,
+            serde_untagged: false,
+            line_number: 0,
+            is_progress: false,
+            serde_deny_unknown_fields: false,
         };
 
         let struct_b = StructInfo {
@@ -478,10 +729,25 @@ mod tests {
                 validator_attributes: None,
                 serde_rename: None,
                 type_structure: TypeStructure::Primitive("number".to_string()),
+                variant_fields: Vec::new(),
+                literal: None,
+                flatten: false,
+                omittable: false,
+                defaulted: false,
+                skip_serializing_if: false,
+                skipped: None,
+                serde_with: None,
             }],
             file_path: "test.rs".to_string(),
             is_enum: false,
             serde_rename_all: None,
+            is_newtype: false,
+            serde_tag: None,
+            serde_content: None,
+            serde_untagged: false,
+            line_number: 0,
+            is_progress: false,
+            serde_deny_unknown_fields: false,
         };
 
         // Insert in order A, B
@@ -541,6 +807,71 @@ mod tests {
         assert_ne!(cache1.combined_hash, cache2.combined_hash);
     }
 
+    #[test]
+    fn test_config_hash_independent_of_map_order() {
+        // Every `HashMap` gets its own hash seed, so two maps built apart iterate their
+        // entries in different orders, as they do across processes
+        let build_config = |reversed: bool| {
+            let mut names: Vec<String> = (0..16).map(|i| format!("Type{}", i)).collect();
+            if reversed {
+                names.reverse();
+            }
+            let map = || -> HashMap<String, String> {
+                names
+                    .iter()
+                    .map(|name| (name.clone(), "string".to_string()))
+                    .collect()
+            };
+            let mut config = create_test_config();
+            config.type_mappings = Some(map());
+            config.serde_with_mappings = Some(map());
+            config.collection_mappings = Some(map());
+            config.field_overrides = Some(
+                names
+                    .iter()
+                    .map(|name| {
+                        (
+                            format!("{}.id", name),
+                            FieldOverride::Type("string".to_string()),
+                        )
+                    })
+                    .collect(),
+            );
+            config
+        };
+
+        let first = GenerationCache::hash_config(&build_config(false)).unwrap();
+        let second = GenerationCache::hash_config(&build_config(true)).unwrap();
+
+        assert_eq!(first, second);
+    }
+
+    #[test]
+    fn test_analysis_hash_covers_events_and_constants() {
+        use crate::models::{ConstInfo, ConstValue, EventInfo};
+
+        let config = create_test_config();
+        let mut analysis = AnalysisResult {
+            commands: vec![CommandInfo::new("ping", Vec::new(), "String")],
+            structs: HashMap::new(),
+            events: Vec::new(),
+            constants: Vec::new(),
+        };
+        let initial = GenerationCache::hash_analysis(&analysis, &config).unwrap();
+
+        analysis
+            .events
+            .push(EventInfo::new("ping-received", "String"));
+        let with_event = GenerationCache::hash_analysis(&analysis, &config).unwrap();
+        assert_ne!(initial, with_event);
+
+        analysis
+            .constants
+            .push(ConstInfo::new("MAX_PINGS", "u32", ConstValue::Integer(3)));
+        let with_constant = GenerationCache::hash_analysis(&analysis, &config).unwrap();
+        assert_ne!(with_event, with_constant);
+    }
+
     #[test]
     fn test_cache_with_channels() {
         use crate::models::ChannelInfo;
@@ -0,0 +1,181 @@
+use crate::interface::config::GenerateConfig;
+use std::process::Command;
+use thiserror::Error;
+
+#[derive(Error, Debug)]
+pub enum HookError {
+    #[error("Failed to run {stage} hook `{command}`: {source}")]
+    Spawn {
+        stage: &'static str,
+        command: String,
+        source: std::io::Error,
+    },
+    #[error("{stage} hook `{command}` failed with {status}")]
+    Failed {
+        stage: &'static str,
+        command: String,
+        status: std::process::ExitStatus,
+    },
+}
+
+/// Runs the `hooks` configured for generation.
+///
+/// Hooks are run through the platform shell (`sh -c`, `cmd /C` on Windows) with:
+/// - `TAURI_TYPEGEN_HOOK`: `before` or `after`
+/// - `TAURI_TYPEGEN_PROJECT_PATH`, `TAURI_TYPEGEN_OUTPUT_PATH`, `TAURI_TYPEGEN_VALIDATION`
+/// - `TAURI_TYPEGEN_MANIFEST` (after only): JSON `{"outputPath", "files"}` listing the
+///   generated files relative to the output path
+pub struct HookRunner<'a> {
+    config: &'a GenerateConfig,
+}
+
+impl<'a> HookRunner<'a> {
+    pub fn new(config: &'a GenerateConfig) -> Self {
+        Self { config }
+    }
+
+    /// Run the `before` hook, if configured. Returns the command that was run.
+    pub fn run_before(&self) -> Result<Option<String>, HookError> {
+        let Some(command) = self.config.hooks().before else {
+            return Ok(None);
+        };
+        self.run("before", &command, None)?;
+        Ok(Some(command))
+    }
+
+    /// Run the `after` hook, if configured. Returns the command that was run.
+    pub fn run_after(&self, generated_files: &[String]) -> Result<Option<String>, HookError> {
+        let Some(command) = self.config.hooks().after else {
+            return Ok(None);
+        };
+        self.run("after", &command, Some(self.manifest(generated_files)))?;
+        Ok(Some(command))
+    }
+
+    /// Manifest of a generation passed to the `after` hook
+    pub fn manifest(&self, generated_files: &[String]) -> String {
+        serde_json::json!({
+            "outputPath": self.config.output_path,
+            "files": generated_files,
+        })
+        .to_string()
+    }
+
+    fn run(
+        &self,
+        stage: &'static str,
+        command: &str,
+        manifest: Option<String>,
+    ) -> Result<(), HookError> {
+        let mut process = if cfg!(windows) {
+            let mut process = Command::new("cmd");
+            process.args(["/C", command]);
+            process
+        } else {
+            let mut process = Command::new("sh");
+            process.args(["-c", command]);
+            process
+        };
+
+        process
+            .env("TAURI_TYPEGEN_HOOK", stage)
+            .env("TAURI_TYPEGEN_PROJECT_PATH", &self.config.project_path)
+            .env("TAURI_TYPEGEN_OUTPUT_PATH", &self.config.output_path)
+            .env("TAURI_TYPEGEN_VALIDATION", &self.config.validation_library);
+        if let Some(manifest) = manifest {
+            process.env("TAURI_TYPEGEN_MANIFEST", manifest);
+        }
+
+        let status = process.status().map_err(|source| HookError::Spawn {
+            stage,
+            command: command.to_string(),
+            source,
+        })?;
+        if !status.success() {
+            return Err(HookError::Failed {
+                stage,
+                command: command.to_string(),
+                status,
+            });
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::interface::config::GenerationHooks;
+    use tempfile::TempDir;
+
+    fn config_with_hooks(before: Option<&str>, after: Option<&str>) -> GenerateConfig {
+        GenerateConfig {
+            output_path: "./src/generated".to_string(),
+            hooks: Some(GenerationHooks {
+                before: before.map(String::from),
+                after: after.map(String::from),
+            }),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_no_hooks_configured() {
+        let config = GenerateConfig::default();
+        let runner = HookRunner::new(&config);
+
+        assert!(runner.run_before().unwrap().is_none());
+        assert!(runner.run_after(&[]).unwrap().is_none());
+    }
+
+    #[test]
+    fn test_manifest_lists_generated_files() {
+        let config = config_with_hooks(None, None);
+        let runner = HookRunner::new(&config);
+
+        let manifest: serde_json::Value = serde_json::from_str(
+            &runner.manifest(&["types.ts".to_string(), "commands.ts".to_string()]),
+        )
+        .unwrap();
+        assert_eq!(manifest["outputPath"], "./src/generated");
+        assert_eq!(manifest["files"][1], "commands.ts");
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_after_hook_receives_environment() {
+        let temp_dir = TempDir::new().unwrap();
+        let out = temp_dir.path().join("env.txt");
+        let command = format!(
+            "echo \"$TAURI_TYPEGEN_HOOK $TAURI_TYPEGEN_OUTPUT_PATH $TAURI_TYPEGEN_MANIFEST\" > {}",
+            out.display()
+        );
+        let config = config_with_hooks(None, Some(&command));
+
+        let ran = HookRunner::new(&config)
+            .run_after(&["types.ts".to_string()])
+            .unwrap();
+
+        assert_eq!(ran, Some(command));
+        let env = std::fs::read_to_string(out).unwrap();
+        assert!(env.starts_with("after ./src/generated "));
+        assert!(env.contains(r#""files":["types.ts"]"#));
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_failing_hook_is_an_error() {
+        let config = config_with_hooks(Some("exit 3"), None);
+
+        let error = HookRunner::new(&config).run_before().unwrap_err();
+
+        assert!(matches!(
+            error,
+            HookError::Failed {
+                stage: "before",
+                ..
+            }
+        ));
+        assert!(error.to_string().contains("`exit 3`"));
+    }
+}
@@ -1,18 +1,25 @@
 pub mod dependency_resolver;
 pub mod generation_cache;
+pub mod hooks;
 pub mod output_manager;
+pub mod path_resolver;
 pub mod project_scanner;
+pub mod rename_map;
 
 use crate::analysis::CommandAnalyzer;
-use crate::generators::create_generator;
+use crate::generators::generator_for;
 use crate::interface::config::{ConfigError, GenerateConfig};
+use crate::interface::configured_analyzer;
 use crate::interface::output::{Logger, ProgressReporter};
 use std::path::Path;
 
 pub use dependency_resolver::*;
 pub use generation_cache::*;
+pub use hooks::*;
 pub use output_manager::*;
+pub use path_resolver::*;
 pub use project_scanner::*;
+pub use rename_map::*;
 
 /// Build-time code generation orchestrator.
 ///
@@ -63,6 +70,9 @@ impl BuildSystem {
     ///
     /// Reads configuration from `tauri.conf.json` in the project root.
     /// If no configuration is found, uses default settings with vanilla TypeScript output.
+    /// Relative paths are resolved against the project rather than the build script's
+    /// working directory and may use the `${workspaceRoot}`, `${manifestDir}` and
+    /// `${outDir}` placeholders (see [`PathResolver`]).
     pub fn generate_at_build_time() -> Result<(), Box<dyn std::error::Error>> {
         let build_system = Self::new(false, false);
         build_system.run_generation()
@@ -89,7 +99,11 @@ impl BuildSystem {
         };
 
         reporter.start_step("Loading configuration");
-        let config = self.load_configuration(&project_info)?;
+        let mut config = self.load_configuration(&project_info)?;
+        // Paths are relative to the project, not to the directory cargo runs build.rs in
+        PathResolver::for_project(&project_info, &config.project_path)
+            .resolve_config(&mut config)?;
+        config.validate()?;
         reporter.complete_step(Some(&format!(
             "Using {} validation with output to {}",
             config.validation_library, config.output_path
@@ -109,7 +123,8 @@ impl BuildSystem {
         reporter.complete_step(None);
 
         reporter.finish(&format!(
-            "Successfully generated TypeScript bindings for {} commands",
+            "Successfully generated {} bindings ({} files)",
+            generator_for(&config).language(),
             generated_files.len()
         ));
 
@@ -123,19 +138,10 @@ impl BuildSystem {
         // Try to load from tauri.conf.json first
         if let Some(tauri_config_path) = &project_info.tauri_config_path {
             if tauri_config_path.exists() {
-                match GenerateConfig::from_tauri_config(tauri_config_path) {
-                    Ok(Some(config)) => {
-                        self.logger
-                            .debug("Loaded configuration from tauri.conf.json");
-                        return Ok(config);
-                    }
-                    Ok(None) => {}
-                    Err(e) => {
-                        self.logger.warning(&format!(
-                            "Failed to load config from tauri.conf.json: {}. Using defaults.",
-                            e
-                        ));
-                    }
+                if let Some(config) = GenerateConfig::from_tauri_config(tauri_config_path)? {
+                    self.logger
+                        .debug("Loaded configuration from tauri.conf.json");
+                    return Ok(config);
                 }
             }
         }
@@ -143,18 +149,9 @@ impl BuildSystem {
         // Try standalone config file
         let standalone_config = project_info.root_path.join("typegen.json");
         if standalone_config.exists() {
-            match GenerateConfig::from_file(&standalone_config) {
-                Ok(config) => {
-                    self.logger.debug("Loaded configuration from typegen.json");
-                    return Ok(config);
-                }
-                Err(e) => {
-                    self.logger.warning(&format!(
-                        "Failed to load config from typegen.json: {}. Using defaults.",
-                        e
-                    ));
-                }
-            }
+            let config = GenerateConfig::from_file(&standalone_config)?;
+            self.logger.debug("Loaded configuration from typegen.json");
+            return Ok(config);
         }
 
         // Use defaults
@@ -189,7 +186,7 @@ impl BuildSystem {
         &self,
         config: &GenerateConfig,
     ) -> Result<Vec<String>, Box<dyn std::error::Error>> {
-        let mut analyzer = CommandAnalyzer::new();
+        let mut analyzer = configured_analyzer(config);
         let commands = analyzer.analyze_project(&config.project_path)?;
 
         if commands.is_empty() {
@@ -232,12 +229,19 @@ impl BuildSystem {
             }
         }
 
-        let validation = match config.validation_library.as_str() {
-            "zod" | "none" => Some(config.validation_library.clone()),
-            _ => return Err("Invalid validation library. Use 'zod' or 'none'".into()),
-        };
+        if !config.has_known_generator() {
+            return Err(
+                ConfigError::InvalidValidationLibrary(config.validation_library.clone()).into(),
+            );
+        }
+
+        let hooks = HookRunner::new(config);
+        if let Some(command) = hooks.run_before()? {
+            self.logger
+                .verbose(&format!("Ran before hook: {}", command));
+        }
 
-        let mut generator = create_generator(validation);
+        let mut generator = generator_for(config);
         let generated_files = generator.generate_models(
             &commands,
             discovered_structs,
@@ -248,7 +252,27 @@ impl BuildSystem {
 
         // Generate dependency visualization if requested
         if config.should_visualize_deps() {
-            self.generate_dependency_visualization(&analyzer, &commands, &config.output_path)?;
+            self.generate_dependency_visualization(&analyzer, &commands, config)?;
+        }
+
+        // Run before saving the cache, so a failed hook is retried on the next build
+        if let Some(command) = hooks.run_after(&generated_files)? {
+            self.logger.verbose(&format!("Ran after hook: {}", command));
+        }
+
+        // Compare against the API recorded by the previous generation before replacing it
+        if config.should_generate_rename_map() {
+            if let Some(previous) = GenerationCache::load_api(&config.output_path) {
+                let renames = RenameMap::between(&previous, &ApiSnapshot::new(&commands, config));
+                if !renames.is_empty() {
+                    let path = renames.save(&config.output_path)?;
+                    self.logger.info(&format!(
+                        "Detected {} renames, wrote {}",
+                        renames.len(),
+                        path.display()
+                    ));
+                }
+            }
         }
 
         // Save cache after successful generation
@@ -257,6 +281,10 @@ impl BuildSystem {
             self.logger
                 .warning(&format!("Failed to save generation cache: {}", e));
         }
+        self.logger
+            .payload_diagnostics(analyzer.get_payload_diagnostics());
+        self.logger
+            .unsupported_constructs(analyzer.get_unsupported_constructs());
 
         Ok(generated_files)
     }
@@ -265,24 +293,24 @@ impl BuildSystem {
         &self,
         analyzer: &CommandAnalyzer,
         commands: &[crate::models::CommandInfo],
-        output_path: &str,
+        config: &GenerateConfig,
     ) -> Result<(), Box<dyn std::error::Error>> {
         use std::fs;
 
         self.logger.debug("Generating dependency visualization");
 
-        let text_viz = analyzer.visualize_dependencies(commands);
-        let viz_file_path = Path::new(output_path).join("dependency-graph.txt");
-        fs::write(&viz_file_path, text_viz)?;
-
-        let dot_viz = analyzer.generate_dot_graph(commands);
-        let dot_file_path = Path::new(output_path).join("dependency-graph.dot");
-        fs::write(&dot_file_path, dot_viz)?;
+        let viz_filter = config.viz_filter();
+        let mut viz_files = Vec::new();
+        for format in config.viz_formats() {
+            let viz = analyzer.render_dependency_graph(commands, format, &viz_filter);
+            let viz_file_path = Path::new(&config.output_path).join(format.file_name());
+            fs::write(&viz_file_path, viz)?;
+            viz_files.push(viz_file_path.display().to_string());
+        }
 
         self.logger.verbose(&format!(
-            "Generated dependency graphs: {} and {}",
-            viz_file_path.display(),
-            dot_file_path.display()
+            "Generated dependency graphs: {}",
+            viz_files.join(", ")
         ));
 
         Ok(())
@@ -411,6 +439,25 @@ mod tests {
         assert_eq!(config.project_path, "./src-tauri");
     }
 
+    #[test]
+    fn test_load_configuration_reports_broken_config() {
+        let temp_dir = TempDir::new().unwrap();
+        let tauri_config_path = temp_dir.path().join("tauri.conf.json");
+        std::fs::write(&tauri_config_path, r#"{"plugins": {"typegen": "#).unwrap();
+
+        let mut project_info = ProjectInfo {
+            root_path: temp_dir.path().to_path_buf(),
+            src_tauri_path: temp_dir.path().join("src-tauri"),
+            tauri_config_path: Some(tauri_config_path),
+        };
+        let build_system = BuildSystem::new(false, false);
+        assert!(build_system.load_configuration(&project_info).is_err());
+
+        project_info.tauri_config_path = None;
+        std::fs::write(temp_dir.path().join("typegen.json"), "{ not json").unwrap();
+        assert!(build_system.load_configuration(&project_info).is_err());
+    }
+
     #[test]
     fn test_build_system_with_verbose_logging() {
         let build_system = BuildSystem::new(true, true);
@@ -432,4 +479,215 @@ mod tests {
             .logger
             .should_log(crate::interface::output::LogLevel::Debug));
     }
+
+    /// Generate with the build script path from `main.rs` source and typegen options,
+    /// returning the project directory and the generated `types.ts` and `commands.ts`
+    fn generate_bindings_of(
+        source: &str,
+        options: serde_json::Value,
+    ) -> Result<(TempDir, String, String), Box<dyn std::error::Error>> {
+        let temp_dir = TempDir::new().unwrap();
+        let project_path = temp_dir.path().join("src-tauri");
+        std::fs::create_dir_all(&project_path).unwrap();
+        std::fs::write(project_path.join("main.rs"), source).unwrap();
+        let output_path = temp_dir.path().join("generated");
+
+        let mut config = serde_json::json!({
+            "project_path": project_path,
+            "output_path": output_path,
+            "force": true,
+        });
+        config
+            .as_object_mut()
+            .unwrap()
+            .extend(options.as_object().unwrap().clone());
+        let config: GenerateConfig = serde_json::from_value(config).unwrap();
+
+        BuildSystem::new(false, false).generate_bindings(&config)?;
+        let read = |name: &str| std::fs::read_to_string(output_path.join(name)).unwrap();
+        let (types, commands) = (read("types.ts"), read("commands.ts"));
+        Ok((temp_dir, types, commands))
+    }
+
+    #[test]
+    fn test_generate_bindings_applies_macro_records() {
+        let records = TempDir::new().unwrap();
+        std::fs::write(
+            records.path().join("expanded.json"),
+            r#"{ "name": "expanded", "parameters": [{ "name": "id", "rustType": "u32" }] }"#,
+        )
+        .unwrap();
+
+        let (_project, _, commands) = generate_bindings_of(
+            "#[tauri::command]\npub fn ping() {}\n",
+            serde_json::json!({ "macro_records": records.path() }),
+        )
+        .unwrap();
+        assert!(commands.contains("export async function expanded("));
+    }
+
+    #[test]
+    fn test_generate_bindings_scans_additional_source_paths() {
+        let vendor = TempDir::new().unwrap();
+        std::fs::create_dir_all(vendor.path().join("src")).unwrap();
+        std::fs::write(
+            vendor.path().join("src/lib.rs"),
+            "#[tauri::command]\npub fn vendored() {}\n",
+        )
+        .unwrap();
+
+        let (_project, _, commands) = generate_bindings_of(
+            "#[tauri::command]\npub fn ping() {}\n",
+            serde_json::json!({ "additional_source_paths": [{ "path": vendor.path() }] }),
+        )
+        .unwrap();
+        assert!(commands.contains("export async function vendored("));
+    }
+
+    #[test]
+    fn test_generate_bindings_applies_collection_mappings() {
+        let (_project, types, _) = generate_bindings_of(
+            r#"
+            #[derive(serde::Serialize, serde::Deserialize)]
+            pub struct User {
+                pub name: String,
+            }
+
+            #[derive(serde::Serialize, serde::Deserialize)]
+            pub struct Team {
+                pub members: ImVector<User>,
+            }
+
+            #[tauri::command]
+            pub fn get_team() -> Team {}
+            "#,
+            serde_json::json!({ "collection_mappings": { "ImVector": "array" } }),
+        )
+        .unwrap();
+        assert!(types.contains("members: User[];"));
+    }
+
+    #[test]
+    fn test_generate_bindings_applies_union_types() {
+        let (_project, _, commands) = generate_bindings_of(
+            r#"
+            #[derive(serde::Serialize, serde::Deserialize)]
+            pub struct User {
+                pub name: String,
+            }
+
+            #[derive(serde::Serialize, serde::Deserialize)]
+            pub struct Guest {
+                pub session: String,
+            }
+
+            #[derive(serde::Serialize, serde::Deserialize)]
+            #[serde(untagged)]
+            pub enum OneOf<A, B> {
+                First(A),
+                Second(B),
+            }
+
+            #[tauri::command]
+            pub fn whoami() -> OneOf<User, Guest> {}
+            "#,
+            serde_json::json!({ "union_types": ["OneOf"] }),
+        )
+        .unwrap();
+        assert!(commands.contains("Promise<types.User | types.Guest>"));
+    }
+
+    #[test]
+    fn test_generate_bindings_enforces_serde_derives() {
+        let source = r#"
+            pub struct Filter {
+                pub query: String,
+            }
+
+            #[tauri::command]
+            pub fn search(filter: Filter) {}
+            "#;
+        assert!(generate_bindings_of(source, serde_json::json!({})).is_ok());
+        let error =
+            generate_bindings_of(source, serde_json::json!({ "strict_serde_derives": true }))
+                .expect_err("strict serde derives should fail generation");
+        assert!(error.to_string().contains("1 type(s)"));
+    }
+
+    #[test]
+    fn test_generate_bindings_skips_injected_types() {
+        let (_project, types, _) = generate_bindings_of(
+            r#"
+            #[derive(serde::Serialize, serde::Deserialize)]
+            pub struct User {
+                pub name: String,
+            }
+
+            #[tauri::command]
+            pub fn save_user(db: my_app::Db, user: User) {}
+            "#,
+            serde_json::json!({ "injected_types": ["my_app::Db"] }),
+        )
+        .unwrap();
+        assert!(types.contains("export interface SaveUserParams {\n  user: User;"));
+        assert!(!types.contains("db:"));
+    }
+
+    #[test]
+    fn test_generate_bindings_applies_field_overrides() {
+        let (_project, types, _) = generate_bindings_of(
+            r#"
+            #[derive(serde::Serialize, serde::Deserialize)]
+            pub struct User {
+                pub created_at: String,
+            }
+
+            #[tauri::command]
+            pub fn get_user() -> User {}
+            "#,
+            serde_json::json!({ "field_overrides": { "User.created_at": "Date" } }),
+        )
+        .unwrap();
+        assert!(types.contains("created_at: Date;"));
+    }
+
+    #[test]
+    fn test_generate_bindings_applies_shared_types_and_all_types() {
+        let shared = TempDir::new().unwrap();
+        std::fs::write(
+            shared.path().join("lib.rs"),
+            "#[derive(serde::Serialize)]\npub struct User {\n    pub name: String,\n}\n",
+        )
+        .unwrap();
+        let source = r#"
+            #[derive(serde::Serialize)]
+            pub struct Order {
+                pub user: User,
+            }
+
+            #[derive(serde::Serialize)]
+            pub struct Unused {
+                pub id: u32,
+            }
+
+            #[tauri::command]
+            pub fn get_order() -> Order {
+                unimplemented!()
+            }
+        "#;
+
+        let (_project, types, _) = generate_bindings_of(
+            source,
+            serde_json::json!({
+                "shared_types": [{ "path": shared.path(), "package": "@acme/core-types" }]
+            }),
+        )
+        .unwrap();
+        assert!(types.contains("import type { User } from '@acme/core-types';"));
+        assert!(!types.contains("export interface Unused"));
+
+        let (_project, types, _) =
+            generate_bindings_of(source, serde_json::json!({ "all_types": true })).unwrap();
+        assert!(types.contains("export interface Unused {"));
+    }
 }
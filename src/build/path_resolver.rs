@@ -0,0 +1,323 @@
+use crate::build::project_scanner::ProjectInfo;
+use crate::interface::config::GenerateConfig;
+use std::path::{Component, Path, PathBuf};
+use thiserror::Error;
+
+#[derive(Error, Debug)]
+pub enum PathError {
+    #[error("Unknown placeholder ${{{0}}} in {1}. Use ${{workspaceRoot}}, ${{manifestDir}} or ${{outDir}}")]
+    UnknownPlaceholder(String, String),
+    #[error("${{{0}}} in {1} is only available in build scripts")]
+    Unavailable(String, String),
+    #[error("{field} resolves to {path}, outside the repository at {repo}. Use a path inside the repository or below ${{outDir}}, or set allowOutsideRepo")]
+    OutsideRepo {
+        field: &'static str,
+        path: String,
+        repo: String,
+    },
+}
+
+/// Resolves the paths of a configuration independently of the current directory.
+///
+/// Relative paths are resolved against a base directory and may start with the
+/// `${workspaceRoot}` (Cargo workspace), `${manifestDir}` (crate being built) and
+/// `${outDir}` (the build script's `OUT_DIR`) placeholders. Output paths resolving
+/// outside the repository are rejected unless they are below `${outDir}`.
+#[derive(Debug, Clone)]
+pub struct PathResolver {
+    base_dir: PathBuf,
+    manifest_dir: Option<PathBuf>,
+    workspace_root: Option<PathBuf>,
+    out_dir: Option<PathBuf>,
+    repo_root: Option<PathBuf>,
+}
+
+impl PathResolver {
+    /// Resolver for paths relative to `base_dir`, with the placeholders taken from the
+    /// Cargo build script environment where available
+    pub fn new<P: AsRef<Path>>(base_dir: P) -> Self {
+        let base_dir = absolute(base_dir.as_ref());
+        let manifest_dir = std::env::var_os("CARGO_MANIFEST_DIR").map(PathBuf::from);
+        let workspace_root = find_workspace_root(manifest_dir.as_deref().unwrap_or(&base_dir));
+        Self {
+            repo_root: find_repo_root(&base_dir),
+            out_dir: std::env::var_os("OUT_DIR").map(PathBuf::from),
+            manifest_dir,
+            workspace_root,
+            base_dir,
+        }
+    }
+
+    /// Resolver for a configuration found in a detected project. The paths of a
+    /// configuration in `src-tauri` are usually written relative to the app root above
+    /// it (e.g. `./src-tauri`), so the directory `project_path` resolves from is used.
+    pub fn for_project(project_info: &ProjectInfo, project_path: &str) -> Self {
+        let root = absolute(&project_info.root_path);
+        let candidates = [Some(root.as_path()), root.parent()];
+        let base_dir = candidates
+            .into_iter()
+            .flatten()
+            .find(|dir| dir.join(project_path).is_dir())
+            .unwrap_or(&root)
+            .to_path_buf();
+        Self::new(base_dir)
+    }
+
+    pub fn base_dir(&self) -> &Path {
+        &self.base_dir
+    }
+
+    /// Expand placeholders in `path` and make it absolute
+    pub fn resolve(&self, path: &str) -> Result<PathBuf, PathError> {
+        let resolved = match path.strip_prefix("${") {
+            Some(rest) => {
+                let (name, remainder) = rest.split_once('}').ok_or_else(|| {
+                    PathError::UnknownPlaceholder(rest.to_string(), path.to_string())
+                })?;
+                let dir = match name {
+                    "workspaceRoot" => self.workspace_root.as_ref(),
+                    "manifestDir" => self.manifest_dir.as_ref(),
+                    "outDir" => self.out_dir.as_ref(),
+                    _ => {
+                        return Err(PathError::UnknownPlaceholder(
+                            name.to_string(),
+                            path.to_string(),
+                        ))
+                    }
+                }
+                .ok_or_else(|| PathError::Unavailable(name.to_string(), path.to_string()))?;
+                dir.join(remainder.trim_start_matches(['/', '\\']))
+            }
+            None => self.base_dir.join(path),
+        };
+        Ok(normalize(&resolved))
+    }
+
+    /// Resolve the project, output, HTTP bridge, compact codec, admin panel, macro record
+    /// and additional source paths of `config`, rejecting outputs outside the repository unless `allowOutsideRepo` is set
+    pub fn resolve_config(&self, config: &mut GenerateConfig) -> Result<(), PathError> {
+        config.project_path = self.resolve(&config.project_path)?.display().to_string();
+
+        let allow_outside = config.should_allow_outside_repo();
+        config.output_path =
+            self.resolve_output("outputPath", &config.output_path, allow_outside)?;
+        if let Some(ref http_bridge) = config.http_bridge {
+            config.http_bridge =
+                Some(self.resolve_output("httpBridge", http_bridge, allow_outside)?);
+        }
+        if let Some(ref compact_codec) = config.compact_codec {
+            config.compact_codec =
+                Some(self.resolve_output("compactCodec", compact_codec, allow_outside)?);
+        }
+        if let Some(ref self_test) = config.self_test {
+            config.self_test = Some(self.resolve_output("selfTest", self_test, allow_outside)?);
+        }
+        if let Some(ref admin_panel) = config.admin_panel {
+            config.admin_panel =
+                Some(self.resolve_output("adminPanel", admin_panel, allow_outside)?);
+        }
+        if let Some(ref macro_records) = config.macro_records {
+            config.macro_records = Some(self.resolve(macro_records)?.display().to_string());
+        }
+        if let Some(ref mut source_paths) = config.additional_source_paths {
+            for source_path in source_paths {
+                source_path.path = self.resolve(&source_path.path)?.display().to_string();
+            }
+        }
+        if let Some(ref mut shared_types) = config.shared_types {
+            for shared in shared_types {
+                shared.path = self.resolve(&shared.path)?.display().to_string();
+            }
+        }
+        Ok(())
+    }
+
+    fn resolve_output(
+        &self,
+        field: &'static str,
+        path: &str,
+        allow_outside: bool,
+    ) -> Result<String, PathError> {
+        let resolved = self.resolve(path)?;
+        let below_out_dir = self
+            .out_dir
+            .as_ref()
+            .is_some_and(|out_dir| resolved.starts_with(normalize(out_dir)));
+        if let Some(ref repo) = self.repo_root {
+            if !allow_outside && !below_out_dir && !resolved.starts_with(repo) {
+                return Err(PathError::OutsideRepo {
+                    field,
+                    path: resolved.display().to_string(),
+                    repo: repo.display().to_string(),
+                });
+            }
+        }
+        Ok(resolved.display().to_string())
+    }
+}
+
+fn absolute(path: &Path) -> PathBuf {
+    if path.is_absolute() {
+        normalize(path)
+    } else {
+        normalize(&std::env::current_dir().unwrap_or_default().join(path))
+    }
+}
+
+/// Resolve `.` and `..` lexically
+fn normalize(path: &Path) -> PathBuf {
+    let mut normalized = PathBuf::new();
+    for component in path.components() {
+        match component {
+            Component::CurDir => {}
+            Component::ParentDir => {
+                normalized.pop();
+            }
+            component => normalized.push(component),
+        }
+    }
+    normalized
+}
+
+/// Nearest directory at or above `dir` with a `.git` directory or file
+fn find_repo_root(dir: &Path) -> Option<PathBuf> {
+    dir.ancestors()
+        .find(|ancestor| ancestor.join(".git").exists())
+        .map(Path::to_path_buf)
+}
+
+/// Directory of the Cargo workspace `dir` belongs to, or of the nearest crate
+fn find_workspace_root(dir: &Path) -> Option<PathBuf> {
+    let manifests: Vec<&Path> = dir
+        .ancestors()
+        .filter(|ancestor| ancestor.join("Cargo.toml").is_file())
+        .collect();
+    manifests
+        .iter()
+        .find(|ancestor| {
+            std::fs::read_to_string(ancestor.join("Cargo.toml"))
+                .is_ok_and(|manifest| manifest.lines().any(|line| line.trim() == "[workspace]"))
+        })
+        .or(manifests.first())
+        .map(|ancestor| ancestor.to_path_buf())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+    use tempfile::TempDir;
+
+    fn resolver(root: &Path) -> PathResolver {
+        PathResolver {
+            base_dir: root.join("app"),
+            manifest_dir: Some(root.join("app/src-tauri")),
+            workspace_root: Some(root.to_path_buf()),
+            out_dir: Some(PathBuf::from("/target/build/out")),
+            repo_root: Some(root.to_path_buf()),
+        }
+    }
+
+    #[test]
+    fn test_resolves_placeholders() {
+        let root = Path::new("/repo");
+        let resolver = resolver(root);
+
+        assert_eq!(
+            resolver.resolve("./src/generated").unwrap(),
+            Path::new("/repo/app/src/generated")
+        );
+        assert_eq!(
+            resolver.resolve("${workspaceRoot}/packages/api").unwrap(),
+            Path::new("/repo/packages/api")
+        );
+        assert_eq!(
+            resolver.resolve("${manifestDir}/../src/generated").unwrap(),
+            Path::new("/repo/app/src/generated")
+        );
+        assert_eq!(
+            resolver.resolve("${outDir}/bindings").unwrap(),
+            Path::new("/target/build/out/bindings")
+        );
+        assert!(matches!(
+            resolver.resolve("${home}/x"),
+            Err(PathError::UnknownPlaceholder(..))
+        ));
+
+        let outside_build = PathResolver {
+            out_dir: None,
+            ..resolver
+        };
+        assert!(matches!(
+            outside_build.resolve("${outDir}/x"),
+            Err(PathError::Unavailable(..))
+        ));
+    }
+
+    #[test]
+    fn test_rejects_outputs_outside_repo() {
+        let resolver = resolver(Path::new("/repo"));
+        let mut config = GenerateConfig {
+            output_path: "../../elsewhere".to_string(),
+            ..Default::default()
+        };
+
+        let error = resolver.resolve_config(&mut config).unwrap_err();
+        assert!(error
+            .to_string()
+            .contains("outputPath resolves to /elsewhere"));
+
+        config.output_path = "${outDir}/bindings".to_string();
+        resolver.resolve_config(&mut config).unwrap();
+        assert_eq!(config.output_path, "/target/build/out/bindings");
+
+        config.output_path = "../../elsewhere".to_string();
+        config.allow_outside_repo = Some(true);
+        resolver.resolve_config(&mut config).unwrap();
+        assert_eq!(config.output_path, "/elsewhere");
+    }
+
+    #[test]
+    fn test_project_base_is_where_project_path_resolves() {
+        let temp_dir = TempDir::new().unwrap();
+        let app = temp_dir.path().join("app");
+        let src_tauri = app.join("src-tauri");
+        fs::create_dir_all(&src_tauri).unwrap();
+        let project_info = ProjectInfo {
+            root_path: src_tauri.clone(),
+            src_tauri_path: src_tauri.clone(),
+            tauri_config_path: Some(src_tauri.join("tauri.conf.json")),
+        };
+
+        // Written relative to the app root by `init`
+        let resolver = PathResolver::for_project(&project_info, "./src-tauri");
+        assert_eq!(resolver.base_dir(), absolute(&app));
+
+        // Written relative to the config
+        fs::create_dir_all(src_tauri.join("src")).unwrap();
+        let resolver = PathResolver::for_project(&project_info, "./src");
+        assert_eq!(resolver.base_dir(), absolute(&src_tauri));
+    }
+
+    #[test]
+    fn test_finds_workspace_and_repo_roots() {
+        let temp_dir = TempDir::new().unwrap();
+        let root = temp_dir.path();
+        let member = root.join("crates/app");
+        fs::create_dir_all(&member).unwrap();
+        fs::create_dir(root.join(".git")).unwrap();
+        fs::write(
+            root.join("Cargo.toml"),
+            "[workspace]\nmembers = [\"crates/*\"]\n",
+        )
+        .unwrap();
+        fs::write(member.join("Cargo.toml"), "[package]\nname = \"app\"\n").unwrap();
+
+        assert_eq!(find_workspace_root(&member).unwrap(), root);
+        assert_eq!(find_repo_root(&member).unwrap(), root);
+
+        fs::write(root.join("Cargo.toml"), "[package]\nname = \"root\"\n").unwrap();
+        // Not a workspace: the nearest crate
+        assert_eq!(find_workspace_root(&member).unwrap(), member);
+    }
+}
@@ -1,3 +1,5 @@
+use crate::analysis::typegen_ignore::TypegenIgnore;
+use std::collections::HashSet;
 use std::fs;
 use std::path::{Path, PathBuf};
 use thiserror::Error;
@@ -117,36 +119,50 @@ impl ProjectScanner {
         Ok("src-tauri".to_string())
     }
 
-    /// Discover all Rust source files in the project
+    /// Discover all Rust source files in the project, honoring `.typegenignore`
     pub fn discover_rust_files(
         &self,
         project_info: &ProjectInfo,
     ) -> Result<Vec<PathBuf>, ScanError> {
+        let root = &project_info.src_tauri_path;
+        let ignore = TypegenIgnore::load(root);
+        let mut visited = HashSet::new();
         let mut rust_files = Vec::new();
-        Self::walk_directory(&project_info.src_tauri_path, &mut rust_files)?;
+        Self::walk_directory(root, root, &ignore, &mut visited, &mut rust_files)?;
         Ok(rust_files)
     }
 
-    /// Recursively walk a directory to find Rust files
-    fn walk_directory(dir: &Path, rust_files: &mut Vec<PathBuf>) -> Result<(), ScanError> {
+    /// Recursively walk a directory to find Rust files. Symlinked directories are
+    /// followed once, so symlink cycles are not descended into again.
+    fn walk_directory(
+        root: &Path,
+        dir: &Path,
+        ignore: &TypegenIgnore,
+        visited: &mut HashSet<PathBuf>,
+        rust_files: &mut Vec<PathBuf>,
+    ) -> Result<(), ScanError> {
         if !dir.exists() || !dir.is_dir() {
             return Ok(());
         }
+        if !visited.insert(fs::canonicalize(dir)?) {
+            return Ok(());
+        }
 
         let entries = fs::read_dir(dir)?;
 
         for entry in entries {
             let entry = entry?;
             let path = entry.path();
+            let relative = path.strip_prefix(root).unwrap_or(&path);
 
             if path.is_dir() {
                 // Skip common directories that shouldn't contain source
-                let dir_name = path.file_name().and_then(|n| n.to_str()).unwrap_or("");
-
-                if !["target", "node_modules", ".git", "dist"].contains(&dir_name) {
-                    Self::walk_directory(&path, rust_files)?;
+                if !ignore.is_skipped_dir(relative) {
+                    Self::walk_directory(root, &path, ignore, visited, rust_files)?;
                 }
-            } else if path.extension().and_then(|s| s.to_str()) == Some("rs") {
+            } else if path.extension().and_then(|s| s.to_str()) == Some("rs")
+                && !ignore.is_ignored(relative, false)
+            {
                 rust_files.push(path);
             }
         }
@@ -245,6 +261,31 @@ mod tests {
         assert!(rust_files.contains(&lib_rs));
     }
 
+    #[cfg(unix)]
+    #[test]
+    fn test_discover_rust_files_survives_symlink_cycles() {
+        let temp_dir = TempDir::new().unwrap();
+        let src_tauri = temp_dir.path().join("src-tauri");
+        let nested = src_tauri.join("src");
+        fs::create_dir_all(&nested).unwrap();
+        fs::write(nested.join("lib.rs"), "// lib").unwrap();
+        fs::write(nested.join("skip.rs"), "// skipped").unwrap();
+        fs::write(src_tauri.join(".typegenignore"), "skip.rs").unwrap();
+        std::os::unix::fs::symlink(&src_tauri, nested.join("loop")).unwrap();
+
+        let project_info = ProjectInfo {
+            root_path: temp_dir.path().to_path_buf(),
+            src_tauri_path: src_tauri,
+            tauri_config_path: None,
+        };
+
+        let rust_files = ProjectScanner::new()
+            .discover_rust_files(&project_info)
+            .unwrap();
+
+        assert_eq!(rust_files, vec![nested.join("lib.rs")]);
+    }
+
     #[test]
     fn test_has_frontend_detection() {
         let temp_dir = TempDir::new().unwrap();
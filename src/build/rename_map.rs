@@ -0,0 +1,294 @@
+use crate::generators::base::template_context::CommandContext;
+use crate::generators::ts::type_visitor::TypeScriptVisitor;
+use crate::interface::config::GenerateConfig;
+use crate::models::{CommandInfo, TypeStructure};
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, HashMap};
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// Rename map file written to the output directory
+pub const RENAME_MAP_FILE_NAME: &str = "renames.json";
+
+/// Frontend-facing API of the commands of a generation, recorded in the generation
+/// cache so the next generation can detect renames
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct ApiSnapshot {
+    pub commands: Vec<CommandSignature>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CommandSignature {
+    pub name: String,
+    pub ts_function_name: String,
+    pub ts_type_name: String,
+    pub file_path: String,
+    pub return_type: String,
+    pub parameters: Vec<ParameterSignature>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ParameterSignature {
+    pub serialized_name: String,
+    pub rust_type: String,
+}
+
+impl ApiSnapshot {
+    pub fn new(commands: &[CommandInfo], config: &GenerateConfig) -> Self {
+        let visitor = TypeScriptVisitor::with_config(config);
+        // Only names are recorded, so types need not be resolved
+        let resolver = |_: &str| TypeStructure::default();
+        let commands = commands
+            .iter()
+            .map(|command| {
+                let context =
+                    CommandContext::new(config).from_command_info(command, &visitor, &resolver);
+                CommandSignature {
+                    name: command.name.clone(),
+                    ts_function_name: context.ts_function_name,
+                    ts_type_name: context.ts_type_name,
+                    file_path: command.file_path.clone(),
+                    return_type: command.return_type.clone(),
+                    parameters: context
+                        .parameters
+                        .into_iter()
+                        .map(|param| ParameterSignature {
+                            serialized_name: param.serialized_name,
+                            rust_type: param.rust_type,
+                        })
+                        .collect(),
+                }
+            })
+            .collect();
+        Self { commands }
+    }
+}
+
+impl CommandSignature {
+    /// What stays the same when a command is renamed: its file, parameter types and return type
+    fn shape(&self) -> (&str, Vec<&str>, &str) {
+        (
+            &self.file_path,
+            self.parameters
+                .iter()
+                .map(|param| param.rust_type.as_str())
+                .collect(),
+            &self.return_type,
+        )
+    }
+}
+
+/// Commands and parameters renamed since the previous generation, keyed by their
+/// old TypeScript names, for updating frontend call sites.
+///
+/// A removed and an added command are taken to be a rename when they are the only
+/// ones with the same file, parameter types and return type. Parameters are renamed
+/// when a command keeps its parameter types in the same order.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct RenameMap {
+    /// Command functions: old name -> new name
+    pub commands: BTreeMap<String, String>,
+    /// Parameter types of renamed commands: old name -> new name
+    pub types: BTreeMap<String, String>,
+    /// Parameters per command function (new name): old name -> new name
+    pub parameters: BTreeMap<String, BTreeMap<String, String>>,
+}
+
+impl RenameMap {
+    /// Renames between two snapshots of the API
+    pub fn between(previous: &ApiSnapshot, current: &ApiSnapshot) -> Self {
+        let previous_by_name: HashMap<&str, &CommandSignature> = previous
+            .commands
+            .iter()
+            .map(|command| (command.name.as_str(), command))
+            .collect();
+        let current_by_name: HashMap<&str, &CommandSignature> = current
+            .commands
+            .iter()
+            .map(|command| (command.name.as_str(), command))
+            .collect();
+
+        let removed: Vec<&CommandSignature> = previous
+            .commands
+            .iter()
+            .filter(|command| !current_by_name.contains_key(command.name.as_str()))
+            .collect();
+        let added: Vec<&CommandSignature> = current
+            .commands
+            .iter()
+            .filter(|command| !previous_by_name.contains_key(command.name.as_str()))
+            .collect();
+
+        let mut pairs: Vec<(&CommandSignature, &CommandSignature)> = current
+            .commands
+            .iter()
+            .filter_map(|command| {
+                previous_by_name
+                    .get(command.name.as_str())
+                    .map(|previous| (*previous, command))
+            })
+            .collect();
+        for old in &removed {
+            let mut candidates = added.iter().filter(|new| new.shape() == old.shape());
+            let (Some(new), None) = (candidates.next(), candidates.next()) else {
+                continue;
+            };
+            let ambiguous = removed
+                .iter()
+                .filter(|other| other.shape() == new.shape())
+                .count()
+                > 1;
+            if !ambiguous {
+                pairs.push((old, new));
+            }
+        }
+
+        let mut renames = Self::default();
+        for (old, new) in pairs {
+            if old.ts_function_name != new.ts_function_name {
+                renames
+                    .commands
+                    .insert(old.ts_function_name.clone(), new.ts_function_name.clone());
+                if !new.parameters.is_empty() {
+                    renames.types.insert(
+                        format!("{}Params", old.ts_type_name),
+                        format!("{}Params", new.ts_type_name),
+                    );
+                }
+            }
+
+            let same_types = old.parameters.len() == new.parameters.len()
+                && old
+                    .parameters
+                    .iter()
+                    .zip(&new.parameters)
+                    .all(|(a, b)| a.rust_type == b.rust_type);
+            if !same_types {
+                continue;
+            }
+            let parameters: BTreeMap<String, String> = old
+                .parameters
+                .iter()
+                .zip(&new.parameters)
+                .filter(|(a, b)| a.serialized_name != b.serialized_name)
+                .map(|(a, b)| (a.serialized_name.clone(), b.serialized_name.clone()))
+                .collect();
+            if !parameters.is_empty() {
+                renames
+                    .parameters
+                    .insert(new.ts_function_name.clone(), parameters);
+            }
+        }
+        renames
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.commands.is_empty() && self.parameters.is_empty()
+    }
+
+    /// Number of renamed commands and parameters
+    pub fn len(&self) -> usize {
+        self.commands.len() + self.parameters.values().map(BTreeMap::len).sum::<usize>()
+    }
+
+    /// Write the map to `renames.json` in the output directory
+    pub fn save<P: AsRef<Path>>(&self, output_dir: P) -> std::io::Result<PathBuf> {
+        let path = output_dir.as_ref().join(RENAME_MAP_FILE_NAME);
+        let content = serde_json::to_string_pretty(self).map_err(std::io::Error::other)?;
+        fs::write(&path, content)?;
+        Ok(path)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::ParameterInfo;
+
+    fn param(name: &str, rust_type: &str) -> ParameterInfo {
+        ParameterInfo {
+            name: name.to_string(),
+            rust_type: rust_type.to_string(),
+            is_optional: false,
+            type_structure: TypeStructure::Primitive("string".to_string()),
+            serde_rename: None,
+        }
+    }
+
+    fn command(name: &str, parameters: Vec<ParameterInfo>, return_type: &str) -> CommandInfo {
+        CommandInfo::new_for_test(
+            name,
+            "src/user.rs",
+            1,
+            parameters,
+            return_type,
+            false,
+            vec![],
+        )
+    }
+
+    fn renames(previous: &[CommandInfo], current: &[CommandInfo]) -> RenameMap {
+        let config = GenerateConfig::default();
+        RenameMap::between(
+            &ApiSnapshot::new(previous, &config),
+            &ApiSnapshot::new(current, &config),
+        )
+    }
+
+    #[test]
+    fn test_detects_renamed_command() {
+        let map = renames(
+            &[command("get_user", vec![param("user_id", "u32")], "User")],
+            &[command("fetch_user", vec![param("user_id", "u32")], "User")],
+        );
+
+        assert_eq!(map.commands["getUser"], "fetchUser");
+        assert_eq!(map.types["GetUserParams"], "FetchUserParams");
+        assert!(map.parameters.is_empty());
+        assert_eq!(map.len(), 1);
+    }
+
+    #[test]
+    fn test_detects_renamed_parameters() {
+        let map = renames(
+            &[command(
+                "get_user",
+                vec![param("user_id", "u32"), param("verbose", "bool")],
+                "User",
+            )],
+            &[command(
+                "fetch_user",
+                vec![param("id", "u32"), param("verbose", "bool")],
+                "User",
+            )],
+        );
+
+        assert_eq!(map.commands["getUser"], "fetchUser");
+        assert_eq!(map.parameters["fetchUser"]["userId"], "id");
+        assert_eq!(map.parameters["fetchUser"].len(), 1);
+    }
+
+    #[test]
+    fn test_ambiguous_or_changed_commands_are_not_renames() {
+        // Two removed commands share the added command's shape
+        let map = renames(
+            &[command("a", vec![], "()"), command("b", vec![], "()")],
+            &[command("c", vec![], "()")],
+        );
+        assert!(map.is_empty());
+
+        // Changed return type: a new command rather than a rename
+        let map = renames(
+            &[command("get_user", vec![], "User")],
+            &[command("fetch_user", vec![], "Account")],
+        );
+        assert!(map.is_empty());
+
+        // Changed parameter types: parameters are not matched up
+        let map = renames(
+            &[command("save", vec![param("value", "u32")], "()")],
+            &[command("save", vec![param("text", "String")], "()")],
+        );
+        assert!(map.is_empty());
+    }
+}
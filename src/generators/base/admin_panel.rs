@@ -0,0 +1,259 @@
+use crate::generators::base::template_context::{CommandContext, ParameterContext};
+use crate::models::TypeStructure;
+use serde::Serialize;
+use std::fs;
+use std::path::{Component, Path, PathBuf};
+
+/// First line of every scaffold, followed by the hash of the rest of the file.
+/// A file whose hash no longer matches was edited and is not overwritten.
+pub const SCAFFOLD_MARKER: &str = "// @scaffold tauri-typegen";
+
+/// Admin panel form and result view scaffolded for a command
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AdminCommand {
+    pub name: String,
+    pub ts_function_name: String,
+    pub component_name: String,
+    pub fields: Vec<AdminField>,
+}
+
+/// Form input for a command parameter
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AdminField {
+    /// Serialized parameter name
+    pub name: String,
+    pub typescript_type: String,
+    /// `text`, `number`, `checkbox` or `json` (a textarea parsed as JSON)
+    pub kind: &'static str,
+    pub is_optional: bool,
+}
+
+/// Outcome of writing a scaffold
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ScaffoldWrite {
+    Written,
+    /// The existing file was edited and is kept
+    KeptEdited,
+}
+
+impl AdminCommand {
+    /// Scaffolds for the commands, in command order. Commands taking channels are
+    /// left out since a form cannot provide them.
+    pub fn collect(commands: &[CommandContext]) -> Vec<Self> {
+        commands
+            .iter()
+            .filter(|command| command.channels.is_empty())
+            .map(|command| Self {
+                name: command.name.clone(),
+                ts_function_name: command.ts_function_name.clone(),
+                component_name: format!("{}Panel", command.ts_type_name),
+                fields: command.parameters.iter().map(AdminField::new).collect(),
+            })
+            .collect()
+    }
+}
+
+impl AdminField {
+    fn new(param: &ParameterContext) -> Self {
+        let structure = match &param.type_structure {
+            TypeStructure::Optional(inner) => inner.as_ref(),
+            structure => structure,
+        };
+        let kind = match structure {
+            _ if param.is_path => "text",
+            TypeStructure::Primitive(primitive) => match primitive.as_str() {
+                "string" => "text",
+                "number" => "number",
+                "boolean" => "checkbox",
+                _ => "json",
+            },
+            _ => "json",
+        };
+        Self {
+            name: param.serialized_name.clone(),
+            typescript_type: param.typescript_type.clone(),
+            kind,
+            is_optional: param.is_optional,
+        }
+    }
+}
+
+/// Write a scaffold into `dir` unless the existing file was edited since it was scaffolded
+pub fn write_scaffold(
+    dir: &Path,
+    file_name: &str,
+    content: &str,
+) -> std::io::Result<ScaffoldWrite> {
+    let path = dir.join(file_name);
+    if let Ok(existing) = fs::read_to_string(&path) {
+        if !is_unedited(&existing) {
+            return Ok(ScaffoldWrite::KeptEdited);
+        }
+    }
+    fs::create_dir_all(dir)?;
+    fs::write(
+        path,
+        format!("{} {}\n{}", SCAFFOLD_MARKER, content_hash(content), content),
+    )?;
+    Ok(ScaffoldWrite::Written)
+}
+
+fn is_unedited(existing: &str) -> bool {
+    let Some((first_line, rest)) = existing.split_once('\n') else {
+        return false;
+    };
+    first_line
+        .strip_prefix(SCAFFOLD_MARKER)
+        .is_some_and(|hash| hash.trim() == content_hash(rest))
+}
+
+fn content_hash(content: &str) -> String {
+    use std::collections::hash_map::DefaultHasher;
+    use std::hash::{Hash, Hasher};
+
+    let mut hasher = DefaultHasher::new();
+    content.hash(&mut hasher);
+    format!("{:x}", hasher.finish())
+}
+
+/// Relative module specifier from `from_dir` to `to_dir`, e.g. `../generated`
+pub fn relative_module(from_dir: &Path, to_dir: &Path) -> String {
+    let from = normalize(from_dir);
+    let to = normalize(to_dir);
+    let common = from
+        .components()
+        .zip(to.components())
+        .take_while(|(a, b)| a == b)
+        .count();
+
+    let mut parts: Vec<String> = from
+        .components()
+        .skip(common)
+        .map(|_| "..".to_string())
+        .collect();
+    parts.extend(
+        to.components()
+            .skip(common)
+            .map(|c| c.as_os_str().to_string_lossy().to_string()),
+    );
+    match parts.first().map(String::as_str) {
+        None => ".".to_string(),
+        Some("..") => parts.join("/"),
+        Some(_) => format!("./{}", parts.join("/")),
+    }
+}
+
+/// Absolute path with `.` and `..` resolved lexically
+fn normalize(path: &Path) -> PathBuf {
+    let absolute = if path.is_absolute() {
+        path.to_path_buf()
+    } else {
+        std::env::current_dir().unwrap_or_default().join(path)
+    };
+    let mut normalized = PathBuf::new();
+    for component in absolute.components() {
+        match component {
+            Component::CurDir => {}
+            Component::ParentDir => {
+                normalized.pop();
+            }
+            component => normalized.push(component),
+        }
+    }
+    normalized
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::generators::ts::type_visitor::TypeScriptVisitor;
+    use crate::models::{CommandInfo, ParameterInfo};
+    use crate::GenerateConfig;
+    use tempfile::TempDir;
+
+    fn param(name: &str, rust_type: &str, type_structure: TypeStructure) -> ParameterInfo {
+        ParameterInfo {
+            name: name.to_string(),
+            rust_type: rust_type.to_string(),
+            is_optional: matches!(type_structure, TypeStructure::Optional(_)),
+            type_structure,
+            serde_rename: None,
+        }
+    }
+
+    #[test]
+    fn test_field_kinds() {
+        let config = GenerateConfig::default();
+        let visitor = TypeScriptVisitor::with_config(&config);
+        let resolver = |_: &str| TypeStructure::default();
+        let command = CommandInfo::new_for_test(
+            "save_user",
+            "src/lib.rs",
+            1,
+            vec![
+                param("name", "String", TypeStructure::Primitive("string".into())),
+                param(
+                    "age",
+                    "Option<u8>",
+                    TypeStructure::Optional(Box::new(TypeStructure::Primitive("number".into()))),
+                ),
+                param("admin", "bool", TypeStructure::Primitive("boolean".into())),
+                param("user", "User", TypeStructure::Custom("User".into())),
+            ],
+            "()",
+            true,
+            vec![],
+        );
+        let context = CommandContext::new(&config).from_command_info(&command, &visitor, &resolver);
+
+        let admin = AdminCommand::collect(&[context]);
+
+        assert_eq!(admin[0].component_name, "SaveUserPanel");
+        let kinds: Vec<&str> = admin[0].fields.iter().map(|f| f.kind).collect();
+        assert_eq!(kinds, vec!["text", "number", "checkbox", "json"]);
+        assert!(admin[0].fields[1].is_optional);
+    }
+
+    #[test]
+    fn test_edited_scaffolds_are_kept() {
+        let temp_dir = TempDir::new().unwrap();
+        let dir = temp_dir.path();
+
+        assert_eq!(
+            write_scaffold(dir, "Panel.tsx", "v1\n").unwrap(),
+            ScaffoldWrite::Written
+        );
+        // Unedited scaffolds are regenerated
+        assert_eq!(
+            write_scaffold(dir, "Panel.tsx", "v2\n").unwrap(),
+            ScaffoldWrite::Written
+        );
+        let written = fs::read_to_string(dir.join("Panel.tsx")).unwrap();
+        assert!(written.starts_with(SCAFFOLD_MARKER));
+        assert!(written.ends_with("\nv2\n"));
+
+        fs::write(dir.join("Panel.tsx"), written.replace("v2", "custom")).unwrap();
+        assert_eq!(
+            write_scaffold(dir, "Panel.tsx", "v3\n").unwrap(),
+            ScaffoldWrite::KeptEdited
+        );
+        assert!(fs::read_to_string(dir.join("Panel.tsx"))
+            .unwrap()
+            .contains("custom"));
+    }
+
+    #[test]
+    fn test_relative_module() {
+        assert_eq!(
+            relative_module(Path::new("./src/admin"), Path::new("./src/generated")),
+            "../generated"
+        );
+        assert_eq!(
+            relative_module(Path::new("/app/src"), Path::new("/app/src/generated")),
+            "./generated"
+        );
+        assert_eq!(relative_module(Path::new("/app"), Path::new("/app")), ".");
+    }
+}
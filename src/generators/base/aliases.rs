@@ -0,0 +1,161 @@
+use crate::generators::base::template_context::{CommandContext, NamingContext};
+use crate::models::CommandInfo;
+use crate::GenerateConfig;
+use serde::Serialize;
+use std::collections::HashSet;
+
+/// Deprecated function name kept for a renamed command (see `aliases`)
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CommandAlias {
+    /// Old Rust command name
+    pub command: String,
+    /// Old TypeScript function name, bound to the new function
+    pub ts_function_name: String,
+    /// Function the alias points at
+    pub target_function_name: String,
+    /// Old and new parameter type names, `None` for commands without parameters
+    pub params_type: Option<(String, String)>,
+}
+
+impl CommandAlias {
+    /// The aliases of `commands`, by old name. Aliases named like one of the commands
+    /// are left out.
+    pub fn collect(commands: &[CommandContext], config: &GenerateConfig) -> Vec<Self> {
+        let Some(ref aliases) = config.aliases else {
+            return Vec::new();
+        };
+        let function_names: HashSet<&str> = commands
+            .iter()
+            .map(|command| command.ts_function_name.as_str())
+            .collect();
+
+        let mut aliases: Vec<Self> = aliases
+            .iter()
+            .filter_map(|(old_name, new_name)| {
+                let target = commands.iter().find(|command| &command.name == new_name)?;
+                let ts_function_name = target.compute_function_name(old_name, &None);
+                if function_names.contains(ts_function_name.as_str()) {
+                    return None;
+                }
+                let has_params = !target.parameters.is_empty() || !target.channels.is_empty();
+                Some(Self {
+                    command: old_name.clone(),
+                    ts_function_name,
+                    target_function_name: target.ts_function_name.clone(),
+                    params_type: has_params.then(|| {
+                        (
+                            format!("{}Params", target.compute_type_name(old_name, &None)),
+                            format!("{}Params", target.ts_type_name),
+                        )
+                    }),
+                })
+            })
+            .collect();
+        aliases.sort_by(|a, b| a.command.cmp(&b.command));
+        aliases
+    }
+
+    /// Warnings for aliases pointing at no command or named like a command
+    pub fn warnings(commands: &[CommandInfo], config: &GenerateConfig) -> Vec<String> {
+        let Some(ref aliases) = config.aliases else {
+            return Vec::new();
+        };
+        let names: HashSet<&str> = commands.iter().map(|cmd| cmd.name.as_str()).collect();
+
+        let mut warnings: Vec<String> = aliases
+            .iter()
+            .filter_map(|(old_name, new_name)| {
+                if !names.contains(new_name.as_str()) {
+                    Some(format!(
+                        "Alias {} points at {}, which is not a command",
+                        old_name, new_name
+                    ))
+                } else if names.contains(old_name.as_str()) {
+                    Some(format!(
+                        "Alias {} of {} is still a command, no alias is generated",
+                        old_name, new_name
+                    ))
+                } else {
+                    None
+                }
+            })
+            .collect();
+        warnings.sort();
+        warnings
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::generators::ts::type_visitor::TypeScriptVisitor;
+    use crate::models::{ParameterInfo, TypeStructure};
+    use std::collections::HashMap;
+
+    #[test]
+    fn test_collects_aliases_of_renamed_commands() {
+        let config = GenerateConfig {
+            aliases: Some(HashMap::from([
+                ("get_user".to_string(), "fetch_user".to_string()),
+                ("ping_server".to_string(), "ping".to_string()),
+                ("fetch_user".to_string(), "ping".to_string()),
+                ("load_user".to_string(), "missing".to_string()),
+            ])),
+            ..Default::default()
+        };
+        let visitor = TypeScriptVisitor::with_config(&config);
+        let resolver = |_: &str| TypeStructure::default();
+        let param = ParameterInfo {
+            name: "id".to_string(),
+            rust_type: "u32".to_string(),
+            is_optional: false,
+            type_structure: TypeStructure::Primitive("number".to_string()),
+            serde_rename: None,
+        };
+        let commands = [
+            CommandInfo::new_for_test(
+                "fetch_user",
+                "src/lib.rs",
+                1,
+                vec![param],
+                "()",
+                false,
+                vec![],
+            ),
+            CommandInfo::new_for_test("ping", "src/lib.rs", 5, vec![], "()", false, vec![]),
+        ];
+        let contexts: Vec<CommandContext> = commands
+            .iter()
+            .map(|command| {
+                CommandContext::new(&config).from_command_info(command, &visitor, &resolver)
+            })
+            .collect();
+
+        let aliases = CommandAlias::collect(&contexts, &config);
+        assert_eq!(
+            aliases,
+            [
+                CommandAlias {
+                    command: "get_user".to_string(),
+                    ts_function_name: "getUser".to_string(),
+                    target_function_name: "fetchUser".to_string(),
+                    params_type: Some(("GetUserParams".to_string(), "FetchUserParams".to_string())),
+                },
+                CommandAlias {
+                    command: "ping_server".to_string(),
+                    ts_function_name: "pingServer".to_string(),
+                    target_function_name: "ping".to_string(),
+                    params_type: None,
+                },
+            ]
+        );
+        assert_eq!(
+            CommandAlias::warnings(&commands, &config),
+            [
+                "Alias fetch_user of ping is still a command, no alias is generated",
+                "Alias load_user points at missing, which is not a command",
+            ]
+        );
+    }
+}
@@ -0,0 +1,527 @@
+use crate::analysis::type_resolver::JSON_VALUE_TYPE;
+use crate::generators::base::casing::property_key;
+use crate::generators::base::template_context::{CommandContext, NamingContext};
+use crate::models::{FieldInfo, StructInfo};
+use crate::{GenerateConfig, TypeStructure};
+use serde::Serialize;
+use std::collections::{HashMap, HashSet, VecDeque};
+
+/// A named fast-check arbitrary, e.g. `UserArbitrary`
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct ArbitraryDefinition {
+    pub name: String,
+    pub expression: String,
+}
+
+/// Builds [fast-check](https://fast-check.dev) arbitraries generating structurally
+/// valid values for the generated types and command parameters.
+///
+/// Integer types keep their Rust bounds (`u8` → `0..=255`), floats exclude NaN and
+/// infinity (not representable in JSON). Arbitraries reference the arbitraries of
+/// the types they contain, so types must be added dependencies first; recursive
+/// references are cut off (empty collections, absent options).
+pub struct ArbitraryBuilder<'a> {
+    structs: &'a HashMap<String, StructInfo>,
+    config: &'a GenerateConfig,
+    /// Zod and valibot schemas parse `Set`s and leave absent options `undefined`
+    zod: bool,
+    /// Types whose arbitrary is already defined
+    defined: HashSet<String>,
+}
+
+impl NamingContext for ArbitraryBuilder<'_> {
+    fn config(&self) -> &GenerateConfig {
+        self.config
+    }
+}
+
+impl<'a> ArbitraryBuilder<'a> {
+    pub fn new(
+        structs: &'a HashMap<String, StructInfo>,
+        config: &'a GenerateConfig,
+        zod: bool,
+    ) -> Self {
+        Self {
+            structs,
+            config,
+            zod,
+            defined: HashSet::new(),
+        }
+    }
+
+    /// Name of the arbitrary of a type
+    pub fn arbitrary_name(type_name: &str) -> String {
+        format!("{}Arbitrary", type_name)
+    }
+
+    /// Arbitrary of a struct or enum; `None` for types without a definition
+    pub fn type_arbitrary(&mut self, name: &str) -> Option<ArbitraryDefinition> {
+        let struct_info = self.structs.get(name)?;
+        let expression = if struct_info.is_enum {
+            self.enum_arbitrary(struct_info)
+        } else if struct_info.is_newtype {
+            let field = struct_info.fields.first()?;
+            self.value(&field.type_structure, &mut Self::numbers(&field.rust_type))
+        } else {
+            self.object_arbitrary(&struct_info.fields, &struct_info.serde_rename_all)
+        };
+        self.defined.insert(name.to_string());
+        Some(ArbitraryDefinition {
+            name: Self::arbitrary_name(name),
+            expression,
+        })
+    }
+
+    /// Arbitrary of the parameters of a command, `None` for commands without
+    /// parameters or taking channels (which cannot be generated)
+    pub fn params_arbitrary(&self, command: &CommandContext) -> Option<ArbitraryDefinition> {
+        if command.parameters.is_empty() || !command.channels.is_empty() {
+            return None;
+        }
+        let members: Vec<String> = command
+            .parameters
+            .iter()
+            .map(|param| {
+                format!(
+                    "{}: {}",
+                    property_key(&param.serialized_name),
+                    self.value(&param.type_structure, &mut Self::numbers(&param.rust_type))
+                )
+            })
+            .collect();
+        Some(ArbitraryDefinition {
+            name: Self::arbitrary_name(&format!("{}Params", command.ts_type_name)),
+            expression: format!("fc.record({{ {} }})", members.join(", ")),
+        })
+    }
+
+    /// Numeric Rust types in a type string, in the order the type structure visits them
+    fn numbers(rust_type: &str) -> VecDeque<String> {
+        rust_type
+            .split(|c: char| !c.is_alphanumeric() && c != '_')
+            .filter(|ident| Self::number_arbitrary(ident).is_some())
+            .map(str::to_string)
+            .collect()
+    }
+
+    fn number_arbitrary(rust_type: &str) -> Option<&'static str> {
+        Some(match rust_type {
+            "u8" => "fc.integer({ min: 0, max: 255 })",
+            "u16" => "fc.integer({ min: 0, max: 65535 })",
+            "u32" => "fc.integer({ min: 0, max: 4294967295 })",
+            "u64" | "u128" | "usize" => "fc.maxSafeNat()",
+            "i8" => "fc.integer({ min: -128, max: 127 })",
+            "i16" => "fc.integer({ min: -32768, max: 32767 })",
+            "i32" => "fc.integer({ min: -2147483648, max: 2147483647 })",
+            "i64" | "i128" | "isize" => "fc.maxSafeInteger()",
+            "f32" => "fc.float({ noNaN: true, noDefaultInfinity: true })",
+            "f64" => "fc.double({ noNaN: true, noDefaultInfinity: true })",
+            _ => return None,
+        })
+    }
+
+    /// Arbitrary expression for a type; `numbers` holds the numeric Rust types of the
+    /// surrounding type string, consumed as number primitives are visited
+    fn value(&self, structure: &TypeStructure, numbers: &mut VecDeque<String>) -> String {
+        match structure {
+            TypeStructure::Primitive(primitive) => match primitive.as_str() {
+                "string" => "fc.string()".to_string(),
+                "number" => numbers
+                    .pop_front()
+                    .and_then(|rust_type| Self::number_arbitrary(&rust_type))
+                    .unwrap_or("fc.integer()")
+                    .to_string(),
+                "boolean" => "fc.boolean()".to_string(),
+                "void" => "fc.constant(null)".to_string(),
+                _ => "fc.anything()".to_string(),
+            },
+            TypeStructure::Array(inner) => format!("fc.array({})", self.value(inner, numbers)),
+            TypeStructure::Set(inner) if self.zod => format!(
+                "fc.uniqueArray({}).map((items) => new Set(items))",
+                self.value(inner, numbers)
+            ),
+            TypeStructure::Set(inner) => format!("fc.uniqueArray({})", self.value(inner, numbers)),
+            TypeStructure::Map { key, value } => {
+                let key = match key.as_ref() {
+                    TypeStructure::Primitive(primitive) if primitive == "number" => {
+                        format!("{}.map(String)", self.value(key, numbers))
+                    }
+                    _ => "fc.string()".to_string(),
+                };
+                format!("fc.dictionary({}, {})", key, self.value(value, numbers))
+            }
+            TypeStructure::Tuple(types) if types.is_empty() => "fc.constant(null)".to_string(),
+            TypeStructure::Tuple(types) => {
+                let values: Vec<String> = types.iter().map(|t| self.value(t, numbers)).collect();
+                format!("fc.tuple({})", values.join(", "))
+            }
+            TypeStructure::Optional(inner) => {
+                // Mirrors the generated types: Zod schemas use `.optional()`, interfaces `| null`
+                let nil = if self.zod { "undefined" } else { "null" };
+                format!(
+                    "fc.option({}, {{ nil: {} }})",
+                    self.value(inner, numbers),
+                    nil
+                )
+            }
+            TypeStructure::Result(inner) => self.value(inner, numbers),
+            TypeStructure::Union(types) => {
+                let values: Vec<String> = types.iter().map(|t| self.value(t, numbers)).collect();
+                format!("fc.oneof({})", values.join(", "))
+            }
+            TypeStructure::Custom(name) => self.custom_value(name),
+        }
+    }
+
+    fn custom_value(&self, name: &str) -> String {
+        if name == JSON_VALUE_TYPE {
+            return "fc.jsonValue()".to_string();
+        }
+        if let Some(ts_type) = self
+            .config
+            .type_mappings
+            .as_ref()
+            .and_then(|mappings| mappings.get(name))
+        {
+            return match ts_type.as_str() {
+                "string" | "number" | "boolean" => self.value(
+                    &TypeStructure::Primitive(ts_type.clone()),
+                    &mut VecDeque::new(),
+                ),
+                _ => "fc.anything()".to_string(),
+            };
+        }
+        if self.defined.contains(name) {
+            return Self::arbitrary_name(name);
+        }
+        if self.structs.contains_key(name) {
+            // Recursive reference to a type whose arbitrary is being defined
+            return "fc.constant(null)".to_string();
+        }
+        "fc.anything()".to_string()
+    }
+
+    fn object_arbitrary(
+        &self,
+        fields: &[FieldInfo],
+        rename_all: &Option<serde_rename_rule::RenameRule>,
+    ) -> String {
+        let (flattened, own): (Vec<&FieldInfo>, Vec<&FieldInfo>) = fields
+            .iter()
+            .filter(|field| field.is_public || self.config.should_include_private())
+            .partition(|field| field.flatten);
+        let members: Vec<String> = own
+            .into_iter()
+            .map(|field| {
+                let key = self.compute_field_name(&field.name, &field.serde_rename, rename_all);
+                let value = self.field_value(field);
+                format!("{}: {}", property_key(&key), value)
+            })
+            .collect();
+        let record = format!("fc.record({{ {} }})", members.join(", "));
+        if flattened.is_empty() {
+            return record;
+        }
+
+        // Flattened fields are generated separately and spread into the record
+        let names: Vec<String> = (0..=flattened.len()).map(|i| format!("p{}", i)).collect();
+        let arbitraries: Vec<String> = flattened
+            .iter()
+            .map(|field| self.field_value(field))
+            .collect();
+        format!(
+            "fc.tuple({}, {}).map(([{}]) => ({{ {} }}))",
+            record,
+            arbitraries.join(", "),
+            names.join(", "),
+            names
+                .iter()
+                .map(|name| format!("...{}", name))
+                .collect::<Vec<_>>()
+                .join(", ")
+        )
+    }
+
+    /// Field values cut off recursion: self-referencing collections stay empty and
+    /// self-referencing options absent
+    fn field_value(&self, field: &FieldInfo) -> String {
+        if let Some(ref literal) = field.literal {
+            let literal = literal.replace('\\', "\\\\").replace('\'', "\\'");
+            return format!("fc.constant('{}')", literal);
+        }
+        let mut numbers = Self::numbers(&field.rust_type);
+        match &field.type_structure {
+            TypeStructure::Array(inner) | TypeStructure::Set(inner) if self.is_recursive(inner) => {
+                if self.zod && matches!(field.type_structure, TypeStructure::Set(_)) {
+                    "fc.constant(new Set())".to_string()
+                } else {
+                    "fc.constant([])".to_string()
+                }
+            }
+            TypeStructure::Map { value, .. } if self.is_recursive(value) => {
+                "fc.constant({})".to_string()
+            }
+            TypeStructure::Optional(inner) if self.is_recursive(inner) => {
+                let nil = if self.zod { "undefined" } else { "null" };
+                format!("fc.constant({})", nil)
+            }
+            structure => self.value(structure, &mut numbers),
+        }
+    }
+
+    fn is_recursive(&self, structure: &TypeStructure) -> bool {
+        matches!(structure, TypeStructure::Custom(name)
+            if self.structs.contains_key(name) && !self.defined.contains(name))
+    }
+
+    /// One-of the variants of an enum, in the enum's serde representation
+    fn enum_arbitrary(&self, enum_info: &StructInfo) -> String {
+        let variants: Vec<String> = enum_info
+            .fields
+            .iter()
+            .map(|variant| {
+                let name = self.compute_variant_name(
+                    &variant.name,
+                    &variant.serde_rename,
+                    &enum_info.serde_rename_all,
+                );
+                let name_literal = format!("'{}'", name.replace('\\', "\\\\").replace('\'', "\\'"));
+                let payload = match variant.rust_type.as_str() {
+                    "enum_variant_tuple" => Some(self.value(
+                        &variant.type_structure,
+                        &mut Self::numbers(&Self::variant_types(variant)),
+                    )),
+                    "enum_variant_struct" => {
+                        Some(self.object_arbitrary(&variant.variant_fields, &None))
+                    }
+                    _ => None,
+                };
+
+                match (
+                    enum_info.is_untagged(),
+                    &enum_info.serde_tag,
+                    &enum_info.serde_content,
+                    payload,
+                ) {
+                    (true, _, _, Some(payload)) => payload,
+                    (true, _, _, None) => "fc.constant(null)".to_string(),
+                    (false, Some(tag), Some(content), Some(payload)) => format!(
+                        "fc.record({{ {}: fc.constant({}), {}: {} }})",
+                        property_key(tag),
+                        name_literal,
+                        property_key(content),
+                        payload
+                    ),
+                    (false, Some(tag), None, Some(payload)) => format!(
+                        "{}.map((fields) => ({{ {}: {} as const, ...fields }}))",
+                        payload,
+                        property_key(tag),
+                        name_literal
+                    ),
+                    (false, Some(tag), _, None) => format!(
+                        "fc.constant({{ {}: {} as const }})",
+                        property_key(tag),
+                        name_literal
+                    ),
+                    (false, None, _, Some(payload)) => {
+                        format!("fc.record({{ {}: {} }})", property_key(&name), payload)
+                    }
+                    (false, None, _, None) => format!("fc.constant({} as const)", name_literal),
+                }
+            })
+            .collect();
+
+        match variants.len() {
+            0 => "fc.constant(null)".to_string(),
+            1 => variants.into_iter().next().unwrap_or_default(),
+            _ => format!("fc.oneof({})", variants.join(", ")),
+        }
+    }
+
+    /// Rust types of a tuple variant's fields, for numeric bounds
+    fn variant_types(variant: &FieldInfo) -> String {
+        variant
+            .variant_fields
+            .iter()
+            .map(|field| field.rust_type.as_str())
+            .collect::<Vec<_>>()
+            .join(", ")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn field(name: &str, rust_type: &str, type_structure: TypeStructure) -> FieldInfo {
+        FieldInfo {
+            name: name.to_string(),
+            rust_type: rust_type.to_string(),
+            is_optional: matches!(type_structure, TypeStructure::Optional(_)),
+            is_public: true,
+            validator_attributes: None,
+            serde_rename: None,
+            type_structure,
+            variant_fields: vec![],
+            literal: None,
+            flatten: false,
+            omittable: false,
+            defaulted: false,
+            skip_serializing_if: false,
+            skipped: None,
+            serde_with: None,
+        }
+    }
+
+    fn type_info(name: &str, fields: Vec<FieldInfo>, is_enum: bool) -> StructInfo {
+        StructInfo {
+            name: name.to_string(),
+            fields,
+            file_path: "models.rs".to_string(),
+            is_enum,
+            serde_rename_all: None,
+            is_newtype: false,
+            serde_tag: None,
+            serde_content: None,
+            serde_untagged: false,
+            line_number: 0,
+            is_progress: false,
+            serde_deny_unknown_fields: false,
+        }
+    }
+
+    fn number() -> TypeStructure {
+        TypeStructure::Primitive("number".to_string())
+    }
+
+    fn custom(name: &str) -> TypeStructure {
+        TypeStructure::Custom(name.to_string())
+    }
+
+    fn structs() -> HashMap<String, StructInfo> {
+        let node = type_info(
+            "Node",
+            vec![
+                field("weight", "f64", number()),
+                field(
+                    "children",
+                    "Vec<Node>",
+                    TypeStructure::Array(Box::new(custom("Node"))),
+                ),
+                field(
+                    "parent",
+                    "Option<Box<Node>>",
+                    TypeStructure::Optional(Box::new(custom("Node"))),
+                ),
+            ],
+            false,
+        );
+        let status = type_info(
+            "Status",
+            vec![
+                field("Active", "enum_variant", TypeStructure::default()),
+                field("Blocked", "enum_variant", TypeStructure::default()),
+            ],
+            true,
+        );
+        let point = type_info(
+            "Point",
+            vec![
+                field("x_pos", "u8", number()),
+                field(
+                    "scores",
+                    "HashMap<String, i16>",
+                    TypeStructure::Map {
+                        key: Box::new(TypeStructure::Primitive("string".to_string())),
+                        value: Box::new(number()),
+                    },
+                ),
+                field("status", "Status", custom("Status")),
+            ],
+            false,
+        );
+        [node, status, point]
+            .into_iter()
+            .map(|info| (info.name.clone(), info))
+            .collect()
+    }
+
+    #[test]
+    fn test_numbers_keep_rust_bounds() {
+        let structs = structs();
+        let config = GenerateConfig::default();
+        let mut builder = ArbitraryBuilder::new(&structs, &config, false);
+        builder.type_arbitrary("Status");
+        let point = builder.type_arbitrary("Point").unwrap();
+
+        assert_eq!(point.name, "PointArbitrary");
+        assert_eq!(
+            point.expression,
+            "fc.record({ x_pos: fc.integer({ min: 0, max: 255 }), \
+             scores: fc.dictionary(fc.string(), fc.integer({ min: -32768, max: 32767 })), \
+             status: StatusArbitrary })"
+        );
+    }
+
+    #[test]
+    fn test_unit_enum_picks_a_variant() {
+        let structs = structs();
+        let config = GenerateConfig::default();
+        let mut builder = ArbitraryBuilder::new(&structs, &config, false);
+
+        assert_eq!(
+            builder.type_arbitrary("Status").unwrap().expression,
+            "fc.oneof(fc.constant('Active' as const), fc.constant('Blocked' as const))"
+        );
+    }
+
+    #[test]
+    fn test_recursion_is_cut_off() {
+        let structs = structs();
+        let config = GenerateConfig::default();
+        let mut builder = ArbitraryBuilder::new(&structs, &config, false);
+
+        assert_eq!(
+            builder.type_arbitrary("Node").unwrap().expression,
+            "fc.record({ weight: fc.double({ noNaN: true, noDefaultInfinity: true }), \
+             children: fc.constant([]), parent: fc.constant(null) })"
+        );
+    }
+
+    #[test]
+    fn test_flattened_fields_are_spread() {
+        let mut structs = structs();
+        let mut point = field("point", "Point", custom("Point"));
+        point.flatten = true;
+        structs.insert(
+            "Labeled".to_string(),
+            type_info(
+                "Labeled",
+                vec![field("label", "String", TypeStructure::default()), point],
+                false,
+            ),
+        );
+        let config = GenerateConfig::default();
+        let mut builder = ArbitraryBuilder::new(&structs, &config, false);
+        builder.type_arbitrary("Point");
+
+        assert_eq!(
+            builder.type_arbitrary("Labeled").unwrap().expression,
+            "fc.tuple(fc.record({ label: fc.string() }), PointArbitrary)\
+             .map(([p0, p1]) => ({ ...p0, ...p1 }))"
+        );
+    }
+
+    #[test]
+    fn test_zod_options_are_undefined() {
+        let structs = HashMap::new();
+        let config = GenerateConfig::default();
+        let builder = ArbitraryBuilder::new(&structs, &config, true);
+        let optional = TypeStructure::Optional(Box::new(number()));
+
+        assert_eq!(
+            builder.value(&optional, &mut ArbitraryBuilder::numbers("Option<i64>")),
+            "fc.option(fc.maxSafeInteger(), { nil: undefined })"
+        );
+    }
+}
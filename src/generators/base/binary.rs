@@ -0,0 +1,195 @@
+use crate::generators::base::template_context::CommandContext;
+use serde::Serialize;
+
+/// Rust types serialized as a byte array, which `binaryHelpers` read from a `File`/`Blob`
+const BYTE_TYPES: &[&str] = &[
+    "Vec<u8>",
+    "Box<[u8]>",
+    "Bytes",
+    "bytes::Bytes",
+    "BytesMut",
+    "bytes::BytesMut",
+    "ByteBuf",
+    "serde_bytes::ByteBuf",
+];
+
+/// Name of the integer parameter that turns a byte parameter into a chunked upload
+const OFFSET_PARAMETER: &str = "offset";
+
+/// File and Blob helpers generated for a command sending or returning bytes
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BinaryCommand {
+    pub name: String,
+    pub ts_function_name: String,
+    pub ts_type_name: String,
+    pub return_type_ts: String,
+    pub has_parameters: bool,
+    /// Serialized name of the single byte parameter, read from a `File`/`Blob`
+    pub bytes_parameter: Option<String>,
+    /// Serialized name of the `offset` parameter of chunked uploads
+    pub offset_parameter: Option<String>,
+    pub returns_bytes: bool,
+}
+
+impl BinaryCommand {
+    /// Binary helpers for the commands that have any, in command order
+    pub fn collect(commands: &[CommandContext]) -> Vec<Self> {
+        commands.iter().filter_map(Self::new).collect()
+    }
+
+    fn new(command: &CommandContext) -> Option<Self> {
+        let mut byte_parameters = command
+            .parameters
+            .iter()
+            .filter(|param| !param.is_optional && Self::is_byte_type(&param.rust_type));
+        // Several byte parameters are ambiguous as to which one the file fills
+        let bytes_parameter = match (byte_parameters.next(), byte_parameters.next()) {
+            (Some(param), None) => Some(param.serialized_name.clone()),
+            _ => None,
+        };
+        let offset_parameter = bytes_parameter.as_ref().and_then(|_| {
+            command
+                .parameters
+                .iter()
+                .find(|param| param.name == OFFSET_PARAMETER && Self::is_integer(&param.rust_type))
+                .map(|param| param.serialized_name.clone())
+        });
+        let returns_bytes =
+            command.raw_response || Self::is_byte_type(Self::ok_type(&command.return_type));
+
+        if bytes_parameter.is_none() && !returns_bytes {
+            return None;
+        }
+        Some(Self {
+            name: command.name.clone(),
+            ts_function_name: command.ts_function_name.clone(),
+            ts_type_name: command.ts_type_name.clone(),
+            return_type_ts: command.return_type_ts.clone(),
+            has_parameters: !command.parameters.is_empty() || !command.channels.is_empty(),
+            bytes_parameter,
+            offset_parameter,
+            returns_bytes,
+        })
+    }
+
+    fn is_byte_type(rust_type: &str) -> bool {
+        let rust_type: String = rust_type.split_whitespace().collect();
+        BYTE_TYPES.contains(&rust_type.as_str())
+    }
+
+    fn is_integer(rust_type: &str) -> bool {
+        matches!(
+            rust_type,
+            "u32" | "u64" | "usize" | "i32" | "i64" | "isize" | "u128" | "i128"
+        )
+    }
+
+    /// `T` of `Result<T, E>`, other types unchanged
+    fn ok_type(rust_type: &str) -> &str {
+        let Some(inner) = rust_type
+            .strip_prefix("Result<")
+            .and_then(|inner| inner.strip_suffix('>'))
+        else {
+            return rust_type;
+        };
+        let mut depth = 0;
+        for (i, c) in inner.char_indices() {
+            match c {
+                '<' | '(' | '[' => depth += 1,
+                '>' | ')' | ']' => depth -= 1,
+                ',' if depth == 0 => return inner[..i].trim(),
+                _ => {}
+            }
+        }
+        inner.trim()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::generators::ts::type_visitor::TypeScriptVisitor;
+    use crate::models::{CommandInfo, ParameterInfo, TypeStructure};
+    use crate::GenerateConfig;
+
+    fn param(name: &str, rust_type: &str) -> ParameterInfo {
+        ParameterInfo {
+            name: name.to_string(),
+            rust_type: rust_type.to_string(),
+            is_optional: false,
+            type_structure: TypeStructure::Array(Box::new(TypeStructure::Primitive(
+                "number".to_string(),
+            ))),
+            serde_rename: None,
+        }
+    }
+
+    fn binary_command(parameters: Vec<ParameterInfo>, return_type: &str) -> Option<BinaryCommand> {
+        let config = GenerateConfig::default();
+        let visitor = TypeScriptVisitor::with_config(&config);
+        let resolver = |_: &str| TypeStructure::default();
+        let command = CommandInfo::new_for_test(
+            "save_file",
+            "src/lib.rs",
+            1,
+            parameters,
+            return_type,
+            true,
+            vec![],
+        );
+        let context = CommandContext::new(&config).from_command_info(&command, &visitor, &resolver);
+        BinaryCommand::new(&context)
+    }
+
+    #[test]
+    fn test_byte_parameter() {
+        let command = binary_command(
+            vec![param("name", "String"), param("data", "Vec<u8>")],
+            "()",
+        )
+        .unwrap();
+
+        assert_eq!(command.bytes_parameter.as_deref(), Some("data"));
+        assert_eq!(command.offset_parameter, None);
+        assert!(!command.returns_bytes);
+    }
+
+    #[test]
+    fn test_chunked_upload_with_offset() {
+        let command = binary_command(
+            vec![param("chunk", "bytes::Bytes"), param("offset", "u64")],
+            "Result<(), String>",
+        )
+        .unwrap();
+
+        assert_eq!(command.bytes_parameter.as_deref(), Some("chunk"));
+        assert_eq!(command.offset_parameter.as_deref(), Some("offset"));
+    }
+
+    #[test]
+    fn test_byte_return_type() {
+        let command = binary_command(vec![], "Result<Vec<u8>, String>").unwrap();
+
+        assert!(command.returns_bytes);
+        assert!(!command.has_parameters);
+        assert_eq!(command.bytes_parameter, None);
+    }
+
+    #[test]
+    fn test_skips_commands_without_bytes() {
+        assert!(binary_command(vec![param("ids", "Vec<u32>")], "Vec<String>").is_none());
+        // Ambiguous which parameter a file fills
+        assert!(binary_command(vec![param("a", "Vec<u8>"), param("b", "Vec<u8>")], "()").is_none());
+    }
+
+    #[test]
+    fn test_ok_type() {
+        assert_eq!(BinaryCommand::ok_type("Result<Vec<u8>, String>"), "Vec<u8>");
+        assert_eq!(
+            BinaryCommand::ok_type("Result<HashMap<String, u8>, Error>"),
+            "HashMap<String, u8>"
+        );
+        assert_eq!(BinaryCommand::ok_type("Vec<u8>"), "Vec<u8>");
+    }
+}
@@ -0,0 +1,349 @@
+/// Case conversion for generated TypeScript identifiers (function, type and
+/// listener names).
+///
+/// Splits on separators, lower→upper transitions and acronym boundaries
+/// (`HTTPServer` → `HTTP`, `Server`); digits stay attached to their word
+/// (`get_url_v2` → `get`, `url`, `v2`). Words matching a configured acronym
+/// are written in the acronym's spelling, so with `["URL", "IO"]`:
+/// `get_url_v2` → `getURLV2`, `io_error` → `IOError` / `ioError`.
+///
+/// Non-ASCII input is made safe for TypeScript: accented Latin letters are
+/// transliterated (`grüße` → `grusse`), other letters are kept, and symbols
+/// such as emoji become a stable code point word (`🎉` → `U1F389`).
+///
+/// Serialized field and parameter names are not converted here: they must
+/// match serde's `rename_all` output exactly.
+#[derive(Debug, Clone, Copy)]
+pub struct CaseConverter<'a> {
+    acronyms: &'a [String],
+}
+
+impl<'a> CaseConverter<'a> {
+    pub fn new(acronyms: &'a [String]) -> Self {
+        Self { acronyms }
+    }
+
+    /// Split an identifier into words, preserving the original spelling of each word
+    pub fn split_words(name: &str) -> Vec<String> {
+        let chars: Vec<char> = Self::transliterate(name).chars().collect();
+        let mut words = Vec::new();
+        let mut current = String::new();
+
+        for (i, &ch) in chars.iter().enumerate() {
+            if !ch.is_alphanumeric() {
+                if !current.is_empty() {
+                    words.push(std::mem::take(&mut current));
+                }
+                continue;
+            }
+
+            if ch.is_uppercase() && !current.is_empty() {
+                let prev = chars[i - 1];
+                let next_is_lower = chars.get(i + 1).is_some_and(|c| c.is_lowercase());
+                // fooBar, v2Name | HTTPServer: the `S` starts a new word
+                if prev.is_lowercase()
+                    || prev.is_numeric()
+                    || (prev.is_uppercase() && next_is_lower)
+                {
+                    words.push(std::mem::take(&mut current));
+                }
+            }
+            current.push(ch);
+        }
+
+        if !current.is_empty() {
+            words.push(current);
+        }
+        words
+    }
+
+    /// `get_url_v2` → `GetUrlV2` (`GetURLV2` with `URL` as acronym)
+    pub fn to_pascal_case(&self, name: &str) -> String {
+        Self::split_words(name)
+            .iter()
+            .map(|word| self.capitalize(word))
+            .collect()
+    }
+
+    /// `get_url_v2` → `getUrlV2` (`getURLV2` with `URL` as acronym)
+    ///
+    /// A leading acronym or all-caps word is lowercased entirely (`io_error` → `ioError`).
+    pub fn to_camel_case(&self, name: &str) -> String {
+        let words = Self::split_words(name);
+        let Some((first, rest)) = words.split_first() else {
+            return String::new();
+        };
+
+        let first = if self.acronym(first).is_some() || !first.chars().any(|c| c.is_lowercase()) {
+            first.to_lowercase()
+        } else {
+            lowercase_first(first)
+        };
+
+        std::iter::once(first)
+            .chain(rest.iter().map(|word| self.capitalize(word)))
+            .collect()
+    }
+
+    /// Make a converted name a valid identifier: `2fa` → `_2fa`, `` → `_`
+    pub fn identifier(name: String) -> String {
+        match name.chars().next() {
+            Some(first) if !first.is_numeric() => name,
+            _ => format!("_{}", name),
+        }
+    }
+
+    /// Whether the name contains symbols that can only be encoded by code point
+    pub fn has_untranslatable_chars(name: &str) -> bool {
+        name.chars()
+            .any(|ch| !ch.is_ascii() && !ch.is_alphanumeric() && transliterate_char(ch).is_none())
+    }
+
+    /// Replace accented Latin letters by ASCII and symbols by a separate `U<hex>` word
+    fn transliterate(name: &str) -> String {
+        let mut result = String::with_capacity(name.len());
+        for ch in name.chars() {
+            if ch.is_ascii() {
+                result.push(ch);
+            } else if let Some(ascii) = transliterate_char(ch) {
+                result.push_str(ascii);
+            } else if ch.is_alphanumeric() {
+                result.push(ch);
+            } else {
+                result.push_str(&format!("_U{:X}_", ch as u32));
+            }
+        }
+        result
+    }
+
+    fn capitalize(&self, word: &str) -> String {
+        if let Some(acronym) = self.acronym(word) {
+            return acronym.to_string();
+        }
+        let mut chars = word.chars();
+        match chars.next() {
+            Some(first) => first.to_uppercase().chain(chars).collect(),
+            None => String::new(),
+        }
+    }
+
+    fn acronym(&self, word: &str) -> Option<&'a str> {
+        self.acronyms
+            .iter()
+            .find(|acronym| acronym.eq_ignore_ascii_case(word))
+            .map(String::as_str)
+    }
+}
+
+/// Whether a property name can be written unquoted in a TypeScript object type or literal
+pub fn is_valid_identifier(name: &str) -> bool {
+    let mut chars = name.chars();
+    match chars.next() {
+        Some(first) if first.is_alphabetic() || first == '_' || first == '$' => {
+            chars.all(|ch| ch.is_alphanumeric() || ch == '_' || ch == '$')
+        }
+        _ => false,
+    }
+}
+
+/// Quote a property name when it is not a valid identifier: `user-id` → `"user-id"`
+pub fn property_key(name: &str) -> String {
+    if is_valid_identifier(name) {
+        name.to_string()
+    } else {
+        format!("\"{}\"", name.replace('\\', "\\\\").replace('"', "\\\""))
+    }
+}
+
+/// ASCII spelling of common accented Latin letters
+fn transliterate_char(ch: char) -> Option<&'static str> {
+    let ascii = match ch {
+        'À' | 'Á' | 'Â' | 'Ã' | 'Ä' | 'Å' | 'Ā' | 'Ă' | 'Ą' => "A",
+        'à' | 'á' | 'â' | 'ã' | 'ä' | 'å' | 'ā' | 'ă' | 'ą' => "a",
+        'Æ' => "AE",
+        'æ' => "ae",
+        'Ç' | 'Ć' | 'Č' => "C",
+        'ç' | 'ć' | 'č' => "c",
+        'Ð' | 'Ď' | 'Đ' => "D",
+        'ð' | 'ď' | 'đ' => "d",
+        'È' | 'É' | 'Ê' | 'Ë' | 'Ē' | 'Ė' | 'Ę' | 'Ě' => "E",
+        'è' | 'é' | 'ê' | 'ë' | 'ē' | 'ė' | 'ę' | 'ě' => "e",
+        'Ğ' => "G",
+        'ğ' => "g",
+        'Ì' | 'Í' | 'Î' | 'Ï' | 'Ī' | 'İ' => "I",
+        'ì' | 'í' | 'î' | 'ï' | 'ī' | 'ı' => "i",
+        'Ł' => "L",
+        'ł' => "l",
+        'Ñ' | 'Ń' | 'Ň' => "N",
+        'ñ' | 'ń' | 'ň' => "n",
+        'Ò' | 'Ó' | 'Ô' | 'Õ' | 'Ö' | 'Ø' | 'Ő' => "O",
+        'ò' | 'ó' | 'ô' | 'õ' | 'ö' | 'ø' | 'ő' => "o",
+        'Œ' => "OE",
+        'œ' => "oe",
+        'Ř' => "R",
+        'ř' => "r",
+        'Ś' | 'Š' | 'Ş' => "S",
+        'ś' | 'š' | 'ş' => "s",
+        'ß' => "ss",
+        'Ť' => "T",
+        'ť' => "t",
+        'Þ' => "Th",
+        'þ' => "th",
+        'Ù' | 'Ú' | 'Û' | 'Ü' | 'Ū' | 'Ů' | 'Ű' => "U",
+        'ù' | 'ú' | 'û' | 'ü' | 'ū' | 'ů' | 'ű' => "u",
+        'Ý' | 'Ÿ' => "Y",
+        'ý' | 'ÿ' => "y",
+        'Ź' | 'Ż' | 'Ž' => "Z",
+        'ź' | 'ż' | 'ž' => "z",
+        _ => return None,
+    };
+    Some(ascii)
+}
+
+fn lowercase_first(word: &str) -> String {
+    let mut chars = word.chars();
+    match chars.next() {
+        Some(first) => first.to_lowercase().chain(chars).collect(),
+        None => String::new(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn acronyms() -> Vec<String> {
+        vec!["URL".to_string(), "IO".to_string(), "ID".to_string()]
+    }
+
+    mod split_words {
+        use super::*;
+
+        #[test]
+        fn test_splits_separators() {
+            assert_eq!(
+                CaseConverter::split_words("user-login_event"),
+                vec!["user", "login", "event"]
+            );
+            assert_eq!(CaseConverter::split_words("__private"), vec!["private"]);
+        }
+
+        #[test]
+        fn test_splits_case_transitions() {
+            assert_eq!(CaseConverter::split_words("userName"), vec!["user", "Name"]);
+            assert_eq!(
+                CaseConverter::split_words("HTTPServer"),
+                vec!["HTTP", "Server"]
+            );
+            assert_eq!(CaseConverter::split_words("v2Name"), vec!["v2", "Name"]);
+        }
+
+        #[test]
+        fn test_digits_stay_attached() {
+            assert_eq!(
+                CaseConverter::split_words("get_url_v2"),
+                vec!["get", "url", "v2"]
+            );
+            assert_eq!(CaseConverter::split_words("sha256sum"), vec!["sha256sum"]);
+        }
+    }
+
+    mod non_ascii {
+        use super::*;
+
+        #[test]
+        fn test_transliterates_accented_letters() {
+            let converter = CaseConverter::new(&[]);
+            assert_eq!(converter.to_camel_case("grüße_senden"), "grusseSenden");
+            assert_eq!(converter.to_pascal_case("café"), "Cafe");
+        }
+
+        #[test]
+        fn test_keeps_other_scripts() {
+            let converter = CaseConverter::new(&[]);
+            assert_eq!(converter.to_pascal_case("привет_мир"), "ПриветМир");
+            assert_eq!(converter.to_camel_case("保存"), "保存");
+        }
+
+        #[test]
+        fn test_encodes_symbols_by_code_point() {
+            let converter = CaseConverter::new(&[]);
+            assert_eq!(converter.to_pascal_case("🎉-party"), "U1F389Party");
+            assert_eq!(converter.to_pascal_case("party🎉"), "PartyU1F389");
+            assert!(CaseConverter::has_untranslatable_chars("🎉-party"));
+            assert!(!CaseConverter::has_untranslatable_chars("grüße"));
+        }
+
+        #[test]
+        fn test_identifier_guards_leading_digits() {
+            assert_eq!(
+                CaseConverter::identifier("2faEnabled".to_string()),
+                "_2faEnabled"
+            );
+            assert_eq!(CaseConverter::identifier(String::new()), "_");
+            assert_eq!(CaseConverter::identifier("greet".to_string()), "greet");
+        }
+
+        #[test]
+        fn test_property_key_quotes_invalid_identifiers() {
+            assert_eq!(property_key("userId"), "userId");
+            assert_eq!(property_key("größe"), "größe");
+            assert_eq!(property_key("$ref"), "$ref");
+            assert_eq!(property_key("user-id"), "\"user-id\"");
+            assert_eq!(property_key("2fa"), "\"2fa\"");
+            assert_eq!(property_key("🎉"), "\"🎉\"");
+            assert_eq!(property_key("say \"hi\""), "\"say \\\"hi\\\"\"");
+        }
+    }
+
+    mod without_acronyms {
+        use super::*;
+
+        #[test]
+        fn test_matches_serde_for_snake_case() {
+            let converter = CaseConverter::new(&[]);
+            assert_eq!(converter.to_pascal_case("get_url_v2"), "GetUrlV2");
+            assert_eq!(converter.to_camel_case("get_url_v2"), "getUrlV2");
+            assert_eq!(converter.to_pascal_case("io_error"), "IoError");
+            assert_eq!(converter.to_camel_case("greet"), "greet");
+        }
+
+        #[test]
+        fn test_preserves_existing_casing() {
+            let converter = CaseConverter::new(&[]);
+            assert_eq!(converter.to_pascal_case("userName"), "UserName");
+            assert_eq!(converter.to_pascal_case("HTTPServer"), "HTTPServer");
+            assert_eq!(converter.to_camel_case("HTTPServer"), "httpServer");
+        }
+    }
+
+    mod with_acronyms {
+        use super::*;
+
+        #[test]
+        fn test_pascal_case() {
+            let acronyms = acronyms();
+            let converter = CaseConverter::new(&acronyms);
+            assert_eq!(converter.to_pascal_case("get_url_v2"), "GetURLV2");
+            assert_eq!(converter.to_pascal_case("io_error"), "IOError");
+            assert_eq!(converter.to_pascal_case("user_id"), "UserID");
+        }
+
+        #[test]
+        fn test_camel_case_lowercases_leading_acronym() {
+            let acronyms = acronyms();
+            let converter = CaseConverter::new(&acronyms);
+            assert_eq!(converter.to_camel_case("get_url_v2"), "getURLV2");
+            assert_eq!(converter.to_camel_case("io_error"), "ioError");
+            assert_eq!(converter.to_camel_case("IOError"), "ioError");
+        }
+
+        #[test]
+        fn test_acronyms_match_whole_words_only() {
+            let acronyms = acronyms();
+            let converter = CaseConverter::new(&acronyms);
+            assert_eq!(converter.to_pascal_case("identity"), "Identity");
+            assert_eq!(converter.to_camel_case("curl_idle"), "curlIdle");
+        }
+    }
+}
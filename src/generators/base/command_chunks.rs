@@ -0,0 +1,164 @@
+use crate::models::CommandInfo;
+use std::collections::{BTreeMap, HashSet};
+use std::path::Path;
+
+/// Name of the chunk holding the state shared by all chunks (notify handler, `CommandHooks`)
+pub const SHARED_CHUNK: &str = "shared";
+
+/// Command bindings of one file when they are split (see `max_commands_per_file`)
+#[derive(Clone)]
+pub struct CommandChunk {
+    /// Rust module the chunk is named after, e.g. `user` for `src/commands/user.rs`
+    pub name: String,
+    pub file_name: String,
+    pub commands: Vec<CommandInfo>,
+}
+
+impl CommandChunk {
+    /// Split `commands` into one chunk per Rust module, `None` while they fit in one file.
+    ///
+    /// Chunks are keyed by module, so adding or removing commands in one module never
+    /// moves the commands of another. Modules with more than `max` commands are split in
+    /// source order into numbered chunks (`user`, `user2`, ...).
+    pub fn split(
+        commands: &[CommandInfo],
+        max: Option<usize>,
+        commands_file: &str,
+    ) -> Option<Vec<Self>> {
+        let max = max.filter(|max| *max > 0)?;
+        if commands.len() <= max {
+            return None;
+        }
+
+        let mut modules: BTreeMap<String, Vec<CommandInfo>> = BTreeMap::new();
+        for command in commands {
+            modules
+                .entry(Self::module_name(&command.file_path))
+                .or_default()
+                .push(command.clone());
+        }
+
+        let stem = commands_file.strip_suffix(".ts").unwrap_or(commands_file);
+        let mut taken: HashSet<String> = HashSet::from([SHARED_CHUNK.to_string()]);
+        let mut chunks = Vec::new();
+        for (module, mut module_commands) in modules {
+            module_commands
+                .sort_by(|a, b| (&a.file_path, a.line_number).cmp(&(&b.file_path, b.line_number)));
+            let mut suffix = 1;
+            for part in module_commands.chunks(max) {
+                let name = loop {
+                    let name = match suffix {
+                        1 => module.clone(),
+                        n => format!("{}{}", module, n),
+                    };
+                    suffix += 1;
+                    if taken.insert(name.clone()) {
+                        break name;
+                    }
+                };
+                chunks.push(Self {
+                    file_name: Self::file_name(stem, &name),
+                    name,
+                    commands: part.to_vec(),
+                });
+            }
+        }
+        Some(chunks)
+    }
+
+    /// File of the chunk shared by the chunks of `commands_file`
+    pub fn shared_file_name(commands_file: &str) -> String {
+        let stem = commands_file.strip_suffix(".ts").unwrap_or(commands_file);
+        Self::file_name(stem, SHARED_CHUNK)
+    }
+
+    fn file_name(stem: &str, name: &str) -> String {
+        format!("{}.{}.ts", stem, name)
+    }
+
+    /// Module a command is defined in: the file stem, or the directory of a `mod.rs`
+    pub fn module_name(file_path: &str) -> String {
+        let path = Path::new(file_path);
+        let stem = path
+            .file_stem()
+            .and_then(|s| s.to_str())
+            .unwrap_or_default();
+        let module = match stem {
+            "mod" => path
+                .parent()
+                .and_then(|p| p.file_name())
+                .and_then(|s| s.to_str())
+                .unwrap_or(stem),
+            _ => stem,
+        };
+
+        let module: String = module
+            .chars()
+            .map(|c| if c.is_ascii_alphanumeric() { c } else { '_' })
+            .collect();
+        if module.is_empty() {
+            "commands".to_string()
+        } else {
+            module
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn command(name: &str, file_path: &str, line_number: usize) -> CommandInfo {
+        CommandInfo::new_for_test(name, file_path, line_number, vec![], "()", false, vec![])
+    }
+
+    fn names(chunk: &CommandChunk) -> Vec<&str> {
+        chunk.commands.iter().map(|c| c.name.as_str()).collect()
+    }
+
+    #[test]
+    fn test_no_split_below_threshold() {
+        let commands = vec![
+            command("a", "src/user.rs", 1),
+            command("b", "src/auth.rs", 1),
+        ];
+
+        assert!(CommandChunk::split(&commands, None, "commands.ts").is_none());
+        assert!(CommandChunk::split(&commands, Some(2), "commands.ts").is_none());
+    }
+
+    #[test]
+    fn test_splits_by_module() {
+        let commands = vec![
+            command("get_user", "src/user.rs", 10),
+            command("login", "src/auth/mod.rs", 3),
+            command("save_user", "src/user.rs", 2),
+        ];
+
+        let chunks = CommandChunk::split(&commands, Some(2), "commands.ts").unwrap();
+
+        assert_eq!(chunks.len(), 2);
+        assert_eq!(chunks[0].name, "auth");
+        assert_eq!(chunks[0].file_name, "commands.auth.ts");
+        assert_eq!(chunks[1].file_name, "commands.user.ts");
+        assert_eq!(names(&chunks[1]), vec!["save_user", "get_user"]);
+    }
+
+    #[test]
+    fn test_large_modules_are_numbered() {
+        let commands: Vec<CommandInfo> = (1..=5)
+            .map(|i| command(&format!("cmd{}", i), "src/shared.rs", i))
+            .collect();
+
+        let chunks = CommandChunk::split(&commands, Some(2), "ipc.ts").unwrap();
+
+        let file_names: Vec<&str> = chunks.iter().map(|c| c.file_name.as_str()).collect();
+        // `shared` is reserved for the shared chunk
+        assert_eq!(
+            file_names,
+            vec!["ipc.shared2.ts", "ipc.shared3.ts", "ipc.shared4.ts"]
+        );
+        assert_eq!(names(&chunks[2]), vec!["cmd5"]);
+        assert_eq!(CommandChunk::shared_file_name("ipc.ts"), "ipc.shared.ts");
+    }
+}
@@ -0,0 +1,200 @@
+use crate::analysis::type_resolver::TypeResolver;
+use crate::generators::base::http_bridge::HttpBridge;
+use crate::generators::base::template_context::CommandContext;
+use crate::models::{CommandInfo, StructInfo};
+use serde::Serialize;
+
+/// A type sent as MessagePack by the `Compact<T>` of the generated codec, with its
+/// `encodeX`/`decodeX` pair in the frontend
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CompactType {
+    pub name: String,
+    /// Path of the Rust type, e.g. `crate::models::Grid`
+    pub rust_path: String,
+    /// Schema validating decoded values and values before they are encoded
+    pub schema: Option<String>,
+}
+
+impl CompactType {
+    /// Compact type of `name`, or the warning why it has no codec when the type is
+    /// missing or not defined in the library crate
+    pub fn new(name: &str, struct_info: Option<&StructInfo>) -> Result<Self, String> {
+        let struct_info = struct_info.ok_or_else(|| {
+            format!(
+                "Warning: compact type {} was not found, no codec is generated for it",
+                name
+            )
+        })?;
+        let module = HttpBridge::module_path(&struct_info.file_path).ok_or_else(|| {
+            format!(
+                "Warning: compact type {} at {}:{} is not defined in the library crate, no codec is generated for it",
+                name, struct_info.file_path, struct_info.line_number
+            )
+        })?;
+        Ok(Self {
+            name: name.to_string(),
+            rust_path: format!("{}::{}", module, name),
+            schema: None,
+        })
+    }
+}
+
+/// A command taking or returning `Compact<T>`: the compact parameter is sent as the
+/// raw request body and compact results arrive as raw response bytes
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CompactCommand {
+    pub name: String,
+    /// Serialized name of the compact parameter
+    pub parameter: Option<String>,
+    /// Compact type of the parameter
+    pub parameter_type: Option<String>,
+    /// Compact type of the returned value, also inside a `Result`
+    pub return_type: Option<String>,
+}
+
+impl CompactCommand {
+    /// `None` for commands not using `Compact<T>`, otherwise the compact command or the
+    /// warning why it is called with JSON as usual. Compact types must be listed in
+    /// `compact_types`, and a compact parameter must be the only frontend parameter
+    /// since the raw request body carries no other arguments.
+    pub fn new(
+        command: &CommandInfo,
+        context: &CommandContext,
+        compact_types: &[&str],
+    ) -> Option<Result<Self, String>> {
+        if compact_types.is_empty() {
+            return None;
+        }
+        let compact_parameter = command.parameters.iter().zip(&context.parameters).find_map(
+            |(param, param_context)| {
+                Self::compact_inner(&param.rust_type).map(|inner| (param_context, inner))
+            },
+        );
+        let return_type = Self::compact_inner(&command.return_type)
+            .or_else(|| Self::result_ok_type(&command.return_type).and_then(Self::compact_inner));
+        if compact_parameter.is_none() && return_type.is_none() {
+            return None;
+        }
+
+        let location = context.location();
+        let unlisted = compact_parameter
+            .map(|(_, inner)| inner)
+            .into_iter()
+            .chain(return_type)
+            .find(|inner| !compact_types.contains(&Self::type_name(inner)));
+        if let Some(inner) = unlisted {
+            return Some(Err(format!(
+                "Warning: type {} of command {} at {} is not in compactTypes, the command is called with JSON",
+                inner, command.name, location
+            )));
+        }
+        if let Some((param, _)) = compact_parameter {
+            if context.parameters.len() > 1 || !context.channels.is_empty() {
+                return Some(Err(format!(
+                    "Warning: compact parameter {} of command {} at {} is not its only parameter, the command is called with JSON",
+                    param.name, command.name, location
+                )));
+            }
+        }
+
+        Some(Ok(Self {
+            name: command.name.clone(),
+            parameter: compact_parameter.map(|(param, _)| param.serialized_name.clone()),
+            parameter_type: compact_parameter.map(|(_, inner)| Self::type_name(inner).to_string()),
+            return_type: return_type.map(|inner| Self::type_name(inner).to_string()),
+        }))
+    }
+
+    /// `T` of `Compact<T>`, also behind a module path like `compact::Compact<T>`
+    fn compact_inner(rust_type: &str) -> Option<&str> {
+        let (path, inner) = rust_type.trim().strip_suffix('>')?.split_once('<')?;
+        let name = path.rsplit("::").next().unwrap_or(path).trim();
+        (name == "Compact").then(|| inner.trim())
+    }
+
+    /// `T` of `Result<T, E>`
+    fn result_ok_type(rust_type: &str) -> Option<&str> {
+        let (path, inner) = rust_type.trim().strip_suffix('>')?.split_once('<')?;
+        let name = path.rsplit("::").next().unwrap_or(path).trim();
+        if name != "Result" {
+            return None;
+        }
+        let ok_len = TypeResolver::split_generic_args(inner).first()?.len();
+        Some(inner.trim_start()[..ok_len].trim())
+    }
+
+    /// Name of a type without its module path
+    fn type_name(rust_type: &str) -> &str {
+        rust_type.rsplit("::").next().unwrap_or(rust_type).trim()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::generators::ts::type_visitor::TypeScriptVisitor;
+    use crate::models::{ParameterInfo, TypeStructure};
+    use crate::GenerateConfig;
+
+    fn compact_command(
+        parameters: Vec<(&str, &str)>,
+        return_type: &str,
+    ) -> Option<Result<CompactCommand, String>> {
+        let config = GenerateConfig::default();
+        let parameters = parameters
+            .into_iter()
+            .map(|(name, rust_type)| ParameterInfo {
+                name: name.to_string(),
+                rust_type: rust_type.to_string(),
+                is_optional: false,
+                type_structure: TypeStructure::Custom("Grid".to_string()),
+                serde_rename: None,
+            })
+            .collect();
+        let command = CommandInfo::new_for_test(
+            "save_grid",
+            "src/lib.rs",
+            1,
+            parameters,
+            return_type,
+            true,
+            vec![],
+        );
+        let context = CommandContext::new(&config).from_command_info(
+            &command,
+            &TypeScriptVisitor::with_config(&config),
+            &|_: &str| TypeStructure::default(),
+        );
+        CompactCommand::new(&command, &context, &["Grid"])
+    }
+
+    #[test]
+    fn test_compact_parameters_and_results() {
+        let command = compact_command(
+            vec![("grid_data", "compact::Compact<Grid>")],
+            "Result<Compact<models::Grid>, String>",
+        )
+        .unwrap()
+        .unwrap();
+        assert_eq!(command.parameter.as_deref(), Some("gridData"));
+        assert_eq!(command.parameter_type.as_deref(), Some("Grid"));
+        assert_eq!(command.return_type.as_deref(), Some("Grid"));
+
+        assert!(compact_command(vec![("grid", "Grid")], "()").is_none());
+    }
+
+    #[test]
+    fn test_unsupported_compact_commands_warn() {
+        let unlisted = compact_command(vec![], "Compact<Mesh>")
+            .unwrap()
+            .unwrap_err();
+        assert!(unlisted.contains("not in compactTypes"));
+
+        let shared = compact_command(vec![("grid", "Compact<Grid>"), ("name", "String")], "()")
+            .unwrap()
+            .unwrap_err();
+        assert!(shared.contains("not its only parameter"));
+    }
+}
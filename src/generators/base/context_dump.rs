@@ -0,0 +1,193 @@
+use crate::analysis::unsupported::UnsupportedConstruct;
+use crate::analysis::CommandAnalyzer;
+use crate::generators::base::template_context::{
+    CommandContext, ConstantContext, EventContext, StructContext,
+};
+use crate::generators::base::type_visitor::TypeVisitor;
+use crate::generators::ts::type_visitor::TypeScriptVisitor;
+use crate::generators::valibot::type_visitor::ValibotVisitor;
+use crate::generators::zod::type_visitor::ZodVisitor;
+use crate::generators::{GlobalContext, TypeCollector};
+use crate::models::CommandInfo;
+use crate::GenerateConfig;
+use serde::Serialize;
+use tera::{Context, Tera};
+
+/// The contexts templates are rendered with, built from an analyzed project.
+///
+/// Serialized as JSON this documents every variable available to templates
+/// (`cargo tauri-typegen template-context`); templates can be checked against it
+/// for undefined variables and syntax errors.
+#[derive(Debug, Clone, Serialize)]
+pub struct TemplateContextDump {
+    pub global: GlobalContext,
+    pub commands: Vec<CommandContext>,
+    pub structs: Vec<StructContext>,
+    pub events: Vec<EventContext>,
+    pub constants: Vec<ConstantContext>,
+    /// Types and macros the bindings do not cover, with the reason
+    pub unsupported: Vec<UnsupportedConstruct>,
+}
+
+impl TemplateContextDump {
+    pub fn new(
+        commands: &[CommandInfo],
+        analyzer: &CommandAnalyzer,
+        config: &GenerateConfig,
+    ) -> Self {
+        match config.generator_library() {
+            "zod" => {
+                Self::with_visitor(commands, analyzer, config, &ZodVisitor::with_config(config))
+            }
+            "valibot" => Self::with_visitor(
+                commands,
+                analyzer,
+                config,
+                &ValibotVisitor::with_config(config),
+            ),
+            _ => Self::with_visitor(
+                commands,
+                analyzer,
+                config,
+                &TypeScriptVisitor::with_config(config),
+            ),
+        }
+    }
+
+    fn with_visitor<V: TypeVisitor>(
+        commands: &[CommandInfo],
+        analyzer: &CommandAnalyzer,
+        config: &GenerateConfig,
+        visitor: &V,
+    ) -> Self {
+        let mut collector = TypeCollector::new();
+        collector.known_structs = analyzer.get_discovered_structs().clone();
+        let mut used_structs = collector.collect_used_types(commands, &collector.known_structs);
+
+        // Event payload types are rendered alongside the command types
+        for event in analyzer.get_discovered_events() {
+            let mut event_types = std::collections::HashSet::new();
+            TypeCollector::collect_referenced_types_from_structure(
+                &event.payload_type_structure,
+                &mut event_types,
+            );
+            for type_name in event_types {
+                if let Some(struct_info) = collector.known_structs.get(&type_name) {
+                    used_structs.insert(type_name, struct_info.clone());
+                }
+            }
+        }
+
+        collector.apply_skip_directions(
+            commands,
+            analyzer.get_discovered_events(),
+            &mut used_structs,
+        );
+
+        let mut structs = collector.create_struct_contexts(&used_structs, visitor, config);
+        structs.sort_by(|a, b| a.name.cmp(&b.name));
+
+        Self {
+            global: GlobalContext::new(&config.validation_library),
+            commands: collector.create_command_contexts(commands, visitor, analyzer, config),
+            structs,
+            events: collector.create_event_contexts(
+                analyzer.get_discovered_events(),
+                visitor,
+                analyzer,
+                config,
+            ),
+            constants: collector.create_constant_contexts(analyzer.get_discovered_constants()),
+            unsupported: analyzer.get_unsupported_constructs().to_vec(),
+        }
+    }
+
+    /// Render a template against the dump; `tera` provides the templates and filters
+    /// it may include or use
+    pub fn check_template(&self, tera: &Tera, name: &str, source: &str) -> Result<(), String> {
+        let mut tera = tera.clone();
+        tera.add_raw_template(name, source)
+            .map_err(|e| Self::describe(&e))?;
+        let context = Context::from_serialize(self).map_err(|e| Self::describe(&e))?;
+        tera.render(name, &context)
+            .map(|_| ())
+            .map_err(|e| Self::describe(&e))
+    }
+
+    /// Error with its source chain, which holds the undefined variable or syntax error
+    fn describe(error: &tera::Error) -> String {
+        let mut message = error.to_string();
+        let mut source = std::error::Error::source(error);
+        while let Some(cause) = source {
+            message.push_str(&format!(": {}", cause));
+            source = cause.source();
+        }
+        message
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::{ParameterInfo, TypeStructure};
+
+    fn dump() -> TemplateContextDump {
+        let param = ParameterInfo {
+            name: "user_id".to_string(),
+            rust_type: "u32".to_string(),
+            is_optional: false,
+            type_structure: TypeStructure::Primitive("number".to_string()),
+            serde_rename: None,
+        };
+        let command = CommandInfo::new_for_test(
+            "get_user",
+            "src/commands.rs",
+            3,
+            vec![param],
+            "String",
+            true,
+            vec![],
+        );
+        TemplateContextDump::new(
+            &[command],
+            &CommandAnalyzer::new(),
+            &GenerateConfig::default(),
+        )
+    }
+
+    #[test]
+    fn test_dump_serializes_contexts() {
+        let json = serde_json::to_value(dump()).unwrap();
+
+        assert_eq!(json["global"]["generator_name"], "none");
+        assert_eq!(json["commands"][0]["name"], "get_user");
+        assert_eq!(
+            json["commands"][0]["parameters"][0]["serializedName"],
+            "userId"
+        );
+        assert!(json["structs"].as_array().unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_check_template_reports_undefined_variables() {
+        let tera = Tera::default();
+        let dump = dump();
+
+        assert!(dump
+            .check_template(
+                &tera,
+                "ok.tera",
+                "{% for command in commands %}{{ command.tsFunctionName }}{% endfor %}"
+            )
+            .is_ok());
+
+        let error = dump
+            .check_template(
+                &tera,
+                "bad.tera",
+                "{% for command in commands %}{{ command.functionName }}{% endfor %}",
+            )
+            .unwrap_err();
+        assert!(error.contains("command.functionName"));
+    }
+}
@@ -0,0 +1,245 @@
+use crate::generators::base::template_context::{StructContext, VariantContext};
+use crate::generators::base::type_visitor::TypeVisitor;
+use crate::generators::TypeCollector;
+use crate::models::{CommandInfo, StructInfo};
+use crate::GenerateConfig;
+use serde::Serialize;
+use std::collections::{BTreeSet, HashMap, HashSet};
+
+/// An ES error class hierarchy generated for a Rust error enum returned by commands
+/// as the `E` of `Result<T, E>`
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ErrorClass {
+    pub name: String,
+    /// Tag field of adjacently and internally tagged enums, externally tagged if `None`
+    pub tag: Option<String>,
+    /// Content field of adjacently tagged enums
+    pub content: Option<String>,
+    pub variants: Vec<VariantContext>,
+}
+
+impl ErrorClass {
+    /// Error classes for the project enums commands fail with, sorted by name.
+    ///
+    /// Untagged enums are skipped since their variants cannot be told apart.
+    pub fn collect<V: TypeVisitor>(
+        commands: &[CommandInfo],
+        structs: &HashMap<String, StructInfo>,
+        visitor: &V,
+        config: &GenerateConfig,
+    ) -> Vec<Self> {
+        let names: BTreeSet<&str> = commands
+            .iter()
+            .filter_map(|command| Self::err_type(&command.return_type))
+            .collect();
+
+        names
+            .into_iter()
+            .filter_map(|name| {
+                let struct_info = structs.get(name)?;
+                if !struct_info.is_enum
+                    || struct_info.is_untagged()
+                    || struct_info.fields.is_empty()
+                {
+                    return None;
+                }
+                Some(Self::new(name, struct_info, visitor, config))
+            })
+            .collect()
+    }
+
+    fn new<V: TypeVisitor>(
+        name: &str,
+        struct_info: &StructInfo,
+        visitor: &V,
+        config: &GenerateConfig,
+    ) -> Self {
+        let context = StructContext::new(config).from_struct_info(name, struct_info, visitor);
+        let variants = struct_info
+            .fields
+            .iter()
+            .zip(&context.fields)
+            .map(|(variant, field)| {
+                context.variant_context(variant, &field.serialized_name, visitor)
+            })
+            .collect();
+
+        Self {
+            name: name.to_string(),
+            tag: struct_info.serde_tag.clone(),
+            content: struct_info
+                .serde_tag
+                .as_ref()
+                .and(struct_info.serde_content.clone()),
+            variants,
+        }
+    }
+
+    /// Project types referenced by the variant payloads of `classes`, which the
+    /// error classes import; the error enums themselves are the generated classes
+    pub fn payload_types(classes: &[Self], structs: &HashMap<String, StructInfo>) -> Vec<String> {
+        let class_names: HashSet<&str> = classes.iter().map(|class| class.name.as_str()).collect();
+        let mut types = HashSet::new();
+        for class in classes {
+            let Some(struct_info) = structs.get(&class.name) else {
+                continue;
+            };
+            for variant in &struct_info.fields {
+                TypeCollector::collect_referenced_types_from_structure(
+                    &variant.type_structure,
+                    &mut types,
+                );
+                for field in &variant.variant_fields {
+                    TypeCollector::collect_referenced_types_from_structure(
+                        &field.type_structure,
+                        &mut types,
+                    );
+                }
+            }
+        }
+
+        let mut types: Vec<String> = types
+            .into_iter()
+            .filter(|name| structs.contains_key(name) && !class_names.contains(name.as_str()))
+            .collect();
+        types.sort();
+        types
+    }
+
+    /// Type name of `E` in `Result<T, E>` without its module path, `None` for other types
+    pub(crate) fn err_type(rust_type: &str) -> Option<&str> {
+        let inner = rust_type.strip_prefix("Result<")?.strip_suffix('>')?;
+        let mut depth = 0;
+        for (i, c) in inner.char_indices() {
+            match c {
+                '<' | '(' | '[' => depth += 1,
+                '>' | ')' | ']' => depth -= 1,
+                ',' if depth == 0 => {
+                    let err_type = inner[i + 1..].trim();
+                    return Some(err_type.rsplit("::").next().unwrap_or(err_type).trim());
+                }
+                _ => {}
+            }
+        }
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::generators::ts::type_visitor::TypeScriptVisitor;
+    use crate::models::{FieldInfo, TypeStructure};
+
+    fn variant(name: &str, rust_type: &str, type_structure: TypeStructure) -> FieldInfo {
+        FieldInfo {
+            name: name.to_string(),
+            rust_type: rust_type.to_string(),
+            is_optional: false,
+            is_public: true,
+            validator_attributes: None,
+            serde_rename: None,
+            type_structure,
+            variant_fields: vec![],
+            literal: None,
+            flatten: false,
+            omittable: false,
+            defaulted: false,
+            skip_serializing_if: false,
+            skipped: None,
+            serde_with: None,
+        }
+    }
+
+    fn error_enum(name: &str, tag: Option<&str>, untagged: bool) -> StructInfo {
+        StructInfo {
+            name: name.to_string(),
+            fields: vec![
+                variant("NotFound", "enum_variant", TypeStructure::default()),
+                variant(
+                    "Invalid",
+                    "enum_variant_tuple",
+                    TypeStructure::Custom("Field".to_string()),
+                ),
+            ],
+            file_path: "src/error.rs".to_string(),
+            is_enum: true,
+            serde_rename_all: None,
+            is_newtype: false,
+            serde_tag: tag.map(String::from),
+            serde_content: None,
+            serde_untagged: untagged,
+            line_number: 0,
+            is_progress: false,
+            serde_deny_unknown_fields: false,
+        }
+    }
+
+    fn command(return_type: &str) -> CommandInfo {
+        CommandInfo::new_for_test("save", "src/lib.rs", 1, vec![], return_type, true, vec![])
+    }
+
+    fn collect(commands: &[CommandInfo], structs: &HashMap<String, StructInfo>) -> Vec<ErrorClass> {
+        let config = GenerateConfig::default();
+        ErrorClass::collect(
+            commands,
+            structs,
+            &TypeScriptVisitor::with_config(&config),
+            &config,
+        )
+    }
+
+    #[test]
+    fn test_collects_error_enums_of_commands() {
+        let mut structs = HashMap::new();
+        structs.insert("AppError".to_string(), error_enum("AppError", None, false));
+        structs.insert(
+            "DbError".to_string(),
+            error_enum("DbError", Some("type"), false),
+        );
+
+        let errors = collect(
+            &[
+                command("Result<(), AppError>"),
+                command("Result<String, crate::DbError>"),
+                command("Result<String, String>"),
+                command("AppError"),
+            ],
+            &structs,
+        );
+
+        assert_eq!(errors.len(), 2);
+        assert_eq!(errors[0].name, "AppError");
+        assert_eq!(errors[0].tag, None);
+        assert_eq!(errors[0].variants[0].payload_type, None);
+        assert_eq!(errors[0].variants[1].payload_type.as_deref(), Some("Field"));
+        assert_eq!(errors[1].tag.as_deref(), Some("type"));
+        assert_eq!(
+            ErrorClass::payload_types(&errors, &structs),
+            Vec::<String>::new()
+        );
+    }
+
+    #[test]
+    fn test_skips_untagged_enums() {
+        let mut structs = HashMap::new();
+        structs.insert("AppError".to_string(), error_enum("AppError", None, true));
+
+        assert!(collect(&[command("Result<(), AppError>")], &structs).is_empty());
+    }
+
+    #[test]
+    fn test_err_type() {
+        assert_eq!(
+            ErrorClass::err_type("Result<Vec<u8>, AppError>"),
+            Some("AppError")
+        );
+        assert_eq!(
+            ErrorClass::err_type("Result<HashMap<String, u8>, crate::error::AppError>"),
+            Some("AppError")
+        );
+        assert_eq!(ErrorClass::err_type("Result<String>"), None);
+        assert_eq!(ErrorClass::err_type("Vec<u8>"), None);
+    }
+}
@@ -0,0 +1,617 @@
+use crate::analysis::type_resolver::JSON_VALUE_TYPE;
+use crate::generators::base::casing::property_key;
+use crate::generators::base::template_context::{CommandContext, NamingContext};
+use crate::models::{CommandInfo, FieldInfo, StructInfo};
+use crate::{GenerateConfig, TypeStructure};
+use std::collections::{HashMap, HashSet};
+
+/// Synthesizes example invocations of generated command functions for their JSDoc
+/// (`const result = await getUser({ id: 1 });`).
+///
+/// Values are derived from the parameter type structures: required fields and
+/// parameters get placeholder values, optional ones are left out to keep the
+/// example short. The same values, with empty strings, zeros and empty collections
+/// instead of placeholders, serve as form default values.
+pub struct ExampleBuilder<'a> {
+    structs: &'a HashMap<String, StructInfo>,
+    config: &'a GenerateConfig,
+    /// Synthesize empty values (`''`, `0`, `[]`) instead of placeholders
+    empty: bool,
+    /// Synthesize no object missing a required field, e.g. a recursive one
+    complete: bool,
+}
+
+impl NamingContext for ExampleBuilder<'_> {
+    fn config(&self) -> &GenerateConfig {
+        self.config
+    }
+}
+
+impl<'a> ExampleBuilder<'a> {
+    pub fn new(structs: &'a HashMap<String, StructInfo>, config: &'a GenerateConfig) -> Self {
+        Self {
+            structs,
+            config,
+            empty: false,
+            complete: false,
+        }
+    }
+
+    /// Builder synthesizing empty values, used for form default values
+    pub fn empty(structs: &'a HashMap<String, StructInfo>, config: &'a GenerateConfig) -> Self {
+        Self {
+            empty: true,
+            ..Self::new(structs, config)
+        }
+    }
+
+    /// Builder synthesizing only values of the complete type, used for mock results
+    pub fn complete(structs: &'a HashMap<String, StructInfo>, config: &'a GenerateConfig) -> Self {
+        Self {
+            complete: true,
+            ..Self::new(structs, config)
+        }
+    }
+
+    /// Object literal with a value for every required parameter of a command
+    /// (channels excluded)
+    pub fn parameters(&self, command: &CommandContext) -> String {
+        let members: Vec<String> = command
+            .parameters
+            .iter()
+            .filter(|param| !param.is_optional)
+            .filter_map(|param| {
+                let value = self.value(&param.type_structure, &mut HashSet::new())?;
+                Some(format!(
+                    "{}: {}",
+                    property_key(&param.serialized_name),
+                    value
+                ))
+            })
+            .collect();
+
+        if members.is_empty() {
+            "{}".to_string()
+        } else {
+            format!("{{ {} }}", members.join(", "))
+        }
+    }
+
+    /// Example call of the generated function for a command
+    pub fn invocation(&self, command: &CommandContext) -> String {
+        let mut args: Vec<String> = command
+            .parameters
+            .iter()
+            .filter(|param| !param.is_optional)
+            .map(|param| {
+                let value = self
+                    .value(&param.type_structure, &mut HashSet::new())
+                    .unwrap_or_else(|| "{}".to_string());
+                format!("{}: {}", property_key(&param.serialized_name), value)
+            })
+            .collect();
+        args.extend(command.channels.iter().map(|channel| {
+            format!(
+                "{}: new Channel()",
+                property_key(&channel.serialized_parameter_name)
+            )
+        }));
+
+        let call = if command.parameters.is_empty() && command.channels.is_empty() {
+            format!("{}()", command.ts_function_name)
+        } else if args.is_empty() {
+            format!("{}({{}})", command.ts_function_name)
+        } else {
+            format!("{}({{ {} }})", command.ts_function_name, args.join(", "))
+        };
+
+        if command.return_type_ts == "void" {
+            format!("await {};", call)
+        } else {
+            format!("const result = await {};", call)
+        }
+    }
+
+    /// Example value of a type, `None` when no value can be synthesized
+    pub fn type_value(&self, type_name: &str) -> Option<String> {
+        self.custom_value(type_name, &mut HashSet::new())
+    }
+
+    /// Example value returned by a command, `None` when no value can be synthesized
+    pub fn result(&self, command: &CommandInfo) -> Option<String> {
+        self.value(&command.return_type_structure, &mut HashSet::new())
+    }
+
+    /// Example TypeScript literal for a type, `None` when no value can be synthesized
+    /// (unknown or recursive types)
+    fn value(&self, structure: &TypeStructure, visiting: &mut HashSet<String>) -> Option<String> {
+        match structure {
+            TypeStructure::Primitive(primitive) => self.primitive_value(primitive),
+            TypeStructure::Array(_) | TypeStructure::Set(_) if self.empty => Some("[]".to_string()),
+            TypeStructure::Map { .. } if self.empty => Some("{}".to_string()),
+            TypeStructure::Array(inner) | TypeStructure::Set(inner) => Some(
+                self.value(inner, visiting)
+                    .map_or_else(|| "[]".to_string(), |value| format!("[{}]", value)),
+            ),
+            TypeStructure::Map { key, value } => {
+                let key = match key.as_ref() {
+                    TypeStructure::Primitive(primitive) if primitive == "number" => "1".to_string(),
+                    _ => "key".to_string(),
+                };
+                Some(self.value(value, visiting).map_or_else(
+                    || "{}".to_string(),
+                    |value| format!("{{ {}: {} }}", key, value),
+                ))
+            }
+            TypeStructure::Tuple(types) => types
+                .iter()
+                .map(|inner| self.value(inner, visiting))
+                .collect::<Option<Vec<_>>>()
+                .map(|values| format!("[{}]", values.join(", "))),
+            TypeStructure::Optional(inner) | TypeStructure::Result(inner) => {
+                self.value(inner, visiting)
+            }
+            TypeStructure::Union(types) => {
+                types.first().and_then(|inner| self.value(inner, visiting))
+            }
+            TypeStructure::Custom(name) => self.custom_value(name, visiting),
+        }
+    }
+
+    fn primitive_value(&self, primitive: &str) -> Option<String> {
+        let value = match (primitive, self.empty) {
+            ("string", false) => "'example'",
+            ("string", true) => "''",
+            ("number", false) => "1",
+            ("number", true) => "0",
+            ("boolean", false) => "true",
+            ("boolean", true) => "false",
+            ("void", _) => "null",
+            _ => return None,
+        };
+        Some(value.to_string())
+    }
+
+    fn custom_value(&self, name: &str, visiting: &mut HashSet<String>) -> Option<String> {
+        if name == JSON_VALUE_TYPE {
+            return Some("{}".to_string());
+        }
+        // Mapped types are only known by their TypeScript type
+        if let Some(ts_type) = self
+            .config
+            .type_mappings
+            .as_ref()
+            .and_then(|mappings| mappings.get(name))
+        {
+            return self.primitive_value(ts_type);
+        }
+
+        let struct_info = self.structs.get(name)?;
+        if !visiting.insert(name.to_string()) {
+            return None;
+        }
+        let value = if struct_info.is_enum {
+            self.enum_value(struct_info, visiting)
+        } else if struct_info.is_newtype {
+            struct_info
+                .fields
+                .first()
+                .and_then(|field| self.value(&field.type_structure, visiting))
+        } else {
+            self.object_value(&struct_info.fields, &struct_info.serde_rename_all, visiting)
+        };
+        visiting.remove(name);
+        value
+    }
+
+    /// Object literal with the required fields of a struct or struct variant, `None` when
+    /// synthesizing complete values and a required field has no value
+    fn object_value(
+        &self,
+        fields: &[FieldInfo],
+        rename_all: &Option<serde_rename_rule::RenameRule>,
+        visiting: &mut HashSet<String>,
+    ) -> Option<String> {
+        let mut members = Vec::new();
+        for field in fields.iter().filter(|field| {
+            !field.is_optional && (field.is_public || self.config.should_include_private())
+        }) {
+            let value = match field.literal {
+                Some(ref literal) => Some(format!(
+                    "'{}'",
+                    literal.replace('\\', "\\\\").replace('\'', "\\'")
+                )),
+                None => self.value(&field.type_structure, visiting),
+            };
+            let Some(value) = value else {
+                if self.complete {
+                    return None;
+                }
+                continue;
+            };
+            if field.flatten {
+                members.push(format!("...{}", value));
+                continue;
+            }
+            let key = self.compute_field_name(&field.name, &field.serde_rename, rename_all);
+            members.push(format!("{}: {}", property_key(&key), value));
+        }
+
+        if members.is_empty() {
+            Some("{}".to_string())
+        } else {
+            Some(format!("{{ {} }}", members.join(", ")))
+        }
+    }
+
+    /// Value of the first variant of an enum, in the enum's serde representation
+    fn enum_value(&self, enum_info: &StructInfo, visiting: &mut HashSet<String>) -> Option<String> {
+        let variant = enum_info.fields.first()?;
+        let name = self.compute_variant_name(
+            &variant.name,
+            &variant.serde_rename,
+            &enum_info.serde_rename_all,
+        );
+        let payload = match variant.rust_type.as_str() {
+            "enum_variant_tuple" => Some(self.value(&variant.type_structure, visiting)?),
+            "enum_variant_struct" => {
+                Some(self.object_value(&variant.variant_fields, &None, visiting)?)
+            }
+            _ => None,
+        };
+
+        // Untagged variants serialize as their payload alone, unit variants as `null`
+        if enum_info.is_untagged() {
+            return Some(payload.unwrap_or_else(|| "null".to_string()));
+        }
+
+        let name_literal = format!("'{}'", name.replace('\\', "\\\\").replace('\'', "\\'"));
+        Some(
+            match (&enum_info.serde_tag, &enum_info.serde_content, payload) {
+                (Some(tag), Some(content), Some(payload)) => format!(
+                    "{{ {}: {}, {}: {} }}",
+                    property_key(tag),
+                    name_literal,
+                    property_key(content),
+                    payload
+                ),
+                // Internally tagged payloads are objects whose entries sit next to the tag
+                (Some(tag), None, Some(payload)) => format!(
+                    "{{ {}: {}, ...{} }}",
+                    property_key(tag),
+                    name_literal,
+                    payload
+                ),
+                (Some(tag), _, _) => format!("{{ {}: {} }}", property_key(tag), name_literal),
+                (None, _, Some(payload)) => format!("{{ {}: {} }}", property_key(&name), payload),
+                (None, _, None) => name_literal,
+            },
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::generators::base::template_context::CommandContext;
+    use crate::generators::ts::type_visitor::TypeScriptVisitor;
+    use crate::models::{ChannelInfo, CommandInfo, ParameterInfo};
+
+    fn field(name: &str, type_structure: TypeStructure, is_optional: bool) -> FieldInfo {
+        FieldInfo {
+            name: name.to_string(),
+            rust_type: String::new(),
+            is_optional,
+            is_public: true,
+            validator_attributes: None,
+            serde_rename: None,
+            type_structure,
+            variant_fields: vec![],
+            literal: None,
+            flatten: false,
+            omittable: false,
+            defaulted: false,
+            skip_serializing_if: false,
+            skipped: None,
+            serde_with: None,
+        }
+    }
+
+    fn variant(name: &str, kind: &str, type_structure: TypeStructure) -> FieldInfo {
+        FieldInfo {
+            rust_type: kind.to_string(),
+            ..field(name, type_structure, false)
+        }
+    }
+
+    fn type_info(name: &str, fields: Vec<FieldInfo>, is_enum: bool) -> StructInfo {
+        StructInfo {
+            name: name.to_string(),
+            fields,
+            file_path: "models.rs".to_string(),
+            is_enum,
+            serde_rename_all: None,
+            is_newtype: false,
+            serde_tag: None,
+            serde_content: None,
+            serde_untagged: false,
+            line_number: 0,
+            is_progress: false,
+            serde_deny_unknown_fields: false,
+        }
+    }
+
+    fn string() -> TypeStructure {
+        TypeStructure::Primitive("string".to_string())
+    }
+
+    fn custom(name: &str) -> TypeStructure {
+        TypeStructure::Custom(name.to_string())
+    }
+
+    fn structs() -> HashMap<String, StructInfo> {
+        let user = type_info(
+            "User",
+            vec![
+                field(
+                    "user_id",
+                    TypeStructure::Primitive("number".to_string()),
+                    false,
+                ),
+                field("display_name", string(), false),
+                field("bio", TypeStructure::Optional(Box::new(string())), true),
+                field("role", custom("Role"), false),
+                field(
+                    "friends",
+                    TypeStructure::Array(Box::new(custom("User"))),
+                    false,
+                ),
+            ],
+            false,
+        );
+        let role = type_info(
+            "Role",
+            vec![
+                variant("Admin", "enum_variant", custom("enum_variant")),
+                variant("Member", "enum_variant", custom("enum_variant")),
+            ],
+            true,
+        );
+        HashMap::from([("User".to_string(), user), ("Role".to_string(), role)])
+    }
+
+    fn example(structure: &TypeStructure, structs: &HashMap<String, StructInfo>) -> Option<String> {
+        let config = GenerateConfig::default();
+        ExampleBuilder::new(structs, &config).value(structure, &mut HashSet::new())
+    }
+
+    #[test]
+    fn test_primitive_and_collection_values() {
+        let structs = HashMap::new();
+        assert_eq!(example(&string(), &structs).unwrap(), "'example'");
+        assert_eq!(
+            example(
+                &TypeStructure::Array(Box::new(TypeStructure::Primitive("number".to_string()))),
+                &structs
+            )
+            .unwrap(),
+            "[1]"
+        );
+        assert_eq!(
+            example(
+                &TypeStructure::Map {
+                    key: Box::new(string()),
+                    value: Box::new(TypeStructure::Primitive("boolean".to_string())),
+                },
+                &structs
+            )
+            .unwrap(),
+            "{ key: true }"
+        );
+        assert_eq!(
+            example(&TypeStructure::Tuple(vec![string(), string()]), &structs).unwrap(),
+            "['example', 'example']"
+        );
+        assert_eq!(example(&custom("Unknown"), &structs), None);
+    }
+
+    #[test]
+    fn test_struct_values_use_serialized_names_and_skip_optional_fields() {
+        // Recursive references are cut off instead of expanding forever
+        assert_eq!(
+            example(&custom("User"), &structs()).unwrap(),
+            "{ user_id: 1, display_name: 'example', role: 'Admin', friends: [] }"
+        );
+    }
+
+    #[test]
+    fn test_enum_representations() {
+        let mut tagged = type_info(
+            "Shape",
+            vec![variant(
+                "Circle",
+                "enum_variant_tuple",
+                TypeStructure::Primitive("number".to_string()),
+            )],
+            true,
+        );
+        let external = HashMap::from([("Shape".to_string(), tagged.clone())]);
+        assert_eq!(
+            example(&custom("Shape"), &external).unwrap(),
+            "{ Circle: 1 }"
+        );
+
+        tagged.serde_tag = Some("type".to_string());
+        tagged.serde_content = Some("data".to_string());
+        let adjacent = HashMap::from([("Shape".to_string(), tagged.clone())]);
+        assert_eq!(
+            example(&custom("Shape"), &adjacent).unwrap(),
+            "{ type: 'Circle', data: 1 }"
+        );
+
+        tagged.serde_content = None;
+        tagged.fields[0].rust_type = "enum_variant_struct".to_string();
+        tagged.fields[0].variant_fields = vec![field(
+            "radius",
+            TypeStructure::Primitive("number".to_string()),
+            false,
+        )];
+        let internal = HashMap::from([("Shape".to_string(), tagged.clone())]);
+        assert_eq!(
+            example(&custom("Shape"), &internal).unwrap(),
+            "{ type: 'Circle', ...{ radius: 1 } }"
+        );
+
+        tagged.serde_tag = None;
+        tagged.fields[0].rust_type = "enum_variant_tuple".to_string();
+        tagged.serde_untagged = true;
+        let untagged = HashMap::from([("Shape".to_string(), tagged)]);
+        assert_eq!(example(&custom("Shape"), &untagged).unwrap(), "1");
+    }
+
+    #[test]
+    fn test_mapped_types_use_their_typescript_type() {
+        let config = GenerateConfig {
+            type_mappings: Some(HashMap::from([(
+                "DateTime".to_string(),
+                "string".to_string(),
+            )])),
+            ..Default::default()
+        };
+        let structs = HashMap::new();
+        let builder = ExampleBuilder::new(&structs, &config);
+        assert_eq!(
+            builder.value(&custom("DateTime"), &mut HashSet::new()),
+            Some("'example'".to_string())
+        );
+    }
+
+    #[test]
+    fn test_empty_values() {
+        let config = GenerateConfig::default();
+        let structs = structs();
+        let builder = ExampleBuilder::empty(&structs, &config);
+        assert_eq!(
+            builder.value(&custom("User"), &mut HashSet::new()).unwrap(),
+            "{ user_id: 0, display_name: '', role: 'Admin', friends: [] }"
+        );
+        assert_eq!(
+            builder
+                .value(
+                    &TypeStructure::Map {
+                        key: Box::new(string()),
+                        value: Box::new(string()),
+                    },
+                    &mut HashSet::new()
+                )
+                .unwrap(),
+            "{}"
+        );
+        assert_eq!(
+            builder.value(
+                &TypeStructure::Primitive("boolean".to_string()),
+                &mut HashSet::new()
+            ),
+            Some("false".to_string())
+        );
+    }
+
+    #[test]
+    fn test_complete_values_skip_recursive_types() {
+        let config = GenerateConfig::default();
+        let mut structs = structs();
+        structs.insert(
+            "Node".to_string(),
+            type_info(
+                "Node",
+                vec![
+                    field("label", string(), false),
+                    field("parent", custom("Node"), false),
+                ],
+                false,
+            ),
+        );
+
+        let partial = ExampleBuilder::new(&structs, &config);
+        assert_eq!(
+            partial.value(&custom("Node"), &mut HashSet::new()).unwrap(),
+            "{ label: 'example' }"
+        );
+        let complete = ExampleBuilder::complete(&structs, &config);
+        assert_eq!(complete.value(&custom("Node"), &mut HashSet::new()), None);
+        // Recursive collections stay empty
+        assert_eq!(
+            complete
+                .value(&custom("User"), &mut HashSet::new())
+                .unwrap(),
+            "{ user_id: 1, display_name: 'example', role: 'Admin', friends: [] }"
+        );
+    }
+
+    #[test]
+    fn test_invocation() {
+        let config = GenerateConfig::default();
+        let structs = structs();
+        let builder = ExampleBuilder::new(&structs, &config);
+        let visitor = TypeScriptVisitor::with_config(&config);
+        let resolver = |_: &str| string();
+        let param = |name: &str, rust_type: &str, type_structure: TypeStructure| ParameterInfo {
+            name: name.to_string(),
+            rust_type: rust_type.to_string(),
+            is_optional: rust_type.starts_with("Option"),
+            type_structure,
+            serde_rename: None,
+        };
+        let context =
+            |params: Vec<ParameterInfo>, return_type: &str, channels: Vec<ChannelInfo>| {
+                let cmd = CommandInfo::new_for_test(
+                    "get_role",
+                    "lib.rs",
+                    1,
+                    params,
+                    return_type,
+                    true,
+                    channels,
+                );
+                CommandContext::new(&config).from_command_info(&cmd, &visitor, &resolver)
+            };
+
+        assert_eq!(
+            builder.invocation(&context(vec![], "Role", vec![])),
+            "const result = await getRole();"
+        );
+        assert_eq!(
+            builder.invocation(&context(
+                vec![
+                    param(
+                        "role_id",
+                        "u32",
+                        TypeStructure::Primitive("number".to_string())
+                    ),
+                    param(
+                        "filter",
+                        "Option<String>",
+                        TypeStructure::Optional(Box::new(string()))
+                    ),
+                ],
+                "()",
+                vec![]
+            )),
+            "await getRole({ roleId: 1 });"
+        );
+        assert_eq!(
+            builder.invocation(&context(
+                vec![],
+                "()",
+                vec![ChannelInfo::new_for_test(
+                    "on_progress",
+                    "u32",
+                    "get_role",
+                    "lib.rs",
+                    1
+                )]
+            )),
+            "await getRole({ onProgress: new Channel() });"
+        );
+    }
+}
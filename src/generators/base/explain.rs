@@ -0,0 +1,656 @@
+use crate::analysis::CommandAnalyzer;
+use crate::generators::base::file_writer::{FileWriter, GeneratedFile};
+use crate::generators::base::template_context::FieldContext;
+use crate::generators::ts::type_visitor::TypeScriptVisitor;
+use crate::generators::{create_generator, TypeCollector};
+use crate::interface::config::GenerateConfig;
+use crate::interface::in_memory_config;
+use crate::models::{CommandInfo, StructInfo, ValidatorAttributes};
+
+/// Lines of a generated file declaring an explained symbol
+#[derive(Debug, Clone, PartialEq)]
+pub struct GeneratedLines {
+    pub file: String,
+    pub lines: Vec<String>,
+}
+
+/// How a generated symbol came out the way it did, from the Rust item through serde
+/// and validator attributes to the template and the generated code
+/// (`cargo tauri-typegen explain types.User.created_at`)
+#[derive(Debug, Clone, PartialEq)]
+pub struct Explanation {
+    pub symbol: String,
+    /// The Rust item, e.g. `User.created_at: DateTime<Utc>`
+    pub rust: String,
+    /// Rust source file and line of the item
+    pub location: String,
+    /// Serde attributes applied and the resulting serialized name
+    pub serde: Vec<String>,
+    /// Validator constraints, as checked by the schemas
+    pub validation: Vec<String>,
+    /// Analyzed type of a field, parameter or return value
+    pub type_structure: Option<String>,
+    /// TypeScript type of a field, parameter or return value
+    pub typescript_type: Option<String>,
+    /// Template rendering the symbol
+    pub template: String,
+    pub output: Vec<GeneratedLines>,
+}
+
+impl Explanation {
+    /// Explanation of `symbol`: `<types file>.<Type>[.<field or variant>]` or
+    /// `<commands file>.<command>[.<parameter>]`, files named without extension.
+    /// Commands and parameters are found by their Rust or generated name.
+    pub fn of(
+        symbol: &str,
+        commands: &[CommandInfo],
+        analyzer: &CommandAnalyzer,
+        config: &GenerateConfig,
+    ) -> Result<Self, String> {
+        if config.target() != "typescript" {
+            return Err(format!(
+                "explain traces TypeScript bindings, not the {} target",
+                config.target()
+            ));
+        }
+        let names = config.file_names();
+        let types_file = Self::file_stem(&names.types);
+        let commands_file = Self::file_stem(&names.commands);
+        let parts: Vec<&str> = symbol.split('.').collect();
+        let (file, item, member) = match parts.as_slice() {
+            [file, item] => (*file, *item, None),
+            [file, item, member] => (*file, *item, Some(*member)),
+            _ => {
+                return Err(format!(
+                    "Invalid symbol {}, use {}.<Type>[.<field>] or {}.<command>[.<parameter>]",
+                    symbol, types_file, commands_file
+                ))
+            }
+        };
+
+        if file != types_file && file != commands_file {
+            return Err(format!(
+                "Unknown file {} in {}, explain symbols of {} or {}",
+                file, symbol, types_file, commands_file
+            ));
+        }
+
+        let files = Self::generate(commands, analyzer, config)?;
+        let mut explanation = if file == types_file {
+            let struct_info = analyzer
+                .get_discovered_structs()
+                .get(item)
+                .ok_or_else(|| format!("No struct or enum named '{}' was found", item))?;
+            Self::of_type(struct_info, member, &files, config)?
+        } else {
+            Self::of_command(item, member, commands, analyzer, &files, config)?
+        };
+        explanation.symbol = symbol.to_string();
+        Ok(explanation)
+    }
+
+    fn of_type(
+        struct_info: &StructInfo,
+        member: Option<&str>,
+        files: &[GeneratedFile],
+        config: &GenerateConfig,
+    ) -> Result<Self, String> {
+        let name = &struct_info.name;
+        let mut serde = Vec::new();
+        if let Some(rule) = struct_info.serde_rename_all {
+            serde.push(format!(
+                "rename_all = \"{}\" on {}",
+                rule.to_rename_all_str(),
+                name
+            ));
+        }
+
+        let mut explanation = Self {
+            symbol: String::new(),
+            rust: format!(
+                "{} {}",
+                if struct_info.is_enum {
+                    "enum"
+                } else {
+                    "struct"
+                },
+                name
+            ),
+            location: format!("{}:{}", struct_info.file_path, struct_info.line_number),
+            serde,
+            validation: Vec::new(),
+            type_structure: None,
+            typescript_type: None,
+            template: Self::type_template(struct_info, config.generator_library()),
+            output: Vec::new(),
+        };
+        let declarations = [name.clone(), format!("{}Schema", name)];
+
+        let Some(member) = member else {
+            let serde = &mut explanation.serde;
+            if let Some(ref tag) = struct_info.serde_tag {
+                serde.push(format!("tag = \"{}\"", tag));
+            }
+            if let Some(ref content) = struct_info.serde_content {
+                serde.push(format!("content = \"{}\"", content));
+            }
+            if struct_info.serde_untagged {
+                serde.push("untagged".to_string());
+            }
+            if struct_info.serde_deny_unknown_fields {
+                serde.push("deny_unknown_fields".to_string());
+            }
+            explanation.output = Self::generated(files, &declarations, None);
+            return Ok(explanation);
+        };
+
+        let visitor = TypeScriptVisitor::with_config(config);
+        let contexts = TypeCollector::new().create_field_contexts(struct_info, &visitor, config);
+        let (field, context) = struct_info
+            .fields
+            .iter()
+            .zip(&contexts)
+            .find(|(field, context)| field.name == member || context.serialized_name == member)
+            .ok_or_else(|| {
+                format!(
+                    "{} has no field or variant {} (fields with #[serde(skip)] and zero-sized fields are left out)",
+                    name, member
+                )
+            })?;
+
+        explanation.rust = if struct_info.is_enum {
+            format!("{}::{}", name, field.name)
+        } else {
+            format!("{}.{}: {}", name, field.name, field.rust_type)
+        };
+        if let Some(ref rename) = field.serde_rename {
+            explanation
+                .serde
+                .push(format!("rename = \"{}\" on {}", rename, field.name));
+        }
+        explanation
+            .serde
+            .push(format!("serialized as {}", context.serialized_name));
+        explanation.validation = Self::constraints(&field.validator_attributes);
+        if let Some(ref literal) = field.literal {
+            explanation
+                .validation
+                .push(format!("#[typegen(literal = \"{}\")]", literal));
+        }
+        if !struct_info.is_enum {
+            explanation.type_structure = Some(format!("{:?}", field.type_structure));
+            explanation.typescript_type = Some(Self::typescript_type(context, name, config));
+        }
+        explanation.output = Self::generated(files, &declarations, Some(&context.serialized_name));
+        Ok(explanation)
+    }
+
+    fn of_command(
+        item: &str,
+        member: Option<&str>,
+        commands: &[CommandInfo],
+        analyzer: &CommandAnalyzer,
+        files: &[GeneratedFile],
+        config: &GenerateConfig,
+    ) -> Result<Self, String> {
+        let visitor = TypeScriptVisitor::with_config(config);
+        let contexts =
+            TypeCollector::new().create_command_contexts(commands, &visitor, analyzer, config);
+        let (command, context) = commands
+            .iter()
+            .zip(&contexts)
+            .find(|(command, context)| command.name == item || context.ts_function_name == item)
+            .ok_or_else(|| format!("No command named '{}' was found", item))?;
+
+        let parameters: Vec<String> = command
+            .parameters
+            .iter()
+            .map(|param| format!("{}: {}", param.name, param.rust_type))
+            .collect();
+        let library = Self::template_library(config.generator_library());
+        let mut explanation = Self {
+            symbol: String::new(),
+            rust: format!(
+                "{}fn {}({}) -> {}",
+                if command.is_async { "async " } else { "" },
+                command.name,
+                parameters.join(", "),
+                command.return_type
+            ),
+            location: format!("{}:{}", command.file_path, command.line_number),
+            serde: Vec::new(),
+            validation: Vec::new(),
+            type_structure: Some(format!("{:?}", command.return_type_structure)),
+            typescript_type: Some(context.return_type_ts.clone()),
+            template: format!("{}/partials/command_function.ts.tera", library),
+            output: Vec::new(),
+        };
+        if let Some(rule) = command.serde_rename_all {
+            explanation.serde.push(format!(
+                "rename_all = \"{}\" on {}",
+                rule.to_rename_all_str(),
+                command.name
+            ));
+        }
+
+        let Some(member) = member else {
+            explanation
+                .serde
+                .push(format!("generated as {}", context.ts_function_name));
+            explanation.output =
+                Self::generated(files, std::slice::from_ref(&context.ts_function_name), None);
+            return Ok(explanation);
+        };
+
+        let (param, param_context) = command
+            .parameters
+            .iter()
+            .zip(&context.parameters)
+            .find(|(param, param_context)| {
+                param.name == member || param_context.serialized_name == member
+            })
+            .ok_or_else(|| {
+                format!(
+                    "Command {} has no parameter {} sent by the frontend",
+                    command.name, member
+                )
+            })?;
+        explanation.rust = format!("{}({}: {})", command.name, param.name, param.rust_type);
+        if let Some(ref rename) = param.serde_rename {
+            explanation
+                .serde
+                .push(format!("rename = \"{}\" on {}", rename, param.name));
+        }
+        explanation
+            .serde
+            .push(format!("serialized as {}", param_context.serialized_name));
+        explanation.type_structure = Some(format!("{:?}", param.type_structure));
+        explanation.typescript_type = Some(param_context.typescript_type.clone());
+        explanation.template = if library == "typescript" {
+            "typescript/partials/param_interface.ts.tera".to_string()
+        } else {
+            format!("{}/partials/param_schemas.ts.tera", library)
+        };
+        let params = format!("{}Params", context.ts_type_name);
+        explanation.output = Self::generated(
+            files,
+            &[format!("{}Schema", params), params],
+            Some(&param_context.serialized_name),
+        );
+        Ok(explanation)
+    }
+
+    /// Human-readable report of the explanation
+    pub fn report(&self) -> String {
+        let mut output = format!("🔎 {}\n\n", self.symbol);
+        output.push_str(&format!("🦀 Rust: {} ({})\n", self.rust, self.location));
+
+        let mut section = |title: &str, lines: &[String]| {
+            output.push_str(&format!("\n{}:\n", title));
+            if lines.is_empty() {
+                output.push_str("• none\n");
+            }
+            for line in lines {
+                output.push_str(&format!("• {}\n", line));
+            }
+        };
+        section("🏷️  Serde", &self.serde);
+        section("✅ Validation", &self.validation);
+        let mut types = Vec::new();
+        if let Some(ref type_structure) = self.type_structure {
+            types.push(format!("TypeStructure: {}", type_structure));
+        }
+        if let Some(ref typescript_type) = self.typescript_type {
+            types.push(format!("TypeScript: {}", typescript_type));
+        }
+        section("🧬 Type", &types);
+        section("📐 Template", std::slice::from_ref(&self.template));
+
+        output.push_str("\n📄 Output:\n");
+        if self.output.is_empty() {
+            output.push_str("• not generated, no command uses it\n");
+        }
+        for generated in &self.output {
+            output.push_str(&format!("{}:\n", generated.file));
+            for line in &generated.lines {
+                output.push_str(&format!("  {}\n", line));
+            }
+        }
+        output
+    }
+
+    fn file_stem(file_name: &str) -> &str {
+        file_name
+            .strip_suffix(".d.ts")
+            .or_else(|| file_name.strip_suffix(".ts"))
+            .unwrap_or(file_name)
+    }
+
+    /// Directory of the templates of a library, `typescript` for plain TypeScript
+    fn template_library(library: &str) -> &str {
+        match library {
+            "zod" | "valibot" => library,
+            _ => "typescript",
+        }
+    }
+
+    /// Template rendering a type, the same choice as the generators make
+    fn type_template(struct_info: &StructInfo, library: &str) -> String {
+        let library = Self::template_library(library);
+        let typescript = library == "typescript";
+        let partial = if struct_info.is_adjacently_tagged() || struct_info.is_internally_tagged() {
+            if typescript {
+                "tagged_enum.tera"
+            } else {
+                "tagged_enum_schema.ts.tera"
+            }
+        } else if struct_info.is_untagged() {
+            if typescript {
+                "untagged_enum.tera"
+            } else {
+                "newtype_schema.ts.tera"
+            }
+        } else if struct_info.is_externally_tagged() && struct_info.has_variant_data() {
+            if typescript {
+                "external_enum.tera"
+            } else {
+                "newtype_schema.ts.tera"
+            }
+        } else if struct_info.is_enum {
+            if !typescript {
+                return format!("{}/types.ts.tera (enum schema rendered inline)", library);
+            }
+            "enum.tera"
+        } else if struct_info.is_newtype {
+            if typescript {
+                "newtype.tera"
+            } else {
+                "newtype_schema.ts.tera"
+            }
+        } else if typescript {
+            "interface.tera"
+        } else {
+            "schema.ts.tera"
+        };
+        format!("{}/partials/{}", library, partial)
+    }
+
+    /// TypeScript type of a field, naming a configured override
+    fn typescript_type(
+        context: &FieldContext,
+        struct_name: &str,
+        config: &GenerateConfig,
+    ) -> String {
+        let selector = format!("{}.{}", struct_name, context.name);
+        match config
+            .field_overrides
+            .as_ref()
+            .and_then(|overrides| overrides.get(&selector))
+        {
+            Some(field_override) => format!(
+                "{} (fieldOverrides[\"{}\"])",
+                field_override.ts_type(),
+                selector
+            ),
+            None => context.typescript_type.clone(),
+        }
+    }
+
+    fn constraints(attributes: &Option<ValidatorAttributes>) -> Vec<String> {
+        let Some(attributes) = attributes else {
+            return Vec::new();
+        };
+        let bounds = |min: Option<String>, max: Option<String>| {
+            [
+                min.map(|min| format!("min = {}", min)),
+                max.map(|max| format!("max = {}", max)),
+            ]
+            .into_iter()
+            .flatten()
+            .collect::<Vec<_>>()
+            .join(", ")
+        };
+        let mut constraints = Vec::new();
+        if let Some(ref length) = attributes.length {
+            constraints.push(format!(
+                "length({})",
+                bounds(
+                    length.min.map(|v| v.to_string()),
+                    length.max.map(|v| v.to_string())
+                )
+            ));
+        }
+        if let Some(ref range) = attributes.range {
+            constraints.push(format!(
+                "range({})",
+                bounds(
+                    range.min.map(|v| v.to_string()),
+                    range.max.map(|v| v.to_string())
+                )
+            ));
+        }
+        if attributes.email {
+            constraints.push("email".to_string());
+        }
+        if attributes.url {
+            constraints.push("url".to_string());
+        }
+        if let Some(ref message) = attributes.custom_message {
+            constraints.push(format!("message = \"{}\"", message));
+        }
+        constraints
+    }
+
+    /// Lines of the generated files declaring one of `names`, only the lines of
+    /// `property` if given
+    fn generated(
+        files: &[GeneratedFile],
+        names: &[String],
+        property: Option<&str>,
+    ) -> Vec<GeneratedLines> {
+        files
+            .iter()
+            .filter_map(|file| {
+                let lines = Self::declaration_lines(&file.content, names, property);
+                (!lines.is_empty()).then(|| GeneratedLines {
+                    file: file.name.clone(),
+                    lines,
+                })
+            })
+            .collect()
+    }
+
+    /// The bindings of the project, generated in memory
+    fn generate(
+        commands: &[CommandInfo],
+        analyzer: &CommandAnalyzer,
+        config: &GenerateConfig,
+    ) -> Result<Vec<GeneratedFile>, String> {
+        let config = in_memory_config(config);
+        let mut generator = create_generator(Some(config.generator_library().to_string()));
+        let mut file_writer = FileWriter::in_memory();
+        generator
+            .write_models(
+                &mut file_writer,
+                commands,
+                analyzer.get_discovered_structs(),
+                analyzer,
+                &config,
+            )
+            .map_err(|e| format!("Failed to generate the bindings: {}", e))?;
+        Ok(file_writer.into_files())
+    }
+
+    /// Lines of the `export` declarations of `names`, from the `export` line to the
+    /// closing line at the start of a line
+    fn declaration_lines(content: &str, names: &[String], property: Option<&str>) -> Vec<String> {
+        let mut lines = Vec::new();
+        let mut declaring = false;
+        for line in content.lines() {
+            if !declaring
+                && Self::declared_name(line).is_some_and(|name| names.iter().any(|n| n == name))
+            {
+                declaring = !line.trim_end().ends_with(';');
+                if property.is_none() {
+                    lines.push(line.to_string());
+                }
+                continue;
+            }
+            if !declaring {
+                continue;
+            }
+            if line.starts_with('}') || line.starts_with(')') {
+                declaring = false;
+                if property.is_none() {
+                    lines.push(line.to_string());
+                }
+            } else if property.map_or(true, |property| Self::declares_property(line, property)) {
+                lines.push(line.to_string());
+            }
+        }
+        lines
+    }
+
+    /// Name declared by an `export` line, e.g. `User` of `export interface User {`
+    fn declared_name(line: &str) -> Option<&str> {
+        let mut rest = line.strip_prefix("export ")?;
+        for keyword in [
+            "declare ",
+            "const ",
+            "type ",
+            "interface ",
+            "async ",
+            "function ",
+            "enum ",
+        ] {
+            rest = rest.strip_prefix(keyword).unwrap_or(rest);
+        }
+        for keyword in ["function ", "type ", "interface "] {
+            rest = rest.strip_prefix(keyword).unwrap_or(rest);
+        }
+        let end = rest
+            .find(|c: char| !(c.is_alphanumeric() || c == '_' || c == '$'))
+            .unwrap_or(rest.len());
+        (end > 0).then(|| &rest[..end])
+    }
+
+    /// Whether a line of an object type or schema declares `property`
+    fn declares_property(line: &str, property: &str) -> bool {
+        let line = line.trim_start();
+        [
+            property.to_string(),
+            format!("\"{}\"", property),
+            format!("'{}'", property),
+        ]
+        .iter()
+        .any(|key| {
+            line.strip_prefix(key.as_str())
+                .is_some_and(|rest| rest.starts_with(':') || rest.starts_with("?:"))
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SOURCE: &str = r#"
+        use serde::{Deserialize, Serialize};
+
+        #[derive(Serialize, Deserialize)]
+        #[serde(rename_all = "camelCase")]
+        pub struct User {
+            #[validate(length(min = 1, max = 20))]
+            pub user_name: String,
+            #[serde(rename = "created")]
+            pub created_at: Option<String>,
+        }
+
+        #[tauri::command]
+        pub fn get_user(user_id: u32) -> Result<User, String> {
+            unimplemented!()
+        }
+    "#;
+
+    fn explain(symbol: &str, validation_library: &str) -> Result<Explanation, String> {
+        let config = GenerateConfig {
+            validation_library: validation_library.to_string(),
+            ..Default::default()
+        };
+        let mut analyzer = CommandAnalyzer::new();
+        let commands = analyzer.analyze_source(SOURCE).unwrap();
+        Explanation::of(symbol, &commands, &analyzer, &config)
+    }
+
+    #[test]
+    fn test_explain_field() {
+        let explanation = explain("types.User.created_at", "zod").unwrap();
+        assert_eq!(explanation.rust, "User.created_at: Option<String>");
+        assert_eq!(
+            explanation.serde,
+            [
+                "rename_all = \"camelCase\" on User",
+                "rename = \"created\" on created_at",
+                "serialized as created",
+            ]
+        );
+        assert_eq!(explanation.template, "zod/partials/schema.ts.tera");
+        assert_eq!(
+            explanation.output,
+            [GeneratedLines {
+                file: "types.ts".to_string(),
+                lines: vec!["  created: z.string().optional(),".to_string()],
+            }]
+        );
+
+        // Fields are also found by their serialized name
+        let explanation = explain("types.User.userName", "none").unwrap();
+        assert_eq!(explanation.validation, ["length(min = 1, max = 20)"]);
+        assert_eq!(explanation.typescript_type.as_deref(), Some("string"));
+        assert_eq!(explanation.output[0].lines, ["  userName: string;"]);
+        assert!(explanation.report().contains("• length(min = 1, max = 20)"));
+    }
+
+    #[test]
+    fn test_explain_type_and_command() {
+        let explanation = explain("types.User", "none").unwrap();
+        assert_eq!(explanation.template, "typescript/partials/interface.tera");
+        assert_eq!(
+            explanation.output[0].lines.first().map(String::as_str),
+            Some("export interface User {")
+        );
+        assert_eq!(
+            explanation.output[0].lines.last().map(String::as_str),
+            Some("}")
+        );
+
+        let explanation = explain("commands.getUser.userId", "valibot").unwrap();
+        assert_eq!(explanation.rust, "get_user(user_id: u32)");
+        assert_eq!(
+            explanation.template,
+            "valibot/partials/param_schemas.ts.tera"
+        );
+        assert_eq!(explanation.output[0].lines, ["  userId: v.number(),"]);
+
+        let explanation = explain("commands.get_user", "none").unwrap();
+        assert_eq!(
+            explanation.rust,
+            "fn get_user(user_id: u32) -> Result<User, String>"
+        );
+        assert_eq!(explanation.output[0].file, "commands.ts");
+    }
+
+    #[test]
+    fn test_explain_unknown_symbols() {
+        assert!(explain("types.Team", "none").unwrap_err().contains("Team"));
+        assert!(explain("types.User.password", "none")
+            .unwrap_err()
+            .contains("no field or variant password"));
+        assert!(explain("models.User", "none")
+            .unwrap_err()
+            .contains("Unknown file models"));
+        assert!(explain("User", "none")
+            .unwrap_err()
+            .contains("Invalid symbol"));
+    }
+}
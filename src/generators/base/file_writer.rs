@@ -1,10 +1,20 @@
 use std::fs;
 use std::path::Path;
 
+/// A generated file kept in memory (see [`FileWriter::in_memory`])
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GeneratedFile {
+    /// File name relative to the output directory
+    pub name: String,
+    pub content: String,
+}
+
 /// Utility for writing generated TypeScript files with consistent patterns
 pub struct FileWriter {
     output_path: String,
     generated_files: Vec<String>,
+    /// Contents of the written files when writing to memory instead of `output_path`
+    memory: Option<Vec<GeneratedFile>>,
 }
 
 impl FileWriter {
@@ -13,21 +23,45 @@ impl FileWriter {
         Ok(Self {
             output_path: output_path.to_string(),
             generated_files: Vec::new(),
+            memory: None,
         })
     }
 
+    /// Writer keeping the files in memory instead of writing them to disk
+    pub fn in_memory() -> Self {
+        Self {
+            output_path: String::new(),
+            generated_files: Vec::new(),
+            memory: Some(Vec::new()),
+        }
+    }
+
     /// Write a TypeScript file with the given content
     pub fn write_typescript_file(
         &mut self,
         filename: &str,
         content: &str,
     ) -> Result<(), Box<dyn std::error::Error>> {
-        let file_path = format!("{}/{}", self.output_path, filename);
-        fs::write(&file_path, content)?;
+        match self.memory {
+            // Like on disk, writing a file again replaces it
+            Some(ref mut files) => match files.iter_mut().find(|file| file.name == filename) {
+                Some(file) => file.content = content.to_string(),
+                None => files.push(GeneratedFile {
+                    name: filename.to_string(),
+                    content: content.to_string(),
+                }),
+            },
+            None => fs::write(self.get_file_path(filename), content)?,
+        }
         self.generated_files.push(filename.to_string());
         Ok(())
     }
 
+    /// Files written by an in-memory writer, in write order; empty when writing to disk
+    pub fn into_files(self) -> Vec<GeneratedFile> {
+        self.memory.unwrap_or_default()
+    }
+
     /// Write the types.ts file
     pub fn write_types_file(&mut self, content: &str) -> Result<(), Box<dyn std::error::Error>> {
         self.write_typescript_file("types.ts", content)
@@ -71,12 +105,19 @@ impl FileWriter {
 
     /// Check if a file exists in the output directory
     pub fn file_exists(&self, filename: &str) -> bool {
+        if let Some(ref files) = self.memory {
+            return files.iter().any(|file| file.name == filename);
+        }
         let file_path = format!("{}/{}", self.output_path, filename);
         Path::new(&file_path).exists()
     }
 
     /// Delete a file if it exists (useful for cleanup)
-    pub fn delete_file(&self, filename: &str) -> Result<(), Box<dyn std::error::Error>> {
+    pub fn delete_file(&mut self, filename: &str) -> Result<(), Box<dyn std::error::Error>> {
+        if let Some(ref mut files) = self.memory {
+            files.retain(|file| file.name != filename);
+            return Ok(());
+        }
         let file_path = format!("{}/{}", self.output_path, filename);
         if Path::new(&file_path).exists() {
             fs::remove_file(&file_path)?;
@@ -315,7 +356,7 @@ mod tests {
         #[test]
         fn test_delete_nonexistent_file() {
             let dir = temp_dir();
-            let writer = FileWriter::new(&dir).unwrap();
+            let mut writer = FileWriter::new(&dir).unwrap();
             let result = writer.delete_file("nonexistent.ts");
             assert!(result.is_ok()); // Should not error
             cleanup_dir(&dir);
@@ -388,4 +429,32 @@ mod tests {
             cleanup_dir(&dir);
         }
     }
+
+    mod in_memory {
+        use super::*;
+
+        #[test]
+        fn test_in_memory_keeps_files() {
+            let mut writer = FileWriter::in_memory();
+            writer.write_types_file("first").unwrap();
+            writer.write_commands_file("commands").unwrap();
+            writer.write_types_file("second").unwrap();
+
+            assert!(writer.file_exists("types.ts"));
+            assert!(!writer.file_exists("events.ts"));
+            assert_eq!(
+                writer.into_files(),
+                [
+                    GeneratedFile {
+                        name: "types.ts".to_string(),
+                        content: "second".to_string(),
+                    },
+                    GeneratedFile {
+                        name: "commands.ts".to_string(),
+                        content: "commands".to_string(),
+                    },
+                ]
+            );
+        }
+    }
 }
@@ -0,0 +1,250 @@
+use crate::generators::base::template_context::CommandContext;
+use crate::models::{CommandInfo, TypeStructure};
+use serde::Serialize;
+use std::collections::HashSet;
+use std::path::{Component, Path};
+
+/// A command exposed as `POST /<command>` by the generated HTTP bridge
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BridgeCommand {
+    pub name: String,
+    /// Path of the command function, e.g. `crate::commands::get_user`
+    pub function_path: String,
+    /// Argument expressions in signature order
+    pub arguments: Vec<String>,
+    pub has_parameters: bool,
+    pub is_async: bool,
+    pub returns_result: bool,
+}
+
+/// A command the bridge cannot call outside a webview, and why
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct SkippedCommand {
+    pub name: String,
+    pub reason: String,
+}
+
+/// Commands of an axum router calling Tauri commands directly.
+///
+/// Frontend parameters are read from the JSON request body under their serialized
+/// names, like `invoke` sends them. `AppHandle` and `State<T>` are supplied from the
+/// app handle; commands injecting anything else (windows, webviews, channels, raw
+/// requests), returning raw bytes (`tauri::ipc::Response`) or using `Compact<T>` are
+/// skipped.
+#[derive(Debug, Default)]
+pub struct HttpBridge {
+    pub commands: Vec<BridgeCommand>,
+    pub skipped: Vec<SkippedCommand>,
+}
+
+impl HttpBridge {
+    /// `contexts` are the command contexts created from `commands`, in the same order
+    pub fn new(commands: &[CommandInfo], contexts: &[CommandContext]) -> Self {
+        let mut bridge = Self::default();
+        let mut seen = HashSet::new();
+
+        for (command, context) in commands.iter().zip(contexts) {
+            if !seen.insert(command.name.clone()) {
+                bridge.skip(command, "another command has the same name".to_string());
+                continue;
+            }
+            let Some(module) = Self::module_path(&command.file_path) else {
+                bridge.skip(command, "not defined in the library crate".to_string());
+                continue;
+            };
+
+            if command.raw_response {
+                bridge.skip(command, "returns raw bytes".to_string());
+                continue;
+            }
+            if context.compact {
+                bridge.skip(command, "sends compact MessagePack payloads".to_string());
+                continue;
+            }
+
+            let arity = command.parameters.len() + command.injected_parameters.len();
+            let mut arguments: Vec<Option<String>> = vec![None; arity];
+            let mut unsupported = None;
+            for injected in &command.injected_parameters {
+                match Self::injected_argument(&injected.rust_type) {
+                    Some(argument) => arguments[injected.position] = Some(argument.to_string()),
+                    None => {
+                        unsupported
+                            .get_or_insert_with(|| format!("injects {}", injected.rust_type));
+                    }
+                }
+            }
+            if let Some(reason) = unsupported {
+                bridge.skip(command, reason);
+                continue;
+            }
+
+            // Frontend parameters fill the remaining positions in order
+            let mut values = context
+                .parameters
+                .iter()
+                .map(|param| format!("arg(&args, {:?})?", param.serialized_name));
+            let arguments = arguments
+                .into_iter()
+                .map(|argument| argument.or_else(|| values.next()).unwrap_or_default())
+                .collect();
+
+            bridge.commands.push(BridgeCommand {
+                name: command.name.clone(),
+                function_path: format!("{}::{}", module, command.name),
+                arguments,
+                has_parameters: !command.parameters.is_empty(),
+                is_async: command.is_async,
+                returns_result: matches!(command.return_type_structure, TypeStructure::Result(_)),
+            });
+        }
+
+        bridge
+    }
+
+    fn skip(&mut self, command: &CommandInfo, reason: String) {
+        self.skipped.push(SkippedCommand {
+            name: command.name.clone(),
+            reason,
+        });
+    }
+
+    /// Expression supplying an injected parameter from `app: AppHandle`
+    fn injected_argument(rust_type: &str) -> Option<&'static str> {
+        let base = rust_type.split('<').next().unwrap_or(rust_type);
+        match base.rsplit("::").next().unwrap_or(base).trim() {
+            "AppHandle" => Some("app.clone()"),
+            "State" => Some("app.state()"),
+            _ => None,
+        }
+    }
+
+    /// Rust module path of a source file, e.g. `src-tauri/src/commands/user.rs` →
+    /// `crate::commands::user`. `None` for files outside `src` or in `src/bin`.
+    pub fn module_path(file_path: &str) -> Option<String> {
+        let components: Vec<String> = Path::new(file_path)
+            .components()
+            .filter_map(|component| match component {
+                Component::Normal(part) => Some(part.to_string_lossy().to_string()),
+                _ => None,
+            })
+            .collect();
+        let src = components.iter().rposition(|part| part == "src")?;
+        let mut modules: Vec<&str> = components[src + 1..].iter().map(String::as_str).collect();
+
+        let file = modules.pop()?.strip_suffix(".rs")?;
+        if modules.first() == Some(&"bin") {
+            return None;
+        }
+        if !(file == "mod" || (modules.is_empty() && (file == "lib" || file == "main"))) {
+            modules.push(file);
+        }
+
+        Some(
+            std::iter::once("crate")
+                .chain(modules)
+                .collect::<Vec<_>>()
+                .join("::"),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::generators::ts::type_visitor::TypeScriptVisitor;
+    use crate::models::{InjectedParameter, ParameterInfo};
+    use crate::GenerateConfig;
+
+    fn bridge(commands: &[CommandInfo]) -> HttpBridge {
+        let config = GenerateConfig::default();
+        let visitor = TypeScriptVisitor::with_config(&config);
+        let resolver = |_: &str| TypeStructure::default();
+        let contexts: Vec<CommandContext> = commands
+            .iter()
+            .map(|cmd| CommandContext::new(&config).from_command_info(cmd, &visitor, &resolver))
+            .collect();
+        HttpBridge::new(commands, &contexts)
+    }
+
+    fn injected(name: &str, rust_type: &str, position: usize) -> InjectedParameter {
+        InjectedParameter {
+            name: name.to_string(),
+            rust_type: rust_type.to_string(),
+            position,
+        }
+    }
+
+    fn command(injected_parameters: Vec<InjectedParameter>) -> CommandInfo {
+        let param = ParameterInfo {
+            name: "user_id".to_string(),
+            rust_type: "u32".to_string(),
+            is_optional: false,
+            type_structure: TypeStructure::Primitive("number".to_string()),
+            serde_rename: None,
+        };
+        let mut cmd = CommandInfo::new_for_test(
+            "get_user",
+            "src-tauri/src/commands/users.rs",
+            3,
+            vec![param],
+            "Result<User, String>",
+            true,
+            vec![],
+        );
+        cmd.injected_parameters = injected_parameters;
+        cmd
+    }
+
+    #[test]
+    fn test_module_paths() {
+        assert_eq!(
+            HttpBridge::module_path("src-tauri/src/lib.rs").as_deref(),
+            Some("crate")
+        );
+        assert_eq!(
+            HttpBridge::module_path("/app/src-tauri/src/commands/mod.rs").as_deref(),
+            Some("crate::commands")
+        );
+        assert_eq!(
+            HttpBridge::module_path("src/commands/users.rs").as_deref(),
+            Some("crate::commands::users")
+        );
+        assert_eq!(HttpBridge::module_path("src/bin/tool.rs"), None);
+        assert_eq!(HttpBridge::module_path("build.rs"), None);
+    }
+
+    #[test]
+    fn test_arguments_follow_signature_order() {
+        let bridge = bridge(&[command(vec![
+            injected("app", "AppHandle", 0),
+            injected("db", "State<Database>", 2),
+        ])]);
+
+        assert!(bridge.skipped.is_empty());
+        let command = &bridge.commands[0];
+        assert_eq!(command.function_path, "crate::commands::users::get_user");
+        assert_eq!(
+            command.arguments,
+            vec!["app.clone()", "arg(&args, \"userId\")?", "app.state()"]
+        );
+        assert!(command.is_async);
+        assert!(command.returns_result);
+    }
+
+    #[test]
+    fn test_commands_injecting_windows_are_skipped() {
+        let bridge = bridge(&[
+            command(vec![injected("window", "tauri::Window<R>", 1)]),
+            command(vec![]),
+        ]);
+
+        assert!(bridge.commands.is_empty());
+        assert_eq!(bridge.skipped[0].reason, "injects tauri::Window<R>");
+        assert_eq!(
+            bridge.skipped[1].reason,
+            "another command has the same name"
+        );
+    }
+}
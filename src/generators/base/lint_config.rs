@@ -0,0 +1,144 @@
+use serde_json::{json, Value};
+
+/// Module exporting the raw `invoke` the generated bindings wrap
+const INVOKE_MODULE: &str = "@tauri-apps/api/core";
+
+/// Linter a lint config is written for (see `lint_config`)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LintTool {
+    /// `.eslintrc` overrides (`eslint.typegen.json`)
+    Eslint,
+    /// `biome.json` section (`biome.typegen.json`)
+    Biome,
+}
+
+impl LintTool {
+    /// Parse a `lint_config` value ("eslint" or "biome")
+    pub fn parse(value: &str) -> Option<Self> {
+        match value {
+            "eslint" => Some(Self::Eslint),
+            "biome" => Some(Self::Biome),
+            _ => None,
+        }
+    }
+
+    /// Name of the file the config is written to, in the output directory
+    pub fn file_name(&self) -> &'static str {
+        match self {
+            Self::Eslint => "eslint.typegen.json",
+            Self::Biome => "biome.typegen.json",
+        }
+    }
+
+    /// Config to merge into the project's lint config, at the project root.
+    ///
+    /// Naming and file length rules are turned off for the generated files under
+    /// `output_path`, and importing `invoke` is restricted everywhere else so calls go
+    /// through the typed bindings.
+    pub fn config(&self, output_path: &str) -> String {
+        let generated = format!("{}/**", output_directory(output_path));
+        let message = format!(
+            "Call commands through the typed bindings in {} instead of invoke",
+            output_directory(output_path)
+        );
+
+        let config: Value = match self {
+            Self::Eslint => json!({
+                "rules": {
+                    "no-restricted-imports": ["error", {
+                        "paths": [{
+                            "name": INVOKE_MODULE,
+                            "importNames": ["invoke"],
+                            "message": message,
+                        }],
+                    }],
+                },
+                "overrides": [{
+                    "files": [generated],
+                    "rules": {
+                        "@typescript-eslint/naming-convention": "off",
+                        "max-lines": "off",
+                        "no-restricted-imports": "off",
+                    },
+                }],
+            }),
+            Self::Biome => json!({
+                "linter": {
+                    "rules": {
+                        "style": {
+                            "noRestrictedImports": {
+                                "level": "error",
+                                "options": {
+                                    "paths": {
+                                        INVOKE_MODULE: {
+                                            "importNames": ["invoke"],
+                                            "message": message,
+                                        },
+                                    },
+                                },
+                            },
+                        },
+                    },
+                },
+                "overrides": [{
+                    "includes": [generated],
+                    "linter": {
+                        "rules": {
+                            "style": {
+                                "useNamingConvention": "off",
+                                "noRestrictedImports": "off",
+                            },
+                        },
+                    },
+                }],
+            }),
+        };
+        serde_json::to_string_pretty(&config).unwrap_or_default()
+    }
+}
+
+/// Output directory as a glob base relative to the project root (`./src/generated/`
+/// becomes `src/generated`)
+fn output_directory(output_path: &str) -> String {
+    let path = output_path.replace('\\', "/");
+    let path = path.trim_start_matches("./").trim_end_matches('/');
+    if path.is_empty() {
+        ".".to_string()
+    } else {
+        path.to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_eslint_config_scopes_overrides_to_output_directory() {
+        let config: Value =
+            serde_json::from_str(&LintTool::Eslint.config("./src/generated/")).unwrap();
+
+        assert_eq!(config["overrides"][0]["files"], json!(["src/generated/**"]));
+        assert_eq!(
+            config["overrides"][0]["rules"]["@typescript-eslint/naming-convention"],
+            "off"
+        );
+        let restricted = &config["rules"]["no-restricted-imports"][1]["paths"][0];
+        assert_eq!(restricted["name"], "@tauri-apps/api/core");
+        assert_eq!(restricted["importNames"], json!(["invoke"]));
+    }
+
+    #[test]
+    fn test_biome_config() {
+        let config: Value = serde_json::from_str(&LintTool::Biome.config("bindings")).unwrap();
+
+        assert_eq!(config["overrides"][0]["includes"], json!(["bindings/**"]));
+        assert_eq!(
+            config["linter"]["rules"]["style"]["noRestrictedImports"]["options"]["paths"]
+                ["@tauri-apps/api/core"]["importNames"],
+            json!(["invoke"])
+        );
+        assert_eq!(LintTool::parse("biome"), Some(LintTool::Biome));
+        assert_eq!(LintTool::parse("tslint"), None);
+    }
+}
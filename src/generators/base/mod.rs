@@ -1,15 +1,155 @@
+pub mod admin_panel;
+pub mod aliases;
+pub mod arbitraries;
+pub mod binary;
+pub mod casing;
+pub mod command_chunks;
+pub mod compact;
+pub mod context_dump;
+pub mod error_classes;
+pub mod examples;
+pub mod explain;
 pub mod file_writer;
+pub mod http_bridge;
+pub mod lint_config;
+pub mod offline;
+pub mod pinia;
+pub mod progress;
+pub mod query_keys;
+pub mod range_literals;
+pub mod react_query;
+pub mod sanitize;
+pub mod self_test;
+pub mod stores;
+pub mod svelte;
 pub mod template_context;
 pub mod templates;
+pub mod testing;
+pub mod trace;
 pub mod type_visitor;
+pub mod validation_messages;
+pub mod vue;
 
 use tera::{Context, Tera};
 
-use crate::analysis::CommandAnalyzer;
+use crate::analysis::derive_checker::SerdeTrait;
+use crate::analysis::type_resolver::JSON_VALUE_TYPE;
+use crate::analysis::{CommandAnalyzer, SELF_TEST_COMMAND};
+use crate::generators::base::casing::CaseConverter;
+use crate::generators::base::file_writer::FileWriter;
+use crate::generators::base::lint_config::LintTool;
+use crate::generators::base::template_context::{
+    CommandContext, EventContext, FieldContext, StructContext,
+};
+use crate::generators::ts::type_visitor::TypeScriptVisitor;
 use crate::generators::{GlobalContext, TypeCollector};
-use crate::models::{CommandInfo, StructInfo};
-use crate::GenerateConfig;
-use std::collections::HashMap;
+use crate::models::{CommandInfo, ConstInfo, EventInfo, StructInfo, TypeStructure};
+use crate::{GenerateConfig, OutputFileNames};
+use std::collections::{BTreeSet, HashMap, HashSet};
+
+/// Global types from the ES and DOM libs that ambient declarations must not redeclare
+const BUILTIN_GLOBAL_TYPES: &[&str] = &[
+    "Array",
+    "Blob",
+    "Boolean",
+    "Comment",
+    "Date",
+    "Document",
+    "Element",
+    "Error",
+    "Event",
+    "File",
+    "FormData",
+    "Function",
+    "Headers",
+    "History",
+    "Image",
+    "Location",
+    "Map",
+    "Node",
+    "Notification",
+    "Number",
+    "Object",
+    "Partial",
+    "Promise",
+    "Range",
+    "Record",
+    "Request",
+    "Response",
+    "Selection",
+    "Set",
+    "Storage",
+    "String",
+    "Symbol",
+    "Text",
+    "URL",
+    "Window",
+    "Worker",
+];
+
+/// Rendering of the TypeScript generators that depends on their schema library; the
+/// files are written by [`BaseBindingsGenerator::write_typescript_models`]
+pub trait TypeScriptFiles {
+    /// Directory of the generator's templates (`typescript`, `zod`, `valibot`)
+    fn template_dir(&self) -> &'static str;
+
+    /// Type collector for this generator, to store the known structs in
+    fn type_collector_mut(&mut self) -> &mut TypeCollector;
+
+    /// Contexts of the commands, typed by the generator's visitor
+    fn command_contexts(
+        &self,
+        commands: &[CommandInfo],
+        analyzer: &CommandAnalyzer,
+        config: &GenerateConfig,
+    ) -> Vec<CommandContext>;
+
+    /// Contexts of the events, typed by the generator's visitor
+    fn event_contexts(
+        &self,
+        events: &[EventInfo],
+        analyzer: &CommandAnalyzer,
+        config: &GenerateConfig,
+    ) -> Vec<EventContext>;
+
+    /// Contexts of the structs, typed by the generator's visitor
+    fn struct_contexts(
+        &self,
+        used_structs: &HashMap<String, StructInfo>,
+        config: &GenerateConfig,
+    ) -> Vec<StructContext>;
+
+    /// Content of the types file, with the schemas of the generator
+    fn generate_types_file_content(
+        &self,
+        commands: &[CommandInfo],
+        used_structs: &HashMap<String, StructInfo>,
+        analyzer: &CommandAnalyzer,
+        config: &GenerateConfig,
+    ) -> String;
+
+    /// Prepare the schemas of the used types before any file is rendered
+    fn prepare_schemas(
+        &mut self,
+        _commands: &[CommandInfo],
+        _used_structs: &HashMap<String, StructInfo>,
+        _config: &GenerateConfig,
+    ) -> Result<(), Box<dyn std::error::Error>> {
+        Ok(())
+    }
+
+    /// Write the files only generated with schemas (schema registry, form hooks)
+    fn write_schema_files(
+        &self,
+        _file_writer: &mut FileWriter,
+        _commands: &[CommandInfo],
+        _used_structs: &HashMap<String, StructInfo>,
+        _analyzer: &CommandAnalyzer,
+        _config: &GenerateConfig,
+    ) -> Result<(), Box<dyn std::error::Error>> {
+        Ok(())
+    }
+}
 
 /// Common trait for all generators
 pub trait BaseBindingsGenerator {
@@ -22,7 +162,46 @@ pub trait BaseBindingsGenerator {
     /// String representation of the chosen validator
     fn generator_type(&self) -> String;
 
-    /// Generate models from Rust commands and structs
+    /// Whether the bindings export a schema next to every type and validate parameters
+    fn has_schemas(&self) -> bool {
+        matches!(self.generator_type().as_str(), "zod" | "valibot")
+    }
+
+    /// Library of the schemas (`"zod"`, `"valibot"`), empty without schemas
+    fn schema_library(&self) -> String {
+        if self.has_schemas() {
+            self.generator_type()
+        } else {
+            String::new()
+        }
+    }
+
+    /// Language of the bindings, as named in the CLI output
+    fn language(&self) -> String {
+        "TypeScript".to_string()
+    }
+
+    /// How the frontend imports the bindings written to `output_path`, shown after
+    /// generation; `None` when there is no single import to suggest
+    fn import_hint(&self, output_path: &str, _config: &GenerateConfig) -> Option<String> {
+        Some(format!(
+            "import {{ /* commands */ }} from '{}'",
+            output_path.trim_end_matches('/')
+        ))
+    }
+
+    /// Write the bindings of the commands and structs through `file_writer`,
+    /// returning the names of the written files
+    fn write_models(
+        &mut self,
+        file_writer: &mut FileWriter,
+        commands: &[CommandInfo],
+        discovered_structs: &HashMap<String, StructInfo>,
+        analyzer: &CommandAnalyzer,
+        config: &GenerateConfig,
+    ) -> Result<Vec<String>, Box<dyn std::error::Error>>;
+
+    /// Generate models from Rust commands and structs into `output_path`
     fn generate_models(
         &mut self,
         commands: &[CommandInfo],
@@ -30,7 +209,427 @@ pub trait BaseBindingsGenerator {
         output_path: &str,
         analyzer: &CommandAnalyzer,
         config: &GenerateConfig,
-    ) -> Result<Vec<String>, Box<dyn std::error::Error>>;
+    ) -> Result<Vec<String>, Box<dyn std::error::Error>> {
+        let mut file_writer = FileWriter::new(output_path)?;
+        self.write_models(
+            &mut file_writer,
+            commands,
+            discovered_structs,
+            analyzer,
+            config,
+        )
+    }
+
+    /// Write the bindings of a TypeScript generator: the types and commands files
+    /// rendered with its schemas, and the files shared by all of them
+    fn write_typescript_models(
+        &mut self,
+        file_writer: &mut FileWriter,
+        commands: &[CommandInfo],
+        discovered_structs: &HashMap<String, StructInfo>,
+        analyzer: &CommandAnalyzer,
+        config: &GenerateConfig,
+    ) -> Result<Vec<String>, Box<dyn std::error::Error>>
+    where
+        Self: TypeScriptFiles + Sized,
+    {
+        // Settings stores are typed by their structs, and error classes type their
+        // variant payloads with the error enums' types
+        let mut extra = self
+            .type_collector()
+            .collect_store_types(discovered_structs, config);
+        if config.should_generate_error_classes() {
+            extra.extend(
+                self.type_collector()
+                    .collect_error_types(commands, discovered_structs),
+            );
+        }
+
+        let used_structs = self.type_collector_mut().collect_bindings_types(
+            commands,
+            analyzer.get_discovered_events(),
+            discovered_structs,
+            extra,
+            config,
+        );
+
+        // Types without a definition are generated as the unknownTypeFallback type
+        let config = &self.type_collector().resolve_unknown_types(
+            commands,
+            analyzer.get_discovered_events(),
+            &used_structs,
+            config,
+        )?;
+        self.prepare_schemas(commands, &used_structs, config)?;
+
+        let file_names = config.file_names();
+
+        // Generate and write types file
+        let types_content =
+            self.generate_types_file_content(commands, &used_structs, analyzer, config);
+        file_writer.write_typescript_file(&file_names.types, &types_content)?;
+
+        // Generate and write commands file, split into chunks above maxCommandsPerFile
+        let command_chunks = self.write_command_files(
+            file_writer,
+            commands,
+            analyzer,
+            config,
+            &|file_commands, shared_module| {
+                self.generate_command_bindings(
+                    commands,
+                    file_commands,
+                    analyzer,
+                    config,
+                    shared_module,
+                )
+            },
+        )?;
+
+        // Generate and write events file if there are any events
+        let events = analyzer.get_discovered_events();
+        if !events.is_empty() {
+            let events_content = self.generate_events_file(events, analyzer, config);
+            file_writer.write_typescript_file(&file_names.events, &events_content)?;
+        }
+
+        // Generate and write enum helpers file if enabled
+        if config.should_generate_enum_helpers() {
+            let struct_contexts = self.struct_contexts(&used_structs, config);
+            if let Some(enums_content) = self.generate_enum_helpers_file(&struct_contexts, config) {
+                file_writer.write_typescript_file(&file_names.enums, &enums_content)?;
+            }
+        }
+
+        // Generate and write constants file if any constants are exported
+        if let Some(constants_content) =
+            self.generate_constants_file(analyzer.get_discovered_constants())
+        {
+            file_writer.write_typescript_file(&file_names.constants, &constants_content)?;
+        }
+
+        // Generate and write ambient global declarations if enabled
+        if config.should_generate_global_declarations() {
+            let globals_content =
+                self.generate_globals_file(commands, &used_structs, analyzer, config);
+            file_writer.write_typescript_file(&file_names.globals, &globals_content)?;
+        }
+
+        // Generate and write the files only generated with schemas
+        self.write_schema_files(file_writer, commands, &used_structs, analyzer, config)?;
+
+        // Generate and write the runtime shim if the web fallback is enabled
+        if config.should_generate_web_fallback() {
+            let runtime_content = self.generate_runtime_file(commands);
+            file_writer.write_typescript_file(&file_names.runtime, &runtime_content)?;
+        }
+
+        // Generate and write the HTTP bridge if configured; it lives in the Rust crate
+        if let Some(bridge_path) = &config.http_bridge {
+            let bridge_content = self.generate_http_bridge_file(commands, analyzer, config);
+            std::fs::write(bridge_path, bridge_content)?;
+        }
+
+        // Scaffold the admin panel if configured; edited scaffolds are kept
+        self.write_admin_panel(commands, file_writer.get_output_path(), analyzer, config)?;
+
+        // Generate and write XState actors if enabled
+        if config.should_generate_xstate_actors() {
+            let actors_content = self.generate_actors_file(commands, analyzer, config);
+            file_writer.write_typescript_file(&file_names.actors, &actors_content)?;
+        }
+
+        // Generate and write File/Blob helpers for byte commands if enabled
+        if config.should_generate_binary_helpers() {
+            if let Some(binary_content) = self.generate_binary_file(commands, analyzer, config) {
+                file_writer.write_typescript_file(&file_names.binary, &binary_content)?;
+            }
+        }
+
+        // Generate and write the offline queue for mutation commands if enabled
+        if config.should_generate_offline_queue() {
+            if let Some(offline_content) = self.generate_offline_file(commands, analyzer, config) {
+                file_writer.write_typescript_file(&file_names.offline, &offline_content)?;
+            }
+        }
+
+        // Generate and write the parameter sanitation helper if enabled
+        if config.should_generate_sanitize_params() {
+            if let Some(sanitize_content) = self.generate_sanitize_file(commands, analyzer, config)
+            {
+                file_writer.write_typescript_file(&file_names.sanitize, &sanitize_content)?;
+            }
+        }
+
+        // Generate and write query key factories if enabled
+        if config.should_generate_query_keys() {
+            if let Some(query_keys_content) =
+                self.generate_query_keys_file(commands, analyzer, config)
+            {
+                file_writer.write_typescript_file(&file_names.query_keys, &query_keys_content)?;
+            }
+        }
+
+        // Generate and write the hooks of the configured frontend framework
+        if let Some(hooks_content) = self.generate_hooks_file(commands, analyzer, config) {
+            file_writer.write_typescript_file(&file_names.hooks, &hooks_content)?;
+        }
+
+        // Generate and write settings store helpers if any stores are configured
+        if let Some(stores_content) = self.generate_stores_file(&used_structs, config) {
+            file_writer.write_typescript_file(&file_names.stores, &stores_content)?;
+        }
+
+        // Generate and write the compact codec if any compact types are configured; the
+        // Rust half lives in the Rust crate
+        if let Some((compact_content, codec_content)) =
+            self.generate_compact_files(commands, discovered_structs, analyzer, config)
+        {
+            file_writer.write_typescript_file(&file_names.compact, &compact_content)?;
+            if let Some(codec_path) = &config.compact_codec {
+                std::fs::write(codec_path, codec_content)?;
+            }
+        }
+
+        // Generate and write the bindings self-test; the command lives in the Rust crate
+        if let Some((self_test_content, command_content)) =
+            self.generate_self_test_files(&used_structs, analyzer, config)
+        {
+            file_writer.write_typescript_file(&file_names.self_test, &self_test_content)?;
+            if let Some(command_path) = &config.self_test {
+                std::fs::write(command_path, command_content)?;
+            }
+        }
+
+        // Generate and write index file; command chunks are re-exported by the commands file
+        let index_files: Vec<String> = file_writer
+            .get_generated_files()
+            .iter()
+            .filter(|file| !command_chunks.contains(file))
+            .cloned()
+            .collect();
+        let index_content = self.generate_index_file(&index_files, &file_names.index);
+        file_writer.write_typescript_file(&file_names.index, &index_content)?;
+
+        // Generate and write fast-check arbitraries if enabled; written after the index
+        // so the test-only file is not re-exported
+        if config.should_generate_arbitraries() {
+            let arbitraries_content =
+                self.generate_arbitraries_file(commands, &used_structs, analyzer, config);
+            file_writer.write_typescript_file(&file_names.arbitraries, &arbitraries_content)?;
+        }
+
+        // Generate and write the mockIPC test harness if enabled; test-only like the
+        // arbitraries, so not re-exported either
+        if config.should_generate_test_harness() {
+            let testing_content = self.generate_testing_file(commands, analyzer, config);
+            file_writer.write_typescript_file(&file_names.testing, &testing_content)?;
+        }
+
+        if config.should_generate_mocks() {
+            let mocks_content = self.generate_mocks_file(commands, analyzer, config);
+            file_writer.write_typescript_file(&file_names.mocks, &mocks_content)?;
+        }
+
+        // Generate and write error classes if enabled; written after the index since
+        // the classes share their names with the error types
+        if config.should_generate_error_classes() {
+            if let Some(errors_content) = self.generate_errors_file(commands, &used_structs, config)
+            {
+                file_writer.write_typescript_file(&file_names.errors, &errors_content)?;
+            }
+        }
+
+        // Write the symbol trace if enabled; written after the index as it is not a module
+        if config.should_generate_source_trace() {
+            let trace_content = self.generate_trace_file(commands, &used_structs, analyzer, config);
+            file_writer.write_typescript_file(&file_names.trace, &trace_content)?;
+        }
+
+        // Write the lint config if configured; written after the index as it is not a module
+        if let Some(tool) = config.lint_config.as_deref().and_then(LintTool::parse) {
+            file_writer
+                .write_typescript_file(tool.file_name(), &tool.config(&config.output_path))?;
+        }
+
+        Ok(file_writer.get_generated_files().to_vec())
+    }
+
+    /// Generate the commands file of `file_commands`, importing the helpers of chunked
+    /// commands files from `shared_module`. Names and invalidation targets are resolved
+    /// among all `commands`, so the commands of other chunks are taken into account.
+    fn generate_command_bindings(
+        &self,
+        commands: &[CommandInfo],
+        file_commands: &[CommandInfo],
+        analyzer: &CommandAnalyzer,
+        config: &GenerateConfig,
+        shared_module: Option<&str>,
+    ) -> String
+    where
+        Self: TypeScriptFiles + Sized,
+    {
+        let mut command_contexts = self.command_contexts(commands, analyzer, config);
+        // Chunks hold fewer commands than the whole, in their own order
+        if file_commands.len() < commands.len() {
+            let mut contexts: Vec<Option<CommandContext>> =
+                command_contexts.into_iter().map(Some).collect();
+            command_contexts = file_commands
+                .iter()
+                .filter_map(|command| {
+                    let index = commands.iter().position(|other| {
+                        other.name == command.name
+                            && other.file_path == command.file_path
+                            && other.line_number == command.line_number
+                    })?;
+                    contexts[index].take()
+                })
+                .collect();
+        }
+
+        let mut context = Context::new();
+        context.insert("header", &self.generate_file_header());
+        if self.has_schemas() {
+            context.insert("schema_library", &self.schema_library());
+        }
+        context.insert("commands", &command_contexts);
+        context.insert(
+            "has_channels",
+            &file_commands.iter().any(|cmd| !cmd.channels.is_empty()),
+        );
+        context.insert(
+            "has_notifications",
+            &command_contexts.iter().any(|cmd| cmd.is_notification),
+        );
+        context.insert(
+            "has_raw_responses",
+            &command_contexts.iter().any(|cmd| cmd.raw_response),
+        );
+        context.insert(
+            "has_serialized_calls",
+            &command_contexts.iter().any(|cmd| cmd.serialize_calls),
+        );
+        context.insert(
+            "has_cached_calls",
+            &command_contexts
+                .iter()
+                .any(|cmd| cmd.cache_ttl_ms.is_some()),
+        );
+        context.insert(
+            "has_cache_invalidations",
+            &command_contexts
+                .iter()
+                .any(|cmd| !cmd.invalidated_commands.is_empty()),
+        );
+        context.insert(
+            "has_streams",
+            &command_contexts.iter().any(|cmd| cmd.is_stream),
+        );
+        context.insert(
+            "has_progress_channels",
+            &command_contexts
+                .iter()
+                .flat_map(|cmd| &cmd.channels)
+                .any(|channel| channel.progress.is_some()),
+        );
+        context.insert(
+            "has_scheduled_calls",
+            &command_contexts
+                .iter()
+                .any(|cmd| cmd.debounce_ms.is_some() || cmd.throttle_ms.is_some()),
+        );
+        context.insert("batch_notifications", &config.should_batch_notifications());
+        context.insert(
+            "runtime_module",
+            &config
+                .should_generate_web_fallback()
+                .then(|| OutputFileNames::module_path(&config.file_names().runtime)),
+        );
+        context.insert(
+            "types_module",
+            &OutputFileNames::module_path(&config.file_names().types),
+        );
+        context.insert(
+            "compact_module",
+            &command_contexts
+                .iter()
+                .any(|cmd| cmd.compact)
+                .then(|| OutputFileNames::module_path(&config.file_names().compact)),
+        );
+        context.insert(
+            "sanitize_module",
+            &(config.should_generate_sanitize_params()
+                && file_commands
+                    .iter()
+                    .any(|cmd| !cmd.parameters.is_empty() || !cmd.channels.is_empty()))
+            .then(|| OutputFileNames::module_path(&config.file_names().sanitize)),
+        );
+
+        context.insert(
+            "aliases",
+            &aliases::CommandAlias::collect(&command_contexts, config),
+        );
+        context.insert("shared_module", &shared_module);
+
+        self.render(
+            &format!("{}/commands.ts.tera", self.template_dir()),
+            &context,
+        )
+        .unwrap_or_else(|e| {
+            eprintln!("Template rendering failed for commands.ts: {}", e);
+            String::new()
+        })
+    }
+
+    /// Generate the events file of `events`
+    fn generate_events_file(
+        &self,
+        events: &[EventInfo],
+        analyzer: &CommandAnalyzer,
+        config: &GenerateConfig,
+    ) -> String
+    where
+        Self: TypeScriptFiles + Sized,
+    {
+        let event_contexts = self.event_contexts(events, analyzer, config);
+
+        let mut context = Context::new();
+        context.insert("header", &self.generate_file_header());
+        context.insert("events", &event_contexts);
+        context.insert("event_router", &config.should_generate_event_router());
+        context.insert(
+            "types_module",
+            &OutputFileNames::module_path(&config.file_names().types),
+        );
+
+        self.render(&format!("{}/events.ts.tera", self.template_dir()), &context)
+            .unwrap_or_else(|e| {
+                eprintln!("Template rendering failed for events.ts: {}", e);
+                String::new()
+            })
+    }
+
+    /// Generate the index file re-exporting every other generated file
+    fn generate_index_file(&self, generated_files: &[String], index_file: &str) -> String
+    where
+        Self: TypeScriptFiles + Sized,
+    {
+        let modules: Vec<String> = generated_files
+            .iter()
+            .filter(|file| file.as_str() != index_file && !file.ends_with(".d.ts"))
+            .map(|file| OutputFileNames::module_path(file))
+            .collect();
+
+        let mut context = Context::new();
+        context.insert("header", &self.generate_file_header());
+        context.insert("modules", &modules);
+
+        self.render(&format!("{}/index.ts.tera", self.template_dir()), &context)
+            .unwrap_or_else(|e| {
+                eprintln!("Template rendering failed for index.ts: {}", e);
+                String::new()
+            })
+    }
 
     /// Base method for generating the default file header
     fn generate_file_header(&self) -> String {
@@ -54,6 +653,1150 @@ pub trait BaseBindingsGenerator {
           })
     }
 
+    /// Generate runtime helpers (values, guard, parser, labels) for unit enums
+    ///
+    /// Returns `None` when none of the given structs is an enum with only unit variants.
+    fn generate_enum_helpers_file(
+        &self,
+        structs: &[StructContext],
+        config: &GenerateConfig,
+    ) -> Option<String> {
+        let mut enums: Vec<&StructContext> = structs
+            .iter()
+            .filter(|s| {
+                // Variants of tagged enums are objects, not strings
+                s.is_enum
+                    && s.tagged_enum.is_none()
+                    && !s.fields.is_empty()
+                    && s.fields.iter().all(|f| f.rust_type == "enum_variant")
+            })
+            .collect();
+        if enums.is_empty() {
+            return None;
+        }
+        enums.sort_by(|a, b| a.name.cmp(&b.name));
+
+        let mut context = Context::new();
+        context.insert("header", &self.generate_file_header());
+        context.insert("enums", &enums);
+        context.insert(
+            "types_module",
+            &OutputFileNames::module_path(&config.file_names().types),
+        );
+
+        Some(
+            self.render("common/enums.ts.tera", &context)
+                .unwrap_or_else(|e| {
+                    eprintln!("Template rendering failed for enum helpers: {}", e);
+                    String::new()
+                }),
+        )
+    }
+
+    /// Generate the constants file for constants marked with `#[typegen(export)]`
+    ///
+    /// Returns `None` when no constants are exported.
+    fn generate_constants_file(&self, constants: &[ConstInfo]) -> Option<String> {
+        let constants = self.type_collector().create_constant_contexts(constants);
+        if constants.is_empty() {
+            return None;
+        }
+
+        let mut context = Context::new();
+        context.insert("header", &self.generate_file_header());
+        context.insert("constants", &constants);
+
+        Some(
+            self.render("common/constants.ts.tera", &context)
+                .unwrap_or_else(|e| {
+                    eprintln!("Template rendering failed for constants: {}", e);
+                    String::new()
+                }),
+        )
+    }
+
+    /// Generate ambient `declare global` aliases for the generated types
+    ///
+    /// Covers the used structs and enums, command parameter types and `JsonValue`.
+    /// Names clashing with built-in types (`Event`, `Request`, ...) are left out.
+    fn generate_globals_file(
+        &self,
+        commands: &[CommandInfo],
+        used_structs: &HashMap<String, StructInfo>,
+        analyzer: &CommandAnalyzer,
+        config: &GenerateConfig,
+    ) -> String {
+        let visitor = TypeScriptVisitor::with_config(config);
+        let command_contexts = self
+            .type_collector()
+            .create_command_contexts(commands, &visitor, analyzer, config);
+
+        let mut names: Vec<String> = used_structs.keys().cloned().collect();
+        names.sort();
+        if self
+            .type_collector()
+            .uses_json_value(commands, used_structs)
+        {
+            names.push(JSON_VALUE_TYPE.to_string());
+        }
+        names.extend(
+            command_contexts
+                .iter()
+                .filter(|cmd| !cmd.parameters.is_empty() || !cmd.channels.is_empty())
+                .map(|cmd| format!("{}Params", cmd.ts_type_name)),
+        );
+
+        let (shadowed, type_names): (Vec<String>, Vec<String>) = names
+            .into_iter()
+            .partition(|name| BUILTIN_GLOBAL_TYPES.contains(&name.as_str()));
+
+        let mut context = Context::new();
+        context.insert("header", &self.generate_file_header());
+        context.insert("type_names", &type_names);
+        context.insert("shadowed", &shadowed);
+        context.insert(
+            "types_module",
+            &OutputFileNames::module_path(&config.file_names().types),
+        );
+
+        self.render("common/globals.d.ts.tera", &context)
+            .unwrap_or_else(|e| {
+                eprintln!("Template rendering failed for global declarations: {}", e);
+                String::new()
+            })
+    }
+
+    /// Generate XState promise actors for the commands and an event union for the
+    /// discovered Tauri events
+    fn generate_actors_file(
+        &self,
+        commands: &[CommandInfo],
+        analyzer: &CommandAnalyzer,
+        config: &GenerateConfig,
+    ) -> String {
+        let visitor = TypeScriptVisitor::with_config(config);
+        let command_contexts = self
+            .type_collector()
+            .create_command_contexts(commands, &visitor, analyzer, config);
+        let event_contexts = self.type_collector().create_event_contexts(
+            analyzer.get_discovered_events(),
+            &visitor,
+            analyzer,
+            config,
+        );
+
+        let file_names = config.file_names();
+        let mut context = Context::new();
+        context.insert("header", &self.generate_file_header());
+        context.insert("commands", &command_contexts);
+        context.insert("events", &event_contexts);
+        context.insert(
+            "commands_module",
+            &OutputFileNames::module_path(&file_names.commands),
+        );
+        context.insert(
+            "types_module",
+            &OutputFileNames::module_path(&file_names.types),
+        );
+
+        self.render("common/actors.ts.tera", &context)
+            .unwrap_or_else(|e| {
+                eprintln!("Template rendering failed for XState actors: {}", e);
+                String::new()
+            })
+    }
+
+    /// Generate the runtime shim routing `invoke` to HTTP endpoints outside Tauri
+    fn generate_runtime_file(&self, commands: &[CommandInfo]) -> String {
+        let mut command_names: Vec<&str> = commands.iter().map(|cmd| cmd.name.as_str()).collect();
+        command_names.sort();
+        command_names.dedup();
+
+        let mut context = Context::new();
+        context.insert("header", &self.generate_file_header());
+        context.insert("command_names", &command_names);
+
+        self.render("common/runtime.ts.tera", &context)
+            .unwrap_or_else(|e| {
+                eprintln!("Template rendering failed for runtime shim: {}", e);
+                String::new()
+            })
+    }
+
+    /// Generate fast-check arbitraries for the used types and the command parameters
+    fn generate_arbitraries_file(
+        &self,
+        commands: &[CommandInfo],
+        used_structs: &HashMap<String, StructInfo>,
+        analyzer: &CommandAnalyzer,
+        config: &GenerateConfig,
+    ) -> String {
+        let mut builder =
+            arbitraries::ArbitraryBuilder::new(used_structs, config, self.has_schemas());
+        let type_names = used_structs.keys().cloned().collect();
+        let type_arbitraries: Vec<_> = analyzer
+            .topological_sort_types(&type_names)
+            .iter()
+            .filter_map(|name| builder.type_arbitrary(name))
+            .collect();
+
+        let visitor = TypeScriptVisitor::with_config(config);
+        let command_contexts = self
+            .type_collector()
+            .create_command_contexts(commands, &visitor, analyzer, config);
+        let mut seen = HashSet::new();
+        let command_arbitraries: Vec<_> = command_contexts
+            .iter()
+            .filter_map(|command| {
+                let arbitrary = builder.params_arbitrary(command)?;
+                seen.insert(arbitrary.name.clone()).then(|| {
+                    serde_json::json!({
+                        "command": command.name,
+                        "name": arbitrary.name,
+                        "expression": arbitrary.expression,
+                    })
+                })
+            })
+            .collect();
+
+        let mut context = Context::new();
+        context.insert("header", &self.generate_file_header());
+        context.insert("types", &type_arbitraries);
+        context.insert("commands", &command_arbitraries);
+
+        self.render("common/arbitraries.ts.tera", &context)
+            .unwrap_or_else(|e| {
+                eprintln!("Template rendering failed for arbitraries: {}", e);
+                String::new()
+            })
+    }
+
+    /// Generate File/Blob helpers for the commands sending or returning bytes,
+    /// `None` if there are no such commands
+    fn generate_binary_file(
+        &self,
+        commands: &[CommandInfo],
+        analyzer: &CommandAnalyzer,
+        config: &GenerateConfig,
+    ) -> Option<String> {
+        let visitor = TypeScriptVisitor::with_config(config);
+        let command_contexts = self
+            .type_collector()
+            .create_command_contexts(commands, &visitor, analyzer, config);
+        let binary_commands = binary::BinaryCommand::collect(&command_contexts);
+        if binary_commands.is_empty() {
+            return None;
+        }
+
+        let file_names = config.file_names();
+        let mut context = Context::new();
+        context.insert("header", &self.generate_file_header());
+        context.insert("commands", &binary_commands);
+        context.insert(
+            "commands_module",
+            &OutputFileNames::module_path(&file_names.commands),
+        );
+        context.insert(
+            "types_module",
+            &OutputFileNames::module_path(&file_names.types),
+        );
+
+        Some(
+            self.render("common/binary.ts.tera", &context)
+                .unwrap_or_else(|e| {
+                    eprintln!("Template rendering failed for binary helpers: {}", e);
+                    String::new()
+                }),
+        )
+    }
+
+    /// Generate the offline queue and wrappers for the mutation commands,
+    /// `None` if there are none
+    fn generate_offline_file(
+        &self,
+        commands: &[CommandInfo],
+        analyzer: &CommandAnalyzer,
+        config: &GenerateConfig,
+    ) -> Option<String> {
+        let visitor = TypeScriptVisitor::with_config(config);
+        let command_contexts = self
+            .type_collector()
+            .create_command_contexts(commands, &visitor, analyzer, config);
+        let offline_commands = offline::OfflineCommand::collect(&command_contexts);
+        if offline_commands.is_empty() {
+            return None;
+        }
+
+        let file_names = config.file_names();
+        let mut context = Context::new();
+        context.insert("header", &self.generate_file_header());
+        context.insert("commands", &offline_commands);
+        context.insert(
+            "commands_module",
+            &OutputFileNames::module_path(&file_names.commands),
+        );
+        context.insert(
+            "types_module",
+            &OutputFileNames::module_path(&file_names.types),
+        );
+
+        Some(
+            self.render("common/offline.ts.tera", &context)
+                .unwrap_or_else(|e| {
+                    eprintln!("Template rendering failed for offline queue: {}", e);
+                    String::new()
+                }),
+        )
+    }
+
+    /// Generate `sanitizeParams` for the commands taking parameters, `None` if there
+    /// are none
+    fn generate_sanitize_file(
+        &self,
+        commands: &[CommandInfo],
+        analyzer: &CommandAnalyzer,
+        config: &GenerateConfig,
+    ) -> Option<String> {
+        let visitor = TypeScriptVisitor::with_config(config);
+        let command_contexts = self
+            .type_collector()
+            .create_command_contexts(commands, &visitor, analyzer, config);
+        let sanitized_commands = sanitize::SanitizedCommand::collect(&command_contexts);
+        if sanitized_commands.is_empty() {
+            return None;
+        }
+
+        let mut context = Context::new();
+        context.insert("header", &self.generate_file_header());
+        context.insert("commands", &sanitized_commands);
+
+        Some(
+            self.render("common/sanitize.ts.tera", &context)
+                .unwrap_or_else(|e| {
+                    eprintln!("Template rendering failed for sanitizeParams: {}", e);
+                    String::new()
+                }),
+        )
+    }
+
+    /// Generate query key factories for the query commands, `None` if there are none
+    fn generate_query_keys_file(
+        &self,
+        commands: &[CommandInfo],
+        analyzer: &CommandAnalyzer,
+        config: &GenerateConfig,
+    ) -> Option<String> {
+        let visitor = TypeScriptVisitor::with_config(config);
+        let command_contexts = self
+            .type_collector()
+            .create_command_contexts(commands, &visitor, analyzer, config);
+        let query_keys = query_keys::QueryKey::collect(&command_contexts);
+        if query_keys.is_empty() {
+            return None;
+        }
+
+        let mut context = Context::new();
+        context.insert("header", &self.generate_file_header());
+        context.insert("commands", &query_keys);
+        context.insert(
+            "types_module",
+            &OutputFileNames::module_path(&config.file_names().types),
+        );
+
+        Some(
+            self.render("common/query_keys.ts.tera", &context)
+                .unwrap_or_else(|e| {
+                    eprintln!("Template rendering failed for query keys: {}", e);
+                    String::new()
+                }),
+        )
+    }
+
+    /// Schema validating values of `structure`, `None` without schemas
+    fn type_schema(
+        &self,
+        _structure: &TypeStructure,
+        _config: &GenerateConfig,
+    ) -> Option<testing::ValueSchema> {
+        None
+    }
+
+    /// Schema validating the value `command` returns, `None` without schemas
+    fn return_schema(
+        &self,
+        command: &CommandInfo,
+        config: &GenerateConfig,
+    ) -> Option<testing::ValueSchema> {
+        self.type_schema(&command.return_type_structure, config)
+    }
+
+    /// Schema validating the value of a struct field, `None` without schemas
+    fn field_schema(
+        &self,
+        _field: &FieldContext,
+        _config: &GenerateConfig,
+    ) -> Option<testing::ValueSchema> {
+        None
+    }
+
+    /// Generate the typed helpers of the configured settings stores (see `stores`),
+    /// `None` without stores
+    fn generate_stores_file(
+        &self,
+        used_structs: &HashMap<String, StructInfo>,
+        config: &GenerateConfig,
+    ) -> Option<String> {
+        let visitor = TypeScriptVisitor::with_config(config);
+        let case_converter = CaseConverter::new(config.acronyms());
+        let mut schema_imports = BTreeSet::new();
+        let settings_stores: Vec<stores::SettingsStore> = config
+            .stores()
+            .into_iter()
+            .filter_map(|(path, type_name)| {
+                let struct_info = used_structs.get(type_name);
+                let store =
+                    stores::SettingsStore::new(path, struct_info, type_name, &case_converter)
+                        .map_err(|warning| eprintln!("{}", warning))
+                        .ok()?;
+                let fields =
+                    self.type_collector()
+                        .create_field_contexts(struct_info?, &visitor, config);
+                let schemas: HashMap<String, String> = fields
+                    .iter()
+                    .filter_map(|field| {
+                        let schema = self.field_schema(field, config)?;
+                        schema_imports.extend(schema.imports);
+                        Some((field.name.clone(), schema.expression))
+                    })
+                    .collect();
+                Some(store.with_keys(&fields, &schemas))
+            })
+            .collect();
+        if settings_stores.is_empty() {
+            return None;
+        }
+
+        let mut context = Context::new();
+        context.insert("header", &self.generate_file_header());
+        context.insert("stores", &settings_stores);
+        context.insert("schema_library", &self.schema_library());
+        context.insert("schema_imports", &schema_imports);
+        context.insert(
+            "types_module",
+            &OutputFileNames::module_path(&config.file_names().types),
+        );
+
+        Some(
+            self.render("common/stores.ts.tera", &context)
+                .unwrap_or_else(|e| {
+                    eprintln!("Template rendering failed for stores: {}", e);
+                    String::new()
+                }),
+        )
+    }
+
+    /// Generate the encoders, decoders and `invokeCompact` of the compact types (see
+    /// `compactTypes`) and the Rust codec of `Compact<T>`, `None` without compact types
+    fn generate_compact_files(
+        &self,
+        commands: &[CommandInfo],
+        discovered_structs: &HashMap<String, StructInfo>,
+        analyzer: &CommandAnalyzer,
+        config: &GenerateConfig,
+    ) -> Option<(String, String)> {
+        let compact_type_names = config.compact_types();
+        let mut schema_imports = BTreeSet::new();
+        let compact_types: Vec<compact::CompactType> = compact_type_names
+            .iter()
+            .filter_map(|name| {
+                let mut compact_type =
+                    compact::CompactType::new(name, discovered_structs.get(*name))
+                        .map_err(|warning| eprintln!("{}", warning))
+                        .ok()?;
+                if let Some(schema) =
+                    self.type_schema(&TypeStructure::Custom(name.to_string()), config)
+                {
+                    schema_imports.extend(schema.imports);
+                    compact_type.schema = Some(schema.expression);
+                }
+                Some(compact_type)
+            })
+            .collect();
+        if compact_types.is_empty() {
+            return None;
+        }
+
+        let visitor = TypeScriptVisitor::with_config(config);
+        let command_contexts = self
+            .type_collector()
+            .create_command_contexts(commands, &visitor, analyzer, config);
+        let compact_commands: Vec<compact::CompactCommand> = commands
+            .iter()
+            .zip(&command_contexts)
+            .filter_map(|(command, context)| {
+                compact::CompactCommand::new(command, context, &compact_type_names)?
+                    .map_err(|warning| eprintln!("{}", warning))
+                    .ok()
+            })
+            .collect();
+
+        let mut context = Context::new();
+        context.insert("header", &self.generate_file_header());
+        context.insert("types", &compact_types);
+        context.insert("commands", &compact_commands);
+        context.insert("schema_library", &self.schema_library());
+        context.insert("schema_imports", &schema_imports);
+        context.insert(
+            "runtime_module",
+            &config
+                .should_generate_web_fallback()
+                .then(|| OutputFileNames::module_path(&config.file_names().runtime)),
+        );
+        context.insert(
+            "types_module",
+            &OutputFileNames::module_path(&config.file_names().types),
+        );
+        let bindings = self
+            .render("common/compact.ts.tera", &context)
+            .unwrap_or_else(|e| {
+                eprintln!("Template rendering failed for compact codec: {}", e);
+                String::new()
+            });
+
+        let mut context = Context::new();
+        context.insert("version", env!("CARGO_PKG_VERSION"));
+        context.insert("types", &compact_types);
+        let codec = self
+            .render("common/compact.rs.tera", &context)
+            .unwrap_or_else(|e| {
+                eprintln!("Template rendering failed for compact codec: {}", e);
+                String::new()
+            });
+
+        Some((bindings, codec))
+    }
+
+    /// Generate `runBindingsSelfTest()` and the Rust self-test command it sends a sample of
+    /// every type through (see `selfTest`), `None` without a self-test file
+    fn generate_self_test_files(
+        &self,
+        used_structs: &HashMap<String, StructInfo>,
+        analyzer: &CommandAnalyzer,
+        config: &GenerateConfig,
+    ) -> Option<(String, String)> {
+        config.self_test.as_ref()?;
+
+        let examples =
+            examples::ExampleBuilder::complete(analyzer.get_discovered_structs(), config);
+        let mut names: Vec<&String> = used_structs.keys().collect();
+        names.sort();
+        let mut schema_imports = BTreeSet::new();
+        let (tested, skipped): (Vec<self_test::SelfTestType>, Vec<_>) = names
+            .into_iter()
+            .map(|name| {
+                let mut self_test_type = self_test::SelfTestType::new(
+                    &used_structs[name],
+                    analyzer.type_derives(name, SerdeTrait::Deserialize),
+                    analyzer.type_derives(name, SerdeTrait::Serialize),
+                    examples.type_value(name),
+                );
+                if self_test_type.round_trip {
+                    if let Some(schema) =
+                        self.type_schema(&TypeStructure::Custom(name.to_string()), config)
+                    {
+                        schema_imports.extend(schema.imports);
+                        self_test_type.schema = Some(schema.expression);
+                    }
+                }
+                self_test_type
+            })
+            .partition(|self_test_type| self_test_type.skipped.is_none());
+
+        let mut context = Context::new();
+        context.insert("header", &self.generate_file_header());
+        context.insert("command", SELF_TEST_COMMAND);
+        context.insert("tested", &tested);
+        context.insert("skipped", &skipped);
+        context.insert("schema_library", &self.schema_library());
+        context.insert("schema_imports", &schema_imports);
+        context.insert(
+            "runtime_module",
+            &config
+                .should_generate_web_fallback()
+                .then(|| OutputFileNames::module_path(&config.file_names().runtime)),
+        );
+        context.insert(
+            "types_module",
+            &OutputFileNames::module_path(&config.file_names().types),
+        );
+        let bindings = self
+            .render("common/self_test.ts.tera", &context)
+            .unwrap_or_else(|e| {
+                eprintln!("Template rendering failed for self-test: {}", e);
+                String::new()
+            });
+
+        let mut context = Context::new();
+        context.insert("version", env!("CARGO_PKG_VERSION"));
+        context.insert("command", SELF_TEST_COMMAND);
+        context.insert("types", &tested);
+        let command = self
+            .render("common/self_test.rs.tera", &context)
+            .unwrap_or_else(|e| {
+                eprintln!("Template rendering failed for self-test: {}", e);
+                String::new()
+            });
+
+        Some((bindings, command))
+    }
+
+    /// Generate the `mockIPC` test harness with a typed handler per command
+    fn generate_testing_file(
+        &self,
+        commands: &[CommandInfo],
+        analyzer: &CommandAnalyzer,
+        config: &GenerateConfig,
+    ) -> String {
+        let visitor = TypeScriptVisitor::with_config(config);
+        let command_contexts = self
+            .type_collector()
+            .create_command_contexts(commands, &visitor, analyzer, config);
+        let return_schemas: HashMap<String, testing::ValueSchema> = commands
+            .iter()
+            .filter_map(|command| {
+                self.return_schema(command, config)
+                    .map(|schema| (command.name.clone(), schema))
+            })
+            .collect();
+        let handlers = testing::MockHandler::collect(&command_contexts, &return_schemas);
+        let schema_imports: BTreeSet<&String> = handlers
+            .iter()
+            .filter_map(|handler| return_schemas.get(&handler.name))
+            .flat_map(|schema| &schema.imports)
+            .collect();
+        let uses_types = handlers.iter().any(|handler| {
+            handler.has_parameters
+                || templates::add_types_prefix(&handler.return_type_ts).contains("types.")
+        });
+
+        let mut context = Context::new();
+        context.insert("header", &self.generate_file_header());
+        context.insert("handlers", &handlers);
+        context.insert("uses_types", &uses_types);
+        context.insert("schema_imports", &schema_imports);
+        context.insert("schema_library", &self.schema_library());
+        context.insert(
+            "types_module",
+            &OutputFileNames::module_path(&config.file_names().types),
+        );
+
+        self.render("common/testing.ts.tera", &context)
+            .unwrap_or_else(|e| {
+                eprintln!("Template rendering failed for test harness: {}", e);
+                String::new()
+            })
+    }
+
+    /// Generate `mocks.ts` with a mock implementation per command returning a fake value
+    /// of its return type
+    fn generate_mocks_file(
+        &self,
+        commands: &[CommandInfo],
+        analyzer: &CommandAnalyzer,
+        config: &GenerateConfig,
+    ) -> String {
+        let visitor = TypeScriptVisitor::with_config(config);
+        let command_contexts = self
+            .type_collector()
+            .create_command_contexts(commands, &visitor, analyzer, config);
+        let examples =
+            examples::ExampleBuilder::complete(analyzer.get_discovered_structs(), config);
+        let mocks = testing::MockImplementation::collect(commands, &command_contexts, &examples);
+        let uses_types = mocks.iter().any(|mock| {
+            mock.has_parameters
+                || templates::add_types_prefix(&mock.return_type_ts).contains("types.")
+        });
+
+        let mut context = Context::new();
+        context.insert("header", &self.generate_file_header());
+        context.insert("mocks", &mocks);
+        context.insert("uses_types", &uses_types);
+        context.insert(
+            "types_module",
+            &OutputFileNames::module_path(&config.file_names().types),
+        );
+
+        self.render("common/mocks.ts.tera", &context)
+            .unwrap_or_else(|e| {
+                eprintln!("Template rendering failed for mocks: {}", e);
+                String::new()
+            })
+    }
+
+    /// Generate TanStack Query hooks for the commands (`framework: "react-query"`),
+    /// `None` if there are no commands without channels
+    fn generate_react_query_file(
+        &self,
+        commands: &[CommandInfo],
+        analyzer: &CommandAnalyzer,
+        config: &GenerateConfig,
+    ) -> Option<String> {
+        let visitor = TypeScriptVisitor::with_config(config);
+        let command_contexts = self
+            .type_collector()
+            .create_command_contexts(commands, &visitor, analyzer, config);
+        let hooks = react_query::ReactQueryHook::collect(
+            &command_contexts,
+            &CaseConverter::new(config.acronyms()),
+        );
+        if hooks.is_empty() {
+            return None;
+        }
+
+        let file_names = config.file_names();
+        let mut context = Context::new();
+        context.insert("header", &self.generate_file_header());
+        context.insert("hooks", &hooks);
+        context.insert("has_queries", &hooks.iter().any(|hook| hook.is_query));
+        context.insert("has_mutations", &hooks.iter().any(|hook| !hook.is_query));
+        context.insert(
+            "has_invalidations",
+            &hooks.iter().any(|hook| !hook.invalidates.is_empty()),
+        );
+        context.insert(
+            "commands_module",
+            &OutputFileNames::module_path(&file_names.commands),
+        );
+        context.insert(
+            "types_module",
+            &OutputFileNames::module_path(&file_names.types),
+        );
+        context.insert(
+            "query_keys_module",
+            &OutputFileNames::module_path(&file_names.query_keys),
+        );
+
+        Some(
+            self.render("common/react_query.ts.tera", &context)
+                .unwrap_or_else(|e| {
+                    eprintln!("Template rendering failed for React Query hooks: {}", e);
+                    String::new()
+                }),
+        )
+    }
+
+    /// Generate Vue composables for the commands (`framework: "vue"`), `None` if there
+    /// are no commands without channels
+    fn generate_vue_file(
+        &self,
+        commands: &[CommandInfo],
+        analyzer: &CommandAnalyzer,
+        config: &GenerateConfig,
+    ) -> Option<String> {
+        let visitor = TypeScriptVisitor::with_config(config);
+        let command_contexts = self
+            .type_collector()
+            .create_command_contexts(commands, &visitor, analyzer, config);
+        let composables =
+            vue::VueComposable::collect(&command_contexts, &CaseConverter::new(config.acronyms()));
+        if composables.is_empty() {
+            return None;
+        }
+
+        let file_names = config.file_names();
+        let mut context = Context::new();
+        context.insert("header", &self.generate_file_header());
+        context.insert("composables", &composables);
+        context.insert(
+            "commands_module",
+            &OutputFileNames::module_path(&file_names.commands),
+        );
+        context.insert(
+            "types_module",
+            &OutputFileNames::module_path(&file_names.types),
+        );
+
+        Some(
+            self.render("common/vue.ts.tera", &context)
+                .unwrap_or_else(|e| {
+                    eprintln!("Template rendering failed for Vue composables: {}", e);
+                    String::new()
+                }),
+        )
+    }
+
+    /// Generate a Pinia store per Rust module with an action per command
+    fn generate_pinia_file(
+        &self,
+        commands: &[CommandInfo],
+        analyzer: &CommandAnalyzer,
+        config: &GenerateConfig,
+    ) -> Option<String> {
+        let visitor = TypeScriptVisitor::with_config(config);
+        let command_contexts = self
+            .type_collector()
+            .create_command_contexts(commands, &visitor, analyzer, config);
+        let stores =
+            pinia::PiniaStore::collect(&command_contexts, &CaseConverter::new(config.acronyms()));
+        if stores.is_empty() {
+            return None;
+        }
+        let uses_types = stores
+            .iter()
+            .flat_map(|store| &store.actions)
+            .any(|action| {
+                action.has_parameters
+                    || action.result_type.as_ref().is_some_and(|result| {
+                        templates::add_types_prefix(result).contains("types.")
+                    })
+            });
+
+        let file_names = config.file_names();
+        let mut context = Context::new();
+        context.insert("header", &self.generate_file_header());
+        context.insert("stores", &stores);
+        context.insert("uses_types", &uses_types);
+        context.insert(
+            "commands_module",
+            &OutputFileNames::module_path(&file_names.commands),
+        );
+        context.insert(
+            "types_module",
+            &OutputFileNames::module_path(&file_names.types),
+        );
+
+        Some(
+            self.render("common/pinia.ts.tera", &context)
+                .unwrap_or_else(|e| {
+                    eprintln!("Template rendering failed for Pinia stores: {}", e);
+                    String::new()
+                }),
+        )
+    }
+
+    /// Generate Svelte stores of the commands and of the discovered events
+    fn generate_svelte_file(
+        &self,
+        commands: &[CommandInfo],
+        analyzer: &CommandAnalyzer,
+        config: &GenerateConfig,
+    ) -> Option<String> {
+        let visitor = TypeScriptVisitor::with_config(config);
+        let collector = self.type_collector();
+        let command_stores = svelte::SvelteCommandStore::collect(
+            &collector.create_command_contexts(commands, &visitor, analyzer, config),
+        );
+        let event_stores = svelte::SvelteEventStore::collect(
+            &collector.create_event_contexts(
+                analyzer.get_discovered_events(),
+                &visitor,
+                analyzer,
+                config,
+            ),
+            &CaseConverter::new(config.acronyms()),
+        );
+        if command_stores.is_empty() && event_stores.is_empty() {
+            return None;
+        }
+        let uses_types = command_stores.iter().any(|store| store.has_parameters)
+            || event_stores
+                .iter()
+                .any(|store| templates::add_types_prefix(&store.payload_type).contains("types."));
+
+        let file_names = config.file_names();
+        let mut context = Context::new();
+        context.insert("header", &self.generate_file_header());
+        context.insert("command_stores", &command_stores);
+        context.insert("event_stores", &event_stores);
+        context.insert("uses_types", &uses_types);
+        context.insert(
+            "commands_module",
+            &OutputFileNames::module_path(&file_names.commands),
+        );
+        context.insert(
+            "events_module",
+            &OutputFileNames::module_path(&file_names.events),
+        );
+        context.insert(
+            "types_module",
+            &OutputFileNames::module_path(&file_names.types),
+        );
+
+        Some(
+            self.render("common/svelte.ts.tera", &context)
+                .unwrap_or_else(|e| {
+                    eprintln!("Template rendering failed for Svelte stores: {}", e);
+                    String::new()
+                }),
+        )
+    }
+
+    /// Generate the hooks of the configured frontend framework (see `framework`)
+    fn generate_hooks_file(
+        &self,
+        commands: &[CommandInfo],
+        analyzer: &CommandAnalyzer,
+        config: &GenerateConfig,
+    ) -> Option<String> {
+        match config.framework()? {
+            "react-query" => self.generate_react_query_file(commands, analyzer, config),
+            "vue" => self.generate_vue_file(commands, analyzer, config),
+            "svelte" => self.generate_svelte_file(commands, analyzer, config),
+            "pinia" => self.generate_pinia_file(commands, analyzer, config),
+            _ => None,
+        }
+    }
+
+    /// Generate `trace.json`, mapping the exported symbols to their Rust sources
+    fn generate_trace_file(
+        &self,
+        commands: &[CommandInfo],
+        used_structs: &HashMap<String, StructInfo>,
+        analyzer: &CommandAnalyzer,
+        config: &GenerateConfig,
+    ) -> String {
+        let visitor = TypeScriptVisitor::with_config(config);
+        let collector = self.type_collector();
+        let trace = trace::Trace::collect(
+            commands,
+            &collector.create_command_contexts(commands, &visitor, analyzer, config),
+            used_structs,
+            &collector.create_event_contexts(
+                analyzer.get_discovered_events(),
+                &visitor,
+                analyzer,
+                config,
+            ),
+            &collector.create_constant_contexts(analyzer.get_discovered_constants()),
+            // Schemas are exported next to their types
+            self.has_schemas().then_some("Schema"),
+            config,
+        );
+        serde_json::to_string_pretty(&trace).unwrap_or_default()
+    }
+
+    /// Write the command bindings, split into one file per Rust module once there are
+    /// more than `max_commands_per_file` commands (see [`command_chunks::CommandChunk`]).
+    ///
+    /// `render` renders the bindings of the given commands, importing the notify handler
+    /// and `CommandHooks` from the shared module if one is given. Returns the chunk files,
+    /// which the commands file re-exports in place of `index.ts`.
+    fn write_command_files(
+        &self,
+        file_writer: &mut FileWriter,
+        commands: &[CommandInfo],
+        analyzer: &CommandAnalyzer,
+        config: &GenerateConfig,
+        render: &dyn Fn(&[CommandInfo], Option<&str>) -> String,
+    ) -> Result<Vec<String>, Box<dyn std::error::Error>> {
+        for warning in aliases::CommandAlias::warnings(commands, config) {
+            eprintln!("Warning: {}", warning);
+        }
+
+        let file_names = config.file_names();
+        let Some(chunks) = command_chunks::CommandChunk::split(
+            commands,
+            config.max_commands_per_file,
+            &file_names.commands,
+        ) else {
+            file_writer.write_typescript_file(&file_names.commands, &render(commands, None))?;
+            return Ok(Vec::new());
+        };
+
+        let shared_file = command_chunks::CommandChunk::shared_file_name(&file_names.commands);
+        let shared_module = OutputFileNames::module_path(&shared_file);
+        let mut chunk_files = Vec::new();
+        for chunk in &chunks {
+            file_writer.write_typescript_file(
+                &chunk.file_name,
+                &render(&chunk.commands, Some(&shared_module)),
+            )?;
+            chunk_files.push(chunk.file_name.clone());
+        }
+
+        let has_command_hooks = self.has_schemas();
+        let command_contexts = self.type_collector().create_command_contexts(
+            commands,
+            &TypeScriptVisitor::with_config(config),
+            analyzer,
+            config,
+        );
+        let has_notifications = command_contexts.iter().any(|cmd| cmd.is_notification);
+        let has_cached_calls = command_contexts
+            .iter()
+            .any(|cmd| cmd.cache_ttl_ms.is_some());
+        let mut shared_exports = Vec::new();
+        if has_command_hooks {
+            shared_exports.push("type CommandHooks");
+        }
+        if has_notifications {
+            shared_exports.push("setNotifyErrorHandler");
+        }
+        if has_cached_calls {
+            shared_exports.push("invalidate");
+        }
+
+        let mut context = Context::new();
+        context.insert("header", &self.generate_file_header());
+        if !shared_exports.is_empty() {
+            context.insert("has_command_hooks", &has_command_hooks);
+            context.insert("schema_library", &self.generator_type());
+            context.insert("has_notifications", &has_notifications);
+            context.insert("batch_notifications", &config.should_batch_notifications());
+            context.insert("export_notify", &true);
+            context.insert("has_cached_calls", &has_cached_calls);
+            context.insert("export_cache", &true);
+            let shared_content = self
+                .render("common/commands_shared.ts.tera", &context)
+                .unwrap_or_else(|e| {
+                    eprintln!("Template rendering failed for {}: {}", shared_file, e);
+                    String::new()
+                });
+            file_writer.write_typescript_file(&shared_file, &shared_content)?;
+            chunk_files.push(shared_file);
+        }
+
+        let modules: Vec<String> = chunks
+            .iter()
+            .map(|chunk| OutputFileNames::module_path(&chunk.file_name))
+            .collect();
+        context.insert("modules", &modules);
+        context.insert("shared_module", &shared_module);
+        context.insert("shared_exports", &shared_exports);
+        let index_content = self
+            .render("common/commands_index.ts.tera", &context)
+            .unwrap_or_else(|e| {
+                eprintln!(
+                    "Template rendering failed for {}: {}",
+                    file_names.commands, e
+                );
+                String::new()
+            });
+        file_writer.write_typescript_file(&file_names.commands, &index_content)?;
+
+        Ok(chunk_files)
+    }
+
+    /// Generate ES error classes for the error enums commands fail with,
+    /// `None` if no command returns a project enum as its error
+    fn generate_errors_file(
+        &self,
+        commands: &[CommandInfo],
+        used_structs: &HashMap<String, StructInfo>,
+        config: &GenerateConfig,
+    ) -> Option<String> {
+        let visitor = TypeScriptVisitor::with_config(config);
+        let errors = error_classes::ErrorClass::collect(commands, used_structs, &visitor, config);
+        if errors.is_empty() {
+            return None;
+        }
+
+        let mut context = Context::new();
+        context.insert("header", &self.generate_file_header());
+        context.insert(
+            "payload_types",
+            &error_classes::ErrorClass::payload_types(&errors, used_structs),
+        );
+        context.insert("errors", &errors);
+        context.insert(
+            "types_module",
+            &OutputFileNames::module_path(&config.file_names().types),
+        );
+
+        Some(
+            self.render("common/errors.ts.tera", &context)
+                .unwrap_or_else(|e| {
+                    eprintln!("Template rendering failed for error classes: {}", e);
+                    String::new()
+                }),
+        )
+    }
+
+    /// Generate an axum router exposing the commands over HTTP (see [`http_bridge::HttpBridge`])
+    fn generate_http_bridge_file(
+        &self,
+        commands: &[CommandInfo],
+        analyzer: &CommandAnalyzer,
+        config: &GenerateConfig,
+    ) -> String {
+        let visitor = TypeScriptVisitor::with_config(config);
+        let command_contexts = self
+            .type_collector()
+            .create_command_contexts(commands, &visitor, analyzer, config);
+        let bridge = http_bridge::HttpBridge::new(commands, &command_contexts);
+
+        let mut context = Context::new();
+        context.insert("version", env!("CARGO_PKG_VERSION"));
+        context.insert("commands", &bridge.commands);
+        context.insert("skipped", &bridge.skipped);
+
+        self.render("common/http_bridge.rs.tera", &context)
+            .unwrap_or_else(|e| {
+                eprintln!("Template rendering failed for HTTP bridge: {}", e);
+                String::new()
+            })
+    }
+
+    /// Scaffold the React admin panel into `config.admin_panel`, keeping edited scaffolds
+    /// (see [`admin_panel::write_scaffold`])
+    fn write_admin_panel(
+        &self,
+        commands: &[CommandInfo],
+        output_path: &str,
+        analyzer: &CommandAnalyzer,
+        config: &GenerateConfig,
+    ) -> Result<(), Box<dyn std::error::Error>> {
+        let Some(admin_dir) = &config.admin_panel else {
+            return Ok(());
+        };
+        let admin_dir = std::path::Path::new(admin_dir);
+        let visitor = TypeScriptVisitor::with_config(config);
+        let command_contexts = self
+            .type_collector()
+            .create_command_contexts(commands, &visitor, analyzer, config);
+        let admin_commands = admin_panel::AdminCommand::collect(&command_contexts);
+
+        let mut context = Context::new();
+        context.insert("version", env!("CARGO_PKG_VERSION"));
+        context.insert("commands", &admin_commands);
+        let commands_module = format!(
+            "{}/{}",
+            admin_panel::relative_module(admin_dir, std::path::Path::new(output_path)),
+            OutputFileNames::module_path(&config.file_names().commands).trim_start_matches("./")
+        );
+        context.insert("commands_module", &commands_module);
+
+        let mut files = vec![
+            (
+                "ResultView.tsx".to_string(),
+                self.render("common/admin_result_view.tsx.tera", &context)?,
+            ),
+            (
+                "AdminPanel.tsx".to_string(),
+                self.render("common/admin_index.tsx.tera", &context)?,
+            ),
+        ];
+        for command in &admin_commands {
+            context.insert("command", command);
+            files.push((
+                format!("{}.tsx", command.component_name),
+                self.render("common/admin_panel.tsx.tera", &context)?,
+            ));
+        }
+
+        for (file_name, content) in files {
+            if admin_panel::write_scaffold(admin_dir, &file_name, &content)?
+                == admin_panel::ScaffoldWrite::KeptEdited
+            {
+                eprintln!(
+                    "Keeping edited admin panel scaffold {}",
+                    admin_dir.join(&file_name).display()
+                );
+            }
+        }
+        Ok(())
+    }
+
     /// Render a template with the given context
     fn render(&self, template_name: &str, context: &Context) -> Result<String, String> {
         self.tera().render(template_name, context).map_err(|e| {
@@ -113,11 +1856,11 @@ mod tests {
             "mock".to_string()
         }
 
-        fn generate_models(
+        fn write_models(
             &mut self,
+            _file_writer: &mut FileWriter,
             _commands: &[CommandInfo],
             _discovered_structs: &HashMap<String, StructInfo>,
-            _output_path: &str,
             _analyzer: &CommandAnalyzer,
             _config: &GenerateConfig,
         ) -> Result<Vec<String>, Box<dyn std::error::Error>> {
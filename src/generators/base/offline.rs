@@ -0,0 +1,78 @@
+use crate::generators::base::template_context::CommandContext;
+use crate::models::CommandKind;
+use serde::Serialize;
+
+/// Offline wrapper generated for a mutation command, queued while offline and
+/// replayed later
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct OfflineCommand {
+    pub name: String,
+    pub ts_function_name: String,
+    pub ts_type_name: String,
+    pub return_type_ts: String,
+    pub has_parameters: bool,
+}
+
+impl OfflineCommand {
+    /// Offline wrappers for the mutation commands, in command order. Commands with
+    /// channels are left out, as a channel cannot be persisted to the queue.
+    pub fn collect(commands: &[CommandContext]) -> Vec<Self> {
+        commands
+            .iter()
+            .filter(|command| command.kind == CommandKind::Mutation && command.channels.is_empty())
+            .map(|command| Self {
+                name: command.name.clone(),
+                ts_function_name: command.ts_function_name.clone(),
+                ts_type_name: command.ts_type_name.clone(),
+                return_type_ts: command.return_type_ts.clone(),
+                has_parameters: !command.parameters.is_empty(),
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::generators::ts::type_visitor::TypeScriptVisitor;
+    use crate::interface::config::CommandOptions;
+    use crate::models::{CommandInfo, TypeStructure};
+    use crate::GenerateConfig;
+    use std::collections::HashMap;
+
+    fn contexts(config: &GenerateConfig, names: &[&str]) -> Vec<CommandContext> {
+        let visitor = TypeScriptVisitor::with_config(config);
+        let resolver = |_: &str| TypeStructure::default();
+        names
+            .iter()
+            .map(|name| {
+                let command =
+                    CommandInfo::new_for_test(*name, "src/lib.rs", 1, vec![], "()", true, vec![]);
+                CommandContext::new(config).from_command_info(&command, &visitor, &resolver)
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_collects_mutations() {
+        let mut config = GenerateConfig::default();
+        let names = ["get_projects", "create_project", "load_draft"];
+
+        let offline = OfflineCommand::collect(&contexts(&config, &names));
+        let offline: Vec<&str> = offline.iter().map(|cmd| cmd.name.as_str()).collect();
+        assert_eq!(offline, ["create_project"]);
+
+        // `load_draft` writes a draft file despite its name
+        config.command_options = Some(HashMap::from([(
+            "load_draft".to_string(),
+            CommandOptions {
+                kind: Some(CommandKind::Mutation),
+                ..Default::default()
+            },
+        )]));
+        let offline = OfflineCommand::collect(&contexts(&config, &names));
+        let offline: Vec<&str> = offline.iter().map(|cmd| cmd.name.as_str()).collect();
+        assert_eq!(offline, ["create_project", "load_draft"]);
+    }
+}
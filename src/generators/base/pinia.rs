@@ -0,0 +1,101 @@
+use crate::generators::base::casing::CaseConverter;
+use crate::generators::base::command_chunks::CommandChunk;
+use crate::generators::base::template_context::CommandContext;
+use serde::Serialize;
+use std::collections::BTreeMap;
+
+/// Pinia store of the commands of a Rust module, e.g. `useUserStore` with id `user` for
+/// `src/commands/user.rs`
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PiniaStore {
+    /// Store id, the module name
+    pub id: String,
+    pub store_name: String,
+    /// Name of the interface of the latest results, e.g. `UserResults`
+    pub results_name: String,
+    pub actions: Vec<PiniaAction>,
+}
+
+/// Action of a Pinia store calling a command, keeping its latest result in the store
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PiniaAction {
+    pub ts_function_name: String,
+    pub ts_type_name: String,
+    pub has_parameters: bool,
+    /// TypeScript type of the result, `None` for commands returning `()`
+    pub result_type: Option<String>,
+}
+
+impl PiniaStore {
+    /// One store per Rust module, ordered by module, with actions for the commands
+    /// without channels, which stream their results instead
+    pub fn collect(commands: &[CommandContext], case_converter: &CaseConverter) -> Vec<Self> {
+        let mut modules: BTreeMap<String, Vec<PiniaAction>> = BTreeMap::new();
+        for command in commands
+            .iter()
+            .filter(|command| command.channels.is_empty())
+        {
+            modules
+                .entry(CommandChunk::module_name(&command.file_path))
+                .or_default()
+                .push(PiniaAction {
+                    ts_function_name: command.ts_function_name.clone(),
+                    ts_type_name: command.ts_type_name.clone(),
+                    has_parameters: !command.parameters.is_empty(),
+                    result_type: (command.return_type_ts != "void")
+                        .then(|| command.return_type_ts.clone()),
+                });
+        }
+
+        modules
+            .into_iter()
+            .map(|(module, actions)| {
+                let name = CaseConverter::identifier(case_converter.to_pascal_case(&module));
+                Self {
+                    store_name: format!("use{}Store", name),
+                    results_name: format!("{}Results", name),
+                    id: module,
+                    actions,
+                }
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::generators::ts::type_visitor::TypeScriptVisitor;
+    use crate::models::{CommandInfo, TypeStructure};
+    use crate::GenerateConfig;
+
+    #[test]
+    fn test_stores_by_module() {
+        let config = GenerateConfig::default();
+        let visitor = TypeScriptVisitor::with_config(&config);
+        let resolver = |_: &str| TypeStructure::default();
+        let contexts: Vec<CommandContext> = [
+            ("get_user", "src/commands/user.rs", "String"),
+            ("save_user", "src/commands/user.rs", "()"),
+            ("get_stats", "src/admin_stats/mod.rs", "u32"),
+        ]
+        .iter()
+        .map(|(name, file, return_type)| {
+            let command =
+                CommandInfo::new_for_test(*name, *file, 1, vec![], *return_type, true, vec![]);
+            CommandContext::new(&config).from_command_info(&command, &visitor, &resolver)
+        })
+        .collect();
+
+        let stores = PiniaStore::collect(&contexts, &CaseConverter::new(&[]));
+        assert_eq!(stores.len(), 2);
+        assert_eq!(stores[0].id, "admin_stats");
+        assert_eq!(stores[0].store_name, "useAdminStatsStore");
+        assert_eq!(stores[0].actions[0].result_type.as_deref(), Some("number"));
+        assert_eq!(stores[1].results_name, "UserResults");
+        assert_eq!(stores[1].actions[0].ts_function_name, "getUser");
+        assert_eq!(stores[1].actions[1].result_type, None);
+    }
+}
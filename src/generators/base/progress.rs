@@ -0,0 +1,139 @@
+use crate::generators::base::casing::is_valid_identifier;
+use crate::generators::base::template_context::NamingContext;
+use crate::models::{StructInfo, TypeStructure};
+use serde::{Deserialize, Serialize};
+
+/// Serialized names of the fields a channel's progress messages report how far
+/// along they are by, so a wrapper can compute the percent done and time left
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ProgressFields {
+    pub current: String,
+    pub total: String,
+}
+
+impl ProgressFields {
+    /// Progress fields of message struct `message`: its numeric `current` and `total`
+    /// fields, or its first two numeric fields if it is annotated `#[typegen(progress)]`
+    pub fn of<N: NamingContext>(message: &StructInfo, naming: &N) -> Option<Self> {
+        if message.is_enum || message.is_newtype {
+            return None;
+        }
+
+        let numeric: Vec<String> = message
+            .fields
+            .iter()
+            .filter(|field| field.type_structure == TypeStructure::Primitive("number".to_string()))
+            .map(|field| {
+                naming.compute_field_name(
+                    &field.name,
+                    &field.serde_rename,
+                    &message.serde_rename_all,
+                )
+            })
+            .filter(|name| is_valid_identifier(name))
+            .collect();
+
+        let named = |name: &str| numeric.iter().find(|field| *field == name).cloned();
+        if let (Some(current), Some(total)) = (named("current"), named("total")) {
+            return Some(Self { current, total });
+        }
+        match numeric.as_slice() {
+            [current, total, ..] if message.is_progress => Some(Self {
+                current: current.clone(),
+                total: total.clone(),
+            }),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::generators::base::template_context::ChannelContext;
+    use crate::models::FieldInfo;
+    use crate::GenerateConfig;
+
+    fn field(name: &str, type_name: &str) -> FieldInfo {
+        FieldInfo {
+            name: name.to_string(),
+            rust_type: type_name.to_string(),
+            is_optional: false,
+            is_public: true,
+            validator_attributes: None,
+            serde_rename: None,
+            type_structure: TypeStructure::Primitive(type_name.to_string()),
+            variant_fields: Vec::new(),
+            literal: None,
+            flatten: false,
+            omittable: false,
+            defaulted: false,
+            skip_serializing_if: false,
+            skipped: None,
+            serde_with: None,
+        }
+    }
+
+    fn message(fields: Vec<FieldInfo>, is_progress: bool) -> StructInfo {
+        StructInfo {
+            name: "Progress".to_string(),
+            fields,
+            file_path: "lib.rs".to_string(),
+            line_number: 1,
+            is_enum: false,
+            serde_rename_all: None,
+            is_newtype: false,
+            serde_tag: None,
+            serde_content: None,
+            serde_untagged: false,
+            is_progress,
+            serde_deny_unknown_fields: false,
+        }
+    }
+
+    #[test]
+    fn test_progress_fields_by_name_or_annotation() {
+        let config = GenerateConfig::default();
+        let naming = ChannelContext::new(&config);
+
+        let named = message(
+            vec![
+                field("label", "string"),
+                field("total", "number"),
+                field("current", "number"),
+            ],
+            false,
+        );
+        assert_eq!(
+            ProgressFields::of(&named, &naming),
+            Some(ProgressFields {
+                current: "current".to_string(),
+                total: "total".to_string(),
+            })
+        );
+
+        let fields = vec![
+            field("label", "string"),
+            field("bytes_done", "number"),
+            field("bytes_total", "number"),
+        ];
+        assert_eq!(
+            ProgressFields::of(&message(fields.clone(), false), &naming),
+            None
+        );
+        assert_eq!(
+            ProgressFields::of(&message(fields, true), &naming),
+            Some(ProgressFields {
+                current: "bytes_done".to_string(),
+                total: "bytes_total".to_string(),
+            })
+        );
+
+        // Annotated, but without two numeric fields
+        let single = message(
+            vec![field("done", "number"), field("label", "string")],
+            true,
+        );
+        assert_eq!(ProgressFields::of(&single, &naming), None);
+    }
+}
@@ -0,0 +1,63 @@
+use crate::generators::base::template_context::CommandContext;
+use crate::models::CommandKind;
+use serde::Serialize;
+
+/// Query key factory generated for a query command.
+///
+/// Keys are built from the Rust command name and the serialized parameters only, so
+/// they stay the same across regenerations as long as the command's IPC contract does.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct QueryKey {
+    pub name: String,
+    pub ts_function_name: String,
+    pub ts_type_name: String,
+    pub has_parameters: bool,
+}
+
+impl QueryKey {
+    /// Key factories for the query commands, ordered by command name so reordered
+    /// source files do not reorder the generated file. Commands with channels stream
+    /// their results and are left out.
+    pub fn collect(commands: &[CommandContext]) -> Vec<Self> {
+        let mut keys: Vec<Self> = commands
+            .iter()
+            .filter(|command| command.kind == CommandKind::Query && command.channels.is_empty())
+            .map(|command| Self {
+                name: command.name.clone(),
+                ts_function_name: command.ts_function_name.clone(),
+                ts_type_name: command.ts_type_name.clone(),
+                has_parameters: !command.parameters.is_empty(),
+            })
+            .collect();
+        keys.sort_by(|a, b| a.name.cmp(&b.name));
+        keys
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::generators::ts::type_visitor::TypeScriptVisitor;
+    use crate::models::{CommandInfo, TypeStructure};
+    use crate::GenerateConfig;
+
+    #[test]
+    fn test_collects_queries_by_name() {
+        let config = GenerateConfig::default();
+        let visitor = TypeScriptVisitor::with_config(&config);
+        let resolver = |_: &str| TypeStructure::default();
+        let contexts: Vec<CommandContext> = ["list_users", "save_user", "get_user"]
+            .iter()
+            .map(|name| {
+                let command =
+                    CommandInfo::new_for_test(*name, "src/lib.rs", 1, vec![], "()", true, vec![]);
+                CommandContext::new(&config).from_command_info(&command, &visitor, &resolver)
+            })
+            .collect();
+
+        let keys = QueryKey::collect(&contexts);
+        let names: Vec<&str> = keys.iter().map(|key| key.name.as_str()).collect();
+        assert_eq!(names, ["get_user", "list_users"]);
+    }
+}
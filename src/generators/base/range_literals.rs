@@ -0,0 +1,151 @@
+use crate::models::{FieldInfo, TypeStructure};
+use crate::GenerateConfig;
+use serde::{Deserialize, Serialize};
+
+const INTEGER_TYPES: &[&str] = &[
+    "u8", "u16", "u32", "u64", "usize", "i8", "i16", "i32", "i64", "isize",
+];
+
+/// Values an integer field is limited to by its `range` validator, spelled out as
+/// literal types when the range is small enough (see `range_literals`)
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RangeLiterals {
+    pub values: Vec<i64>,
+}
+
+impl RangeLiterals {
+    /// Literals of field `field` of type `type_name`, `None` unless it is an integer
+    /// with a `range` validator of at most the configured number of values
+    pub fn of(type_name: &str, field: &FieldInfo, config: &GenerateConfig) -> Option<Self> {
+        let limit = config.range_literal_limit(type_name, &field.name)?;
+        let rust_type: String = field.rust_type.split_whitespace().collect();
+        let rust_type = rust_type
+            .strip_prefix("Option<")
+            .and_then(|inner| inner.strip_suffix('>'))
+            .unwrap_or(&rust_type);
+        let is_number = match &field.type_structure {
+            TypeStructure::Optional(inner) => **inner == number(),
+            type_structure => *type_structure == number(),
+        };
+        if !INTEGER_TYPES.contains(&rust_type) || !is_number {
+            return None;
+        }
+
+        let range = field.validator_attributes.as_ref()?.range.as_ref()?;
+        let (min, max) = (range.min?, range.max?);
+        if min.fract() != 0.0 || max.fract() != 0.0 || min > max || max - min >= limit as f64 {
+            return None;
+        }
+        Some(Self {
+            values: (min as i64..=max as i64).collect(),
+        })
+    }
+
+    /// TypeScript union of the literals, e.g. `0 | 1 | 2`
+    pub fn union(&self) -> String {
+        self.literals().join(" | ")
+    }
+
+    /// Zod schema accepting exactly the literals
+    pub fn zod_schema(&self) -> String {
+        let schemas: Vec<String> = self
+            .literals()
+            .iter()
+            .map(|literal| format!("z.literal({})", literal))
+            .collect();
+        match schemas.as_slice() {
+            [schema] => schema.clone(),
+            _ => format!("z.union([{}])", schemas.join(", ")),
+        }
+    }
+
+    /// Valibot schema accepting exactly the literals
+    pub fn valibot_schema(&self) -> String {
+        format!("v.picklist([{}])", self.literals().join(", "))
+    }
+
+    fn literals(&self) -> Vec<String> {
+        self.values.iter().map(i64::to_string).collect()
+    }
+}
+
+fn number() -> TypeStructure {
+    TypeStructure::Primitive("number".to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::{RangeConstraint, ValidatorAttributes};
+    use std::collections::HashMap;
+
+    fn field(name: &str, rust_type: &str, min: f64, max: f64) -> FieldInfo {
+        let type_structure = if rust_type.starts_with("Option<") {
+            TypeStructure::Optional(Box::new(number()))
+        } else {
+            number()
+        };
+        FieldInfo {
+            name: name.to_string(),
+            rust_type: rust_type.to_string(),
+            is_optional: rust_type.starts_with("Option<"),
+            is_public: true,
+            validator_attributes: Some(ValidatorAttributes {
+                range: Some(RangeConstraint {
+                    min: Some(min),
+                    max: Some(max),
+                    message: None,
+                }),
+                ..Default::default()
+            }),
+            serde_rename: None,
+            type_structure,
+            variant_fields: Vec::new(),
+            literal: None,
+            flatten: false,
+            omittable: false,
+            defaulted: false,
+            skip_serializing_if: false,
+            skipped: None,
+            serde_with: None,
+        }
+    }
+
+    #[test]
+    fn test_small_integer_ranges_become_literals() {
+        let config = GenerateConfig {
+            range_literals: Some(HashMap::from([
+                ("*".to_string(), 4),
+                ("Settings.volume".to_string(), 0),
+            ])),
+            ..Default::default()
+        };
+
+        let level = RangeLiterals::of("Settings", &field("level", "u8", 0.0, 3.0), &config)
+            .expect("range of four values");
+        assert_eq!(level.union(), "0 | 1 | 2 | 3");
+        assert_eq!(
+            level.zod_schema(),
+            "z.union([z.literal(0), z.literal(1), z.literal(2), z.literal(3)])"
+        );
+        assert_eq!(level.valibot_schema(), "v.picklist([0, 1, 2, 3])");
+
+        let offset = RangeLiterals::of(
+            "Settings",
+            &field("offset", "Option<i8>", -1.0, 1.0),
+            &config,
+        );
+        assert_eq!(offset.map(|literals| literals.values), Some(vec![-1, 0, 1]));
+
+        // Too many values, not an integer, or disabled for the field
+        assert!(RangeLiterals::of("Settings", &field("level", "u8", 0.0, 4.0), &config).is_none());
+        assert!(RangeLiterals::of("Settings", &field("ratio", "f32", 0.0, 1.0), &config).is_none());
+        assert!(RangeLiterals::of("Settings", &field("volume", "u8", 0.0, 1.0), &config).is_none());
+        assert!(RangeLiterals::of(
+            "Settings",
+            &field("level", "u8", 0.0, 3.0),
+            &Default::default()
+        )
+        .is_none());
+    }
+}
@@ -0,0 +1,90 @@
+use crate::generators::base::casing::CaseConverter;
+use crate::generators::base::query_keys::QueryKey;
+use crate::generators::base::template_context::CommandContext;
+use crate::models::CommandKind;
+use serde::Serialize;
+use std::collections::HashSet;
+
+/// TanStack Query hook generated for a command: `useGetUserQuery` for queries,
+/// `useSaveUserMutation` for mutations
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ReactQueryHook {
+    pub name: String,
+    pub hook_name: String,
+    pub ts_function_name: String,
+    pub ts_type_name: String,
+    pub return_type_ts: String,
+    pub is_query: bool,
+    pub has_parameters: bool,
+    /// Function names of the queries whose keys a successful mutation invalidates
+    pub invalidates: Vec<String>,
+}
+
+impl ReactQueryHook {
+    /// Hooks for the commands without channels, which stream their results instead
+    pub fn collect(commands: &[CommandContext], case_converter: &CaseConverter) -> Vec<Self> {
+        // Only queries with a key factory can be invalidated
+        let query_keys = QueryKey::collect(commands);
+        let keyed_queries: HashSet<&str> = query_keys
+            .iter()
+            .map(|key| key.ts_function_name.as_str())
+            .collect();
+        commands
+            .iter()
+            .filter(|command| command.channels.is_empty())
+            .map(|command| {
+                let is_query = command.kind == CommandKind::Query;
+                Self {
+                    name: command.name.clone(),
+                    hook_name: format!(
+                        "use{}{}",
+                        case_converter.to_pascal_case(&command.ts_function_name),
+                        if is_query { "Query" } else { "Mutation" }
+                    ),
+                    ts_function_name: command.ts_function_name.clone(),
+                    ts_type_name: command.ts_type_name.clone(),
+                    return_type_ts: command.return_type_ts.clone(),
+                    is_query,
+                    has_parameters: !command.parameters.is_empty(),
+                    invalidates: command
+                        .invalidates
+                        .iter()
+                        .filter(|query| !is_query && keyed_queries.contains(query.as_str()))
+                        .cloned()
+                        .collect(),
+                }
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::generators::ts::type_visitor::TypeScriptVisitor;
+    use crate::models::{CommandInfo, TypeStructure};
+    use crate::GenerateConfig;
+
+    #[test]
+    fn test_hooks_by_command_kind() {
+        let config = GenerateConfig::default();
+        let visitor = TypeScriptVisitor::with_config(&config);
+        let resolver = |_: &str| TypeStructure::default();
+        let mut contexts: Vec<CommandContext> = ["get_user", "save_user"]
+            .iter()
+            .map(|name| {
+                let command =
+                    CommandInfo::new_for_test(*name, "src/lib.rs", 1, vec![], "()", true, vec![]);
+                CommandContext::new(&config).from_command_info(&command, &visitor, &resolver)
+            })
+            .collect();
+        contexts[1].invalidates = vec!["getUser".to_string()];
+
+        let hooks = ReactQueryHook::collect(&contexts, &CaseConverter::new(&[]));
+        assert_eq!(hooks[0].hook_name, "useGetUserQuery");
+        assert!(hooks[0].is_query);
+        assert_eq!(hooks[1].hook_name, "useSaveUserMutation");
+        assert_eq!(hooks[1].invalidates, ["getUser"]);
+    }
+}
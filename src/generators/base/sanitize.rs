@@ -0,0 +1,142 @@
+use crate::generators::base::template_context::{CommandContext, ParameterContext};
+use crate::models::TypeStructure;
+use serde::Serialize;
+use std::collections::HashSet;
+
+/// Parameters a command takes, the keys `sanitizeParams` keeps
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SanitizedCommand {
+    pub name: String,
+    pub parameters: Vec<SanitizedParameter>,
+}
+
+/// A parameter by its serialized name, with the kind its value is coerced to
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SanitizedParameter {
+    pub name: String,
+    /// `string`, `number` or `boolean`; `other` values are passed unchanged
+    pub kind: &'static str,
+}
+
+impl SanitizedCommand {
+    /// The commands taking parameters or channels, in command order. The first
+    /// command wins when several share a name.
+    pub fn collect(commands: &[CommandContext]) -> Vec<Self> {
+        let mut seen = HashSet::new();
+        commands
+            .iter()
+            .filter(|command| !command.parameters.is_empty() || !command.channels.is_empty())
+            .filter(|command| seen.insert(command.name.clone()))
+            .map(|command| Self {
+                name: command.name.clone(),
+                parameters: command
+                    .parameters
+                    .iter()
+                    .map(SanitizedParameter::new)
+                    .chain(command.channels.iter().map(|channel| SanitizedParameter {
+                        name: channel.serialized_parameter_name.clone(),
+                        kind: "other",
+                    }))
+                    .collect(),
+            })
+            .collect()
+    }
+}
+
+impl SanitizedParameter {
+    fn new(param: &ParameterContext) -> Self {
+        let structure = match &param.type_structure {
+            TypeStructure::Optional(inner) => inner.as_ref(),
+            structure => structure,
+        };
+        let kind = match structure {
+            _ if param.is_path => "string",
+            TypeStructure::Primitive(primitive) => match primitive.as_str() {
+                "string" => "string",
+                "number" => "number",
+                "boolean" => "boolean",
+                _ => "other",
+            },
+            _ => "other",
+        };
+        Self {
+            name: param.serialized_name.clone(),
+            kind,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::generators::ts::type_visitor::TypeScriptVisitor;
+    use crate::models::{CommandInfo, ParameterInfo};
+    use crate::GenerateConfig;
+
+    fn parameter(name: &str, rust_type: &str, type_structure: TypeStructure) -> ParameterInfo {
+        ParameterInfo {
+            name: name.to_string(),
+            rust_type: rust_type.to_string(),
+            is_optional: false,
+            type_structure,
+            serde_rename: None,
+        }
+    }
+
+    #[test]
+    fn test_collects_parameter_kinds() {
+        let config = GenerateConfig::default();
+        let visitor = TypeScriptVisitor::with_config(&config);
+        let resolver = |_: &str| TypeStructure::default();
+        let commands = [
+            CommandInfo::new_for_test(
+                "update_user",
+                "src/lib.rs",
+                1,
+                vec![
+                    parameter(
+                        "user_id",
+                        "u32",
+                        TypeStructure::Primitive("number".to_string()),
+                    ),
+                    parameter(
+                        "active",
+                        "Option<bool>",
+                        TypeStructure::Optional(Box::new(TypeStructure::Primitive(
+                            "boolean".to_string(),
+                        ))),
+                    ),
+                    parameter("user", "User", TypeStructure::Custom("User".to_string())),
+                ],
+                "()",
+                false,
+                vec![],
+            ),
+            CommandInfo::new_for_test("ping", "src/lib.rs", 5, vec![], "()", false, vec![]),
+        ];
+        let contexts: Vec<CommandContext> = commands
+            .iter()
+            .map(|command| {
+                CommandContext::new(&config).from_command_info(command, &visitor, &resolver)
+            })
+            .collect();
+
+        let sanitized = SanitizedCommand::collect(&contexts);
+        assert_eq!(sanitized.len(), 1);
+        let kinds: Vec<(&str, &str)> = sanitized[0]
+            .parameters
+            .iter()
+            .map(|param| (param.name.as_str(), param.kind))
+            .collect();
+        assert_eq!(
+            kinds,
+            [
+                ("userId", "number"),
+                ("active", "boolean"),
+                ("user", "other")
+            ]
+        );
+    }
+}
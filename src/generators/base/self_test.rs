@@ -0,0 +1,103 @@
+use crate::generators::base::http_bridge::HttpBridge;
+use crate::models::StructInfo;
+use serde::Serialize;
+
+/// A type whose sample `runBindingsSelfTest()` sends through the generated self-test
+/// command, or the reason it is skipped
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SelfTestType {
+    pub name: String,
+    /// Path of the Rust type, e.g. `crate::models::User`
+    pub rust_path: String,
+    /// Sample value as a TypeScript literal
+    pub sample: String,
+    /// Whether the command sends the value back, the type deriving `Serialize`
+    pub round_trip: bool,
+    /// Schema validating the value sent back
+    pub schema: Option<String>,
+    /// Why no sample is sent
+    pub skipped: Option<String>,
+}
+
+impl SelfTestType {
+    /// Self-test of a type given whether it derives `Deserialize` and `Serialize` (`None`
+    /// when unknown) and its sample, skipped when the command cannot name or
+    /// deserialize the type or no sample can be synthesized
+    pub fn new(
+        struct_info: &StructInfo,
+        deserialize: Option<bool>,
+        serialize: Option<bool>,
+        sample: Option<String>,
+    ) -> Self {
+        let rust_path = HttpBridge::module_path(&struct_info.file_path)
+            .map(|module| format!("{}::{}", module, struct_info.name));
+        let skipped = if rust_path.is_none() {
+            Some("not defined in the library crate")
+        } else if deserialize != Some(true) {
+            Some("does not derive Deserialize")
+        } else if sample.is_none() {
+            Some("no sample value can be synthesized")
+        } else {
+            None
+        };
+        Self {
+            name: struct_info.name.clone(),
+            rust_path: rust_path.unwrap_or_default(),
+            sample: sample.unwrap_or_default(),
+            round_trip: serialize == Some(true),
+            schema: None,
+            skipped: skipped.map(str::to_string),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn type_info(name: &str, file_path: &str) -> StructInfo {
+        StructInfo {
+            name: name.to_string(),
+            fields: vec![],
+            file_path: file_path.to_string(),
+            is_enum: false,
+            serde_rename_all: None,
+            is_newtype: false,
+            serde_tag: None,
+            serde_content: None,
+            serde_untagged: false,
+            line_number: 1,
+            is_progress: false,
+            serde_deny_unknown_fields: false,
+        }
+    }
+
+    #[test]
+    fn test_self_test_types() {
+        let sample = || Some("{}".to_string());
+        let user = type_info("User", "src-tauri/src/models/user.rs");
+
+        let tested = SelfTestType::new(&user, Some(true), Some(true), sample());
+        assert_eq!(tested.rust_path, "crate::models::user::User");
+        assert!(tested.round_trip);
+        assert_eq!(tested.skipped, None);
+        assert!(!SelfTestType::new(&user, Some(true), Some(false), sample()).round_trip);
+
+        let skipped = |struct_info: &StructInfo, deserialize, sample| {
+            SelfTestType::new(struct_info, deserialize, Some(true), sample).skipped
+        };
+        assert_eq!(
+            skipped(&user, Some(false), sample()).as_deref(),
+            Some("does not derive Deserialize")
+        );
+        assert_eq!(
+            skipped(&user, Some(true), None).as_deref(),
+            Some("no sample value can be synthesized")
+        );
+        assert_eq!(
+            skipped(&type_info("User", "src/bin/tool.rs"), Some(true), sample()).as_deref(),
+            Some("not defined in the library crate")
+        );
+    }
+}
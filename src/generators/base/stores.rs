@@ -0,0 +1,155 @@
+use crate::generators::base::casing::CaseConverter;
+use crate::generators::base::template_context::FieldContext;
+use crate::models::StructInfo;
+use serde::Serialize;
+use std::collections::HashMap;
+
+/// Typed helpers generated for a tauri-plugin-store file, e.g. `appSettingsStore` for
+/// `{"settings.json": "AppSettings"}`
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SettingsStore {
+    /// Store file loaded by the plugin
+    pub path: String,
+    pub type_name: String,
+    pub store_name: String,
+    /// Serialized names of the struct fields, the keys of the store
+    pub keys: Vec<StoreKey>,
+}
+
+/// Key of a settings store with the schema validating its value
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct StoreKey {
+    pub name: String,
+    pub schema: Option<String>,
+}
+
+impl SettingsStore {
+    /// Store of `path` keyed by the fields of `struct_info`, or the warning why no
+    /// helpers are generated when the type is missing or not a struct with named fields
+    pub fn new(
+        path: &str,
+        struct_info: Option<&StructInfo>,
+        type_name: &str,
+        case_converter: &CaseConverter,
+    ) -> Result<Self, String> {
+        let struct_info = struct_info.ok_or_else(|| {
+            format!(
+                "Warning: type {} of store {} was not found, no helpers are generated for it",
+                type_name, path
+            )
+        })?;
+        if struct_info.is_enum || struct_info.is_newtype {
+            return Err(format!(
+                "Warning: type {} of store {} is not a struct with named fields, no helpers are generated for it",
+                type_name, path
+            ));
+        }
+        Ok(Self {
+            path: path.to_string(),
+            type_name: type_name.to_string(),
+            store_name: format!("{}Store", case_converter.to_camel_case(type_name)),
+            keys: Vec::new(),
+        })
+    }
+
+    /// Add the keys of the store from its field contexts, with their schemas by field name
+    pub fn with_keys(mut self, fields: &[FieldContext], schemas: &HashMap<String, String>) -> Self {
+        self.keys = fields
+            .iter()
+            .map(|field| StoreKey {
+                name: field.serialized_name.clone(),
+                schema: schemas.get(&field.name).cloned(),
+            })
+            .collect();
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::generators::ts::type_visitor::TypeScriptVisitor;
+    use crate::generators::TypeCollector;
+    use crate::models::FieldInfo;
+    use crate::GenerateConfig;
+    use serde_rename_rule::RenameRule;
+
+    fn settings(is_enum: bool) -> StructInfo {
+        let field = |name: &str| FieldInfo {
+            name: name.to_string(),
+            rust_type: "String".to_string(),
+            is_optional: false,
+            is_public: true,
+            validator_attributes: None,
+            serde_rename: None,
+            type_structure: Default::default(),
+            variant_fields: vec![],
+            literal: None,
+            flatten: false,
+            omittable: false,
+            defaulted: false,
+            skip_serializing_if: false,
+            skipped: None,
+            serde_with: None,
+        };
+        StructInfo {
+            name: "AppSettings".to_string(),
+            fields: vec![field("theme"), field("font_size")],
+            file_path: "settings.rs".to_string(),
+            line_number: 1,
+            is_enum,
+            serde_rename_all: Some(RenameRule::CamelCase),
+            is_newtype: false,
+            serde_tag: None,
+            serde_content: None,
+            serde_untagged: false,
+            is_progress: false,
+            serde_deny_unknown_fields: false,
+        }
+    }
+
+    #[test]
+    fn test_store_keys_are_serialized_field_names() {
+        let config = GenerateConfig::default();
+        let struct_info = settings(false);
+        let fields = TypeCollector::new().create_field_contexts(
+            &struct_info,
+            &TypeScriptVisitor::with_config(&config),
+            &config,
+        );
+        let schemas = HashMap::from([("theme".to_string(), "z.string()".to_string())]);
+
+        let store = SettingsStore::new(
+            "settings.json",
+            Some(&struct_info),
+            "AppSettings",
+            &CaseConverter::new(&[]),
+        )
+        .unwrap()
+        .with_keys(&fields, &schemas);
+        assert_eq!(store.store_name, "appSettingsStore");
+        assert_eq!(store.keys[0].schema.as_deref(), Some("z.string()"));
+        assert_eq!(store.keys[1].name, "fontSize");
+        assert_eq!(store.keys[1].schema, None);
+    }
+
+    #[test]
+    fn test_enum_stores_rejected() {
+        let struct_info = settings(true);
+        let error = SettingsStore::new(
+            "settings.json",
+            Some(&struct_info),
+            "AppSettings",
+            &CaseConverter::new(&[]),
+        )
+        .unwrap_err();
+        assert!(error.contains("not a struct with named fields"));
+        assert!(
+            SettingsStore::new("settings.json", None, "Missing", &CaseConverter::new(&[]))
+                .unwrap_err()
+                .contains("was not found")
+        );
+    }
+}
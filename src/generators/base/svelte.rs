@@ -0,0 +1,130 @@
+use crate::generators::base::casing::CaseConverter;
+use crate::generators::base::template_context::{CommandContext, EventContext};
+use crate::models::CommandKind;
+use serde::Serialize;
+use std::collections::HashSet;
+
+/// Svelte store generated for a command, e.g. `getUserStore`: queries run when the store
+/// is created, mutations run on `execute`
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SvelteCommandStore {
+    pub name: String,
+    pub store_name: String,
+    pub ts_function_name: String,
+    pub ts_type_name: String,
+    pub is_query: bool,
+    pub has_parameters: bool,
+    /// Whether mutations refetch the query's subscribed stores once they succeed
+    pub invalidated: bool,
+    /// Function names of the queries a successful mutation refetches
+    pub invalidates: Vec<String>,
+}
+
+impl SvelteCommandStore {
+    /// Stores for the commands without channels, which stream their results instead
+    pub fn collect(commands: &[CommandContext]) -> Vec<Self> {
+        let invalidated = CommandContext::invalidated_queries(commands);
+        commands
+            .iter()
+            .filter(|command| command.channels.is_empty())
+            .map(|command| Self {
+                name: command.name.clone(),
+                store_name: format!("{}Store", command.ts_function_name),
+                ts_function_name: command.ts_function_name.clone(),
+                ts_type_name: command.ts_type_name.clone(),
+                is_query: command.kind == CommandKind::Query,
+                has_parameters: !command.parameters.is_empty(),
+                invalidated: invalidated.contains(command.ts_function_name.as_str()),
+                invalidates: command
+                    .invalidates
+                    .iter()
+                    .filter(|query| invalidated.contains(query.as_str()))
+                    .cloned()
+                    .collect(),
+            })
+            .collect()
+    }
+}
+
+/// Readable store of an event's latest payload, e.g. `userLoginEvent` listening with
+/// `onUserLogin`
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SvelteEventStore {
+    pub event_name: String,
+    pub store_name: String,
+    pub listener_name: String,
+    pub payload_type: String,
+}
+
+impl SvelteEventStore {
+    /// One store per event name, listening with the event's generated listener
+    pub fn collect(events: &[EventContext], case_converter: &CaseConverter) -> Vec<Self> {
+        let mut seen = HashSet::new();
+        events
+            .iter()
+            .filter(|event| seen.insert(event.event_name.as_str()))
+            .map(|event| {
+                let base = event
+                    .ts_function_name
+                    .strip_prefix("on")
+                    .unwrap_or(&event.ts_function_name);
+                Self {
+                    event_name: event.event_name.clone(),
+                    store_name: format!("{}Event", case_converter.to_camel_case(base)),
+                    listener_name: event.ts_function_name.clone(),
+                    payload_type: event.typescript_payload_type.clone(),
+                }
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::generators::ts::type_visitor::TypeScriptVisitor;
+    use crate::models::{CommandInfo, EventInfo, TypeStructure};
+    use crate::GenerateConfig;
+
+    #[test]
+    fn test_stores_by_command_kind_and_event() {
+        let config = GenerateConfig::default();
+        let visitor = TypeScriptVisitor::with_config(&config);
+        let resolver = |_: &str| TypeStructure::default();
+        let contexts: Vec<CommandContext> = ["get_user", "save_user"]
+            .iter()
+            .map(|name| {
+                let command =
+                    CommandInfo::new_for_test(*name, "src/lib.rs", 1, vec![], "()", true, vec![]);
+                CommandContext::new(&config).from_command_info(&command, &visitor, &resolver)
+            })
+            .collect();
+
+        let stores = SvelteCommandStore::collect(&contexts);
+        assert_eq!(stores[0].store_name, "getUserStore");
+        assert!(stores[0].is_query);
+        assert_eq!(stores[1].store_name, "saveUserStore");
+        assert!(!stores[1].is_query);
+
+        let event = EventInfo {
+            event_name: "user-login".to_string(),
+            payload_type: "String".to_string(),
+            payload_type_structure: TypeStructure::Primitive("string".to_string()),
+            file_path: "src/lib.rs".to_string(),
+            line_number: 1,
+            emitted_by: None,
+        };
+        let events: Vec<EventContext> = [&event, &event]
+            .iter()
+            .map(|event| EventContext::new(&config).from_event_info(event, &visitor, &resolver))
+            .collect();
+
+        let event_stores = SvelteEventStore::collect(&events, &CaseConverter::new(&[]));
+        assert_eq!(event_stores.len(), 1);
+        assert_eq!(event_stores[0].store_name, "userLoginEvent");
+        assert_eq!(event_stores[0].listener_name, "onUserLogin");
+        assert_eq!(event_stores[0].payload_type, "string");
+    }
+}
@@ -1,8 +1,19 @@
+use crate::analysis::type_resolver::TypeResolver;
+use crate::generators::base::casing::{property_key, CaseConverter};
+use crate::generators::base::compact::CompactCommand;
+use crate::generators::base::progress::ProgressFields;
+use crate::generators::base::range_literals::RangeLiterals;
+use crate::generators::base::templates::escape_js;
 use crate::generators::base::type_visitor::TypeVisitor;
-use crate::models::{ChannelInfo, CommandInfo, EventInfo, FieldInfo, ParameterInfo};
+use crate::generators::TypeCollector;
+use crate::models::{
+    CallSerialization, ChannelInfo, CommandInfo, CommandKind, ConstInfo, ConstValue, EventInfo,
+    FieldInfo, ParameterInfo, StructInfo,
+};
 use crate::{GenerateConfig, TypeStructure};
 use serde::{Deserialize, Serialize};
 use serde_rename_rule::RenameRule;
+use std::collections::{HashMap, HashSet};
 
 /// Trait for contexts that provide naming convention functionality
 pub trait NamingContext {
@@ -12,12 +23,12 @@ pub trait NamingContext {
     /// Convert an event name to a TypeScript event listener function name
     /// Example: "user_login" -> "onUserLogin", "user-login" -> "onUserLogin"
     fn event_name_to_function(&self, event_name: &str) -> String {
-        // Normalize kebab-case to snake_case since serde_rename_rule expects snake_case
-        let normalized = event_name.replace('-', "_");
-        format!(
-            "on{}",
-            self.apply_naming_convention(&normalized, RenameRule::PascalCase)
-        )
+        format!("on{}", self.case_converter().to_pascal_case(event_name))
+    }
+
+    /// Case converter for generated identifiers, honoring configured acronyms
+    fn case_converter(&self) -> CaseConverter<'_> {
+        CaseConverter::new(self.config().acronyms())
     }
 
     /// Apply serde naming convention transformations
@@ -51,6 +62,28 @@ pub trait NamingContext {
         }
     }
 
+    /// Compute the serialized name (tag value) of an enum variant based on serde attributes
+    ///
+    /// Priority:
+    /// 1. Variant-level `#[serde(rename = "...")]` takes precedence
+    /// 2. Enum-level `#[serde(rename_all = "...")]` applies naming convention to the
+    ///    PascalCase variant name, e.g. `UserJoined` → `USER_JOINED`
+    /// 3. Otherwise, the variant name as written, as serde does
+    fn compute_variant_name(
+        &self,
+        variant_name: &str,
+        variant_rename: &Option<String>,
+        enum_rename_all: &Option<RenameRule>,
+    ) -> String {
+        if let Some(rename) = variant_rename {
+            rename.to_string()
+        } else if let Some(convention) = enum_rename_all {
+            convention.apply_to_variant(variant_name)
+        } else {
+            variant_name.to_string()
+        }
+    }
+
     /// Compute the serialized name for a parameter based on serde attributes
     ///
     /// Priority:
@@ -85,7 +118,7 @@ pub trait NamingContext {
     fn compute_function_name(&self, name: &str, _rename_all: &Option<RenameRule>) -> String {
         // Always use TypeScript conventions (camelCase for functions)
         // Command-level rename_all doesn't affect the function name
-        self.apply_naming_convention(name, RenameRule::CamelCase)
+        CaseConverter::identifier(self.case_converter().to_camel_case(name))
     }
 
     /// Compute the TypeScript type name (PascalCase)
@@ -95,7 +128,7 @@ pub trait NamingContext {
     fn compute_type_name(&self, name: &str, _rename_all: &Option<RenameRule>) -> String {
         // Always use TypeScript conventions (PascalCase for types)
         // Command-level rename_all doesn't affect the type name
-        self.apply_naming_convention(name, RenameRule::PascalCase)
+        CaseConverter::identifier(self.case_converter().to_pascal_case(name))
     }
 }
 
@@ -113,6 +146,37 @@ pub struct CommandContext {
     pub channels: Vec<ChannelContext>,
     pub ts_function_name: String, // Computed field
     pub ts_type_name: String,     // Computed field
+    pub is_notification: bool,    // Computed field: gets a fire-and-forget `xNotify` variant
+    /// Computed field: streams over its only channel and returns a result, so it gets
+    /// an `xStream` variant returning both from one call
+    pub is_stream: bool,
+    pub example: String, // Computed field: example call for the JSDoc `@example`
+    pub raw_response: bool, // Returns raw bytes (`tauri::ipc::Response`)
+    /// Takes or returns `Compact<T>`, called through `invokeCompact` (see
+    /// `GenerateConfig::compact_types`)
+    pub compact: bool,
+    pub serialize_calls: bool, // Computed field: concurrent calls are queued
+    /// Serialized name of the parameter calls are queued by, `None` for one queue per command
+    pub serialize_key: Option<String>,
+    /// Delay before the latest call is sent (`debounceMs` of `commandOptions`)
+    pub debounce_ms: Option<u32>,
+    /// Minimum interval between sent calls (`throttleMs` of `commandOptions`)
+    pub throttle_ms: Option<u32>,
+    /// How long results are reused for calls with the same parameters (`cacheTtlMs`
+    /// of `commandOptions`), only set for queries without channels
+    pub cache_ttl_ms: Option<u32>,
+    /// Whether the command reads or changes state (see `GenerateConfig::command_kind`)
+    pub kind: CommandKind,
+    /// Function names of the query commands a successful call makes stale
+    pub invalidates: Vec<String>,
+    /// Names of the cached query commands (see `cache_ttl_ms`) whose results a
+    /// successful call drops
+    pub invalidated_commands: Vec<String>,
+    /// Events emitted in the command's body
+    pub emitted_events: Vec<EventContext>,
+    /// Whether the zod bindings validate the parameters before the call (see
+    /// `GenerateConfig::validates_command`)
+    pub validate: bool,
     #[serde(skip)]
     config: GenerateConfig,
 }
@@ -124,6 +188,70 @@ impl NamingContext for CommandContext {
 }
 
 impl CommandContext {
+    /// Rust source location (`file:line`) of the command
+    pub fn location(&self) -> String {
+        format!("{}:{}", self.file_path, self.line_number)
+    }
+
+    /// Parameters and channels sent under the same name (`user_id` and `userId` both
+    /// become `userId`), as `(serialized name, first Rust name, later Rust name)`
+    pub fn parameter_collisions(&self) -> Vec<(&str, &str, &str)> {
+        let names = self
+            .parameters
+            .iter()
+            .map(|param| (param.serialized_name.as_str(), param.name.as_str()))
+            .chain(self.channels.iter().map(|channel| {
+                (
+                    channel.serialized_parameter_name.as_str(),
+                    channel.parameter_name.as_str(),
+                )
+            }));
+
+        let mut seen: HashMap<&str, &str> = HashMap::new();
+        let mut collisions = Vec::new();
+        for (serialized, rust_name) in names {
+            match seen.get(serialized) {
+                Some(first) => collisions.push((serialized, *first, rust_name)),
+                None => {
+                    seen.insert(serialized, rust_name);
+                }
+            }
+        }
+        collisions
+    }
+
+    /// Keep one parameter or channel of every serialized name, so the params interface
+    /// and schema declare each key once. Channels win over parameters, the first of
+    /// colliding parameters or channels over later ones.
+    pub fn remove_parameter_collisions(&mut self) {
+        let mut channel_names = HashSet::new();
+        self.channels
+            .retain(|channel| channel_names.insert(channel.serialized_parameter_name.clone()));
+
+        let mut names = HashSet::new();
+        self.parameters.retain(|param| {
+            !channel_names.contains(&param.serialized_name)
+                && names.insert(param.serialized_name.clone())
+        });
+    }
+
+    /// Function names of the queries without channels that mutations among `commands`
+    /// invalidate, which the framework hooks refetch once such a mutation succeeds
+    pub fn invalidated_queries(commands: &[CommandContext]) -> HashSet<&str> {
+        let queries: HashSet<&str> = commands
+            .iter()
+            .filter(|command| command.kind == CommandKind::Query && command.channels.is_empty())
+            .map(|command| command.ts_function_name.as_str())
+            .collect();
+        commands
+            .iter()
+            .filter(|command| command.kind != CommandKind::Query)
+            .flat_map(|command| &command.invalidates)
+            .map(String::as_str)
+            .filter(|query| queries.contains(query))
+            .collect()
+    }
+
     /// Create a new CommandContext with the given config
     pub fn new(config: &GenerateConfig) -> Self {
         Self {
@@ -137,6 +265,21 @@ impl CommandContext {
             channels: Vec::new(),
             ts_function_name: String::new(),
             ts_type_name: String::new(),
+            is_notification: false,
+            is_stream: false,
+            example: String::new(),
+            raw_response: false,
+            compact: false,
+            serialize_calls: false,
+            serialize_key: None,
+            debounce_ms: None,
+            throttle_ms: None,
+            cache_ttl_ms: None,
+            kind: CommandKind::default(),
+            invalidates: Vec::new(),
+            invalidated_commands: Vec::new(),
+            emitted_events: Vec::new(),
+            validate: false,
             config: config.clone(),
         }
     }
@@ -203,9 +346,48 @@ impl CommandContext {
         self.channels = channels;
         self.ts_function_name = ts_function_name;
         self.ts_type_name = ts_type_name;
+        self.raw_response = cmd.raw_response;
+        self.is_notification = self.config.should_generate_notify_commands()
+            && cmd.channels.is_empty()
+            && Self::returns_unit(&cmd.return_type_structure);
+        self.is_stream = cmd.channels.len() == 1 && !Self::returns_unit(&cmd.return_type_structure);
+
+        let serialization = self.config.serialize_calls(cmd);
+        self.serialize_calls = serialization.is_some();
+        self.serialize_key = match serialization {
+            // Keys may be given as Rust or serialized names; unknown keys queue per command
+            Some(CallSerialization::Key(key)) => self
+                .parameters
+                .iter()
+                .find(|param| param.name == key || param.serialized_name == key)
+                .map(|param| param.serialized_name.clone()),
+            _ => None,
+        };
+        if let Some(options) = self.config.command_options(&cmd.name) {
+            self.debounce_ms = options.debounce_ms;
+            self.throttle_ms = options.throttle_ms;
+        }
+        self.kind = self.config.command_kind(cmd);
+        if self.kind == CommandKind::Query && self.channels.is_empty() {
+            self.cache_ttl_ms = self
+                .config
+                .command_options(&cmd.name)
+                .and_then(|options| options.cache_ttl_ms);
+        }
+        let compact_types = self.config.compact_types();
+        self.compact = matches!(CompactCommand::new(cmd, &self, &compact_types), Some(Ok(_)));
 
         self
     }
+
+    /// Whether a command returns `()` or `Result<(), E>`
+    fn returns_unit(return_type: &TypeStructure) -> bool {
+        match return_type {
+            TypeStructure::Primitive(prim) => prim == "void",
+            TypeStructure::Result(inner) => Self::returns_unit(inner),
+            _ => false,
+        }
+    }
 }
 
 /// Template context wrapper for ParameterInfo with computed TypeScript-specific fields
@@ -216,6 +398,7 @@ pub struct ParameterContext {
     pub rust_type: String,
     pub typescript_type: String, // Computed field
     pub is_optional: bool,
+    pub is_path: bool,           // Computed field
     pub serialized_name: String, // Computed field
     pub type_structure: TypeStructure,
     #[serde(skip)]
@@ -236,6 +419,7 @@ impl ParameterContext {
             rust_type: String::new(),
             typescript_type: String::new(),
             is_optional: false,
+            is_path: false,
             serialized_name: String::new(),
             type_structure: TypeStructure::default(),
             config: config.clone(),
@@ -258,6 +442,7 @@ impl ParameterContext {
         self.rust_type = param.rust_type.clone();
         self.typescript_type = typescript_type;
         self.is_optional = param.is_optional;
+        self.is_path = TypeResolver::is_path_type(&param.rust_type);
         self.serialized_name = serialized_name.to_string();
         self.type_structure = param.type_structure.clone();
 
@@ -273,8 +458,17 @@ pub struct FieldContext {
     pub rust_type: String,
     pub typescript_type: String, // Computed field (for vanilla TS or zod schemas)
     pub is_optional: bool,
+    pub is_path: bool, // Computed field
     pub serialized_name: String,
     pub validator_attributes: Option<crate::models::ValidatorAttributes>,
+    pub range_literals: Option<RangeLiterals>, // Computed field
+    pub literal: Option<String>,
+    /// `#[serde(flatten)]`: the fields of the type sit next to the other fields, typed
+    /// without the `Option` of optional flattened fields
+    pub flatten: bool,
+    /// `#[serde(default)]` or `skip_serializing_if`: the key may be absent, so the
+    /// property is optional even without an `Option`
+    pub omittable: bool,
     #[serde(skip_serializing)]
     pub type_structure: TypeStructure, // Keep for internal use but don't expose to templates
     #[serde(skip)]
@@ -295,13 +489,31 @@ impl FieldContext {
             rust_type: String::new(),
             typescript_type: String::new(),
             is_optional: false,
+            is_path: false,
             serialized_name: String::new(),
             validator_attributes: None,
+            range_literals: None,
+            literal: None,
+            flatten: false,
+            omittable: false,
             type_structure: TypeStructure::default(),
             config: config.clone(),
         }
     }
 
+    /// Populate this context from an enum variant, named by its tag value
+    pub fn from_variant_info<V: TypeVisitor>(
+        self,
+        variant: &FieldInfo,
+        enum_rename_all: &Option<RenameRule>,
+        visitor: &V,
+    ) -> Self {
+        let mut context = self.from_field_info(variant, &None, visitor);
+        context.serialized_name =
+            context.compute_variant_name(&variant.name, &variant.serde_rename, enum_rename_all);
+        context
+    }
+
     /// Populate this context from a FieldInfo
     pub fn from_field_info<V: TypeVisitor>(
         mut self,
@@ -309,7 +521,14 @@ impl FieldContext {
         struct_rename_all: &Option<RenameRule>,
         visitor: &V,
     ) -> Self {
-        let typescript_type = visitor.visit_type(&field.type_structure);
+        let typescript_type = match (&field.literal, &field.type_structure) {
+            (Some(literal), _) => visitor.visit_string_literal(
+                literal,
+                matches!(field.type_structure, TypeStructure::Optional(_)),
+            ),
+            (None, TypeStructure::Optional(inner)) if field.flatten => visitor.visit_type(inner),
+            (None, structure) => visitor.visit_type(structure),
+        };
 
         // Compute serialized name from serde attributes using NamingContext trait
         let serialized_name =
@@ -319,12 +538,53 @@ impl FieldContext {
         self.rust_type = field.rust_type.clone();
         self.typescript_type = typescript_type;
         self.is_optional = field.is_optional;
+        self.is_path = TypeResolver::is_path_type(&field.rust_type);
         self.serialized_name = serialized_name;
         self.validator_attributes = field.validator_attributes.clone();
+        self.literal = field.literal.clone();
+        self.flatten = field.flatten;
+        self.omittable = field.omittable;
         self.type_structure = field.type_structure.clone();
 
         self
     }
+
+    /// Type a field of `type_name` limited to a small integer range as the union of its
+    /// values (see `range_literals`)
+    pub fn with_range_literals<V: TypeVisitor>(
+        mut self,
+        type_name: &str,
+        field: &FieldInfo,
+        visitor: &V,
+    ) -> Self {
+        if let Some(literals) = RangeLiterals::of(type_name, field, &self.config) {
+            let optional = matches!(field.type_structure, TypeStructure::Optional(_));
+            self.typescript_type = visitor.visit_literals(&literals, optional);
+            self.range_literals = Some(literals);
+        }
+        self
+    }
+
+    /// Type a flattened map of `struct_info` by the keys it adds next to the other
+    /// fields (see [`TypeCollector::flattened_map_value`])
+    pub fn with_flattened_map<V: TypeVisitor>(
+        mut self,
+        struct_info: &StructInfo,
+        field: &FieldInfo,
+        visitor: &V,
+    ) -> Self {
+        let structure = match &field.type_structure {
+            TypeStructure::Optional(inner) => inner.as_ref(),
+            structure => structure,
+        };
+        if let (true, TypeStructure::Map { key, value }) = (field.flatten, structure) {
+            self.typescript_type = visitor.visit_type(&TypeStructure::Map {
+                key: key.clone(),
+                value: Box::new(TypeCollector::flattened_map_value(struct_info, value)),
+            });
+        }
+        self
+    }
 }
 
 /// Template context wrapper for StructInfo with computed TypeScript-specific fields
@@ -334,6 +594,12 @@ pub struct StructContext {
     pub name: String,
     pub fields: Vec<FieldContext>,
     pub is_enum: bool,
+    pub is_newtype: bool,
+    pub tagged_enum: Option<TaggedEnumContext>, // Computed field
+    pub untagged_variants: Option<Vec<String>>, // Computed field: TypeScript type of each variant
+    /// TypeScript type of each variant of an externally tagged enum with data, e.g.
+    /// `"Quit"` or `{ Move: { x: number } }`; `None` for enums of unit variants only
+    pub external_variants: Option<Vec<String>>,
     #[serde(skip)]
     config: GenerateConfig,
 }
@@ -351,6 +617,10 @@ impl StructContext {
             name: String::new(),
             fields: Vec::new(),
             is_enum: false,
+            is_newtype: false,
+            tagged_enum: None,
+            untagged_variants: None,
+            external_variants: None,
             config: config.clone(),
         }
     }
@@ -366,20 +636,148 @@ impl StructContext {
             .fields
             .iter()
             .map(|field| {
-                FieldContext::new(&self.config).from_field_info(
-                    field,
-                    &struct_info.serde_rename_all,
-                    visitor,
-                )
+                let context = FieldContext::new(&self.config);
+                if struct_info.is_enum {
+                    context.from_variant_info(field, &struct_info.serde_rename_all, visitor)
+                } else {
+                    context
+                        .from_field_info(field, &struct_info.serde_rename_all, visitor)
+                        .with_range_literals(name, field, visitor)
+                        .with_flattened_map(struct_info, field, visitor)
+                }
             })
             .collect();
 
+        let tagged_enum = match &struct_info.serde_tag {
+            Some(tag) if struct_info.is_enum => Some(TaggedEnumContext {
+                tag: tag.clone(),
+                content: struct_info.serde_content.clone(),
+                variants: struct_info
+                    .fields
+                    .iter()
+                    .zip(&field_contexts)
+                    .map(|(variant, field_context)| {
+                        self.variant_context(variant, &field_context.serialized_name, visitor)
+                    })
+                    .collect(),
+            }),
+            _ => None,
+        };
+
+        // Untagged variants serialize as their payload alone; unit variants as `null`
+        let untagged_variants = struct_info.is_untagged().then(|| {
+            let mut members: Vec<String> = Vec::new();
+            for variant in &struct_info.fields {
+                let member = self
+                    .variant_context(variant, &variant.name, visitor)
+                    .payload_type
+                    .unwrap_or_else(|| "null".to_string());
+                if !members.contains(&member) {
+                    members.push(member);
+                }
+            }
+            members
+        });
+
+        // Externally tagged variants with data serialize as `{ "Variant": payload }`
+        let external_variants =
+            (struct_info.is_externally_tagged() && struct_info.has_variant_data()).then(|| {
+                struct_info
+                    .fields
+                    .iter()
+                    .zip(&field_contexts)
+                    .map(|(variant, field_context)| {
+                        let serialized_name = &field_context.serialized_name;
+                        match self
+                            .variant_context(variant, serialized_name, visitor)
+                            .payload_type
+                        {
+                            Some(payload) => {
+                                format!("{{ {}: {} }}", property_key(serialized_name), payload)
+                            }
+                            None => format!("\"{}\"", serialized_name),
+                        }
+                    })
+                    .collect()
+            });
+
         self.name = name.to_string();
         self.fields = field_contexts;
         self.is_enum = struct_info.is_enum;
+        self.is_newtype = struct_info.is_newtype;
+        self.tagged_enum = tagged_enum;
+        self.untagged_variants = untagged_variants;
+        self.external_variants = external_variants;
 
         self
     }
+
+    /// Build the context for a single variant of a tagged enum
+    pub(crate) fn variant_context<V: TypeVisitor>(
+        &self,
+        variant: &FieldInfo,
+        serialized_name: &str,
+        visitor: &V,
+    ) -> VariantContext {
+        let payload_fields = (variant.rust_type == "enum_variant_struct").then(|| {
+            variant
+                .variant_fields
+                .iter()
+                .map(|field| {
+                    format!(
+                        "{}{}: {}",
+                        property_key(&self.compute_field_name(
+                            &field.name,
+                            &field.serde_rename,
+                            &None
+                        )),
+                        if field.is_optional { "?" } else { "" },
+                        visitor.visit_type_for_interface(&field.type_structure)
+                    )
+                })
+                .collect::<Vec<_>>()
+                .join("; ")
+        });
+        let payload_type = match variant.rust_type.as_str() {
+            "enum_variant_tuple" => Some(visitor.visit_type_for_interface(&variant.type_structure)),
+            "enum_variant_struct" => payload_fields
+                .as_ref()
+                .map(|fields| format!("{{ {} }}", fields)),
+            _ => None,
+        };
+
+        VariantContext {
+            name: variant.name.clone(),
+            serialized_name: serialized_name.to_string(),
+            constructor_name: self.case_converter().to_camel_case(&variant.name),
+            payload_type,
+            payload_fields,
+        }
+    }
+}
+
+/// Template context for an adjacently tagged enum, `#[serde(tag = "...", content = "...")]`,
+/// or an internally tagged one, `#[serde(tag = "...")]`
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TaggedEnumContext {
+    pub tag: String,
+    /// Field holding the payload, `None` when the payload sits next to the tag
+    pub content: Option<String>,
+    pub variants: Vec<VariantContext>,
+}
+
+/// Template context for one variant of a tagged enum
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct VariantContext {
+    pub name: String,
+    pub serialized_name: String,      // Value of the tag field
+    pub constructor_name: String,     // Computed field, e.g. `Text` -> `text`
+    pub payload_type: Option<String>, // TypeScript type of the content field, None for unit variants
+    /// Members of a struct variant's payload, e.g. `x: number; y: number`, inlined next
+    /// to the tag of internally tagged enums
+    pub payload_fields: Option<String>,
 }
 
 /// Template context wrapper for ChannelInfo with computed TypeScript-specific fields
@@ -393,6 +791,9 @@ pub struct ChannelContext {
     pub file_path: String,
     pub line_number: usize,
     pub serialized_parameter_name: String, // Computed field
+    /// Fields of progress messages a `xWithProgress` wrapper computes the percent
+    /// done and time left from, `None` for other messages
+    pub progress: Option<ProgressFields>,
     #[serde(skip)]
     config: GenerateConfig,
 }
@@ -414,6 +815,7 @@ impl ChannelContext {
             file_path: String::new(),
             line_number: 0,
             serialized_parameter_name: String::new(),
+            progress: None,
             config: config.clone(),
         }
     }
@@ -463,6 +865,11 @@ impl NamingContext for EventContext {
 }
 
 impl EventContext {
+    /// Rust source location (`file:line`) of the emit call
+    pub fn location(&self) -> String {
+        format!("{}:{}", self.file_path, self.line_number)
+    }
+
     /// Create a new EventContext with the given config
     pub fn new(config: &GenerateConfig) -> Self {
         Self {
@@ -500,6 +907,69 @@ impl EventContext {
     }
 }
 
+/// Template context for a Rust constant marked with `#[typegen(export)]`
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ConstantContext {
+    pub name: String,
+    pub rust_type: String,
+    pub value: String, // Computed field: TypeScript literal
+    pub file_path: String,
+    pub line_number: usize,
+}
+
+impl ConstantContext {
+    /// Create a ConstantContext from a ConstInfo
+    pub fn from_const_info(constant: &ConstInfo) -> Self {
+        let value = match &constant.value {
+            ConstValue::Array(_) => format!("{} as const", Self::literal(&constant.value)),
+            value => Self::literal(value),
+        };
+
+        Self {
+            name: constant.name.clone(),
+            rust_type: constant.rust_type.clone(),
+            value,
+            file_path: constant.file_path.clone(),
+            line_number: constant.line_number,
+        }
+    }
+
+    /// Rust source location (`file:line`) of the constant
+    pub fn location(&self) -> String {
+        format!("{}:{}", self.file_path, self.line_number)
+    }
+
+    /// Render a constant value as a TypeScript literal
+    fn literal(value: &ConstValue) -> String {
+        match value {
+            ConstValue::Bool(b) => b.to_string(),
+            ConstValue::Integer(i) => i.to_string(),
+            ConstValue::Float(f) if f.is_nan() => "NaN".to_string(),
+            ConstValue::Float(f) if f.is_infinite() => {
+                if *f > 0.0 { "Infinity" } else { "-Infinity" }.to_string()
+            }
+            ConstValue::Float(f) => f.to_string(),
+            ConstValue::String(s) => format!("\"{}\"", escape_js(s)),
+            ConstValue::Array(items) => format!(
+                "[{}]",
+                items
+                    .iter()
+                    .map(Self::literal)
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            ),
+        }
+    }
+}
+
+/// Template context for a type-only import of field override or shared types
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct TypeImportContext {
+    pub from: String,
+    pub types: Vec<String>,
+}
+
 #[cfg(test)]
 mod tests {
     use GenerateConfig;
@@ -635,6 +1105,38 @@ mod tests {
         assert_eq!(result, "user_id");
     }
 
+    #[test]
+    fn test_compute_variant_name() {
+        let ctx = MockContext {
+            config: mock_config_with_snake_case(),
+        };
+
+        // Variant-level rename takes precedence over the enum's rename_all
+        let result = ctx.compute_variant_name(
+            "UserJoined",
+            &Some("joined".to_string()),
+            &Some(RenameRule::KebabCase),
+        );
+        assert_eq!(result, "joined");
+
+        let kebab = Some(RenameRule::KebabCase);
+        assert_eq!(
+            ctx.compute_variant_name("UserJoined", &None, &kebab),
+            "user-joined"
+        );
+        let screaming = Some(RenameRule::ScreamingSnakeCase);
+        assert_eq!(
+            ctx.compute_variant_name("UserJoined", &None, &screaming),
+            "USER_JOINED"
+        );
+
+        // Without serde attributes variants keep their name, whatever the field case
+        assert_eq!(
+            ctx.compute_variant_name("UserJoined", &None, &None),
+            "UserJoined"
+        );
+    }
+
     #[test]
     fn test_compute_field_name_with_snake_case_default() {
         let ctx = MockContext {
@@ -726,7 +1228,7 @@ mod tests {
             "onStatusChanged"
         );
 
-        // Kebab-case is normalized to snake_case before conversion
+        // Kebab-case and other separators split words as well
         assert_eq!(
             ctx.event_name_to_function("progress-update"),
             "onProgressUpdate"
@@ -737,6 +1239,27 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_generated_names_honor_acronyms() {
+        let ctx = MockContext {
+            config: GenerateConfig {
+                acronyms: Some(vec!["URL".to_string(), "IO".to_string()]),
+                ..mock_config()
+            },
+        };
+
+        assert_eq!(ctx.compute_function_name("get_url_v2", &None), "getURLV2");
+        assert_eq!(ctx.compute_type_name("get_url_v2", &None), "GetURLV2");
+        assert_eq!(ctx.compute_function_name("io_error", &None), "ioError");
+        assert_eq!(ctx.event_name_to_function("io-ready"), "onIOReady");
+
+        // Serialized names follow serde exactly, acronyms do not apply
+        assert_eq!(
+            ctx.compute_field_name("image_url", &None, &Some(RenameRule::CamelCase)),
+            "imageUrl"
+        );
+    }
+
     #[test]
     fn test_serde_rename_priority_order() {
         let ctx = MockContext {
@@ -772,6 +1295,84 @@ mod tests {
         assert_eq!(ctx.config.default_parameter_case, "camelCase");
     }
 
+    #[test]
+    fn test_command_context_marks_unit_commands_as_notifications() {
+        let config = GenerateConfig {
+            notify_commands: Some(true),
+            ..mock_config()
+        };
+        let visitor = crate::generators::ts::type_visitor::TypeScriptVisitor::new();
+        let resolver = |_: &str| TypeStructure::Primitive("string".to_string());
+        let context_for = |return_type: &str, config: &GenerateConfig| {
+            let cmd = CommandInfo::new_for_test(
+                "cmd",
+                "src/main.rs",
+                1,
+                vec![],
+                return_type,
+                false,
+                vec![],
+            );
+            CommandContext::new(config).from_command_info(&cmd, &visitor, &resolver)
+        };
+
+        assert!(context_for("()", &config).is_notification);
+        assert!(context_for("Result<(), String>", &config).is_notification);
+        assert!(!context_for("Result<u32, String>", &config).is_notification);
+        assert!(!context_for("String", &config).is_notification);
+        assert!(!context_for("()", &mock_config()).is_notification);
+    }
+
+    #[test]
+    fn test_command_context_parameter_collisions() {
+        let visitor = crate::generators::ts::type_visitor::TypeScriptVisitor::new();
+        let resolver = |_: &str| TypeStructure::Primitive("string".to_string());
+        let param = |name: &str| ParameterInfo {
+            name: name.to_string(),
+            rust_type: "String".to_string(),
+            is_optional: false,
+            type_structure: TypeStructure::Primitive("string".to_string()),
+            serde_rename: None,
+        };
+        let cmd = CommandInfo::new_for_test(
+            "save",
+            "src/main.rs",
+            1,
+            vec![param("user_id"), param("userId"), param("name")],
+            "()",
+            false,
+            vec![ChannelInfo::new_for_test(
+                "on_name",
+                "String",
+                "save",
+                "src/main.rs",
+                1,
+            )],
+        );
+        let mut context =
+            CommandContext::new(&mock_config()).from_command_info(&cmd, &visitor, &resolver);
+        assert_eq!(
+            context.parameter_collisions(),
+            [("userId", "user_id", "userId")]
+        );
+
+        context.channels[0].serialized_parameter_name = "name".to_string();
+        assert_eq!(
+            context.parameter_collisions(),
+            [("userId", "user_id", "userId"), ("name", "name", "on_name")]
+        );
+
+        context.remove_parameter_collisions();
+        assert!(context.parameter_collisions().is_empty());
+        let names: Vec<&str> = context
+            .parameters
+            .iter()
+            .map(|param| param.name.as_str())
+            .collect();
+        assert_eq!(names, ["user_id"]);
+        assert_eq!(context.channels.len(), 1);
+    }
+
     #[test]
     fn test_parameter_context_builder_pattern() {
         let config = mock_config();
@@ -782,6 +1383,304 @@ mod tests {
         assert!(matches!(ctx.type_structure, TypeStructure::Primitive(_)));
     }
 
+    #[test]
+    fn test_parameter_context_detects_path_types() {
+        let config = mock_config();
+        let visitor = crate::generators::ts::type_visitor::TypeScriptVisitor::new();
+        let param = ParameterInfo {
+            name: "path".to_string(),
+            rust_type: "Option<std::path::PathBuf>".to_string(),
+            is_optional: true,
+            type_structure: TypeStructure::Optional(Box::new(TypeStructure::Primitive(
+                "string".to_string(),
+            ))),
+            serde_rename: None,
+        };
+
+        let ctx =
+            ParameterContext::new(&config).from_parameter_info(&param, &None, &visitor, "path");
+        assert!(ctx.is_path);
+        assert_eq!(ctx.typescript_type, "string | null");
+    }
+
+    #[test]
+    fn test_struct_context_for_adjacently_tagged_enum() {
+        let config = mock_config();
+        let visitor = crate::generators::ts::type_visitor::TypeScriptVisitor::new();
+        let variant = |name: &str, rust_type: &str, type_structure, variant_fields| FieldInfo {
+            name: name.to_string(),
+            rust_type: rust_type.to_string(),
+            is_optional: false,
+            is_public: true,
+            validator_attributes: None,
+            serde_rename: None,
+            type_structure,
+            variant_fields,
+            literal: None,
+            flatten: false,
+            omittable: false,
+            defaulted: false,
+            skip_serializing_if: false,
+            skipped: None,
+            serde_with: None,
+        };
+        let coord = |name: &str| FieldInfo {
+            name: name.to_string(),
+            rust_type: "i32".to_string(),
+            is_optional: false,
+            is_public: true,
+            validator_attributes: None,
+            serde_rename: None,
+            type_structure: TypeStructure::Primitive("number".to_string()),
+            variant_fields: vec![],
+            literal: None,
+            flatten: false,
+            omittable: false,
+            defaulted: false,
+            skip_serializing_if: false,
+            skipped: None,
+            serde_with: None,
+        };
+        let struct_info = crate::models::StructInfo {
+            name: "Message".to_string(),
+            fields: vec![
+                variant(
+                    "Text",
+                    "enum_variant_tuple",
+                    TypeStructure::Primitive("string".to_string()),
+                    vec![],
+                ),
+                variant(
+                    "MoveTo",
+                    "enum_variant_struct",
+                    TypeStructure::Custom("enum_variant".to_string()),
+                    vec![coord("x"), coord("y")],
+                ),
+                variant(
+                    "Quit",
+                    "enum_variant",
+                    TypeStructure::Custom("enum_variant".to_string()),
+                    vec![],
+                ),
+            ],
+            file_path: "test.rs".to_string(),
+            is_enum: true,
+            is_newtype: false,
+            serde_rename_all: None,
+            serde_tag: Some("type".to_string()),
+            serde_content: Some("data".to_string()),
+            serde_untagged: false,
+            line_number: 0,
+            is_progress: false,
+            serde_deny_unknown_fields: false,
+        };
+
+        let ctx = StructContext::new(&config).from_struct_info("Message", &struct_info, &visitor);
+        let tagged = ctx.tagged_enum.expect("adjacently tagged enum context");
+        assert_eq!(tagged.tag, "type");
+        assert_eq!(tagged.content, Some("data".to_string()));
+
+        let text = &tagged.variants[0];
+        assert_eq!(text.serialized_name, "Text");
+        assert_eq!(text.constructor_name, "text");
+        assert_eq!(text.payload_type, Some("string".to_string()));
+
+        let move_to = &tagged.variants[1];
+        assert_eq!(move_to.constructor_name, "moveTo");
+        assert_eq!(
+            move_to.payload_type,
+            Some("{ x: number; y: number }".to_string())
+        );
+
+        assert_eq!(tagged.variants[2].payload_type, None);
+    }
+
+    #[test]
+    fn test_struct_context_for_internally_tagged_enum() {
+        let config = mock_config();
+        let visitor = crate::generators::ts::type_visitor::TypeScriptVisitor::new();
+        let radius = FieldInfo {
+            name: "radius".to_string(),
+            rust_type: "f64".to_string(),
+            is_optional: false,
+            is_public: true,
+            validator_attributes: None,
+            serde_rename: None,
+            type_structure: TypeStructure::Primitive("number".to_string()),
+            variant_fields: vec![],
+            literal: None,
+            flatten: false,
+            omittable: false,
+            defaulted: false,
+            skip_serializing_if: false,
+            skipped: None,
+            serde_with: None,
+        };
+        let struct_info = crate::models::StructInfo {
+            name: "Shape".to_string(),
+            fields: vec![FieldInfo {
+                name: "Circle".to_string(),
+                rust_type: "enum_variant_struct".to_string(),
+                type_structure: TypeStructure::Custom("enum_variant".to_string()),
+                variant_fields: vec![radius.clone()],
+                ..radius
+            }],
+            file_path: "test.rs".to_string(),
+            is_enum: true,
+            is_newtype: false,
+            serde_rename_all: None,
+            serde_tag: Some("type".to_string()),
+            serde_content: None,
+            serde_untagged: false,
+            line_number: 0,
+            is_progress: false,
+            serde_deny_unknown_fields: false,
+        };
+        assert!(struct_info.is_internally_tagged());
+
+        let ctx = StructContext::new(&config).from_struct_info("Shape", &struct_info, &visitor);
+        let tagged = ctx.tagged_enum.expect("internally tagged enum context");
+        assert_eq!(tagged.tag, "type");
+        assert_eq!(tagged.content, None);
+        assert_eq!(
+            tagged.variants[0].payload_fields,
+            Some("radius: number".to_string())
+        );
+        assert_eq!(
+            tagged.variants[0].payload_type,
+            Some("{ radius: number }".to_string())
+        );
+    }
+
+    #[test]
+    fn test_struct_context_for_externally_tagged_enum() {
+        let config = mock_config();
+        let visitor = crate::generators::ts::type_visitor::TypeScriptVisitor::new();
+        let variant = |name: &str, rust_type: &str, type_structure| FieldInfo {
+            name: name.to_string(),
+            rust_type: rust_type.to_string(),
+            is_optional: false,
+            is_public: true,
+            validator_attributes: None,
+            serde_rename: None,
+            type_structure,
+            variant_fields: vec![],
+            literal: None,
+            flatten: false,
+            omittable: false,
+            defaulted: false,
+            skip_serializing_if: false,
+            skipped: None,
+            serde_with: None,
+        };
+        let mut struct_info = crate::models::StructInfo {
+            name: "Command".to_string(),
+            fields: vec![
+                variant(
+                    "Say",
+                    "enum_variant_tuple",
+                    TypeStructure::Primitive("string".to_string()),
+                ),
+                variant(
+                    "Stop",
+                    "enum_variant",
+                    TypeStructure::Custom("enum_variant".to_string()),
+                ),
+            ],
+            file_path: "test.rs".to_string(),
+            is_enum: true,
+            is_newtype: false,
+            serde_rename_all: None,
+            serde_tag: None,
+            serde_content: None,
+            serde_untagged: false,
+            line_number: 0,
+            is_progress: false,
+            serde_deny_unknown_fields: false,
+        };
+
+        let ctx = StructContext::new(&config).from_struct_info("Command", &struct_info, &visitor);
+        assert_eq!(
+            ctx.external_variants,
+            Some(vec!["{ Say: string }".to_string(), "\"Stop\"".to_string()])
+        );
+
+        // Enums of unit variants stay string unions
+        struct_info.fields.remove(0);
+        let ctx = StructContext::new(&config).from_struct_info("Command", &struct_info, &visitor);
+        assert!(ctx.external_variants.is_none());
+    }
+
+    #[test]
+    fn test_struct_context_for_untagged_enum() {
+        let config = mock_config();
+        let visitor = crate::generators::ts::type_visitor::TypeScriptVisitor::new();
+        let variant = |name: &str, rust_type: &str, type_structure| FieldInfo {
+            name: name.to_string(),
+            rust_type: rust_type.to_string(),
+            is_optional: false,
+            is_public: true,
+            validator_attributes: None,
+            serde_rename: None,
+            type_structure,
+            variant_fields: vec![],
+            literal: None,
+            flatten: false,
+            omittable: false,
+            defaulted: false,
+            skip_serializing_if: false,
+            skipped: None,
+            serde_with: None,
+        };
+        let struct_info = crate::models::StructInfo {
+            name: "ConfigValue".to_string(),
+            fields: vec![
+                variant(
+                    "Flag",
+                    "enum_variant_tuple",
+                    TypeStructure::Primitive("boolean".to_string()),
+                ),
+                variant(
+                    "Int",
+                    "enum_variant_tuple",
+                    TypeStructure::Primitive("number".to_string()),
+                ),
+                variant(
+                    "Float",
+                    "enum_variant_tuple",
+                    TypeStructure::Primitive("number".to_string()),
+                ),
+                variant(
+                    "Empty",
+                    "enum_variant",
+                    TypeStructure::Custom("enum_variant".to_string()),
+                ),
+            ],
+            file_path: "test.rs".to_string(),
+            is_enum: true,
+            is_newtype: false,
+            serde_rename_all: None,
+            serde_tag: None,
+            serde_content: None,
+            serde_untagged: true,
+            line_number: 0,
+            is_progress: false,
+            serde_deny_unknown_fields: false,
+        };
+
+        let ctx =
+            StructContext::new(&config).from_struct_info("ConfigValue", &struct_info, &visitor);
+        assert!(ctx.tagged_enum.is_none());
+        assert_eq!(
+            ctx.untagged_variants,
+            Some(vec![
+                "boolean".to_string(),
+                "number".to_string(),
+                "null".to_string()
+            ])
+        );
+    }
+
     #[test]
     fn test_field_context_builder_pattern() {
         let config = mock_config();
@@ -800,6 +1699,7 @@ mod tests {
         assert_eq!(ctx.name, "");
         assert_eq!(ctx.fields.len(), 0);
         assert!(!ctx.is_enum);
+        assert!(!ctx.is_newtype);
     }
 
     #[test]
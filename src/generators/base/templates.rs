@@ -1,3 +1,4 @@
+use crate::generators::base::casing::property_key;
 use crate::template;
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
@@ -25,12 +26,152 @@ pub trait TemplateRegistry: Sized {
     fn create_tera() -> Result<Tera, String> {
         let mut tera = Tera::default();
 
-        // register common template
+        // register common templates
         template!(tera, "common/header.tera", "templates/header.tera");
+        template!(tera, "common/enums.ts.tera", "templates/enums.ts.tera");
+        template!(
+            tera,
+            "common/tagged_enum_helpers.tera",
+            "templates/tagged_enum_helpers.tera"
+        );
+        template!(tera, "common/notify.tera", "templates/notify.tera");
+        template!(
+            tera,
+            "common/raw_response.tera",
+            "templates/raw_response.tera"
+        );
+        template!(tera, "common/call_queue.tera", "templates/call_queue.tera");
+        template!(tera, "common/call_cache.tera", "templates/call_cache.tera");
+        template!(
+            tera,
+            "common/react_query.ts.tera",
+            "templates/react_query.ts.tera"
+        );
+        template!(tera, "common/testing.ts.tera", "templates/testing.ts.tera");
+        template!(tera, "common/mocks.ts.tera", "templates/mocks.ts.tera");
+        template!(tera, "common/vue.ts.tera", "templates/vue.ts.tera");
+        template!(tera, "common/svelte.ts.tera", "templates/svelte.ts.tera");
+        template!(tera, "common/pinia.ts.tera", "templates/pinia.ts.tera");
+        template!(tera, "common/stores.ts.tera", "templates/stores.ts.tera");
+        template!(tera, "common/compact.ts.tera", "templates/compact.ts.tera");
+        template!(tera, "common/compact.rs.tera", "templates/compact.rs.tera");
+        template!(
+            tera,
+            "common/self_test.ts.tera",
+            "templates/self_test.ts.tera"
+        );
+        template!(
+            tera,
+            "common/self_test.rs.tera",
+            "templates/self_test.rs.tera"
+        );
+        template!(
+            tera,
+            "common/call_schedule.tera",
+            "templates/call_schedule.tera"
+        );
+        template!(
+            tera,
+            "common/globals.d.ts.tera",
+            "templates/globals.d.ts.tera"
+        );
+        template!(
+            tera,
+            "common/constants.ts.tera",
+            "templates/constants.ts.tera"
+        );
+        template!(
+            tera,
+            "common/notify_command.tera",
+            "templates/notify_command.tera"
+        );
+        template!(tera, "common/progress.tera", "templates/progress.tera");
+        template!(
+            tera,
+            "common/progress_command.tera",
+            "templates/progress_command.tera"
+        );
+        template!(tera, "common/stream.tera", "templates/stream.tera");
+        template!(
+            tera,
+            "common/stream_command.tera",
+            "templates/stream_command.tera"
+        );
+        template!(
+            tera,
+            "common/command_doc.tera",
+            "templates/command_doc.tera"
+        );
+        template!(tera, "common/actors.ts.tera", "templates/actors.ts.tera");
+        template!(tera, "common/runtime.ts.tera", "templates/runtime.ts.tera");
+        template!(
+            tera,
+            "common/arbitraries.ts.tera",
+            "templates/arbitraries.ts.tera"
+        );
+        template!(tera, "common/binary.ts.tera", "templates/binary.ts.tera");
+        template!(tera, "common/offline.ts.tera", "templates/offline.ts.tera");
+        template!(
+            tera,
+            "common/query_keys.ts.tera",
+            "templates/query_keys.ts.tera"
+        );
+        template!(tera, "common/errors.ts.tera", "templates/errors.ts.tera");
+        template!(
+            tera,
+            "common/event_router.tera",
+            "templates/event_router.tera"
+        );
+        template!(
+            tera,
+            "common/sanitize.ts.tera",
+            "templates/sanitize.ts.tera"
+        );
+        template!(
+            tera,
+            "common/command_alias.tera",
+            "templates/command_alias.tera"
+        );
+        template!(
+            tera,
+            "common/command_hooks.tera",
+            "templates/command_hooks.tera"
+        );
+        template!(
+            tera,
+            "common/commands_shared.ts.tera",
+            "templates/commands_shared.ts.tera"
+        );
+        template!(
+            tera,
+            "common/commands_index.ts.tera",
+            "templates/commands_index.ts.tera"
+        );
+        template!(
+            tera,
+            "common/http_bridge.rs.tera",
+            "templates/http_bridge.rs.tera"
+        );
+        template!(
+            tera,
+            "common/admin_panel.tsx.tera",
+            "templates/admin_panel.tsx.tera"
+        );
+        template!(
+            tera,
+            "common/admin_result_view.tsx.tera",
+            "templates/admin_result_view.tsx.tera"
+        );
+        template!(
+            tera,
+            "common/admin_index.tsx.tera",
+            "templates/admin_index.tsx.tera"
+        );
 
         // register common filters
         tera.register_filter("escape_js", escape_js_filter);
         tera.register_filter("add_types_prefix", add_types_prefix_filter);
+        tera.register_filter("property_key", property_key_filter);
 
         // register registry specific templates
         Self::register_templates(&mut tera)?;
@@ -48,21 +189,38 @@ pub trait TemplateRegistry: Sized {
     fn register_filters(tera: &mut Tera);
 }
 
+/// Escape problematic JS characters for use in a double-quoted string literal
+pub(crate) fn escape_js(s: &str) -> String {
+    s.replace('\\', "\\\\") // Backslash must be first
+        .replace('"', "\\\"") // Escape double quotes
+        .replace('\n', "\\n") // Escape newlines
+        .replace('\r', "\\r") // Escape carriage returns
+        .replace('\t', "\\t") // Escape tabs
+}
+
 /// Filter to escape problematic JS characters
 fn escape_js_filter(value: &Value, _args: &HashMap<String, Value>) -> tera::Result<Value> {
     if let Some(s) = value.as_str() {
-        let escaped = s
-            .replace('\\', "\\\\") // Backslash must be first
-            .replace('"', "\\\"") // Escape double quotes
-            .replace('\n', "\\n") // Escape newlines
-            .replace('\r', "\\r") // Escape carriage returns
-            .replace('\t', "\\t"); // Escape tabs
-        Ok(Value::String(escaped))
+        Ok(Value::String(escape_js(s)))
     } else {
         Err("escape_js filter expects a string".into())
     }
 }
 
+/// Filter to quote object property names that are not valid identifiers
+/// Usage: {{ field.serializedName | property_key }}
+///
+/// Examples:
+/// - "userId" -> "userId"
+/// - "user-id" -> "\"user-id\""
+fn property_key_filter(value: &Value, _args: &HashMap<String, Value>) -> tera::Result<Value> {
+    if let Some(name) = value.as_str() {
+        Ok(Value::String(property_key(name)))
+    } else {
+        Err("property_key filter expects a string".into())
+    }
+}
+
 /// Filter to add "types." prefix to custom types for namespace imports
 /// Usage: {{ some_type | add_types_prefix }}
 ///
@@ -81,18 +239,39 @@ fn add_types_prefix_filter(value: &Value, _args: &HashMap<String, Value>) -> ter
 }
 
 /// Add "types." prefix to custom types for use in function signatures
-fn add_types_prefix(ts_type: &str) -> String {
+pub(crate) fn add_types_prefix(ts_type: &str) -> String {
     // Handle primitives - no prefix needed
     if matches!(
         ts_type,
-        "void" | "string" | "number" | "boolean" | "any" | "unknown" | "null" | "undefined"
+        "void"
+            | "string"
+            | "number"
+            | "boolean"
+            | "any"
+            | "unknown"
+            | "null"
+            | "undefined"
+            | "Uint8Array"
     ) {
         return ts_type.to_string();
     }
 
+    // Handle general unions: CustomType | null -> types.CustomType | null
+    let members = split_top_level_union(ts_type);
+    if members.len() > 1 {
+        return members
+            .into_iter()
+            .map(add_types_prefix)
+            .collect::<Vec<_>>()
+            .join(" | ");
+    }
+
     // Handle arrays: CustomType[] -> types.CustomType[]
     if let Some(base_type) = ts_type.strip_suffix("[]") {
-        if matches!(base_type, "string" | "number" | "boolean" | "void") {
+        if matches!(
+            base_type,
+            "string" | "number" | "boolean" | "void" | "any" | "unknown"
+        ) {
             return ts_type.to_string();
         }
         return format!("types.{}[]", base_type);
@@ -103,18 +282,6 @@ fn add_types_prefix(ts_type: &str) -> String {
         return ts_type.to_string();
     }
 
-    // Handle union with null: CustomType | null -> types.CustomType | null
-    if ts_type.ends_with(" | null") {
-        let base = ts_type.strip_suffix(" | null").unwrap();
-        return format!("{} | null", add_types_prefix(base));
-    }
-
-    // Handle union with undefined: CustomType | undefined -> types.CustomType | undefined
-    if ts_type.ends_with(" | undefined") {
-        let base = ts_type.strip_suffix(" | undefined").unwrap();
-        return format!("{} | undefined", add_types_prefix(base));
-    }
-
     // Handle tuples [T, U, ...] - keep as is since they're inline
     if ts_type.starts_with('[') && ts_type.ends_with(']') {
         return ts_type.to_string();
@@ -128,6 +295,28 @@ fn add_types_prefix(ts_type: &str) -> String {
     }
 }
 
+/// Split a TypeScript type on ` | ` separators that are not nested in brackets
+fn split_top_level_union(ts_type: &str) -> Vec<&str> {
+    let mut members = Vec::new();
+    let mut depth = 0i32;
+    let mut start = 0;
+    let bytes = ts_type.as_bytes();
+
+    for (i, ch) in ts_type.char_indices() {
+        match ch {
+            '<' | '[' | '(' | '{' => depth += 1,
+            '>' | ']' | ')' | '}' => depth -= 1,
+            '|' if depth == 0 && i > 0 && bytes[i - 1] == b' ' => {
+                members.push(ts_type[start..i - 1].trim());
+                start = i + 1;
+            }
+            _ => {}
+        }
+    }
+    members.push(ts_type[start..].trim());
+    members
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -309,6 +498,16 @@ mod tests {
             assert_eq!(add_types_prefix("[User, Product]"), "[User, Product]");
         }
 
+        #[test]
+        fn test_union_types() {
+            assert_eq!(add_types_prefix("User | Guest"), "types.User | types.Guest");
+            assert_eq!(add_types_prefix("string | User"), "string | types.User");
+            assert_eq!(
+                add_types_prefix("Record<string, A | B> | User"),
+                "Record<string, A | B> | types.User"
+            );
+        }
+
         #[test]
         fn test_empty_array_syntax() {
             // "[]" by itself is treated as a custom type name, gets prefix
@@ -348,4 +547,28 @@ mod tests {
             assert!(result.is_err());
         }
     }
+
+    mod property_key_filter_func {
+        use super::*;
+
+        #[test]
+        fn test_filter_keeps_identifiers() {
+            let value = Value::String("userId".to_string());
+            let result = property_key_filter(&value, &HashMap::new()).unwrap();
+            assert_eq!(result.as_str().unwrap(), "userId");
+        }
+
+        #[test]
+        fn test_filter_quotes_invalid_identifiers() {
+            let value = Value::String("user-id".to_string());
+            let result = property_key_filter(&value, &HashMap::new()).unwrap();
+            assert_eq!(result.as_str().unwrap(), "\"user-id\"");
+        }
+
+        #[test]
+        fn test_filter_non_string_errors() {
+            let value = Value::Bool(true);
+            assert!(property_key_filter(&value, &HashMap::new()).is_err());
+        }
+    }
 }
@@ -0,0 +1,192 @@
+use crate::generators::base::examples::ExampleBuilder;
+use crate::generators::base::template_context::CommandContext;
+use crate::generators::TypeCollector;
+use crate::models::{CommandInfo, TypeStructure};
+use crate::GenerateConfig;
+use serde::Serialize;
+use std::collections::{BTreeSet, HashMap, HashSet};
+
+/// Schema of a value, e.g. the value a command returns, checked against mocked responses
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct ValueSchema {
+    /// Schema expression, e.g. `z.array(UserSchema)`
+    pub expression: String,
+    /// Schemas of the types file the expression references
+    pub imports: Vec<String>,
+}
+
+impl ValueSchema {
+    /// Schema rendered as `expression`, referencing the schemas of the types in
+    /// `structure` that are emitted (`known_schemas`) and not mapped to another type
+    pub fn new(
+        expression: String,
+        structure: &TypeStructure,
+        known_schemas: Option<&HashSet<String>>,
+        config: &GenerateConfig,
+    ) -> Self {
+        let mut referenced = HashSet::new();
+        TypeCollector::collect_referenced_types_from_structure(structure, &mut referenced);
+        let imports: BTreeSet<String> = referenced
+            .into_iter()
+            .filter(|name| known_schemas.is_some_and(|known| known.contains(name)))
+            .filter(|name| {
+                config.field_override(name).is_none()
+                    && !config
+                        .type_mappings
+                        .as_ref()
+                        .is_some_and(|mappings| mappings.contains_key(name))
+            })
+            .map(|name| format!("{}Schema", name))
+            .collect();
+        Self {
+            expression,
+            imports: imports.into_iter().collect(),
+        }
+    }
+}
+
+/// Mock handler of a command in the generated test harness
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MockHandler {
+    pub name: String,
+    pub ts_type_name: String,
+    pub return_type_ts: String,
+    pub has_parameters: bool,
+    pub return_schema: Option<String>,
+}
+
+impl MockHandler {
+    /// Handlers for the commands without channels, which cannot be mocked through
+    /// `mockIPC`, with the return schemas by command name
+    pub fn collect(
+        commands: &[CommandContext],
+        return_schemas: &HashMap<String, ValueSchema>,
+    ) -> Vec<Self> {
+        commands
+            .iter()
+            .filter(|command| command.channels.is_empty())
+            .map(|command| Self {
+                name: command.name.clone(),
+                ts_type_name: command.ts_type_name.clone(),
+                return_type_ts: command.return_type_ts.clone(),
+                has_parameters: !command.parameters.is_empty(),
+                return_schema: return_schemas
+                    .get(&command.name)
+                    .map(|schema| schema.expression.clone()),
+            })
+            .collect()
+    }
+}
+
+/// Mock implementation of a command in the generated `mocks.ts`, returning a fake value
+/// of its return type
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MockImplementation {
+    pub name: String,
+    pub ts_type_name: String,
+    pub return_type_ts: String,
+    pub has_parameters: bool,
+    /// Fake result, `None` for commands returning `()` and for results no value can be
+    /// synthesized for (unknown or recursive types)
+    pub result: Option<String>,
+}
+
+impl MockImplementation {
+    /// Implementations for the commands without channels, which cannot be mocked through
+    /// `mockIPC`; `contexts` are the contexts of `commands`, in the same order
+    pub fn collect(
+        commands: &[CommandInfo],
+        contexts: &[CommandContext],
+        examples: &ExampleBuilder,
+    ) -> Vec<Self> {
+        commands
+            .iter()
+            .zip(contexts)
+            .filter(|(_, context)| context.channels.is_empty())
+            .map(|(command, context)| Self {
+                name: command.name.clone(),
+                ts_type_name: context.ts_type_name.clone(),
+                return_type_ts: context.return_type_ts.clone(),
+                has_parameters: !context.parameters.is_empty(),
+                result: (context.return_type_ts != "void")
+                    .then(|| examples.result(command))
+                    .flatten(),
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_return_schema_imports_emitted_unmapped_schemas() {
+        let config = GenerateConfig {
+            type_mappings: Some(HashMap::from([(
+                "DateTime".to_string(),
+                "string".to_string(),
+            )])),
+            ..Default::default()
+        };
+        let known: HashSet<String> = ["User", "DateTime"].map(String::from).into();
+        let structure = TypeStructure::Tuple(vec![
+            TypeStructure::Array(Box::new(TypeStructure::Custom("User".to_string()))),
+            TypeStructure::Custom("DateTime".to_string()),
+            TypeStructure::Custom("External".to_string()),
+        ]);
+
+        let schema = ValueSchema::new("schema".to_string(), &structure, Some(&known), &config);
+        assert_eq!(schema.imports, ["UserSchema"]);
+    }
+
+    #[test]
+    fn test_mock_implementations_return_fake_results() {
+        use crate::generators::ts::type_visitor::TypeScriptVisitor;
+
+        let config = GenerateConfig::default();
+        let visitor = TypeScriptVisitor::with_config(&config);
+        let resolver = |rust_type: &str| match rust_type {
+            "String" => TypeStructure::Primitive("string".to_string()),
+            "Vec<u32>" => {
+                TypeStructure::Array(Box::new(TypeStructure::Primitive("number".to_string())))
+            }
+            _ => TypeStructure::Primitive("void".to_string()),
+        };
+        let commands: Vec<CommandInfo> =
+            [("greet", "String"), ("ids", "Vec<u32>"), ("reset", "()")]
+                .iter()
+                .map(|(name, return_type)| {
+                    let mut command = CommandInfo::new_for_test(
+                        *name,
+                        "src/lib.rs",
+                        1,
+                        vec![],
+                        *return_type,
+                        true,
+                        vec![],
+                    );
+                    command.return_type_structure = resolver(return_type);
+                    command
+                })
+                .collect();
+        let contexts: Vec<CommandContext> = commands
+            .iter()
+            .map(|command| {
+                CommandContext::new(&config).from_command_info(command, &visitor, &resolver)
+            })
+            .collect();
+
+        let structs = HashMap::new();
+        let mocks = MockImplementation::collect(
+            &commands,
+            &contexts,
+            &ExampleBuilder::new(&structs, &config),
+        );
+        let results: Vec<Option<&str>> = mocks.iter().map(|mock| mock.result.as_deref()).collect();
+        assert_eq!(results, [Some("'example'"), Some("[1]"), None]);
+        assert_eq!(mocks[1].return_type_ts, "number[]");
+    }
+}
@@ -0,0 +1,242 @@
+use crate::generators::base::command_chunks::CommandChunk;
+use crate::generators::base::template_context::{CommandContext, ConstantContext, EventContext};
+use crate::interface::config::GenerateConfig;
+use crate::models::{CommandInfo, StructInfo};
+use serde::Serialize;
+use std::collections::HashMap;
+use std::path::Path;
+
+/// What a generated symbol was generated from
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub enum SymbolKind {
+    Command,
+    Params,
+    Struct,
+    Enum,
+    Event,
+    Constant,
+}
+
+/// A generated exported symbol and the Rust item it was generated from
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TracedSymbol {
+    pub symbol: String,
+    /// Generated file exporting the symbol
+    pub file: String,
+    pub kind: SymbolKind,
+    pub rust_name: String,
+    /// Rust source file, relative to the project path where possible
+    pub rust_file: String,
+    pub line: usize,
+}
+
+/// Contents of `trace.json`: a machine-readable map from the generated symbols to
+/// their Rust sources, for jumping from a binding to its handler
+#[derive(Debug, Clone, Default, PartialEq, Serialize)]
+pub struct Trace {
+    pub symbols: Vec<TracedSymbol>,
+}
+
+impl Trace {
+    /// Trace the commands and their parameter types, the types, events and constants.
+    /// Types are ordered by name; everything else keeps the order of the generated files.
+    /// `schema_suffix` adds the schemas generated next to the types (`UserSchema`).
+    pub fn collect(
+        commands: &[CommandInfo],
+        command_contexts: &[CommandContext],
+        used_structs: &HashMap<String, StructInfo>,
+        events: &[EventContext],
+        constants: &[ConstantContext],
+        schema_suffix: Option<&str>,
+        config: &GenerateConfig,
+    ) -> Self {
+        let file_names = config.file_names();
+        let project_path = Path::new(&config.project_path);
+        let rust_file = |file: &str| {
+            Path::new(file)
+                .strip_prefix(project_path)
+                .map(|relative| relative.display().to_string())
+                .unwrap_or_else(|_| file.to_string())
+        };
+
+        // Commands split into chunks are exported from their chunk file
+        let mut chunk_files: HashMap<(String, usize), String> = HashMap::new();
+        let chunks =
+            CommandChunk::split(commands, config.max_commands_per_file, &file_names.commands);
+        for chunk in chunks.into_iter().flatten() {
+            for command in chunk.commands {
+                chunk_files.insert(
+                    (command.file_path, command.line_number),
+                    chunk.file_name.clone(),
+                );
+            }
+        }
+
+        let mut trace = Self::default();
+        let mut push = |symbol: String, file: &str, kind, rust_name: &str, path: &str, line| {
+            trace.symbols.push(TracedSymbol {
+                symbol,
+                file: file.to_string(),
+                kind,
+                rust_name: rust_name.to_string(),
+                rust_file: rust_file(path),
+                line,
+            });
+        };
+
+        for command in command_contexts {
+            let file = chunk_files
+                .get(&(command.file_path.clone(), command.line_number))
+                .unwrap_or(&file_names.commands);
+            push(
+                command.ts_function_name.clone(),
+                file,
+                SymbolKind::Command,
+                &command.name,
+                &command.file_path,
+                command.line_number,
+            );
+        }
+        for command in command_contexts {
+            if command.parameters.is_empty() && command.channels.is_empty() {
+                continue;
+            }
+            let params_type = format!("{}Params", command.ts_type_name);
+            if let Some(suffix) = schema_suffix {
+                push(
+                    format!("{}{}", params_type, suffix),
+                    &file_names.types,
+                    SymbolKind::Params,
+                    &command.name,
+                    &command.file_path,
+                    command.line_number,
+                );
+            }
+            push(
+                params_type,
+                &file_names.types,
+                SymbolKind::Params,
+                &command.name,
+                &command.file_path,
+                command.line_number,
+            );
+        }
+
+        let mut types: Vec<(&String, &StructInfo)> = used_structs.iter().collect();
+        types.sort_by_key(|(name, _)| name.as_str());
+        for (name, info) in types {
+            let kind = if info.is_enum {
+                SymbolKind::Enum
+            } else {
+                SymbolKind::Struct
+            };
+            if let Some(suffix) = schema_suffix {
+                push(
+                    format!("{}{}", name, suffix),
+                    &file_names.types,
+                    kind,
+                    &info.name,
+                    &info.file_path,
+                    info.line_number,
+                );
+            }
+            push(
+                name.clone(),
+                &file_names.types,
+                kind,
+                &info.name,
+                &info.file_path,
+                info.line_number,
+            );
+        }
+
+        for event in events {
+            push(
+                event.ts_function_name.clone(),
+                &file_names.events,
+                SymbolKind::Event,
+                &event.event_name,
+                &event.file_path,
+                event.line_number,
+            );
+        }
+        for constant in constants {
+            push(
+                constant.name.clone(),
+                &file_names.constants,
+                SymbolKind::Constant,
+                &constant.name,
+                &constant.file_path,
+                constant.line_number,
+            );
+        }
+        trace
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::generators::ts::type_visitor::TypeScriptVisitor;
+    use crate::models::{ParameterInfo, TypeStructure};
+
+    #[test]
+    fn test_traces_commands_params_and_schemas() {
+        let config = GenerateConfig {
+            project_path: "/app/src-tauri".to_string(),
+            ..Default::default()
+        };
+        let visitor = TypeScriptVisitor::with_config(&config);
+        let resolver = |_: &str| TypeStructure::default();
+        let commands = vec![CommandInfo::new_for_test(
+            "get_user",
+            "/app/src-tauri/src/user.rs",
+            12,
+            vec![ParameterInfo {
+                name: "id".to_string(),
+                rust_type: "u32".to_string(),
+                is_optional: false,
+                type_structure: TypeStructure::Primitive("number".to_string()),
+                serde_rename: None,
+            }],
+            "String",
+            true,
+            vec![],
+        )];
+        let contexts: Vec<CommandContext> = commands
+            .iter()
+            .map(|command| {
+                CommandContext::new(&config).from_command_info(command, &visitor, &resolver)
+            })
+            .collect();
+
+        let trace = Trace::collect(
+            &commands,
+            &contexts,
+            &HashMap::new(),
+            &[],
+            &[],
+            Some("Schema"),
+            &config,
+        );
+        let symbols: Vec<(&str, &str, SymbolKind)> = trace
+            .symbols
+            .iter()
+            .map(|symbol| (symbol.symbol.as_str(), symbol.file.as_str(), symbol.kind))
+            .collect();
+        assert_eq!(
+            symbols,
+            [
+                ("getUser", "commands.ts", SymbolKind::Command),
+                ("GetUserParamsSchema", "types.ts", SymbolKind::Params),
+                ("GetUserParams", "types.ts", SymbolKind::Params),
+            ]
+        );
+        assert!(trace
+            .symbols
+            .iter()
+            .all(|symbol| symbol.rust_file == "src/user.rs" && symbol.line == 12));
+    }
+}
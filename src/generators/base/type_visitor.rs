@@ -1,3 +1,4 @@
+use crate::generators::base::range_literals::RangeLiterals;
 use crate::models::TypeStructure;
 use crate::GenerateConfig;
 
@@ -17,6 +18,7 @@ pub trait TypeVisitor {
             TypeStructure::Tuple(types) => self.visit_tuple(types),
             TypeStructure::Optional(inner) => self.visit_optional(inner),
             TypeStructure::Result(inner) => self.visit_result(inner),
+            TypeStructure::Union(types) => self.visit_union(types),
             TypeStructure::Custom(name) => self.visit_custom(name),
         }
     }
@@ -58,16 +60,45 @@ pub trait TypeVisitor {
         format!("{} | null", self.visit_type(inner))
     }
 
+    /// Visit an integer type limited to a few values (see `range_literals`)
+    fn visit_literals(&self, literals: &RangeLiterals, optional: bool) -> String {
+        if optional {
+            format!("{} | null", literals.union())
+        } else {
+            literals.union()
+        }
+    }
+
+    /// Visit a field fixed to a string value (see `#[typegen(literal = "...")]`)
+    fn visit_string_literal(&self, literal: &str, optional: bool) -> String {
+        let literal = string_literal(literal);
+        if optional {
+            format!("{} | null", literal)
+        } else {
+            literal
+        }
+    }
+
     /// Visit a result type (success type only, errors handled by Tauri)
     fn visit_result(&self, inner: &TypeStructure) -> String {
         self.visit_type(inner)
     }
 
+    /// Visit a union type (Either, configured union types)
+    fn visit_union(&self, types: &[TypeStructure]) -> String {
+        let type_strs: Vec<String> = types.iter().map(|t| self.visit_type(t)).collect();
+        type_strs.join(" | ")
+    }
+
     /// Visit a custom/user-defined type
     /// Checks config.type_mappings first before returning the type name as-is
     fn visit_custom(&self, name: &str) -> String {
         // Check if there's a custom type mapping configured
         if let Some(config) = self.get_config() {
+            // Overridden fields reference their `Type.field` selector
+            if let Some(field_override) = config.field_override(name) {
+                return field_override.ts_type().to_string();
+            }
             if let Some(ref mappings) = config.type_mappings {
                 if let Some(mapped_type) = mappings.get(name) {
                     return mapped_type.clone();
@@ -85,6 +116,46 @@ pub trait TypeVisitor {
         self.visit_type(structure)
     }
 }
+/// A string as a double-quoted TypeScript literal
+pub fn string_literal(value: &str) -> String {
+    serde_json::to_string(value).unwrap_or_default()
+}
+
+const RUST_NUMBER_TYPES: &[&str] = &[
+    "u8", "u16", "u32", "u64", "u128", "usize", "i8", "i16", "i32", "i64", "i128", "isize", "f32",
+    "f64",
+];
+
+/// Rust number types of `rust_type` in order of appearance, one per `number` of the
+/// `structure` parsed from it, for targets distinguishing integer and float types;
+/// empty when they don't line up, e.g. behind a type alias
+pub fn rust_number_types<'a>(rust_type: &'a str, structure: &TypeStructure) -> Vec<&'a str> {
+    fn count_numbers(structure: &TypeStructure) -> usize {
+        match structure {
+            TypeStructure::Primitive(name) => usize::from(name == "number"),
+            TypeStructure::Array(inner)
+            | TypeStructure::Set(inner)
+            | TypeStructure::Optional(inner)
+            | TypeStructure::Result(inner) => count_numbers(inner),
+            TypeStructure::Map { key, value } => count_numbers(key) + count_numbers(value),
+            TypeStructure::Tuple(types) | TypeStructure::Union(types) => {
+                types.iter().map(count_numbers).sum()
+            }
+            TypeStructure::Custom(_) => 0,
+        }
+    }
+
+    let numbers: Vec<&str> = rust_type
+        .split(|c: char| !c.is_alphanumeric() && c != '_')
+        .filter(|token| RUST_NUMBER_TYPES.contains(token))
+        .collect();
+    if numbers.len() == count_numbers(structure) {
+        numbers
+    } else {
+        Vec::new()
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -241,6 +312,14 @@ mod tests {
             assert_eq!(visitor.visit_type(&result(custom("User"))), "User");
         }
 
+        #[test]
+        fn test_union_types() {
+            let visitor = TypeScriptVisitor::new();
+            let union = TypeStructure::Union(vec![primitive("string"), custom("User")]);
+
+            assert_eq!(visitor.visit_type(&union), "string | User");
+        }
+
         #[test]
         fn test_custom_types() {
             let visitor = TypeScriptVisitor::new();
@@ -360,6 +439,26 @@ mod tests {
             );
         }
 
+        #[test]
+        fn test_known_schemas_restrict_references() {
+            let known: std::collections::HashSet<String> = ["User".to_string()].into();
+            let visitor = ZodVisitor::new().with_known_schemas(Some(&known));
+
+            assert_eq!(
+                visitor.visit_type(&array(custom("User"))),
+                "z.array(UserSchema)"
+            );
+            assert_eq!(
+                visitor.visit_type(&custom("External")),
+                "z.unknown() /* No schema generated for External */"
+            );
+            // Without a known set every custom type references its schema
+            assert_eq!(
+                ZodVisitor::new().visit_type(&custom("External")),
+                "ExternalSchema"
+            );
+        }
+
         #[test]
         fn test_empty_tuple() {
             let visitor = ZodVisitor::new();
@@ -379,6 +478,18 @@ mod tests {
             assert_eq!(visitor.visit_type(&result(custom("User"))), "UserSchema");
         }
 
+        #[test]
+        fn test_union_types() {
+            let visitor = ZodVisitor::new();
+            let union = TypeStructure::Union(vec![primitive("string"), custom("User")]);
+
+            assert_eq!(
+                visitor.visit_type(&union),
+                "z.union([z.string(), UserSchema])"
+            );
+            assert_eq!(visitor.visit_type_for_interface(&union), "string | User");
+        }
+
         #[test]
         fn test_custom_types() {
             let visitor = ZodVisitor::new();
@@ -498,13 +609,75 @@ mod tests {
                 validation_library: "none".to_string(),
                 visualize_deps: Some(false),
                 verbose: Some(false),
+                verbosity: None,
+                trace: None,
                 include_private: Some(false),
                 type_mappings: Some(type_mappings),
+                serde_with_mappings: None,
                 exclude_patterns: None,
                 include_patterns: None,
+                max_scan_depth: None,
                 default_parameter_case: "camelCase".to_string(),
                 default_field_case: "camelCase".to_string(),
                 force: Some(false),
+                output_file_names: None,
+                collection_mappings: None,
+                union_types: None,
+                non_empty_paths: None,
+                enum_helpers: None,
+                notify_commands: None,
+                batch_notifications: None,
+                acronyms: None,
+                global_declarations: None,
+                strict_serde_derives: None,
+                skip_zero_sized_fields: None,
+                separate_input_types: None,
+                target: None,
+                kotlin_package: None,
+                viz_focus: None,
+                viz_depth: None,
+                viz_format: None,
+                validation_messages: None,
+                validation_messages_file: None,
+                schema_registry: None,
+                form_hooks: None,
+                xstate_actors: None,
+                web_fallback: None,
+                http_bridge: None,
+                admin_panel: None,
+                arbitraries: None,
+                injected_types: None,
+                field_overrides: None,
+                command_options: None,
+                hooks: None,
+                binary_helpers: None,
+                error_classes: None,
+                max_commands_per_file: None,
+                rename_map: None,
+                allow_outside_repo: None,
+                offline_queue: None,
+                query_keys: None,
+                source_trace: None,
+                macro_records: None,
+                additional_source_paths: None,
+                event_router: None,
+                sanitize_params: None,
+                command_validation: None,
+                aliases: None,
+                range_literals: None,
+                all_types: None,
+                shared_types: None,
+                lint_config: None,
+                framework: None,
+                unknown_type_fallback: None,
+                zod_object_mode: None,
+                test_harness: None,
+                mocks: None,
+                ipc_size_lint: None,
+                stores: None,
+                compact_types: None,
+                compact_codec: None,
+                self_test: None,
             }
         }
 
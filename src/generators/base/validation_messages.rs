@@ -0,0 +1,154 @@
+use serde::Serialize;
+use std::collections::{BTreeMap, HashMap};
+
+/// Validation message templates per constraint, used instead of the validation
+/// library's built-in (English) messages.
+///
+/// Templates may reference the constraint bounds as `{min}` and `{max}`:
+/// `{"minLength": "Mindestens {min} Zeichen"}`. A `message` given on the Rust
+/// `#[validate(...)]` attribute always takes precedence.
+#[derive(Debug, Clone, Default, PartialEq, Serialize)]
+#[serde(transparent)]
+pub struct ValidationMessages {
+    templates: BTreeMap<String, String>,
+}
+
+impl ValidationMessages {
+    /// Constraints a message template can be configured for. `length` and `range`
+    /// apply to both bounds unless `minLength`/`maxLength` or `min`/`max` are set.
+    pub const KEYS: &'static [&'static str] = &[
+        "minLength",
+        "maxLength",
+        "length",
+        "min",
+        "max",
+        "range",
+        "email",
+        "url",
+    ];
+
+    pub fn new(templates: HashMap<String, String>) -> Self {
+        Self {
+            templates: templates.into_iter().collect(),
+        }
+    }
+
+    /// Message for the lower bound of a `length` constraint
+    pub fn min_length(
+        &self,
+        explicit: &Option<String>,
+        min: Option<u64>,
+        max: Option<u64>,
+    ) -> Option<String> {
+        self.resolve(&["minLength", "length"], explicit, min, max)
+    }
+
+    /// Message for the upper bound of a `length` constraint
+    pub fn max_length(
+        &self,
+        explicit: &Option<String>,
+        min: Option<u64>,
+        max: Option<u64>,
+    ) -> Option<String> {
+        self.resolve(&["maxLength", "length"], explicit, min, max)
+    }
+
+    /// Message for the lower bound of a `range` constraint
+    pub fn min(
+        &self,
+        explicit: &Option<String>,
+        min: Option<f64>,
+        max: Option<f64>,
+    ) -> Option<String> {
+        self.resolve(&["min", "range"], explicit, min, max)
+    }
+
+    /// Message for the upper bound of a `range` constraint
+    pub fn max(
+        &self,
+        explicit: &Option<String>,
+        min: Option<f64>,
+        max: Option<f64>,
+    ) -> Option<String> {
+        self.resolve(&["max", "range"], explicit, min, max)
+    }
+
+    /// Message for a format constraint without bounds (`email`, `url`)
+    pub fn format(&self, key: &str) -> Option<String> {
+        self.templates.get(key).cloned()
+    }
+
+    fn resolve<T: ToString>(
+        &self,
+        keys: &[&str],
+        explicit: &Option<String>,
+        min: Option<T>,
+        max: Option<T>,
+    ) -> Option<String> {
+        if explicit.is_some() {
+            return explicit.clone();
+        }
+        let template = keys.iter().find_map(|key| self.templates.get(*key))?;
+        let bound = |value: Option<T>| value.map(|v| v.to_string()).unwrap_or_default();
+        Some(
+            template
+                .replace("{min}", &bound(min))
+                .replace("{max}", &bound(max)),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn messages(entries: &[(&str, &str)]) -> ValidationMessages {
+        ValidationMessages::new(
+            entries
+                .iter()
+                .map(|(key, template)| (key.to_string(), template.to_string()))
+                .collect(),
+        )
+    }
+
+    #[test]
+    fn test_templates_are_filled_with_bounds() {
+        let messages = messages(&[
+            ("minLength", "Mindestens {min} Zeichen"),
+            ("range", "Zwischen {min} und {max}"),
+        ]);
+        assert_eq!(
+            messages.min_length(&None, Some(3), None).as_deref(),
+            Some("Mindestens 3 Zeichen")
+        );
+        assert_eq!(
+            messages.max(&None, Some(0.5), Some(10.0)).as_deref(),
+            Some("Zwischen 0.5 und 10")
+        );
+    }
+
+    #[test]
+    fn test_specific_keys_take_precedence_over_groups() {
+        let messages = messages(&[("length", "Ungültige Länge"), ("maxLength", "Zu lang")]);
+        assert_eq!(
+            messages.min_length(&None, Some(1), Some(5)).as_deref(),
+            Some("Ungültige Länge")
+        );
+        assert_eq!(
+            messages.max_length(&None, Some(1), Some(5)).as_deref(),
+            Some("Zu lang")
+        );
+    }
+
+    #[test]
+    fn test_explicit_messages_take_precedence() {
+        let messages = messages(&[("min", "Zu klein")]);
+        let explicit = Some("Too small".to_string());
+        assert_eq!(
+            messages.min(&explicit, Some(1.0), None).as_deref(),
+            Some("Too small")
+        );
+        assert_eq!(messages.max(&None, None, Some(1.0)), None);
+        assert_eq!(messages.format("email"), None);
+    }
+}
@@ -0,0 +1,83 @@
+use crate::generators::base::casing::CaseConverter;
+use crate::generators::base::template_context::CommandContext;
+use crate::models::CommandKind;
+use serde::Serialize;
+
+/// Vue composable generated for a command, e.g. `useGetUser`: queries run when called
+/// and again when their parameters change, mutations run on `execute`
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct VueComposable {
+    pub name: String,
+    pub composable_name: String,
+    pub ts_function_name: String,
+    pub ts_type_name: String,
+    pub is_query: bool,
+    pub has_parameters: bool,
+    /// Whether mutations refetch the query's active composables once they succeed
+    pub invalidated: bool,
+    /// Function names of the queries a successful mutation refetches
+    pub invalidates: Vec<String>,
+}
+
+impl VueComposable {
+    /// Composables for the commands without channels, which stream their results instead
+    pub fn collect(commands: &[CommandContext], case_converter: &CaseConverter) -> Vec<Self> {
+        let invalidated = CommandContext::invalidated_queries(commands);
+        commands
+            .iter()
+            .filter(|command| command.channels.is_empty())
+            .map(|command| Self {
+                name: command.name.clone(),
+                composable_name: format!(
+                    "use{}",
+                    case_converter.to_pascal_case(&command.ts_function_name)
+                ),
+                ts_function_name: command.ts_function_name.clone(),
+                ts_type_name: command.ts_type_name.clone(),
+                is_query: command.kind == CommandKind::Query,
+                has_parameters: !command.parameters.is_empty(),
+                invalidated: invalidated.contains(command.ts_function_name.as_str()),
+                invalidates: command
+                    .invalidates
+                    .iter()
+                    .filter(|query| invalidated.contains(query.as_str()))
+                    .cloned()
+                    .collect(),
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::generators::ts::type_visitor::TypeScriptVisitor;
+    use crate::models::{CommandInfo, TypeStructure};
+    use crate::GenerateConfig;
+
+    #[test]
+    fn test_composables_by_command_kind() {
+        let config = GenerateConfig::default();
+        let visitor = TypeScriptVisitor::with_config(&config);
+        let resolver = |_: &str| TypeStructure::default();
+        let mut contexts: Vec<CommandContext> = ["get_user", "save_user"]
+            .iter()
+            .map(|name| {
+                let command =
+                    CommandInfo::new_for_test(*name, "src/lib.rs", 1, vec![], "()", true, vec![]);
+                CommandContext::new(&config).from_command_info(&command, &visitor, &resolver)
+            })
+            .collect();
+        contexts[1].invalidates = vec!["getUser".to_string(), "saveUser".to_string()];
+
+        let composables = VueComposable::collect(&contexts, &CaseConverter::new(&[]));
+        assert_eq!(composables[0].composable_name, "useGetUser");
+        assert!(composables[0].is_query);
+        assert!(composables[0].invalidated);
+        assert_eq!(composables[1].composable_name, "useSaveUser");
+        assert!(!composables[1].is_query);
+        // Only queries are refetched
+        assert_eq!(composables[1].invalidates, ["getUser"]);
+    }
+}
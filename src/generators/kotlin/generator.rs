@@ -0,0 +1,437 @@
+use crate::analysis::CommandAnalyzer;
+use crate::generators::base::casing::CaseConverter;
+use crate::generators::base::file_writer::FileWriter;
+use crate::generators::base::template_context::FieldContext;
+use crate::generators::base::templates::{GlobalContext, TemplateRegistry};
+use crate::generators::base::type_visitor::string_literal;
+use crate::generators::base::BaseBindingsGenerator;
+use crate::generators::kotlin::templates::KotlinTemplate;
+use crate::generators::kotlin::type_visitor::KotlinVisitor;
+use crate::generators::TypeCollector;
+use crate::models::{CommandInfo, StructInfo};
+use crate::{GenerateConfig, OutputFileNames, TypeStructure};
+use serde::Serialize;
+use std::collections::{HashMap, HashSet};
+use tera::{Context, Tera};
+
+/// Kotlin hard keywords, escaped with backticks when used as names
+const KEYWORDS: &[&str] = &[
+    "as",
+    "break",
+    "class",
+    "continue",
+    "do",
+    "else",
+    "false",
+    "for",
+    "fun",
+    "if",
+    "in",
+    "interface",
+    "is",
+    "null",
+    "object",
+    "package",
+    "return",
+    "super",
+    "this",
+    "throw",
+    "true",
+    "try",
+    "typealias",
+    "typeof",
+    "val",
+    "var",
+    "when",
+    "while",
+];
+
+/// A data class, enum class or type alias of the types file
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct KotlinType {
+    pub name: String,
+    /// Aliased type for newtypes and enums with data, `None` otherwise
+    pub alias: Option<String>,
+    /// Why the type is an alias, shown as its KDoc
+    pub alias_doc: Option<String>,
+    /// Entries of an enum class
+    pub entries: Vec<KotlinEntry>,
+    /// Properties of a data class
+    pub fields: Vec<KotlinField>,
+}
+
+/// An entry of an enum class, serialized as its variant name
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct KotlinEntry {
+    pub name: String,
+    /// `@SerialName` when the serialized name differs from the entry name
+    pub serial_name: Option<String>,
+}
+
+/// A property of a data class, named like the Rust field in camelCase
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct KotlinField {
+    pub name: String,
+    /// `@SerialName` when the serialized name differs from the property name
+    pub serial_name: Option<String>,
+    pub type_name: String,
+    pub default: Option<String>,
+}
+
+/// A suspend function of the `Commands` class invoking a command
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct KotlinCommand {
+    /// Name the command is invoked by
+    pub name: String,
+    pub function_name: String,
+    pub parameters: Vec<KotlinParameter>,
+    pub return_type: String,
+}
+
+/// A parameter of a command function, sent under its serialized name
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct KotlinParameter {
+    pub name: String,
+    pub serialized_name: String,
+    pub type_name: String,
+}
+
+/// Generator for Kotlin bindings: kotlinx.serialization data classes of the types and a
+/// `Commands` class of suspend functions calling the commands through an `invoke`
+/// function, for native Android code of Tauri mobile apps
+pub struct KotlinBindingsGenerator {
+    collector: TypeCollector,
+    tera: Tera,
+}
+
+impl KotlinBindingsGenerator {
+    pub fn new() -> Self {
+        Self {
+            collector: TypeCollector::new(),
+            tera: KotlinTemplate::create_tera()
+                .expect("Failed to initialize Kotlin template engine"),
+        }
+    }
+
+    /// Name of the Kotlin file replacing a TypeScript file, e.g. `Types.kt` for
+    /// `types.ts`
+    pub fn file_name(file_name: &str, case_converter: &CaseConverter) -> String {
+        let module = OutputFileNames::module_name(file_name);
+        format!("{}.kt", case_converter.to_pascal_case(&module))
+    }
+
+    /// A name as a Kotlin identifier, keywords escaped with backticks
+    pub fn identifier(name: String) -> String {
+        let name = CaseConverter::identifier(name);
+        if KEYWORDS.contains(&name.as_str()) {
+            format!("`{}`", name)
+        } else {
+            name
+        }
+    }
+
+    /// Declarations of the used types, ordered by name
+    fn create_types(
+        &self,
+        used_structs: &HashMap<String, StructInfo>,
+        local_types: &HashSet<String>,
+        config: &GenerateConfig,
+    ) -> Vec<KotlinType> {
+        let visitor = KotlinVisitor::with_config(config).with_local_types(local_types);
+        let case_converter = CaseConverter::new(config.acronyms());
+        let mut names: Vec<&String> = used_structs.keys().collect();
+        names.sort();
+
+        names
+            .into_iter()
+            .map(|name| {
+                let struct_info = &used_structs[name];
+                let mut kotlin_type = KotlinType {
+                    name: name.clone(),
+                    alias: None,
+                    alias_doc: None,
+                    entries: Vec::new(),
+                    fields: Vec::new(),
+                };
+                let fields = self
+                    .collector
+                    .create_field_contexts(struct_info, &visitor, config);
+
+                if struct_info.is_enum {
+                    if struct_info.has_variant_data() || !struct_info.is_externally_tagged() {
+                        // Sealed classes would need custom serializers, or a discriminator
+                        // per enum, which kotlinx.serialization only has as an experimental
+                        // API, so the value is left for the caller to decode
+                        kotlin_type.alias = Some("JsonElement".to_string());
+                        kotlin_type.alias_doc = Some(format!(
+                            "{} enum `{}`, kept as JSON",
+                            if struct_info.is_untagged() {
+                                "Untagged"
+                            } else if struct_info.is_internally_tagged() {
+                                "Internally tagged"
+                            } else if struct_info.is_adjacently_tagged() {
+                                "Adjacently tagged"
+                            } else {
+                                "Externally tagged"
+                            },
+                            name
+                        ));
+                    } else {
+                        kotlin_type.entries = fields
+                            .iter()
+                            .map(|variant| KotlinEntry {
+                                name: Self::identifier(variant.name.clone()),
+                                serial_name: (variant.serialized_name != variant.name)
+                                    .then(|| variant.serialized_name.clone()),
+                            })
+                            .collect();
+                    }
+                } else if struct_info.is_newtype && !struct_info.fields.is_empty() {
+                    let field = &struct_info.fields[0];
+                    kotlin_type.alias =
+                        Some(visitor.annotate(&field.rust_type, &field.type_structure));
+                } else {
+                    kotlin_type.fields = self
+                        .collector
+                        .serialized_fields(struct_info, config)
+                        .iter()
+                        .map(|field| {
+                            let field_visitor =
+                                visitor.for_rust_type(&field.rust_type, &field.type_structure);
+                            let context = FieldContext::new(config).from_field_info(
+                                field,
+                                &struct_info.serde_rename_all,
+                                &field_visitor,
+                            );
+                            let optional = field.is_optional
+                                || matches!(field.type_structure, TypeStructure::Optional(_));
+                            let property = CaseConverter::identifier(
+                                case_converter.to_camel_case(&field.name),
+                            );
+                            KotlinField {
+                                serial_name: (context.serialized_name != property)
+                                    .then(|| context.serialized_name.clone()),
+                                name: Self::identifier(property),
+                                type_name: if optional
+                                    && field.literal.is_none()
+                                    && !context.typescript_type.ends_with('?')
+                                {
+                                    format!("{}?", context.typescript_type)
+                                } else {
+                                    context.typescript_type
+                                },
+                                default: match field.literal {
+                                    Some(ref literal) => Some(string_literal(literal)),
+                                    // Fields the backend may leave out are `null` when absent,
+                                    // and `null` is left out again when sent
+                                    None => optional.then(|| "null".to_string()),
+                                },
+                            }
+                        })
+                        .collect();
+                }
+                kotlin_type
+            })
+            .collect()
+    }
+
+    /// Functions of the commands without channels, which need a Tauri webview to stream
+    fn create_commands(
+        &self,
+        commands: &[CommandInfo],
+        local_types: &HashSet<String>,
+        analyzer: &CommandAnalyzer,
+        config: &GenerateConfig,
+    ) -> Vec<KotlinCommand> {
+        let visitor = KotlinVisitor::with_config(config).with_local_types(local_types);
+        let case_converter = CaseConverter::new(config.acronyms());
+        let contexts = self
+            .collector
+            .create_command_contexts(commands, &visitor, analyzer, config);
+        commands
+            .iter()
+            .zip(&contexts)
+            .filter(|(command, _)| command.channels.is_empty())
+            .map(|(command, context)| KotlinCommand {
+                name: command.name.clone(),
+                function_name: Self::identifier(context.ts_function_name.clone()),
+                parameters: command
+                    .parameters
+                    .iter()
+                    .zip(&context.parameters)
+                    .map(|(param, param_context)| KotlinParameter {
+                        name: Self::identifier(CaseConverter::identifier(
+                            case_converter.to_camel_case(&param.name),
+                        )),
+                        serialized_name: param_context.serialized_name.clone(),
+                        type_name: visitor.annotate(&param.rust_type, &param.type_structure),
+                    })
+                    .collect(),
+                return_type: visitor.annotate(&command.return_type, &command.return_type_structure),
+            })
+            .collect()
+    }
+
+    fn render_file(&self, template_name: &str, context: &Context) -> String {
+        self.render(template_name, context).unwrap_or_else(|e| {
+            eprintln!("Template rendering failed for {}: {}", template_name, e);
+            String::new()
+        })
+    }
+}
+
+impl BaseBindingsGenerator for KotlinBindingsGenerator {
+    fn tera(&self) -> &Tera {
+        &self.tera
+    }
+
+    fn type_collector(&self) -> &TypeCollector {
+        &self.collector
+    }
+
+    fn generator_type(&self) -> String {
+        "kotlin".to_string()
+    }
+
+    fn language(&self) -> String {
+        "Kotlin".to_string()
+    }
+
+    fn import_hint(&self, _output_path: &str, config: &GenerateConfig) -> Option<String> {
+        Some(format!("import {}.Commands", config.kotlin_package()))
+    }
+
+    /// The default header in comments of Kotlin
+    fn generate_file_header(&self) -> String {
+        let mut context = Context::new();
+        context.insert("global", &GlobalContext::new(&self.generator_type()));
+        self.render_file("kotlin/header.tera", &context)
+    }
+
+    fn write_models(
+        &mut self,
+        file_writer: &mut FileWriter,
+        commands: &[CommandInfo],
+        discovered_structs: &HashMap<String, StructInfo>,
+        analyzer: &CommandAnalyzer,
+        config: &GenerateConfig,
+    ) -> Result<Vec<String>, Box<dyn std::error::Error>> {
+        // Types used by commands and events, like the TypeScript bindings
+        let used_structs = self.collector.collect_bindings_types(
+            commands,
+            analyzer.get_discovered_events(),
+            discovered_structs,
+            HashMap::new(),
+            config,
+        );
+
+        // Types without a definition are `JsonElement`, unless they fail generation
+        if config.unknown_type_fallback() == "error" {
+            self.collector.resolve_unknown_types(
+                commands,
+                analyzer.get_discovered_events(),
+                &used_structs,
+                config,
+            )?;
+        }
+
+        let local_types: HashSet<String> = used_structs.keys().cloned().collect();
+        let file_names = config.file_names();
+        let case_converter = CaseConverter::new(config.acronyms());
+
+        let mut context = Context::new();
+        context.insert("header", &self.generate_file_header());
+        context.insert("package", config.kotlin_package());
+        context.insert(
+            "types",
+            &self.create_types(&used_structs, &local_types, config),
+        );
+        let types_content = self.render_file("kotlin/types.kt.tera", &context);
+        file_writer.write_typescript_file(
+            &Self::file_name(&file_names.types, &case_converter),
+            &types_content,
+        )?;
+
+        let mut context = Context::new();
+        context.insert("header", &self.generate_file_header());
+        context.insert("package", config.kotlin_package());
+        context.insert(
+            "commands",
+            &self.create_commands(commands, &local_types, analyzer, config),
+        );
+        context.insert(
+            "skipped_commands",
+            &commands
+                .iter()
+                .filter(|command| !command.channels.is_empty())
+                .map(|command| command.name.as_str())
+                .collect::<Vec<_>>(),
+        );
+        let commands_content = self.render_file("kotlin/commands.kt.tera", &context);
+        file_writer.write_typescript_file(
+            &Self::file_name(&file_names.commands, &case_converter),
+            &commands_content,
+        )?;
+
+        Ok(file_writer.get_generated_files().to_vec())
+    }
+}
+
+impl Default for KotlinBindingsGenerator {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_import_hint_uses_the_package() {
+        let generator = KotlinBindingsGenerator::new();
+        let config = GenerateConfig {
+            kotlin_package: Some("com.example.app".to_string()),
+            ..Default::default()
+        };
+        assert_eq!(generator.language(), "Kotlin");
+        assert_eq!(
+            generator.import_hint("./android", &config).as_deref(),
+            Some("import com.example.app.Commands")
+        );
+    }
+
+    #[test]
+    fn test_identifiers_escape_keywords() {
+        assert_eq!(
+            KotlinBindingsGenerator::identifier("userName".to_string()),
+            "userName"
+        );
+        assert_eq!(
+            KotlinBindingsGenerator::identifier("when".to_string()),
+            "`when`"
+        );
+        assert_eq!(
+            KotlinBindingsGenerator::identifier("2fa".to_string()),
+            "_2fa"
+        );
+    }
+
+    #[test]
+    fn test_file_names_of_typescript_files() {
+        let case_converter = CaseConverter::new(&[]);
+        assert_eq!(
+            KotlinBindingsGenerator::file_name("types.ts", &case_converter),
+            "Types.kt"
+        );
+        assert_eq!(
+            KotlinBindingsGenerator::file_name("api-commands.ts", &case_converter),
+            "ApiCommands.kt"
+        );
+    }
+}
@@ -0,0 +1,3 @@
+pub mod generator;
+pub mod templates;
+pub mod type_visitor;
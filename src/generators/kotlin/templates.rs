@@ -0,0 +1,40 @@
+use crate::{generators::base::templates::TemplateRegistry, template};
+use tera::Tera;
+
+pub struct KotlinTemplate;
+
+/// Create and configure a Tera template engine for the Kotlin generator
+impl TemplateRegistry for KotlinTemplate {
+    fn register_filters(_tera: &mut Tera) {}
+
+    /// Register kotlin-specific templates from embedded strings
+    fn register_templates(tera: &mut Tera) -> Result<(), String> {
+        template!(tera, "kotlin/header.tera", "templates/header.tera");
+        template!(tera, "kotlin/types.kt.tera", "templates/types.kt.tera");
+        template!(
+            tera,
+            "kotlin/commands.kt.tera",
+            "templates/commands.kt.tera"
+        );
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_create_tera_registers_kotlin_templates() {
+        let tera = KotlinTemplate::create_tera().unwrap();
+        let names: Vec<&str> = tera.get_template_names().collect();
+        for name in [
+            "kotlin/header.tera",
+            "kotlin/types.kt.tera",
+            "kotlin/commands.kt.tera",
+        ] {
+            assert!(names.contains(&name), "missing {}", name);
+        }
+    }
+}
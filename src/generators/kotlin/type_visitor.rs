@@ -0,0 +1,211 @@
+use crate::analysis::type_resolver::JSON_VALUE_TYPE;
+use crate::generators::base::type_visitor::{rust_number_types, TypeVisitor};
+use crate::models::TypeStructure;
+use crate::GenerateConfig;
+use std::cell::RefCell;
+use std::collections::HashSet;
+
+/// Kotlin type visitor - converts TypeStructure to types of kotlinx.serialization data
+/// classes
+///
+/// TypeStructure only knows `number`, so [`KotlinVisitor::for_rust_type`] recovers the
+/// Kotlin number type from the Rust type the structure was parsed from.
+pub struct KotlinVisitor<'a> {
+    config: Option<&'a GenerateConfig>,
+    /// Types declared in the generated types file; other custom types are `JsonElement`
+    local_types: Option<&'a HashSet<String>>,
+    /// Kotlin types of the remaining numbers of the visited type, last one first
+    numbers: RefCell<Vec<&'static str>>,
+}
+
+impl<'a> Default for KotlinVisitor<'a> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<'a> KotlinVisitor<'a> {
+    pub fn new() -> Self {
+        Self {
+            config: None,
+            local_types: None,
+            numbers: RefCell::new(Vec::new()),
+        }
+    }
+
+    pub fn with_config(config: &'a GenerateConfig) -> Self {
+        Self {
+            config: Some(config),
+            ..Self::new()
+        }
+    }
+
+    /// Reference the given types of the types file by name
+    pub fn with_local_types(mut self, local_types: &'a HashSet<String>) -> Self {
+        self.local_types = Some(local_types);
+        self
+    }
+
+    /// Visitor for `structure` parsed from `rust_type`, typing its numbers after the
+    /// Rust number types; numbers behind type aliases are `Double`
+    pub fn for_rust_type(&self, rust_type: &str, structure: &TypeStructure) -> Self {
+        let mut numbers: Vec<&'static str> = rust_number_types(rust_type, structure)
+            .into_iter()
+            .map(Self::number_type)
+            .collect();
+        numbers.reverse();
+
+        Self {
+            config: self.config,
+            local_types: self.local_types,
+            numbers: RefCell::new(numbers),
+        }
+    }
+
+    /// Type of `structure` parsed from `rust_type`
+    pub fn annotate(&self, rust_type: &str, structure: &TypeStructure) -> String {
+        self.for_rust_type(rust_type, structure)
+            .visit_type(structure)
+    }
+
+    /// Kotlin type of a Rust number type, unsigned ones included; 128-bit integers
+    /// don't fit a Kotlin number and are `Double`
+    fn number_type(rust_type: &str) -> &'static str {
+        match rust_type {
+            "i8" => "Byte",
+            "i16" => "Short",
+            "i32" => "Int",
+            "i64" | "isize" => "Long",
+            "u8" => "UByte",
+            "u16" => "UShort",
+            "u32" => "UInt",
+            "u64" | "usize" => "ULong",
+            "f32" => "Float",
+            _ => "Double",
+        }
+    }
+}
+
+impl<'a> TypeVisitor for KotlinVisitor<'a> {
+    fn get_config(&self) -> Option<&GenerateConfig> {
+        self.config
+    }
+
+    fn visit_primitive(&self, type_name: &str) -> String {
+        match type_name {
+            "string" => "String".to_string(),
+            "number" => self
+                .numbers
+                .borrow_mut()
+                .pop()
+                .unwrap_or("Double")
+                .to_string(),
+            "boolean" => "Boolean".to_string(),
+            "void" => "Unit".to_string(),
+            "Uint8Array" => "List<UByte>".to_string(),
+            _ => "JsonElement".to_string(),
+        }
+    }
+
+    fn visit_array(&self, inner: &TypeStructure) -> String {
+        format!("List<{}>", self.visit_type(inner))
+    }
+
+    fn visit_map(&self, key: &TypeStructure, value: &TypeStructure) -> String {
+        format!("Map<{}, {}>", self.visit_type(key), self.visit_type(value))
+    }
+
+    fn visit_set(&self, inner: &TypeStructure) -> String {
+        format!("Set<{}>", self.visit_type(inner))
+    }
+
+    /// Tuples are JSON arrays of mixed types, which kotlinx.serialization reads as
+    /// `JsonArray` (`Pair` and `Triple` are objects)
+    fn visit_tuple(&self, types: &[TypeStructure]) -> String {
+        if types.is_empty() {
+            "Unit".to_string()
+        } else {
+            // Keep the numbers of the following types aligned
+            for structure in types {
+                self.visit_type(structure);
+            }
+            "JsonArray".to_string()
+        }
+    }
+
+    fn visit_optional(&self, inner: &TypeStructure) -> String {
+        let inner = self.visit_type(inner);
+        if inner.ends_with('?') {
+            inner
+        } else {
+            format!("{}?", inner)
+        }
+    }
+
+    fn visit_string_literal(&self, _literal: &str, optional: bool) -> String {
+        if optional {
+            "String?".to_string()
+        } else {
+            "String".to_string()
+        }
+    }
+
+    fn visit_union(&self, types: &[TypeStructure]) -> String {
+        for structure in types {
+            self.visit_type(structure);
+        }
+        "JsonElement".to_string()
+    }
+
+    /// Types of the types file, `JsonElement` for everything else: type mappings and
+    /// field overrides name TypeScript types
+    fn visit_custom(&self, name: &str) -> String {
+        match self.local_types {
+            Some(local_types) if name != JSON_VALUE_TYPE && local_types.contains(name) => {
+                name.to_string()
+            }
+            _ => "JsonElement".to_string(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn number() -> TypeStructure {
+        TypeStructure::Primitive("number".to_string())
+    }
+
+    #[test]
+    fn test_numbers_follow_rust_types() {
+        let visitor = KotlinVisitor::new();
+        let structure = TypeStructure::Map {
+            key: Box::new(TypeStructure::Primitive("string".to_string())),
+            value: Box::new(TypeStructure::Array(Box::new(number()))),
+        };
+        assert_eq!(
+            visitor.annotate("HashMap<String, Vec<u32>>", &structure),
+            "Map<String, List<UInt>>"
+        );
+        let tuple = TypeStructure::Tuple(vec![number(), number()]);
+        assert_eq!(visitor.annotate("(i64, f32)", &tuple), "JsonArray");
+    }
+
+    #[test]
+    fn test_local_and_unknown_types() {
+        let local_types = HashSet::from(["User".to_string()]);
+        let visitor = KotlinVisitor::new().with_local_types(&local_types);
+        let optional =
+            |name: &str| TypeStructure::Optional(Box::new(TypeStructure::Custom(name.to_string())));
+        assert_eq!(visitor.visit_type(&optional("User")), "User?");
+        assert_eq!(
+            visitor.visit_type(&optional(JSON_VALUE_TYPE)),
+            "JsonElement?"
+        );
+        assert_eq!(
+            visitor.visit_type(&TypeStructure::Optional(Box::new(optional("User")))),
+            "User?"
+        );
+    }
+}
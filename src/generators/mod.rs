@@ -1,17 +1,38 @@
 pub mod base;
+pub mod kotlin;
+pub mod python;
+pub mod registry;
+pub mod swift;
 pub mod ts;
+pub mod valibot;
 pub mod zod;
 
+use crate::analysis::type_resolver::JSON_VALUE_TYPE;
 use crate::analysis::CommandAnalyzer;
-use crate::models::{CommandInfo, EventInfo, StructInfo};
+use crate::interface::output::{Logger, Phase};
+use crate::models::{
+    CommandInfo, ConstInfo, ConstValue, EventInfo, FieldInfo, SkipDirection, StructInfo,
+};
 use crate::GenerateConfig;
-use base::template_context::{CommandContext, EventContext, FieldContext, StructContext};
+use crate::TypeStructure;
+use base::casing::CaseConverter;
+use base::examples::ExampleBuilder;
+use base::progress::ProgressFields;
+use base::template_context::{
+    CommandContext, ConstantContext, EventContext, FieldContext, NamingContext, StructContext,
+    TypeImportContext,
+};
 use base::type_visitor::TypeVisitor;
-use std::collections::HashMap;
+use std::cell::RefCell;
+use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet};
 
 pub use base::templates::GlobalContext;
 pub use base::BaseBindingsGenerator as BindingsGenerator;
+pub use kotlin::generator::KotlinBindingsGenerator;
+pub use python::generator::PythonBindingsGenerator;
+pub use swift::generator::SwiftBindingsGenerator;
 pub use ts::generator::TypeScriptBindingsGenerator;
+pub use valibot::generator::ValibotBindingsGenerator;
 pub use zod::generator::ZodBindingsGenerator;
 
 /// Macro to reduce boilerplate for template registration
@@ -25,11 +46,27 @@ macro_rules! template {
 }
 
 /// Factory function to create the appropriate bindings generator
-/// Returns a boxed trait object for polymorphism
+/// Returns a boxed trait object for polymorphism; names of registered generators (see
+/// [`registry`]) create those. Other names fall back to plain TypeScript, so callers
+/// reject them first with [`GenerateConfig::has_known_generator`].
 pub fn create_generator(validation_library: Option<String>) -> Box<dyn BindingsGenerator> {
     match validation_library.as_deref().unwrap_or("none") {
         "zod" => Box::new(ZodBindingsGenerator::new()),
-        _ => Box::new(TypeScriptBindingsGenerator::new()),
+        "valibot" => Box::new(ValibotBindingsGenerator::new()),
+        name => {
+            registry::create(name).unwrap_or_else(|| Box::new(TypeScriptBindingsGenerator::new()))
+        }
+    }
+}
+
+/// Create the generator of the configured target, the one of the validation library
+/// for TypeScript
+pub fn generator_for(config: &GenerateConfig) -> Box<dyn BindingsGenerator> {
+    match config.target() {
+        "python" => Box::new(PythonBindingsGenerator::new()),
+        "kotlin" => Box::new(KotlinBindingsGenerator::new()),
+        "swift" => Box::new(SwiftBindingsGenerator::new()),
+        _ => create_generator(Some(config.generator_library().to_string())),
     }
 }
 
@@ -41,13 +78,219 @@ pub fn create_generator(validation_library: Option<String>) -> Box<dyn BindingsG
 /// filtering unused code and collecting only the types needed for generation.
 pub struct TypeCollector {
     pub known_structs: HashMap<String, StructInfo>,
+    /// Naming diagnostics already printed (contexts are created once per output file)
+    reported_warnings: RefCell<HashSet<String>>,
 }
 
 impl TypeCollector {
     pub fn new() -> Self {
         Self {
             known_structs: HashMap::new(),
+            reported_warnings: RefCell::new(HashSet::new()),
+        }
+    }
+
+    /// Whether `name` is a known struct serialized as an object, whose fields can be
+    /// spread into another object (flattened fields, internally tagged variants)
+    pub fn is_object_struct(&self, name: &str) -> bool {
+        self.known_structs
+            .get(name)
+            .is_some_and(|struct_info| !struct_info.is_enum && !struct_info.is_newtype)
+    }
+
+    /// Value type of the keys a flattened map adds: the values of the map when it is
+    /// the only field, otherwise `unknown`, since its index signature also covers the
+    /// keys of the other fields and would make their types conflict
+    pub fn flattened_map_value(struct_info: &StructInfo, value: &TypeStructure) -> TypeStructure {
+        if struct_info.fields.len() == 1 {
+            value.clone()
+        } else {
+            TypeStructure::Primitive("unknown".to_string())
+        }
+    }
+
+    /// Fields as serde serializes them: the fields of flattened structs are inlined
+    /// with their serialized names resolved, and become optional when the flattened
+    /// field is. Other flattened fields (maps) have no fixed keys and are left out.
+    pub fn serialized_fields(
+        &self,
+        struct_info: &StructInfo,
+        config: &GenerateConfig,
+    ) -> Vec<FieldInfo> {
+        let mut fields = Vec::new();
+        for field in &struct_info.fields {
+            if !field.flatten {
+                fields.push(field.clone());
+                continue;
+            }
+            let (optional, inner) = match &field.type_structure {
+                TypeStructure::Optional(inner) => (true, inner.as_ref()),
+                structure => (false, structure),
+            };
+            let TypeStructure::Custom(name) = inner else {
+                continue;
+            };
+            if !self.is_object_struct(name) {
+                continue;
+            }
+            let flattened = &self.known_structs[name];
+            let naming = FieldContext::new(config);
+            for mut inlined in self.serialized_fields(flattened, config) {
+                inlined.serde_rename = Some(naming.compute_field_name(
+                    &inlined.name,
+                    &inlined.serde_rename,
+                    &flattened.serde_rename_all,
+                ));
+                if optional && !inlined.is_optional {
+                    inlined.is_optional = true;
+                    inlined.rust_type = format!("Option<{}>", inlined.rust_type);
+                    inlined.type_structure =
+                        TypeStructure::Optional(Box::new(inlined.type_structure));
+                }
+                fields.push(inlined);
+            }
         }
+        fields
+    }
+
+    /// Types the bindings define: those used by commands and event payloads, the
+    /// `extra` types a target adds (e.g. error and store types) and, for a shared types
+    /// package, every discovered type. Fields serde skips in one direction are applied
+    /// by the way each type travels (see [`Self::apply_skip_directions`]).
+    pub fn collect_bindings_types(
+        &mut self,
+        commands: &[CommandInfo],
+        events: &[EventInfo],
+        discovered_structs: &HashMap<String, StructInfo>,
+        extra: HashMap<String, StructInfo>,
+        config: &GenerateConfig,
+    ) -> HashMap<String, StructInfo> {
+        self.known_structs = discovered_structs.clone();
+        let traced = config.verbosity().enabled(Phase::Generation, 2);
+        // Why each type was included, only kept for `--trace generation`
+        let mut reasons: BTreeMap<String, &'static str> = BTreeMap::new();
+
+        let mut used_structs = self.collect_used_types(commands, discovered_structs);
+        if traced {
+            let mut command_types = HashSet::new();
+            for command in commands {
+                for param in &command.parameters {
+                    Self::collect_referenced_types_from_structure(
+                        &param.type_structure,
+                        &mut command_types,
+                    );
+                }
+                Self::collect_referenced_types_from_structure(
+                    &command.return_type_structure,
+                    &mut command_types,
+                );
+                for channel in &command.channels {
+                    Self::collect_referenced_types_from_structure(
+                        &channel.message_type_structure,
+                        &mut command_types,
+                    );
+                }
+            }
+            for name in used_structs.keys() {
+                let reason = if command_types.contains(name) {
+                    "used by a command"
+                } else {
+                    "referenced by another used type"
+                };
+                reasons.insert(name.clone(), reason);
+            }
+        }
+        for event in events {
+            let mut event_types = HashSet::new();
+            Self::collect_referenced_types_from_structure(
+                &event.payload_type_structure,
+                &mut event_types,
+            );
+            used_structs.extend(event_types.into_iter().filter_map(|type_name| {
+                let struct_info = discovered_structs.get(&type_name)?.clone();
+                if traced {
+                    reasons
+                        .entry(type_name.clone())
+                        .or_insert("payload of an event");
+                }
+                Some((type_name, struct_info))
+            }));
+        }
+        if traced {
+            for name in extra.keys() {
+                reasons
+                    .entry(name.clone())
+                    .or_insert("needed by the target's error classes or stores");
+            }
+        }
+        used_structs.extend(extra);
+        if config.should_include_all_types() {
+            if traced {
+                for name in discovered_structs.keys() {
+                    reasons.entry(name.clone()).or_insert("allTypes is set");
+                }
+            }
+            used_structs.extend(discovered_structs.clone());
+        }
+
+        let before_skipping = traced.then(|| used_structs.clone());
+        self.apply_skip_directions(commands, events, &mut used_structs);
+        if let Some(before_skipping) = before_skipping {
+            let logger = Logger::with_verbosity(config.verbosity());
+            for decision in Self::selection_decisions(
+                discovered_structs,
+                &reasons,
+                &before_skipping,
+                &used_structs,
+            ) {
+                logger.detail(Phase::Generation, 2, &decision);
+            }
+        }
+        used_structs
+    }
+
+    /// Describe which types were chosen or skipped for the bindings and why, and
+    /// which fields the skip directions removed or made optional
+    fn selection_decisions(
+        discovered_structs: &HashMap<String, StructInfo>,
+        reasons: &BTreeMap<String, &'static str>,
+        before_skipping: &HashMap<String, StructInfo>,
+        used_structs: &HashMap<String, StructInfo>,
+    ) -> Vec<String> {
+        let mut decisions: Vec<String> = reasons
+            .iter()
+            .map(|(name, reason)| format!("Type {} included: {}", name, reason))
+            .collect();
+        let skipped: BTreeSet<&String> = discovered_structs
+            .keys()
+            .filter(|name| !used_structs.contains_key(*name))
+            .collect();
+        decisions.extend(
+            skipped
+                .into_iter()
+                .map(|name| format!("Type {} skipped: not used by any command or event", name)),
+        );
+
+        for name in reasons.keys() {
+            let (Some(before), Some(after)) = (before_skipping.get(name), used_structs.get(name))
+            else {
+                continue;
+            };
+            for field in &before.fields {
+                match after.fields.iter().find(|kept| kept.name == field.name) {
+                    None => decisions.push(format!(
+                        "Field {}.{} removed: skipped in the only direction {} travels in",
+                        name, field.name, name
+                    )),
+                    Some(kept) if kept.omittable && !field.omittable => decisions.push(format!(
+                        "Field {}.{} made optional: skipped in one of the directions {} travels in",
+                        name, field.name, name
+                    )),
+                    Some(_) => {}
+                }
+            }
+        }
+        decisions
     }
 
     /// Filter only the types used by commands
@@ -95,6 +338,335 @@ impl TypeCollector {
             .collect()
     }
 
+    /// Apply `#[serde(skip_serializing)]` and `#[serde(skip_deserializing)]` by the
+    /// direction each used type travels in: command parameters are deserialized by the
+    /// backend, results, channel messages and events serialized. Fields skipped in the
+    /// only direction a type travels in are removed; types traveling both ways (or in
+    /// no known direction, like error and store types) keep them as optional keys.
+    pub fn apply_skip_directions(
+        &self,
+        commands: &[CommandInfo],
+        events: &[EventInfo],
+        used_structs: &mut HashMap<String, StructInfo>,
+    ) {
+        let mut inputs = HashSet::new();
+        let mut outputs = HashSet::new();
+        for command in commands {
+            for param in &command.parameters {
+                Self::collect_referenced_types_from_structure(&param.type_structure, &mut inputs);
+            }
+            Self::collect_referenced_types_from_structure(
+                &command.return_type_structure,
+                &mut outputs,
+            );
+            for channel in &command.channels {
+                Self::collect_referenced_types_from_structure(
+                    &channel.message_type_structure,
+                    &mut outputs,
+                );
+            }
+        }
+        for event in events {
+            Self::collect_referenced_types_from_structure(
+                &event.payload_type_structure,
+                &mut outputs,
+            );
+        }
+        for types in [&mut inputs, &mut outputs] {
+            let initial_types = types.clone();
+            self.discover_nested_dependencies(&initial_types, used_structs, types);
+        }
+
+        for (name, struct_info) in used_structs.iter_mut() {
+            if struct_info.is_enum {
+                continue;
+            }
+            let (mut input, mut output) = (inputs.contains(name), outputs.contains(name));
+            if !input && !output {
+                (input, output) = (true, true);
+            }
+            struct_info.fields.retain_mut(|field| {
+                // Whether the type travels the way the field is skipped in, and the other way
+                let (skipped_way, other_way) = match field.skipped {
+                    None => return true,
+                    Some(SkipDirection::Serializing) => (output, input),
+                    Some(SkipDirection::Deserializing) => (input, output),
+                };
+                if skipped_way && other_way {
+                    field.is_optional = true;
+                    field.omittable = true;
+                }
+                !skipped_way || other_way
+            });
+        }
+    }
+
+    /// Collect the error types commands fail with (`E` of `Result<T, E>`) and the
+    /// types they reference, for the generated error classes
+    pub fn collect_error_types(
+        &self,
+        commands: &[CommandInfo],
+        all_structs: &HashMap<String, StructInfo>,
+    ) -> HashMap<String, StructInfo> {
+        let mut error_types: HashSet<String> = commands
+            .iter()
+            .filter_map(|command| base::error_classes::ErrorClass::err_type(&command.return_type))
+            .filter(|name| all_structs.contains_key(*name))
+            .map(String::from)
+            .collect();
+        let initial_types = error_types.clone();
+        self.discover_nested_dependencies(&initial_types, all_structs, &mut error_types);
+
+        all_structs
+            .iter()
+            .filter(|(name, _)| error_types.contains(*name))
+            .map(|(k, v)| (k.clone(), v.clone()))
+            .collect()
+    }
+
+    /// Collect the structs of the configured settings stores (see `stores`) and the
+    /// types they reference
+    pub fn collect_store_types(
+        &self,
+        all_structs: &HashMap<String, StructInfo>,
+        config: &GenerateConfig,
+    ) -> HashMap<String, StructInfo> {
+        let mut store_types: HashSet<String> = config
+            .stores()
+            .into_iter()
+            .map(|(_, type_name)| type_name)
+            .filter(|name| all_structs.contains_key(*name))
+            .map(String::from)
+            .collect();
+        let initial_types = store_types.clone();
+        self.discover_nested_dependencies(&initial_types, all_structs, &mut store_types);
+
+        all_structs
+            .iter()
+            .filter(|(name, _)| store_types.contains(*name))
+            .map(|(k, v)| (k.clone(), v.clone()))
+            .collect()
+    }
+
+    /// Check whether commands or structs reference the built-in `JsonValue` type
+    /// (generated for `serde_json::Value`) and no user type of that name exists
+    pub fn uses_json_value(
+        &self,
+        commands: &[CommandInfo],
+        used_structs: &HashMap<String, StructInfo>,
+    ) -> bool {
+        if used_structs.contains_key(JSON_VALUE_TYPE) {
+            return false;
+        }
+
+        Self::referenced_types(commands, used_structs).contains(JSON_VALUE_TYPE)
+    }
+
+    /// Custom type names referenced by the commands and the fields of the structs
+    fn referenced_types(
+        commands: &[CommandInfo],
+        used_structs: &HashMap<String, StructInfo>,
+    ) -> HashSet<String> {
+        let mut referenced = HashSet::new();
+        for command in commands {
+            for param in &command.parameters {
+                Self::collect_referenced_types_from_structure(
+                    &param.type_structure,
+                    &mut referenced,
+                );
+            }
+            Self::collect_referenced_types_from_structure(
+                &command.return_type_structure,
+                &mut referenced,
+            );
+            for channel in &command.channels {
+                Self::collect_referenced_types_from_structure(
+                    &channel.message_type_structure,
+                    &mut referenced,
+                );
+            }
+        }
+        for struct_info in used_structs.values() {
+            for field in &struct_info.fields {
+                Self::collect_referenced_types_from_structure(
+                    &field.type_structure,
+                    &mut referenced,
+                );
+                for variant_field in &field.variant_fields {
+                    Self::collect_referenced_types_from_structure(
+                        &variant_field.type_structure,
+                        &mut referenced,
+                    );
+                }
+            }
+        }
+
+        referenced
+    }
+
+    /// Types referenced by the commands, events or structs that no definition was found
+    /// for: neither a struct, the built-in `JsonValue`, a type mapping nor a field override
+    pub fn unresolved_types(
+        &self,
+        commands: &[CommandInfo],
+        events: &[EventInfo],
+        used_structs: &HashMap<String, StructInfo>,
+        config: &GenerateConfig,
+    ) -> BTreeSet<String> {
+        let mut referenced = Self::referenced_types(commands, used_structs);
+        for event in events {
+            Self::collect_referenced_types_from_structure(
+                &event.payload_type_structure,
+                &mut referenced,
+            );
+        }
+
+        referenced
+            .into_iter()
+            // Struct variants of enums are typed by their variant fields, with an
+            // `enum_variant` placeholder
+            .filter(|name| {
+                name != JSON_VALUE_TYPE
+                    && name != "enum_variant"
+                    && !used_structs.contains_key(name)
+                    && !self.known_structs.contains_key(name)
+                    && config.field_override(name).is_none()
+                    && !config
+                        .type_mappings
+                        .as_ref()
+                        .is_some_and(|mappings| mappings.contains_key(name))
+            })
+            .collect()
+    }
+
+    /// `config` with the `serdeWithMappings` and the unresolved types (see
+    /// [`Self::unresolved_types`]) added to its type mappings, the latter as its
+    /// `unknownTypeFallback` type, or an error naming them if the fallback is `"error"`
+    pub fn resolve_unknown_types(
+        &self,
+        commands: &[CommandInfo],
+        events: &[EventInfo],
+        used_structs: &HashMap<String, StructInfo>,
+        config: &GenerateConfig,
+    ) -> Result<GenerateConfig, String> {
+        let mut config = config.clone();
+        // Fields converted with a mapped `serde(with)` path reference it as a type
+        if let Some(ref mappings) = config.serde_with_mappings {
+            let type_mappings = config.type_mappings.get_or_insert_with(HashMap::new);
+            for (path, ts_type) in mappings {
+                type_mappings
+                    .entry(path.clone())
+                    .or_insert_with(|| ts_type.clone());
+            }
+        }
+        let unresolved = self.unresolved_types(commands, events, used_structs, &config);
+        if unresolved.is_empty() {
+            return Ok(config);
+        }
+
+        let names: Vec<&str> = unresolved.iter().map(String::as_str).collect();
+        let fallback = config.unknown_type_fallback().to_string();
+        if fallback == "error" {
+            return Err(format!(
+                "No definition found for referenced types: {}. Define them in the scanned \
+                 sources, map them with typeMappings, or set unknownTypeFallback to \
+                 \"unknown\" or \"any\"",
+                names.join(", ")
+            ));
+        }
+        eprintln!(
+            "Warning: No definition found for referenced types: {}; generating them as {}",
+            names.join(", "),
+            fallback
+        );
+        config
+            .type_mappings
+            .get_or_insert_with(HashMap::new)
+            .extend(unresolved.into_iter().map(|name| (name, fallback.clone())));
+        Ok(config)
+    }
+
+    /// Imports of the field override types used by the given structs, grouped by module
+    pub fn field_override_imports(
+        &self,
+        used_structs: &HashMap<String, StructInfo>,
+        config: &GenerateConfig,
+    ) -> Vec<TypeImportContext> {
+        let mut referenced = HashSet::new();
+        for struct_info in used_structs.values() {
+            for field in &struct_info.fields {
+                Self::collect_referenced_types_from_structure(
+                    &field.type_structure,
+                    &mut referenced,
+                );
+            }
+        }
+
+        let mut imports: BTreeMap<String, BTreeSet<String>> = BTreeMap::new();
+        for selector in referenced {
+            let Some(field_override) = config.field_override(&selector) else {
+                continue;
+            };
+            let Some(from) = field_override.import_from() else {
+                continue;
+            };
+            // `Money<Currency>` imports `Money`
+            let type_name: String = field_override
+                .ts_type()
+                .trim()
+                .chars()
+                .take_while(|c| c.is_alphanumeric() || *c == '_' || *c == '$')
+                .collect();
+            if !type_name.is_empty() {
+                imports
+                    .entry(from.to_string())
+                    .or_default()
+                    .insert(type_name);
+            }
+        }
+
+        imports
+            .into_iter()
+            .map(|(from, types)| TypeImportContext {
+                from,
+                types: types.into_iter().collect(),
+            })
+            .collect()
+    }
+
+    /// Split the given structs into imports of those defined in a `shared_types` crate,
+    /// grouped by package, and the structs the project defines itself
+    pub fn split_shared_types(
+        &self,
+        used_structs: &HashMap<String, StructInfo>,
+        config: &GenerateConfig,
+    ) -> (Vec<TypeImportContext>, HashMap<String, StructInfo>) {
+        let mut imports: BTreeMap<String, BTreeSet<String>> = BTreeMap::new();
+        let mut local_structs = HashMap::new();
+        for (name, struct_info) in used_structs {
+            match config.shared_types_package(&struct_info.file_path) {
+                Some(package) => {
+                    imports
+                        .entry(package.to_string())
+                        .or_default()
+                        .insert(name.clone());
+                }
+                None => {
+                    local_structs.insert(name.clone(), struct_info.clone());
+                }
+            }
+        }
+
+        let imports = imports
+            .into_iter()
+            .map(|(from, types)| TypeImportContext {
+                from,
+                types: types.into_iter().collect(),
+            })
+            .collect();
+        (imports, local_structs)
+    }
+
     /// Recursively discover nested dependencies
     fn discover_nested_dependencies(
         &self,
@@ -119,6 +691,12 @@ impl TypeCollector {
                         &field.type_structure,
                         &mut nested_types,
                     );
+                    for variant_field in &field.variant_fields {
+                        Self::collect_referenced_types_from_structure(
+                            &variant_field.type_structure,
+                            &mut nested_types,
+                        );
+                    }
 
                     for nested_type in nested_types {
                         if !all_types.contains(&nested_type)
@@ -155,7 +733,7 @@ impl TypeCollector {
                 Self::collect_referenced_types_from_structure(key, used_types);
                 Self::collect_referenced_types_from_structure(value, used_types);
             }
-            TypeStructure::Tuple(types) => {
+            TypeStructure::Tuple(types) | TypeStructure::Union(types) => {
                 for t in types {
                     Self::collect_referenced_types_from_structure(t, used_types);
                 }
@@ -176,14 +754,117 @@ impl TypeCollector {
     ) -> Vec<CommandContext> {
         let type_resolver = analyzer.get_type_resolver();
 
-        commands
+        let mut contexts: Vec<CommandContext> = commands
             .iter()
             .map(|cmd| {
                 CommandContext::new(config).from_command_info(cmd, visitor, &|rust_type: &str| {
                     type_resolver.borrow_mut().parse_type_structure(rust_type)
                 })
             })
-            .collect()
+            .collect();
+
+        let suffixes = self.disambiguate_names(
+            "command",
+            contexts.iter().map(|ctx| {
+                (
+                    ctx.ts_function_name.as_str(),
+                    ctx.name.as_str(),
+                    ctx.location(),
+                )
+            }),
+        );
+        for ctx in &mut contexts {
+            for (serialized, first, second) in ctx.parameter_collisions() {
+                self.warn(format!(
+                    "Warning: command '{}' ({}) has parameters '{}' and '{}' that are both sent as '{}'; the bindings declare it once",
+                    ctx.name,
+                    ctx.location(),
+                    first,
+                    second,
+                    serialized
+                ));
+            }
+            ctx.remove_parameter_collisions();
+            let cache_ttl_ms = config
+                .command_options(&ctx.name)
+                .and_then(|options| options.cache_ttl_ms);
+            if cache_ttl_ms.is_some() && ctx.cache_ttl_ms.is_none() {
+                self.warn(format!(
+                    "Warning: cacheTtlMs of command '{}' ({}) is ignored; only queries without channels are cached",
+                    ctx.name,
+                    ctx.location()
+                ));
+            }
+        }
+
+        let examples = ExampleBuilder::new(analyzer.get_discovered_structs(), config);
+        for (ctx, suffix) in contexts.iter_mut().zip(suffixes) {
+            ctx.ts_function_name.push_str(&suffix);
+            ctx.ts_type_name.push_str(&suffix);
+            ctx.example = examples.invocation(ctx);
+        }
+
+        let structs = analyzer.get_discovered_structs();
+        for channel in contexts.iter_mut().flat_map(|ctx| ctx.channels.iter_mut()) {
+            let crate::TypeStructure::Custom(message_type) = type_resolver
+                .borrow_mut()
+                .parse_type_structure(&channel.message_type)
+            else {
+                continue;
+            };
+            let Some(message) = structs.get(&message_type) else {
+                continue;
+            };
+            channel.progress = ProgressFields::of(message, &*channel);
+            if message.is_progress && channel.progress.is_none() {
+                self.warn(format!(
+                    "Warning: progress message '{}' ({}:{}) has no two numeric fields to report progress by",
+                    message.name, message.file_path, message.line_number
+                ));
+            }
+        }
+
+        // Invalidation targets are resolved once all function names are final
+        let function_names: HashMap<String, String> = contexts
+            .iter()
+            .map(|ctx| (ctx.name.clone(), ctx.ts_function_name.clone()))
+            .collect();
+        let cached_commands: HashSet<String> = contexts
+            .iter()
+            .filter(|ctx| ctx.cache_ttl_ms.is_some())
+            .map(|ctx| ctx.name.clone())
+            .collect();
+        for (ctx, cmd) in contexts.iter_mut().zip(commands) {
+            ctx.invalidates = config
+                .invalidates(cmd)
+                .iter()
+                .filter_map(|query| function_names.get(query).cloned())
+                .collect();
+            ctx.invalidated_commands = config
+                .invalidates(cmd)
+                .iter()
+                .filter(|query| cached_commands.contains(*query))
+                .cloned()
+                .collect();
+        }
+
+        // Events are attributed to the command functions emitting them in their bodies
+        let events = analyzer.get_discovered_events();
+        let event_contexts = self.create_event_contexts(events, visitor, analyzer, config);
+        for (ctx, cmd) in contexts.iter_mut().zip(commands) {
+            for (event, event_ctx) in events.iter().zip(&event_contexts) {
+                if event.emitted_by.as_deref() == Some(cmd.name.as_str())
+                    && event.file_path == cmd.file_path
+                    && !ctx
+                        .emitted_events
+                        .iter()
+                        .any(|emitted| emitted.event_name == event.event_name)
+                {
+                    ctx.emitted_events.push(event_ctx.clone());
+                }
+            }
+        }
+        contexts
     }
 
     /// Create EventContext instances from EventInfo using the provided visitor
@@ -196,16 +877,130 @@ impl TypeCollector {
     ) -> Vec<EventContext> {
         let type_resolver = analyzer.get_type_resolver();
 
-        events
+        let mut contexts: Vec<EventContext> = events
             .iter()
             .map(|event| {
                 EventContext::new(config).from_event_info(event, visitor, &|rust_type: &str| {
                     type_resolver.borrow_mut().parse_type_structure(rust_type)
                 })
             })
+            .collect();
+
+        let suffixes = self.disambiguate_names(
+            "event",
+            contexts.iter().map(|ctx| {
+                (
+                    ctx.ts_function_name.as_str(),
+                    ctx.event_name.as_str(),
+                    ctx.location(),
+                )
+            }),
+        );
+        for (ctx, suffix) in contexts.iter_mut().zip(suffixes) {
+            ctx.ts_function_name.push_str(&suffix);
+        }
+        contexts
+    }
+
+    /// Create ConstantContext instances for exported constants, ordered by source location.
+    ///
+    /// Later constants sharing a name with an earlier one are skipped with a warning.
+    pub fn create_constant_contexts(&self, constants: &[ConstInfo]) -> Vec<ConstantContext> {
+        let mut sorted: Vec<&ConstInfo> = constants.iter().collect();
+        sorted.sort_by(|a, b| {
+            (a.file_path.as_str(), a.line_number).cmp(&(b.file_path.as_str(), b.line_number))
+        });
+
+        let mut contexts: Vec<ConstantContext> = Vec::new();
+        for constant in sorted {
+            let context = ConstantContext::from_const_info(constant);
+            if let Some(existing) = contexts.iter().find(|c| c.name == context.name) {
+                self.warn(format!(
+                    "Warning: Exported constant {} at {} is skipped, the name is already exported from {}",
+                    context.name,
+                    context.location(),
+                    existing.location()
+                ));
+                continue;
+            }
+            if Self::exceeds_safe_integer(&constant.value) {
+                self.warn(format!(
+                    "Warning: Exported constant {} at {} exceeds Number.MAX_SAFE_INTEGER and loses precision in JavaScript",
+                    context.name,
+                    context.location()
+                ));
+            }
+            contexts.push(context);
+        }
+        contexts
+    }
+
+    fn exceeds_safe_integer(value: &ConstValue) -> bool {
+        const MAX_SAFE_INTEGER: i128 = (1 << 53) - 1;
+        match value {
+            ConstValue::Integer(i) => i.abs() > MAX_SAFE_INTEGER,
+            ConstValue::Array(items) => items.iter().any(Self::exceeds_safe_integer),
+            _ => false,
+        }
+    }
+
+    /// Resolve generated TypeScript names claimed by more than one Rust name
+    /// (`user-login` and `user_login` both produce `onUserLogin`).
+    ///
+    /// Takes `(generated name, Rust name, source location)` entries in source order and
+    /// returns the suffix to append to each: empty for the first claimant, `2`, `3`, ...
+    /// for later ones. Entries repeating a Rust name (the same event emitted twice)
+    /// share its suffix. Collisions and names containing untranslatable symbols are
+    /// reported with their source location.
+    fn disambiguate_names<'a>(
+        &self,
+        kind: &str,
+        entries: impl Iterator<Item = (&'a str, &'a str, String)>,
+    ) -> Vec<String> {
+        let mut owners: HashMap<String, (&str, String)> = HashMap::new();
+        let mut assigned: HashMap<&str, String> = HashMap::new();
+
+        entries
+            .map(|(generated, rust_name, location)| {
+                if let Some(suffix) = assigned.get(rust_name) {
+                    return suffix.clone();
+                }
+
+                if CaseConverter::has_untranslatable_chars(rust_name) {
+                    self.warn(format!(
+                        "Warning: {} '{}' ({}) contains characters that are not valid in TypeScript identifiers; generating '{}'",
+                        kind, rust_name, location, generated
+                    ));
+                }
+
+                let mut unique_name = generated.to_string();
+                let mut counter = 1;
+                while owners.contains_key(&unique_name) {
+                    counter += 1;
+                    unique_name = format!("{}{}", generated, counter);
+                }
+                if let Some((owner, owner_location)) = owners.get(generated).filter(|_| counter > 1) {
+                    self.warn(format!(
+                        "Warning: {} '{}' ({}) generates the TypeScript name '{}' already used by '{}' ({}); generating '{}' instead",
+                        kind, rust_name, location, generated, owner, owner_location, unique_name
+                    ));
+                }
+
+                let suffix = unique_name[generated.len()..].to_string();
+                owners.insert(unique_name, (rust_name, location));
+                assigned.insert(rust_name, suffix.clone());
+                suffix
+            })
             .collect()
     }
 
+    /// Print a warning once per collector
+    fn warn(&self, message: String) {
+        if self.reported_warnings.borrow_mut().insert(message.clone()) {
+            eprintln!("{}", message);
+        }
+    }
+
     /// Create StructContext instances from StructInfo using the provided visitor
     pub fn create_struct_contexts<V: TypeVisitor>(
         &self,
@@ -232,11 +1027,15 @@ impl TypeCollector {
             .fields
             .iter()
             .map(|field| {
-                FieldContext::new(config).from_field_info(
-                    field,
-                    &struct_info.serde_rename_all,
-                    visitor,
-                )
+                let context = FieldContext::new(config);
+                if struct_info.is_enum {
+                    context.from_variant_info(field, &struct_info.serde_rename_all, visitor)
+                } else {
+                    context
+                        .from_field_info(field, &struct_info.serde_rename_all, visitor)
+                        .with_range_literals(&struct_info.name, field, visitor)
+                        .with_flattened_map(struct_info, field, visitor)
+                }
             })
             .collect()
     }
@@ -264,6 +1063,12 @@ mod tests {
             assert!(std::any::type_name_of_val(&gen).contains("Box"));
         }
 
+        #[test]
+        fn test_create_generator_valibot() {
+            let gen = create_generator(Some("valibot".to_string()));
+            assert_eq!(gen.generator_type(), "valibot");
+        }
+
         #[test]
         fn test_create_generator_none() {
             let gen = create_generator(Some("none".to_string()));
@@ -276,6 +1081,24 @@ mod tests {
             assert!(std::any::type_name_of_val(&gen).contains("Box"));
         }
 
+        #[test]
+        fn test_generator_for_target() {
+            let mut config = GenerateConfig {
+                validation_library: "zod".to_string(),
+                ..Default::default()
+            };
+            assert_eq!(generator_for(&config).generator_type(), "zod");
+
+            config.target = Some("python".to_string());
+            assert_eq!(generator_for(&config).generator_type(), "python");
+
+            config.target = Some("kotlin".to_string());
+            assert_eq!(generator_for(&config).generator_type(), "kotlin");
+
+            config.target = Some("swift".to_string());
+            assert_eq!(generator_for(&config).generator_type(), "swift");
+        }
+
         #[test]
         fn test_create_generator_unknown_fallback() {
             let gen = create_generator(Some("unknown".to_string()));
@@ -435,6 +1258,13 @@ mod tests {
                 file_path: "test.rs".to_string(),
                 is_enum: false,
                 serde_rename_all: None,
+                is_newtype: false,
+                serde_tag: None,
+                serde_content: None,
+                serde_untagged: false,
+                line_number: 0,
+                is_progress: false,
+                serde_deny_unknown_fields: false,
             }
         }
 
@@ -551,6 +1381,14 @@ mod tests {
                 validator_attributes: None,
                 serde_rename: None,
                 type_structure,
+                variant_fields: Vec::new(),
+                literal: None,
+                flatten: false,
+                omittable: false,
+                defaulted: false,
+                skip_serializing_if: false,
+                skipped: None,
+                serde_with: None,
             }
         }
 
@@ -561,6 +1399,13 @@ mod tests {
                 file_path: "test.rs".to_string(),
                 is_enum: false,
                 serde_rename_all: None,
+                is_newtype: false,
+                serde_tag: None,
+                serde_content: None,
+                serde_untagged: false,
+                line_number: 0,
+                is_progress: false,
+                serde_deny_unknown_fields: false,
             }
         }
 
@@ -650,5 +1495,494 @@ mod tests {
             assert!(used.contains_key("B"));
             assert!(used.contains_key("C"));
         }
+
+        #[test]
+        fn test_serialized_fields_inline_flattened_structs() {
+            let mut collector = TypeCollector::new();
+            let mut paging = create_struct_with_fields(
+                "Paging",
+                vec![create_field(
+                    "page_size",
+                    "u32",
+                    TypeStructure::Primitive("number".to_string()),
+                )],
+            );
+            paging.serde_rename_all = Some(serde_rename_rule::RenameRule::SnakeCase);
+            collector.known_structs.insert("Paging".to_string(), paging);
+
+            let mut flattened = create_field(
+                "paging",
+                "Option<Paging>",
+                TypeStructure::Optional(Box::new(TypeStructure::Custom("Paging".to_string()))),
+            );
+            flattened.flatten = true;
+            let mut extra = create_field(
+                "extra",
+                "HashMap<String, String>",
+                TypeStructure::Map {
+                    key: Box::new(TypeStructure::Primitive("string".to_string())),
+                    value: Box::new(TypeStructure::Primitive("string".to_string())),
+                },
+            );
+            extra.flatten = true;
+            let query = create_struct_with_fields(
+                "Query",
+                vec![
+                    create_field("term", "String", TypeStructure::default()),
+                    flattened,
+                    extra,
+                ],
+            );
+
+            let fields = collector.serialized_fields(&query, &GenerateConfig::default());
+
+            assert_eq!(fields.len(), 2);
+            assert_eq!(fields[0].name, "term");
+            assert_eq!(fields[1].name, "page_size");
+            assert_eq!(fields[1].serde_rename.as_deref(), Some("page_size"));
+            assert!(fields[1].is_optional);
+            assert_eq!(fields[1].rust_type, "Option<u32>");
+        }
+    }
+
+    mod skip_directions {
+        use super::*;
+        use crate::models::{FieldInfo, ParameterInfo, SkipDirection};
+
+        fn field(name: &str, skipped: Option<SkipDirection>) -> FieldInfo {
+            FieldInfo {
+                name: name.to_string(),
+                rust_type: "String".to_string(),
+                is_optional: false,
+                is_public: true,
+                validator_attributes: None,
+                serde_rename: None,
+                type_structure: TypeStructure::default(),
+                variant_fields: Vec::new(),
+                literal: None,
+                flatten: false,
+                omittable: false,
+                defaulted: false,
+                skip_serializing_if: false,
+                skipped,
+                serde_with: None,
+            }
+        }
+
+        fn skipping_struct(name: &str) -> StructInfo {
+            StructInfo {
+                name: name.to_string(),
+                fields: vec![
+                    field("id", None),
+                    field("password", Some(SkipDirection::Serializing)),
+                    field("created", Some(SkipDirection::Deserializing)),
+                ],
+                file_path: "test.rs".to_string(),
+                is_enum: false,
+                serde_rename_all: None,
+                is_newtype: false,
+                serde_tag: None,
+                serde_content: None,
+                serde_untagged: false,
+                line_number: 0,
+                is_progress: false,
+                serde_deny_unknown_fields: false,
+            }
+        }
+
+        fn command(param: Option<&str>, returns: &str) -> CommandInfo {
+            let parameters = param
+                .map(|name| ParameterInfo {
+                    name: "value".to_string(),
+                    rust_type: name.to_string(),
+                    is_optional: false,
+                    type_structure: TypeStructure::Custom(name.to_string()),
+                    serde_rename: None,
+                })
+                .into_iter()
+                .collect();
+            CommandInfo::new_for_test("cmd", "test.rs", 1, parameters, returns, false, vec![])
+        }
+
+        fn field_names(struct_info: &StructInfo) -> Vec<&str> {
+            struct_info.fields.iter().map(|f| f.name.as_str()).collect()
+        }
+
+        #[test]
+        fn test_fields_follow_the_direction_types_travel_in() {
+            let collector = TypeCollector::new();
+            let mut used: HashMap<String, StructInfo> = ["Input", "Output", "Both", "Store"]
+                .into_iter()
+                .map(|name| (name.to_string(), skipping_struct(name)))
+                .collect();
+            let commands = vec![
+                command(Some("Input"), "()"),
+                command(None, "Output"),
+                command(Some("Both"), "Both"),
+            ];
+
+            collector.apply_skip_directions(&commands, &[], &mut used);
+
+            assert_eq!(field_names(&used["Input"]), vec!["id", "password"]);
+            assert!(!used["Input"].fields[1].is_optional);
+            assert_eq!(field_names(&used["Output"]), vec!["id", "created"]);
+            for name in ["Both", "Store"] {
+                assert_eq!(field_names(&used[name]), vec!["id", "password", "created"]);
+                assert!(used[name].fields[1].omittable);
+                assert!(used[name].fields[2].is_optional);
+            }
+        }
+
+        #[test]
+        fn test_bindings_types_apply_skip_directions_to_extra_types() {
+            let mut collector = TypeCollector::new();
+            let discovered: HashMap<String, StructInfo> = ["Input", "Store", "Unused"]
+                .into_iter()
+                .map(|name| (name.to_string(), skipping_struct(name)))
+                .collect();
+            let extra = HashMap::from([("Store".to_string(), skipping_struct("Store"))]);
+
+            let used = collector.collect_bindings_types(
+                &[command(Some("Input"), "()")],
+                &[],
+                &discovered,
+                extra,
+                &GenerateConfig::default(),
+            );
+
+            let mut names: Vec<&str> = used.keys().map(String::as_str).collect();
+            names.sort();
+            assert_eq!(names, vec!["Input", "Store"]);
+            assert_eq!(field_names(&used["Input"]), vec!["id", "password"]);
+            assert_eq!(
+                field_names(&used["Store"]),
+                vec!["id", "password", "created"]
+            );
+            assert_eq!(collector.known_structs.len(), 3);
+        }
+
+        #[test]
+        fn test_selection_decisions_explain_chosen_skipped_and_filtered() {
+            let collector = TypeCollector::new();
+            let discovered: HashMap<String, StructInfo> = ["Input", "Both", "Unused"]
+                .into_iter()
+                .map(|name| (name.to_string(), skipping_struct(name)))
+                .collect();
+            let before: HashMap<String, StructInfo> = ["Input", "Both"]
+                .into_iter()
+                .map(|name| (name.to_string(), skipping_struct(name)))
+                .collect();
+            let mut used = before.clone();
+            collector.apply_skip_directions(
+                &[command(Some("Input"), "()"), command(Some("Both"), "Both")],
+                &[],
+                &mut used,
+            );
+            let reasons = BTreeMap::from([
+                ("Both".to_string(), "used by a command"),
+                ("Input".to_string(), "used by a command"),
+            ]);
+
+            let decisions =
+                TypeCollector::selection_decisions(&discovered, &reasons, &before, &used);
+
+            assert_eq!(
+                decisions,
+                vec![
+                    "Type Both included: used by a command",
+                    "Type Input included: used by a command",
+                    "Type Unused skipped: not used by any command or event",
+                    "Field Both.password made optional: skipped in one of the directions Both travels in",
+                    "Field Both.created made optional: skipped in one of the directions Both travels in",
+                    "Field Input.created removed: skipped in the only direction Input travels in",
+                ]
+            );
+        }
+    }
+
+    mod json_value_usage {
+        use super::*;
+        use crate::models::{CommandInfo, ParameterInfo, StructInfo};
+
+        fn json_command(type_structure: TypeStructure) -> CommandInfo {
+            let param = ParameterInfo {
+                name: "value".to_string(),
+                rust_type: "Option<serde_json::Value>".to_string(),
+                is_optional: true,
+                type_structure,
+                serde_rename: None,
+            };
+            CommandInfo::new_for_test("set_value", "test.rs", 1, vec![param], "()", false, vec![])
+        }
+
+        #[test]
+        fn test_detects_json_value_in_parameters() {
+            let collector = TypeCollector::new();
+            let command = json_command(TypeStructure::Optional(Box::new(TypeStructure::Custom(
+                JSON_VALUE_TYPE.to_string(),
+            ))));
+            assert!(collector.uses_json_value(&[command], &HashMap::new()));
+        }
+
+        #[test]
+        fn test_no_json_value_without_usage() {
+            let collector = TypeCollector::new();
+            let command = json_command(TypeStructure::Primitive("string".to_string()));
+            assert!(!collector.uses_json_value(&[command], &HashMap::new()));
+        }
+
+        #[test]
+        fn test_user_defined_json_value_takes_precedence() {
+            let collector = TypeCollector::new();
+            let command = json_command(TypeStructure::Custom(JSON_VALUE_TYPE.to_string()));
+            let mut structs = HashMap::new();
+            structs.insert(
+                JSON_VALUE_TYPE.to_string(),
+                StructInfo {
+                    name: JSON_VALUE_TYPE.to_string(),
+                    fields: vec![],
+                    file_path: "test.rs".to_string(),
+                    is_enum: false,
+                    serde_rename_all: None,
+                    is_newtype: false,
+                    serde_tag: None,
+                    serde_content: None,
+                    serde_untagged: false,
+                    line_number: 0,
+                    is_progress: false,
+                    serde_deny_unknown_fields: false,
+                },
+            );
+            assert!(!collector.uses_json_value(&[command], &structs));
+        }
+    }
+
+    mod unknown_types {
+        use super::*;
+        use crate::models::{CommandInfo, ParameterInfo};
+
+        fn command(params: &[&str]) -> CommandInfo {
+            let parameters = params
+                .iter()
+                .map(|name| ParameterInfo {
+                    name: name.to_lowercase(),
+                    rust_type: name.to_string(),
+                    is_optional: false,
+                    type_structure: TypeStructure::Custom(name.to_string()),
+                    serde_rename: None,
+                })
+                .collect();
+            CommandInfo::new_for_test("save", "test.rs", 1, parameters, "()", false, vec![])
+        }
+
+        #[test]
+        fn test_unresolved_types_exclude_mapped_and_json_value() {
+            let collector = TypeCollector::new();
+            let config = GenerateConfig {
+                type_mappings: Some(HashMap::from([(
+                    "DateTime".to_string(),
+                    "string".to_string(),
+                )])),
+                ..Default::default()
+            };
+            let commands = [command(&[
+                "External",
+                "DateTime",
+                JSON_VALUE_TYPE,
+                "enum_variant",
+            ])];
+
+            let unresolved = collector.unresolved_types(&commands, &[], &HashMap::new(), &config);
+            assert_eq!(unresolved.into_iter().collect::<Vec<_>>(), ["External"]);
+        }
+
+        #[test]
+        fn test_resolve_unknown_types_by_fallback() {
+            let collector = TypeCollector::new();
+            let commands = [command(&["External"])];
+
+            let config = collector
+                .resolve_unknown_types(&commands, &[], &HashMap::new(), &GenerateConfig::default())
+                .unwrap();
+            assert_eq!(
+                config
+                    .type_mappings
+                    .unwrap()
+                    .get("External")
+                    .map(String::as_str),
+                Some("unknown")
+            );
+
+            let strict = GenerateConfig {
+                unknown_type_fallback: Some("error".to_string()),
+                ..Default::default()
+            };
+            let error = collector
+                .resolve_unknown_types(&commands, &[], &HashMap::new(), &strict)
+                .unwrap_err();
+            assert!(error.contains("No definition found for referenced types: External"));
+        }
+    }
+
+    mod name_collisions {
+        use super::*;
+        use crate::generators::ts::type_visitor::TypeScriptVisitor;
+        use crate::models::{CommandInfo, EventInfo};
+
+        fn event(name: &str, line_number: usize) -> EventInfo {
+            EventInfo {
+                event_name: name.to_string(),
+                payload_type: "u32".to_string(),
+                payload_type_structure: TypeStructure::Primitive("number".to_string()),
+                file_path: "events.rs".to_string(),
+                line_number,
+                emitted_by: None,
+            }
+        }
+
+        #[test]
+        fn test_colliding_commands_get_numeric_suffix() {
+            let collector = TypeCollector::new();
+            let commands = vec![
+                CommandInfo::new_for_test("grüße_senden", "a.rs", 1, vec![], "()", false, vec![]),
+                CommandInfo::new_for_test("grusse_senden", "b.rs", 2, vec![], "()", false, vec![]),
+            ];
+
+            let contexts = collector.create_command_contexts(
+                &commands,
+                &TypeScriptVisitor::new(),
+                &CommandAnalyzer::new(),
+                &GenerateConfig::default(),
+            );
+
+            assert_eq!(contexts[0].ts_function_name, "grusseSenden");
+            assert_eq!(contexts[0].ts_type_name, "GrusseSenden");
+            assert_eq!(contexts[1].ts_function_name, "grusseSenden2");
+            assert_eq!(contexts[1].ts_type_name, "GrusseSenden2");
+        }
+
+        #[test]
+        fn test_colliding_events_get_numeric_suffix() {
+            let collector = TypeCollector::new();
+            let events = vec![
+                event("user-login", 1),
+                event("user_login", 2),
+                event("user-login", 3),
+                event("user.login", 4),
+            ];
+
+            let contexts = collector.create_event_contexts(
+                &events,
+                &TypeScriptVisitor::new(),
+                &CommandAnalyzer::new(),
+                &GenerateConfig::default(),
+            );
+            let names: Vec<&str> = contexts
+                .iter()
+                .map(|ctx| ctx.ts_function_name.as_str())
+                .collect();
+
+            // Re-emitting the same event keeps its name
+            assert_eq!(
+                names,
+                vec!["onUserLogin", "onUserLogin2", "onUserLogin", "onUserLogin3"]
+            );
+        }
+
+        #[test]
+        fn test_symbol_event_names_are_encoded() {
+            let collector = TypeCollector::new();
+            let contexts = collector.create_event_contexts(
+                &[event("🎉", 1)],
+                &TypeScriptVisitor::new(),
+                &CommandAnalyzer::new(),
+                &GenerateConfig::default(),
+            );
+            assert_eq!(contexts[0].ts_function_name, "onU1F389");
+        }
+    }
+
+    mod constants {
+        use super::*;
+
+        fn constant(
+            name: &str,
+            file_path: &str,
+            line_number: usize,
+            value: ConstValue,
+        ) -> ConstInfo {
+            ConstInfo {
+                name: name.to_string(),
+                rust_type: "usize".to_string(),
+                value,
+                file_path: file_path.to_string(),
+                line_number,
+            }
+        }
+
+        #[test]
+        fn test_constants_are_ordered_by_location() {
+            let collector = TypeCollector::new();
+            let contexts = collector.create_constant_contexts(&[
+                constant("B", "b.rs", 1, ConstValue::Integer(2)),
+                constant("A_LATER", "a.rs", 9, ConstValue::Bool(true)),
+                constant("A", "a.rs", 3, ConstValue::String("x".to_string())),
+            ]);
+
+            let names: Vec<&str> = contexts.iter().map(|c| c.name.as_str()).collect();
+            assert_eq!(names, vec!["A", "A_LATER", "B"]);
+            assert_eq!(contexts[0].value, "\"x\"");
+            assert_eq!(contexts[1].value, "true");
+        }
+
+        #[test]
+        fn test_duplicate_names_keep_first_constant() {
+            let collector = TypeCollector::new();
+            let contexts = collector.create_constant_contexts(&[
+                constant("LIMIT", "b.rs", 1, ConstValue::Integer(2)),
+                constant("LIMIT", "a.rs", 1, ConstValue::Integer(1)),
+            ]);
+
+            assert_eq!(contexts.len(), 1);
+            assert_eq!(contexts[0].value, "1");
+        }
+
+        #[test]
+        fn test_array_and_float_literals() {
+            let collector = TypeCollector::new();
+            let contexts = collector.create_constant_contexts(&[
+                constant(
+                    "EVENTS",
+                    "a.rs",
+                    1,
+                    ConstValue::Array(vec![
+                        ConstValue::String("user-login".to_string()),
+                        ConstValue::String("say \"hi\"".to_string()),
+                    ]),
+                ),
+                constant("RATIO", "a.rs", 2, ConstValue::Float(0.25)),
+                constant("UNBOUNDED", "a.rs", 3, ConstValue::Float(f64::INFINITY)),
+            ]);
+
+            assert_eq!(
+                contexts[0].value,
+                "[\"user-login\", \"say \\\"hi\\\"\"] as const"
+            );
+            assert_eq!(contexts[1].value, "0.25");
+            assert_eq!(contexts[2].value, "Infinity");
+        }
+
+        #[test]
+        fn test_exceeds_safe_integer() {
+            assert!(!TypeCollector::exceeds_safe_integer(&ConstValue::Integer(
+                (1 << 53) - 1
+            )));
+            assert!(TypeCollector::exceeds_safe_integer(&ConstValue::Integer(
+                i64::MAX.into()
+            )));
+            assert!(TypeCollector::exceeds_safe_integer(&ConstValue::Array(
+                vec![ConstValue::Integer(-(1 << 60))]
+            )));
+        }
     }
 }
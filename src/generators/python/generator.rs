@@ -0,0 +1,655 @@
+use crate::analysis::CommandAnalyzer;
+use crate::generators::base::file_writer::FileWriter;
+use crate::generators::base::template_context::FieldContext;
+use crate::generators::base::templates::{GlobalContext, TemplateRegistry};
+use crate::generators::base::type_visitor::string_literal;
+use crate::generators::base::BaseBindingsGenerator;
+use crate::generators::python::templates::PythonTemplate;
+use crate::generators::python::type_visitor::PythonVisitor;
+use crate::generators::TypeCollector;
+use crate::models::{CommandInfo, FieldInfo, StructInfo, TypeStructure};
+use crate::{GenerateConfig, OutputFileNames};
+use serde::Serialize;
+use std::collections::{HashMap, HashSet};
+use tera::{Context, Tera};
+
+/// Python keywords and names that can't be attributes of a Pydantic model or a
+/// parameter of a method; they get a trailing underscore
+const RESERVED_NAMES: &[&str] = &[
+    "False",
+    "None",
+    "True",
+    "and",
+    "as",
+    "assert",
+    "async",
+    "await",
+    "break",
+    "class",
+    "continue",
+    "def",
+    "del",
+    "elif",
+    "else",
+    "except",
+    "finally",
+    "for",
+    "from",
+    "global",
+    "if",
+    "import",
+    "in",
+    "is",
+    "lambda",
+    "nonlocal",
+    "not",
+    "or",
+    "pass",
+    "raise",
+    "return",
+    "self",
+    "try",
+    "while",
+    "with",
+    "yield",
+    "copy",
+    "dict",
+    "json",
+    "schema",
+    "validate",
+    "construct",
+];
+
+/// A Pydantic model, or a type alias for enums and newtypes, of the types module
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PythonType {
+    pub name: String,
+    /// Annotation of a type alias, `None` for models
+    pub alias: Option<String>,
+    pub fields: Vec<PythonField>,
+}
+
+/// A field of a Pydantic model, named like the Rust field
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PythonField {
+    pub name: String,
+    pub annotation: String,
+    /// Arguments of its `Field(...)`: the serialized name as alias, the default and
+    /// the validator constraints
+    pub arguments: Vec<String>,
+}
+
+/// An async method of the `Commands` class invoking a command
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PythonCommand {
+    /// Name the command is invoked by
+    pub name: String,
+    pub method_name: String,
+    pub parameters: Vec<PythonParameter>,
+    pub return_type: String,
+}
+
+/// A parameter of a command method, sent under its serialized name
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PythonParameter {
+    pub name: String,
+    pub serialized_name: String,
+    pub annotation: String,
+}
+
+/// Generator for Python bindings: Pydantic models of the types and a `Commands`
+/// class of async methods calling the commands through an `invoke` callable
+pub struct PythonBindingsGenerator {
+    collector: TypeCollector,
+    tera: Tera,
+}
+
+impl PythonBindingsGenerator {
+    pub fn new() -> Self {
+        Self {
+            collector: TypeCollector::new(),
+            tera: PythonTemplate::create_tera()
+                .expect("Failed to initialize Python template engine"),
+        }
+    }
+
+    /// A Rust name as a Python identifier, with a trailing underscore for reserved names
+    pub fn identifier(name: &str) -> String {
+        if RESERVED_NAMES.contains(&name) || name.starts_with("model_") {
+            format!("{}_", name)
+        } else {
+            name.to_string()
+        }
+    }
+
+    /// Models and aliases of the used types, each after the types it references
+    fn create_types(
+        &self,
+        used_structs: &HashMap<String, StructInfo>,
+        local_types: &HashSet<String>,
+        config: &GenerateConfig,
+    ) -> Vec<PythonType> {
+        let visitor = PythonVisitor::with_config(config).with_local_types(local_types, "");
+        Self::dependency_order(used_structs)
+            .into_iter()
+            .flat_map(|name| {
+                let struct_info = &used_structs[name];
+                if struct_info.is_enum {
+                    self.enum_types(name, struct_info, &visitor, config)
+                } else if struct_info.is_newtype && !struct_info.fields.is_empty() {
+                    let field = &struct_info.fields[0];
+                    vec![PythonType {
+                        name: name.to_string(),
+                        alias: Some(visitor.annotate(&field.rust_type, &field.type_structure)),
+                        fields: Vec::new(),
+                    }]
+                } else {
+                    let fields = self.model_fields(
+                        &self.collector.serialized_fields(struct_info, config),
+                        &struct_info.serde_rename_all,
+                        &visitor,
+                        config,
+                    );
+                    vec![PythonType {
+                        name: name.to_string(),
+                        alias: None,
+                        fields,
+                    }]
+                }
+            })
+            .collect()
+    }
+
+    /// Tagged and untagged enums are a `Union` of their variants, other enums a `Literal`
+    /// of the variant names
+    fn enum_types(
+        &self,
+        name: &str,
+        struct_info: &StructInfo,
+        visitor: &PythonVisitor,
+        config: &GenerateConfig,
+    ) -> Vec<PythonType> {
+        let variants = self
+            .collector
+            .create_field_contexts(struct_info, visitor, config);
+        let mut types = Vec::new();
+        let mut members = Vec::new();
+
+        match (&struct_info.serde_tag, &struct_info.serde_content) {
+            (Some(tag), Some(content)) => {
+                for (variant, context) in struct_info.fields.iter().zip(&variants) {
+                    let variant_name = format!("{}{}", name, variant.name);
+                    let literal = string_literal(&context.serialized_name);
+                    let mut fields = vec![Self::field(
+                        tag,
+                        tag,
+                        format!("Literal[{}]", literal),
+                        Some(literal),
+                    )];
+                    let payload_name = format!("{}Data", variant_name);
+                    if let Some(payload) =
+                        self.variant_payload(&payload_name, variant, &mut types, visitor, config)
+                    {
+                        fields.push(Self::field(content, content, payload, None));
+                    }
+                    types.push(PythonType {
+                        name: variant_name.clone(),
+                        alias: None,
+                        fields,
+                    });
+                    members.push(variant_name);
+                }
+            }
+            (Some(tag), None) => {
+                // The payload's fields sit next to the tag
+                for (variant, context) in struct_info.fields.iter().zip(&variants) {
+                    let variant_name = format!("{}{}", name, variant.name);
+                    let literal = string_literal(&context.serialized_name);
+                    let mut fields = vec![Self::field(
+                        tag,
+                        tag,
+                        format!("Literal[{}]", literal),
+                        Some(literal),
+                    )];
+                    match variant.rust_type.as_str() {
+                        "enum_variant_struct" => fields.extend(self.model_fields(
+                            &variant.variant_fields,
+                            &None,
+                            visitor,
+                            config,
+                        )),
+                        "enum_variant_tuple" => {
+                            if let Some(payload) = self.inlined_struct(&variant.type_structure) {
+                                fields.extend(self.model_fields(
+                                    &self.collector.serialized_fields(payload, config),
+                                    &payload.serde_rename_all,
+                                    visitor,
+                                    config,
+                                ));
+                            }
+                        }
+                        _ => {}
+                    }
+                    types.push(PythonType {
+                        name: variant_name.clone(),
+                        alias: None,
+                        fields,
+                    });
+                    members.push(variant_name);
+                }
+            }
+            _ if struct_info.has_variant_data() && !struct_info.is_untagged() => {
+                // `{ "Variant": payload }`, unit variants as their name
+                for (variant, context) in struct_info.fields.iter().zip(&variants) {
+                    let variant_name = format!("{}{}", name, variant.name);
+                    let payload_name = format!("{}Data", variant_name);
+                    match self.variant_payload(&payload_name, variant, &mut types, visitor, config)
+                    {
+                        Some(payload) => {
+                            types.push(PythonType {
+                                name: variant_name.clone(),
+                                alias: None,
+                                fields: vec![Self::field(
+                                    &context.serialized_name,
+                                    &context.serialized_name,
+                                    payload,
+                                    None,
+                                )],
+                            });
+                            members.push(variant_name);
+                        }
+                        None => members.push(format!(
+                            "Literal[{}]",
+                            string_literal(&context.serialized_name)
+                        )),
+                    }
+                }
+            }
+            _ if struct_info.is_untagged() => {
+                for variant in &struct_info.fields {
+                    let variant_name = format!("{}{}", name, variant.name);
+                    let member = self
+                        .variant_payload(&variant_name, variant, &mut types, visitor, config)
+                        .unwrap_or_else(|| "None".to_string());
+                    if !members.contains(&member) {
+                        members.push(member);
+                    }
+                }
+            }
+            _ => {
+                let literals: Vec<String> = variants
+                    .iter()
+                    .map(|variant| string_literal(&variant.serialized_name))
+                    .collect();
+                types.push(PythonType {
+                    name: name.to_string(),
+                    alias: Some(format!("Literal[{}]", literals.join(", "))),
+                    fields: Vec::new(),
+                });
+                return types;
+            }
+        }
+
+        types.push(PythonType {
+            name: name.to_string(),
+            alias: Some(format!("Union[{}]", members.join(", "))),
+            fields: Vec::new(),
+        });
+        types
+    }
+
+    /// Struct wrapped by a newtype variant of an internally tagged enum, whose fields
+    /// serde writes next to the tag
+    fn inlined_struct(&self, structure: &TypeStructure) -> Option<&StructInfo> {
+        match structure {
+            TypeStructure::Custom(name) if self.collector.is_object_struct(name) => {
+                self.collector.known_structs.get(name)
+            }
+            _ => None,
+        }
+    }
+
+    /// Annotation of the payload of a variant, `None` for unit variants; struct
+    /// variants get a model named `model_name`, pushed to `types`
+    fn variant_payload(
+        &self,
+        model_name: &str,
+        variant: &FieldInfo,
+        types: &mut Vec<PythonType>,
+        visitor: &PythonVisitor,
+        config: &GenerateConfig,
+    ) -> Option<String> {
+        match variant.rust_type.as_str() {
+            "enum_variant_tuple" => {
+                let rust_types: Vec<&str> = variant
+                    .variant_fields
+                    .iter()
+                    .map(|field| field.rust_type.as_str())
+                    .collect();
+                Some(visitor.annotate(&rust_types.join(", "), &variant.type_structure))
+            }
+            "enum_variant_struct" => {
+                types.push(PythonType {
+                    name: model_name.to_string(),
+                    alias: None,
+                    fields: self.model_fields(&variant.variant_fields, &None, visitor, config),
+                });
+                Some(model_name.to_string())
+            }
+            _ => None,
+        }
+    }
+
+    fn model_fields(
+        &self,
+        fields: &[FieldInfo],
+        rename_all: &Option<serde_rename_rule::RenameRule>,
+        visitor: &PythonVisitor,
+        config: &GenerateConfig,
+    ) -> Vec<PythonField> {
+        fields
+            .iter()
+            .map(|field| {
+                let field_visitor = visitor.for_rust_type(&field.rust_type, &field.type_structure);
+                let context =
+                    FieldContext::new(config).from_field_info(field, rename_all, &field_visitor);
+                let optional =
+                    field.is_optional || matches!(field.type_structure, TypeStructure::Optional(_));
+                let mut annotation = context.typescript_type;
+                let default = match field.literal {
+                    Some(ref literal) => Some(string_literal(literal)),
+                    // Fields the backend may leave out are `None` when absent, and `None`
+                    // is left out again when sent
+                    None if optional => {
+                        if !annotation.starts_with("Optional[") {
+                            annotation = format!("Optional[{}]", annotation);
+                        }
+                        Some("None".to_string())
+                    }
+                    None => None,
+                };
+                let mut python_field =
+                    Self::field(&field.name, &context.serialized_name, annotation, default);
+                if let Some(ref validator) = field.validator_attributes {
+                    if let Some(ref length) = validator.length {
+                        python_field
+                            .arguments
+                            .extend(length.min.map(|min| format!("min_length={}", min)));
+                        python_field
+                            .arguments
+                            .extend(length.max.map(|max| format!("max_length={}", max)));
+                    }
+                    if let Some(ref range) = validator.range {
+                        python_field
+                            .arguments
+                            .extend(range.min.map(|min| format!("ge={}", min)));
+                        python_field
+                            .arguments
+                            .extend(range.max.map(|max| format!("le={}", max)));
+                    }
+                }
+                python_field
+            })
+            .collect()
+    }
+
+    /// A model field aliased to its serialized name when the Python name differs
+    fn field(
+        name: &str,
+        serialized_name: &str,
+        annotation: String,
+        default: Option<String>,
+    ) -> PythonField {
+        let name = Self::identifier(name);
+        let mut arguments = Vec::new();
+        if name != serialized_name {
+            arguments.push(format!("alias={}", string_literal(serialized_name)));
+        }
+        if let Some(default) = default {
+            arguments.push(format!("default={}", default));
+        }
+        PythonField {
+            name,
+            annotation,
+            arguments,
+        }
+    }
+
+    /// Names of the types, ordered by name with every type after the local types it
+    /// references; the aliases of enums are evaluated when the module is imported
+    fn dependency_order(structs: &HashMap<String, StructInfo>) -> Vec<&str> {
+        fn visit<'a>(
+            name: &'a str,
+            structs: &'a HashMap<String, StructInfo>,
+            visited: &mut HashSet<&'a str>,
+            order: &mut Vec<&'a str>,
+        ) {
+            if !visited.insert(name) {
+                return;
+            }
+            let mut references = HashSet::new();
+            for field in &structs[name].fields {
+                TypeCollector::collect_referenced_types_from_structure(
+                    &field.type_structure,
+                    &mut references,
+                );
+                for variant_field in &field.variant_fields {
+                    TypeCollector::collect_referenced_types_from_structure(
+                        &variant_field.type_structure,
+                        &mut references,
+                    );
+                }
+            }
+            let mut references: Vec<&String> = references.iter().collect();
+            references.sort();
+            for reference in references {
+                if let Some((key, _)) = structs.get_key_value(reference.as_str()) {
+                    visit(key, structs, visited, order);
+                }
+            }
+            order.push(name);
+        }
+
+        let mut names: Vec<&str> = structs.keys().map(String::as_str).collect();
+        names.sort();
+        let mut visited = HashSet::new();
+        let mut order = Vec::new();
+        for name in names {
+            visit(name, structs, &mut visited, &mut order);
+        }
+        order
+    }
+
+    /// Methods of the commands without channels, which need a Tauri webview to stream
+    fn create_commands(
+        &self,
+        commands: &[CommandInfo],
+        local_types: &HashSet<String>,
+        analyzer: &CommandAnalyzer,
+        config: &GenerateConfig,
+    ) -> Vec<PythonCommand> {
+        let visitor = PythonVisitor::with_config(config).with_local_types(local_types, "types.");
+        let contexts = self
+            .collector
+            .create_command_contexts(commands, &visitor, analyzer, config);
+        commands
+            .iter()
+            .zip(&contexts)
+            .filter(|(command, _)| command.channels.is_empty())
+            .map(|(command, context)| PythonCommand {
+                name: command.name.clone(),
+                method_name: Self::identifier(&command.name),
+                parameters: command
+                    .parameters
+                    .iter()
+                    .zip(&context.parameters)
+                    .map(|(param, param_context)| PythonParameter {
+                        name: Self::identifier(&param.name),
+                        serialized_name: param_context.serialized_name.clone(),
+                        annotation: visitor.annotate(&param.rust_type, &param.type_structure),
+                    })
+                    .collect(),
+                return_type: visitor.annotate(&command.return_type, &command.return_type_structure),
+            })
+            .collect()
+    }
+
+    /// Commands left out of the `Commands` class
+    fn skipped_commands(commands: &[CommandInfo]) -> Vec<&str> {
+        commands
+            .iter()
+            .filter(|command| !command.channels.is_empty())
+            .map(|command| command.name.as_str())
+            .collect()
+    }
+
+    fn render_file(&self, template_name: &str, context: &Context) -> String {
+        self.render(template_name, context).unwrap_or_else(|e| {
+            eprintln!("Template rendering failed for {}: {}", template_name, e);
+            String::new()
+        })
+    }
+}
+
+impl BaseBindingsGenerator for PythonBindingsGenerator {
+    fn tera(&self) -> &Tera {
+        &self.tera
+    }
+
+    fn type_collector(&self) -> &TypeCollector {
+        &self.collector
+    }
+
+    fn generator_type(&self) -> String {
+        "python".to_string()
+    }
+
+    fn language(&self) -> String {
+        "Python".to_string()
+    }
+
+    /// The package is named after the output directory
+    fn import_hint(&self, output_path: &str, _config: &GenerateConfig) -> Option<String> {
+        let package = std::path::Path::new(output_path.trim_end_matches('/')).file_name()?;
+        Some(format!(
+            "from {} import Commands",
+            package.to_string_lossy()
+        ))
+    }
+
+    /// The default header in comments of Python
+    fn generate_file_header(&self) -> String {
+        let mut context = Context::new();
+        context.insert("global", &GlobalContext::new(&self.generator_type()));
+        self.render_file("python/header.tera", &context)
+    }
+
+    fn write_models(
+        &mut self,
+        file_writer: &mut FileWriter,
+        commands: &[CommandInfo],
+        discovered_structs: &HashMap<String, StructInfo>,
+        analyzer: &CommandAnalyzer,
+        config: &GenerateConfig,
+    ) -> Result<Vec<String>, Box<dyn std::error::Error>> {
+        // Types used by commands and events, like the TypeScript bindings
+        let used_structs = self.collector.collect_bindings_types(
+            commands,
+            analyzer.get_discovered_events(),
+            discovered_structs,
+            HashMap::new(),
+            config,
+        );
+
+        // Types without a definition are typed as `Any`, unless they fail generation
+        if config.unknown_type_fallback() == "error" {
+            self.collector.resolve_unknown_types(
+                commands,
+                analyzer.get_discovered_events(),
+                &used_structs,
+                config,
+            )?;
+        }
+
+        let local_types: HashSet<String> = used_structs.keys().cloned().collect();
+        let file_names = config.file_names();
+        let types_module = OutputFileNames::module_name(&file_names.types);
+        let commands_module = OutputFileNames::module_name(&file_names.commands);
+
+        let types = self.create_types(&used_structs, &local_types, config);
+        let mut context = Context::new();
+        context.insert("header", &self.generate_file_header());
+        context.insert("types", &types);
+        let types_content = self.render_file("python/types.py.tera", &context);
+        file_writer.write_typescript_file(&format!("{}.py", types_module), &types_content)?;
+
+        let mut context = Context::new();
+        context.insert("header", &self.generate_file_header());
+        context.insert(
+            "commands",
+            &self.create_commands(commands, &local_types, analyzer, config),
+        );
+        context.insert("skipped_commands", &Self::skipped_commands(commands));
+        context.insert("types_module", &types_module);
+        let commands_content = self.render_file("python/commands.py.tera", &context);
+        file_writer.write_typescript_file(&format!("{}.py", commands_module), &commands_content)?;
+
+        let mut context = Context::new();
+        context.insert("header", &self.generate_file_header());
+        context.insert("types_module", &types_module);
+        context.insert("commands_module", &commands_module);
+        let init_content = self.render_file("python/init.py.tera", &context);
+        file_writer.write_typescript_file("__init__.py", &init_content)?;
+
+        Ok(file_writer.get_generated_files().to_vec())
+    }
+}
+
+impl Default for PythonBindingsGenerator {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_import_hint_names_the_package() {
+        let generator = PythonBindingsGenerator::new();
+        assert_eq!(generator.language(), "Python");
+        assert_eq!(
+            generator
+                .import_hint("./python/bindings/", &GenerateConfig::default())
+                .as_deref(),
+            Some("from bindings import Commands")
+        );
+    }
+
+    #[test]
+    fn test_identifiers_escape_reserved_names() {
+        assert_eq!(
+            PythonBindingsGenerator::identifier("user_name"),
+            "user_name"
+        );
+        assert_eq!(PythonBindingsGenerator::identifier("from"), "from_");
+        assert_eq!(PythonBindingsGenerator::identifier("json"), "json_");
+        assert_eq!(
+            PythonBindingsGenerator::identifier("model_name"),
+            "model_name_"
+        );
+    }
+
+    #[test]
+    fn test_generator_type_is_python() {
+        let gen = PythonBindingsGenerator::new();
+        assert_eq!(gen.generator_type(), "python");
+        assert!(!gen.has_schemas());
+        assert!(gen.generate_file_header().starts_with("# Auto-generated"));
+    }
+}
@@ -0,0 +1,42 @@
+use crate::{generators::base::templates::TemplateRegistry, template};
+use tera::Tera;
+
+pub struct PythonTemplate;
+
+/// Create and configure a Tera template engine for the Python generator
+impl TemplateRegistry for PythonTemplate {
+    fn register_filters(_tera: &mut Tera) {}
+
+    /// Register python-specific templates from embedded strings
+    fn register_templates(tera: &mut Tera) -> Result<(), String> {
+        template!(tera, "python/header.tera", "templates/header.tera");
+        template!(tera, "python/types.py.tera", "templates/types.py.tera");
+        template!(
+            tera,
+            "python/commands.py.tera",
+            "templates/commands.py.tera"
+        );
+        template!(tera, "python/init.py.tera", "templates/init.py.tera");
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_create_tera_registers_python_templates() {
+        let tera = PythonTemplate::create_tera().unwrap();
+        let names: Vec<&str> = tera.get_template_names().collect();
+        for name in [
+            "python/header.tera",
+            "python/types.py.tera",
+            "python/commands.py.tera",
+            "python/init.py.tera",
+        ] {
+            assert!(names.contains(&name), "missing {}", name);
+        }
+    }
+}
@@ -0,0 +1,200 @@
+use crate::analysis::type_resolver::JSON_VALUE_TYPE;
+use crate::generators::base::type_visitor::{rust_number_types, string_literal, TypeVisitor};
+use crate::models::TypeStructure;
+use crate::GenerateConfig;
+use std::cell::RefCell;
+use std::collections::HashSet;
+
+/// Python type visitor - converts TypeStructure to type annotations of Pydantic models
+///
+/// TypeStructure only knows `number`, so [`PythonVisitor::for_rust_type`] recovers
+/// `int` or `float` from the Rust type the structure was parsed from.
+pub struct PythonVisitor<'a> {
+    config: Option<&'a GenerateConfig>,
+    /// Models defined in the generated types module; other custom types are `Any`
+    local_types: Option<&'a HashSet<String>>,
+    /// Prefix of references to the local types, e.g. `types.` outside the types module
+    prefix: &'a str,
+    /// `int`/`float` of the remaining numbers of the visited type, last one first
+    numbers: RefCell<Vec<&'static str>>,
+}
+
+impl<'a> Default for PythonVisitor<'a> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<'a> PythonVisitor<'a> {
+    pub fn new() -> Self {
+        Self {
+            config: None,
+            local_types: None,
+            prefix: "",
+            numbers: RefCell::new(Vec::new()),
+        }
+    }
+
+    pub fn with_config(config: &'a GenerateConfig) -> Self {
+        Self {
+            config: Some(config),
+            ..Self::new()
+        }
+    }
+
+    /// Reference the given models of the types module, prefixed with `prefix`
+    pub fn with_local_types(mut self, local_types: &'a HashSet<String>, prefix: &'a str) -> Self {
+        self.local_types = Some(local_types);
+        self.prefix = prefix;
+        self
+    }
+
+    /// Visitor for `structure` parsed from `rust_type`, typing its numbers as `int` or
+    /// `float` by the Rust number types in order of appearance. When they don't line up
+    /// (e.g. behind a type alias), numbers are typed as `float`, which accepts both.
+    pub fn for_rust_type(&self, rust_type: &str, structure: &TypeStructure) -> Self {
+        let mut numbers: Vec<&'static str> = rust_number_types(rust_type, structure)
+            .into_iter()
+            .map(|number| {
+                if number.starts_with('f') {
+                    "float"
+                } else {
+                    "int"
+                }
+            })
+            .collect();
+        numbers.reverse();
+
+        Self {
+            config: self.config,
+            local_types: self.local_types,
+            prefix: self.prefix,
+            numbers: RefCell::new(numbers),
+        }
+    }
+
+    /// Annotation of `structure` parsed from `rust_type`
+    pub fn annotate(&self, rust_type: &str, structure: &TypeStructure) -> String {
+        self.for_rust_type(rust_type, structure)
+            .visit_type(structure)
+    }
+}
+
+impl<'a> TypeVisitor for PythonVisitor<'a> {
+    fn get_config(&self) -> Option<&GenerateConfig> {
+        self.config
+    }
+
+    fn visit_primitive(&self, type_name: &str) -> String {
+        match type_name {
+            "string" => "str".to_string(),
+            "number" => self
+                .numbers
+                .borrow_mut()
+                .pop()
+                .unwrap_or("float")
+                .to_string(),
+            "boolean" => "bool".to_string(),
+            "void" => "None".to_string(),
+            "Uint8Array" => "bytes".to_string(),
+            _ => "Any".to_string(),
+        }
+    }
+
+    fn visit_array(&self, inner: &TypeStructure) -> String {
+        format!("list[{}]", self.visit_type(inner))
+    }
+
+    fn visit_map(&self, key: &TypeStructure, value: &TypeStructure) -> String {
+        format!("dict[{}, {}]", self.visit_type(key), self.visit_type(value))
+    }
+
+    fn visit_set(&self, inner: &TypeStructure) -> String {
+        format!("list[{}]", self.visit_type(inner))
+    }
+
+    fn visit_tuple(&self, types: &[TypeStructure]) -> String {
+        if types.is_empty() {
+            "None".to_string()
+        } else {
+            let type_strs: Vec<String> = types.iter().map(|t| self.visit_type(t)).collect();
+            format!("tuple[{}]", type_strs.join(", "))
+        }
+    }
+
+    fn visit_optional(&self, inner: &TypeStructure) -> String {
+        format!("Optional[{}]", self.visit_type(inner))
+    }
+
+    fn visit_string_literal(&self, literal: &str, optional: bool) -> String {
+        let literal = format!("Literal[{}]", string_literal(literal));
+        if optional {
+            format!("Optional[{}]", literal)
+        } else {
+            literal
+        }
+    }
+
+    fn visit_union(&self, types: &[TypeStructure]) -> String {
+        let type_strs: Vec<String> = types.iter().map(|t| self.visit_type(t)).collect();
+        format!("Union[{}]", type_strs.join(", "))
+    }
+
+    /// Models of the types module, `Any` for everything else: type mappings and
+    /// field overrides name TypeScript types
+    fn visit_custom(&self, name: &str) -> String {
+        match self.local_types {
+            Some(local_types) if name != JSON_VALUE_TYPE && local_types.contains(name) => {
+                format!("{}{}", self.prefix, name)
+            }
+            _ => "Any".to_string(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn custom(name: &str) -> TypeStructure {
+        TypeStructure::Custom(name.to_string())
+    }
+
+    fn number() -> TypeStructure {
+        TypeStructure::Primitive("number".to_string())
+    }
+
+    #[test]
+    fn test_numbers_follow_rust_types() {
+        let visitor = PythonVisitor::new();
+        let structure = TypeStructure::Map {
+            key: Box::new(number()),
+            value: Box::new(TypeStructure::Array(Box::new(number()))),
+        };
+        assert_eq!(
+            visitor.annotate("HashMap<u32, Vec<f64>>", &structure),
+            "dict[int, list[float]]"
+        );
+        // An alias hides the Rust number type
+        assert_eq!(
+            visitor.annotate("HashMap<UserId, Vec<f64>>", &structure),
+            "dict[float, list[float]]"
+        );
+    }
+
+    #[test]
+    fn test_local_and_unknown_types() {
+        let local_types = HashSet::from(["User".to_string()]);
+        let visitor = PythonVisitor::new().with_local_types(&local_types, "types.");
+        let structure = TypeStructure::Optional(Box::new(TypeStructure::Tuple(vec![
+            custom("User"),
+            custom("chrono::DateTime"),
+            custom(JSON_VALUE_TYPE),
+        ])));
+        assert_eq!(
+            visitor.visit_type(&structure),
+            "Optional[tuple[types.User, Any, Any]]"
+        );
+        assert_eq!(visitor.visit_type(&TypeStructure::Tuple(vec![])), "None");
+    }
+}
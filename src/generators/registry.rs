@@ -0,0 +1,217 @@
+//! Generators of other crates, registered by name and selected like the built-in ones
+//! through `validation_library` (see [`crate::api::register_generator`]). The registry
+//! is per process, so only generation run by the registering program sees them.
+
+use crate::analysis::CommandAnalyzer;
+use crate::generators::base::file_writer::{FileWriter, GeneratedFile};
+use crate::generators::base::BaseBindingsGenerator;
+use crate::generators::TypeCollector;
+use crate::interface::AnalysisResult;
+use crate::models::{CommandInfo, StructInfo};
+use crate::GenerateConfig;
+use std::collections::{BTreeMap, HashMap};
+use std::sync::{Arc, Mutex, PoisonError};
+use tera::Tera;
+
+/// `validation_library` values of the built-in generators, which cannot be replaced
+pub const BUILT_IN_GENERATORS: &[&str] = &["zod", "valibot", "none"];
+
+/// Output backend of another crate, generating bindings in its own language from the
+/// analysis of the project
+pub trait BindingsGenerator {
+    /// Files of the bindings, named relative to the output directory
+    fn generate(
+        &mut self,
+        analysis: &AnalysisResult,
+        config: &GenerateConfig,
+    ) -> Result<Vec<GeneratedFile>, Box<dyn std::error::Error>>;
+}
+
+type Factory = Arc<dyn Fn() -> Box<dyn BindingsGenerator> + Send + Sync>;
+
+static REGISTRY: Mutex<BTreeMap<String, Factory>> = Mutex::new(BTreeMap::new());
+
+/// Register `factory` for `validation_library` value `name`, replacing an earlier
+/// registration of the name. Built-in generators cannot be replaced.
+pub fn register<F>(name: &str, factory: F) -> Result<(), String>
+where
+    F: Fn() -> Box<dyn BindingsGenerator> + Send + Sync + 'static,
+{
+    if name.trim().is_empty() {
+        return Err("Generator names must not be empty".to_string());
+    }
+    if BUILT_IN_GENERATORS.contains(&name) {
+        return Err(format!(
+            "Generator {} is built in and cannot be registered",
+            name
+        ));
+    }
+    REGISTRY
+        .lock()
+        .unwrap_or_else(PoisonError::into_inner)
+        .insert(name.to_string(), Arc::new(factory));
+    Ok(())
+}
+
+/// Names of the registered generators, in order
+pub fn registered() -> Vec<String> {
+    REGISTRY
+        .lock()
+        .unwrap_or_else(PoisonError::into_inner)
+        .keys()
+        .cloned()
+        .collect()
+}
+
+/// Whether a generator is registered for `name`
+pub fn is_registered(name: &str) -> bool {
+    REGISTRY
+        .lock()
+        .unwrap_or_else(PoisonError::into_inner)
+        .contains_key(name)
+}
+
+/// A new generator of the factory registered for `name`
+pub fn create(name: &str) -> Option<Box<dyn BaseBindingsGenerator>> {
+    let factory = REGISTRY
+        .lock()
+        .unwrap_or_else(PoisonError::into_inner)
+        .get(name)
+        .cloned()?;
+    Some(Box::new(RegisteredGenerator {
+        name: name.to_string(),
+        generator: factory(),
+        tera: Tera::default(),
+        collector: TypeCollector::new(),
+    }))
+}
+
+/// Adapter running a registered generator in place of a built-in one
+struct RegisteredGenerator {
+    name: String,
+    generator: Box<dyn BindingsGenerator>,
+    tera: Tera,
+    collector: TypeCollector,
+}
+
+impl BaseBindingsGenerator for RegisteredGenerator {
+    fn tera(&self) -> &Tera {
+        &self.tera
+    }
+
+    fn type_collector(&self) -> &TypeCollector {
+        &self.collector
+    }
+
+    fn generator_type(&self) -> String {
+        self.name.clone()
+    }
+
+    fn language(&self) -> String {
+        self.name.clone()
+    }
+
+    fn import_hint(&self, _output_path: &str, _config: &GenerateConfig) -> Option<String> {
+        None
+    }
+
+    fn write_models(
+        &mut self,
+        file_writer: &mut FileWriter,
+        commands: &[CommandInfo],
+        discovered_structs: &HashMap<String, StructInfo>,
+        analyzer: &CommandAnalyzer,
+        config: &GenerateConfig,
+    ) -> Result<Vec<String>, Box<dyn std::error::Error>> {
+        let analysis = AnalysisResult {
+            commands: commands.to_vec(),
+            structs: discovered_structs.clone(),
+            events: analyzer.get_discovered_events().to_vec(),
+            constants: analyzer.get_discovered_constants().to_vec(),
+        };
+        for file in self.generator.generate(&analysis, config)? {
+            if file.name.contains('/') || file.name.contains('\\') {
+                return Err(format!(
+                    "Generator {} wrote {}, generated files must be named without directories",
+                    self.name, file.name
+                )
+                .into());
+            }
+            file_writer.write_typescript_file(&file.name, &file.content)?;
+        }
+        Ok(file_writer.get_generated_files().to_vec())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct CommandList;
+
+    impl BindingsGenerator for CommandList {
+        fn generate(
+            &mut self,
+            analysis: &AnalysisResult,
+            _config: &GenerateConfig,
+        ) -> Result<Vec<GeneratedFile>, Box<dyn std::error::Error>> {
+            let names: Vec<&str> = analysis.commands.iter().map(|c| c.name.as_str()).collect();
+            Ok(vec![GeneratedFile {
+                name: "commands.txt".to_string(),
+                content: names.join("\n"),
+            }])
+        }
+    }
+
+    #[test]
+    fn test_registered_generators_write_their_files() {
+        register("registry-test", || Box::new(CommandList)).unwrap();
+        assert!(is_registered("registry-test"));
+        assert!(registered().contains(&"registry-test".to_string()));
+
+        let mut analyzer = CommandAnalyzer::new();
+        let commands = analyzer
+            .analyze_source("#[tauri::command] fn ping() {}")
+            .unwrap();
+        let mut generator = create("registry-test").unwrap();
+        assert_eq!(generator.generator_type(), "registry-test");
+        assert!(!generator.has_schemas());
+
+        let mut file_writer = FileWriter::in_memory();
+        let written = generator
+            .write_models(
+                &mut file_writer,
+                &commands,
+                analyzer.get_discovered_structs(),
+                &analyzer,
+                &GenerateConfig::default(),
+            )
+            .unwrap();
+        assert_eq!(written, ["commands.txt"]);
+        assert_eq!(file_writer.into_files()[0].content, "ping");
+    }
+
+    #[test]
+    fn test_built_in_generators_cannot_be_registered() {
+        let error = register("zod", || Box::new(CommandList)).unwrap_err();
+        assert!(error.contains("built in"));
+        assert!(register(" ", || Box::new(CommandList)).is_err());
+        assert!(create("unregistered").is_none());
+    }
+
+    #[test]
+    fn test_unregistered_names_are_rejected() {
+        let config = GenerateConfig {
+            validation_library: "registry-unregistered".to_string(),
+            ..Default::default()
+        };
+        assert!(matches!(
+            config.validate_options(),
+            Err(crate::interface::config::ConfigError::InvalidValidationLibrary(name))
+                if name == "registry-unregistered"
+        ));
+
+        register("registry-unregistered", || Box::new(CommandList)).unwrap();
+        assert!(config.validate_options().is_ok());
+    }
+}
@@ -0,0 +1,434 @@
+use crate::analysis::CommandAnalyzer;
+use crate::generators::base::casing::CaseConverter;
+use crate::generators::base::file_writer::FileWriter;
+use crate::generators::base::template_context::FieldContext;
+use crate::generators::base::templates::{GlobalContext, TemplateRegistry};
+use crate::generators::base::type_visitor::string_literal;
+use crate::generators::base::BaseBindingsGenerator;
+use crate::generators::swift::templates::SwiftTemplate;
+use crate::generators::swift::type_visitor::SwiftVisitor;
+use crate::generators::TypeCollector;
+use crate::models::{CommandInfo, StructInfo};
+use crate::{GenerateConfig, OutputFileNames, TypeStructure};
+use serde::Serialize;
+use std::collections::{HashMap, HashSet};
+use tera::{Context, Tera};
+
+/// Swift keywords, escaped with backticks when used as names
+const KEYWORDS: &[&str] = &[
+    "as",
+    "associatedtype",
+    "break",
+    "case",
+    "catch",
+    "class",
+    "continue",
+    "default",
+    "defer",
+    "deinit",
+    "do",
+    "else",
+    "enum",
+    "extension",
+    "fallthrough",
+    "false",
+    "fileprivate",
+    "for",
+    "func",
+    "guard",
+    "if",
+    "import",
+    "in",
+    "init",
+    "inout",
+    "internal",
+    "is",
+    "let",
+    "nil",
+    "operator",
+    "private",
+    "protocol",
+    "public",
+    "repeat",
+    "rethrows",
+    "return",
+    "self",
+    "Self",
+    "static",
+    "struct",
+    "subscript",
+    "super",
+    "switch",
+    "throw",
+    "throws",
+    "true",
+    "try",
+    "typealias",
+    "var",
+    "where",
+    "while",
+];
+
+/// A struct, enum or type alias of the types file
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SwiftType {
+    pub name: String,
+    /// Aliased type for newtypes and enums with data, `None` otherwise
+    pub alias: Option<String>,
+    /// Why the type is an alias, shown as its doc comment
+    pub alias_doc: Option<String>,
+    /// Cases of a string enum
+    pub cases: Vec<SwiftMember>,
+    /// Properties of a struct
+    pub fields: Vec<SwiftField>,
+}
+
+/// A case of an enum or a property of an argument struct, coded under its serialized name
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SwiftMember {
+    pub name: String,
+    pub serialized_name: String,
+}
+
+/// A property of a struct, named like the Rust field in camelCase
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SwiftField {
+    pub name: String,
+    pub serialized_name: String,
+    pub type_name: String,
+    /// Default of its initializer parameter
+    pub default: Option<String>,
+}
+
+/// An async function of the `Commands` struct invoking a command
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SwiftCommand {
+    /// Name the command is invoked by
+    pub name: String,
+    pub function_name: String,
+    /// Name of the private struct encoding the arguments, e.g. `GetUserArgs`
+    pub args_name: String,
+    pub parameters: Vec<SwiftField>,
+    pub return_type: String,
+}
+
+/// Generator for Swift bindings: `Codable` structs of the types and a `Commands` struct
+/// of async functions calling the commands through an `invoke` closure, for native iOS
+/// code of Tauri mobile apps
+pub struct SwiftBindingsGenerator {
+    collector: TypeCollector,
+    tera: Tera,
+}
+
+impl SwiftBindingsGenerator {
+    pub fn new() -> Self {
+        Self {
+            collector: TypeCollector::new(),
+            tera: SwiftTemplate::create_tera().expect("Failed to initialize Swift template engine"),
+        }
+    }
+
+    /// Name of the Swift file replacing a TypeScript file, e.g. `Types.swift` for
+    /// `types.ts`
+    pub fn file_name(file_name: &str, case_converter: &CaseConverter) -> String {
+        let module = OutputFileNames::module_name(file_name);
+        format!("{}.swift", case_converter.to_pascal_case(&module))
+    }
+
+    /// A name as a Swift identifier, keywords escaped with backticks
+    pub fn identifier(name: String) -> String {
+        let name = CaseConverter::identifier(name);
+        if KEYWORDS.contains(&name.as_str()) {
+            format!("`{}`", name)
+        } else {
+            name
+        }
+    }
+
+    /// Declarations of the used types, ordered by name
+    fn create_types(
+        &self,
+        used_structs: &HashMap<String, StructInfo>,
+        local_types: &HashSet<String>,
+        config: &GenerateConfig,
+    ) -> Vec<SwiftType> {
+        let visitor = SwiftVisitor::with_config(config).with_local_types(local_types);
+        let case_converter = CaseConverter::new(config.acronyms());
+        let mut names: Vec<&String> = used_structs.keys().collect();
+        names.sort();
+
+        names
+            .into_iter()
+            .map(|name| {
+                let struct_info = &used_structs[name];
+                let mut swift_type = SwiftType {
+                    name: name.clone(),
+                    alias: None,
+                    alias_doc: None,
+                    cases: Vec::new(),
+                    fields: Vec::new(),
+                };
+                let fields = self
+                    .collector
+                    .create_field_contexts(struct_info, &visitor, config);
+
+                if struct_info.is_enum {
+                    if struct_info.has_variant_data() || !struct_info.is_externally_tagged() {
+                        // Variants with payloads need hand-written coding, so the value
+                        // is left for the caller to decode
+                        swift_type.alias = Some("JSONValue".to_string());
+                        swift_type.alias_doc = Some(format!(
+                            "{} enum `{}`, kept as JSON",
+                            if struct_info.is_untagged() {
+                                "Untagged"
+                            } else if struct_info.is_internally_tagged() {
+                                "Internally tagged"
+                            } else if struct_info.is_adjacently_tagged() {
+                                "Adjacently tagged"
+                            } else {
+                                "Externally tagged"
+                            },
+                            name
+                        ));
+                    } else {
+                        swift_type.cases = fields
+                            .iter()
+                            .map(|variant| SwiftMember {
+                                name: Self::identifier(case_converter.to_camel_case(&variant.name)),
+                                serialized_name: variant.serialized_name.clone(),
+                            })
+                            .collect();
+                    }
+                } else if struct_info.is_newtype && !struct_info.fields.is_empty() {
+                    let field = &struct_info.fields[0];
+                    swift_type.alias =
+                        Some(visitor.annotate(&field.rust_type, &field.type_structure));
+                } else {
+                    swift_type.fields = self
+                        .collector
+                        .serialized_fields(struct_info, config)
+                        .iter()
+                        .map(|field| {
+                            let field_visitor =
+                                visitor.for_rust_type(&field.rust_type, &field.type_structure);
+                            let context = FieldContext::new(config).from_field_info(
+                                field,
+                                &struct_info.serde_rename_all,
+                                &field_visitor,
+                            );
+                            let optional = field.is_optional
+                                || matches!(field.type_structure, TypeStructure::Optional(_));
+                            SwiftField {
+                                name: Self::identifier(case_converter.to_camel_case(&field.name)),
+                                serialized_name: context.serialized_name,
+                                type_name: if optional
+                                    && field.literal.is_none()
+                                    && !context.typescript_type.ends_with('?')
+                                {
+                                    format!("{}?", context.typescript_type)
+                                } else {
+                                    context.typescript_type
+                                },
+                                default: match field.literal {
+                                    Some(ref literal) => Some(string_literal(literal)),
+                                    // Fields the backend may leave out are `nil` when absent,
+                                    // and `nil` is left out again when sent
+                                    None => optional.then(|| "nil".to_string()),
+                                },
+                            }
+                        })
+                        .collect();
+                }
+                swift_type
+            })
+            .collect()
+    }
+
+    /// Functions of the commands without channels, which need a Tauri webview to stream
+    fn create_commands(
+        &self,
+        commands: &[CommandInfo],
+        local_types: &HashSet<String>,
+        analyzer: &CommandAnalyzer,
+        config: &GenerateConfig,
+    ) -> Vec<SwiftCommand> {
+        let visitor = SwiftVisitor::with_config(config).with_local_types(local_types);
+        let case_converter = CaseConverter::new(config.acronyms());
+        let contexts = self
+            .collector
+            .create_command_contexts(commands, &visitor, analyzer, config);
+        commands
+            .iter()
+            .zip(&contexts)
+            .filter(|(command, _)| command.channels.is_empty())
+            .map(|(command, context)| SwiftCommand {
+                name: command.name.clone(),
+                function_name: Self::identifier(context.ts_function_name.clone()),
+                args_name: format!("{}Args", context.ts_type_name),
+                parameters: command
+                    .parameters
+                    .iter()
+                    .zip(&context.parameters)
+                    .map(|(param, param_context)| SwiftField {
+                        name: Self::identifier(case_converter.to_camel_case(&param.name)),
+                        serialized_name: param_context.serialized_name.clone(),
+                        type_name: visitor.annotate(&param.rust_type, &param.type_structure),
+                        default: None,
+                    })
+                    .collect(),
+                return_type: visitor.annotate(&command.return_type, &command.return_type_structure),
+            })
+            .collect()
+    }
+
+    fn render_file(&self, template_name: &str, context: &Context) -> String {
+        self.render(template_name, context).unwrap_or_else(|e| {
+            eprintln!("Template rendering failed for {}: {}", template_name, e);
+            String::new()
+        })
+    }
+}
+
+impl BaseBindingsGenerator for SwiftBindingsGenerator {
+    fn tera(&self) -> &Tera {
+        &self.tera
+    }
+
+    fn type_collector(&self) -> &TypeCollector {
+        &self.collector
+    }
+
+    fn generator_type(&self) -> String {
+        "swift".to_string()
+    }
+
+    fn language(&self) -> String {
+        "Swift".to_string()
+    }
+
+    /// The files are compiled into the app's module, so they are used without an import
+    fn import_hint(&self, _output_path: &str, _config: &GenerateConfig) -> Option<String> {
+        Some("let commands = Commands(invoke: invoke)".to_string())
+    }
+
+    /// The default header in comments of Swift
+    fn generate_file_header(&self) -> String {
+        let mut context = Context::new();
+        context.insert("global", &GlobalContext::new(&self.generator_type()));
+        self.render_file("swift/header.tera", &context)
+    }
+
+    fn write_models(
+        &mut self,
+        file_writer: &mut FileWriter,
+        commands: &[CommandInfo],
+        discovered_structs: &HashMap<String, StructInfo>,
+        analyzer: &CommandAnalyzer,
+        config: &GenerateConfig,
+    ) -> Result<Vec<String>, Box<dyn std::error::Error>> {
+        // Types used by commands and events, like the TypeScript bindings
+        let used_structs = self.collector.collect_bindings_types(
+            commands,
+            analyzer.get_discovered_events(),
+            discovered_structs,
+            HashMap::new(),
+            config,
+        );
+
+        // Types without a definition are `JSONValue`, unless they fail generation
+        if config.unknown_type_fallback() == "error" {
+            self.collector.resolve_unknown_types(
+                commands,
+                analyzer.get_discovered_events(),
+                &used_structs,
+                config,
+            )?;
+        }
+
+        let local_types: HashSet<String> = used_structs.keys().cloned().collect();
+        let file_names = config.file_names();
+        let case_converter = CaseConverter::new(config.acronyms());
+        let types = self.create_types(&used_structs, &local_types, config);
+        let swift_commands = self.create_commands(commands, &local_types, analyzer, config);
+
+        // `JSONValue` is declared once it is referenced
+        let uses_json_value = types
+            .iter()
+            .flat_map(|swift_type| {
+                swift_type
+                    .alias
+                    .iter()
+                    .chain(swift_type.fields.iter().map(|field| &field.type_name))
+            })
+            .chain(swift_commands.iter().flat_map(|command| {
+                std::iter::once(&command.return_type)
+                    .chain(command.parameters.iter().map(|param| &param.type_name))
+            }))
+            .any(|type_name| type_name.contains("JSONValue"));
+
+        let mut context = Context::new();
+        context.insert("header", &self.generate_file_header());
+        context.insert("types", &types);
+        context.insert("uses_json_value", &uses_json_value);
+        let types_content = self.render_file("swift/types.swift.tera", &context);
+        file_writer.write_typescript_file(
+            &Self::file_name(&file_names.types, &case_converter),
+            &types_content,
+        )?;
+
+        let mut context = Context::new();
+        context.insert("header", &self.generate_file_header());
+        context.insert("commands", &swift_commands);
+        context.insert(
+            "skipped_commands",
+            &commands
+                .iter()
+                .filter(|command| !command.channels.is_empty())
+                .map(|command| command.name.as_str())
+                .collect::<Vec<_>>(),
+        );
+        let commands_content = self.render_file("swift/commands.swift.tera", &context);
+        file_writer.write_typescript_file(
+            &Self::file_name(&file_names.commands, &case_converter),
+            &commands_content,
+        )?;
+
+        Ok(file_writer.get_generated_files().to_vec())
+    }
+}
+
+impl Default for SwiftBindingsGenerator {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_identifiers_escape_keywords() {
+        assert_eq!(
+            SwiftBindingsGenerator::identifier("userName".to_string()),
+            "userName"
+        );
+        assert_eq!(
+            SwiftBindingsGenerator::identifier("default".to_string()),
+            "`default`"
+        );
+    }
+
+    #[test]
+    fn test_file_names_of_typescript_files() {
+        let case_converter = CaseConverter::new(&[]);
+        assert_eq!(
+            SwiftBindingsGenerator::file_name("commands.ts", &case_converter),
+            "Commands.swift"
+        );
+    }
+}
@@ -0,0 +1,40 @@
+use crate::{generators::base::templates::TemplateRegistry, template};
+use tera::Tera;
+
+pub struct SwiftTemplate;
+
+/// Create and configure a Tera template engine for the Swift generator
+impl TemplateRegistry for SwiftTemplate {
+    fn register_filters(_tera: &mut Tera) {}
+
+    /// Register swift-specific templates from embedded strings
+    fn register_templates(tera: &mut Tera) -> Result<(), String> {
+        template!(tera, "swift/header.tera", "templates/header.tera");
+        template!(tera, "swift/types.swift.tera", "templates/types.swift.tera");
+        template!(
+            tera,
+            "swift/commands.swift.tera",
+            "templates/commands.swift.tera"
+        );
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_create_tera_registers_swift_templates() {
+        let tera = SwiftTemplate::create_tera().unwrap();
+        let names: Vec<&str> = tera.get_template_names().collect();
+        for name in [
+            "swift/header.tera",
+            "swift/types.swift.tera",
+            "swift/commands.swift.tera",
+        ] {
+            assert!(names.contains(&name), "missing {}", name);
+        }
+    }
+}
@@ -1,7 +1,8 @@
 use crate::analysis::CommandAnalyzer;
 use crate::generators::base::file_writer::FileWriter;
+use crate::generators::base::template_context::{CommandContext, EventContext, StructContext};
 use crate::generators::base::templates::TemplateRegistry;
-use crate::generators::base::BaseBindingsGenerator;
+use crate::generators::base::{BaseBindingsGenerator, TypeScriptFiles};
 use crate::generators::ts::templates::TypeScriptTemplate;
 use crate::generators::ts::type_visitor::TypeScriptVisitor;
 use crate::generators::TypeCollector;
@@ -24,6 +25,82 @@ impl TypeScriptBindingsGenerator {
                 .expect("Failed to initialize TypeScript template engine"),
         }
     }
+}
+
+impl BaseBindingsGenerator for TypeScriptBindingsGenerator {
+    fn tera(&self) -> &Tera {
+        &self.tera
+    }
+
+    fn type_collector(&self) -> &TypeCollector {
+        &self.collector
+    }
+
+    fn generator_type(&self) -> String {
+        "none".to_string()
+    }
+
+    fn write_models(
+        &mut self,
+        file_writer: &mut FileWriter,
+        commands: &[CommandInfo],
+        discovered_structs: &HashMap<String, StructInfo>,
+        analyzer: &CommandAnalyzer,
+        config: &GenerateConfig,
+    ) -> Result<Vec<String>, Box<dyn std::error::Error>> {
+        self.write_typescript_models(file_writer, commands, discovered_structs, analyzer, config)
+    }
+}
+
+impl TypeScriptFiles for TypeScriptBindingsGenerator {
+    fn template_dir(&self) -> &'static str {
+        "typescript"
+    }
+
+    fn type_collector_mut(&mut self) -> &mut TypeCollector {
+        &mut self.collector
+    }
+
+    fn command_contexts(
+        &self,
+        commands: &[CommandInfo],
+        analyzer: &CommandAnalyzer,
+        config: &GenerateConfig,
+    ) -> Vec<CommandContext> {
+        let command_contexts = self.collector.create_command_contexts(
+            commands,
+            &TypeScriptVisitor::with_config(config),
+            analyzer,
+            config,
+        );
+        command_contexts
+    }
+
+    fn event_contexts(
+        &self,
+        events: &[EventInfo],
+        analyzer: &CommandAnalyzer,
+        config: &GenerateConfig,
+    ) -> Vec<EventContext> {
+        self.collector.create_event_contexts(
+            events,
+            &TypeScriptVisitor::with_config(config),
+            analyzer,
+            config,
+        )
+    }
+
+    fn struct_contexts(
+        &self,
+        used_structs: &HashMap<String, StructInfo>,
+        config: &GenerateConfig,
+    ) -> Vec<StructContext> {
+        self.collector.create_struct_contexts(
+            used_structs,
+            &TypeScriptVisitor::with_config(config),
+            config,
+        )
+    }
 
     /// Generate the complete types.ts file content
     fn generate_types_file_content(
@@ -36,6 +113,11 @@ impl TypeScriptBindingsGenerator {
         let has_channels = commands.iter().any(|cmd| !cmd.channels.is_empty());
         let visitor = TypeScriptVisitor::with_config(config);
 
+        // Types of shared crates are imported from their package instead of defined
+        let (shared_imports, local_structs) =
+            self.collector.split_shared_types(used_structs, config);
+        let used_structs = &local_structs;
+
         // Convert structs to context wrappers
         let struct_context = self
             .collector
@@ -50,6 +132,15 @@ impl TypeScriptBindingsGenerator {
         let mut context = Context::new();
         context.insert("header", &self.generate_file_header());
         context.insert("has_channels", &has_channels);
+        context.insert(
+            "type_imports",
+            &self.collector.field_override_imports(used_structs, config),
+        );
+        context.insert("shared_imports", &shared_imports);
+        context.insert(
+            "uses_json_value",
+            &self.collector.uses_json_value(commands, used_structs),
+        );
         context.insert("structs", &struct_context);
         context.insert("commands", &command_context);
 
@@ -59,145 +150,6 @@ impl TypeScriptBindingsGenerator {
                 String::new()
             })
     }
-
-    /// Generate command bindings
-    fn generate_command_bindings(
-        &self,
-        commands: &[CommandInfo],
-        analyzer: &CommandAnalyzer,
-        config: &GenerateConfig,
-    ) -> String {
-        let has_channels = commands.iter().any(|cmd| !cmd.channels.is_empty());
-        let visitor = TypeScriptVisitor::with_config(config);
-
-        // Convert commands to context wrappers
-        let command_contexts = self
-            .collector
-            .create_command_contexts(commands, &visitor, analyzer, config);
-
-        let mut context = Context::new();
-        context.insert("header", &self.generate_file_header());
-        context.insert("commands", &command_contexts);
-        context.insert("has_channels", &has_channels);
-
-        self.render("typescript/commands.ts.tera", &context)
-            .unwrap_or_else(|e| {
-                eprintln!("Template rendering failed for commands.ts: {}", e);
-                String::new()
-            })
-    }
-
-    /// Generate index.ts file
-    fn generate_index_file(&self, generated_files: &[String]) -> String {
-        let mut context = Context::new();
-        context.insert("header", &self.generate_file_header());
-        context.insert("files", generated_files);
-
-        self.render("typescript/index.ts.tera", &context)
-            .unwrap_or_else(|e| {
-                eprintln!("Template rendering failed for index.ts: {}", e);
-                String::new()
-            })
-    }
-
-    /// Generate events file content
-    fn generate_events_file(
-        &self,
-        events: &[EventInfo],
-        analyzer: &CommandAnalyzer,
-        config: &GenerateConfig,
-    ) -> String {
-        let visitor = TypeScriptVisitor::with_config(config);
-
-        // Convert events to context wrappers
-        let event_contexts = self
-            .collector
-            .create_event_contexts(events, &visitor, analyzer, config);
-
-        let mut context = Context::new();
-        context.insert("header", &self.generate_file_header());
-        context.insert("events", &event_contexts);
-
-        self.render("typescript/events.ts.tera", &context)
-            .unwrap_or_else(|e| {
-                eprintln!("Template rendering failed for events.ts: {}", e);
-                String::new()
-            })
-    }
-}
-
-impl BaseBindingsGenerator for TypeScriptBindingsGenerator {
-    fn tera(&self) -> &Tera {
-        &self.tera
-    }
-
-    fn type_collector(&self) -> &TypeCollector {
-        &self.collector
-    }
-
-    fn generator_type(&self) -> String {
-        "none".to_string()
-    }
-
-    fn generate_models(
-        &mut self,
-        commands: &[CommandInfo],
-        discovered_structs: &HashMap<String, StructInfo>,
-        output_path: &str,
-        analyzer: &CommandAnalyzer,
-        config: &GenerateConfig,
-    ) -> Result<Vec<String>, Box<dyn std::error::Error>> {
-        // Store known structs for reference
-        self.collector.known_structs = discovered_structs.clone();
-
-        // Filter to only the types used by commands
-        let mut used_structs = self
-            .collector
-            .collect_used_types(commands, discovered_structs);
-
-        // Also collect types used in events
-        let events = analyzer.get_discovered_events();
-
-        for event in events {
-            let mut event_types = std::collections::HashSet::new();
-            TypeCollector::collect_referenced_types_from_structure(
-                &event.payload_type_structure,
-                &mut event_types,
-            );
-
-            // Add event payload types to used_structs
-            for type_name in event_types {
-                if let Some(struct_info) = discovered_structs.get(&type_name) {
-                    used_structs.insert(type_name.clone(), struct_info.clone());
-                }
-            }
-        }
-
-        // Create file writer
-        let mut file_writer = FileWriter::new(output_path)?;
-
-        // Generate and write types file
-        let types_content =
-            self.generate_types_file_content(commands, &used_structs, analyzer, config);
-        file_writer.write_types_file(&types_content)?;
-
-        // Generate and write commands file
-        let commands_content = self.generate_command_bindings(commands, analyzer, config);
-        file_writer.write_commands_file(&commands_content)?;
-
-        // Generate and write events file if there are any events
-        let events = analyzer.get_discovered_events();
-        if !events.is_empty() {
-            let events_content = self.generate_events_file(events, analyzer, config);
-            file_writer.write_events_file(&events_content)?;
-        }
-
-        // Generate and write index file
-        let index_content = self.generate_index_file(file_writer.get_generated_files());
-        file_writer.write_index_file(&index_content)?;
-
-        Ok(file_writer.get_generated_files().to_vec())
-    }
 }
 
 impl Default for TypeScriptBindingsGenerator {
@@ -295,15 +247,96 @@ mod tests {
         fn test_generate_index_file_with_empty_files() {
             let gen = TypeScriptBindingsGenerator::new();
             let files = vec![];
-            let result = gen.generate_index_file(&files);
+            let result = gen.generate_index_file(&files, "index.ts");
             assert!(result.contains("Auto-generated") || result.contains("//"));
         }
 
+        fn enum_info(name: &str, variants: &[&str], rust_type: &str) -> StructInfo {
+            StructInfo {
+                name: name.to_string(),
+                fields: variants
+                    .iter()
+                    .map(|variant| crate::models::FieldInfo {
+                        name: variant.to_string(),
+                        rust_type: rust_type.to_string(),
+                        is_optional: false,
+                        is_public: true,
+                        validator_attributes: None,
+                        serde_rename: None,
+                        type_structure: crate::TypeStructure::Custom(rust_type.to_string()),
+                        variant_fields: vec![],
+                        literal: None,
+                        flatten: false,
+                        omittable: false,
+                        defaulted: false,
+                        skip_serializing_if: false,
+                        skipped: None,
+                        serde_with: None,
+                    })
+                    .collect(),
+                file_path: "main.rs".to_string(),
+                is_enum: true,
+                serde_rename_all: None,
+                is_newtype: false,
+                serde_tag: None,
+                serde_content: None,
+                serde_untagged: false,
+                line_number: 0,
+                is_progress: false,
+                serde_deny_unknown_fields: false,
+            }
+        }
+
+        #[test]
+        fn test_generate_enum_helpers_file() {
+            let gen = TypeScriptBindingsGenerator::new();
+            let config = GenerateConfig::default();
+            let mut structs = HashMap::new();
+            structs.insert(
+                "Status".to_string(),
+                enum_info("Status", &["Active", "Done"], "enum_variant"),
+            );
+            let contexts = gen.collector.create_struct_contexts(
+                &structs,
+                &TypeScriptVisitor::with_config(&config),
+                &config,
+            );
+
+            let result = gen.generate_enum_helpers_file(&contexts, &config).unwrap();
+            assert!(result.contains("import type * as types from './types';"));
+            assert!(result.contains("export const StatusValues: readonly types.Status[] = ["));
+            assert!(
+                result.contains("export function isStatus(value: unknown): value is types.Status")
+            );
+            assert!(result.contains("export function parseStatus(value: string): types.Status"));
+            assert!(result.contains("${StatusValues.join(', ')}"));
+            assert!(result.contains("export const StatusLabels: Record<types.Status, string> = {"));
+            assert!(result.contains("\"Done\": \"Done\","));
+        }
+
+        #[test]
+        fn test_generate_enum_helpers_file_skips_data_enums() {
+            let gen = TypeScriptBindingsGenerator::new();
+            let config = GenerateConfig::default();
+            let mut structs = HashMap::new();
+            structs.insert(
+                "Shape".to_string(),
+                enum_info("Shape", &["Circle"], "enum_variant_tuple"),
+            );
+            let contexts = gen.collector.create_struct_contexts(
+                &structs,
+                &TypeScriptVisitor::with_config(&config),
+                &config,
+            );
+
+            assert!(gen.generate_enum_helpers_file(&contexts, &config).is_none());
+        }
+
         #[test]
         fn test_generate_index_file_with_files() {
             let gen = TypeScriptBindingsGenerator::new();
             let files = vec!["types.ts".to_string(), "commands.ts".to_string()];
-            let result = gen.generate_index_file(&files);
+            let result = gen.generate_index_file(&files, "index.ts");
             assert!(!result.is_empty());
         }
     }
@@ -34,6 +34,26 @@ impl TemplateRegistry for TypeScriptTemplate {
             "typescript/partials/enum.tera",
             "templates/partials/enum.tera"
         );
+        template!(
+            tera,
+            "typescript/partials/newtype.tera",
+            "templates/partials/newtype.tera"
+        );
+        template!(
+            tera,
+            "typescript/partials/tagged_enum.tera",
+            "templates/partials/tagged_enum.tera"
+        );
+        template!(
+            tera,
+            "typescript/partials/untagged_enum.tera",
+            "templates/partials/untagged_enum.tera"
+        );
+        template!(
+            tera,
+            "typescript/partials/external_enum.tera",
+            "templates/partials/external_enum.tera"
+        );
         template!(
             tera,
             "typescript/partials/param_interface.ts.tera",
@@ -88,6 +108,9 @@ mod tests {
             assert!(template_names.contains(&"typescript/partials/param_interface.ts.tera"));
             assert!(template_names.contains(&"typescript/partials/command_function.ts.tera"));
             assert!(template_names.contains(&"typescript/partials/event_listener.ts.tera"));
+            assert!(template_names.contains(&"typescript/partials/tagged_enum.tera"));
+            assert!(template_names.contains(&"typescript/partials/untagged_enum.tera"));
+            assert!(template_names.contains(&"typescript/partials/external_enum.tera"));
         }
 
         #[test]
@@ -96,14 +119,83 @@ mod tests {
             let template_names: Vec<&str> = tera.get_template_names().collect();
 
             assert!(template_names.contains(&"common/header.tera"));
+            assert!(template_names.contains(&"common/enums.ts.tera"));
+            assert!(template_names.contains(&"common/tagged_enum_helpers.tera"));
+            assert!(template_names.contains(&"common/notify.tera"));
+            assert!(template_names.contains(&"common/raw_response.tera"));
+            assert!(template_names.contains(&"common/call_queue.tera"));
+            assert!(template_names.contains(&"common/call_cache.tera"));
+            assert!(template_names.contains(&"common/react_query.ts.tera"));
+            assert!(template_names.contains(&"common/testing.ts.tera"));
+            assert!(template_names.contains(&"common/mocks.ts.tera"));
+            assert!(template_names.contains(&"common/vue.ts.tera"));
+            assert!(template_names.contains(&"common/svelte.ts.tera"));
+            assert!(template_names.contains(&"common/pinia.ts.tera"));
+            assert!(template_names.contains(&"common/stores.ts.tera"));
+            assert!(template_names.contains(&"common/compact.ts.tera"));
+            assert!(template_names.contains(&"common/compact.rs.tera"));
+            assert!(template_names.contains(&"common/self_test.ts.tera"));
+            assert!(template_names.contains(&"common/self_test.rs.tera"));
+            assert!(template_names.contains(&"common/call_schedule.tera"));
+            assert!(template_names.contains(&"common/notify_command.tera"));
+            assert!(template_names.contains(&"common/progress.tera"));
+            assert!(template_names.contains(&"common/progress_command.tera"));
+            assert!(template_names.contains(&"common/stream.tera"));
+            assert!(template_names.contains(&"common/stream_command.tera"));
+            assert!(template_names.contains(&"common/globals.d.ts.tera"));
+            assert!(template_names.contains(&"common/constants.ts.tera"));
+            assert!(template_names.contains(&"common/command_doc.tera"));
+            assert!(template_names.contains(&"common/actors.ts.tera"));
+            assert!(template_names.contains(&"common/runtime.ts.tera"));
+            assert!(template_names.contains(&"common/arbitraries.ts.tera"));
+            assert!(template_names.contains(&"common/binary.ts.tera"));
+            assert!(template_names.contains(&"common/offline.ts.tera"));
+            assert!(template_names.contains(&"common/query_keys.ts.tera"));
+            assert!(template_names.contains(&"common/errors.ts.tera"));
+            assert!(template_names.contains(&"common/event_router.tera"));
+            assert!(template_names.contains(&"common/sanitize.ts.tera"));
+            assert!(template_names.contains(&"common/command_alias.tera"));
+            assert!(template_names.contains(&"common/command_hooks.tera"));
+            assert!(template_names.contains(&"common/commands_shared.ts.tera"));
+            assert!(template_names.contains(&"common/commands_index.ts.tera"));
+            assert!(template_names.contains(&"common/http_bridge.rs.tera"));
+            assert!(template_names.contains(&"common/admin_panel.tsx.tera"));
+            assert!(template_names.contains(&"common/admin_result_view.tsx.tera"));
+            assert!(template_names.contains(&"common/admin_index.tsx.tera"));
         }
 
         #[test]
         fn test_template_count() {
             let tera = TypeScriptTemplate::create_tera().unwrap();
-            let count = tera.get_template_names().count();
-            // Should have at least 10 templates (4 main + 5 partials + 1 common)
-            assert!(count >= 10);
+            let mut names: Vec<&str> = tera
+                .get_template_names()
+                .filter(|name| name.starts_with("typescript/"))
+                .collect();
+            names.sort();
+            // 4 main templates and 9 partials, besides the common templates
+            assert_eq!(
+                names,
+                vec![
+                    "typescript/commands.ts.tera",
+                    "typescript/events.ts.tera",
+                    "typescript/index.ts.tera",
+                    "typescript/partials/command_function.ts.tera",
+                    "typescript/partials/enum.tera",
+                    "typescript/partials/event_listener.ts.tera",
+                    "typescript/partials/external_enum.tera",
+                    "typescript/partials/interface.tera",
+                    "typescript/partials/newtype.tera",
+                    "typescript/partials/param_interface.ts.tera",
+                    "typescript/partials/tagged_enum.tera",
+                    "typescript/partials/untagged_enum.tera",
+                    "typescript/types.ts.tera",
+                ]
+            );
+            let common = tera
+                .get_template_names()
+                .filter(|name| name.starts_with("common/"))
+                .count();
+            assert_eq!(tera.get_template_names().count(), names.len() + common);
         }
 
         #[test]
@@ -0,0 +1,817 @@
+use crate::analysis::type_resolver::JSON_VALUE_TYPE;
+use crate::analysis::CommandAnalyzer;
+use crate::generators::base::casing::property_key;
+use crate::generators::base::examples::ExampleBuilder;
+use crate::generators::base::file_writer::FileWriter;
+use crate::generators::base::template_context::{
+    CommandContext, EventContext, FieldContext, StructContext,
+};
+use crate::generators::base::templates::TemplateRegistry;
+use crate::generators::base::testing::ValueSchema;
+use crate::generators::base::type_visitor::TypeVisitor;
+use crate::generators::base::validation_messages::ValidationMessages;
+use crate::generators::base::{BaseBindingsGenerator, TypeScriptFiles};
+use crate::generators::valibot::schema_builder::ValibotSchemaBuilder;
+use crate::generators::valibot::templates::ValibotTemplate;
+use crate::generators::valibot::type_visitor::ValibotVisitor;
+use crate::generators::TypeCollector;
+use crate::models::{CommandInfo, EventInfo, FieldInfo, StructInfo, TypeStructure};
+use crate::{GenerateConfig, OutputFileNames};
+use std::collections::{HashMap, HashSet};
+use tera::{Context, Tera};
+
+/// Generator for Valibot schema-based TypeScript bindings with validation
+pub struct ValibotBindingsGenerator {
+    collector: TypeCollector,
+    tera: Tera,
+    /// Types whose schema is emitted in types.ts, set per generation run
+    known_schemas: Option<HashSet<String>>,
+    /// Configured validation message templates, set per generation run
+    validation_messages: Option<ValidationMessages>,
+}
+
+impl ValibotBindingsGenerator {
+    pub fn new() -> Self {
+        Self {
+            collector: TypeCollector::new(),
+            tera: ValibotTemplate::create_tera()
+                .expect("Failed to initialize Valibot template engine"),
+            known_schemas: None,
+            validation_messages: None,
+        }
+    }
+
+    /// Valibot visitor referencing only schemas emitted in this run
+    fn visitor<'a>(&'a self, config: &'a GenerateConfig) -> ValibotVisitor<'a> {
+        ValibotVisitor::with_config(config).with_known_schemas(self.known_schemas.as_ref())
+    }
+
+    /// Schema builder referencing only schemas emitted in this run
+    fn schema_builder<'a>(&'a self, config: &'a GenerateConfig) -> ValibotSchemaBuilder<'a> {
+        ValibotSchemaBuilder::new(config)
+            .with_known_schemas(self.known_schemas.as_ref())
+            .with_messages(self.validation_messages.as_ref())
+    }
+
+    /// Generate Valibot schema for a struct
+    fn generate_struct_schema(
+        &self,
+        name: &str,
+        struct_info: &StructInfo,
+        config: &GenerateConfig,
+    ) -> String {
+        if struct_info.is_adjacently_tagged() || struct_info.is_internally_tagged() {
+            self.generate_tagged_enum_schema(name, struct_info, config)
+        } else if struct_info.is_untagged() {
+            self.generate_untagged_enum_schema(name, struct_info, config)
+        } else if struct_info.is_externally_tagged() && struct_info.has_variant_data() {
+            self.generate_external_enum_schema(name, struct_info, config)
+        } else if struct_info.is_enum {
+            self.generate_enum_schema(name, struct_info, config)
+        } else if struct_info.is_newtype {
+            self.generate_newtype_schema(name, struct_info, config)
+        } else {
+            self.generate_object_schema(name, struct_info, config)
+        }
+    }
+
+    /// Generate Valibot schema for an enum
+    fn generate_enum_schema(
+        &self,
+        name: &str,
+        struct_info: &StructInfo,
+        config: &GenerateConfig,
+    ) -> String {
+        let visitor = self.visitor(config);
+
+        // Convert fields to context to get serialized names
+        let field_contexts: Vec<FieldContext> =
+            self.collector
+                .create_field_contexts(struct_info, &visitor, config);
+
+        let variants: Vec<String> = field_contexts
+            .iter()
+            .map(|field| format!("\"{}\"", field.serialized_name))
+            .collect();
+
+        let enum_values = variants.join(", ");
+        format!(
+            "export const {0}Schema = /* @__PURE__ */ (() => v.picklist([{1}]))();\n\nexport type {0} = v.InferOutput<typeof {0}Schema>;\n\n",
+            name, enum_values
+        )
+    }
+
+    /// Generate a discriminated union schema plus constructor/match helpers
+    /// for an adjacently or internally tagged enum
+    fn generate_tagged_enum_schema(
+        &self,
+        name: &str,
+        struct_info: &StructInfo,
+        config: &GenerateConfig,
+    ) -> String {
+        let visitor = self.visitor(config);
+        let struct_context =
+            StructContext::new(config).from_struct_info(name, struct_info, &visitor);
+        let Some(tagged) = struct_context.tagged_enum else {
+            return self.generate_enum_schema(name, struct_info, config);
+        };
+
+        // Pair each variant's tag value with the valibot schema of its content, or with
+        // the entries next to the tag when internally tagged
+        let variants: Vec<serde_json::Value> = struct_info
+            .fields
+            .iter()
+            .zip(&tagged.variants)
+            .map(|(variant, variant_context)| {
+                serde_json::json!({
+                    "serializedName": variant_context.serialized_name,
+                    "schema": self.build_variant_schema(variant, config),
+                    "entries": self.build_variant_entries(variant, config),
+                })
+            })
+            .collect();
+
+        let mut context = Context::new();
+        context.insert("name", name);
+        context.insert("tagged", &tagged);
+        context.insert("variants", &variants);
+
+        self.render("valibot/partials/tagged_enum_schema.ts.tera", &context)
+            .unwrap_or_else(|e| {
+                eprintln!("Template rendering failed for {}: {}", name, e);
+                format!("// Error generating schema for {}: {}\n", name, e)
+            })
+    }
+
+    /// Generate Valibot schema for an untagged enum as a union of its variant payloads
+    fn generate_untagged_enum_schema(
+        &self,
+        name: &str,
+        struct_info: &StructInfo,
+        config: &GenerateConfig,
+    ) -> String {
+        let visitor = self.visitor(config);
+        let mut members: Vec<String> = Vec::new();
+        for variant in &struct_info.fields {
+            // Serde tries variants in order, so payloads must not be coerced
+            let member = match variant.rust_type.as_str() {
+                "enum_variant_tuple" => visitor.visit_type(&variant.type_structure),
+                _ => self
+                    .build_variant_schema(variant, config)
+                    .unwrap_or_else(|| "v.null()".to_string()),
+            };
+            if !members.contains(&member) {
+                members.push(member);
+            }
+        }
+
+        let schema = match members.as_slice() {
+            [single] => single.clone(),
+            _ => format!("v.union([{}])", members.join(", ")),
+        };
+
+        let mut context = Context::new();
+        context.insert("name", name);
+        context.insert("schema", &schema);
+
+        self.render("valibot/partials/newtype_schema.ts.tera", &context)
+            .unwrap_or_else(|e| {
+                eprintln!("Template rendering failed for {}: {}", name, e);
+                format!("// Error generating schema for {}: {}\n", name, e)
+            })
+    }
+
+    /// Generate Valibot schema for an externally tagged enum with data as a union of its
+    /// unit variant names and `{ Variant: payload }` objects
+    fn generate_external_enum_schema(
+        &self,
+        name: &str,
+        struct_info: &StructInfo,
+        config: &GenerateConfig,
+    ) -> String {
+        let visitor = self.visitor(config);
+        let field_contexts = self
+            .collector
+            .create_field_contexts(struct_info, &visitor, config);
+        let members: Vec<String> = struct_info
+            .fields
+            .iter()
+            .zip(&field_contexts)
+            .map(|(variant, field_context)| {
+                let serialized_name = &field_context.serialized_name;
+                match self.build_variant_schema(variant, config) {
+                    Some(schema) => format!(
+                        "v.object({{ {}: {} }})",
+                        property_key(serialized_name),
+                        schema
+                    ),
+                    None => format!("v.literal(\"{}\")", serialized_name),
+                }
+            })
+            .collect();
+
+        let mut context = Context::new();
+        context.insert("name", name);
+        context.insert("schema", &format!("v.union([{}])", members.join(", ")));
+
+        self.render("valibot/partials/newtype_schema.ts.tera", &context)
+            .unwrap_or_else(|e| {
+                eprintln!("Template rendering failed for {}: {}", name, e);
+                format!("// Error generating schema for {}: {}\n", name, e)
+            })
+    }
+
+    /// Build the Valibot schema of an enum variant's payload, `None` for unit variants
+    fn build_variant_schema(&self, variant: &FieldInfo, config: &GenerateConfig) -> Option<String> {
+        let schema_builder = self.schema_builder(config);
+
+        match variant.rust_type.as_str() {
+            "enum_variant_tuple" => {
+                Some(schema_builder.build_schema(&variant.type_structure, &None))
+            }
+            "enum_variant_struct" => Some(format!(
+                "v.object({{ {} }})",
+                self.build_variant_fields(variant, config).join(", ")
+            )),
+            _ => None,
+        }
+    }
+
+    /// Build the object entries of an internally tagged variant's payload, which serde
+    /// writes next to the tag: the fields of a struct variant, or those of the struct a
+    /// newtype variant wraps. `None` for unit variants and other payloads
+    fn build_variant_entries(
+        &self,
+        variant: &FieldInfo,
+        config: &GenerateConfig,
+    ) -> Option<String> {
+        match (variant.rust_type.as_str(), &variant.type_structure) {
+            ("enum_variant_struct", _) => {
+                Some(self.build_variant_fields(variant, config).join(", "))
+            }
+            ("enum_variant_tuple", TypeStructure::Custom(name))
+                if self.collector.is_object_struct(name) =>
+            {
+                Some(format!("...{}Schema.entries", name))
+            }
+            _ => None,
+        }
+    }
+
+    /// Build the `key: schema` entries of a struct variant's fields
+    fn build_variant_fields(&self, variant: &FieldInfo, config: &GenerateConfig) -> Vec<String> {
+        let visitor = self.visitor(config);
+        let schema_builder = self.schema_builder(config);
+
+        variant
+            .variant_fields
+            .iter()
+            .map(|field| {
+                let field_context =
+                    FieldContext::new(config).from_field_info(field, &None, &visitor);
+                format!(
+                    "{}: {}",
+                    property_key(&field_context.serialized_name),
+                    schema_builder.build_schema(&field.type_structure, &field.validator_attributes)
+                )
+            })
+            .collect()
+    }
+
+    /// Generate Valibot schema for a collection newtype, aliasing the wrapped type
+    fn generate_newtype_schema(
+        &self,
+        name: &str,
+        struct_info: &StructInfo,
+        config: &GenerateConfig,
+    ) -> String {
+        let schema_builder = self.schema_builder(config);
+        let schema = struct_info
+            .fields
+            .first()
+            .map(|field| schema_builder.build_schema(&field.type_structure, &None))
+            .unwrap_or_else(|| "v.unknown()".to_string());
+
+        let mut context = Context::new();
+        context.insert("name", name);
+        context.insert("schema", &schema);
+
+        self.render("valibot/partials/newtype_schema.ts.tera", &context)
+            .unwrap_or_else(|e| {
+                eprintln!("Template rendering failed for {}: {}", name, e);
+                format!("// Error generating schema for {}: {}\n", name, e)
+            })
+    }
+
+    /// Build the schemas of `#[serde(flatten)]` fields: spreads of the entries of
+    /// flattened structs (partial when optional), the rest schema of a flattened map
+    /// and schemas of other types to intersect the object with
+    fn build_flattened_schemas(
+        &self,
+        struct_info: &StructInfo,
+        config: &GenerateConfig,
+    ) -> (Vec<String>, Option<String>, Vec<String>) {
+        let schema_builder = self.schema_builder(config);
+        let mut spreads = Vec::new();
+        let mut rest = None;
+        let mut intersections = Vec::new();
+
+        for field in struct_info.fields.iter().filter(|field| field.flatten) {
+            let (structure, optional) = match &field.type_structure {
+                TypeStructure::Optional(inner) => (inner.as_ref(), true),
+                structure => (structure, false),
+            };
+            match structure {
+                TypeStructure::Custom(name) if self.collector.is_object_struct(name) => spreads
+                    .push(if optional {
+                        format!("...v.partial({}Schema).entries", name)
+                    } else {
+                        format!("...{}Schema.entries", name)
+                    }),
+                TypeStructure::Map { value, .. } if rest.is_none() => {
+                    rest = Some(schema_builder.build_schema(
+                        &TypeCollector::flattened_map_value(struct_info, value),
+                        &None,
+                    ))
+                }
+                structure => intersections.push(schema_builder.build_schema(structure, &None)),
+            }
+        }
+
+        (spreads, rest, intersections)
+    }
+
+    /// Generate Valibot schema for an object/struct using templates
+    fn generate_object_schema(
+        &self,
+        name: &str,
+        struct_info: &StructInfo,
+        config: &GenerateConfig,
+    ) -> String {
+        let visitor = self.visitor(config);
+
+        // Convert FieldInfo to FieldContext with computed Valibot schemas
+        let mut field_contexts: Vec<FieldContext> =
+            self.collector
+                .create_field_contexts(struct_info, &visitor, config);
+
+        // Enrich with complete valibot schemas including validators
+        for field_context in &mut field_contexts {
+            field_context.typescript_type = self.build_field_schema(field_context, config);
+        }
+
+        let (spreads, rest, intersections) = self.build_flattened_schemas(struct_info, config);
+
+        let mut context = Context::new();
+        context.insert("name", name);
+        context.insert("fields", &field_contexts);
+        context.insert("spreads", &spreads);
+        context.insert("rest", &rest);
+        context.insert("intersections", &intersections);
+
+        self.render("valibot/partials/schema.ts.tera", &context)
+            .unwrap_or_else(|e| {
+                eprintln!("Template rendering failed for {}: {}", name, e);
+                format!("// Error generating schema for {}: {}\n", name, e)
+            })
+    }
+
+    /// Build the complete Valibot schema of a struct field, including validators
+    fn build_field_schema(&self, field_context: &FieldContext, config: &GenerateConfig) -> String {
+        let schema_builder = self.schema_builder(config);
+        let schema = if let Some(ref literal) = field_context.literal {
+            schema_builder.build_string_literal_schema(&field_context.type_structure, literal)
+        } else if let Some(ref literals) = field_context.range_literals {
+            schema_builder.build_literal_schema(&field_context.type_structure, literals)
+        } else if field_context.is_path && config.should_require_non_empty_paths() {
+            schema_builder.build_path_schema(
+                &field_context.type_structure,
+                &field_context.validator_attributes,
+            )
+        } else {
+            schema_builder.build_schema(
+                &field_context.type_structure,
+                &field_context.validator_attributes,
+            )
+        };
+
+        // Keys serde fills in or leaves out are optional, like `Option` fields
+        if field_context.omittable
+            && !matches!(field_context.type_structure, TypeStructure::Optional(_))
+        {
+            format!("v.optional({})", schema)
+        } else {
+            schema
+        }
+    }
+
+    /// Generate schemaRegistry.ts mapping type and command names to their schemas
+    fn generate_schema_registry_file(
+        &self,
+        commands: &[CommandInfo],
+        used_structs: &HashMap<String, StructInfo>,
+        analyzer: &CommandAnalyzer,
+        config: &GenerateConfig,
+    ) -> String {
+        let mut type_names: Vec<&String> = used_structs.keys().collect();
+        type_names.sort();
+
+        // Only commands with parameters have a params schema; the first command
+        // wins when several share a name
+        let visitor = self.visitor(config);
+        let mut seen = HashSet::new();
+        let command_contexts: Vec<_> = self
+            .collector
+            .create_command_contexts(commands, &visitor, analyzer, config)
+            .into_iter()
+            .filter(|cmd| !cmd.parameters.is_empty() && seen.insert(cmd.name.clone()))
+            .collect();
+
+        let mut context = Context::new();
+        context.insert("header", &self.generate_file_header());
+        context.insert("type_names", &type_names);
+        context.insert("commands", &command_contexts);
+        context.insert(
+            "types_module",
+            &OutputFileNames::module_path(&config.file_names().types),
+        );
+
+        self.render("valibot/schema_registry.ts.tera", &context)
+            .unwrap_or_else(|e| {
+                eprintln!("Template rendering failed for schemaRegistry.ts: {}", e);
+                String::new()
+            })
+    }
+
+    /// Generate forms.ts with a react-hook-form hook per command taking parameters
+    fn generate_forms_file(
+        &self,
+        commands: &[CommandInfo],
+        analyzer: &CommandAnalyzer,
+        config: &GenerateConfig,
+    ) -> String {
+        // Channels cannot be form values, so commands taking them are left out
+        let visitor = self.visitor(config);
+        let command_contexts: Vec<_> = self
+            .collector
+            .create_command_contexts(commands, &visitor, analyzer, config)
+            .into_iter()
+            .filter(|cmd| !cmd.parameters.is_empty() && cmd.channels.is_empty())
+            .collect();
+
+        let values = ExampleBuilder::empty(analyzer.get_discovered_structs(), config);
+        let defaults: HashMap<&str, String> = command_contexts
+            .iter()
+            .map(|cmd| (cmd.ts_function_name.as_str(), values.parameters(cmd)))
+            .collect();
+
+        let file_names = config.file_names();
+        let mut context = Context::new();
+        context.insert("header", &self.generate_file_header());
+        context.insert("commands", &command_contexts);
+        context.insert("defaults", &defaults);
+        context.insert(
+            "commands_module",
+            &OutputFileNames::module_path(&file_names.commands),
+        );
+        context.insert(
+            "types_module",
+            &OutputFileNames::module_path(&file_names.types),
+        );
+
+        self.render("valibot/forms.ts.tera", &context)
+            .unwrap_or_else(|e| {
+                eprintln!("Template rendering failed for forms.ts: {}", e);
+                String::new()
+            })
+    }
+}
+
+impl BaseBindingsGenerator for ValibotBindingsGenerator {
+    fn tera(&self) -> &Tera {
+        &self.tera
+    }
+
+    fn type_collector(&self) -> &TypeCollector {
+        &self.collector
+    }
+
+    fn generator_type(&self) -> String {
+        "valibot".to_string()
+    }
+
+    fn type_schema(
+        &self,
+        structure: &TypeStructure,
+        config: &GenerateConfig,
+    ) -> Option<ValueSchema> {
+        Some(ValueSchema::new(
+            self.visitor(config).visit_type(structure),
+            structure,
+            self.known_schemas.as_ref(),
+            config,
+        ))
+    }
+
+    fn field_schema(&self, field: &FieldContext, config: &GenerateConfig) -> Option<ValueSchema> {
+        Some(ValueSchema::new(
+            self.build_field_schema(field, config),
+            &field.type_structure,
+            self.known_schemas.as_ref(),
+            config,
+        ))
+    }
+
+    fn write_models(
+        &mut self,
+        file_writer: &mut FileWriter,
+        commands: &[CommandInfo],
+        discovered_structs: &HashMap<String, StructInfo>,
+        analyzer: &CommandAnalyzer,
+        config: &GenerateConfig,
+    ) -> Result<Vec<String>, Box<dyn std::error::Error>> {
+        self.write_typescript_models(file_writer, commands, discovered_structs, analyzer, config)
+    }
+}
+
+impl TypeScriptFiles for ValibotBindingsGenerator {
+    fn template_dir(&self) -> &'static str {
+        "valibot"
+    }
+
+    fn type_collector_mut(&mut self) -> &mut TypeCollector {
+        &mut self.collector
+    }
+
+    fn command_contexts(
+        &self,
+        commands: &[CommandInfo],
+        analyzer: &CommandAnalyzer,
+        config: &GenerateConfig,
+    ) -> Vec<CommandContext> {
+        let mut command_contexts = self.collector.create_command_contexts(
+            commands,
+            &self.visitor(config),
+            analyzer,
+            config,
+        );
+        for command_context in &mut command_contexts {
+            command_context.validate = config.validates_command(&command_context.name);
+        }
+        command_contexts
+    }
+
+    fn event_contexts(
+        &self,
+        events: &[EventInfo],
+        analyzer: &CommandAnalyzer,
+        config: &GenerateConfig,
+    ) -> Vec<EventContext> {
+        self.collector
+            .create_event_contexts(events, &self.visitor(config), analyzer, config)
+    }
+
+    fn struct_contexts(
+        &self,
+        used_structs: &HashMap<String, StructInfo>,
+        config: &GenerateConfig,
+    ) -> Vec<StructContext> {
+        self.collector
+            .create_struct_contexts(used_structs, &self.visitor(config), config)
+    }
+
+    /// Generate the complete types.ts file content (with embedded schemas)
+    fn generate_types_file_content(
+        &self,
+        commands: &[CommandInfo],
+        used_structs: &HashMap<String, StructInfo>,
+        analyzer: &CommandAnalyzer,
+        config: &GenerateConfig,
+    ) -> String {
+        // Types of shared crates are imported from their package instead of defined
+        let (shared_imports, local_structs) =
+            self.collector.split_shared_types(used_structs, config);
+        let used_structs = &local_structs;
+
+        // Sort structs topologically
+        let type_names: HashSet<String> = used_structs.keys().cloned().collect();
+        let sorted_types = analyzer.topological_sort_types(&type_names);
+
+        // Generate struct schemas
+        let mut struct_schemas = String::new();
+        for name in &sorted_types {
+            if let Some(struct_info) = used_structs.get(name) {
+                struct_schemas.push_str(&self.generate_struct_schema(name, struct_info, config));
+            }
+        }
+
+        // Convert commands to context wrappers
+        let visitor = self.visitor(config);
+        let schema_builder = self.schema_builder(config);
+        let mut command_contexts = self
+            .collector
+            .create_command_contexts(commands, &visitor, analyzer, config);
+
+        // Enrich parameters with complete valibot schemas
+        for command_context in &mut command_contexts {
+            for param in &mut command_context.parameters {
+                let valibot_schema = if param.is_path && config.should_require_non_empty_paths() {
+                    schema_builder.build_path_schema(&param.type_structure, &None)
+                } else {
+                    schema_builder.build_param_schema(&param.type_structure)
+                };
+                param.typescript_type = valibot_schema;
+            }
+        }
+
+        // Generate parameter schemas using template
+        let param_schemas = {
+            let mut context = Context::new();
+            context.insert("commands", &command_contexts);
+            self.render("valibot/partials/param_schemas.ts.tera", &context)
+                .unwrap_or_else(|e| {
+                    eprintln!("Template rendering failed for param schemas: {}", e);
+                    String::new()
+                })
+        };
+
+        // Generate type aliases using template
+        let type_aliases = {
+            let mut context = Context::new();
+            context.insert("commands", &command_contexts);
+            context.insert("struct_names", &sorted_types);
+            self.render("valibot/partials/type_aliases.ts.tera", &context)
+                .unwrap_or_else(|e| {
+                    eprintln!("Template rendering failed for type aliases: {}", e);
+                    String::new()
+                })
+        };
+
+        // Render main types.ts template
+        let mut context = Context::new();
+        context.insert("header", &self.generate_file_header());
+        context.insert(
+            "has_channels",
+            &commands.iter().any(|cmd| !cmd.channels.is_empty()),
+        );
+        context.insert(
+            "uses_json_value",
+            &self.collector.uses_json_value(commands, used_structs),
+        );
+        context.insert(
+            "type_imports",
+            &self.collector.field_override_imports(used_structs, config),
+        );
+        context.insert("shared_imports", &shared_imports);
+        context.insert("struct_schemas", &struct_schemas);
+        context.insert("param_schemas", &param_schemas);
+        context.insert("type_aliases", &type_aliases);
+
+        self.render("valibot/types.ts.tera", &context)
+            .unwrap_or_else(|e| {
+                eprintln!("Template rendering failed for types.ts: {}", e);
+                String::new()
+            })
+    }
+
+    fn prepare_schemas(
+        &mut self,
+        commands: &[CommandInfo],
+        used_structs: &HashMap<String, StructInfo>,
+        config: &GenerateConfig,
+    ) -> Result<(), Box<dyn std::error::Error>> {
+        // Struct and parameter schemas reference these instead of inlining definitions
+        let mut known_schemas: HashSet<String> = used_structs.keys().cloned().collect();
+        if self.collector.uses_json_value(commands, used_structs) {
+            known_schemas.insert(JSON_VALUE_TYPE.to_string());
+        }
+        self.known_schemas = Some(known_schemas);
+        self.validation_messages = Some(config.validation_messages()?);
+        Ok(())
+    }
+
+    fn write_schema_files(
+        &self,
+        file_writer: &mut FileWriter,
+        commands: &[CommandInfo],
+        used_structs: &HashMap<String, StructInfo>,
+        analyzer: &CommandAnalyzer,
+        config: &GenerateConfig,
+    ) -> Result<(), Box<dyn std::error::Error>> {
+        let file_names = config.file_names();
+
+        // Generate and write schema registry if enabled
+        if config.should_generate_schema_registry() {
+            let registry_content =
+                self.generate_schema_registry_file(commands, used_structs, analyzer, config);
+            file_writer.write_typescript_file(&file_names.schema_registry, &registry_content)?;
+        }
+
+        // Generate and write react-hook-form hooks if enabled
+        if config.should_generate_form_hooks() {
+            let forms_content = self.generate_forms_file(commands, analyzer, config);
+            file_writer.write_typescript_file(&file_names.forms, &forms_content)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl Default for ValibotBindingsGenerator {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::TypeStructure;
+
+    fn field(name: &str, rust_type: &str, type_structure: TypeStructure) -> FieldInfo {
+        FieldInfo {
+            name: name.to_string(),
+            rust_type: rust_type.to_string(),
+            is_optional: false,
+            is_public: true,
+            type_structure,
+            serde_rename: None,
+            validator_attributes: None,
+            variant_fields: Vec::new(),
+            literal: None,
+            flatten: false,
+            omittable: false,
+            defaulted: false,
+            skip_serializing_if: false,
+            skipped: None,
+            serde_with: None,
+        }
+    }
+
+    fn struct_info(fields: Vec<FieldInfo>, is_enum: bool) -> StructInfo {
+        StructInfo {
+            name: "Test".to_string(),
+            fields,
+            file_path: "test.rs".to_string(),
+            is_enum,
+            serde_rename_all: None,
+            is_newtype: false,
+            serde_tag: None,
+            serde_content: None,
+            serde_untagged: false,
+            line_number: 0,
+            is_progress: false,
+            serde_deny_unknown_fields: false,
+        }
+    }
+
+    #[test]
+    fn test_generator_type_returns_valibot() {
+        let gen = ValibotBindingsGenerator::new();
+        assert_eq!(gen.generator_type(), "valibot");
+        assert!(gen.has_schemas());
+        assert!(gen.generate_file_header().contains("valibot"));
+    }
+
+    #[test]
+    fn test_generate_struct_schemas() {
+        let gen = ValibotBindingsGenerator::new();
+        let config = GenerateConfig {
+            validation_library: "valibot".to_string(),
+            ..Default::default()
+        };
+
+        let user = struct_info(
+            vec![
+                field(
+                    "user_name",
+                    "String",
+                    TypeStructure::Primitive("string".to_string()),
+                ),
+                field(
+                    "age",
+                    "Option<u32>",
+                    TypeStructure::Optional(Box::new(TypeStructure::Primitive(
+                        "number".to_string(),
+                    ))),
+                ),
+            ],
+            false,
+        );
+        let result = gen.generate_struct_schema("User", &user, &config);
+        assert!(result.contains("export const UserSchema = /* @__PURE__ */ (() => v.object({"));
+        assert!(result.contains("user_name: v.string(),"));
+        assert!(result.contains("age: v.optional(v.number()),"));
+        assert!(result.contains("export type User = v.InferOutput<typeof UserSchema>;"));
+
+        let role = struct_info(
+            vec![
+                field("Admin", "enum_variant", TypeStructure::default()),
+                field("Member", "enum_variant", TypeStructure::default()),
+            ],
+            true,
+        );
+        let result = gen.generate_struct_schema("Role", &role, &config);
+        assert!(result.contains("v.picklist([\"Admin\", \"Member\"])"));
+    }
+}
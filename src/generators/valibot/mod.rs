@@ -0,0 +1,4 @@
+pub mod generator;
+pub mod schema_builder;
+pub mod templates;
+pub mod type_visitor;
@@ -0,0 +1,333 @@
+use crate::generators::base::range_literals::RangeLiterals;
+use crate::generators::base::templates::escape_js;
+use crate::generators::base::type_visitor::{string_literal, TypeVisitor};
+use crate::generators::base::validation_messages::ValidationMessages;
+use crate::generators::valibot::type_visitor::{record_key, ValibotVisitor};
+use crate::models::{LengthConstraint, TypeStructure, ValidatorAttributes};
+use crate::GenerateConfig;
+use std::collections::HashSet;
+
+/// Builds complete Valibot schemas, piping validators into validation actions
+pub struct ValibotSchemaBuilder<'a> {
+    visitor: ValibotVisitor<'a>,
+    messages: Option<&'a ValidationMessages>,
+}
+
+impl<'a> ValibotSchemaBuilder<'a> {
+    pub fn new(config: &'a GenerateConfig) -> Self {
+        Self {
+            visitor: ValibotVisitor::with_config(config),
+            messages: None,
+        }
+    }
+
+    /// Use configured message templates for validators without an explicit message
+    pub fn with_messages(mut self, messages: Option<&'a ValidationMessages>) -> Self {
+        self.messages = messages;
+        self
+    }
+
+    /// Only reference schemas of the given types, see [`ValibotVisitor::with_known_schemas`]
+    pub fn with_known_schemas(mut self, known_schemas: Option<&'a HashSet<String>>) -> Self {
+        self.visitor = self.visitor.with_known_schemas(known_schemas);
+        self
+    }
+
+    /// Build a complete Valibot schema string for a field, including validators
+    pub fn build_schema(
+        &self,
+        type_structure: &TypeStructure,
+        validator_attributes: &Option<ValidatorAttributes>,
+    ) -> String {
+        self.render_type(type_structure, validator_attributes, false)
+    }
+
+    /// Build a Valibot schema for a parameter (no validators applied)
+    pub fn build_param_schema(&self, type_structure: &TypeStructure) -> String {
+        self.render_type(type_structure, &None, true)
+    }
+
+    /// Build a Valibot schema for a path field or parameter that rejects empty strings
+    ///
+    /// An explicit `length` validator takes precedence over the implicit `v.minLength(1)`.
+    pub fn build_path_schema(
+        &self,
+        type_structure: &TypeStructure,
+        validator_attributes: &Option<ValidatorAttributes>,
+    ) -> String {
+        let mut validator = validator_attributes.clone().unwrap_or_default();
+        if validator.length.is_none() {
+            validator.length = Some(LengthConstraint {
+                min: Some(1),
+                max: None,
+                message: None,
+            });
+        }
+        self.render_type(type_structure, &Some(validator), false)
+    }
+
+    /// Build a Valibot schema for an integer field limited to the given literals
+    pub fn build_literal_schema(
+        &self,
+        type_structure: &TypeStructure,
+        literals: &RangeLiterals,
+    ) -> String {
+        match type_structure {
+            TypeStructure::Optional(inner) => {
+                format!("v.optional({})", self.build_literal_schema(inner, literals))
+            }
+            _ => literals.valibot_schema(),
+        }
+    }
+
+    /// Build a Valibot schema for a field fixed to a string value
+    pub fn build_string_literal_schema(
+        &self,
+        type_structure: &TypeStructure,
+        literal: &str,
+    ) -> String {
+        match type_structure {
+            TypeStructure::Optional(inner) => {
+                format!(
+                    "v.optional({})",
+                    self.build_string_literal_schema(inner, literal)
+                )
+            }
+            _ => format!("v.literal({})", string_literal(literal)),
+        }
+    }
+
+    fn render_type(
+        &self,
+        ts: &TypeStructure,
+        validator: &Option<ValidatorAttributes>,
+        skip_validation: bool,
+    ) -> String {
+        match ts {
+            TypeStructure::Optional(inner) => {
+                format!("v.optional({})", self.render_type(inner, validator, false))
+            }
+            TypeStructure::Primitive(prim) => {
+                self.render_primitive(prim, validator, skip_validation)
+            }
+            TypeStructure::Array(inner) => {
+                let array_schema = format!("v.array({})", self.render_type(inner, validator, true));
+                let mut actions = Vec::new();
+                if !skip_validation {
+                    self.push_length_actions(&mut actions, validator);
+                }
+                pipe(array_schema, actions)
+            }
+            TypeStructure::Map { key, value } => format!(
+                "v.record({}, {})",
+                record_key(self.render_type(key, validator, true)),
+                self.render_type(value, validator, true)
+            ),
+            TypeStructure::Set(inner) => {
+                format!("v.set({})", self.render_type(inner, validator, true))
+            }
+            TypeStructure::Tuple(types) => {
+                if types.is_empty() {
+                    "v.void()".to_string()
+                } else {
+                    let type_strs: Vec<String> = types
+                        .iter()
+                        .map(|t| self.render_type(t, validator, true))
+                        .collect();
+                    format!("v.tuple([{}])", type_strs.join(", "))
+                }
+            }
+            TypeStructure::Result(inner) => format!(
+                "v.union([{}, v.object({{ error: v.string() }})])",
+                self.render_type(inner, validator, true)
+            ),
+            TypeStructure::Union(types) => {
+                let type_strs: Vec<String> = types
+                    .iter()
+                    .map(|t| self.render_type(t, validator, true))
+                    .collect();
+                format!("v.union([{}])", type_strs.join(", "))
+            }
+            TypeStructure::Custom(_) => {
+                // Use visitor for custom types (handles type mappings)
+                self.visitor.visit_type(ts)
+            }
+        }
+    }
+
+    fn render_primitive(
+        &self,
+        type_name: &str,
+        validator: &Option<ValidatorAttributes>,
+        skip_validation: bool,
+    ) -> String {
+        let validator = validator.as_ref().filter(|_| !skip_validation);
+        let no_messages = ValidationMessages::default();
+        let messages = self.messages.unwrap_or(&no_messages);
+        let mut actions = Vec::new();
+
+        let schema = match type_name {
+            "string" => {
+                if let Some(val) = validator {
+                    if val.email {
+                        actions.push(format!("v.email({})", self.format_message("email")));
+                    }
+                    if val.url {
+                        actions.push(format!("v.url({})", self.format_message("url")));
+                    }
+                }
+                self.push_length_actions(&mut actions, &validator.cloned());
+                "v.string()"
+            }
+            "number" => {
+                if let Some(range) = validator.and_then(|val| val.range.as_ref()) {
+                    if let Some(min) = range.min {
+                        let message = messages.min(&range.message, range.min, range.max);
+                        actions.push(action("minValue", min, &message));
+                    }
+                    if let Some(max) = range.max {
+                        let message = messages.max(&range.message, range.min, range.max);
+                        actions.push(action("maxValue", max, &message));
+                    }
+                }
+                "v.number()"
+            }
+            "boolean" => "v.boolean()",
+            "void" => "v.void()",
+            "unknown" => "v.unknown()",
+            _ => return format!("v.unknown() /* Unknown primitive: {} */", type_name),
+        };
+        pipe(schema.to_string(), actions)
+    }
+
+    fn push_length_actions(
+        &self,
+        actions: &mut Vec<String>,
+        validator: &Option<ValidatorAttributes>,
+    ) {
+        let Some(length) = validator.as_ref().and_then(|val| val.length.as_ref()) else {
+            return;
+        };
+
+        let no_messages = ValidationMessages::default();
+        let messages = self.messages.unwrap_or(&no_messages);
+        if let Some(min) = length.min {
+            let message = messages.min_length(&length.message, length.min, length.max);
+            actions.push(action("minLength", min, &message));
+        }
+        if let Some(max) = length.max {
+            let message = messages.max_length(&length.message, length.min, length.max);
+            actions.push(action("maxLength", max, &message));
+        }
+    }
+
+    /// Message argument of a format action (`v.email(...)`), empty without a template
+    fn format_message(&self, key: &str) -> String {
+        self.messages
+            .and_then(|messages| messages.format(key))
+            .map(|message| format!("\"{}\"", escape_js(&message)))
+            .unwrap_or_default()
+    }
+}
+
+/// A `v.minValue(...)`-style action with an optional message
+fn action(name: &str, value: impl std::fmt::Display, message: &Option<String>) -> String {
+    match message {
+        Some(message) => format!("v.{}({}, \"{}\")", name, value, escape_js(message)),
+        None => format!("v.{}({})", name, value),
+    }
+}
+
+/// The schema piped into the actions, the bare schema without any
+fn pipe(schema: String, actions: Vec<String>) -> String {
+    if actions.is_empty() {
+        schema
+    } else {
+        format!("v.pipe({}, {})", schema, actions.join(", "))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::RangeConstraint;
+
+    fn string() -> TypeStructure {
+        TypeStructure::Primitive("string".to_string())
+    }
+
+    #[test]
+    fn test_build_schema_with_validators() {
+        let config = GenerateConfig::default();
+        let builder = ValibotSchemaBuilder::new(&config);
+
+        let validator = ValidatorAttributes {
+            email: true,
+            length: Some(LengthConstraint {
+                min: Some(3),
+                max: Some(50),
+                message: Some("3 to 50 characters".to_string()),
+            }),
+            ..Default::default()
+        };
+        assert_eq!(
+            builder.build_schema(
+                &TypeStructure::Optional(Box::new(string())),
+                &Some(validator)
+            ),
+            "v.optional(v.pipe(v.string(), v.email(), v.minLength(3, \"3 to 50 characters\"), \
+             v.maxLength(50, \"3 to 50 characters\")))"
+        );
+
+        let validator = ValidatorAttributes {
+            range: Some(RangeConstraint {
+                min: Some(1.0),
+                max: None,
+                message: None,
+            }),
+            ..Default::default()
+        };
+        let number = TypeStructure::Primitive("number".to_string());
+        assert_eq!(
+            builder.build_schema(&number, &Some(validator.clone())),
+            "v.pipe(v.number(), v.minValue(1))"
+        );
+        // Parameters are not validated
+        assert_eq!(builder.build_param_schema(&number), "v.number()");
+        assert_eq!(
+            builder.build_schema(
+                &TypeStructure::Array(Box::new(number.clone())),
+                &Some(validator)
+            ),
+            "v.array(v.number())"
+        );
+    }
+
+    #[test]
+    fn test_build_path_and_collection_schemas() {
+        let config = GenerateConfig::default();
+        let builder = ValibotSchemaBuilder::new(&config);
+
+        assert_eq!(
+            builder.build_path_schema(&string(), &None),
+            "v.pipe(v.string(), v.minLength(1))"
+        );
+        assert_eq!(
+            builder.build_schema(
+                &TypeStructure::Map {
+                    key: Box::new(TypeStructure::Primitive("number".to_string())),
+                    value: Box::new(TypeStructure::Set(Box::new(string()))),
+                },
+                &None
+            ),
+            "v.record(v.string(), v.set(v.string()))"
+        );
+        assert_eq!(
+            builder.build_schema(
+                &TypeStructure::Result(Box::new(TypeStructure::Custom("User".to_string()))),
+                &None
+            ),
+            "v.union([UserSchema, v.object({ error: v.string() })])"
+        );
+    }
+}
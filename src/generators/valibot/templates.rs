@@ -0,0 +1,85 @@
+use crate::{generators::base::templates::TemplateRegistry, template};
+use tera::Tera;
+
+pub struct ValibotTemplate;
+
+impl TemplateRegistry for ValibotTemplate {
+    /// Register valibot-specific templates from embedded strings
+    fn register_templates(tera: &mut Tera) -> Result<(), String> {
+        // Main templates
+        template!(tera, "valibot/types.ts.tera", "templates/types.ts.tera");
+        template!(
+            tera,
+            "valibot/commands.ts.tera",
+            "templates/commands.ts.tera"
+        );
+        template!(tera, "valibot/events.ts.tera", "templates/events.ts.tera");
+        template!(tera, "valibot/index.ts.tera", "templates/index.ts.tera");
+        template!(
+            tera,
+            "valibot/schema_registry.ts.tera",
+            "templates/schema_registry.ts.tera"
+        );
+        template!(tera, "valibot/forms.ts.tera", "templates/forms.ts.tera");
+
+        // Partial templates
+        template!(
+            tera,
+            "valibot/partials/schema.ts.tera",
+            "templates/partials/schema.ts.tera"
+        );
+        template!(
+            tera,
+            "valibot/partials/newtype_schema.ts.tera",
+            "templates/partials/newtype_schema.ts.tera"
+        );
+        template!(
+            tera,
+            "valibot/partials/tagged_enum_schema.ts.tera",
+            "templates/partials/tagged_enum_schema.ts.tera"
+        );
+        template!(
+            tera,
+            "valibot/partials/param_schemas.ts.tera",
+            "templates/partials/param_schemas.ts.tera"
+        );
+        template!(
+            tera,
+            "valibot/partials/type_aliases.ts.tera",
+            "templates/partials/type_aliases.ts.tera"
+        );
+        template!(
+            tera,
+            "valibot/partials/command_function.ts.tera",
+            "templates/partials/command_function.ts.tera"
+        );
+        template!(
+            tera,
+            "valibot/partials/event_listener.ts.tera",
+            "templates/partials/event_listener.ts.tera"
+        );
+
+        Ok(())
+    }
+
+    /// Valibot has no specific filters
+    fn register_filters(_tera: &mut Tera) {}
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_create_tera_registers_valibot_templates() {
+        let tera = ValibotTemplate::create_tera().unwrap();
+        let template_names: Vec<&str> = tera.get_template_names().collect();
+
+        assert!(template_names.contains(&"valibot/types.ts.tera"));
+        assert!(template_names.contains(&"valibot/commands.ts.tera"));
+        assert!(template_names.contains(&"valibot/partials/command_function.ts.tera"));
+        assert!(template_names.contains(&"common/command_hooks.tera"));
+        // 6 main + 7 partials + 28 common
+        assert_eq!(template_names.len(), 57);
+    }
+}
@@ -0,0 +1,218 @@
+use crate::generators::base::range_literals::RangeLiterals;
+use crate::generators::base::type_visitor::{string_literal, TypeVisitor};
+use crate::generators::ts::type_visitor::TypeScriptVisitor;
+use crate::models::TypeStructure;
+use crate::GenerateConfig;
+use std::collections::HashSet;
+
+/// Valibot schema visitor - converts TypeStructure to Valibot schema strings
+pub struct ValibotVisitor<'a> {
+    config: Option<&'a GenerateConfig>,
+    /// Types with an emitted `XSchema`; custom types outside this set render as
+    /// `v.unknown()` instead of referencing a schema that does not exist
+    known_schemas: Option<&'a HashSet<String>>,
+}
+
+impl<'a> Default for ValibotVisitor<'a> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<'a> ValibotVisitor<'a> {
+    pub fn new() -> Self {
+        Self {
+            config: None,
+            known_schemas: None,
+        }
+    }
+
+    pub fn with_config(config: &'a GenerateConfig) -> Self {
+        Self {
+            config: Some(config),
+            known_schemas: None,
+        }
+    }
+
+    /// Restrict schema references to the given set of emitted schemas
+    pub fn with_known_schemas(mut self, known_schemas: Option<&'a HashSet<String>>) -> Self {
+        self.known_schemas = known_schemas;
+        self
+    }
+}
+
+impl<'a> TypeVisitor for ValibotVisitor<'a> {
+    fn get_config(&self) -> Option<&GenerateConfig> {
+        self.config
+    }
+
+    fn visit_primitive(&self, type_name: &str) -> String {
+        match type_name {
+            "string" => "v.string()".to_string(),
+            "number" => "v.number()".to_string(),
+            "boolean" => "v.boolean()".to_string(),
+            "void" => "v.void()".to_string(),
+            "unknown" => "v.unknown()".to_string(),
+            "Uint8Array" => "v.instance(Uint8Array)".to_string(),
+            _ => {
+                eprintln!(
+                    "Warning: ValibotVisitor received unexpected primitive: {}",
+                    type_name
+                );
+                format!("v.unknown() /* Unexpected: {} */", type_name)
+            }
+        }
+    }
+
+    fn visit_array(&self, inner: &TypeStructure) -> String {
+        format!("v.array({})", self.visit_type(inner))
+    }
+
+    fn visit_map(&self, key: &TypeStructure, value: &TypeStructure) -> String {
+        format!(
+            "v.record({}, {})",
+            record_key(self.visit_type(key)),
+            self.visit_type(value)
+        )
+    }
+
+    fn visit_set(&self, inner: &TypeStructure) -> String {
+        // Sets arrive as JSON arrays
+        format!("v.array({})", self.visit_type(inner))
+    }
+
+    fn visit_tuple(&self, types: &[TypeStructure]) -> String {
+        if types.is_empty() {
+            "v.void()".to_string()
+        } else {
+            let type_strs: Vec<String> = types.iter().map(|t| self.visit_type(t)).collect();
+            format!("v.tuple([{}])", type_strs.join(", "))
+        }
+    }
+
+    fn visit_optional(&self, inner: &TypeStructure) -> String {
+        format!("v.nullable({})", self.visit_type(inner))
+    }
+
+    fn visit_literals(&self, literals: &RangeLiterals, optional: bool) -> String {
+        if optional {
+            format!("v.nullable({})", literals.valibot_schema())
+        } else {
+            literals.valibot_schema()
+        }
+    }
+
+    fn visit_string_literal(&self, literal: &str, optional: bool) -> String {
+        let schema = format!("v.literal({})", string_literal(literal));
+        if optional {
+            format!("v.nullable({})", schema)
+        } else {
+            schema
+        }
+    }
+
+    fn visit_result(&self, inner: &TypeStructure) -> String {
+        // Result in Rust becomes the success type in TypeScript (errors thrown by Tauri)
+        self.visit_type(inner)
+    }
+
+    fn visit_union(&self, types: &[TypeStructure]) -> String {
+        let type_strs: Vec<String> = types.iter().map(|t| self.visit_type(t)).collect();
+        format!("v.union([{}])", type_strs.join(", "))
+    }
+
+    fn visit_custom(&self, name: &str) -> String {
+        if let Some(config) = self.get_config() {
+            let mapped_type = config
+                .field_override(name)
+                .map(|field_override| field_override.ts_type())
+                .or_else(|| {
+                    config
+                        .type_mappings
+                        .as_ref()
+                        .and_then(|mappings| mappings.get(name))
+                        .map(String::as_str)
+                });
+            if let Some(mapped_type) = mapped_type {
+                return match mapped_type {
+                    "string" => "v.string()".to_string(),
+                    "number" => "v.number()".to_string(),
+                    "boolean" => "v.boolean()".to_string(),
+                    "void" => "v.void()".to_string(),
+                    "unknown" => "v.unknown()".to_string(),
+                    "any" => "v.any()".to_string(),
+                    // Other mapped types are accepted as they are
+                    _ => format!("v.custom<{}>(() => true)", mapped_type),
+                };
+            }
+        }
+        match self.known_schemas {
+            Some(known) if !known.contains(name) => {
+                format!("v.unknown() /* No schema generated for {} */", name)
+            }
+            _ => format!("{}Schema", name),
+        }
+    }
+
+    /// TypeScript types (not valibot schemas) for type interfaces and signatures
+    fn visit_type_for_interface(&self, structure: &TypeStructure) -> String {
+        match self.config {
+            Some(config) => TypeScriptVisitor::with_config(config).visit_type(structure),
+            None => TypeScriptVisitor::new().visit_type(structure),
+        }
+    }
+}
+
+/// Valibot records are keyed by string schemas; JSON object keys are strings anyway
+pub(crate) fn record_key(key_schema: String) -> String {
+    match key_schema.as_str() {
+        "v.number()" | "v.boolean()" => "v.string()".to_string(),
+        _ => key_schema,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn primitive(name: &str) -> TypeStructure {
+        TypeStructure::Primitive(name.to_string())
+    }
+
+    #[test]
+    fn test_valibot_schemas() {
+        let visitor = ValibotVisitor::new();
+        assert_eq!(visitor.visit_type(&primitive("string")), "v.string()");
+        assert_eq!(
+            visitor.visit_type(&TypeStructure::Optional(Box::new(primitive("number")))),
+            "v.nullable(v.number())"
+        );
+        assert_eq!(
+            visitor.visit_type(&TypeStructure::Map {
+                key: Box::new(primitive("number")),
+                value: Box::new(TypeStructure::Custom("User".to_string())),
+            }),
+            "v.record(v.string(), UserSchema)"
+        );
+        assert_eq!(
+            visitor.visit_type(&TypeStructure::Tuple(vec![
+                primitive("string"),
+                primitive("boolean")
+            ])),
+            "v.tuple([v.string(), v.boolean()])"
+        );
+
+        let known = HashSet::from(["User".to_string()]);
+        let visitor = ValibotVisitor::new().with_known_schemas(Some(&known));
+        assert_eq!(
+            visitor.visit_type(&TypeStructure::Custom("Role".to_string())),
+            "v.unknown() /* No schema generated for Role */"
+        );
+        assert_eq!(
+            visitor.visit_type_for_interface(&TypeStructure::Array(Box::new(
+                TypeStructure::Custom("User".to_string())
+            ))),
+            "User[]"
+        );
+    }
+}
@@ -102,6 +102,13 @@ fn type_structure_to_zod_schema(type_structure: &TypeStructure, is_record_key: b
                 inner_schema
             )
         }
+        TypeStructure::Union(types) => {
+            let schemas: Vec<String> = types
+                .iter()
+                .map(|t| type_structure_to_zod_schema(t, false))
+                .collect();
+            format!("z.union([{}])", schemas.join(", "))
+        }
         TypeStructure::Custom(name) => {
             // Reference to a custom type schema
             format!("{}Schema", name)
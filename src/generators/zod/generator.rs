@@ -1,14 +1,22 @@
+use crate::analysis::type_resolver::JSON_VALUE_TYPE;
 use crate::analysis::CommandAnalyzer;
+use crate::generators::base::casing::property_key;
+use crate::generators::base::examples::ExampleBuilder;
 use crate::generators::base::file_writer::FileWriter;
-use crate::generators::base::template_context::FieldContext;
+use crate::generators::base::template_context::{
+    CommandContext, EventContext, FieldContext, StructContext,
+};
 use crate::generators::base::templates::TemplateRegistry;
-use crate::generators::base::BaseBindingsGenerator;
+use crate::generators::base::testing::ValueSchema;
+use crate::generators::base::type_visitor::TypeVisitor;
+use crate::generators::base::validation_messages::ValidationMessages;
+use crate::generators::base::{BaseBindingsGenerator, TypeScriptFiles};
 use crate::generators::zod::schema_builder::ZodSchemaBuilder;
 use crate::generators::zod::templates::ZodTemplate;
 use crate::generators::zod::type_visitor::ZodVisitor;
 use crate::generators::TypeCollector;
-use crate::models::{CommandInfo, EventInfo, StructInfo};
-use crate::GenerateConfig;
+use crate::models::{CommandInfo, EventInfo, FieldInfo, StructInfo, TypeStructure};
+use crate::{GenerateConfig, OutputFileNames};
 use std::collections::{HashMap, HashSet};
 use tera::{Context, Tera};
 
@@ -16,6 +24,10 @@ use tera::{Context, Tera};
 pub struct ZodBindingsGenerator {
     collector: TypeCollector,
     tera: Tera,
+    /// Types whose schema is emitted in types.ts, set per generation run
+    known_schemas: Option<HashSet<String>>,
+    /// Configured validation message templates, set per generation run
+    validation_messages: Option<ValidationMessages>,
 }
 
 impl ZodBindingsGenerator {
@@ -23,9 +35,23 @@ impl ZodBindingsGenerator {
         Self {
             collector: TypeCollector::new(),
             tera: ZodTemplate::create_tera().expect("Failed to initialize Zod template engine"),
+            known_schemas: None,
+            validation_messages: None,
         }
     }
 
+    /// Zod visitor referencing only schemas emitted in this run
+    fn visitor<'a>(&'a self, config: &'a GenerateConfig) -> ZodVisitor<'a> {
+        ZodVisitor::with_config(config).with_known_schemas(self.known_schemas.as_ref())
+    }
+
+    /// Schema builder referencing only schemas emitted in this run
+    fn schema_builder<'a>(&'a self, config: &'a GenerateConfig) -> ZodSchemaBuilder<'a> {
+        ZodSchemaBuilder::new(config)
+            .with_known_schemas(self.known_schemas.as_ref())
+            .with_messages(self.validation_messages.as_ref())
+    }
+
     /// Generate Zod schema for a struct
     fn generate_struct_schema(
         &self,
@@ -33,8 +59,16 @@ impl ZodBindingsGenerator {
         struct_info: &StructInfo,
         config: &GenerateConfig,
     ) -> String {
-        if struct_info.is_enum {
+        if struct_info.is_adjacently_tagged() || struct_info.is_internally_tagged() {
+            self.generate_tagged_enum_schema(name, struct_info, config)
+        } else if struct_info.is_untagged() {
+            self.generate_untagged_enum_schema(name, struct_info, config)
+        } else if struct_info.is_externally_tagged() && struct_info.has_variant_data() {
+            self.generate_external_enum_schema(name, struct_info, config)
+        } else if struct_info.is_enum {
             self.generate_enum_schema(name, struct_info, config)
+        } else if struct_info.is_newtype {
+            self.generate_newtype_schema(name, struct_info, config)
         } else {
             self.generate_object_schema(name, struct_info, config)
         }
@@ -47,7 +81,7 @@ impl ZodBindingsGenerator {
         struct_info: &StructInfo,
         config: &GenerateConfig,
     ) -> String {
-        let visitor = ZodVisitor::with_config(config);
+        let visitor = self.visitor(config);
 
         // Convert fields to context to get serialized names
         let field_contexts: Vec<FieldContext> =
@@ -61,11 +95,254 @@ impl ZodBindingsGenerator {
 
         let enum_values = variants.join(", ");
         format!(
-            "export const {}Schema = z.enum([{}]);\n\n",
+            "export const {0}Schema = /* @__PURE__ */ (() => z.enum([{1}]))();\n\nexport type {0} = z.infer<typeof {0}Schema>;\n\n",
             name, enum_values
         )
     }
 
+    /// Generate a discriminated union schema plus constructor/match helpers
+    /// for an adjacently or internally tagged enum
+    fn generate_tagged_enum_schema(
+        &self,
+        name: &str,
+        struct_info: &StructInfo,
+        config: &GenerateConfig,
+    ) -> String {
+        let visitor = self.visitor(config);
+        let struct_context =
+            StructContext::new(config).from_struct_info(name, struct_info, &visitor);
+        let Some(tagged) = struct_context.tagged_enum else {
+            return self.generate_enum_schema(name, struct_info, config);
+        };
+
+        // Pair each variant's tag value with the zod schema of its content, or with
+        // the entries next to the tag when internally tagged
+        let variants: Vec<serde_json::Value> = struct_info
+            .fields
+            .iter()
+            .zip(&tagged.variants)
+            .map(|(variant, variant_context)| {
+                serde_json::json!({
+                    "serializedName": variant_context.serialized_name,
+                    "schema": self.build_variant_schema(variant, config),
+                    "entries": self.build_variant_entries(variant, config),
+                })
+            })
+            .collect();
+
+        let mut context = Context::new();
+        context.insert("name", name);
+        context.insert("tagged", &tagged);
+        context.insert("variants", &variants);
+
+        self.render("zod/partials/tagged_enum_schema.ts.tera", &context)
+            .unwrap_or_else(|e| {
+                eprintln!("Template rendering failed for {}: {}", name, e);
+                format!("// Error generating schema for {}: {}\n", name, e)
+            })
+    }
+
+    /// Generate Zod schema for an untagged enum as a union of its variant payloads
+    fn generate_untagged_enum_schema(
+        &self,
+        name: &str,
+        struct_info: &StructInfo,
+        config: &GenerateConfig,
+    ) -> String {
+        let visitor = self.visitor(config);
+        let mut members: Vec<String> = Vec::new();
+        for variant in &struct_info.fields {
+            // Serde tries variants in order, so payloads must not be coerced
+            let member = match variant.rust_type.as_str() {
+                "enum_variant_tuple" => visitor.visit_type(&variant.type_structure),
+                _ => self
+                    .build_variant_schema(variant, config)
+                    .unwrap_or_else(|| "z.null()".to_string()),
+            };
+            if !members.contains(&member) {
+                members.push(member);
+            }
+        }
+
+        let schema = match members.as_slice() {
+            [single] => single.clone(),
+            _ => format!("z.union([{}])", members.join(", ")),
+        };
+
+        let mut context = Context::new();
+        context.insert("name", name);
+        context.insert("schema", &schema);
+
+        self.render("zod/partials/newtype_schema.ts.tera", &context)
+            .unwrap_or_else(|e| {
+                eprintln!("Template rendering failed for {}: {}", name, e);
+                format!("// Error generating schema for {}: {}\n", name, e)
+            })
+    }
+
+    /// Generate Zod schema for an externally tagged enum with data as a union of its
+    /// unit variant names and `{ Variant: payload }` objects
+    fn generate_external_enum_schema(
+        &self,
+        name: &str,
+        struct_info: &StructInfo,
+        config: &GenerateConfig,
+    ) -> String {
+        let visitor = self.visitor(config);
+        let field_contexts = self
+            .collector
+            .create_field_contexts(struct_info, &visitor, config);
+        let members: Vec<String> = struct_info
+            .fields
+            .iter()
+            .zip(&field_contexts)
+            .map(|(variant, field_context)| {
+                let serialized_name = &field_context.serialized_name;
+                match self.build_variant_schema(variant, config) {
+                    Some(schema) => format!(
+                        "z.object({{ {}: {} }})",
+                        property_key(serialized_name),
+                        schema
+                    ),
+                    None => format!("z.literal(\"{}\")", serialized_name),
+                }
+            })
+            .collect();
+
+        let mut context = Context::new();
+        context.insert("name", name);
+        context.insert("schema", &format!("z.union([{}])", members.join(", ")));
+
+        self.render("zod/partials/newtype_schema.ts.tera", &context)
+            .unwrap_or_else(|e| {
+                eprintln!("Template rendering failed for {}: {}", name, e);
+                format!("// Error generating schema for {}: {}\n", name, e)
+            })
+    }
+
+    /// Build the Zod schema of an enum variant's payload, `None` for unit variants
+    fn build_variant_schema(&self, variant: &FieldInfo, config: &GenerateConfig) -> Option<String> {
+        let schema_builder = self.schema_builder(config);
+
+        match variant.rust_type.as_str() {
+            "enum_variant_tuple" => {
+                Some(schema_builder.build_schema(&variant.type_structure, &None))
+            }
+            "enum_variant_struct" => Some(format!(
+                "z.object({{ {} }})",
+                self.build_variant_fields(variant, config).join(", ")
+            )),
+            _ => None,
+        }
+    }
+
+    /// Build the object entries of an internally tagged variant's payload, which serde
+    /// writes next to the tag: the fields of a struct variant, or those of the struct a
+    /// newtype variant wraps. `None` for unit variants and other payloads
+    fn build_variant_entries(
+        &self,
+        variant: &FieldInfo,
+        config: &GenerateConfig,
+    ) -> Option<String> {
+        match (variant.rust_type.as_str(), &variant.type_structure) {
+            ("enum_variant_struct", _) => {
+                Some(self.build_variant_fields(variant, config).join(", "))
+            }
+            ("enum_variant_tuple", TypeStructure::Custom(name))
+                if self.collector.is_object_struct(name) =>
+            {
+                Some(format!("...{}Schema.shape", name))
+            }
+            _ => None,
+        }
+    }
+
+    /// Build the `key: schema` entries of a struct variant's fields
+    fn build_variant_fields(&self, variant: &FieldInfo, config: &GenerateConfig) -> Vec<String> {
+        let visitor = self.visitor(config);
+        let schema_builder = self.schema_builder(config);
+
+        variant
+            .variant_fields
+            .iter()
+            .map(|field| {
+                let field_context =
+                    FieldContext::new(config).from_field_info(field, &None, &visitor);
+                format!(
+                    "{}: {}",
+                    property_key(&field_context.serialized_name),
+                    schema_builder.build_schema(&field.type_structure, &field.validator_attributes)
+                )
+            })
+            .collect()
+    }
+
+    /// Generate Zod schema for a collection newtype, aliasing the wrapped type
+    fn generate_newtype_schema(
+        &self,
+        name: &str,
+        struct_info: &StructInfo,
+        config: &GenerateConfig,
+    ) -> String {
+        let schema_builder = self.schema_builder(config);
+        let schema = struct_info
+            .fields
+            .first()
+            .map(|field| schema_builder.build_schema(&field.type_structure, &None))
+            .unwrap_or_else(|| "z.unknown()".to_string());
+
+        let mut context = Context::new();
+        context.insert("name", name);
+        context.insert("schema", &schema);
+
+        self.render("zod/partials/newtype_schema.ts.tera", &context)
+            .unwrap_or_else(|e| {
+                eprintln!("Template rendering failed for {}: {}", name, e);
+                format!("// Error generating schema for {}: {}\n", name, e)
+            })
+    }
+
+    /// Build the schemas of `#[serde(flatten)]` fields: spreads of the shapes of
+    /// flattened structs (partial when optional), and `.catchall(...)` for maps or
+    /// `.and(...)` for other types appended to the object schema
+    fn build_flattened_schemas(
+        &self,
+        struct_info: &StructInfo,
+        config: &GenerateConfig,
+    ) -> (Vec<String>, Vec<String>) {
+        let schema_builder = self.schema_builder(config);
+        let mut spreads = Vec::new();
+        let mut suffixes = Vec::new();
+
+        for field in struct_info.fields.iter().filter(|field| field.flatten) {
+            let (structure, optional) = match &field.type_structure {
+                TypeStructure::Optional(inner) => (inner.as_ref(), true),
+                structure => (structure, false),
+            };
+            match structure {
+                TypeStructure::Custom(name) if self.collector.is_object_struct(name) => spreads
+                    .push(if optional {
+                        format!("...{}Schema.partial().shape", name)
+                    } else {
+                        format!("...{}Schema.shape", name)
+                    }),
+                TypeStructure::Map { value, .. } => suffixes.push(format!(
+                    ".catchall({})",
+                    schema_builder.build_schema(
+                        &TypeCollector::flattened_map_value(struct_info, value),
+                        &None
+                    )
+                )),
+                structure => suffixes.push(format!(
+                    ".and({})",
+                    schema_builder.build_schema(structure, &None)
+                )),
+            }
+        }
+
+        (spreads, suffixes)
+    }
+
     /// Generate Zod schema for an object/struct using templates
     fn generate_object_schema(
         &self,
@@ -73,8 +350,7 @@ impl ZodBindingsGenerator {
         struct_info: &StructInfo,
         config: &GenerateConfig,
     ) -> String {
-        let visitor = ZodVisitor::with_config(config);
-        let schema_builder = ZodSchemaBuilder::new(config);
+        let visitor = self.visitor(config);
 
         // Convert FieldInfo to FieldContext with computed Zod schemas
         let mut field_contexts: Vec<FieldContext> =
@@ -83,16 +359,24 @@ impl ZodBindingsGenerator {
 
         // Enrich with complete zod schemas including validators
         for field_context in &mut field_contexts {
-            let zod_schema = schema_builder.build_schema(
-                &field_context.type_structure,
-                &field_context.validator_attributes,
-            );
-            field_context.typescript_type = zod_schema;
+            field_context.typescript_type = self.build_field_schema(field_context, config);
         }
 
+        // serde rejects unknown fields of `deny_unknown_fields` structs, like `.strict()`
+        let object_mode = if struct_info.serde_deny_unknown_fields {
+            "strict"
+        } else {
+            config.zod_object_mode()
+        };
+
+        let (spreads, suffixes) = self.build_flattened_schemas(struct_info, config);
+
         let mut context = Context::new();
         context.insert("name", name);
         context.insert("fields", &field_contexts);
+        context.insert("object_mode", object_mode);
+        context.insert("spreads", &spreads);
+        context.insert("suffixes", &suffixes);
 
         self.render("zod/partials/schema.ts.tera", &context)
             .unwrap_or_else(|e| {
@@ -101,6 +385,210 @@ impl ZodBindingsGenerator {
             })
     }
 
+    /// Build the complete Zod schema of a struct field, including validators
+    fn build_field_schema(&self, field_context: &FieldContext, config: &GenerateConfig) -> String {
+        let schema_builder = self.schema_builder(config);
+        let schema = if let Some(ref literal) = field_context.literal {
+            schema_builder.build_string_literal_schema(&field_context.type_structure, literal)
+        } else if let Some(ref literals) = field_context.range_literals {
+            schema_builder.build_literal_schema(&field_context.type_structure, literals)
+        } else if field_context.is_path && config.should_require_non_empty_paths() {
+            schema_builder.build_path_schema(
+                &field_context.type_structure,
+                &field_context.validator_attributes,
+            )
+        } else {
+            schema_builder.build_schema(
+                &field_context.type_structure,
+                &field_context.validator_attributes,
+            )
+        };
+
+        // Keys serde fills in or leaves out are optional, like `Option` fields
+        if field_context.omittable
+            && !matches!(field_context.type_structure, TypeStructure::Optional(_))
+        {
+            format!("{}.optional()", schema)
+        } else {
+            schema
+        }
+    }
+
+    /// Generate schemaRegistry.ts mapping type and command names to their schemas
+    fn generate_schema_registry_file(
+        &self,
+        commands: &[CommandInfo],
+        used_structs: &HashMap<String, StructInfo>,
+        analyzer: &CommandAnalyzer,
+        config: &GenerateConfig,
+    ) -> String {
+        let mut type_names: Vec<&String> = used_structs.keys().collect();
+        type_names.sort();
+
+        // Only commands with parameters have a params schema; the first command
+        // wins when several share a name
+        let visitor = self.visitor(config);
+        let mut seen = HashSet::new();
+        let command_contexts: Vec<_> = self
+            .collector
+            .create_command_contexts(commands, &visitor, analyzer, config)
+            .into_iter()
+            .filter(|cmd| !cmd.parameters.is_empty() && seen.insert(cmd.name.clone()))
+            .collect();
+
+        let mut context = Context::new();
+        context.insert("header", &self.generate_file_header());
+        context.insert("type_names", &type_names);
+        context.insert("commands", &command_contexts);
+        context.insert(
+            "types_module",
+            &OutputFileNames::module_path(&config.file_names().types),
+        );
+
+        self.render("zod/schema_registry.ts.tera", &context)
+            .unwrap_or_else(|e| {
+                eprintln!("Template rendering failed for schemaRegistry.ts: {}", e);
+                String::new()
+            })
+    }
+
+    /// Generate forms.ts with a react-hook-form hook per command taking parameters
+    fn generate_forms_file(
+        &self,
+        commands: &[CommandInfo],
+        analyzer: &CommandAnalyzer,
+        config: &GenerateConfig,
+    ) -> String {
+        // Channels cannot be form values, so commands taking them are left out
+        let visitor = self.visitor(config);
+        let command_contexts: Vec<_> = self
+            .collector
+            .create_command_contexts(commands, &visitor, analyzer, config)
+            .into_iter()
+            .filter(|cmd| !cmd.parameters.is_empty() && cmd.channels.is_empty())
+            .collect();
+
+        let values = ExampleBuilder::empty(analyzer.get_discovered_structs(), config);
+        let defaults: HashMap<&str, String> = command_contexts
+            .iter()
+            .map(|cmd| (cmd.ts_function_name.as_str(), values.parameters(cmd)))
+            .collect();
+
+        let file_names = config.file_names();
+        let mut context = Context::new();
+        context.insert("header", &self.generate_file_header());
+        context.insert("commands", &command_contexts);
+        context.insert("defaults", &defaults);
+        context.insert(
+            "commands_module",
+            &OutputFileNames::module_path(&file_names.commands),
+        );
+        context.insert(
+            "types_module",
+            &OutputFileNames::module_path(&file_names.types),
+        );
+
+        self.render("zod/forms.ts.tera", &context)
+            .unwrap_or_else(|e| {
+                eprintln!("Template rendering failed for forms.ts: {}", e);
+                String::new()
+            })
+    }
+}
+
+impl BaseBindingsGenerator for ZodBindingsGenerator {
+    fn tera(&self) -> &Tera {
+        &self.tera
+    }
+
+    fn type_collector(&self) -> &TypeCollector {
+        &self.collector
+    }
+
+    fn generator_type(&self) -> String {
+        "zod".to_string()
+    }
+
+    fn type_schema(
+        &self,
+        structure: &TypeStructure,
+        config: &GenerateConfig,
+    ) -> Option<ValueSchema> {
+        Some(ValueSchema::new(
+            self.visitor(config).visit_type(structure),
+            structure,
+            self.known_schemas.as_ref(),
+            config,
+        ))
+    }
+
+    fn field_schema(&self, field: &FieldContext, config: &GenerateConfig) -> Option<ValueSchema> {
+        Some(ValueSchema::new(
+            self.build_field_schema(field, config),
+            &field.type_structure,
+            self.known_schemas.as_ref(),
+            config,
+        ))
+    }
+
+    fn write_models(
+        &mut self,
+        file_writer: &mut FileWriter,
+        commands: &[CommandInfo],
+        discovered_structs: &HashMap<String, StructInfo>,
+        analyzer: &CommandAnalyzer,
+        config: &GenerateConfig,
+    ) -> Result<Vec<String>, Box<dyn std::error::Error>> {
+        self.write_typescript_models(file_writer, commands, discovered_structs, analyzer, config)
+    }
+}
+
+impl TypeScriptFiles for ZodBindingsGenerator {
+    fn template_dir(&self) -> &'static str {
+        "zod"
+    }
+
+    fn type_collector_mut(&mut self) -> &mut TypeCollector {
+        &mut self.collector
+    }
+
+    fn command_contexts(
+        &self,
+        commands: &[CommandInfo],
+        analyzer: &CommandAnalyzer,
+        config: &GenerateConfig,
+    ) -> Vec<CommandContext> {
+        let mut command_contexts = self.collector.create_command_contexts(
+            commands,
+            &self.visitor(config),
+            analyzer,
+            config,
+        );
+        for command_context in &mut command_contexts {
+            command_context.validate = config.validates_command(&command_context.name);
+        }
+        command_contexts
+    }
+
+    fn event_contexts(
+        &self,
+        events: &[EventInfo],
+        analyzer: &CommandAnalyzer,
+        config: &GenerateConfig,
+    ) -> Vec<EventContext> {
+        self.collector
+            .create_event_contexts(events, &self.visitor(config), analyzer, config)
+    }
+
+    fn struct_contexts(
+        &self,
+        used_structs: &HashMap<String, StructInfo>,
+        config: &GenerateConfig,
+    ) -> Vec<StructContext> {
+        self.collector
+            .create_struct_contexts(used_structs, &self.visitor(config), config)
+    }
+
     /// Generate the complete types.ts file content (with embedded schemas)
     fn generate_types_file_content(
         &self,
@@ -109,6 +597,11 @@ impl ZodBindingsGenerator {
         analyzer: &CommandAnalyzer,
         config: &GenerateConfig,
     ) -> String {
+        // Types of shared crates are imported from their package instead of defined
+        let (shared_imports, local_structs) =
+            self.collector.split_shared_types(used_structs, config);
+        let used_structs = &local_structs;
+
         // Sort structs topologically
         let type_names: HashSet<String> = used_structs.keys().cloned().collect();
         let sorted_types = analyzer.topological_sort_types(&type_names);
@@ -122,8 +615,8 @@ impl ZodBindingsGenerator {
         }
 
         // Convert commands to context wrappers
-        let visitor = ZodVisitor::with_config(config);
-        let schema_builder = ZodSchemaBuilder::new(config);
+        let visitor = self.visitor(config);
+        let schema_builder = self.schema_builder(config);
         let mut command_contexts = self
             .collector
             .create_command_contexts(commands, &visitor, analyzer, config);
@@ -131,7 +624,11 @@ impl ZodBindingsGenerator {
         // Enrich parameters with complete zod schemas
         for command_context in &mut command_contexts {
             for param in &mut command_context.parameters {
-                let zod_schema = schema_builder.build_param_schema(&param.type_structure);
+                let zod_schema = if param.is_path && config.should_require_non_empty_paths() {
+                    schema_builder.build_path_schema(&param.type_structure, &None)
+                } else {
+                    schema_builder.build_param_schema(&param.type_structure)
+                };
                 param.typescript_type = zod_schema;
             }
         }
@@ -166,6 +663,15 @@ impl ZodBindingsGenerator {
             "has_channels",
             &commands.iter().any(|cmd| !cmd.channels.is_empty()),
         );
+        context.insert(
+            "uses_json_value",
+            &self.collector.uses_json_value(commands, used_structs),
+        );
+        context.insert(
+            "type_imports",
+            &self.collector.field_override_imports(used_structs, config),
+        );
+        context.insert("shared_imports", &shared_imports);
         context.insert("struct_schemas", &struct_schemas);
         context.insert("param_schemas", &param_schemas);
         context.insert("type_aliases", &type_aliases);
@@ -177,146 +683,46 @@ impl ZodBindingsGenerator {
             })
     }
 
-    /// Generate command bindings with validation
-    fn generate_command_bindings(
-        &self,
+    fn prepare_schemas(
+        &mut self,
         commands: &[CommandInfo],
-        analyzer: &CommandAnalyzer,
+        used_structs: &HashMap<String, StructInfo>,
         config: &GenerateConfig,
-    ) -> String {
-        // Use ZodVisitor for command bindings - it can generate both Zod schemas
-        // and TypeScript types (via visit_type_for_interface)
-        let visitor = ZodVisitor::with_config(config);
-
-        // Convert commands to context wrappers
-        let command_contexts = self
-            .collector
-            .create_command_contexts(commands, &visitor, analyzer, config);
-
-        let mut context = Context::new();
-        context.insert("header", &self.generate_file_header());
-        context.insert("commands", &command_contexts);
-        context.insert(
-            "has_channels",
-            &commands.iter().any(|cmd| !cmd.channels.is_empty()),
-        );
-
-        self.render("zod/commands.ts.tera", &context)
-            .unwrap_or_else(|e| {
-                eprintln!("Template rendering failed for commands.ts: {}", e);
-                String::new()
-            })
-    }
-
-    /// Generate index.ts file
-    fn generate_index_file(&self, generated_files: &[String]) -> String {
-        let mut context = Context::new();
-        context.insert("header", &self.generate_file_header());
-        context.insert("files", generated_files);
-
-        self.render("zod/index.ts.tera", &context)
-            .unwrap_or_else(|e| {
-                eprintln!("Template rendering failed for index.ts: {}", e);
-                String::new()
-            })
+    ) -> Result<(), Box<dyn std::error::Error>> {
+        // Struct and parameter schemas reference these instead of inlining definitions
+        let mut known_schemas: HashSet<String> = used_structs.keys().cloned().collect();
+        if self.collector.uses_json_value(commands, used_structs) {
+            known_schemas.insert(JSON_VALUE_TYPE.to_string());
+        }
+        self.known_schemas = Some(known_schemas);
+        self.validation_messages = Some(config.validation_messages()?);
+        Ok(())
     }
 
-    /// Generate events file content
-    fn generate_events_file(
+    fn write_schema_files(
         &self,
-        events: &[EventInfo],
-        analyzer: &CommandAnalyzer,
-        config: &GenerateConfig,
-    ) -> String {
-        let visitor = ZodVisitor::with_config(config);
-
-        // Convert events to context wrappers
-        let event_contexts = self
-            .collector
-            .create_event_contexts(events, &visitor, analyzer, config);
-
-        let mut context = Context::new();
-        context.insert("header", &self.generate_file_header());
-        context.insert("events", &event_contexts);
-
-        self.render("zod/events.ts.tera", &context)
-            .unwrap_or_else(|e| {
-                eprintln!("Template rendering failed for events.ts: {}", e);
-                String::new()
-            })
-    }
-}
-
-impl BaseBindingsGenerator for ZodBindingsGenerator {
-    fn tera(&self) -> &Tera {
-        &self.tera
-    }
-
-    fn type_collector(&self) -> &TypeCollector {
-        &self.collector
-    }
-
-    fn generator_type(&self) -> String {
-        "zod".to_string()
-    }
-
-    fn generate_models(
-        &mut self,
+        file_writer: &mut FileWriter,
         commands: &[CommandInfo],
-        discovered_structs: &HashMap<String, StructInfo>,
-        output_path: &str,
+        used_structs: &HashMap<String, StructInfo>,
         analyzer: &CommandAnalyzer,
         config: &GenerateConfig,
-    ) -> Result<Vec<String>, Box<dyn std::error::Error>> {
-        // Store known structs for reference
-        self.collector.known_structs = discovered_structs.clone();
-
-        // Filter to only the types used by commands
-        let mut used_structs = self
-            .collector
-            .collect_used_types(commands, discovered_structs);
-
-        // Also collect types used in events
-        let events = analyzer.get_discovered_events();
-        for event in events {
-            let mut event_types = std::collections::HashSet::new();
-            TypeCollector::collect_referenced_types_from_structure(
-                &event.payload_type_structure,
-                &mut event_types,
-            );
-
-            // Add event payload types to used_structs
-            for type_name in event_types {
-                if let Some(struct_info) = discovered_structs.get(&type_name) {
-                    used_structs.insert(type_name.clone(), struct_info.clone());
-                }
-            }
+    ) -> Result<(), Box<dyn std::error::Error>> {
+        let file_names = config.file_names();
+
+        // Generate and write schema registry if enabled
+        if config.should_generate_schema_registry() {
+            let registry_content =
+                self.generate_schema_registry_file(commands, used_structs, analyzer, config);
+            file_writer.write_typescript_file(&file_names.schema_registry, &registry_content)?;
         }
 
-        // Create file writer
-        let mut file_writer = FileWriter::new(output_path)?;
-
-        // Generate and write types file (with embedded schemas)
-        let types_content =
-            self.generate_types_file_content(commands, &used_structs, analyzer, config);
-        file_writer.write_types_file(&types_content)?;
-
-        // Generate and write commands file
-        let commands_content = self.generate_command_bindings(commands, analyzer, config);
-        file_writer.write_commands_file(&commands_content)?;
-
-        // Generate and write events file if there are any events
-        let events = analyzer.get_discovered_events();
-        if !events.is_empty() {
-            let events_content = self.generate_events_file(events, analyzer, config);
-            file_writer.write_events_file(&events_content)?;
+        // Generate and write react-hook-form hooks if enabled
+        if config.should_generate_form_hooks() {
+            let forms_content = self.generate_forms_file(commands, analyzer, config);
+            file_writer.write_typescript_file(&file_names.forms, &forms_content)?;
         }
 
-        // Generate and write index file
-        let index_content = self.generate_index_file(file_writer.get_generated_files());
-        file_writer.write_index_file(&index_content)?;
-
-        Ok(file_writer.get_generated_files().to_vec())
+        Ok(())
     }
 }
 
@@ -421,13 +827,75 @@ mod tests {
                 validation_library: "zod".to_string(),
                 visualize_deps: Some(false),
                 verbose: Some(false),
+                verbosity: None,
+                trace: None,
                 include_private: Some(false),
                 type_mappings: None,
+                serde_with_mappings: None,
                 exclude_patterns: None,
                 include_patterns: None,
+                max_scan_depth: None,
                 default_parameter_case: "camelCase".to_string(),
                 default_field_case: "camelCase".to_string(),
                 force: Some(false),
+                output_file_names: None,
+                collection_mappings: None,
+                union_types: None,
+                non_empty_paths: None,
+                enum_helpers: None,
+                notify_commands: None,
+                batch_notifications: None,
+                acronyms: None,
+                global_declarations: None,
+                strict_serde_derives: None,
+                skip_zero_sized_fields: None,
+                separate_input_types: None,
+                target: None,
+                kotlin_package: None,
+                viz_focus: None,
+                viz_depth: None,
+                viz_format: None,
+                validation_messages: None,
+                validation_messages_file: None,
+                schema_registry: None,
+                form_hooks: None,
+                xstate_actors: None,
+                web_fallback: None,
+                http_bridge: None,
+                admin_panel: None,
+                arbitraries: None,
+                injected_types: None,
+                field_overrides: None,
+                command_options: None,
+                hooks: None,
+                binary_helpers: None,
+                error_classes: None,
+                max_commands_per_file: None,
+                rename_map: None,
+                allow_outside_repo: None,
+                offline_queue: None,
+                query_keys: None,
+                source_trace: None,
+                macro_records: None,
+                additional_source_paths: None,
+                event_router: None,
+                sanitize_params: None,
+                command_validation: None,
+                aliases: None,
+                range_literals: None,
+                all_types: None,
+                shared_types: None,
+                lint_config: None,
+                framework: None,
+                unknown_type_fallback: None,
+                zod_object_mode: None,
+                test_harness: None,
+                mocks: None,
+                ipc_size_lint: None,
+                stores: None,
+                compact_types: None,
+                compact_codec: None,
+                self_test: None,
             }
         }
 
@@ -442,10 +910,25 @@ mod tests {
                     type_structure: TypeStructure::Primitive("string".to_string()),
                     serde_rename: None,
                     validator_attributes: None,
+                    variant_fields: Vec::new(),
+                    literal: None,
+                    flatten: false,
+                    omittable: false,
+                    defaulted: false,
+                    skip_serializing_if: false,
+                    skipped: None,
+                    serde_with: None,
                 }],
                 file_path: "test.rs".to_string(),
                 is_enum,
                 serde_rename_all: None,
+                is_newtype: false,
+                serde_tag: None,
+                serde_content: None,
+                serde_untagged: false,
+                line_number: 0,
+                is_progress: false,
+                serde_deny_unknown_fields: false,
             }
         }
 
@@ -470,6 +953,25 @@ mod tests {
             assert!(!result.is_empty());
         }
 
+        #[test]
+        fn test_object_schema_unknown_keys() {
+            let gen = ZodBindingsGenerator::new();
+            let mut config = create_test_config();
+            let mut struct_info = create_test_struct(false);
+
+            let result = gen.generate_object_schema("TestStruct", &struct_info, &config);
+            assert!(result.contains("}))();"));
+
+            config.zod_object_mode = Some("passthrough".to_string());
+            let result = gen.generate_object_schema("TestStruct", &struct_info, &config);
+            assert!(result.contains("}).passthrough())();"));
+
+            // deny_unknown_fields wins over the configured mode
+            struct_info.serde_deny_unknown_fields = true;
+            let result = gen.generate_object_schema("TestStruct", &struct_info, &config);
+            assert!(result.contains("}).strict())();"));
+        }
+
         #[test]
         fn test_generate_struct_schema_for_enum() {
             let gen = ZodBindingsGenerator::new();
@@ -498,7 +1000,7 @@ mod tests {
         fn test_generate_index_file_with_empty_files() {
             let gen = ZodBindingsGenerator::new();
             let files = vec![];
-            let result = gen.generate_index_file(&files);
+            let result = gen.generate_index_file(&files, "index.ts");
             assert!(result.contains("Auto-generated") || result.contains("//"));
         }
 
@@ -506,7 +1008,7 @@ mod tests {
         fn test_generate_index_file_with_files() {
             let gen = ZodBindingsGenerator::new();
             let files = vec!["types.ts".to_string(), "commands.ts".to_string()];
-            let result = gen.generate_index_file(&files);
+            let result = gen.generate_index_file(&files, "index.ts");
             assert!(!result.is_empty());
         }
     }
@@ -1,20 +1,37 @@
-use crate::generators::base::type_visitor::TypeVisitor;
+use crate::generators::base::range_literals::RangeLiterals;
+use crate::generators::base::type_visitor::{string_literal, TypeVisitor};
+use crate::generators::base::validation_messages::ValidationMessages;
 use crate::generators::zod::type_visitor::ZodVisitor;
-use crate::models::{TypeStructure, ValidatorAttributes};
+use crate::models::{LengthConstraint, TypeStructure, ValidatorAttributes};
 use crate::GenerateConfig;
+use std::collections::HashSet;
 
 /// Builds complete Zod schemas including validator modifiers
 pub struct ZodSchemaBuilder<'a> {
     visitor: ZodVisitor<'a>,
+    messages: Option<&'a ValidationMessages>,
 }
 
 impl<'a> ZodSchemaBuilder<'a> {
     pub fn new(config: &'a GenerateConfig) -> Self {
         Self {
             visitor: ZodVisitor::with_config(config),
+            messages: None,
         }
     }
 
+    /// Use configured message templates for validators without an explicit message
+    pub fn with_messages(mut self, messages: Option<&'a ValidationMessages>) -> Self {
+        self.messages = messages;
+        self
+    }
+
+    /// Only reference schemas of the given types, see [`ZodVisitor::with_known_schemas`]
+    pub fn with_known_schemas(mut self, known_schemas: Option<&'a HashSet<String>>) -> Self {
+        self.visitor = self.visitor.with_known_schemas(known_schemas);
+        self
+    }
+
     /// Build a complete Zod schema string for a field, including validators
     pub fn build_schema(
         &self,
@@ -29,6 +46,56 @@ impl<'a> ZodSchemaBuilder<'a> {
         self.render_type(type_structure, &None, true, false)
     }
 
+    /// Build a Zod schema for a path field or parameter that rejects empty strings
+    ///
+    /// An explicit `length` validator takes precedence over the implicit `.min(1)`.
+    pub fn build_path_schema(
+        &self,
+        type_structure: &TypeStructure,
+        validator_attributes: &Option<ValidatorAttributes>,
+    ) -> String {
+        let mut validator = validator_attributes.clone().unwrap_or_default();
+        if validator.length.is_none() {
+            validator.length = Some(LengthConstraint {
+                min: Some(1),
+                max: None,
+                message: None,
+            });
+        }
+        self.render_type(type_structure, &Some(validator), false, false)
+    }
+
+    /// Build a Zod schema for an integer field limited to the given literals
+    pub fn build_literal_schema(
+        &self,
+        type_structure: &TypeStructure,
+        literals: &RangeLiterals,
+    ) -> String {
+        match type_structure {
+            TypeStructure::Optional(inner) => {
+                format!("{}.optional()", self.build_literal_schema(inner, literals))
+            }
+            _ => literals.zod_schema(),
+        }
+    }
+
+    /// Build a Zod schema for a field fixed to a string value
+    pub fn build_string_literal_schema(
+        &self,
+        type_structure: &TypeStructure,
+        literal: &str,
+    ) -> String {
+        match type_structure {
+            TypeStructure::Optional(inner) => {
+                format!(
+                    "{}.optional()",
+                    self.build_string_literal_schema(inner, literal)
+                )
+            }
+            _ => format!("z.literal({})", string_literal(literal)),
+        }
+    }
+
     fn render_type(
         &self,
         ts: &TypeStructure,
@@ -78,6 +145,13 @@ impl<'a> ZodSchemaBuilder<'a> {
                     inner_schema
                 )
             }
+            TypeStructure::Union(types) => {
+                let type_strs: Vec<String> = types
+                    .iter()
+                    .map(|t| self.render_type(t, validator, true, false))
+                    .collect();
+                format!("z.union([{}])", type_strs.join(", "))
+            }
             TypeStructure::Custom(_) => {
                 // Use visitor for custom types (handles type mappings)
                 self.visitor.visit_type(ts)
@@ -107,6 +181,7 @@ impl<'a> ZodSchemaBuilder<'a> {
             }
             "boolean" => "z.coerce.boolean()".to_string(),
             "void" => "z.void()".to_string(),
+            "unknown" => "z.unknown()".to_string(),
             _ => format!("z.unknown() /* Unknown primitive: {} */", type_name),
         };
         base_schema
@@ -129,10 +204,10 @@ impl<'a> ZodSchemaBuilder<'a> {
         let mut result = schema.to_string();
 
         if val.email {
-            result.push_str(".email()");
+            result.push_str(&format!(".email({})", self.format_message("email")));
         }
         if val.url {
-            result.push_str(".url()");
+            result.push_str(&format!(".url({})", self.format_message("url")));
         }
 
         result = self.apply_length_validator(&result, validator, skip_validation);
@@ -157,40 +232,16 @@ impl<'a> ZodSchemaBuilder<'a> {
             return schema.to_string();
         };
 
+        let no_messages = ValidationMessages::default();
+        let messages = self.messages.unwrap_or(&no_messages);
         let mut result = schema.to_string();
-
-        if let (Some(min), Some(max)) = (range.min, range.max) {
-            if let Some(ref msg) = range.message {
-                result.push_str(&format!(
-                    ".min({}, {{ message: \"{}\" }}).max({}, {{ message: \"{}\" }})",
-                    min,
-                    escape_js_string(msg),
-                    max,
-                    escape_js_string(msg)
-                ));
-            } else {
-                result.push_str(&format!(".min({}).max({})", min, max));
-            }
-        } else if let Some(min) = range.min {
-            if let Some(ref msg) = range.message {
-                result.push_str(&format!(
-                    ".min({}, {{ message: \"{}\" }})",
-                    min,
-                    escape_js_string(msg)
-                ));
-            } else {
-                result.push_str(&format!(".min({})", min));
-            }
-        } else if let Some(max) = range.max {
-            if let Some(ref msg) = range.message {
-                result.push_str(&format!(
-                    ".max({}, {{ message: \"{}\" }})",
-                    max,
-                    escape_js_string(msg)
-                ));
-            } else {
-                result.push_str(&format!(".max({})", max));
-            }
+        if let Some(min) = range.min {
+            let message = messages.min(&range.message, range.min, range.max);
+            result.push_str(&bound("min", min, &message));
+        }
+        if let Some(max) = range.max {
+            let message = messages.max(&range.message, range.min, range.max);
+            result.push_str(&bound("max", max, &message));
         }
 
         result
@@ -214,44 +265,41 @@ impl<'a> ZodSchemaBuilder<'a> {
             return schema.to_string();
         };
 
+        let no_messages = ValidationMessages::default();
+        let messages = self.messages.unwrap_or(&no_messages);
         let mut result = schema.to_string();
-
-        if let (Some(min), Some(max)) = (length.min, length.max) {
-            if let Some(ref msg) = length.message {
-                result.push_str(&format!(
-                    ".min({}, {{ message: \"{}\" }}).max({}, {{ message: \"{}\" }})",
-                    min,
-                    escape_js_string(msg),
-                    max,
-                    escape_js_string(msg)
-                ));
-            } else {
-                result.push_str(&format!(".min({}).max({})", min, max));
-            }
-        } else if let Some(min) = length.min {
-            if let Some(ref msg) = length.message {
-                result.push_str(&format!(
-                    ".min({}, {{ message: \"{}\" }})",
-                    min,
-                    escape_js_string(msg)
-                ));
-            } else {
-                result.push_str(&format!(".min({})", min));
-            }
-        } else if let Some(max) = length.max {
-            if let Some(ref msg) = length.message {
-                result.push_str(&format!(
-                    ".max({}, {{ message: \"{}\" }})",
-                    max,
-                    escape_js_string(msg)
-                ));
-            } else {
-                result.push_str(&format!(".max({})", max));
-            }
+        if let Some(min) = length.min {
+            let message = messages.min_length(&length.message, length.min, length.max);
+            result.push_str(&bound("min", min, &message));
+        }
+        if let Some(max) = length.max {
+            let message = messages.max_length(&length.message, length.min, length.max);
+            result.push_str(&bound("max", max, &message));
         }
 
         result
     }
+
+    /// Message argument of a format validator (`.email(...)`), empty without a template
+    fn format_message(&self, key: &str) -> String {
+        self.messages
+            .and_then(|messages| messages.format(key))
+            .map(|message| format!("{{ message: \"{}\" }}", escape_js_string(&message)))
+            .unwrap_or_default()
+    }
+}
+
+/// A `.min(...)`/`.max(...)` call with an optional message
+fn bound(method: &str, value: impl std::fmt::Display, message: &Option<String>) -> String {
+    match message {
+        Some(message) => format!(
+            ".{}({}, {{ message: \"{}\" }})",
+            method,
+            value,
+            escape_js_string(message)
+        ),
+        None => format!(".{}({})", method, value),
+    }
 }
 
 fn escape_js_string(s: &str) -> String {
@@ -330,6 +378,42 @@ mod tests {
         assert!(result.contains(".max(10)"));
     }
 
+    #[test]
+    fn test_build_path_schema_requires_non_empty() {
+        let config = test_config();
+        let builder = ZodSchemaBuilder::new(&config);
+
+        let ts = TypeStructure::Primitive("string".to_string());
+        assert_eq!(builder.build_path_schema(&ts, &None), "z.string().min(1)");
+
+        let optional = TypeStructure::Optional(Box::new(ts));
+        assert_eq!(
+            builder.build_path_schema(&optional, &None),
+            "z.string().min(1).optional()"
+        );
+    }
+
+    #[test]
+    fn test_build_path_schema_keeps_explicit_length() {
+        let config = test_config();
+        let builder = ZodSchemaBuilder::new(&config);
+
+        let validator = ValidatorAttributes {
+            length: Some(LengthConstraint {
+                min: Some(3),
+                max: None,
+                message: None,
+            }),
+            ..Default::default()
+        };
+
+        let ts = TypeStructure::Primitive("string".to_string());
+        assert_eq!(
+            builder.build_path_schema(&ts, &Some(validator)),
+            "z.string().min(3)"
+        );
+    }
+
     #[test]
     fn test_build_schema_with_email_validator() {
         let config = test_config();
@@ -411,6 +495,59 @@ mod tests {
         assert!(result.contains("Too short"));
     }
 
+    #[test]
+    fn test_build_schema_with_message_templates() {
+        let config = test_config();
+        let messages = ValidationMessages::new(
+            [
+                ("length", "Zwischen {min} und {max} Zeichen"),
+                ("min", "Mindestens {min}"),
+                ("email", "Ungültige \"E-Mail\""),
+            ]
+            .into_iter()
+            .map(|(key, template)| (key.to_string(), template.to_string()))
+            .collect(),
+        );
+        let builder = ZodSchemaBuilder::new(&config).with_messages(Some(&messages));
+
+        let validator = ValidatorAttributes {
+            email: true,
+            url: false,
+            length: Some(LengthConstraint {
+                min: Some(3),
+                max: Some(50),
+                message: None,
+            }),
+            range: None,
+            custom_message: None,
+        };
+        let ts = TypeStructure::Primitive("string".to_string());
+        assert_eq!(
+            builder.build_schema(&ts, &Some(validator)),
+            "z.string().email({ message: \"Ungültige \\\"E-Mail\\\"\" })\
+             .min(3, { message: \"Zwischen 3 und 50 Zeichen\" })\
+             .max(50, { message: \"Zwischen 3 und 50 Zeichen\" })"
+        );
+
+        // Bounds without a template keep the default message
+        let validator = ValidatorAttributes {
+            email: false,
+            url: false,
+            length: None,
+            range: Some(RangeConstraint {
+                min: Some(1.0),
+                max: Some(10.0),
+                message: None,
+            }),
+            custom_message: None,
+        };
+        let ts = TypeStructure::Primitive("number".to_string());
+        assert_eq!(
+            builder.build_schema(&ts, &Some(validator)),
+            "z.coerce.number().min(1, { message: \"Mindestens 1\" }).max(10)"
+        );
+    }
+
     #[test]
     fn test_build_schema_map() {
         let config = test_config();
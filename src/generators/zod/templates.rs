@@ -12,6 +12,12 @@ impl TemplateRegistry for ZodTemplate {
         template!(tera, "zod/commands.ts.tera", "templates/commands.ts.tera");
         template!(tera, "zod/events.ts.tera", "templates/events.ts.tera");
         template!(tera, "zod/index.ts.tera", "templates/index.ts.tera");
+        template!(
+            tera,
+            "zod/schema_registry.ts.tera",
+            "templates/schema_registry.ts.tera"
+        );
+        template!(tera, "zod/forms.ts.tera", "templates/forms.ts.tera");
 
         // Partial templates
         template!(
@@ -26,6 +32,17 @@ impl TemplateRegistry for ZodTemplate {
             "templates/partials/enum_schema.ts.tera"
         );
 
+        template!(
+            tera,
+            "zod/partials/newtype_schema.ts.tera",
+            "templates/partials/newtype_schema.ts.tera"
+        );
+        template!(
+            tera,
+            "zod/partials/tagged_enum_schema.ts.tera",
+            "templates/partials/tagged_enum_schema.ts.tera"
+        );
+
         template!(
             tera,
             "zod/partials/param_schemas.ts.tera",
@@ -80,6 +97,8 @@ mod tests {
             assert!(template_names.contains(&"zod/commands.ts.tera"));
             assert!(template_names.contains(&"zod/events.ts.tera"));
             assert!(template_names.contains(&"zod/index.ts.tera"));
+            assert!(template_names.contains(&"zod/schema_registry.ts.tera"));
+            assert!(template_names.contains(&"zod/forms.ts.tera"));
         }
 
         #[test]
@@ -89,6 +108,8 @@ mod tests {
 
             assert!(template_names.contains(&"zod/partials/schema.ts.tera"));
             assert!(template_names.contains(&"zod/partials/enum_schema.ts.tera"));
+            assert!(template_names.contains(&"zod/partials/newtype_schema.ts.tera"));
+            assert!(template_names.contains(&"zod/partials/tagged_enum_schema.ts.tera"));
             assert!(template_names.contains(&"zod/partials/param_schemas.ts.tera"));
             assert!(template_names.contains(&"zod/partials/type_aliases.ts.tera"));
             assert!(template_names.contains(&"zod/partials/command_function.ts.tera"));
@@ -101,14 +122,57 @@ mod tests {
             let template_names: Vec<&str> = tera.get_template_names().collect();
 
             assert!(template_names.contains(&"common/header.tera"));
+            assert!(template_names.contains(&"common/enums.ts.tera"));
+            assert!(template_names.contains(&"common/tagged_enum_helpers.tera"));
+            assert!(template_names.contains(&"common/notify.tera"));
+            assert!(template_names.contains(&"common/raw_response.tera"));
+            assert!(template_names.contains(&"common/call_queue.tera"));
+            assert!(template_names.contains(&"common/call_cache.tera"));
+            assert!(template_names.contains(&"common/react_query.ts.tera"));
+            assert!(template_names.contains(&"common/testing.ts.tera"));
+            assert!(template_names.contains(&"common/mocks.ts.tera"));
+            assert!(template_names.contains(&"common/vue.ts.tera"));
+            assert!(template_names.contains(&"common/svelte.ts.tera"));
+            assert!(template_names.contains(&"common/pinia.ts.tera"));
+            assert!(template_names.contains(&"common/stores.ts.tera"));
+            assert!(template_names.contains(&"common/compact.ts.tera"));
+            assert!(template_names.contains(&"common/compact.rs.tera"));
+            assert!(template_names.contains(&"common/self_test.ts.tera"));
+            assert!(template_names.contains(&"common/self_test.rs.tera"));
+            assert!(template_names.contains(&"common/call_schedule.tera"));
+            assert!(template_names.contains(&"common/notify_command.tera"));
+            assert!(template_names.contains(&"common/progress.tera"));
+            assert!(template_names.contains(&"common/progress_command.tera"));
+            assert!(template_names.contains(&"common/stream.tera"));
+            assert!(template_names.contains(&"common/stream_command.tera"));
+            assert!(template_names.contains(&"common/globals.d.ts.tera"));
+            assert!(template_names.contains(&"common/constants.ts.tera"));
+            assert!(template_names.contains(&"common/command_doc.tera"));
+            assert!(template_names.contains(&"common/actors.ts.tera"));
+            assert!(template_names.contains(&"common/runtime.ts.tera"));
+            assert!(template_names.contains(&"common/arbitraries.ts.tera"));
+            assert!(template_names.contains(&"common/binary.ts.tera"));
+            assert!(template_names.contains(&"common/offline.ts.tera"));
+            assert!(template_names.contains(&"common/query_keys.ts.tera"));
+            assert!(template_names.contains(&"common/errors.ts.tera"));
+            assert!(template_names.contains(&"common/event_router.tera"));
+            assert!(template_names.contains(&"common/sanitize.ts.tera"));
+            assert!(template_names.contains(&"common/command_alias.tera"));
+            assert!(template_names.contains(&"common/command_hooks.tera"));
+            assert!(template_names.contains(&"common/commands_shared.ts.tera"));
+            assert!(template_names.contains(&"common/commands_index.ts.tera"));
+            assert!(template_names.contains(&"common/http_bridge.rs.tera"));
+            assert!(template_names.contains(&"common/admin_panel.tsx.tera"));
+            assert!(template_names.contains(&"common/admin_result_view.tsx.tera"));
+            assert!(template_names.contains(&"common/admin_index.tsx.tera"));
         }
 
         #[test]
         fn test_template_count() {
             let tera = ZodTemplate::create_tera().unwrap();
             let count = tera.get_template_names().count();
-            // Should have 11 templates (4 main + 6 partials + 1 common)
-            assert!(count == 11);
+            // Should have 58 templates (6 main + 8 partials + 44 common)
+            assert!(count == 58);
         }
 
         #[test]
@@ -1,10 +1,15 @@
-use crate::generators::base::type_visitor::TypeVisitor;
+use crate::generators::base::range_literals::RangeLiterals;
+use crate::generators::base::type_visitor::{string_literal, TypeVisitor};
 use crate::models::TypeStructure;
 use crate::GenerateConfig;
+use std::collections::HashSet;
 
 /// Zod schema visitor - converts TypeStructure to Zod schema strings
 pub struct ZodVisitor<'a> {
     config: Option<&'a GenerateConfig>,
+    /// Types with an emitted `XSchema`; custom types outside this set render as
+    /// `z.unknown()` instead of referencing a schema that does not exist
+    known_schemas: Option<&'a HashSet<String>>,
 }
 
 impl<'a> Default for ZodVisitor<'a> {
@@ -15,14 +20,24 @@ impl<'a> Default for ZodVisitor<'a> {
 
 impl<'a> ZodVisitor<'a> {
     pub fn new() -> Self {
-        Self { config: None }
+        Self {
+            config: None,
+            known_schemas: None,
+        }
     }
 
     pub fn with_config(config: &'a GenerateConfig) -> Self {
         Self {
             config: Some(config),
+            known_schemas: None,
         }
     }
+
+    /// Restrict schema references to the given set of emitted schemas
+    pub fn with_known_schemas(mut self, known_schemas: Option<&'a HashSet<String>>) -> Self {
+        self.known_schemas = known_schemas;
+        self
+    }
 }
 
 impl<'a> TypeVisitor for ZodVisitor<'a> {
@@ -36,6 +51,8 @@ impl<'a> TypeVisitor for ZodVisitor<'a> {
             "number" => "z.number()".to_string(),
             "boolean" => "z.boolean()".to_string(),
             "void" => "z.void()".to_string(),
+            "unknown" => "z.unknown()".to_string(),
+            "Uint8Array" => "z.instanceof(Uint8Array)".to_string(),
             _ => {
                 eprintln!(
                     "Warning: ZodVisitor received unexpected primitive: {}",
@@ -76,33 +93,70 @@ impl<'a> TypeVisitor for ZodVisitor<'a> {
         format!("{}.nullable()", self.visit_type(inner))
     }
 
+    fn visit_literals(&self, literals: &RangeLiterals, optional: bool) -> String {
+        if optional {
+            format!("{}.nullable()", literals.zod_schema())
+        } else {
+            literals.zod_schema()
+        }
+    }
+
+    fn visit_string_literal(&self, literal: &str, optional: bool) -> String {
+        let schema = format!("z.literal({})", string_literal(literal));
+        if optional {
+            format!("{}.nullable()", schema)
+        } else {
+            schema
+        }
+    }
+
     fn visit_result(&self, inner: &TypeStructure) -> String {
         // Result in Rust becomes the success type in TypeScript (errors thrown by Tauri)
         self.visit_type(inner)
     }
 
+    fn visit_union(&self, types: &[TypeStructure]) -> String {
+        let type_strs: Vec<String> = types.iter().map(|t| self.visit_type(t)).collect();
+        format!("z.union([{}])", type_strs.join(", "))
+    }
+
     fn visit_custom(&self, name: &str) -> String {
         // Check if there's a custom type mapping configured
         if let Some(config) = self.get_config() {
-            if let Some(ref mappings) = config.type_mappings {
-                if let Some(mapped_type) = mappings.get(name) {
-                    // Type is mapped to a primitive TypeScript type
-                    // Convert to appropriate Zod schema
-                    return match mapped_type.as_str() {
-                        "string" => "z.string()".to_string(),
-                        "number" => "z.number()".to_string(),
-                        "boolean" => "z.boolean()".to_string(),
-                        "void" => "z.void()".to_string(),
-                        _ => {
-                            // For non-primitive mappings, use z.custom()
-                            format!("z.custom<{}>((val) => true)", mapped_type)
-                        }
-                    };
-                }
+            let mapped_type = config
+                .field_override(name)
+                .map(|field_override| field_override.ts_type())
+                .or_else(|| {
+                    config
+                        .type_mappings
+                        .as_ref()
+                        .and_then(|mappings| mappings.get(name))
+                        .map(String::as_str)
+                });
+            if let Some(mapped_type) = mapped_type {
+                // Type is mapped to a primitive TypeScript type
+                // Convert to appropriate Zod schema
+                return match mapped_type {
+                    "string" => "z.string()".to_string(),
+                    "number" => "z.number()".to_string(),
+                    "boolean" => "z.boolean()".to_string(),
+                    "void" => "z.void()".to_string(),
+                    "unknown" => "z.unknown()".to_string(),
+                    "any" => "z.any()".to_string(),
+                    _ => {
+                        // For non-primitive mappings, use z.custom()
+                        format!("z.custom<{}>((val) => true)", mapped_type)
+                    }
+                };
+            }
+        }
+        // No mapping found, reference the emitted schema for the type
+        match self.known_schemas {
+            Some(known) if !known.contains(name) => {
+                format!("z.unknown() /* No schema generated for {} */", name)
             }
+            _ => format!("{}Schema", name),
         }
-        // No mapping found, reference the schema for custom types
-        format!("{}Schema", name)
     }
 
     /// Override to return TypeScript types (not zod schemas) for type interfaces
@@ -140,9 +194,19 @@ impl<'a> TypeVisitor for ZodVisitor<'a> {
                 format!("{} | null", self.visit_type_for_interface(inner))
             }
             TypeStructure::Result(inner) => self.visit_type_for_interface(inner),
+            TypeStructure::Union(types) => {
+                let type_strs: Vec<String> = types
+                    .iter()
+                    .map(|t| self.visit_type_for_interface(t))
+                    .collect();
+                type_strs.join(" | ")
+            }
             TypeStructure::Custom(name) => {
-                // Apply custom type mappings
+                // Apply field overrides and custom type mappings
                 if let Some(config) = self.get_config() {
+                    if let Some(field_override) = config.field_override(name) {
+                        return field_override.ts_type().to_string();
+                    }
                     if let Some(ref mappings) = config.type_mappings {
                         if let Some(mapped_type) = mappings.get(name) {
                             return mapped_type.clone();
@@ -1,4 +1,5 @@
 use crate::interface::config::GenerateConfig;
+use crate::interface::messages::Locale;
 use clap::{Args, Parser, Subcommand};
 use std::path::PathBuf;
 
@@ -22,10 +23,24 @@ pub struct TauriTypegenArgs {
     #[arg(short = 'V', long = "version")]
     pub version: bool,
 
+    /// Language of the output: en, de or ja
+    #[arg(long = "output-locale", value_name = "LOCALE", global = true, value_parser = parse_locale)]
+    pub output_locale: Option<Locale>,
+
     #[command(subcommand)]
     pub command: Option<TypegenCommands>,
 }
 
+fn parse_locale(code: &str) -> Result<Locale, String> {
+    Locale::parse(code).ok_or_else(|| {
+        format!(
+            "unsupported locale '{}', use one of: {}",
+            code,
+            Locale::CODES.join(", ")
+        )
+    })
+}
+
 #[derive(Subcommand)]
 pub enum TypegenCommands {
     /// Generate TypeScript models and bindings from Tauri commands
@@ -38,18 +53,37 @@ pub enum TypegenCommands {
         #[arg(short = 'o', long = "output-path")]
         output_path: Option<PathBuf>,
 
-        /// Validation library to use (zod or none). Defaults to config file value or "none"
+        /// Validation library to use (zod, valibot or none). Defaults to config file value or "none"
         #[arg(short = 'v', long = "validation")]
         validation_library: Option<String>,
 
-        /// Verbose output
-        #[arg(long, action = clap::ArgAction::SetTrue)]
-        verbose: bool,
+        /// Verbose output. Repeat for more detail: steps and summaries, then
+        /// discovered types, then every file walked (`-v` is `--validation`)
+        #[arg(long, action = clap::ArgAction::Count)]
+        verbose: u8,
+
+        /// Log phases at full verbosity: scan, analysis, generation or cache
+        #[arg(long, value_name = "PHASE", value_delimiter = ',')]
+        trace: Vec<String>,
 
         /// Generate dependency graph visualization
         #[arg(long, action = clap::ArgAction::SetTrue)]
         visualize_deps: bool,
 
+        /// Limit the dependency graph visualization to the neighborhood of a command or type
+        #[arg(long = "viz-focus", value_name = "TYPE_OR_COMMAND")]
+        viz_focus: Option<String>,
+
+        /// Maximum number of edges from the focus (or from the commands) shown in the
+        /// dependency graph visualization
+        #[arg(long = "viz-depth", value_name = "DEPTH")]
+        viz_depth: Option<usize>,
+
+        /// Dependency graph visualization format: text, dot, html or all.
+        /// Defaults to config file value or text and dot
+        #[arg(long = "viz-format", value_name = "FORMAT")]
+        viz_format: Option<String>,
+
         /// Configuration file path
         #[arg(short = 'c', long = "config")]
         config_file: Option<PathBuf>,
@@ -57,6 +91,14 @@ pub enum TypegenCommands {
         /// Force regeneration, ignoring cache
         #[arg(short = 'f', long, action = clap::ArgAction::SetTrue)]
         force: bool,
+
+        /// Save the analysis result as IR (JSON) for generating later with --from-ir
+        #[arg(long = "save-ir", value_name = "FILE")]
+        save_ir: Option<PathBuf>,
+
+        /// Generate from an IR saved with --save-ir instead of analyzing the project
+        #[arg(long = "from-ir", value_name = "FILE", conflicts_with = "save_ir")]
+        from_ir: Option<PathBuf>,
     },
     /// Initialize configuration for a Tauri project and run initial generation
     Init {
@@ -72,7 +114,7 @@ pub enum TypegenCommands {
         #[arg(short = 'o', long = "output")]
         output_path: Option<PathBuf>,
 
-        /// Validation library to use (zod or none). Defaults to "none"
+        /// Validation library to use (zod, valibot or none). Defaults to "none"
         #[arg(short = 'v', long = "validation")]
         validation_library: Option<String>,
 
@@ -88,6 +130,56 @@ pub enum TypegenCommands {
         #[arg(long, action = clap::ArgAction::SetTrue)]
         force: bool,
     },
+    /// Print the contexts templates are rendered with as JSON, or check templates against them
+    TemplateContext {
+        /// Path to the Tauri project source directory. Defaults to config file value or "./src-tauri"
+        #[arg(short = 'p', long = "project-path")]
+        project_path: Option<PathBuf>,
+
+        /// Validation library to use (zod, valibot or none). Defaults to config file value or "none"
+        #[arg(short = 'v', long = "validation")]
+        validation_library: Option<String>,
+
+        /// Configuration file path
+        #[arg(short = 'c', long = "config")]
+        config_file: Option<PathBuf>,
+
+        /// Templates to render against the context instead of printing it
+        #[arg(long = "check", value_name = "TEMPLATE", num_args = 1..)]
+        check: Vec<PathBuf>,
+    },
+    /// List the types, commands, events and generated files affected by changing a type
+    Impact {
+        /// Name of the struct or enum to change
+        #[arg(value_name = "TYPE")]
+        type_name: String,
+
+        /// Path to the Tauri project source directory. Defaults to config file value or "./src-tauri"
+        #[arg(short = 'p', long = "project-path")]
+        project_path: Option<PathBuf>,
+
+        /// Configuration file path
+        #[arg(short = 'c', long = "config")]
+        config_file: Option<PathBuf>,
+    },
+    /// Explain how a generated type, field, command or parameter was derived from Rust
+    Explain {
+        /// Generated symbol, e.g. types.User.created_at or commands.getUser.userId
+        #[arg(value_name = "SYMBOL")]
+        symbol: String,
+
+        /// Path to the Tauri project source directory. Defaults to config file value or "./src-tauri"
+        #[arg(short = 'p', long = "project-path")]
+        project_path: Option<PathBuf>,
+
+        /// Validation library to use (zod, valibot or none). Defaults to config file value or "none"
+        #[arg(short = 'v', long = "validation")]
+        validation_library: Option<String>,
+
+        /// Configuration file path
+        #[arg(short = 'c', long = "config")]
+        config_file: Option<PathBuf>,
+    },
 }
 
 impl From<&TypegenCommands> for GenerateConfig {
@@ -98,7 +190,11 @@ impl From<&TypegenCommands> for GenerateConfig {
                 output_path,
                 validation_library,
                 verbose,
+                trace,
                 visualize_deps,
+                viz_focus,
+                viz_depth,
+                viz_format,
                 ..
             } => {
                 let mut config = GenerateConfig::default();
@@ -111,13 +207,20 @@ impl From<&TypegenCommands> for GenerateConfig {
                 if let Some(v) = validation_library {
                     config.validation_library = v.clone();
                 }
-                // For boolean flags: only set if true (flag was present)
-                if *verbose {
+                // For flags: only set if present
+                if *verbose > 0 {
                     config.verbose = Some(true);
+                    config.verbosity = Some(*verbose);
+                }
+                if !trace.is_empty() {
+                    config.trace = Some(trace.clone());
                 }
                 if *visualize_deps {
                     config.visualize_deps = Some(true);
                 }
+                config.viz_focus = viz_focus.clone();
+                config.viz_depth = *viz_depth;
+                config.viz_format = viz_format.clone();
                 config
             }
             TypegenCommands::Init {
@@ -147,6 +250,32 @@ impl From<&TypegenCommands> for GenerateConfig {
                 }
                 config
             }
+            TypegenCommands::TemplateContext {
+                project_path,
+                validation_library,
+                ..
+            }
+            | TypegenCommands::Explain {
+                project_path,
+                validation_library,
+                ..
+            } => {
+                let mut config = GenerateConfig::default();
+                if let Some(p) = project_path {
+                    config.project_path = p.to_string_lossy().to_string();
+                }
+                if let Some(v) = validation_library {
+                    config.validation_library = v.clone();
+                }
+                config
+            }
+            TypegenCommands::Impact { project_path, .. } => {
+                let mut config = GenerateConfig::default();
+                if let Some(p) = project_path {
+                    config.project_path = p.to_string_lossy().to_string();
+                }
+                config
+            }
         }
     }
 }
@@ -154,6 +283,7 @@ impl From<&TypegenCommands> for GenerateConfig {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::interface::output::Phase;
 
     #[test]
     fn test_default_generate_config_from_cli() {
@@ -162,10 +292,16 @@ mod tests {
             project_path: None,
             output_path: None,
             validation_library: None,
-            verbose: false,
+            verbose: 0,
+            trace: vec![],
             visualize_deps: false,
+            viz_focus: None,
+            viz_depth: None,
+            viz_format: None,
             config_file: None,
             force: false,
+            save_ir: None,
+            from_ir: None,
         };
 
         let config = GenerateConfig::from(&cmd);
@@ -176,16 +312,46 @@ mod tests {
         assert_eq!(config.visualize_deps, Some(false));
     }
 
+    #[test]
+    fn test_viz_filter_from_cli() {
+        let cmd = TypegenCommands::Generate {
+            project_path: None,
+            output_path: None,
+            validation_library: None,
+            verbose: 0,
+            trace: vec![],
+            visualize_deps: true,
+            viz_focus: Some("User".to_string()),
+            viz_depth: Some(2),
+            viz_format: None,
+            config_file: None,
+            force: false,
+            save_ir: None,
+            from_ir: None,
+        };
+
+        let config = GenerateConfig::from(&cmd);
+        let filter = config.viz_filter();
+        assert_eq!(filter.focus.as_deref(), Some("User"));
+        assert_eq!(filter.depth, Some(2));
+    }
+
     #[test]
     fn test_custom_generate_config_from_cli() {
         let cmd = TypegenCommands::Generate {
             project_path: Some(PathBuf::from("./my-tauri")),
             output_path: Some(PathBuf::from("./types")),
             validation_library: Some("none".to_string()),
-            verbose: true,
+            verbose: 1,
+            trace: vec![],
             visualize_deps: true,
+            viz_focus: None,
+            viz_depth: None,
+            viz_format: None,
             config_file: None,
             force: false,
+            save_ir: None,
+            from_ir: None,
         };
 
         let config = GenerateConfig::from(&cmd);
@@ -203,10 +369,16 @@ mod tests {
             project_path: None,
             output_path: None,
             validation_library: Some("none".to_string()),
-            verbose: true,
+            verbose: 1,
+            trace: vec![],
             visualize_deps: false,
+            viz_focus: None,
+            viz_depth: None,
+            viz_format: None,
             config_file: None,
             force: false,
+            save_ir: None,
+            from_ir: None,
         };
 
         let config = GenerateConfig::from(&cmd);
@@ -217,6 +389,69 @@ mod tests {
         assert_eq!(config.visualize_deps, Some(false)); // default (not set)
     }
 
+    #[test]
+    fn test_verbosity_levels_from_cli() {
+        let cli = CargoCli::parse_from([
+            "cargo",
+            "tauri-typegen",
+            "generate",
+            "--verbose",
+            "--verbose",
+            "--trace",
+            "analysis,cache",
+        ]);
+        let CargoSubcommands::TauriTypegen(args) = cli.command;
+
+        let config = GenerateConfig::from(args.command.as_ref().unwrap());
+        assert_eq!(config.verbosity, Some(2));
+        assert_eq!(
+            config.trace,
+            Some(vec!["analysis".to_string(), "cache".to_string()])
+        );
+        assert!(config.verbosity().enabled(Phase::Cache, 3));
+        assert!(!config.verbosity().enabled(Phase::Scan, 3));
+    }
+
+    #[test]
+    fn test_ir_flags_from_cli() {
+        let cli =
+            CargoCli::parse_from(["cargo", "tauri-typegen", "generate", "--from-ir", "ir.json"]);
+        let CargoSubcommands::TauriTypegen(args) = cli.command;
+        assert!(matches!(
+            args.command,
+            Some(TypegenCommands::Generate { from_ir: Some(ref path), save_ir: None, .. })
+                if path == &PathBuf::from("ir.json")
+        ));
+
+        let both = CargoCli::try_parse_from([
+            "cargo",
+            "tauri-typegen",
+            "generate",
+            "--from-ir",
+            "ir.json",
+            "--save-ir",
+            "out.json",
+        ]);
+        assert!(both.is_err());
+    }
+
+    #[test]
+    fn test_output_locale_from_cli() {
+        let cli = CargoCli::parse_from([
+            "cargo",
+            "tauri-typegen",
+            "generate",
+            "--output-locale",
+            "ja",
+        ]);
+        let CargoSubcommands::TauriTypegen(args) = cli.command;
+        assert_eq!(args.output_locale, Some(Locale::Ja));
+
+        let unsupported =
+            CargoCli::try_parse_from(["cargo", "tauri-typegen", "--output-locale", "fr", "init"]);
+        assert!(unsupported.is_err());
+    }
+
     #[test]
     fn test_default_init_config_from_cli() {
         let cmd = TypegenCommands::Init {
@@ -256,4 +491,57 @@ mod tests {
         assert_eq!(config.verbose, Some(true));
         assert_eq!(config.visualize_deps, Some(true));
     }
+
+    #[test]
+    fn test_template_context_config_from_cli() {
+        let cmd = TypegenCommands::TemplateContext {
+            project_path: Some(PathBuf::from("./my-tauri")),
+            validation_library: Some("zod".to_string()),
+            config_file: None,
+            check: vec![PathBuf::from("templates/types.ts.tera")],
+        };
+
+        let config = GenerateConfig::from(&cmd);
+        assert_eq!(config.project_path, "./my-tauri");
+        assert_eq!(config.output_path, "./src/generated");
+        assert_eq!(config.validation_library, "zod");
+    }
+
+    #[test]
+    fn test_impact_from_cli() {
+        let cli = CargoCli::parse_from(["cargo", "tauri-typegen", "impact", "User", "-p", "./app"]);
+        let CargoSubcommands::TauriTypegen(args) = cli.command;
+        let Some(TypegenCommands::Impact {
+            type_name,
+            project_path,
+            config_file,
+        }) = args.command
+        else {
+            panic!("expected the impact subcommand");
+        };
+        assert_eq!(type_name, "User");
+        assert_eq!(project_path, Some(PathBuf::from("./app")));
+        assert_eq!(config_file, None);
+    }
+
+    #[test]
+    fn test_explain_from_cli() {
+        let cli = CargoCli::parse_from([
+            "cargo",
+            "tauri-typegen",
+            "explain",
+            "types.User.created_at",
+            "-v",
+            "zod",
+        ]);
+        let CargoSubcommands::TauriTypegen(args) = cli.command;
+        let Some(cmd @ TypegenCommands::Explain { .. }) = args.command else {
+            panic!("expected the explain subcommand");
+        };
+        let TypegenCommands::Explain { ref symbol, .. } = cmd else {
+            unreachable!()
+        };
+        assert_eq!(symbol, "types.User.created_at");
+        assert_eq!(GenerateConfig::from(&cmd).validation_library, "zod");
+    }
 }
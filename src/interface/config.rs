@@ -1,15 +1,37 @@
+use crate::analysis::dependency_graph::{VizFilter, VizFormat};
+use crate::analysis::type_resolver::{CollectionKind, TypeResolver, TYPE_MAPPING_WILDCARD};
+use crate::analysis::typegen_ignore::match_wildcard;
+#[cfg(feature = "generators")]
+use crate::generators::base::validation_messages::ValidationMessages;
+use crate::interface::output::{Phase, Verbosity, MAX_VERBOSITY};
+use crate::models::{CallSerialization, CommandInfo, CommandKind};
 use serde::{Deserialize, Serialize};
+use serde_rename_rule::RenameRule;
 use std::fs;
-use std::path::Path;
+use std::path::{Component, Path, PathBuf};
 use thiserror::Error;
 
+/// Frontend frameworks hooks can be generated for (see `GenerateConfig::framework`)
+pub const FRAMEWORKS: &[&str] = &["react-query", "vue", "svelte", "pinia"];
+
+/// Values of `unknown_type_fallback`
+pub const UNKNOWN_TYPE_FALLBACKS: &[&str] = &["unknown", "any", "error"];
+
+/// Values of `zod_object_mode`
+pub const ZOD_OBJECT_MODES: &[&str] = &["strip", "strict", "passthrough"];
+
+/// Languages bindings can be generated in (see `GenerateConfig::target`)
+pub const TARGETS: &[&str] = &["typescript", "python", "kotlin", "swift"];
+
 #[derive(Error, Debug)]
 pub enum ConfigError {
     #[error("IO error: {0}")]
     Io(#[from] std::io::Error),
     #[error("JSON parsing error: {0}")]
     Json(#[from] serde_json::Error),
-    #[error("Invalid validation library: {0}. Use 'zod' or 'none'")]
+    #[error(
+        "Invalid validation library: {0}. Use 'zod', 'valibot', 'none' or a registered generator"
+    )]
     InvalidValidationLibrary(String),
     #[error("Invalid configuration: {0}")]
     InvalidConfig(String),
@@ -25,7 +47,7 @@ pub struct GenerateConfig {
     #[serde(default = "default_output_path")]
     pub output_path: String,
 
-    /// Validation library to use ('zod' or 'none')
+    /// Validation library to use ('zod', 'valibot' or 'none')
     #[serde(default = "default_validation_library")]
     pub validation_library: String,
 
@@ -33,10 +55,34 @@ pub struct GenerateConfig {
     #[serde(default)]
     pub verbose: Option<bool>,
 
+    /// Verbosity level from 1 (steps and summaries) to 3 (everything, including each
+    /// file walked). `verbose` alone is level 1.
+    #[serde(default)]
+    pub verbosity: Option<u8>,
+
+    /// Phases logged at full verbosity regardless of the level:
+    /// "scan", "analysis", "generation" or "cache"
+    #[serde(default)]
+    pub trace: Option<Vec<String>>,
+
     /// Generate dependency graph visualization
     #[serde(default)]
     pub visualize_deps: Option<bool>,
 
+    /// Command or type whose neighborhood the dependency graph visualization is limited to
+    #[serde(default)]
+    pub viz_focus: Option<String>,
+
+    /// Maximum number of edges from the focus (or from the commands) shown in the
+    /// dependency graph visualization
+    #[serde(default)]
+    pub viz_depth: Option<usize>,
+
+    /// Dependency graph visualization format: "text", "dot", "html" or "all".
+    /// Text and DOT files are written when unset.
+    #[serde(default)]
+    pub viz_format: Option<String>,
+
     /// Include private struct fields in generation
     #[serde(default)]
     pub include_private: Option<bool>,
@@ -45,6 +91,297 @@ pub struct GenerateConfig {
     #[serde(default)]
     pub type_mappings: Option<std::collections::HashMap<String, String>>,
 
+    /// TypeScript types of fields converted by a `#[serde(with = "...")]` module or a
+    /// `serialize_with` function, by path, e.g. `{"chrono::serde::ts_seconds": "number"}`
+    #[serde(default)]
+    pub serde_with_mappings: Option<std::collections::HashMap<String, String>>,
+
+    /// Additional collection types and their semantics ("array", "map" or "set"),
+    /// e.g. `{"ImVector": "array"}`. Extends the built-in IndexMap/SmallVec/... support.
+    #[serde(default)]
+    pub collection_mappings: Option<std::collections::HashMap<String, String>>,
+
+    /// Generic types whose type arguments are generated as a union (`A | B`),
+    /// e.g. untagged `OneOf<A, B>` enums. `Either` is always included.
+    #[serde(default)]
+    pub union_types: Option<Vec<String>>,
+
+    /// Additional parameter types supplied by Tauri rather than the frontend, as full
+    /// paths (e.g. `"tauri_plugin_store::Store"`). Extends the built-in `AppHandle`,
+    /// `State`, `Window`, `Webview`, `WebviewWindow` and IPC request/channel types.
+    #[serde(default)]
+    pub injected_types: Option<Vec<String>>,
+
+    /// TypeScript types for individual struct fields, keyed by `Type.field` selectors,
+    /// e.g. `{"User.created_at": "Date"}`. Takes precedence over the analyzed field type.
+    #[serde(default)]
+    pub field_overrides: Option<std::collections::HashMap<String, FieldOverride>>,
+
+    /// Options for individual commands keyed by command name, taking precedence over
+    /// `#[typegen(...)]` attributes, e.g. `{"save_project": {"serializeCalls": true}}`
+    #[serde(default)]
+    pub command_options: Option<std::collections::HashMap<String, CommandOptions>>,
+
+    /// Validation of individual commands ("zod", "valibot" or "none") keyed by command
+    /// name or a `*` pattern, e.g. `{"submit_*": "zod"}`. Listed commands are validated or
+    /// not regardless of `validation_library`, sharing one `types.ts` with schemas.
+    #[serde(default)]
+    pub command_validation: Option<std::collections::HashMap<String, String>>,
+
+    /// Old names of renamed commands mapped to their new command, e.g.
+    /// `{"get_user": "fetch_user"}`. The old function names are kept as deprecated
+    /// aliases of the new ones.
+    #[serde(default)]
+    pub aliases: Option<std::collections::HashMap<String, String>>,
+
+    /// Largest `range` validator spelled out as integer literals, keyed by `Type.field`
+    /// or a `*` pattern, e.g. `{"Settings.*": 8}`. Integer fields whose range has at
+    /// most that many values get a union like `0 | 1 | 2 | 3` instead of `number`.
+    #[serde(default)]
+    pub range_literals: Option<std::collections::HashMap<String, u32>>,
+
+    /// Validation message templates per constraint ("minLength", "range", "email", ...),
+    /// e.g. `{"minLength": "Mindestens {min} Zeichen"}`. Overrides entries of
+    /// `validation_messages_file`.
+    #[serde(default)]
+    pub validation_messages: Option<std::collections::HashMap<String, String>>,
+
+    /// JSON file with validation message templates, e.g. a locale file `locales/de.json`
+    #[serde(default)]
+    pub validation_messages_file: Option<String>,
+
+    /// Reject empty strings for path fields and parameters (`PathBuf`, `OsString`, ...)
+    /// in generated Zod schemas
+    #[serde(default)]
+    pub non_empty_paths: Option<bool>,
+
+    /// Generate runtime helpers for unit enums (values array, type guard,
+    /// parse function and labels map) into a separate `enums.ts`
+    #[serde(default)]
+    pub enum_helpers: Option<bool>,
+
+    /// Generate fire-and-forget `xNotify` variants for commands returning `()`
+    /// or `Result<(), E>`
+    #[serde(default)]
+    pub notify_commands: Option<bool>,
+
+    /// Batch fire-and-forget command calls and send them in a single microtask
+    /// (requires `notify_commands`)
+    #[serde(default)]
+    pub batch_notifications: Option<bool>,
+
+    /// Generate a `globals.d.ts` declaring the generated types in a `declare global`
+    /// block so they can be used without imports (types only)
+    #[serde(default)]
+    pub global_declarations: Option<bool>,
+
+    /// Generate a `schemaRegistry.ts` mapping type and command names to their schemas
+    /// (zod and valibot only), for form builders looking up schemas by string key
+    #[serde(default)]
+    pub schema_registry: Option<bool>,
+
+    /// Generate react-hook-form hooks (`useCreateUserForm`) wiring the params schema,
+    /// default values and a submit handler calling the command into a `forms.ts` (zod and
+    /// valibot only)
+    #[serde(default)]
+    pub form_hooks: Option<bool>,
+
+    /// Generate XState v5 promise actors per command and an event union for the
+    /// discovered Tauri events into an `actors.ts`
+    #[serde(default)]
+    pub xstate_actors: Option<bool>,
+
+    /// Route generated commands through a runtime shim that falls back to HTTP
+    /// endpoints (or throws `TauriNotAvailableError`) when the frontend runs outside Tauri
+    #[serde(default)]
+    pub web_fallback: Option<bool>,
+
+    /// Generate fast-check arbitraries for the types and command parameters into an
+    /// `arbitraries.ts` for property-based tests (requires `fast-check`)
+    #[serde(default)]
+    pub arbitraries: Option<bool>,
+
+    /// Generate a `testing.ts` with `setupTauriMocks(handlers)`, wiring `mockIPC` from
+    /// `@tauri-apps/api/mocks` to handlers typed by the commands; with validation, mocked
+    /// responses are checked against the return schemas
+    #[serde(default)]
+    pub test_harness: Option<bool>,
+
+    /// Generate a `mocks.ts` with a mock implementation per command returning a fake value
+    /// of its return type, keyed by command name, and `mockAllCommands(overrides)` routing
+    /// `mockIPC` from `@tauri-apps/api/mocks` to them
+    #[serde(default)]
+    pub mocks: Option<bool>,
+
+    /// Generate File/Blob helpers for commands sending or returning bytes into a `binary.ts`
+    /// (`xFromFile`, chunked uploads for commands taking an `offset`, `xAsBlob`)
+    #[serde(default)]
+    pub binary_helpers: Option<bool>,
+
+    /// Generate an `offline.ts` whose wrappers queue mutation commands while offline
+    /// and replay them later
+    #[serde(default)]
+    pub offline_queue: Option<bool>,
+
+    /// Generate a `queryKeys.ts` with stable, serializable query key factories for
+    /// query commands
+    #[serde(default)]
+    pub query_keys: Option<bool>,
+
+    /// Write a `trace.json` mapping every exported symbol to the Rust item it was
+    /// generated from
+    #[serde(default)]
+    pub source_trace: Option<bool>,
+
+    /// Generate an `EventMap` and a `handleEvent` router in events.ts that requires a handler
+    /// for every event (or a fallback)
+    #[serde(default)]
+    pub event_router: Option<bool>,
+
+    /// Generate `sanitizeParams(command, params)`, used by the bindings before invoking, which
+    /// strips keys a command does not take and coerces numeric and boolean strings
+    #[serde(default)]
+    pub sanitize_params: Option<bool>,
+
+    /// Generate every serializable type of the project, not only those used by commands,
+    /// e.g. for a types package shared by several apps (see `shared_types`)
+    #[serde(default)]
+    pub all_types: Option<bool>,
+
+    /// Generate ES error classes with a `fromUnknown()` mapper for the error enums
+    /// of commands returning `Result<T, E>`
+    #[serde(default)]
+    pub error_classes: Option<bool>,
+
+    /// Write a `renames.json` mapping renamed commands and parameters to their new names,
+    /// detected against the API recorded by the previous generation
+    #[serde(default)]
+    pub rename_map: Option<bool>,
+
+    /// Split the command bindings into one file per Rust module once there are more
+    /// commands than this, re-exported from the commands file
+    #[serde(default)]
+    pub max_commands_per_file: Option<usize>,
+
+    /// Rust file to write an axum router exposing each command over HTTP for
+    /// debugging, e.g. `./src-tauri/src/typegen_bridge.rs`
+    #[serde(default)]
+    pub http_bridge: Option<String>,
+
+    /// Directory to scaffold an experimental React admin panel into, with a form and
+    /// result view per command, e.g. `./src/admin`. Edited scaffolds are not overwritten.
+    #[serde(default)]
+    pub admin_panel: Option<String>,
+
+    /// Write a lint config for the output directory, `"eslint"` (`.eslintrc` overrides)
+    /// or `"biome"` (`biome.json` section): rules firing on generated code are turned
+    /// off there, and importing the raw `invoke` is restricted everywhere else
+    #[serde(default)]
+    pub lint_config: Option<String>,
+
+    /// Frontend framework to generate hooks wrapping the command bindings for:
+    /// `"react-query"` for TanStack Query `useQuery`/`useMutation` hooks, `"vue"` for
+    /// Vue 3 composables with ref-based `data`/`error`/`loading` state, `"svelte"` for
+    /// Svelte stores of the commands and of the latest payload of each event, `"pinia"`
+    /// for a Pinia store per Rust module with an action per command
+    #[serde(default)]
+    pub framework: Option<String>,
+
+    /// What referenced types without a definition (from other crates, or otherwise not
+    /// found) are generated as: `"unknown"` (default), `"any"`, or `"error"` to fail
+    /// generation naming them
+    #[serde(default)]
+    pub unknown_type_fallback: Option<String>,
+
+    /// How Zod object schemas of structs without `#[serde(deny_unknown_fields)]` treat
+    /// unknown keys: `"strip"` (default), `"strict"` or `"passthrough"`. Structs with the
+    /// attribute always get `.strict()`, rejecting unknown keys like serde does
+    #[serde(default)]
+    pub zod_object_mode: Option<String>,
+
+    /// tauri-plugin-store files to generate typed `getSetting`/`setSetting` helpers for,
+    /// mapping the store file to the Rust struct whose fields are its keys, e.g.
+    /// `{"settings.json": "AppSettings"}`
+    #[serde(default)]
+    pub stores: Option<std::collections::HashMap<String, String>>,
+
+    /// Experimental: types sent as MessagePack instead of JSON when wrapped in the
+    /// `Compact<T>` of the generated codec (see `compact_codec`), e.g. `["Grid"]`
+    #[serde(default)]
+    pub compact_types: Option<Vec<String>>,
+
+    /// Rust file to write the codec of the compact types to, e.g.
+    /// `./src-tauri/src/compact.rs`
+    #[serde(default)]
+    pub compact_codec: Option<String>,
+
+    /// Rust file to write the `__typegen_selftest` command to, e.g.
+    /// `./src-tauri/src/typegen_selftest.rs`, generating `runBindingsSelfTest()` which
+    /// round-trips a sample of every type through it in debug builds
+    #[serde(default)]
+    pub self_test: Option<String>,
+
+    /// Directory of the command records written by the `tauri-typegen-macros` companion
+    /// crate, e.g. `${outDir}/tauri-typegen`. Recorded commands the scanner misses, such
+    /// as ones generated by `macro_rules!`, are added to the scanned ones.
+    #[serde(default)]
+    pub macro_records: Option<String>,
+
+    /// Source roots scanned along with `project_path`, e.g. the source of a path
+    /// dependency vendored read-only elsewhere in the workspace
+    #[serde(default)]
+    pub additional_source_paths: Option<Vec<AdditionalSourcePath>>,
+
+    /// Source roots of crates whose types are generated once into a shared package,
+    /// e.g. `[{"path": "../../crates/core/src", "package": "@acme/core-types"}]`. They
+    /// are scanned along with `project_path`, and the bindings import their types.
+    #[serde(default)]
+    pub shared_types: Option<Vec<SharedTypes>>,
+
+    /// Fail generation when types used by commands lack the serde derives Tauri requires
+    /// (Serialize for return values and channel messages, Deserialize for parameters)
+    #[serde(default)]
+    pub strict_serde_derives: Option<bool>,
+
+    /// Leave out struct fields of zero-sized marker types (`PhantomData<T>`,
+    /// `PhantomPinned`, `()`), which carry no data (default: true)
+    #[serde(default)]
+    pub skip_zero_sized_fields: Option<bool>,
+
+    /// Generate a separate `XInput` type for structs used both as command parameters
+    /// and as results whose fields differ by direction (one-way serde skips, defaults),
+    /// typing the parameters with it
+    #[serde(default)]
+    pub separate_input_types: Option<bool>,
+
+    /// Language of the generated bindings: `"typescript"` (default), `"python"` for
+    /// Pydantic models and async command wrappers taking an `invoke` callable, e.g. for
+    /// pytauri frontends or test harnesses, `"kotlin"` for kotlinx.serialization data
+    /// classes and suspend command wrappers for native Android code, or `"swift"` for
+    /// `Codable` structs and async command wrappers for native iOS code.
+    /// `validation_library` applies to TypeScript only
+    #[serde(default)]
+    pub target: Option<String>,
+
+    /// Package of the generated Kotlin files (default: `tauri.typegen`)
+    #[serde(default)]
+    pub kotlin_package: Option<String>,
+
+    /// Report command parameters and return types likely to exceed a practical IPC size
+    /// (byte buffers, deeply nested collections) as informational lints (default: true)
+    #[serde(default)]
+    pub ipc_size_lint: Option<bool>,
+
+    /// Allow output paths that resolve outside the repository containing the project
+    /// (which otherwise fails generation, except below `${outDir}`)
+    #[serde(default)]
+    pub allow_outside_repo: Option<bool>,
+
+    /// Acronyms kept intact in generated function and type names,
+    /// e.g. `["URL", "IO"]` turns `get_url_v2` into `getURLV2`
+    #[serde(default)]
+    pub acronyms: Option<Vec<String>>,
+
     /// File patterns to exclude from analysis
     #[serde(default)]
     pub exclude_patterns: Option<Vec<String>>,
@@ -53,6 +390,11 @@ pub struct GenerateConfig {
     #[serde(default)]
     pub include_patterns: Option<Vec<String>>,
 
+    /// Maximum directory depth scanned below `project_path` for Rust files.
+    /// Symlinks are followed; symlink cycles are skipped.
+    #[serde(default)]
+    pub max_scan_depth: Option<usize>,
+
     /// Default naming convention for command parameters when no serde attribute is present
     /// Options: "camelCase", "snake_case", "PascalCase", "SCREAMING_SNAKE_CASE", "kebab-case", "SCREAMING-KEBAB-CASE"
     /// Default: "camelCase" (matches Tauri's default behavior - Tauri converts camelCase from JS to snake_case in Rust)
@@ -69,6 +411,440 @@ pub struct GenerateConfig {
     /// Force regeneration, ignoring cache
     #[serde(default)]
     pub force: Option<bool>,
+
+    /// Custom names for the generated files (e.g. `types.ts` → `models.ts`)
+    #[serde(default)]
+    pub output_file_names: Option<OutputFileNames>,
+
+    /// Shell commands run before and after the bindings are written
+    #[serde(default)]
+    pub hooks: Option<GenerationHooks>,
+}
+
+/// Shell commands run around generation, e.g. `{"after": "pnpm format:generated"}`.
+///
+/// Hooks run only when the bindings are regenerated, from the current directory,
+/// with the output path and the generated files passed as `TAURI_TYPEGEN_*`
+/// environment variables. A failing hook fails generation.
+#[derive(Debug, Serialize, Deserialize, Clone, Default, PartialEq, Eq)]
+pub struct GenerationHooks {
+    /// Run before the bindings are written
+    #[serde(default)]
+    pub before: Option<String>,
+
+    /// Run after the bindings are written
+    #[serde(default)]
+    pub after: Option<String>,
+}
+
+/// A source root scanned besides the project (see `additional_source_paths`), e.g.
+/// `{"path": "../vendor/commands", "include": ["src/**/*.rs"]}`.
+///
+/// `include` patterns use the `.typegenignore` syntax, relative to `path`; without
+/// them every Rust file not ignored by the root's own `.typegenignore` is scanned.
+#[derive(Debug, Serialize, Deserialize, Clone, Default, PartialEq, Eq)]
+pub struct AdditionalSourcePath {
+    pub path: String,
+
+    #[serde(default)]
+    pub include: Option<Vec<String>>,
+}
+
+/// A crate whose types are generated into a package of their own (see `shared_types`),
+/// e.g. `{"path": "../../crates/core/src", "package": "@acme/core-types"}`.
+///
+/// The package is generated from the crate with `all_types` and the same validation
+/// library as the apps importing from it.
+#[derive(Debug, Serialize, Deserialize, Clone, Default, PartialEq, Eq)]
+pub struct SharedTypes {
+    pub path: String,
+
+    /// Module the bindings import the crate's types from
+    pub package: String,
+}
+
+/// TypeScript type replacing the analyzed type of a field (see `field_overrides`).
+///
+/// Either a type name (`"Date"`) or a type imported into the generated types file
+/// (`{"type": "Money", "from": "../money"}`).
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq)]
+#[serde(untagged)]
+pub enum FieldOverride {
+    Type(String),
+    Imported {
+        #[serde(rename = "type")]
+        ts_type: String,
+        from: String,
+    },
+}
+
+/// Options of a command's binding (see `command_options`)
+#[derive(Debug, Serialize, Deserialize, Clone, Default, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct CommandOptions {
+    /// Queue concurrent calls instead of sending them in parallel: `true` for one call
+    /// at a time, a parameter name for one call at a time per value of it
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub serialize_calls: Option<SerializeCalls>,
+
+    /// Send a call only once no further call followed it for this many milliseconds,
+    /// e.g. for search-as-you-type. Superseded calls resolve with the latest result.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub debounce_ms: Option<u32>,
+
+    /// Send at most one call per this many milliseconds, the latest one of the window.
+    /// Superseded calls resolve with the latest result.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub throttle_ms: Option<u32>,
+
+    /// Whether the command reads or changes state, inferred from its name by default
+    /// (`get_*`, `list_*`, `is_*`, ... are queries)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub kind: Option<CommandKind>,
+
+    /// Query commands a successful call makes stale, replacing the command's
+    /// `#[typegen(invalidates)]` attributes
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub invalidates: Option<Vec<String>>,
+
+    /// Reuse the result of a query for calls with the same parameters for this many
+    /// milliseconds, until dropped with the generated `invalidate`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cache_ttl_ms: Option<u32>,
+}
+
+/// `serializeCalls` of a command: enabled, or the parameter calls are queued by
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq)]
+#[serde(untagged)]
+pub enum SerializeCalls {
+    Enabled(bool),
+    Key(String),
+}
+
+impl FieldOverride {
+    /// TypeScript type of the field
+    pub fn ts_type(&self) -> &str {
+        match self {
+            FieldOverride::Type(ts_type) | FieldOverride::Imported { ts_type, .. } => ts_type,
+        }
+    }
+
+    /// Module the type is imported from, if any
+    pub fn import_from(&self) -> Option<&str> {
+        match self {
+            FieldOverride::Type(_) => None,
+            FieldOverride::Imported { from, .. } => Some(from),
+        }
+    }
+}
+
+/// File names used for the generated bindings.
+///
+/// Cross-file imports (`./types`) and the `index.ts` re-exports are derived
+/// from these names, so renaming a file keeps the import graph intact.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct OutputFileNames {
+    /// File containing type definitions (and schemas for zod)
+    #[serde(default = "default_types_file_name")]
+    pub types: String,
+
+    /// File containing command bindings
+    #[serde(default = "default_commands_file_name")]
+    pub commands: String,
+
+    /// File containing event listener helpers
+    #[serde(default = "default_events_file_name")]
+    pub events: String,
+
+    /// File containing runtime enum helpers (see `enum_helpers`)
+    #[serde(default = "default_enums_file_name")]
+    pub enums: String,
+
+    /// Barrel file re-exporting all other generated files
+    #[serde(default = "default_index_file_name")]
+    pub index: String,
+
+    /// Ambient declarations of the generated types (see `global_declarations`)
+    #[serde(default = "default_globals_file_name")]
+    pub globals: String,
+
+    /// Rust constants marked with `#[typegen(export)]`
+    #[serde(default = "default_constants_file_name")]
+    pub constants: String,
+
+    /// Schema lookup tables by type and command name (see `schema_registry`)
+    #[serde(
+        default = "default_schema_registry_file_name",
+        alias = "schemaRegistry"
+    )]
+    pub schema_registry: String,
+
+    /// react-hook-form hooks per command (see `form_hooks`)
+    #[serde(default = "default_forms_file_name")]
+    pub forms: String,
+
+    /// XState actors and event types (see `xstate_actors`)
+    #[serde(default = "default_actors_file_name")]
+    pub actors: String,
+
+    /// Runtime shim with the web fallback (see `web_fallback`)
+    #[serde(default = "default_runtime_file_name")]
+    pub runtime: String,
+
+    /// fast-check arbitraries (see `arbitraries`)
+    #[serde(default = "default_arbitraries_file_name")]
+    pub arbitraries: String,
+
+    /// IPC mocks for tests (see `test_harness`)
+    #[serde(default = "default_testing_file_name")]
+    pub testing: String,
+
+    /// Mock implementations of the commands (see `mocks`)
+    #[serde(default = "default_mocks_file_name")]
+    pub mocks: String,
+
+    /// `runBindingsSelfTest()` of the self-test command (see `self_test`)
+    #[serde(default = "default_self_test_file_name")]
+    pub self_test: String,
+
+    /// File and Blob helpers for byte commands (see `binary_helpers`)
+    #[serde(default = "default_binary_file_name")]
+    pub binary: String,
+
+    /// Offline queue and wrappers for mutation commands (see `offline_queue`)
+    #[serde(default = "default_offline_file_name")]
+    pub offline: String,
+
+    /// Query key factories for query commands (see `query_keys`)
+    #[serde(default = "default_query_keys_file_name")]
+    pub query_keys: String,
+
+    /// Symbol to Rust source map (see `source_trace`)
+    #[serde(default = "default_trace_file_name")]
+    pub trace: String,
+
+    /// Error classes for command error enums (see `error_classes`)
+    #[serde(default = "default_errors_file_name")]
+    pub errors: String,
+
+    /// Parameter sanitation helper used by the bindings (see `sanitize_params`)
+    #[serde(default = "default_sanitize_file_name")]
+    pub sanitize: String,
+
+    /// Hooks of the frontend framework (see `framework`)
+    #[serde(default = "default_hooks_file_name")]
+    pub hooks: String,
+
+    /// Typed helpers of the configured settings stores (see `stores`)
+    #[serde(default = "default_stores_file_name")]
+    pub stores: String,
+
+    /// Encoders, decoders and `invoke` of the compact types (see `compactTypes`)
+    #[serde(default = "default_compact_file_name")]
+    pub compact: String,
+}
+
+fn default_types_file_name() -> String {
+    "types.ts".to_string()
+}
+
+fn default_commands_file_name() -> String {
+    "commands.ts".to_string()
+}
+
+fn default_events_file_name() -> String {
+    "events.ts".to_string()
+}
+
+fn default_enums_file_name() -> String {
+    "enums.ts".to_string()
+}
+
+fn default_index_file_name() -> String {
+    "index.ts".to_string()
+}
+
+fn default_globals_file_name() -> String {
+    "globals.d.ts".to_string()
+}
+
+fn default_constants_file_name() -> String {
+    "constants.ts".to_string()
+}
+
+fn default_schema_registry_file_name() -> String {
+    "schemaRegistry.ts".to_string()
+}
+
+fn default_forms_file_name() -> String {
+    "forms.ts".to_string()
+}
+
+fn default_actors_file_name() -> String {
+    "actors.ts".to_string()
+}
+
+fn default_runtime_file_name() -> String {
+    "runtime.ts".to_string()
+}
+
+fn default_arbitraries_file_name() -> String {
+    "arbitraries.ts".to_string()
+}
+
+fn default_testing_file_name() -> String {
+    "testing.ts".to_string()
+}
+
+fn default_mocks_file_name() -> String {
+    "mocks.ts".to_string()
+}
+
+fn default_self_test_file_name() -> String {
+    "selfTest.ts".to_string()
+}
+
+fn default_binary_file_name() -> String {
+    "binary.ts".to_string()
+}
+
+fn default_offline_file_name() -> String {
+    "offline.ts".to_string()
+}
+
+fn default_query_keys_file_name() -> String {
+    "queryKeys.ts".to_string()
+}
+
+fn default_trace_file_name() -> String {
+    "trace.json".to_string()
+}
+
+fn default_errors_file_name() -> String {
+    "errors.ts".to_string()
+}
+
+fn default_sanitize_file_name() -> String {
+    "sanitize.ts".to_string()
+}
+
+fn default_hooks_file_name() -> String {
+    "hooks.ts".to_string()
+}
+
+fn default_stores_file_name() -> String {
+    "stores.ts".to_string()
+}
+
+fn default_compact_file_name() -> String {
+    "compact.ts".to_string()
+}
+
+impl Default for OutputFileNames {
+    fn default() -> Self {
+        Self {
+            types: default_types_file_name(),
+            commands: default_commands_file_name(),
+            events: default_events_file_name(),
+            enums: default_enums_file_name(),
+            index: default_index_file_name(),
+            globals: default_globals_file_name(),
+            constants: default_constants_file_name(),
+            schema_registry: default_schema_registry_file_name(),
+            forms: default_forms_file_name(),
+            actors: default_actors_file_name(),
+            runtime: default_runtime_file_name(),
+            arbitraries: default_arbitraries_file_name(),
+            testing: default_testing_file_name(),
+            mocks: default_mocks_file_name(),
+            self_test: default_self_test_file_name(),
+            binary: default_binary_file_name(),
+            offline: default_offline_file_name(),
+            query_keys: default_query_keys_file_name(),
+            trace: default_trace_file_name(),
+            errors: default_errors_file_name(),
+            sanitize: default_sanitize_file_name(),
+            hooks: default_hooks_file_name(),
+            stores: default_stores_file_name(),
+            compact: default_compact_file_name(),
+        }
+    }
+}
+
+impl OutputFileNames {
+    /// Module specifier used in relative imports, e.g. `models.ts` → `./models`
+    pub fn module_path(file_name: &str) -> String {
+        let stem = file_name.strip_suffix(".ts").unwrap_or(file_name);
+        format!("./{}", stem)
+    }
+
+    /// Name of the module replacing a file in other targets, e.g. `types` for `types.ts`
+    /// and `api_commands` for `api-commands.ts`
+    pub fn module_name(file_name: &str) -> String {
+        let file_name = Path::new(file_name)
+            .file_name()
+            .and_then(|name| name.to_str())
+            .unwrap_or(file_name);
+        file_name
+            .split('.')
+            .next()
+            .unwrap_or(file_name)
+            .replace('-', "_")
+    }
+
+    /// Validate that all names are usable and distinct
+    pub fn validate(&self) -> Result<(), ConfigError> {
+        let names = [
+            &self.types,
+            &self.commands,
+            &self.events,
+            &self.enums,
+            &self.index,
+            &self.globals,
+            &self.constants,
+            &self.schema_registry,
+            &self.forms,
+            &self.actors,
+            &self.runtime,
+            &self.arbitraries,
+            &self.testing,
+            &self.mocks,
+            &self.self_test,
+            &self.binary,
+            &self.offline,
+            &self.query_keys,
+            &self.trace,
+            &self.errors,
+            &self.sanitize,
+            &self.hooks,
+            &self.stores,
+            &self.compact,
+        ];
+
+        for name in names {
+            if name.trim().is_empty() {
+                return Err(ConfigError::InvalidConfig(
+                    "Output file names must not be empty".to_string(),
+                ));
+            }
+            if name.contains('/') || name.contains('\\') {
+                return Err(ConfigError::InvalidConfig(format!(
+                    "Output file name must not contain path separators: {}",
+                    name
+                )));
+            }
+        }
+
+        let unique: std::collections::HashSet<&String> = names.iter().copied().collect();
+        if unique.len() != names.len() {
+            return Err(ConfigError::InvalidConfig(
+                "Output file names must be distinct".to_string(),
+            ));
+        }
+
+        Ok(())
+    }
 }
 
 fn default_project_path() -> String {
@@ -100,14 +876,76 @@ impl Default for GenerateConfig {
             output_path: default_output_path(),
             validation_library: default_validation_library(),
             verbose: Some(false),
+            verbosity: None,
+            trace: None,
             visualize_deps: Some(false),
+            viz_focus: None,
+            viz_depth: None,
+            viz_format: None,
             include_private: Some(false),
             type_mappings: None,
+            serde_with_mappings: None,
+            collection_mappings: None,
+            union_types: None,
+            injected_types: None,
+            field_overrides: None,
+            command_options: None,
+            command_validation: None,
+            aliases: None,
+            range_literals: None,
+            validation_messages: None,
+            validation_messages_file: None,
+            non_empty_paths: None,
+            enum_helpers: None,
+            notify_commands: None,
+            batch_notifications: None,
+            global_declarations: None,
+            schema_registry: None,
+            form_hooks: None,
+            xstate_actors: None,
+            web_fallback: None,
+            arbitraries: None,
+            test_harness: None,
+            mocks: None,
+            binary_helpers: None,
+            offline_queue: None,
+            query_keys: None,
+            source_trace: None,
+            event_router: None,
+            sanitize_params: None,
+            all_types: None,
+            error_classes: None,
+            rename_map: None,
+            max_commands_per_file: None,
+            http_bridge: None,
+            admin_panel: None,
+            lint_config: None,
+            framework: None,
+            unknown_type_fallback: None,
+            zod_object_mode: None,
+            stores: None,
+            compact_types: None,
+            compact_codec: None,
+            self_test: None,
+            macro_records: None,
+            additional_source_paths: None,
+            shared_types: None,
+            strict_serde_derives: None,
+            skip_zero_sized_fields: None,
+            separate_input_types: None,
+            target: None,
+            kotlin_package: None,
+            ipc_size_lint: None,
+            allow_outside_repo: None,
+            acronyms: None,
             exclude_patterns: None,
             include_patterns: None,
+            max_scan_depth: None,
             default_parameter_case: default_parameter_case(),
             default_field_case: default_field_case(),
             force: Some(false),
+            output_file_names: None,
+            hooks: None,
         }
     }
 }
@@ -118,22 +956,27 @@ impl GenerateConfig {
         Self::default()
     }
 
-    /// Load configuration from a file
+    /// Load configuration from a file. It is only parsed: callers check it with
+    /// [`GenerateConfig::validate`], or [`GenerateConfig::validate_options`] when the
+    /// project sources are not needed
     pub fn from_file<P: AsRef<Path>>(path: P) -> Result<Self, ConfigError> {
-        let content = fs::read_to_string(path)?;
-        let config: Self = serde_json::from_str(&content)?;
-        config.validate()?;
-        Ok(config)
+        let content = fs::read_to_string(&path)?;
+        let value: serde_json::Value = serde_json::from_str(&content)?;
+        let value = Self::resolve_extends(value, path.as_ref(), RenameRule::SnakeCase)?;
+        Ok(serde_json::from_value(value)?)
     }
 
-    /// Load configuration from Tauri configuration file
+    /// Load configuration from Tauri configuration file, parsed only like
+    /// [`GenerateConfig::from_file`]
     pub fn from_tauri_config<P: AsRef<Path>>(path: P) -> Result<Option<Self>, ConfigError> {
-        let content = fs::read_to_string(path)?;
+        let content = fs::read_to_string(&path)?;
         let tauri_config: serde_json::Value = serde_json::from_str(&content)?;
 
         // Look for typegen plugin configuration
         if let Some(plugins) = tauri_config.get("plugins") {
             if let Some(typegen) = plugins.get("typegen") {
+                let typegen =
+                    &Self::resolve_extends(typegen.clone(), path.as_ref(), RenameRule::CamelCase)?;
                 let mut config = Self::default();
 
                 if let Some(project_path) = typegen.get("projectPath").and_then(|v| v.as_str()) {
@@ -149,10 +992,27 @@ impl GenerateConfig {
                 if let Some(verbose) = typegen.get("verbose").and_then(|v| v.as_bool()) {
                     config.verbose = Some(verbose);
                 }
+                if let Some(verbosity) = typegen.get("verbosity").and_then(|v| v.as_u64()) {
+                    config.verbosity = Some(verbosity.min(u8::MAX as u64) as u8);
+                }
+                if let Some(trace) = typegen.get("trace") {
+                    if let Ok(phases) = serde_json::from_value::<Vec<String>>(trace.clone()) {
+                        config.trace = Some(phases);
+                    }
+                }
                 if let Some(visualize_deps) = typegen.get("visualizeDeps").and_then(|v| v.as_bool())
                 {
                     config.visualize_deps = Some(visualize_deps);
                 }
+                if let Some(viz_focus) = typegen.get("vizFocus").and_then(|v| v.as_str()) {
+                    config.viz_focus = Some(viz_focus.to_string());
+                }
+                if let Some(viz_depth) = typegen.get("vizDepth").and_then(|v| v.as_u64()) {
+                    config.viz_depth = Some(viz_depth as usize);
+                }
+                if let Some(viz_format) = typegen.get("vizFormat").and_then(|v| v.as_str()) {
+                    config.viz_format = Some(viz_format.to_string());
+                }
                 if let Some(include_private) =
                     typegen.get("includePrivate").and_then(|v| v.as_bool())
                 {
@@ -166,34 +1026,456 @@ impl GenerateConfig {
                         config.type_mappings = Some(mappings);
                     }
                 }
-                if let Some(exclude_patterns) = typegen.get("excludePatterns") {
-                    if let Ok(patterns) =
-                        serde_json::from_value::<Vec<String>>(exclude_patterns.clone())
+                if let Some(serde_with_mappings) = typegen.get("serdeWithMappings") {
+                    if let Ok(mappings) = serde_json::from_value::<
+                        std::collections::HashMap<String, String>,
+                    >(serde_with_mappings.clone())
                     {
-                        config.exclude_patterns = Some(patterns);
+                        config.serde_with_mappings = Some(mappings);
                     }
                 }
-                if let Some(include_patterns) = typegen.get("includePatterns") {
-                    if let Ok(patterns) =
-                        serde_json::from_value::<Vec<String>>(include_patterns.clone())
+                if let Some(collection_mappings) = typegen.get("collectionMappings") {
+                    if let Ok(mappings) = serde_json::from_value::<
+                        std::collections::HashMap<String, String>,
+                    >(collection_mappings.clone())
                     {
-                        config.include_patterns = Some(patterns);
+                        config.collection_mappings = Some(mappings);
                     }
                 }
-                if let Some(force) = typegen.get("force").and_then(|v| v.as_bool()) {
-                    config.force = Some(force);
+                if let Some(union_types) = typegen.get("unionTypes") {
+                    if let Ok(types) = serde_json::from_value::<Vec<String>>(union_types.clone()) {
+                        config.union_types = Some(types);
+                    }
                 }
-
-                config.validate()?;
-                return Ok(Some(config));
-            }
-        }
-
-        Ok(None)
-    }
-
-    /// Save configuration to a file
-    pub fn save_to_file<P: AsRef<Path>>(&self, path: P) -> Result<(), ConfigError> {
+                if let Some(field_overrides) = typegen.get("fieldOverrides") {
+                    if let Ok(overrides) = serde_json::from_value::<
+                        std::collections::HashMap<String, FieldOverride>,
+                    >(field_overrides.clone())
+                    {
+                        config.field_overrides = Some(overrides);
+                    }
+                }
+                if let Some(command_options) = typegen.get("commandOptions") {
+                    if let Ok(options) = serde_json::from_value::<
+                        std::collections::HashMap<String, CommandOptions>,
+                    >(command_options.clone())
+                    {
+                        config.command_options = Some(options);
+                    }
+                }
+                if let Some(injected_types) = typegen.get("injectedTypes") {
+                    if let Ok(types) = serde_json::from_value::<Vec<String>>(injected_types.clone())
+                    {
+                        config.injected_types = Some(types);
+                    }
+                }
+                if let Some(acronyms) = typegen.get("acronyms") {
+                    if let Ok(acronyms) = serde_json::from_value::<Vec<String>>(acronyms.clone()) {
+                        config.acronyms = Some(acronyms);
+                    }
+                }
+                if let Some(command_validation) = typegen.get("commandValidation") {
+                    if let Ok(validation) = serde_json::from_value::<
+                        std::collections::HashMap<String, String>,
+                    >(command_validation.clone())
+                    {
+                        config.command_validation = Some(validation);
+                    }
+                }
+                if let Some(aliases) = typegen.get("aliases") {
+                    if let Ok(aliases) = serde_json::from_value::<
+                        std::collections::HashMap<String, String>,
+                    >(aliases.clone())
+                    {
+                        config.aliases = Some(aliases);
+                    }
+                }
+                if let Some(range_literals) = typegen.get("rangeLiterals") {
+                    if let Ok(range_literals) = serde_json::from_value::<
+                        std::collections::HashMap<String, u32>,
+                    >(range_literals.clone())
+                    {
+                        config.range_literals = Some(range_literals);
+                    }
+                }
+                if let Some(validation_messages) = typegen.get("validationMessages") {
+                    if let Ok(messages) = serde_json::from_value::<
+                        std::collections::HashMap<String, String>,
+                    >(validation_messages.clone())
+                    {
+                        config.validation_messages = Some(messages);
+                    }
+                }
+                if let Some(file) = typegen
+                    .get("validationMessagesFile")
+                    .and_then(|v| v.as_str())
+                {
+                    config.validation_messages_file = Some(file.to_string());
+                }
+                if let Some(non_empty_paths) =
+                    typegen.get("nonEmptyPaths").and_then(|v| v.as_bool())
+                {
+                    config.non_empty_paths = Some(non_empty_paths);
+                }
+                if let Some(enum_helpers) = typegen.get("enumHelpers").and_then(|v| v.as_bool()) {
+                    config.enum_helpers = Some(enum_helpers);
+                }
+                if let Some(notify_commands) =
+                    typegen.get("notifyCommands").and_then(|v| v.as_bool())
+                {
+                    config.notify_commands = Some(notify_commands);
+                }
+                if let Some(batch_notifications) =
+                    typegen.get("batchNotifications").and_then(|v| v.as_bool())
+                {
+                    config.batch_notifications = Some(batch_notifications);
+                }
+                if let Some(global_declarations) =
+                    typegen.get("globalDeclarations").and_then(|v| v.as_bool())
+                {
+                    config.global_declarations = Some(global_declarations);
+                }
+                if let Some(schema_registry) =
+                    typegen.get("schemaRegistry").and_then(|v| v.as_bool())
+                {
+                    config.schema_registry = Some(schema_registry);
+                }
+                if let Some(form_hooks) = typegen.get("formHooks").and_then(|v| v.as_bool()) {
+                    config.form_hooks = Some(form_hooks);
+                }
+                if let Some(xstate_actors) = typegen.get("xstateActors").and_then(|v| v.as_bool()) {
+                    config.xstate_actors = Some(xstate_actors);
+                }
+                if let Some(web_fallback) = typegen.get("webFallback").and_then(|v| v.as_bool()) {
+                    config.web_fallback = Some(web_fallback);
+                }
+                if let Some(arbitraries) = typegen.get("arbitraries").and_then(|v| v.as_bool()) {
+                    config.arbitraries = Some(arbitraries);
+                }
+                if let Some(test_harness) = typegen.get("testHarness").and_then(|v| v.as_bool()) {
+                    config.test_harness = Some(test_harness);
+                }
+                if let Some(mocks) = typegen.get("mocks").and_then(|v| v.as_bool()) {
+                    config.mocks = Some(mocks);
+                }
+                if let Some(binary_helpers) = typegen.get("binaryHelpers").and_then(|v| v.as_bool())
+                {
+                    config.binary_helpers = Some(binary_helpers);
+                }
+                if let Some(offline_queue) = typegen.get("offlineQueue").and_then(|v| v.as_bool()) {
+                    config.offline_queue = Some(offline_queue);
+                }
+                if let Some(query_keys) = typegen.get("queryKeys").and_then(|v| v.as_bool()) {
+                    config.query_keys = Some(query_keys);
+                }
+                if let Some(source_trace) = typegen.get("sourceTrace").and_then(|v| v.as_bool()) {
+                    config.source_trace = Some(source_trace);
+                }
+                if let Some(event_router) = typegen.get("eventRouter").and_then(|v| v.as_bool()) {
+                    config.event_router = Some(event_router);
+                }
+                if let Some(sanitize_params) =
+                    typegen.get("sanitizeParams").and_then(|v| v.as_bool())
+                {
+                    config.sanitize_params = Some(sanitize_params);
+                }
+                if let Some(all_types) = typegen.get("allTypes").and_then(|v| v.as_bool()) {
+                    config.all_types = Some(all_types);
+                }
+                if let Some(error_classes) = typegen.get("errorClasses").and_then(|v| v.as_bool()) {
+                    config.error_classes = Some(error_classes);
+                }
+                if let Some(rename_map) = typegen.get("renameMap").and_then(|v| v.as_bool()) {
+                    config.rename_map = Some(rename_map);
+                }
+                if let Some(max_commands_per_file) =
+                    typegen.get("maxCommandsPerFile").and_then(|v| v.as_u64())
+                {
+                    config.max_commands_per_file = Some(max_commands_per_file as usize);
+                }
+                if let Some(http_bridge) = typegen.get("httpBridge").and_then(|v| v.as_str()) {
+                    config.http_bridge = Some(http_bridge.to_string());
+                }
+                if let Some(admin_panel) = typegen.get("adminPanel").and_then(|v| v.as_str()) {
+                    config.admin_panel = Some(admin_panel.to_string());
+                }
+                if let Some(lint_config) = typegen.get("lintConfig").and_then(|v| v.as_str()) {
+                    config.lint_config = Some(lint_config.to_string());
+                }
+                if let Some(framework) = typegen.get("framework").and_then(|v| v.as_str()) {
+                    config.framework = Some(framework.to_string());
+                }
+                if let Some(fallback) = typegen.get("unknownTypeFallback").and_then(|v| v.as_str())
+                {
+                    config.unknown_type_fallback = Some(fallback.to_string());
+                }
+                if let Some(mode) = typegen.get("zodObjectMode").and_then(|v| v.as_str()) {
+                    config.zod_object_mode = Some(mode.to_string());
+                }
+                if let Some(stores) = typegen.get("stores") {
+                    if let Ok(stores) = serde_json::from_value::<
+                        std::collections::HashMap<String, String>,
+                    >(stores.clone())
+                    {
+                        config.stores = Some(stores);
+                    }
+                }
+                if let Some(compact_types) = typegen.get("compactTypes") {
+                    if let Ok(compact_types) =
+                        serde_json::from_value::<Vec<String>>(compact_types.clone())
+                    {
+                        config.compact_types = Some(compact_types);
+                    }
+                }
+                if let Some(compact_codec) = typegen.get("compactCodec").and_then(|v| v.as_str()) {
+                    config.compact_codec = Some(compact_codec.to_string());
+                }
+                if let Some(self_test) = typegen.get("selfTest").and_then(|v| v.as_str()) {
+                    config.self_test = Some(self_test.to_string());
+                }
+                if let Some(macro_records) = typegen.get("macroRecords").and_then(|v| v.as_str()) {
+                    config.macro_records = Some(macro_records.to_string());
+                }
+                if let Some(source_paths) = typegen.get("additionalSourcePaths") {
+                    if let Ok(source_paths) =
+                        serde_json::from_value::<Vec<AdditionalSourcePath>>(source_paths.clone())
+                    {
+                        config.additional_source_paths = Some(source_paths);
+                    }
+                }
+                if let Some(shared_types) = typegen.get("sharedTypes") {
+                    if let Ok(shared_types) =
+                        serde_json::from_value::<Vec<SharedTypes>>(shared_types.clone())
+                    {
+                        config.shared_types = Some(shared_types);
+                    }
+                }
+                if let Some(strict_serde_derives) =
+                    typegen.get("strictSerdeDerives").and_then(|v| v.as_bool())
+                {
+                    config.strict_serde_derives = Some(strict_serde_derives);
+                }
+                if let Some(skip_zero_sized_fields) =
+                    typegen.get("skipZeroSizedFields").and_then(|v| v.as_bool())
+                {
+                    config.skip_zero_sized_fields = Some(skip_zero_sized_fields);
+                }
+                if let Some(separate_input_types) =
+                    typegen.get("separateInputTypes").and_then(|v| v.as_bool())
+                {
+                    config.separate_input_types = Some(separate_input_types);
+                }
+                if let Some(target) = typegen.get("target").and_then(|v| v.as_str()) {
+                    config.target = Some(target.to_string());
+                }
+                if let Some(package) = typegen.get("kotlinPackage").and_then(|v| v.as_str()) {
+                    config.kotlin_package = Some(package.to_string());
+                }
+                if let Some(ipc_size_lint) = typegen.get("ipcSizeLint").and_then(|v| v.as_bool()) {
+                    config.ipc_size_lint = Some(ipc_size_lint);
+                }
+                if let Some(allow_outside_repo) =
+                    typegen.get("allowOutsideRepo").and_then(|v| v.as_bool())
+                {
+                    config.allow_outside_repo = Some(allow_outside_repo);
+                }
+                if let Some(exclude_patterns) = typegen.get("excludePatterns") {
+                    if let Ok(patterns) =
+                        serde_json::from_value::<Vec<String>>(exclude_patterns.clone())
+                    {
+                        config.exclude_patterns = Some(patterns);
+                    }
+                }
+                if let Some(include_patterns) = typegen.get("includePatterns") {
+                    if let Ok(patterns) =
+                        serde_json::from_value::<Vec<String>>(include_patterns.clone())
+                    {
+                        config.include_patterns = Some(patterns);
+                    }
+                }
+                if let Some(max_scan_depth) = typegen.get("maxScanDepth").and_then(|v| v.as_u64()) {
+                    config.max_scan_depth = Some(max_scan_depth as usize);
+                }
+                if let Some(force) = typegen.get("force").and_then(|v| v.as_bool()) {
+                    config.force = Some(force);
+                }
+                if let Some(file_names) = typegen.get("outputFileNames") {
+                    if let Ok(names) = serde_json::from_value::<OutputFileNames>(file_names.clone())
+                    {
+                        config.output_file_names = Some(names);
+                    }
+                }
+                if let Some(hooks) = typegen.get("hooks") {
+                    if let Ok(hooks) = serde_json::from_value::<GenerationHooks>(hooks.clone()) {
+                        config.hooks = Some(hooks);
+                    }
+                }
+
+                return Ok(Some(config));
+            }
+        }
+
+        Ok(None)
+    }
+
+    /// Top-level keys holding a path, in snake case
+    const PATH_KEYS: [&'static str; 8] = [
+        "project_path",
+        "output_path",
+        "validation_messages_file",
+        "http_bridge",
+        "admin_panel",
+        "compact_codec",
+        "self_test",
+        "macro_records",
+    ];
+
+    /// Top-level keys holding a list of objects with a `path`, in snake case
+    const PATH_LIST_KEYS: [&'static str; 2] = ["additional_source_paths", "shared_types"];
+
+    /// Merge the configurations named by `extends` (relative to `path`) under `value`.
+    ///
+    /// Base files are standalone configs or `tauri.conf.json` files, whose typegen plugin
+    /// section is used. Their top-level keys are converted to `key_case`, the case of the
+    /// extending file, and their relative paths rebased onto their directory. Objects are
+    /// merged key by key, everything else is replaced.
+    fn resolve_extends(
+        value: serde_json::Value,
+        path: &Path,
+        key_case: RenameRule,
+    ) -> Result<serde_json::Value, ConfigError> {
+        let mut chain = vec![path.canonicalize()?];
+        Self::resolve_extends_chain(value, path, key_case, &mut chain)
+    }
+
+    fn resolve_extends_chain(
+        mut value: serde_json::Value,
+        path: &Path,
+        key_case: RenameRule,
+        chain: &mut Vec<std::path::PathBuf>,
+    ) -> Result<serde_json::Value, ConfigError> {
+        let Some(extends) = value.as_object_mut().and_then(|obj| obj.remove("extends")) else {
+            return Ok(value);
+        };
+        let extends = extends.as_str().ok_or_else(|| {
+            ConfigError::InvalidConfig(format!(
+                "extends in {} must be a path to a configuration file",
+                path.display()
+            ))
+        })?;
+
+        let base_path = path.parent().unwrap_or(Path::new(".")).join(extends);
+        let canonical = base_path.canonicalize().map_err(|e| {
+            ConfigError::InvalidConfig(format!(
+                "Cannot read base configuration {} extended by {}: {}",
+                base_path.display(),
+                path.display(),
+                e
+            ))
+        })?;
+        if chain.contains(&canonical) {
+            let cycle = chain
+                .iter()
+                .chain(std::iter::once(&canonical))
+                .map(|p| p.display().to_string())
+                .collect::<Vec<_>>()
+                .join(" -> ");
+            return Err(ConfigError::InvalidConfig(format!(
+                "Configuration extends itself: {}",
+                cycle
+            )));
+        }
+        chain.push(canonical);
+
+        let content = fs::read_to_string(&base_path)?;
+        let mut base: serde_json::Value = serde_json::from_str(&content)?;
+        if let Some(typegen) = base.pointer("/plugins/typegen") {
+            base = typegen.clone();
+        }
+        let mut base = Self::convert_top_level_keys(base, key_case);
+        // Before resolving its own bases, whose paths are rebased onto their directories
+        Self::rebase_paths(
+            &mut base,
+            base_path.parent().unwrap_or(Path::new("")),
+            key_case,
+        );
+        let mut base = Self::resolve_extends_chain(base, &base_path, key_case, chain)?;
+
+        Self::deep_merge(&mut base, value);
+        Ok(base)
+    }
+
+    /// Rebase the relative paths of a base config onto `base_dir`, the directory of the
+    /// base file as seen from the extending file. Absolute paths and paths starting with
+    /// a placeholder (`${workspaceRoot}/...`) are kept.
+    fn rebase_paths(value: &mut serde_json::Value, base_dir: &Path, key_case: RenameRule) {
+        let Some(obj) = value.as_object_mut() else {
+            return;
+        };
+        let key = |name: &str| match key_case {
+            RenameRule::SnakeCase => name.to_string(),
+            _ => key_case.apply_to_field(name),
+        };
+        let rebase = |path: &mut serde_json::Value| {
+            if let serde_json::Value::String(path) = path {
+                if !path.starts_with('$') && !Path::new(path.as_str()).is_absolute() {
+                    let relative: PathBuf = Path::new(path.as_str())
+                        .components()
+                        .filter(|component| !matches!(component, Component::CurDir))
+                        .collect();
+                    *path = base_dir.join(relative).display().to_string();
+                }
+            }
+        };
+
+        for name in Self::PATH_KEYS {
+            if let Some(path) = obj.get_mut(&key(name)) {
+                rebase(path);
+            }
+        }
+        for name in Self::PATH_LIST_KEYS {
+            if let Some(serde_json::Value::Array(entries)) = obj.get_mut(&key(name)) {
+                for path in entries.iter_mut().filter_map(|entry| entry.get_mut("path")) {
+                    rebase(path);
+                }
+            }
+        }
+    }
+
+    fn convert_top_level_keys(value: serde_json::Value, key_case: RenameRule) -> serde_json::Value {
+        match value {
+            serde_json::Value::Object(obj) => obj
+                .into_iter()
+                .map(|(key, value)| {
+                    let key = match key_case {
+                        RenameRule::SnakeCase => key_case.apply_to_variant(&key),
+                        _ => key_case.apply_to_field(&key),
+                    };
+                    (key, value)
+                })
+                .collect(),
+            other => other,
+        }
+    }
+
+    fn deep_merge(base: &mut serde_json::Value, overlay: serde_json::Value) {
+        match (base, overlay) {
+            (serde_json::Value::Object(base), serde_json::Value::Object(overlay)) => {
+                for (key, value) in overlay {
+                    match base.get_mut(&key) {
+                        Some(existing) => Self::deep_merge(existing, value),
+                        None => {
+                            base.insert(key, value);
+                        }
+                    }
+                }
+            }
+            (base, overlay) => *base = overlay,
+        }
+    }
+
+    /// Save configuration to a file
+    pub fn save_to_file<P: AsRef<Path>>(&self, path: P) -> Result<(), ConfigError> {
         let content = serde_json::to_string_pretty(self)?;
         fs::write(path, content)?;
         Ok(())
@@ -213,18 +1495,156 @@ impl GenerateConfig {
         let mut tauri_config = serde_json::from_str::<serde_json::Value>(&content)?;
 
         // Create typegen plugin configuration
-        let typegen_config = serde_json::json!({
+        let mut typegen_config = serde_json::json!({
             "projectPath": self.project_path,
             "outputPath": self.output_path,
             "validationLibrary": self.validation_library,
             "verbose": self.verbose.unwrap_or(false),
             "visualizeDeps": self.visualize_deps.unwrap_or(false),
+            "vizFocus": self.viz_focus,
+            "vizDepth": self.viz_depth,
+            "vizFormat": self.viz_format,
             "includePrivate": self.include_private.unwrap_or(false),
             "typeMappings": self.type_mappings,
+            "collectionMappings": self.collection_mappings,
+            "unionTypes": self.union_types,
+            "injectedTypes": self.injected_types,
+            "fieldOverrides": self.field_overrides,
+            "validationMessages": self.validation_messages,
+            "validationMessagesFile": self.validation_messages_file,
+            "nonEmptyPaths": self.non_empty_paths.unwrap_or(false),
+            "enumHelpers": self.enum_helpers.unwrap_or(false),
+            "notifyCommands": self.notify_commands.unwrap_or(false),
+            "batchNotifications": self.batch_notifications.unwrap_or(false),
+            "globalDeclarations": self.global_declarations.unwrap_or(false),
+            "schemaRegistry": self.schema_registry.unwrap_or(false),
+            "formHooks": self.form_hooks.unwrap_or(false),
+            "xstateActors": self.xstate_actors.unwrap_or(false),
+            "webFallback": self.web_fallback.unwrap_or(false),
+            "arbitraries": self.arbitraries.unwrap_or(false),
+            "binaryHelpers": self.binary_helpers.unwrap_or(false),
+            "errorClasses": self.error_classes.unwrap_or(false),
+            "renameMap": self.rename_map.unwrap_or(false),
+            "maxCommandsPerFile": self.max_commands_per_file,
+            "httpBridge": self.http_bridge,
+            "adminPanel": self.admin_panel,
+            "strictSerdeDerives": self.strict_serde_derives.unwrap_or(false),
+            "allowOutsideRepo": self.allow_outside_repo.unwrap_or(false),
+            "acronyms": self.acronyms,
             "excludePatterns": self.exclude_patterns,
             "includePatterns": self.include_patterns,
+            "maxScanDepth": self.max_scan_depth,
             "force": self.force.unwrap_or(false),
+            "outputFileNames": self.output_file_names,
+            "hooks": self.hooks,
         });
+        // Added separately, the object above is at the json! macro's recursion limit
+        if let Some(typegen_obj) = typegen_config.as_object_mut() {
+            typegen_obj.insert("verbosity".to_string(), serde_json::json!(self.verbosity));
+            typegen_obj.insert("trace".to_string(), serde_json::json!(self.trace));
+            typegen_obj.insert(
+                "commandOptions".to_string(),
+                serde_json::json!(self.command_options),
+            );
+            typegen_obj.insert(
+                "commandValidation".to_string(),
+                serde_json::json!(self.command_validation),
+            );
+            typegen_obj.insert("aliases".to_string(), serde_json::json!(self.aliases));
+            typegen_obj.insert(
+                "rangeLiterals".to_string(),
+                serde_json::json!(self.range_literals),
+            );
+            typegen_obj.insert(
+                "offlineQueue".to_string(),
+                serde_json::json!(self.offline_queue.unwrap_or(false)),
+            );
+            typegen_obj.insert(
+                "queryKeys".to_string(),
+                serde_json::json!(self.query_keys.unwrap_or(false)),
+            );
+            typegen_obj.insert(
+                "sourceTrace".to_string(),
+                serde_json::json!(self.source_trace.unwrap_or(false)),
+            );
+            typegen_obj.insert(
+                "eventRouter".to_string(),
+                serde_json::json!(self.event_router.unwrap_or(false)),
+            );
+            typegen_obj.insert(
+                "sanitizeParams".to_string(),
+                serde_json::json!(self.sanitize_params.unwrap_or(false)),
+            );
+            typegen_obj.insert(
+                "allTypes".to_string(),
+                serde_json::json!(self.all_types.unwrap_or(false)),
+            );
+            typegen_obj.insert(
+                "macroRecords".to_string(),
+                serde_json::json!(self.macro_records),
+            );
+            typegen_obj.insert(
+                "additionalSourcePaths".to_string(),
+                serde_json::json!(self.additional_source_paths),
+            );
+            typegen_obj.insert(
+                "sharedTypes".to_string(),
+                serde_json::json!(self.shared_types),
+            );
+            typegen_obj.insert(
+                "lintConfig".to_string(),
+                serde_json::json!(self.lint_config),
+            );
+            typegen_obj.insert("framework".to_string(), serde_json::json!(self.framework));
+            typegen_obj.insert(
+                "testHarness".to_string(),
+                serde_json::json!(self.test_harness.unwrap_or(false)),
+            );
+            typegen_obj.insert(
+                "mocks".to_string(),
+                serde_json::json!(self.mocks.unwrap_or(false)),
+            );
+            typegen_obj.insert(
+                "unknownTypeFallback".to_string(),
+                serde_json::json!(self.unknown_type_fallback),
+            );
+            typegen_obj.insert(
+                "zodObjectMode".to_string(),
+                serde_json::json!(self.zod_object_mode),
+            );
+            typegen_obj.insert("stores".to_string(), serde_json::json!(self.stores));
+            typegen_obj.insert(
+                "compactTypes".to_string(),
+                serde_json::json!(self.compact_types),
+            );
+            typegen_obj.insert(
+                "compactCodec".to_string(),
+                serde_json::json!(self.compact_codec),
+            );
+            typegen_obj.insert("selfTest".to_string(), serde_json::json!(self.self_test));
+            typegen_obj.insert(
+                "ipcSizeLint".to_string(),
+                serde_json::json!(self.ipc_size_lint.unwrap_or(true)),
+            );
+            typegen_obj.insert(
+                "skipZeroSizedFields".to_string(),
+                serde_json::json!(self.skip_zero_sized_fields.unwrap_or(true)),
+            );
+            typegen_obj.insert(
+                "separateInputTypes".to_string(),
+                serde_json::json!(self.separate_input_types.unwrap_or(false)),
+            );
+            typegen_obj.insert(
+                "serdeWithMappings".to_string(),
+                serde_json::json!(self.serde_with_mappings),
+            );
+            if let Some(ref target) = self.target {
+                typegen_obj.insert("target".to_string(), serde_json::json!(target));
+            }
+            if let Some(ref package) = self.kotlin_package {
+                typegen_obj.insert("kotlinPackage".to_string(), serde_json::json!(package));
+            }
+        }
 
         // Ensure plugins section exists and insert typegen configuration
         if !tauri_config.is_object() {
@@ -250,267 +1670,2364 @@ impl GenerateConfig {
         Ok(())
     }
 
-    /// Validate the configuration
-    pub fn validate(&self) -> Result<(), ConfigError> {
+    /// Validate the options of the configuration, without checking its paths
+    pub fn validate_options(&self) -> Result<(), ConfigError> {
         // Validate validation library
-        match self.validation_library.as_str() {
-            "zod" | "none" => {}
-            _ => {
-                return Err(ConfigError::InvalidValidationLibrary(
-                    self.validation_library.clone(),
-                ));
+        if !self.has_known_generator() {
+            return Err(ConfigError::InvalidValidationLibrary(
+                self.validation_library.clone(),
+            ));
+        }
+
+        if let Some(ref mappings) = self.collection_mappings {
+            for (rust_type, kind) in mappings {
+                if CollectionKind::parse(kind).is_none() {
+                    return Err(ConfigError::InvalidConfig(format!(
+                        "Invalid collection mapping for {}: {}. Use 'array', 'map' or 'set'",
+                        rust_type, kind
+                    )));
+                }
             }
         }
 
-        // Validate paths exist
-        let project_path = Path::new(&self.project_path);
-        if !project_path.exists() {
-            return Err(ConfigError::InvalidConfig(format!(
-                "Project path does not exist: {}",
-                self.project_path
-            )));
+        if let Some(ref command_options) = self.command_options {
+            for (command, options) in command_options {
+                if let Some(SerializeCalls::Key(key)) = &options.serialize_calls {
+                    if key.trim().is_empty() {
+                        return Err(ConfigError::InvalidConfig(format!(
+                            "serializeCalls of command {} must be true, false or a parameter name",
+                            command
+                        )));
+                    }
+                }
+                if options.debounce_ms.is_some() && options.throttle_ms.is_some() {
+                    return Err(ConfigError::InvalidConfig(format!(
+                        "Command {} sets both debounceMs and throttleMs, use one of them",
+                        command
+                    )));
+                }
+                if options.debounce_ms == Some(0) || options.throttle_ms == Some(0) {
+                    return Err(ConfigError::InvalidConfig(format!(
+                        "debounceMs and throttleMs of command {} must be greater than 0",
+                        command
+                    )));
+                }
+                if options.cache_ttl_ms == Some(0) {
+                    return Err(ConfigError::InvalidConfig(format!(
+                        "cacheTtlMs of command {} must be greater than 0",
+                        command
+                    )));
+                }
+            }
         }
 
-        Ok(())
-    }
+        if let Some(ref command_validation) = self.command_validation {
+            for (command, validation) in command_validation {
+                if !matches!(validation.as_str(), "zod" | "valibot" | "none") {
+                    return Err(ConfigError::InvalidConfig(format!(
+                        "Invalid validation for command {}: {}. Use 'zod', 'valibot' or 'none'",
+                        command, validation
+                    )));
+                }
+            }
+            // All commands share one types.ts, so its schemas come from a single library
+            let libraries: std::collections::BTreeSet<&str> = command_validation
+                .values()
+                .map(String::as_str)
+                .chain(std::iter::once(self.validation_library.as_str()))
+                .filter(|library| *library != "none")
+                .collect();
+            if libraries.len() > 1 {
+                return Err(ConfigError::InvalidConfig(format!(
+                    "commandValidation and validationLibrary mix {}, use one of them",
+                    libraries.into_iter().collect::<Vec<_>>().join(" and ")
+                )));
+            }
+        }
 
-    /// Merge with another configuration, with other taking precedence
-    pub fn merge(&mut self, other: &GenerateConfig) {
-        if other.project_path != default_project_path() {
-            self.project_path = other.project_path.clone();
+        if let Some(ref aliases) = self.aliases {
+            for (old_name, new_name) in aliases {
+                if old_name.trim().is_empty() || new_name.trim().is_empty() || old_name == new_name
+                {
+                    return Err(ConfigError::InvalidConfig(format!(
+                        "Invalid alias {} → {}: map an old command name to a different new one",
+                        old_name, new_name
+                    )));
+                }
+            }
         }
-        if other.output_path != default_output_path() {
-            self.output_path = other.output_path.clone();
+
+        if let Some(ref range_literals) = self.range_literals {
+            if let Some(selector) = range_literals
+                .keys()
+                .find(|selector| selector != &"*" && !selector.contains('.'))
+            {
+                return Err(ConfigError::InvalidConfig(format!(
+                    "Invalid range literals selector: {}. Use 'Type.field' or a '*' pattern",
+                    selector
+                )));
+            }
         }
-        if other.validation_library != default_validation_library() {
-            self.validation_library = other.validation_library.clone();
+
+        if let Some(ref shared_types) = self.shared_types {
+            for shared in shared_types {
+                if shared.path.trim().is_empty() || shared.package.trim().is_empty() {
+                    return Err(ConfigError::InvalidConfig(format!(
+                        "Invalid shared types {:?} → {:?}: set both a source path and a package",
+                        shared.path, shared.package
+                    )));
+                }
+            }
         }
-        if other.verbose.is_some() {
-            self.verbose = other.verbose;
+
+        if let Some(ref overrides) = self.field_overrides {
+            for (selector, field_override) in overrides {
+                let valid_selector = selector.split_once('.').is_some_and(|(type_name, field)| {
+                    !type_name.is_empty() && !field.is_empty() && !field.contains('.')
+                });
+                if !valid_selector {
+                    return Err(ConfigError::InvalidConfig(format!(
+                        "Invalid field override selector {}: use 'Type.field'",
+                        selector
+                    )));
+                }
+                if field_override.ts_type().trim().is_empty() {
+                    return Err(ConfigError::InvalidConfig(format!(
+                        "Field override for {} must name a TypeScript type",
+                        selector
+                    )));
+                }
+            }
         }
-        if other.visualize_deps.is_some() {
-            self.visualize_deps = other.visualize_deps;
+
+        if let Some(ref injected_types) = self.injected_types {
+            for type_path in injected_types {
+                if !type_path.contains("::") {
+                    return Err(ConfigError::InvalidConfig(format!(
+                        "Invalid injected type {}: use the full path, e.g. 'tauri::State'",
+                        type_path
+                    )));
+                }
+            }
         }
-        if other.include_private.is_some() {
-            self.include_private = other.include_private;
+
+        if let Some(ref mappings) = self.type_mappings {
+            for (rust_type, ts_type) in mappings {
+                if ts_type == TYPE_MAPPING_WILDCARD && !TypeResolver::is_type_pattern(rust_type) {
+                    return Err(ConfigError::InvalidConfig(format!(
+                        "Invalid type mapping for {}: '{}' requires a wildcard pattern like 'Arc<*>'",
+                        rust_type, ts_type
+                    )));
+                }
+            }
         }
-        if other.type_mappings.is_some() {
-            self.type_mappings = other.type_mappings.clone();
+
+        #[cfg(feature = "generators")]
+        self.validation_messages()?;
+
+        if let Some(ref viz_format) = self.viz_format {
+            if VizFormat::parse(viz_format).is_none() {
+                return Err(ConfigError::InvalidConfig(format!(
+                    "Invalid visualization format: {}. Use 'text', 'dot', 'html' or 'all'",
+                    viz_format
+                )));
+            }
         }
-        if other.exclude_patterns.is_some() {
-            self.exclude_patterns = other.exclude_patterns.clone();
+
+        if self.verbosity.is_some_and(|level| level > MAX_VERBOSITY) {
+            return Err(ConfigError::InvalidConfig(format!(
+                "verbosity must be at most {}",
+                MAX_VERBOSITY
+            )));
         }
-        if other.include_patterns.is_some() {
-            self.include_patterns = other.include_patterns.clone();
+
+        if let Some(invalid) = self
+            .trace
+            .iter()
+            .flatten()
+            .find(|phase| Phase::parse(phase).is_none())
+        {
+            return Err(ConfigError::InvalidConfig(format!(
+                "Invalid trace phase: {}. Use {}",
+                invalid,
+                Phase::NAMES.join(", ")
+            )));
         }
-        if other.force.is_some() {
-            self.force = other.force;
+
+        if self.max_scan_depth == Some(0) {
+            return Err(ConfigError::InvalidConfig(
+                "maxScanDepth must be at least 1".to_string(),
+            ));
         }
-    }
 
-    /// Get effective verbose setting
-    pub fn is_verbose(&self) -> bool {
-        self.verbose.unwrap_or(false)
-    }
+        if self.max_commands_per_file == Some(0) {
+            return Err(ConfigError::InvalidConfig(
+                "maxCommandsPerFile must be at least 1".to_string(),
+            ));
+        }
 
-    /// Get effective visualize_deps setting
-    pub fn should_visualize_deps(&self) -> bool {
-        self.visualize_deps.unwrap_or(false)
-    }
+        if let Some(ref http_bridge) = self.http_bridge {
+            if !http_bridge.ends_with(".rs") {
+                return Err(ConfigError::InvalidConfig(format!(
+                    "HTTP bridge must be a Rust file (.rs): {}",
+                    http_bridge
+                )));
+            }
+        }
 
-    /// Get effective include_private setting
-    pub fn should_include_private(&self) -> bool {
-        self.include_private.unwrap_or(false)
-    }
+        if let Some(ref admin_panel) = self.admin_panel {
+            if admin_panel.trim().is_empty() {
+                return Err(ConfigError::InvalidConfig(
+                    "Admin panel directory must not be empty".to_string(),
+                ));
+            }
+        }
 
-    /// Get effective force setting
+        if let Some(ref lint_config) = self.lint_config {
+            if !matches!(lint_config.as_str(), "eslint" | "biome") {
+                return Err(ConfigError::InvalidConfig(format!(
+                    "Invalid lint config: {}. Use 'eslint' or 'biome'",
+                    lint_config
+                )));
+            }
+        }
+
+        if let Some(ref framework) = self.framework {
+            if !FRAMEWORKS.contains(&framework.as_str()) {
+                return Err(ConfigError::InvalidConfig(format!(
+                    "Invalid framework: {}. Use one of: {}",
+                    framework,
+                    FRAMEWORKS.join(", ")
+                )));
+            }
+        }
+
+        if let Some(ref fallback) = self.unknown_type_fallback {
+            if !UNKNOWN_TYPE_FALLBACKS.contains(&fallback.as_str()) {
+                return Err(ConfigError::InvalidConfig(format!(
+                    "Invalid unknown type fallback: {}. Use one of: {}",
+                    fallback,
+                    UNKNOWN_TYPE_FALLBACKS.join(", ")
+                )));
+            }
+        }
+
+        if let Some(ref mode) = self.zod_object_mode {
+            if !ZOD_OBJECT_MODES.contains(&mode.as_str()) {
+                return Err(ConfigError::InvalidConfig(format!(
+                    "Invalid Zod object mode: {}. Use one of: {}",
+                    mode,
+                    ZOD_OBJECT_MODES.join(", ")
+                )));
+            }
+        }
+
+        if let Some(ref target) = self.target {
+            if !TARGETS.contains(&target.as_str()) {
+                return Err(ConfigError::InvalidConfig(format!(
+                    "Invalid target: {}. Use one of: {}",
+                    target,
+                    TARGETS.join(", ")
+                )));
+            }
+        }
+
+        if let Some(ref package) = self.kotlin_package {
+            let valid = package.split('.').all(|segment| {
+                segment.starts_with(|c: char| c.is_ascii_alphabetic() || c == '_')
+                    && segment
+                        .chars()
+                        .all(|c| c.is_ascii_alphanumeric() || c == '_')
+            });
+            if !valid {
+                return Err(ConfigError::InvalidConfig(format!(
+                    "Invalid Kotlin package: {}. Use dot-separated identifiers like com.example.app",
+                    package
+                )));
+            }
+        }
+
+        if let Some(ref stores) = self.stores {
+            if let Some((path, type_name)) = stores
+                .iter()
+                .find(|(path, type_name)| path.trim().is_empty() || type_name.trim().is_empty())
+            {
+                return Err(ConfigError::InvalidConfig(format!(
+                    "Invalid store: '{}' -> '{}'. Stores map a store file to a Rust type",
+                    path, type_name
+                )));
+            }
+        }
+
+        match (&self.compact_types, &self.compact_codec) {
+            (Some(types), _) if types.iter().any(|name| name.trim().is_empty()) => {
+                return Err(ConfigError::InvalidConfig(
+                    "Compact types must not be empty".to_string(),
+                ));
+            }
+            (Some(types), None) if !types.is_empty() => {
+                return Err(ConfigError::InvalidConfig(
+                    "compactTypes requires compactCodec, the Rust file of the codec".to_string(),
+                ));
+            }
+            (_, Some(codec)) if !codec.ends_with(".rs") => {
+                return Err(ConfigError::InvalidConfig(format!(
+                    "Compact codec must be a Rust file (.rs): {}",
+                    codec
+                )));
+            }
+            _ => {}
+        }
+
+        if let Some(ref self_test) = self.self_test {
+            if !self_test.ends_with(".rs") {
+                return Err(ConfigError::InvalidConfig(format!(
+                    "Self-test command must be a Rust file (.rs): {}",
+                    self_test
+                )));
+            }
+        }
+
+        if let Some(ref acronyms) = self.acronyms {
+            if let Some(invalid) = acronyms
+                .iter()
+                .find(|a| a.is_empty() || !a.chars().all(char::is_alphanumeric))
+            {
+                return Err(ConfigError::InvalidConfig(format!(
+                    "Invalid acronym: '{}'. Acronyms must be non-empty and alphanumeric",
+                    invalid
+                )));
+            }
+        }
+
+        if let Some(ref file_names) = self.output_file_names {
+            file_names.validate()?;
+        }
+
+        Ok(())
+    }
+
+    /// Validate the configuration, including that the project path exists
+    pub fn validate(&self) -> Result<(), ConfigError> {
+        self.validate_options()?;
+
+        // Validate paths exist
+        let project_path = Path::new(&self.project_path);
+        if !project_path.exists() {
+            return Err(ConfigError::InvalidConfig(format!(
+                "Project path does not exist: {}",
+                self.project_path
+            )));
+        }
+
+        Ok(())
+    }
+
+    /// Merge with another configuration, with other taking precedence
+    pub fn merge(&mut self, other: &GenerateConfig) {
+        if other.project_path != default_project_path() {
+            self.project_path = other.project_path.clone();
+        }
+        if other.output_path != default_output_path() {
+            self.output_path = other.output_path.clone();
+        }
+        if other.validation_library != default_validation_library() {
+            self.validation_library = other.validation_library.clone();
+        }
+        if other.verbose.is_some() {
+            self.verbose = other.verbose;
+        }
+        if other.verbosity.is_some() {
+            self.verbosity = other.verbosity;
+        }
+        if other.trace.is_some() {
+            self.trace = other.trace.clone();
+        }
+        if other.visualize_deps.is_some() {
+            self.visualize_deps = other.visualize_deps;
+        }
+        if other.viz_focus.is_some() {
+            self.viz_focus = other.viz_focus.clone();
+        }
+        if other.viz_depth.is_some() {
+            self.viz_depth = other.viz_depth;
+        }
+        if other.viz_format.is_some() {
+            self.viz_format = other.viz_format.clone();
+        }
+        if other.include_private.is_some() {
+            self.include_private = other.include_private;
+        }
+        if other.type_mappings.is_some() {
+            self.type_mappings = other.type_mappings.clone();
+        }
+        if other.serde_with_mappings.is_some() {
+            self.serde_with_mappings = other.serde_with_mappings.clone();
+        }
+        if other.collection_mappings.is_some() {
+            self.collection_mappings = other.collection_mappings.clone();
+        }
+        if other.union_types.is_some() {
+            self.union_types = other.union_types.clone();
+        }
+        if other.injected_types.is_some() {
+            self.injected_types = other.injected_types.clone();
+        }
+        if other.field_overrides.is_some() {
+            self.field_overrides = other.field_overrides.clone();
+        }
+        if other.command_options.is_some() {
+            self.command_options = other.command_options.clone();
+        }
+        if other.command_validation.is_some() {
+            self.command_validation = other.command_validation.clone();
+        }
+        if other.aliases.is_some() {
+            self.aliases = other.aliases.clone();
+        }
+        if other.range_literals.is_some() {
+            self.range_literals = other.range_literals.clone();
+        }
+        if other.validation_messages.is_some() {
+            self.validation_messages = other.validation_messages.clone();
+        }
+        if other.validation_messages_file.is_some() {
+            self.validation_messages_file = other.validation_messages_file.clone();
+        }
+        if other.non_empty_paths.is_some() {
+            self.non_empty_paths = other.non_empty_paths;
+        }
+        if other.enum_helpers.is_some() {
+            self.enum_helpers = other.enum_helpers;
+        }
+        if other.notify_commands.is_some() {
+            self.notify_commands = other.notify_commands;
+        }
+        if other.batch_notifications.is_some() {
+            self.batch_notifications = other.batch_notifications;
+        }
+        if other.global_declarations.is_some() {
+            self.global_declarations = other.global_declarations;
+        }
+        if other.schema_registry.is_some() {
+            self.schema_registry = other.schema_registry;
+        }
+        if other.form_hooks.is_some() {
+            self.form_hooks = other.form_hooks;
+        }
+        if other.xstate_actors.is_some() {
+            self.xstate_actors = other.xstate_actors;
+        }
+        if other.web_fallback.is_some() {
+            self.web_fallback = other.web_fallback;
+        }
+        if other.arbitraries.is_some() {
+            self.arbitraries = other.arbitraries;
+        }
+        if other.test_harness.is_some() {
+            self.test_harness = other.test_harness;
+        }
+        if other.mocks.is_some() {
+            self.mocks = other.mocks;
+        }
+        if other.binary_helpers.is_some() {
+            self.binary_helpers = other.binary_helpers;
+        }
+        if other.offline_queue.is_some() {
+            self.offline_queue = other.offline_queue;
+        }
+        if other.query_keys.is_some() {
+            self.query_keys = other.query_keys;
+        }
+        if other.source_trace.is_some() {
+            self.source_trace = other.source_trace;
+        }
+        if other.event_router.is_some() {
+            self.event_router = other.event_router;
+        }
+        if other.sanitize_params.is_some() {
+            self.sanitize_params = other.sanitize_params;
+        }
+        if other.all_types.is_some() {
+            self.all_types = other.all_types;
+        }
+        if other.error_classes.is_some() {
+            self.error_classes = other.error_classes;
+        }
+        if other.rename_map.is_some() {
+            self.rename_map = other.rename_map;
+        }
+        if other.max_commands_per_file.is_some() {
+            self.max_commands_per_file = other.max_commands_per_file;
+        }
+        if other.http_bridge.is_some() {
+            self.http_bridge = other.http_bridge.clone();
+        }
+        if other.admin_panel.is_some() {
+            self.admin_panel = other.admin_panel.clone();
+        }
+        if other.lint_config.is_some() {
+            self.lint_config = other.lint_config.clone();
+        }
+        if other.framework.is_some() {
+            self.framework = other.framework.clone();
+        }
+        if other.unknown_type_fallback.is_some() {
+            self.unknown_type_fallback = other.unknown_type_fallback.clone();
+        }
+        if other.zod_object_mode.is_some() {
+            self.zod_object_mode = other.zod_object_mode.clone();
+        }
+        if other.stores.is_some() {
+            self.stores = other.stores.clone();
+        }
+        if other.compact_types.is_some() {
+            self.compact_types = other.compact_types.clone();
+        }
+        if other.compact_codec.is_some() {
+            self.compact_codec = other.compact_codec.clone();
+        }
+        if other.self_test.is_some() {
+            self.self_test = other.self_test.clone();
+        }
+        if other.macro_records.is_some() {
+            self.macro_records = other.macro_records.clone();
+        }
+        if other.additional_source_paths.is_some() {
+            self.additional_source_paths = other.additional_source_paths.clone();
+        }
+        if other.shared_types.is_some() {
+            self.shared_types = other.shared_types.clone();
+        }
+        if other.strict_serde_derives.is_some() {
+            self.strict_serde_derives = other.strict_serde_derives;
+        }
+        if other.skip_zero_sized_fields.is_some() {
+            self.skip_zero_sized_fields = other.skip_zero_sized_fields;
+        }
+        if other.separate_input_types.is_some() {
+            self.separate_input_types = other.separate_input_types;
+        }
+        if other.target.is_some() {
+            self.target = other.target.clone();
+        }
+        if other.kotlin_package.is_some() {
+            self.kotlin_package = other.kotlin_package.clone();
+        }
+        if other.ipc_size_lint.is_some() {
+            self.ipc_size_lint = other.ipc_size_lint;
+        }
+        if other.allow_outside_repo.is_some() {
+            self.allow_outside_repo = other.allow_outside_repo;
+        }
+        if other.acronyms.is_some() {
+            self.acronyms = other.acronyms.clone();
+        }
+        if other.exclude_patterns.is_some() {
+            self.exclude_patterns = other.exclude_patterns.clone();
+        }
+        if other.include_patterns.is_some() {
+            self.include_patterns = other.include_patterns.clone();
+        }
+        if other.max_scan_depth.is_some() {
+            self.max_scan_depth = other.max_scan_depth;
+        }
+        if other.force.is_some() {
+            self.force = other.force;
+        }
+        if other.output_file_names.is_some() {
+            self.output_file_names = other.output_file_names.clone();
+        }
+        if other.hooks.is_some() {
+            self.hooks = other.hooks.clone();
+        }
+    }
+
+    /// Get effective verbose setting
+    pub fn is_verbose(&self) -> bool {
+        self.verbose.unwrap_or(false) || self.verbosity().is_verbose()
+    }
+
+    /// Verbosity level and traced phases; `verbose` counts as level 1
+    pub fn verbosity(&self) -> Verbosity {
+        let level = self
+            .verbosity
+            .unwrap_or(0)
+            .max(u8::from(self.verbose.unwrap_or(false)));
+        let traced = self
+            .trace
+            .iter()
+            .flatten()
+            .filter_map(|phase| Phase::parse(phase))
+            .collect();
+        Verbosity::new(level, traced)
+    }
+
+    /// Get effective visualize_deps setting
+    pub fn should_visualize_deps(&self) -> bool {
+        self.visualize_deps.unwrap_or(false)
+    }
+
+    /// Get the dependency graph visualization filter
+    pub fn viz_filter(&self) -> VizFilter {
+        VizFilter {
+            focus: self.viz_focus.clone(),
+            depth: self.viz_depth,
+        }
+    }
+
+    /// Get the dependency graph visualization formats to write
+    pub fn viz_formats(&self) -> Vec<VizFormat> {
+        self.viz_format
+            .as_deref()
+            .and_then(VizFormat::parse)
+            .unwrap_or_else(|| VizFormat::DEFAULT.to_vec())
+    }
+
+    /// Get effective include_private setting
+    pub fn should_include_private(&self) -> bool {
+        self.include_private.unwrap_or(false)
+    }
+
+    /// Load the validation message templates: the entries of `validation_messages_file`,
+    /// overridden by inline `validation_messages`
+    #[cfg(feature = "generators")]
+    pub fn validation_messages(&self) -> Result<ValidationMessages, ConfigError> {
+        let mut templates = std::collections::HashMap::new();
+        if let Some(ref file) = self.validation_messages_file {
+            let content = fs::read_to_string(file).map_err(|e| {
+                ConfigError::InvalidConfig(format!(
+                    "Failed to read validation messages file {}: {}",
+                    file, e
+                ))
+            })?;
+            templates = serde_json::from_str(&content)?;
+        }
+        if let Some(ref messages) = self.validation_messages {
+            templates.extend(messages.clone());
+        }
+
+        if let Some(key) = templates
+            .keys()
+            .find(|key| !ValidationMessages::KEYS.contains(&key.as_str()))
+        {
+            return Err(ConfigError::InvalidConfig(format!(
+                "Unknown validation message constraint: '{}'. Use one of {}",
+                key,
+                ValidationMessages::KEYS.join(", ")
+            )));
+        }
+
+        Ok(ValidationMessages::new(templates))
+    }
+
+    /// Get effective non_empty_paths setting
+    pub fn should_require_non_empty_paths(&self) -> bool {
+        self.non_empty_paths.unwrap_or(false)
+    }
+
+    /// Get effective enum_helpers setting
+    pub fn should_generate_enum_helpers(&self) -> bool {
+        self.enum_helpers.unwrap_or(false)
+    }
+
+    /// Get effective notify_commands setting
+    pub fn should_generate_notify_commands(&self) -> bool {
+        self.notify_commands.unwrap_or(false)
+    }
+
+    /// Get effective batch_notifications setting
+    pub fn should_batch_notifications(&self) -> bool {
+        self.batch_notifications.unwrap_or(false)
+    }
+
+    /// Get effective global_declarations setting
+    pub fn should_generate_global_declarations(&self) -> bool {
+        self.global_declarations.unwrap_or(false)
+    }
+
+    /// Get effective schema_registry setting
+    pub fn should_generate_schema_registry(&self) -> bool {
+        self.schema_registry.unwrap_or(false)
+    }
+
+    /// Get effective form_hooks setting
+    pub fn should_generate_form_hooks(&self) -> bool {
+        self.form_hooks.unwrap_or(false)
+    }
+
+    /// Get effective xstate_actors setting
+    pub fn should_generate_xstate_actors(&self) -> bool {
+        self.xstate_actors.unwrap_or(false)
+    }
+
+    /// Get effective web_fallback setting
+    pub fn should_generate_web_fallback(&self) -> bool {
+        self.web_fallback.unwrap_or(false)
+    }
+
+    /// Get effective arbitraries setting
+    pub fn should_generate_arbitraries(&self) -> bool {
+        self.arbitraries.unwrap_or(false)
+    }
+
+    /// Get effective test_harness setting
+    pub fn should_generate_test_harness(&self) -> bool {
+        self.test_harness.unwrap_or(false)
+    }
+
+    /// Get effective mocks setting
+    pub fn should_generate_mocks(&self) -> bool {
+        self.mocks.unwrap_or(false)
+    }
+
+    /// Get effective binary_helpers setting
+    pub fn should_generate_binary_helpers(&self) -> bool {
+        self.binary_helpers.unwrap_or(false)
+    }
+
+    /// Get effective offline_queue setting
+    pub fn should_generate_offline_queue(&self) -> bool {
+        self.offline_queue.unwrap_or(false)
+    }
+
+    /// Get effective query_keys setting
+    pub fn should_generate_query_keys(&self) -> bool {
+        // The React Query hooks key their queries with the factories
+        self.query_keys.unwrap_or(false) || self.framework() == Some("react-query")
+    }
+
+    /// Get the frontend framework hooks are generated for
+    pub fn framework(&self) -> Option<&str> {
+        self.framework.as_deref()
+    }
+
+    /// Get the type referenced types without a definition are generated as, `"unknown"`
+    /// unless configured; `"error"` fails generation instead
+    pub fn unknown_type_fallback(&self) -> &str {
+        self.unknown_type_fallback.as_deref().unwrap_or("unknown")
+    }
+
+    /// Get how Zod object schemas treat unknown keys, `"strip"` unless configured
+    pub fn zod_object_mode(&self) -> &str {
+        self.zod_object_mode.as_deref().unwrap_or("strip")
+    }
+
+    /// Get the language of the generated bindings, `"typescript"` unless configured
+    pub fn target(&self) -> &str {
+        self.target.as_deref().unwrap_or("typescript")
+    }
+
+    /// Get the package of the generated Kotlin files, `tauri.typegen` unless configured
+    pub fn kotlin_package(&self) -> &str {
+        self.kotlin_package.as_deref().unwrap_or("tauri.typegen")
+    }
+
+    /// Get the configured stores as `(store file, Rust type)`, ordered by store file
+    pub fn stores(&self) -> Vec<(&str, &str)> {
+        let mut stores: Vec<(&str, &str)> = self
+            .stores
+            .iter()
+            .flatten()
+            .map(|(path, type_name)| (path.as_str(), type_name.as_str()))
+            .collect();
+        stores.sort();
+        stores
+    }
+
+    /// Get the types sent in the compact layout, empty without a codec to decode them
+    pub fn compact_types(&self) -> Vec<&str> {
+        if self.compact_codec.is_none() {
+            return Vec::new();
+        }
+        self.compact_types
+            .iter()
+            .flatten()
+            .map(String::as_str)
+            .collect()
+    }
+
+    /// Type mappings resolving `Compact<T>` parameters and return types to `T` while a
+    /// compact codec is generated
+    pub fn compact_type_mappings(&self) -> std::collections::HashMap<String, String> {
+        let mut mappings = std::collections::HashMap::new();
+        if self.compact_codec.is_some() {
+            mappings.insert(
+                format!("Compact<{}>", TYPE_MAPPING_WILDCARD),
+                TYPE_MAPPING_WILDCARD.to_string(),
+            );
+        }
+        mappings
+    }
+
+    /// Get effective source_trace setting
+    pub fn should_generate_source_trace(&self) -> bool {
+        self.source_trace.unwrap_or(false)
+    }
+
+    /// Get effective event_router setting
+    pub fn should_generate_event_router(&self) -> bool {
+        self.event_router.unwrap_or(false)
+    }
+
+    /// Get effective sanitize_params setting
+    pub fn should_generate_sanitize_params(&self) -> bool {
+        self.sanitize_params.unwrap_or(false)
+    }
+
+    /// Get effective all_types setting
+    pub fn should_include_all_types(&self) -> bool {
+        self.all_types.unwrap_or(false)
+    }
+
+    /// Get effective error_classes setting
+    pub fn should_generate_error_classes(&self) -> bool {
+        self.error_classes.unwrap_or(false)
+    }
+
+    /// Get effective rename_map setting
+    pub fn should_generate_rename_map(&self) -> bool {
+        self.rename_map.unwrap_or(false)
+    }
+
+    /// Get effective strict_serde_derives setting
+    pub fn should_enforce_serde_derives(&self) -> bool {
+        self.strict_serde_derives.unwrap_or(false)
+    }
+
+    /// Get effective skip_zero_sized_fields setting
+    pub fn should_skip_zero_sized_fields(&self) -> bool {
+        self.skip_zero_sized_fields.unwrap_or(true)
+    }
+
+    /// Get effective separate_input_types setting
+    pub fn should_separate_input_types(&self) -> bool {
+        self.separate_input_types.unwrap_or(false)
+    }
+
+    /// Get effective ipc_size_lint setting
+    pub fn should_lint_ipc_size(&self) -> bool {
+        self.ipc_size_lint.unwrap_or(true)
+    }
+
+    /// Get effective allow_outside_repo setting
+    pub fn should_allow_outside_repo(&self) -> bool {
+        self.allow_outside_repo.unwrap_or(false)
+    }
+
+    /// Field override for a `Type.field` selector
+    pub fn field_override(&self, selector: &str) -> Option<&FieldOverride> {
+        self.field_overrides.as_ref()?.get(selector)
+    }
+
+    /// Options configured for a command
+    pub fn command_options(&self, command: &str) -> Option<&CommandOptions> {
+        self.command_options.as_ref()?.get(command)
+    }
+
+    /// Validation configured for a command: the `command_validation` entry of its name,
+    /// otherwise of the longest pattern matching it
+    pub fn command_validation(&self, command: &str) -> Option<&str> {
+        let command_validation = self.command_validation.as_ref()?;
+        command_validation
+            .get(command)
+            .or_else(|| {
+                command_validation
+                    .iter()
+                    .filter(|(pattern, _)| {
+                        pattern.contains('*') && match_wildcard(pattern, command)
+                    })
+                    .max_by_key(|(pattern, _)| (pattern.len(), pattern.as_str()))
+                    .map(|(_, validation)| validation)
+            })
+            .map(String::as_str)
+    }
+
+    /// Largest range spelled out as literals for a field: the `range_literals` entry
+    /// of `Type.field`, otherwise of the longest `*` pattern matching it
+    pub fn range_literal_limit(&self, type_name: &str, field: &str) -> Option<u32> {
+        let range_literals = self.range_literals.as_ref()?;
+        let selector = format!("{}.{}", type_name, field);
+        range_literals
+            .get(&selector)
+            .or_else(|| {
+                range_literals
+                    .iter()
+                    .filter(|(pattern, _)| {
+                        pattern.contains('*') && match_wildcard(pattern, &selector)
+                    })
+                    .max_by_key(|(pattern, _)| (pattern.len(), pattern.as_str()))
+                    .map(|(_, limit)| limit)
+            })
+            .copied()
+    }
+
+    /// Package of the `shared_types` entry whose source path contains `file_path`
+    pub fn shared_types_package(&self, file_path: &str) -> Option<&str> {
+        self.shared_types
+            .iter()
+            .flatten()
+            .find(|shared| std::path::Path::new(file_path).starts_with(&shared.path))
+            .map(|shared| shared.package.as_str())
+    }
+
+    /// Whether the schema bindings validate a command's parameters: as configured for
+    /// the command, otherwise unless the schema library is only used for the commands
+    /// configured
+    pub fn validates_command(&self, command: &str) -> bool {
+        match self.command_validation(command) {
+            Some(validation) => validation != "none",
+            None => self.command_validation.is_none() || self.validation_library != "none",
+        }
+    }
+
+    /// Library of the generated bindings: the schema library any command is validated
+    /// with, so validated and unvalidated commands share the same `types.ts`
+    pub fn generator_library(&self) -> &str {
+        self.command_validation
+            .iter()
+            .flat_map(|validation| validation.values())
+            .map(String::as_str)
+            .find(|library| *library != "none")
+            .unwrap_or(&self.validation_library)
+    }
+
+    /// Whether `validation_library` names a built-in generator or one registered through
+    /// [`crate::api::register_generator`]
+    pub fn has_known_generator(&self) -> bool {
+        match self.validation_library.as_str() {
+            "zod" | "valibot" | "none" => true,
+            #[cfg(feature = "generators")]
+            name => crate::generators::registry::is_registered(name),
+            #[cfg(not(feature = "generators"))]
+            _ => false,
+        }
+    }
+
+    /// Whether the generated bindings export a schema next to every type (zod and
+    /// valibot)
+    pub fn uses_schemas(&self) -> bool {
+        matches!(self.generator_library(), "zod" | "valibot")
+    }
+
+    /// How the binding of a command queues concurrent calls: the configured
+    /// `serializeCalls`, otherwise the command's `#[typegen(serialize_calls)]` attribute
+    pub fn serialize_calls(&self, command: &CommandInfo) -> Option<CallSerialization> {
+        match self
+            .command_options(&command.name)
+            .and_then(|options| options.serialize_calls.as_ref())
+        {
+            Some(SerializeCalls::Enabled(true)) => Some(CallSerialization::Command),
+            Some(SerializeCalls::Enabled(false)) => None,
+            Some(SerializeCalls::Key(key)) => Some(CallSerialization::Key(key.clone())),
+            None => command.serialize_calls.clone(),
+        }
+    }
+
+    /// Whether a command reads or changes state: the configured `kind`, otherwise
+    /// inferred from its name
+    pub fn command_kind(&self, command: &CommandInfo) -> CommandKind {
+        self.command_options(&command.name)
+            .and_then(|options| options.kind)
+            .unwrap_or_else(|| CommandKind::infer(&command.name))
+    }
+
+    /// Query commands a successful call of `command` makes stale: the configured
+    /// `invalidates`, otherwise the command's `#[typegen(invalidates)]` attributes
+    pub fn invalidates<'a>(&'a self, command: &'a CommandInfo) -> &'a [String] {
+        self.command_options(&command.name)
+            .and_then(|options| options.invalidates.as_deref())
+            .unwrap_or(&command.invalidates)
+    }
+
+    pub fn acronyms(&self) -> &[String] {
+        self.acronyms.as_deref().unwrap_or_default()
+    }
+
+    /// Get effective force setting
     pub fn should_force(&self) -> bool {
         self.force.unwrap_or(false)
     }
-}
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-    use tempfile::NamedTempFile;
+    /// Get effective output file names
+    pub fn file_names(&self) -> OutputFileNames {
+        self.output_file_names.clone().unwrap_or_default()
+    }
+
+    /// Get configured generation hooks
+    pub fn hooks(&self) -> GenerationHooks {
+        self.hooks.clone().unwrap_or_default()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::NamedTempFile;
+
+    #[test]
+    fn test_default_config() {
+        let config = GenerateConfig::default();
+        assert_eq!(config.project_path, "./src-tauri");
+        assert_eq!(config.output_path, "./src/generated");
+        assert_eq!(config.validation_library, "none");
+        assert!(!config.is_verbose());
+        assert!(!config.should_visualize_deps());
+        assert!(!config.should_include_private());
+        assert!(!config.should_force());
+    }
+
+    #[test]
+    fn test_config_validation() {
+        let config = GenerateConfig {
+            validation_library: "invalid".to_string(),
+            ..Default::default()
+        };
+
+        let result = config.validate();
+        assert!(result.is_err());
+        if let Err(ConfigError::InvalidValidationLibrary(lib)) = result {
+            assert_eq!(lib, "invalid");
+        } else {
+            panic!("Expected InvalidValidationLibrary error");
+        }
+    }
+
+    #[test]
+    fn test_config_merge() {
+        let mut base = GenerateConfig::default();
+        let override_config = GenerateConfig {
+            output_path: "./custom".to_string(),
+            verbose: Some(true),
+            ..Default::default()
+        };
+
+        base.merge(&override_config);
+        assert_eq!(base.output_path, "./custom");
+        assert!(base.is_verbose());
+        assert_eq!(base.validation_library, "none"); // Should remain default
+    }
+
+    #[test]
+    fn test_save_and_load_config() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let project_path = temp_dir.path().join("src-tauri");
+        std::fs::create_dir_all(&project_path).unwrap();
+
+        let config = GenerateConfig {
+            project_path: project_path.to_string_lossy().to_string(),
+            output_path: "./test".to_string(),
+            verbose: Some(true),
+            ..Default::default()
+        };
+
+        let temp_file = NamedTempFile::new().unwrap();
+        config.save_to_file(temp_file.path()).unwrap();
+
+        let loaded_config = GenerateConfig::from_file(temp_file.path()).unwrap();
+        assert_eq!(loaded_config.output_path, "./test");
+        assert!(loaded_config.is_verbose());
+    }
+
+    #[test]
+    fn test_loaders_parse_without_validating() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let config_path = temp_dir.path().join("typegen.json");
+        std::fs::write(
+            &config_path,
+            r#"{ "project_path": "./missing", "validation_library": "zod" }"#,
+        )
+        .unwrap();
+        let tauri_conf_path = temp_dir.path().join("tauri.conf.json");
+        std::fs::write(
+            &tauri_conf_path,
+            r#"{ "plugins": { "typegen": { "projectPath": "./missing", "validationLibrary": "yup" } } }"#,
+        )
+        .unwrap();
+
+        // Generating from an IR needs the options but no project sources
+        let config = GenerateConfig::from_file(&config_path).unwrap();
+        assert!(config.validate_options().is_ok());
+        assert!(config
+            .validate()
+            .unwrap_err()
+            .to_string()
+            .contains("Project path does not exist"));
+
+        let config = GenerateConfig::from_tauri_config(&tauri_conf_path)
+            .unwrap()
+            .unwrap();
+        assert!(matches!(
+            config.validate_options(),
+            Err(ConfigError::InvalidValidationLibrary(library)) if library == "yup"
+        ));
+
+        std::fs::write(&config_path, "{ not json").unwrap();
+        assert!(GenerateConfig::from_file(&config_path).is_err());
+    }
+
+    #[test]
+    fn test_save_to_tauri_config_preserves_existing_content() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let project_path = temp_dir.path().join("src-tauri");
+        std::fs::create_dir_all(&project_path).unwrap();
+
+        let tauri_conf_path = temp_dir.path().join("tauri.conf.json");
+
+        // Create existing tauri.conf.json with some content
+        let existing_content = serde_json::json!({
+            "package": {
+                "productName": "My App",
+                "version": "1.0.0"
+            },
+            "tauri": {
+                "allowlist": {
+                    "all": false
+                }
+            },
+            "plugins": {
+                "shell": {
+                    "all": false
+                }
+            }
+        });
+
+        fs::write(
+            &tauri_conf_path,
+            serde_json::to_string_pretty(&existing_content).unwrap(),
+        )
+        .unwrap();
+
+        let config = GenerateConfig {
+            project_path: project_path.to_string_lossy().to_string(),
+            output_path: "./test".to_string(),
+            validation_library: "zod".to_string(),
+            verbose: Some(true),
+            ..Default::default()
+        };
+
+        // Save to tauri config - should preserve existing content
+        config.save_to_tauri_config(&tauri_conf_path).unwrap();
+
+        // Read back and verify
+        let updated_content = fs::read_to_string(&tauri_conf_path).unwrap();
+        let updated_json: serde_json::Value = serde_json::from_str(&updated_content).unwrap();
+
+        // Check that existing content is preserved
+        assert_eq!(updated_json["package"]["productName"], "My App");
+        assert_eq!(updated_json["package"]["version"], "1.0.0");
+        assert_eq!(updated_json["tauri"]["allowlist"]["all"], false);
+        assert_eq!(updated_json["plugins"]["shell"]["all"], false);
+
+        // Check that typegen config was added
+        assert_eq!(updated_json["plugins"]["typegen"]["outputPath"], "./test");
+        assert_eq!(
+            updated_json["plugins"]["typegen"]["validationLibrary"],
+            "zod"
+        );
+        assert_eq!(updated_json["plugins"]["typegen"]["verbose"], true);
+    }
+
+    #[test]
+    fn test_save_to_tauri_config_creates_plugins_section() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let project_path = temp_dir.path().join("src-tauri");
+        std::fs::create_dir_all(&project_path).unwrap();
+
+        let tauri_conf_path = temp_dir.path().join("tauri.conf.json");
+
+        // Create existing tauri.conf.json without plugins section
+        let existing_content = serde_json::json!({
+            "package": {
+                "productName": "My App",
+                "version": "1.0.0"
+            },
+            "tauri": {
+                "allowlist": {
+                    "all": false
+                }
+            }
+        });
+
+        fs::write(
+            &tauri_conf_path,
+            serde_json::to_string_pretty(&existing_content).unwrap(),
+        )
+        .unwrap();
+
+        let config = GenerateConfig {
+            project_path: project_path.to_string_lossy().to_string(),
+            output_path: "./test".to_string(),
+            validation_library: "none".to_string(),
+            ..Default::default()
+        };
+
+        // Save to tauri config - should create plugins section
+        config.save_to_tauri_config(&tauri_conf_path).unwrap();
+
+        // Read back and verify
+        let updated_content = fs::read_to_string(&tauri_conf_path).unwrap();
+        let updated_json: serde_json::Value = serde_json::from_str(&updated_content).unwrap();
+
+        // Check that existing content is preserved
+        assert_eq!(updated_json["package"]["productName"], "My App");
+        assert_eq!(updated_json["tauri"]["allowlist"]["all"], false);
+
+        // Check that plugins section was created with typegen config
+        assert!(updated_json["plugins"].is_object());
+        assert_eq!(updated_json["plugins"]["typegen"]["outputPath"], "./test");
+        assert_eq!(
+            updated_json["plugins"]["typegen"]["validationLibrary"],
+            "none"
+        );
+    }
+
+    #[test]
+    fn test_config_validation_rejects_pass_through_without_wildcard() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let mut config = GenerateConfig {
+            project_path: temp_dir.path().to_string_lossy().to_string(),
+            type_mappings: Some([("Arc".to_string(), "*".to_string())].into_iter().collect()),
+            ..Default::default()
+        };
+        assert!(matches!(
+            config.validate(),
+            Err(ConfigError::InvalidConfig(_))
+        ));
+
+        config.type_mappings = Some(
+            [("Arc<*>".to_string(), "*".to_string())]
+                .into_iter()
+                .collect(),
+        );
+        assert!(config.validate().is_ok());
+    }
+
+    #[test]
+    fn test_config_validation_rejects_invalid_field_override_selectors() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let overrides: std::collections::HashMap<String, FieldOverride> = serde_json::from_str(
+            r#"{"User": "Date", "Order.total": {"type": "Money", "from": "../money"}}"#,
+        )
+        .unwrap();
+        assert_eq!(
+            overrides["Order.total"],
+            FieldOverride::Imported {
+                ts_type: "Money".to_string(),
+                from: "../money".to_string(),
+            }
+        );
+
+        let mut config = GenerateConfig {
+            project_path: temp_dir.path().to_string_lossy().to_string(),
+            field_overrides: Some(overrides),
+            ..Default::default()
+        };
+        assert!(matches!(
+            config.validate(),
+            Err(ConfigError::InvalidConfig(_))
+        ));
+
+        config.field_overrides.as_mut().unwrap().remove("User");
+        assert!(config.validate().is_ok());
+    }
+
+    #[test]
+    fn test_config_validation_requires_injected_type_paths() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let mut config = GenerateConfig {
+            project_path: temp_dir.path().to_string_lossy().to_string(),
+            injected_types: Some(vec!["Store".to_string()]),
+            ..Default::default()
+        };
+        assert!(matches!(
+            config.validate(),
+            Err(ConfigError::InvalidConfig(_))
+        ));
+
+        config.injected_types = Some(vec!["tauri_plugin_store::Store".to_string()]);
+        assert!(config.validate().is_ok());
+    }
+
+    #[test]
+    fn test_config_validation_rejects_invalid_acronyms() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let mut config = GenerateConfig {
+            project_path: temp_dir.path().to_string_lossy().to_string(),
+            acronyms: Some(vec!["URL".to_string(), "I/O".to_string()]),
+            ..Default::default()
+        };
+        assert!(matches!(
+            config.validate(),
+            Err(ConfigError::InvalidConfig(_))
+        ));
+
+        config.acronyms = Some(vec!["URL".to_string(), "IO".to_string()]);
+        assert!(config.validate().is_ok());
+    }
+
+    #[test]
+    fn test_config_validation_rejects_unknown_collection_kind() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let mut mappings = std::collections::HashMap::new();
+        mappings.insert("ImVector".to_string(), "tree".to_string());
+
+        let config = GenerateConfig {
+            project_path: temp_dir.path().to_string_lossy().to_string(),
+            collection_mappings: Some(mappings),
+            ..Default::default()
+        };
+
+        assert!(matches!(
+            config.validate(),
+            Err(ConfigError::InvalidConfig(_))
+        ));
+    }
+
+    #[test]
+    fn test_readme_examples_use_the_typegen_plugin_key() {
+        let readme = include_str!("../../README.md");
+        assert!(!readme.contains("\"tauri-typegen\": {"));
+    }
+
+    #[test]
+    fn test_non_empty_paths_from_tauri_config() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let tauri_conf_path = temp_dir.path().join("tauri.conf.json");
+        let content = serde_json::json!({
+            "plugins": {
+                "typegen": {
+                    "projectPath": temp_dir.path().to_string_lossy(),
+                    "nonEmptyPaths": true
+                }
+            }
+        });
+        fs::write(&tauri_conf_path, content.to_string()).unwrap();
+
+        let config = GenerateConfig::from_tauri_config(&tauri_conf_path)
+            .unwrap()
+            .unwrap();
+        assert!(config.should_require_non_empty_paths());
+        assert!(!GenerateConfig::default().should_require_non_empty_paths());
+    }
+
+    #[test]
+    fn test_notify_commands_from_tauri_config() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let tauri_conf_path = temp_dir.path().join("tauri.conf.json");
+        let content = serde_json::json!({
+            "plugins": {
+                "typegen": {
+                    "projectPath": temp_dir.path().to_string_lossy(),
+                    "notifyCommands": true,
+                    "batchNotifications": true
+                }
+            }
+        });
+        fs::write(&tauri_conf_path, content.to_string()).unwrap();
+
+        let config = GenerateConfig::from_tauri_config(&tauri_conf_path)
+            .unwrap()
+            .unwrap();
+        assert!(config.should_generate_notify_commands());
+        assert!(config.should_batch_notifications());
+        assert!(!GenerateConfig::default().should_generate_notify_commands());
+        assert!(!GenerateConfig::default().should_batch_notifications());
+    }
+
+    #[test]
+    fn test_global_declarations_from_tauri_config() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let tauri_conf_path = temp_dir.path().join("tauri.conf.json");
+        let content = serde_json::json!({
+            "plugins": {
+                "typegen": {
+                    "projectPath": temp_dir.path().to_string_lossy(),
+                    "globalDeclarations": true
+                }
+            }
+        });
+        fs::write(&tauri_conf_path, content.to_string()).unwrap();
+
+        let config = GenerateConfig::from_tauri_config(&tauri_conf_path)
+            .unwrap()
+            .unwrap();
+        assert!(config.should_generate_global_declarations());
+        assert!(!GenerateConfig::default().should_generate_global_declarations());
+    }
+
+    #[test]
+    fn test_strict_serde_derives_from_tauri_config() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let tauri_conf_path = temp_dir.path().join("tauri.conf.json");
+        let content = serde_json::json!({
+            "plugins": {
+                "typegen": {
+                    "projectPath": temp_dir.path().to_string_lossy(),
+                    "strictSerdeDerives": true
+                }
+            }
+        });
+        fs::write(&tauri_conf_path, content.to_string()).unwrap();
+
+        let config = GenerateConfig::from_tauri_config(&tauri_conf_path)
+            .unwrap()
+            .unwrap();
+        assert!(config.should_enforce_serde_derives());
+        assert!(!GenerateConfig::default().should_enforce_serde_derives());
+    }
+
+    #[test]
+    fn test_skip_zero_sized_fields_from_tauri_config() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let tauri_conf_path = temp_dir.path().join("tauri.conf.json");
+        let content = serde_json::json!({
+            "plugins": {
+                "typegen": {
+                    "projectPath": temp_dir.path().to_string_lossy(),
+                    "skipZeroSizedFields": false
+                }
+            }
+        });
+        fs::write(&tauri_conf_path, content.to_string()).unwrap();
+
+        let config = GenerateConfig::from_tauri_config(&tauri_conf_path)
+            .unwrap()
+            .unwrap();
+        assert!(!config.should_skip_zero_sized_fields());
+        assert!(GenerateConfig::default().should_skip_zero_sized_fields());
+    }
+
+    #[test]
+    fn test_separate_input_types_from_tauri_config() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let tauri_conf_path = temp_dir.path().join("tauri.conf.json");
+        let content = serde_json::json!({
+            "plugins": {
+                "typegen": {
+                    "projectPath": temp_dir.path().to_string_lossy(),
+                    "separateInputTypes": true
+                }
+            }
+        });
+        fs::write(&tauri_conf_path, content.to_string()).unwrap();
+
+        let config = GenerateConfig::from_tauri_config(&tauri_conf_path)
+            .unwrap()
+            .unwrap();
+        assert!(config.should_separate_input_types());
+        assert!(!GenerateConfig::default().should_separate_input_types());
+    }
+
+    #[test]
+    fn test_serde_with_mappings_from_tauri_config() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let tauri_conf_path = temp_dir.path().join("tauri.conf.json");
+        let content = serde_json::json!({
+            "plugins": {
+                "typegen": {
+                    "projectPath": temp_dir.path().to_string_lossy(),
+                    "serdeWithMappings": { "chrono::serde::ts_seconds": "number" }
+                }
+            }
+        });
+        fs::write(&tauri_conf_path, content.to_string()).unwrap();
+
+        let config = GenerateConfig::from_tauri_config(&tauri_conf_path)
+            .unwrap()
+            .unwrap();
+        let mappings = config.serde_with_mappings.unwrap();
+        assert_eq!(mappings["chrono::serde::ts_seconds"], "number");
+    }
+
+    #[test]
+    fn test_ipc_size_lint_from_tauri_config() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let tauri_conf_path = temp_dir.path().join("tauri.conf.json");
+        let content = serde_json::json!({
+            "plugins": {
+                "typegen": {
+                    "projectPath": temp_dir.path().to_string_lossy(),
+                    "ipcSizeLint": false
+                }
+            }
+        });
+        fs::write(&tauri_conf_path, content.to_string()).unwrap();
+
+        let config = GenerateConfig::from_tauri_config(&tauri_conf_path)
+            .unwrap()
+            .unwrap();
+        assert!(!config.should_lint_ipc_size());
+        assert!(GenerateConfig::default().should_lint_ipc_size());
+    }
+
+    #[test]
+    fn test_extends_deep_merges_base_config() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let project_path = temp_dir.path().to_string_lossy().to_string();
+        fs::create_dir(temp_dir.path().join("app")).unwrap();
+        fs::write(
+            temp_dir.path().join("typegen.base.json"),
+            serde_json::json!({
+                "project_path": project_path,
+                "validation_library": "zod",
+                "type_mappings": { "DateTime<Utc>": "string", "Uuid": "string" }
+            })
+            .to_string(),
+        )
+        .unwrap();
+        let config_path = temp_dir.path().join("app/typegen.json");
+        fs::write(
+            &config_path,
+            serde_json::json!({
+                "extends": "../typegen.base.json",
+                "output_path": "./bindings",
+                "type_mappings": { "Uuid": "UUID" }
+            })
+            .to_string(),
+        )
+        .unwrap();
+
+        let config = GenerateConfig::from_file(&config_path).unwrap();
+
+        assert_eq!(config.project_path, project_path);
+        assert_eq!(config.validation_library, "zod");
+        assert_eq!(config.output_path, "./bindings");
+        let mappings = config.type_mappings.unwrap();
+        assert_eq!(mappings["DateTime<Utc>"], "string");
+        assert_eq!(mappings["Uuid"], "UUID");
+    }
+
+    #[test]
+    fn test_tauri_config_extends_standalone_config() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        fs::write(
+            temp_dir.path().join("typegen.base.json"),
+            serde_json::json!({
+                "validation_library": "zod",
+                "enum_helpers": true
+            })
+            .to_string(),
+        )
+        .unwrap();
+        let tauri_conf_path = temp_dir.path().join("tauri.conf.json");
+        let content = serde_json::json!({
+            "plugins": {
+                "typegen": {
+                    "extends": "./typegen.base.json",
+                    "projectPath": temp_dir.path().to_string_lossy()
+                }
+            }
+        });
+        fs::write(&tauri_conf_path, content.to_string()).unwrap();
+
+        let config = GenerateConfig::from_tauri_config(&tauri_conf_path)
+            .unwrap()
+            .unwrap();
+
+        assert_eq!(config.validation_library, "zod");
+        assert!(config.should_generate_enum_helpers());
+    }
+
+    #[test]
+    fn test_extends_rebases_base_paths_onto_base_directory() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let root = temp_dir.path();
+        for dir in [
+            "app",
+            "src-tauri",
+            "configs/generated",
+            "configs/shared/locales",
+            "crates/shared",
+        ] {
+            fs::create_dir_all(root.join(dir)).unwrap();
+        }
+        fs::write(root.join("configs/shared/locales/de.json"), "{}").unwrap();
+        fs::write(
+            root.join("configs/shared/typegen.root.json"),
+            serde_json::json!({
+                "project_path": "../../src-tauri",
+                "validation_messages_file": "locales/de.json",
+                "macro_records": "${outDir}/tauri-typegen"
+            })
+            .to_string(),
+        )
+        .unwrap();
+        fs::write(
+            root.join("configs/typegen.base.json"),
+            serde_json::json!({
+                "extends": "./shared/typegen.root.json",
+                "output_path": "./generated",
+                "additional_source_paths": [{ "path": "../crates/shared" }]
+            })
+            .to_string(),
+        )
+        .unwrap();
+        let config_path = root.join("app/typegen.json");
+        fs::write(
+            &config_path,
+            serde_json::json!({ "extends": "../configs/typegen.base.json" }).to_string(),
+        )
+        .unwrap();
+        let tauri_conf_path = root.join("app/tauri.conf.json");
+        fs::write(
+            &tauri_conf_path,
+            serde_json::json!({
+                "plugins": { "typegen": { "extends": "../configs/typegen.base.json" } }
+            })
+            .to_string(),
+        )
+        .unwrap();
+        let same_dir = |path: &str, expected: &str| {
+            assert_eq!(
+                Path::new(path).canonicalize().unwrap(),
+                root.join(expected).canonicalize().unwrap(),
+                "{}",
+                path
+            );
+        };
+
+        let config = GenerateConfig::from_file(&config_path).unwrap();
+
+        same_dir(&config.project_path, "src-tauri");
+        same_dir(&config.output_path, "configs/generated");
+        same_dir(
+            config.validation_messages_file.as_deref().unwrap(),
+            "configs/shared/locales/de.json",
+        );
+        same_dir(
+            &config.additional_source_paths.unwrap()[0].path,
+            "crates/shared",
+        );
+        assert_eq!(
+            config.macro_records.as_deref(),
+            Some("${outDir}/tauri-typegen")
+        );
+
+        let tauri_config = GenerateConfig::from_tauri_config(&tauri_conf_path)
+            .unwrap()
+            .unwrap();
+        same_dir(&tauri_config.project_path, "src-tauri");
+        same_dir(&tauri_config.output_path, "configs/generated");
+    }
+
+    #[test]
+    fn test_extends_rejects_cycles() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let a = temp_dir.path().join("a.json");
+        let b = temp_dir.path().join("b.json");
+        fs::write(&a, r#"{"extends": "./b.json"}"#).unwrap();
+        fs::write(&b, r#"{"extends": "./a.json"}"#).unwrap();
+
+        let error = GenerateConfig::from_file(&a).unwrap_err().to_string();
+
+        assert!(error.contains("extends itself"));
+        assert!(error.contains("a.json -> "));
+    }
+
+    #[test]
+    fn test_hooks_from_tauri_config() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let tauri_conf_path = temp_dir.path().join("tauri.conf.json");
+        let content = serde_json::json!({
+            "plugins": {
+                "typegen": {
+                    "projectPath": temp_dir.path().to_string_lossy(),
+                    "hooks": { "after": "pnpm format:generated" }
+                }
+            }
+        });
+        fs::write(&tauri_conf_path, content.to_string()).unwrap();
+
+        let config = GenerateConfig::from_tauri_config(&tauri_conf_path)
+            .unwrap()
+            .unwrap();
+        assert_eq!(config.hooks().before, None);
+        assert_eq!(
+            config.hooks().after.as_deref(),
+            Some("pnpm format:generated")
+        );
+    }
+
+    #[test]
+    fn test_additional_source_paths_from_tauri_config() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let tauri_conf_path = temp_dir.path().join("tauri.conf.json");
+        let content = serde_json::json!({
+            "plugins": {
+                "typegen": {
+                    "projectPath": temp_dir.path().to_string_lossy(),
+                    "additionalSourcePaths": [
+                        { "path": "../vendor/commands", "include": ["src/**/*.rs"] },
+                        { "path": "../shared" }
+                    ]
+                }
+            }
+        });
+        fs::write(&tauri_conf_path, content.to_string()).unwrap();
+
+        let config = GenerateConfig::from_tauri_config(&tauri_conf_path)
+            .unwrap()
+            .unwrap();
+        assert_eq!(
+            config.additional_source_paths,
+            Some(vec![
+                AdditionalSourcePath {
+                    path: "../vendor/commands".to_string(),
+                    include: Some(vec!["src/**/*.rs".to_string()]),
+                },
+                AdditionalSourcePath {
+                    path: "../shared".to_string(),
+                    include: None,
+                },
+            ])
+        );
+    }
+
+    #[test]
+    fn test_viz_filter_from_tauri_config() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let tauri_conf_path = temp_dir.path().join("tauri.conf.json");
+        let content = serde_json::json!({
+            "plugins": {
+                "typegen": {
+                    "projectPath": temp_dir.path().to_string_lossy(),
+                    "vizFocus": "get_user",
+                    "vizDepth": 3
+                }
+            }
+        });
+        fs::write(&tauri_conf_path, content.to_string()).unwrap();
+
+        let config = GenerateConfig::from_tauri_config(&tauri_conf_path)
+            .unwrap()
+            .unwrap();
+        let filter = config.viz_filter();
+        assert_eq!(filter.focus.as_deref(), Some("get_user"));
+        assert_eq!(filter.depth, Some(3));
+        assert_eq!(GenerateConfig::default().viz_filter(), VizFilter::default());
+    }
+
+    #[test]
+    fn test_max_scan_depth_from_tauri_config() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let tauri_conf_path = temp_dir.path().join("tauri.conf.json");
+        let content = serde_json::json!({
+            "plugins": {
+                "typegen": {
+                    "projectPath": temp_dir.path().to_string_lossy(),
+                    "maxScanDepth": 6
+                }
+            }
+        });
+        fs::write(&tauri_conf_path, content.to_string()).unwrap();
+
+        let mut config = GenerateConfig::from_tauri_config(&tauri_conf_path)
+            .unwrap()
+            .unwrap();
+        assert_eq!(config.max_scan_depth, Some(6));
+
+        config.max_scan_depth = Some(0);
+        assert!(config.validate().is_err());
+    }
+
+    #[test]
+    fn test_command_options_from_tauri_config() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let tauri_conf_path = temp_dir.path().join("tauri.conf.json");
+        let content = serde_json::json!({
+            "plugins": {
+                "typegen": {
+                    "projectPath": temp_dir.path().to_string_lossy(),
+                    "commandOptions": {
+                        "save_project": { "serializeCalls": "project_id" },
+                        "reset_db": { "serializeCalls": true }
+                    }
+                }
+            }
+        });
+        fs::write(&tauri_conf_path, content.to_string()).unwrap();
+
+        let mut config = GenerateConfig::from_tauri_config(&tauri_conf_path)
+            .unwrap()
+            .unwrap();
+        let command =
+            CommandInfo::new_for_test("reset_db", "lib.rs", 1, vec![], "()", false, vec![]);
+        assert_eq!(
+            config.serialize_calls(&command),
+            Some(CallSerialization::Command)
+        );
+        assert_eq!(
+            config
+                .command_options("save_project")
+                .unwrap()
+                .serialize_calls,
+            Some(SerializeCalls::Key("project_id".to_string()))
+        );
+        assert!(config.validate().is_ok());
+
+        config.command_options.as_mut().unwrap().insert(
+            "load".to_string(),
+            CommandOptions {
+                serialize_calls: Some(SerializeCalls::Key(" ".to_string())),
+                ..Default::default()
+            },
+        );
+        assert!(config.validate().is_err());
+    }
+
+    #[test]
+    fn test_command_debounce_and_throttle_options() {
+        let mut config: GenerateConfig = serde_json::from_str(
+            r#"{"project_path": ".", "command_options": {"search": {"debounceMs": 250}, "scroll": {"throttleMs": 100}}}"#,
+        )
+        .unwrap();
+        assert_eq!(
+            config.command_options("search").unwrap().debounce_ms,
+            Some(250)
+        );
+        assert_eq!(
+            config.command_options("scroll").unwrap().throttle_ms,
+            Some(100)
+        );
+        assert!(config.validate().is_ok());
+
+        let search = config
+            .command_options
+            .as_mut()
+            .unwrap()
+            .get_mut("search")
+            .unwrap();
+        search.throttle_ms = Some(100);
+        assert!(config.validate().is_err());
+
+        let search = config
+            .command_options
+            .as_mut()
+            .unwrap()
+            .get_mut("search")
+            .unwrap();
+        search.throttle_ms = None;
+        search.debounce_ms = Some(0);
+        assert!(config.validate().is_err());
+    }
 
     #[test]
-    fn test_default_config() {
-        let config = GenerateConfig::default();
-        assert_eq!(config.project_path, "./src-tauri");
-        assert_eq!(config.output_path, "./src/generated");
-        assert_eq!(config.validation_library, "none");
-        assert!(!config.is_verbose());
-        assert!(!config.should_visualize_deps());
-        assert!(!config.should_include_private());
-        assert!(!config.should_force());
+    fn test_command_cache_ttl_option() {
+        let mut config: GenerateConfig = serde_json::from_str(
+            r#"{"project_path": ".", "command_options": {"get_user": {"cacheTtlMs": 5000}}}"#,
+        )
+        .unwrap();
+        assert_eq!(
+            config.command_options("get_user").unwrap().cache_ttl_ms,
+            Some(5000)
+        );
+        assert!(config.validate().is_ok());
+
+        let get_user = config
+            .command_options
+            .as_mut()
+            .unwrap()
+            .get_mut("get_user")
+            .unwrap();
+        get_user.cache_ttl_ms = Some(0);
+        assert!(config.validate().is_err());
     }
 
     #[test]
-    fn test_config_validation() {
-        let config = GenerateConfig {
-            validation_library: "invalid".to_string(),
+    fn test_command_validation() {
+        let mut config: GenerateConfig = serde_json::from_str(
+            r#"{"command_validation": {"submit_*": "zod", "submit_draft": "none", "submit_form_*": "none"}}"#,
+        )
+        .unwrap();
+        assert_eq!(config.command_validation("submit_order"), Some("zod"));
+        // Exact names win over patterns, longer patterns over shorter ones
+        assert_eq!(config.command_validation("submit_draft"), Some("none"));
+        assert_eq!(config.command_validation("submit_form_login"), Some("none"));
+        assert_eq!(config.command_validation("get_orders"), None);
+
+        // Zod is used for the listed commands only
+        assert_eq!(config.generator_library(), "zod");
+        assert!(config.validates_command("submit_order"));
+        assert!(!config.validates_command("get_orders"));
+        assert!(config.validate_options().is_ok());
+
+        config.validation_library = "zod".to_string();
+        assert!(config.validates_command("get_orders"));
+        assert!(!config.validates_command("submit_draft"));
+        assert!(GenerateConfig::default().validates_command("get_orders"));
+        assert_eq!(GenerateConfig::default().generator_library(), "none");
+
+        config
+            .command_validation
+            .as_mut()
+            .unwrap()
+            .insert("get_orders".to_string(), "yup".to_string());
+        assert!(config.validate_options().is_err());
+
+        // One types.ts cannot hold schemas of two libraries
+        let mixed: GenerateConfig = serde_json::from_str(
+            r#"{"validation_library": "valibot", "command_validation": {"submit_*": "zod"}}"#,
+        )
+        .unwrap();
+        assert!(mixed.validate_options().is_err());
+        let valibot: GenerateConfig =
+            serde_json::from_str(r#"{"command_validation": {"submit_*": "valibot"}}"#).unwrap();
+        assert!(valibot.validate_options().is_ok());
+        assert_eq!(valibot.generator_library(), "valibot");
+        assert!(valibot.uses_schemas());
+        assert!(!valibot.validates_command("get_orders"));
+    }
+
+    #[test]
+    fn test_range_literal_limit() {
+        let mut config: GenerateConfig = serde_json::from_str(
+            r#"{"range_literals": {"*": 8, "Settings.*": 4, "Settings.volume": 0}}"#,
+        )
+        .unwrap();
+        assert_eq!(config.range_literal_limit("Settings", "volume"), Some(0));
+        assert_eq!(config.range_literal_limit("Settings", "level"), Some(4));
+        assert_eq!(config.range_literal_limit("User", "role"), Some(8));
+        assert_eq!(
+            GenerateConfig::default().range_literal_limit("User", "role"),
+            None
+        );
+        assert!(config.validate_options().is_ok());
+
+        config
+            .range_literals
+            .as_mut()
+            .unwrap()
+            .insert("level".to_string(), 4);
+        assert!(config.validate_options().is_err());
+    }
+
+    #[test]
+    fn test_shared_types_package() {
+        let mut config: GenerateConfig = serde_json::from_str(
+            r#"{"shared_types": [{"path": "../crates/core/src", "package": "@acme/core-types"}]}"#,
+        )
+        .unwrap();
+        assert_eq!(
+            config.shared_types_package("../crates/core/src/user.rs"),
+            Some("@acme/core-types")
+        );
+        assert_eq!(
+            config.shared_types_package("../crates/core/srcs/user.rs"),
+            None
+        );
+        assert_eq!(config.shared_types_package("src/main.rs"), None);
+        assert!(config.validate_options().is_ok());
+
+        config.shared_types.as_mut().unwrap()[0].package = String::new();
+        assert!(config.validate_options().is_err());
+    }
+
+    #[test]
+    fn test_aliases_validation() {
+        let mut config: GenerateConfig =
+            serde_json::from_str(r#"{"aliases": {"get_user": "fetch_user"}}"#).unwrap();
+        assert!(config.validate_options().is_ok());
+
+        config
+            .aliases
+            .as_mut()
+            .unwrap()
+            .insert("fetch_user".to_string(), "fetch_user".to_string());
+        assert!(config.validate_options().is_err());
+    }
+
+    #[test]
+    fn test_command_invalidates_option() {
+        let config: GenerateConfig = serde_json::from_str(
+            r#"{"command_options": {"rename_project": {"invalidates": ["get_projects"]}}}"#,
+        )
+        .unwrap();
+        let mut command = CommandInfo::new_for_test(
+            "rename_project",
+            "src/lib.rs",
+            1,
+            vec![],
+            "()",
+            false,
+            vec![],
+        );
+        command.invalidates = vec!["get_project".to_string()];
+        // The configured queries replace the attribute's
+        assert_eq!(config.invalidates(&command), ["get_projects"]);
+
+        assert_eq!(
+            GenerateConfig::default().invalidates(&command),
+            ["get_project"]
+        );
+    }
+
+    #[test]
+    fn test_max_commands_per_file_from_tauri_config() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let tauri_conf_path = temp_dir.path().join("tauri.conf.json");
+        let content = serde_json::json!({
+            "plugins": {
+                "typegen": {
+                    "projectPath": temp_dir.path().to_string_lossy(),
+                    "maxCommandsPerFile": 50
+                }
+            }
+        });
+        fs::write(&tauri_conf_path, content.to_string()).unwrap();
+
+        let mut config = GenerateConfig::from_tauri_config(&tauri_conf_path)
+            .unwrap()
+            .unwrap();
+        assert_eq!(config.max_commands_per_file, Some(50));
+
+        config.max_commands_per_file = Some(0);
+        assert!(config.validate().is_err());
+    }
+
+    #[test]
+    fn test_viz_formats() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let mut config = GenerateConfig {
+            project_path: temp_dir.path().to_string_lossy().to_string(),
             ..Default::default()
         };
+        assert_eq!(config.viz_formats(), VizFormat::DEFAULT.to_vec());
 
-        let result = config.validate();
-        assert!(result.is_err());
-        if let Err(ConfigError::InvalidValidationLibrary(lib)) = result {
-            assert_eq!(lib, "invalid");
-        } else {
-            panic!("Expected InvalidValidationLibrary error");
-        }
+        config.viz_format = Some("html".to_string());
+        assert_eq!(config.viz_formats(), vec![VizFormat::Html]);
+        assert!(config.validate().is_ok());
+
+        config.viz_format = Some("png".to_string());
+        assert!(config.validate().is_err());
     }
 
     #[test]
-    fn test_config_merge() {
-        let mut base = GenerateConfig::default();
-        let override_config = GenerateConfig {
-            output_path: "./custom".to_string(),
-            verbose: Some(true),
+    fn test_lint_config_validation() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let mut config = GenerateConfig {
+            project_path: temp_dir.path().to_string_lossy().to_string(),
+            lint_config: Some("biome".to_string()),
             ..Default::default()
         };
+        assert!(config.validate().is_ok());
 
-        base.merge(&override_config);
-        assert_eq!(base.output_path, "./custom");
-        assert!(base.is_verbose());
-        assert_eq!(base.validation_library, "none"); // Should remain default
+        config.lint_config = Some("tslint".to_string());
+        let error = config.validate().unwrap_err().to_string();
+        assert!(error.contains("Invalid lint config: tslint"));
     }
 
     #[test]
-    fn test_save_and_load_config() {
+    fn test_framework_validation() {
         let temp_dir = tempfile::TempDir::new().unwrap();
-        let project_path = temp_dir.path().join("src-tauri");
-        std::fs::create_dir_all(&project_path).unwrap();
-
-        let config = GenerateConfig {
-            project_path: project_path.to_string_lossy().to_string(),
-            output_path: "./test".to_string(),
-            verbose: Some(true),
+        let mut config = GenerateConfig {
+            project_path: temp_dir.path().to_string_lossy().to_string(),
+            framework: Some("react-query".to_string()),
             ..Default::default()
         };
+        assert!(config.validate().is_ok());
+        // Hooks are keyed by the query key factories
+        assert!(config.should_generate_query_keys());
 
-        let temp_file = NamedTempFile::new().unwrap();
-        config.save_to_file(temp_file.path()).unwrap();
+        config.framework = Some("vue".to_string());
+        assert!(config.validate().is_ok());
+        assert!(!config.should_generate_query_keys());
 
-        let loaded_config = GenerateConfig::from_file(temp_file.path()).unwrap();
-        assert_eq!(loaded_config.output_path, "./test");
-        assert!(loaded_config.is_verbose());
+        config.framework = Some("svelte".to_string());
+        assert!(config.validate().is_ok());
+
+        config.framework = Some("pinia".to_string());
+        assert!(config.validate().is_ok());
+
+        config.framework = Some("angular".to_string());
+        let error = config.validate().unwrap_err().to_string();
+        assert!(error.contains("Invalid framework: angular"));
     }
 
     #[test]
-    fn test_save_to_tauri_config_preserves_existing_content() {
+    fn test_stores_validation() {
         let temp_dir = tempfile::TempDir::new().unwrap();
-        let project_path = temp_dir.path().join("src-tauri");
-        std::fs::create_dir_all(&project_path).unwrap();
-
-        let tauri_conf_path = temp_dir.path().join("tauri.conf.json");
+        let mut config = GenerateConfig {
+            project_path: temp_dir.path().to_string_lossy().to_string(),
+            stores: Some(std::collections::HashMap::from([
+                ("window.json".to_string(), "WindowState".to_string()),
+                ("settings.json".to_string(), "AppSettings".to_string()),
+            ])),
+            ..Default::default()
+        };
+        assert!(config.validate().is_ok());
+        assert_eq!(
+            config.stores(),
+            [
+                ("settings.json", "AppSettings"),
+                ("window.json", "WindowState")
+            ]
+        );
 
-        // Create existing tauri.conf.json with some content
-        let existing_content = serde_json::json!({
-            "package": {
-                "productName": "My App",
-                "version": "1.0.0"
-            },
-            "tauri": {
-                "allowlist": {
-                    "all": false
-                }
-            },
-            "plugins": {
-                "shell": {
-                    "all": false
-                }
-            }
-        });
+        config.stores = Some(std::collections::HashMap::from([(
+            "settings.json".to_string(),
+            String::new(),
+        )]));
+        let error = config.validate().unwrap_err().to_string();
+        assert!(error.contains("Invalid store: 'settings.json'"));
+    }
 
-        fs::write(
-            &tauri_conf_path,
-            serde_json::to_string_pretty(&existing_content).unwrap(),
-        )
-        .unwrap();
+    #[test]
+    fn test_compact_validation() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let mut config = GenerateConfig {
+            project_path: temp_dir.path().to_string_lossy().to_string(),
+            compact_types: Some(vec!["Grid".to_string()]),
+            ..Default::default()
+        };
+        let error = config.validate().unwrap_err().to_string();
+        assert!(error.contains("compactTypes requires compactCodec"));
+        assert!(config.compact_types().is_empty());
+        assert!(config.compact_type_mappings().is_empty());
+
+        config.compact_codec = Some("src-tauri/src/compact.ts".to_string());
+        let error = config.validate().unwrap_err().to_string();
+        assert!(error.contains("must be a Rust file"));
+
+        config.compact_codec = Some("src-tauri/src/compact.rs".to_string());
+        assert!(config.validate().is_ok());
+        assert_eq!(config.compact_types(), ["Grid"]);
+        assert_eq!(
+            config
+                .compact_type_mappings()
+                .get("Compact<*>")
+                .map(String::as_str),
+            Some("*")
+        );
+    }
 
-        let config = GenerateConfig {
-            project_path: project_path.to_string_lossy().to_string(),
-            output_path: "./test".to_string(),
-            validation_library: "zod".to_string(),
-            verbose: Some(true),
+    #[test]
+    fn test_self_test_validation() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let mut config = GenerateConfig {
+            project_path: temp_dir.path().to_string_lossy().to_string(),
+            self_test: Some("src-tauri/src/selftest.ts".to_string()),
             ..Default::default()
         };
+        let error = config.validate().unwrap_err().to_string();
+        assert!(error.contains("must be a Rust file"));
 
-        // Save to tauri config - should preserve existing content
-        config.save_to_tauri_config(&tauri_conf_path).unwrap();
+        config.self_test = Some("src-tauri/src/typegen_selftest.rs".to_string());
+        assert!(config.validate().is_ok());
+    }
 
-        // Read back and verify
-        let updated_content = fs::read_to_string(&tauri_conf_path).unwrap();
-        let updated_json: serde_json::Value = serde_json::from_str(&updated_content).unwrap();
+    #[test]
+    fn test_unknown_type_fallback_validation() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let mut config = GenerateConfig {
+            project_path: temp_dir.path().to_string_lossy().to_string(),
+            ..Default::default()
+        };
+        assert_eq!(config.unknown_type_fallback(), "unknown");
 
-        // Check that existing content is preserved
-        assert_eq!(updated_json["package"]["productName"], "My App");
-        assert_eq!(updated_json["package"]["version"], "1.0.0");
-        assert_eq!(updated_json["tauri"]["allowlist"]["all"], false);
-        assert_eq!(updated_json["plugins"]["shell"]["all"], false);
+        config.unknown_type_fallback = Some("error".to_string());
+        assert!(config.validate().is_ok());
 
-        // Check that typegen config was added
-        assert_eq!(updated_json["plugins"]["typegen"]["outputPath"], "./test");
-        assert_eq!(
-            updated_json["plugins"]["typegen"]["validationLibrary"],
-            "zod"
-        );
-        assert_eq!(updated_json["plugins"]["typegen"]["verbose"], true);
+        config.unknown_type_fallback = Some("never".to_string());
+        let error = config.validate().unwrap_err().to_string();
+        assert!(error.contains("Invalid unknown type fallback: never"));
     }
 
     #[test]
-    fn test_save_to_tauri_config_creates_plugins_section() {
+    fn test_target_validation() {
         let temp_dir = tempfile::TempDir::new().unwrap();
-        let project_path = temp_dir.path().join("src-tauri");
-        std::fs::create_dir_all(&project_path).unwrap();
+        let mut config = GenerateConfig {
+            project_path: temp_dir.path().to_string_lossy().to_string(),
+            ..Default::default()
+        };
+        assert_eq!(config.target(), "typescript");
+
+        config.target = Some("python".to_string());
+        assert!(config.validate().is_ok());
+
+        config.target = Some("ruby".to_string());
+        let error = config.validate().unwrap_err().to_string();
+        assert!(error.contains("Invalid target: ruby"));
+
+        config.target = Some("kotlin".to_string());
+        assert_eq!(config.kotlin_package(), "tauri.typegen");
+        config.kotlin_package = Some("com.example.app".to_string());
+        assert!(config.validate().is_ok());
+        config.kotlin_package = Some("com.example-app".to_string());
+        let error = config.validate().unwrap_err().to_string();
+        assert!(error.contains("Invalid Kotlin package: com.example-app"));
+    }
 
-        let tauri_conf_path = temp_dir.path().join("tauri.conf.json");
+    #[test]
+    fn test_zod_object_mode_validation() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let mut config = GenerateConfig {
+            project_path: temp_dir.path().to_string_lossy().to_string(),
+            ..Default::default()
+        };
+        assert_eq!(config.zod_object_mode(), "strip");
 
-        // Create existing tauri.conf.json without plugins section
-        let existing_content = serde_json::json!({
-            "package": {
-                "productName": "My App",
-                "version": "1.0.0"
-            },
-            "tauri": {
-                "allowlist": {
-                    "all": false
-                }
-            }
-        });
+        config.zod_object_mode = Some("passthrough".to_string());
+        assert!(config.validate().is_ok());
+
+        config.zod_object_mode = Some("loose".to_string());
+        let error = config.validate().unwrap_err().to_string();
+        assert!(error.contains("Invalid Zod object mode: loose"));
+    }
 
+    #[test]
+    #[cfg(feature = "generators")]
+    fn test_validation_messages_from_file_and_inline() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let locale_path = temp_dir.path().join("de.json");
         fs::write(
-            &tauri_conf_path,
-            serde_json::to_string_pretty(&existing_content).unwrap(),
+            &locale_path,
+            r#"{"minLength": "Mindestens {min} Zeichen", "email": "Ungültige E-Mail"}"#,
         )
         .unwrap();
 
-        let config = GenerateConfig {
-            project_path: project_path.to_string_lossy().to_string(),
-            output_path: "./test".to_string(),
-            validation_library: "none".to_string(),
+        let mut config = GenerateConfig {
+            project_path: temp_dir.path().to_string_lossy().to_string(),
+            validation_messages_file: Some(locale_path.to_string_lossy().to_string()),
+            validation_messages: Some(
+                [("email".to_string(), "Bitte E-Mail prüfen".to_string())]
+                    .into_iter()
+                    .collect(),
+            ),
             ..Default::default()
         };
+        let messages = config.validation_messages().unwrap();
+        assert_eq!(
+            messages.min_length(&None, Some(2), None).as_deref(),
+            Some("Mindestens 2 Zeichen")
+        );
+        assert_eq!(
+            messages.format("email").as_deref(),
+            Some("Bitte E-Mail prüfen")
+        );
+        assert!(config.validate().is_ok());
 
-        // Save to tauri config - should create plugins section
-        config.save_to_tauri_config(&tauri_conf_path).unwrap();
+        config.validation_messages = Some(
+            [("minLen".to_string(), "Zu kurz".to_string())]
+                .into_iter()
+                .collect(),
+        );
+        assert!(config.validate().is_err());
 
-        // Read back and verify
-        let updated_content = fs::read_to_string(&tauri_conf_path).unwrap();
-        let updated_json: serde_json::Value = serde_json::from_str(&updated_content).unwrap();
+        config.validation_messages = None;
+        config.validation_messages_file = Some("missing.json".to_string());
+        assert!(config.validate().is_err());
+    }
 
-        // Check that existing content is preserved
-        assert_eq!(updated_json["package"]["productName"], "My App");
-        assert_eq!(updated_json["tauri"]["allowlist"]["all"], false);
+    mod output_file_names {
+        use super::*;
+
+        #[test]
+        fn test_defaults() {
+            let config = GenerateConfig::default();
+            let names = config.file_names();
+            assert_eq!(names.types, "types.ts");
+            assert_eq!(names.commands, "commands.ts");
+            assert_eq!(names.events, "events.ts");
+            assert_eq!(names.enums, "enums.ts");
+            assert_eq!(names.index, "index.ts");
+            assert_eq!(names.globals, "globals.d.ts");
+            assert_eq!(names.constants, "constants.ts");
+            assert_eq!(names.schema_registry, "schemaRegistry.ts");
+            assert_eq!(names.forms, "forms.ts");
+            assert_eq!(names.actors, "actors.ts");
+            assert_eq!(names.runtime, "runtime.ts");
+            assert_eq!(names.arbitraries, "arbitraries.ts");
+            assert_eq!(names.testing, "testing.ts");
+            assert_eq!(names.mocks, "mocks.ts");
+            assert_eq!(names.self_test, "selfTest.ts");
+            assert_eq!(names.binary, "binary.ts");
+            assert_eq!(names.offline, "offline.ts");
+            assert_eq!(names.query_keys, "queryKeys.ts");
+            assert_eq!(names.trace, "trace.json");
+            assert_eq!(names.errors, "errors.ts");
+            assert_eq!(names.sanitize, "sanitize.ts");
+            assert_eq!(names.hooks, "hooks.ts");
+            assert_eq!(names.stores, "stores.ts");
+            assert_eq!(names.compact, "compact.ts");
+        }
 
-        // Check that plugins section was created with typegen config
-        assert!(updated_json["plugins"].is_object());
-        assert_eq!(updated_json["plugins"]["typegen"]["outputPath"], "./test");
-        assert_eq!(
-            updated_json["plugins"]["typegen"]["validationLibrary"],
-            "none"
-        );
+        #[test]
+        fn test_module_path_strips_extension() {
+            assert_eq!(OutputFileNames::module_path("models.ts"), "./models");
+            assert_eq!(OutputFileNames::module_path("api.gen.ts"), "./api.gen");
+            assert_eq!(OutputFileNames::module_path("bindings"), "./bindings");
+        }
+
+        #[test]
+        fn test_module_name_of_other_targets() {
+            assert_eq!(OutputFileNames::module_name("types.ts"), "types");
+            assert_eq!(
+                OutputFileNames::module_name("api-commands.ts"),
+                "api_commands"
+            );
+            assert_eq!(OutputFileNames::module_name("globals.d.ts"), "globals");
+        }
+
+        #[test]
+        fn test_partial_deserialization_keeps_defaults() {
+            let names: OutputFileNames = serde_json::from_str(r#"{"types": "models.ts"}"#).unwrap();
+            assert_eq!(names.types, "models.ts");
+            assert_eq!(names.commands, "commands.ts");
+        }
+
+        #[test]
+        fn test_schema_registry_accepts_camel_case_key() {
+            let names: OutputFileNames =
+                serde_json::from_str(r#"{"schemaRegistry": "schemas.ts"}"#).unwrap();
+            assert_eq!(names.schema_registry, "schemas.ts");
+        }
+
+        #[test]
+        fn test_validate_rejects_duplicates() {
+            let names = OutputFileNames {
+                commands: "types.ts".to_string(),
+                ..Default::default()
+            };
+            assert!(names.validate().is_err());
+        }
+
+        #[test]
+        fn test_validate_rejects_path_separators() {
+            let names = OutputFileNames {
+                types: "nested/types.ts".to_string(),
+                ..Default::default()
+            };
+            assert!(names.validate().is_err());
+        }
+
+        #[test]
+        fn test_from_tauri_config() {
+            let temp_dir = tempfile::TempDir::new().unwrap();
+            let project_path = temp_dir.path().join("src-tauri");
+            std::fs::create_dir_all(&project_path).unwrap();
+
+            let tauri_conf_path = temp_dir.path().join("tauri.conf.json");
+            let content = serde_json::json!({
+                "plugins": {
+                    "typegen": {
+                        "projectPath": project_path.to_string_lossy(),
+                        "outputFileNames": {
+                            "types": "models.ts",
+                            "commands": "ipc.ts"
+                        }
+                    }
+                }
+            });
+            fs::write(&tauri_conf_path, content.to_string()).unwrap();
+
+            let config = GenerateConfig::from_tauri_config(&tauri_conf_path)
+                .unwrap()
+                .unwrap();
+            let names = config.file_names();
+            assert_eq!(names.types, "models.ts");
+            assert_eq!(names.commands, "ipc.ts");
+            assert_eq!(names.index, "index.ts");
+        }
     }
 }
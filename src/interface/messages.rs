@@ -0,0 +1,452 @@
+use std::fmt;
+
+/// Language of the CLI output
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum Locale {
+    #[default]
+    En,
+    De,
+    Ja,
+}
+
+impl Locale {
+    pub const CODES: [&'static str; 3] = ["en", "de", "ja"];
+
+    /// Parse a language code, ignoring the region and encoding (`de-AT`, `ja_JP.UTF-8`)
+    pub fn parse(code: &str) -> Option<Self> {
+        let language = code
+            .trim()
+            .split(['-', '_', '.'])
+            .next()
+            .unwrap_or_default()
+            .to_lowercase();
+        match language.as_str() {
+            "en" => Some(Locale::En),
+            "de" => Some(Locale::De),
+            "ja" => Some(Locale::Ja),
+            _ => None,
+        }
+    }
+
+    pub fn code(&self) -> &'static str {
+        match self {
+            Locale::En => "en",
+            Locale::De => "de",
+            Locale::Ja => "ja",
+        }
+    }
+}
+
+impl fmt::Display for Locale {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.code())
+    }
+}
+
+/// Identifier of a CLI message, independent of its wording in any locale
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum MessageId {
+    Error,
+    CacheSaveFailed,
+    StepLoadingConfig,
+    UsingValidation,
+    StepValidatingProject,
+    StepAnalyzing,
+    FoundCommands,
+    NoCommandsFound,
+    UpToDate,
+    StepGenerating,
+    GeneratedFiles,
+    GenerationComplete,
+    UnsupportedConstructs,
+    LargePayloads,
+    UsageGenerated,
+    UsageLocation,
+    UsageImport,
+    CircularDependency,
+    UnknownVizFocus,
+    ParameterNotSerializable,
+    UnknownSerializeCallsKey,
+    ConstantNotEvaluated,
+    UnknownInvalidatedCommand,
+    MissingSerdeDerive,
+    UnmatchedFieldOverride,
+    InitStart,
+    InitUpdatedTauriConfig,
+    InitCreatedConfig,
+    InitSummary,
+    InitProjectPath,
+    InitOutputPath,
+    InitValidation,
+    InitRunning,
+    InitComplete,
+    InitHint,
+}
+
+impl MessageId {
+    pub const ALL: [MessageId; 35] = [
+        MessageId::Error,
+        MessageId::CacheSaveFailed,
+        MessageId::StepLoadingConfig,
+        MessageId::UsingValidation,
+        MessageId::StepValidatingProject,
+        MessageId::StepAnalyzing,
+        MessageId::FoundCommands,
+        MessageId::NoCommandsFound,
+        MessageId::UpToDate,
+        MessageId::StepGenerating,
+        MessageId::GeneratedFiles,
+        MessageId::GenerationComplete,
+        MessageId::UnsupportedConstructs,
+        MessageId::LargePayloads,
+        MessageId::UsageGenerated,
+        MessageId::UsageLocation,
+        MessageId::UsageImport,
+        MessageId::CircularDependency,
+        MessageId::UnknownVizFocus,
+        MessageId::ParameterNotSerializable,
+        MessageId::UnknownSerializeCallsKey,
+        MessageId::ConstantNotEvaluated,
+        MessageId::UnknownInvalidatedCommand,
+        MessageId::MissingSerdeDerive,
+        MessageId::UnmatchedFieldOverride,
+        MessageId::InitStart,
+        MessageId::InitUpdatedTauriConfig,
+        MessageId::InitCreatedConfig,
+        MessageId::InitSummary,
+        MessageId::InitProjectPath,
+        MessageId::InitOutputPath,
+        MessageId::InitValidation,
+        MessageId::InitRunning,
+        MessageId::InitComplete,
+        MessageId::InitHint,
+    ];
+
+    /// Stable key of the message, e.g. `generate.up_to_date`
+    pub fn key(&self) -> &'static str {
+        match self {
+            MessageId::Error => "error",
+            MessageId::CacheSaveFailed => "cache.save_failed",
+            MessageId::StepLoadingConfig => "generate.step.load_config",
+            MessageId::UsingValidation => "generate.using_validation",
+            MessageId::StepValidatingProject => "generate.step.validate",
+            MessageId::StepAnalyzing => "generate.step.analyze",
+            MessageId::FoundCommands => "generate.found_commands",
+            MessageId::NoCommandsFound => "generate.no_commands",
+            MessageId::UpToDate => "generate.up_to_date",
+            MessageId::StepGenerating => "generate.step.generate",
+            MessageId::GeneratedFiles => "generate.generated_files",
+            MessageId::GenerationComplete => "generate.complete",
+            MessageId::UnsupportedConstructs => "generate.unsupported",
+            MessageId::LargePayloads => "generate.large_payloads",
+            MessageId::UsageGenerated => "usage.generated",
+            MessageId::UsageLocation => "usage.location",
+            MessageId::UsageImport => "usage.import",
+            MessageId::CircularDependency => "analysis.circular_dependency",
+            MessageId::UnknownVizFocus => "analysis.unknown_viz_focus",
+            MessageId::ParameterNotSerializable => "analysis.parameter_not_serializable",
+            MessageId::UnknownSerializeCallsKey => "analysis.unknown_serialize_calls_key",
+            MessageId::ConstantNotEvaluated => "analysis.constant_not_evaluated",
+            MessageId::UnknownInvalidatedCommand => "analysis.unknown_invalidated_command",
+            MessageId::MissingSerdeDerive => "analysis.missing_serde_derive",
+            MessageId::UnmatchedFieldOverride => "analysis.unmatched_field_override",
+            MessageId::InitStart => "init.start",
+            MessageId::InitUpdatedTauriConfig => "init.updated_tauri_config",
+            MessageId::InitCreatedConfig => "init.created_config",
+            MessageId::InitSummary => "init.summary",
+            MessageId::InitProjectPath => "init.summary.project_path",
+            MessageId::InitOutputPath => "init.summary.output_path",
+            MessageId::InitValidation => "init.summary.validation",
+            MessageId::InitRunning => "init.running",
+            MessageId::InitComplete => "init.complete",
+            MessageId::InitHint => "init.hint",
+        }
+    }
+
+    /// Wording in English, German and Japanese; `{name}` is replaced by an argument
+    fn catalog(&self) -> [&'static str; 3] {
+        match self {
+            MessageId::Error => ["Error: {error}", "Fehler: {error}", "エラー: {error}"],
+            MessageId::CacheSaveFailed => [
+                "Warning: Failed to save generation cache: {error}",
+                "Warnung: Generierungs-Cache konnte nicht gespeichert werden: {error}",
+                "警告: 生成キャッシュを保存できませんでした: {error}",
+            ],
+            MessageId::StepLoadingConfig => [
+                "Loading configuration",
+                "Konfiguration wird geladen",
+                "設定を読み込んでいます",
+            ],
+            MessageId::UsingValidation => [
+                "Using {library} validation",
+                "Validierung mit {library}",
+                "{library} による検証を使用",
+            ],
+            MessageId::StepValidatingProject => [
+                "Validating project structure",
+                "Projektstruktur wird geprüft",
+                "プロジェクト構成を検証しています",
+            ],
+            MessageId::StepAnalyzing => [
+                "Analyzing Tauri commands",
+                "Tauri-Befehle werden analysiert",
+                "Tauri コマンドを解析しています",
+            ],
+            MessageId::FoundCommands => [
+                "Found {count} commands",
+                "{count} Befehle gefunden",
+                "{count} 個のコマンドが見つかりました",
+            ],
+            MessageId::NoCommandsFound => [
+                "No Tauri commands found. Make sure your project contains functions with #[tauri::command] attributes.",
+                "Keine Tauri-Befehle gefunden. Stellen Sie sicher, dass Ihr Projekt Funktionen mit #[tauri::command]-Attributen enthält.",
+                "Tauri コマンドが見つかりません。プロジェクトに #[tauri::command] 属性の付いた関数があることを確認してください。",
+            ],
+            MessageId::UpToDate => [
+                "{language} bindings are up to date",
+                "{language}-Bindings sind aktuell",
+                "{language} バインディングは最新です",
+            ],
+            MessageId::StepGenerating => [
+                "Generating {language} bindings",
+                "{language}-Bindings werden generiert",
+                "{language} バインディングを生成しています",
+            ],
+            MessageId::GeneratedFiles => [
+                "Generated {count} files",
+                "{count} Dateien generiert",
+                "{count} 個のファイルを生成しました",
+            ],
+            MessageId::GenerationComplete => [
+                "Generation complete",
+                "Generierung abgeschlossen",
+                "生成が完了しました",
+            ],
+            MessageId::UnsupportedConstructs => [
+                "Not covered by the bindings ({count} unsupported constructs):",
+                "Nicht von den Bindings abgedeckt ({count} nicht unterstützte Konstrukte):",
+                "バインディングの対象外 (サポートされていない構文 {count} 個):",
+            ],
+            MessageId::LargePayloads => [
+                "Payloads likely to exceed a practical IPC size ({count}):",
+                "Nutzdaten, die voraussichtlich eine praktikable IPC-Größe überschreiten ({count}):",
+                "実用的な IPC サイズを超える可能性のあるペイロード ({count} 個):",
+            ],
+            MessageId::UsageGenerated => [
+                "Generated {language} bindings for {count} commands",
+                "{language}-Bindings für {count} Befehle generiert",
+                "{count} 個のコマンドの {language} バインディングを生成しました",
+            ],
+            MessageId::UsageLocation => [
+                "Location: {path}",
+                "Speicherort: {path}",
+                "出力先: {path}",
+            ],
+            MessageId::UsageImport => [
+                "Import in your frontend:",
+                "Import im Frontend:",
+                "フロントエンドでのインポート:",
+            ],
+            MessageId::CircularDependency => [
+                "Warning: Circular dependency detected involving type: {type}",
+                "Warnung: Zirkuläre Abhängigkeit mit dem Typ {type} erkannt",
+                "警告: 型 {type} を含む循環依存が見つかりました",
+            ],
+            MessageId::UnknownVizFocus => [
+                "Warning: Visualization focus '{focus}' matches no command or type, showing the full graph",
+                "Warnung: Visualisierungsfokus '{focus}' passt zu keinem Befehl oder Typ, der vollständige Graph wird angezeigt",
+                "警告: 可視化の対象 '{focus}' に一致するコマンドや型がないため、グラフ全体を表示します",
+            ],
+            MessageId::ParameterNotSerializable => [
+                "Warning: Parameter {parameter} of command {command} at {location} cannot cross the IPC boundary ({reason}), it is left out of the generated parameters",
+                "Warnung: Parameter {parameter} des Befehls {command} in {location} kann die IPC-Grenze nicht überqueren ({reason}) und wird in den generierten Parametern ausgelassen",
+                "警告: {location} のコマンド {command} の引数 {parameter} は IPC 境界を越えられないため ({reason})、生成される引数から除外されます",
+            ],
+            MessageId::UnknownSerializeCallsKey => [
+                "Warning: serialize_calls key {key} of command {command} at {location} is not a parameter, calls are queued per command",
+                "Warnung: serialize_calls-Schlüssel {key} des Befehls {command} in {location} ist kein Parameter, Aufrufe werden pro Befehl eingereiht",
+                "警告: {location} のコマンド {command} の serialize_calls キー {key} は引数ではないため、呼び出しはコマンドごとに順番待ちになります",
+            ],
+            MessageId::ConstantNotEvaluated => [
+                "Warning: Cannot evaluate exported constant {name} at {location}, only literals, arrays and arithmetic on constants of the same file are supported",
+                "Warnung: Exportierte Konstante {name} in {location} kann nicht ausgewertet werden, unterstützt werden nur Literale, Arrays und Arithmetik mit Konstanten derselben Datei",
+                "警告: {location} のエクスポート定数 {name} を評価できません。リテラル、配列、同じファイルの定数に対する算術のみがサポートされています",
+            ],
+            MessageId::UnknownInvalidatedCommand => [
+                "Warning: command {command} at {location} invalidates {query}, which is not a command",
+                "Warnung: Befehl {command} in {location} invalidiert {query}, das kein Befehl ist",
+                "警告: {location} のコマンド {command} が無効化する {query} はコマンドではありません",
+            ],
+            MessageId::MissingSerdeDerive => [
+                "Warning: {diagnostic}",
+                "Warnung: {diagnostic}",
+                "警告: {diagnostic}",
+            ],
+            MessageId::UnmatchedFieldOverride => [
+                "Warning: Field override {selector} matches no field of a struct used by commands",
+                "Warnung: Feld-Override {selector} passt zu keinem Feld eines von Befehlen verwendeten Structs",
+                "警告: フィールドの上書き {selector} はコマンドが使う構造体のどのフィールドにも一致しません",
+            ],
+            MessageId::InitStart => [
+                "Initializing Tauri TypeScript generation configuration",
+                "Konfiguration der Tauri-TypeScript-Generierung wird eingerichtet",
+                "Tauri TypeScript 生成の設定を初期化しています",
+            ],
+            MessageId::InitUpdatedTauriConfig => [
+                "Updated typegen configuration in {path}",
+                "typegen-Konfiguration in {path} aktualisiert",
+                "{path} の typegen 設定を更新しました",
+            ],
+            MessageId::InitCreatedConfig => [
+                "Created configuration file: {path}",
+                "Konfigurationsdatei erstellt: {path}",
+                "設定ファイルを作成しました: {path}",
+            ],
+            MessageId::InitSummary => [
+                "Configuration summary:",
+                "Zusammenfassung der Konfiguration:",
+                "設定の概要:",
+            ],
+            MessageId::InitProjectPath => [
+                "Project path: {path}",
+                "Projektpfad: {path}",
+                "プロジェクトのパス: {path}",
+            ],
+            MessageId::InitOutputPath => [
+                "Generated files output path: {path}",
+                "Ausgabepfad der generierten Dateien: {path}",
+                "生成ファイルの出力先: {path}",
+            ],
+            MessageId::InitValidation => [
+                "Validation library: {library}",
+                "Validierungsbibliothek: {library}",
+                "検証ライブラリ: {library}",
+            ],
+            MessageId::InitRunning => [
+                "Running initial generation...",
+                "Erste Generierung läuft...",
+                "初回の生成を実行しています...",
+            ],
+            MessageId::InitComplete => [
+                "Initialization complete! Your Tauri project is now set up for TypeScript generation.",
+                "Initialisierung abgeschlossen! Ihr Tauri-Projekt ist jetzt für die TypeScript-Generierung eingerichtet.",
+                "初期化が完了しました。Tauri プロジェクトで TypeScript の生成を利用できます。",
+            ],
+            MessageId::InitHint => [
+                "You can run 'cargo tauri-typegen generate' anytime to regenerate bindings.",
+                "Mit 'cargo tauri-typegen generate' können Sie die Bindings jederzeit neu generieren.",
+                "'cargo tauri-typegen generate' でいつでもバインディングを再生成できます。",
+            ],
+        }
+    }
+
+    /// Wording of the message in `locale`, with `{name}` placeholders
+    pub fn template(&self, locale: Locale) -> &'static str {
+        let [en, de, ja] = self.catalog();
+        match locale {
+            Locale::En => en,
+            Locale::De => de,
+            Locale::Ja => ja,
+        }
+    }
+}
+
+/// A CLI message: its identifier and the arguments filled into its wording
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Message {
+    pub id: MessageId,
+    pub args: Vec<(&'static str, String)>,
+}
+
+impl Message {
+    pub fn new(id: MessageId) -> Self {
+        Self {
+            id,
+            args: Vec::new(),
+        }
+    }
+
+    pub fn arg(mut self, name: &'static str, value: impl fmt::Display) -> Self {
+        self.args.push((name, value.to_string()));
+        self
+    }
+
+    pub fn render(&self, locale: Locale) -> String {
+        self.args.iter().fold(
+            self.id.template(locale).to_string(),
+            |text, (name, value)| text.replace(&format!("{{{}}}", name), value),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn placeholders(template: &str) -> Vec<&str> {
+        let mut names: Vec<&str> = template
+            .split('{')
+            .skip(1)
+            .filter_map(|part| part.split_once('}').map(|(name, _)| name))
+            .collect();
+        names.sort();
+        names
+    }
+
+    #[test]
+    fn test_locale_parse() {
+        assert_eq!(Locale::parse("de"), Some(Locale::De));
+        assert_eq!(Locale::parse("ja_JP.UTF-8"), Some(Locale::Ja));
+        assert_eq!(Locale::parse("EN-us"), Some(Locale::En));
+        assert_eq!(Locale::parse("fr"), None);
+    }
+
+    #[test]
+    fn test_render() {
+        let message = Message::new(MessageId::FoundCommands).arg("count", 3);
+
+        assert_eq!(message.render(Locale::En), "Found 3 commands");
+        assert_eq!(message.render(Locale::De), "3 Befehle gefunden");
+        assert_eq!(message.render(Locale::Ja), "3 個のコマンドが見つかりました");
+    }
+
+    #[test]
+    fn test_render_analysis_warning() {
+        let message = Message::new(MessageId::UnknownInvalidatedCommand)
+            .arg("command", "create_user")
+            .arg("location", "src/lib.rs:12")
+            .arg("query", "list_user");
+
+        assert_eq!(
+            message.render(Locale::En),
+            "Warning: command create_user at src/lib.rs:12 invalidates list_user, which is not a command"
+        );
+        assert_eq!(
+            message.render(Locale::De),
+            "Warnung: Befehl create_user in src/lib.rs:12 invalidiert list_user, das kein Befehl ist"
+        );
+    }
+
+    #[test]
+    fn test_catalog_is_complete() {
+        for id in MessageId::ALL {
+            let english = placeholders(id.template(Locale::En));
+            for code in Locale::CODES {
+                let template = id.template(Locale::parse(code).unwrap());
+                assert!(!template.is_empty(), "{} has no {} wording", id.key(), code);
+                assert_eq!(
+                    placeholders(template),
+                    english,
+                    "{} uses other placeholders in {}",
+                    id.key(),
+                    code
+                );
+            }
+        }
+
+        let mut keys: Vec<&str> = MessageId::ALL.iter().map(MessageId::key).collect();
+        keys.sort();
+        keys.dedup();
+        assert_eq!(keys.len(), MessageId::ALL.len());
+    }
+}
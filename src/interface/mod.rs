@@ -1,12 +1,21 @@
+#[cfg(feature = "cli")]
 pub mod cli;
 pub mod config;
+pub mod messages;
 pub mod output;
 
 use crate::analysis::CommandAnalyzer;
-use crate::generators::create_generator;
+#[cfg(feature = "generators")]
+use crate::generators::base::file_writer::{FileWriter, GeneratedFile};
+#[cfg(feature = "generators")]
+use crate::generators::generator_for;
+use crate::models::{CommandInfo, ConstInfo, EventInfo, StructInfo};
+use std::collections::HashMap;
 
+#[cfg(feature = "cli")]
 pub use cli::*;
 pub use config::*;
+pub use messages::*;
 pub use output::*;
 
 /// Generate TypeScript bindings from a Tauri project.
@@ -48,10 +57,11 @@ pub use output::*;
 /// println!("Generated {} files", files.len());
 /// # Ok::<(), Box<dyn std::error::Error>>(())
 /// ```
+#[cfg(feature = "generators")]
 pub fn generate_from_config(
     config: &config::GenerateConfig,
 ) -> Result<Vec<String>, Box<dyn std::error::Error>> {
-    let logger = output::Logger::new(config.is_verbose(), false);
+    let logger = output::Logger::with_verbosity(config.verbosity());
 
     if config.is_verbose() {
         logger.info(&format!(
@@ -66,28 +76,17 @@ pub fn generate_from_config(
         .map_err(|e| Box::new(e) as Box<dyn std::error::Error>)?;
 
     // Analyze commands with struct discovery
-    let mut analyzer = CommandAnalyzer::new();
-
-    // Apply custom type mappings from configuration
-    if let Some(ref mappings) = config.type_mappings {
-        analyzer.add_type_mappings(mappings);
-        if config.is_verbose() {
-            logger.info(&format!(
-                "📝 Applied {} custom type mappings",
-                mappings.len()
-            ));
-            for (rust_type, ts_type) in mappings {
-                logger.verbose(&format!("  {} → {}", rust_type, ts_type));
-            }
-        }
-    }
-
+    let mut analyzer = configured_analyzer(config);
     let commands = analyzer.analyze_project(&config.project_path)?;
 
     if config.is_verbose() {
         logger.info(&format!("📋 Found {} Tauri commands:", commands.len()));
         for cmd in &commands {
-            logger.verbose(&format!("  - {} ({})", cmd.name, cmd.file_path));
+            logger.detail(
+                output::Phase::Analysis,
+                2,
+                &format!("  - {} ({})", cmd.name, cmd.file_path),
+            );
         }
 
         let discovered_structs = analyzer.get_discovered_structs();
@@ -101,19 +100,27 @@ pub fn generate_from_config(
             } else {
                 "struct"
             };
-            logger.verbose(&format!(
-                "  - {} ({}) with {} fields",
-                name,
-                struct_type,
-                struct_info.fields.len()
-            ));
+            logger.detail(
+                output::Phase::Analysis,
+                2,
+                &format!(
+                    "  - {} ({}) with {} fields",
+                    name,
+                    struct_type,
+                    struct_info.fields.len()
+                ),
+            );
             for field in &struct_info.fields {
                 let visibility = if field.is_public { "pub" } else { "private" };
                 let optional = if field.is_optional { "?" } else { "" };
-                logger.verbose(&format!(
-                    "    • {}{}: {} ({})",
-                    field.name, optional, field.rust_type, visibility
-                ));
+                logger.detail(
+                    output::Phase::Analysis,
+                    3,
+                    &format!(
+                        "    • {}{}: {} ({})",
+                        field.name, optional, field.rust_type, visibility
+                    ),
+                );
             }
         }
 
@@ -122,7 +129,7 @@ pub fn generate_from_config(
         }
     }
 
-    if commands.is_empty() {
+    if commands.is_empty() && !config.should_include_all_types() {
         if config.is_verbose() {
             logger.warning("⚠️  No Tauri commands found. Make sure your project contains functions with #[tauri::command] attributes.");
         }
@@ -130,12 +137,12 @@ pub fn generate_from_config(
     }
 
     // Validate validation library
-    let validation = match config.validation_library.as_str() {
-        "zod" | "none" => Some(config.validation_library.clone()),
-        _ => {
-            return Err("Invalid validation library. Use 'zod' or 'none'".into());
-        }
-    };
+    if !config.has_known_generator() {
+        return Err(
+            ConfigError::InvalidValidationLibrary(config.validation_library.clone()).into(),
+        );
+    }
+    let validation = Some(config.generator_library().to_string());
 
     if config.is_verbose() {
         logger.info(&format!(
@@ -145,7 +152,7 @@ pub fn generate_from_config(
     }
 
     // Generate TypeScript models with discovered structs
-    let mut generator = create_generator(validation);
+    let mut generator = generator_for(config);
     let generated_files = generator.generate_models(
         &commands,
         analyzer.get_discovered_structs(),
@@ -161,9 +168,190 @@ pub fn generate_from_config(
             commands.len()
         ));
         for file in &generated_files {
-            logger.verbose(&format!("  📄 {}/{}", config.output_path, file));
+            logger.detail(
+                output::Phase::Generation,
+                2,
+                &format!("  📄 {}/{}", config.output_path, file),
+            );
         }
     }
+    logger.payload_diagnostics(analyzer.get_payload_diagnostics());
+    logger.unsupported_constructs(analyzer.get_unsupported_constructs());
 
     Ok(generated_files)
 }
+
+/// Analyzer with the type mappings and analysis options of `config` applied.
+///
+/// Shared by the CLI, build scripts and the library API, so the same configuration
+/// analyzes the same way everywhere.
+pub fn configured_analyzer(config: &config::GenerateConfig) -> CommandAnalyzer {
+    let logger = output::Logger::with_verbosity(config.verbosity());
+    let mut analyzer = CommandAnalyzer::new();
+
+    // Apply custom type mappings from configuration
+    if let Some(ref mappings) = config.type_mappings {
+        analyzer.add_type_mappings(mappings);
+        if config.is_verbose() {
+            logger.info(&format!(
+                "📝 Applied {} custom type mappings",
+                mappings.len()
+            ));
+            for (rust_type, ts_type) in mappings {
+                logger.detail(
+                    output::Phase::Analysis,
+                    2,
+                    &format!("  {} → {}", rust_type, ts_type),
+                );
+            }
+        }
+    }
+
+    // Apply serde(with) mappings from configuration
+    if let Some(ref mappings) = config.serde_with_mappings {
+        analyzer.add_serde_with_mappings(mappings);
+    }
+
+    // Apply custom collection mappings from configuration
+    if let Some(ref mappings) = config.collection_mappings {
+        analyzer.add_collection_mappings(mappings);
+        if config.is_verbose() {
+            logger.info(&format!(
+                "📝 Applied {} custom collection mappings",
+                mappings.len()
+            ));
+        }
+    }
+
+    // Apply custom union types from configuration
+    if let Some(ref union_types) = config.union_types {
+        analyzer.add_union_types(union_types);
+    }
+
+    // Apply additional Tauri-injected parameter types from configuration
+    if let Some(ref injected_types) = config.injected_types {
+        analyzer.add_injected_types(injected_types);
+    }
+
+    // Apply per-field TypeScript types from configuration
+    if let Some(ref overrides) = config.field_overrides {
+        analyzer.add_field_overrides(overrides.keys());
+    }
+
+    analyzer.set_strict_serde_derives(config.should_enforce_serde_derives());
+    analyzer.set_ipc_size_lint(config.should_lint_ipc_size());
+    analyzer.set_skip_zero_sized_fields(config.should_skip_zero_sized_fields());
+    analyzer.set_separate_input_types(config.should_separate_input_types());
+    analyzer.set_macro_records(config.macro_records.as_deref());
+    for source_path in config.additional_source_paths.iter().flatten() {
+        analyzer.add_source_path(
+            &source_path.path,
+            source_path.include.as_deref().unwrap_or_default(),
+        );
+    }
+    for shared in config.shared_types.iter().flatten() {
+        analyzer.add_source_path(&shared.path, &[]);
+    }
+    analyzer.add_root_types(config.stores().into_iter().map(|(_, type_name)| type_name));
+    analyzer.add_root_types(config.compact_types());
+    analyzer.add_type_mappings(&config.compact_type_mappings());
+    analyzer.set_all_types(config.should_include_all_types());
+    analyzer.set_max_scan_depth(config.max_scan_depth);
+    analyzer
+}
+
+/// Commands, types, events and constants found in Rust source (see [`analyze_source`])
+#[derive(Clone)]
+pub struct AnalysisResult {
+    pub commands: Vec<CommandInfo>,
+    /// Structs and enums by name
+    pub structs: HashMap<String, StructInfo>,
+    pub events: Vec<EventInfo>,
+    pub constants: Vec<ConstInfo>,
+}
+
+/// Analyze Rust source given as a string, without reading any files.
+///
+/// The source is analyzed as a single file, so types must be defined in it and `mod`
+/// declarations are not followed. Useful for playgrounds and tests of tooling built on
+/// the analysis.
+///
+/// # Example
+///
+/// ```rust
+/// let result = tauri_typegen::analyze_source(
+///     "#[tauri::command] fn greet(name: String) -> String { name }",
+/// )?;
+/// assert_eq!(result.commands[0].name, "greet");
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+pub fn analyze_source(source: &str) -> Result<AnalysisResult, Box<dyn std::error::Error>> {
+    let mut analyzer = CommandAnalyzer::new();
+    let commands = analyzer.analyze_source(source)?;
+    Ok(AnalysisResult {
+        commands,
+        structs: analyzer.get_discovered_structs().clone(),
+        events: analyzer.get_discovered_events().to_vec(),
+        constants: analyzer.get_discovered_constants().to_vec(),
+    })
+}
+
+/// `config` without the files written outside the output directory (the HTTP bridge,
+/// admin panel, compact codec and self-test command), for generating in memory
+#[cfg(feature = "generators")]
+pub(crate) fn in_memory_config(config: &config::GenerateConfig) -> config::GenerateConfig {
+    config::GenerateConfig {
+        http_bridge: None,
+        admin_panel: None,
+        compact_codec: None,
+        self_test: None,
+        ..config.clone()
+    }
+}
+
+/// Generate the bindings of Rust source given as a string, without touching the
+/// filesystem: nothing is read and the files are returned instead of written.
+///
+/// `projectPath` and `outputPath` of `config` are not used, and the HTTP bridge and
+/// admin panel, which live outside the output directory, are not generated.
+///
+/// # Example
+///
+/// ```rust
+/// use tauri_typegen::{generate_from_source, GenerateConfig};
+///
+/// let files = generate_from_source(
+///     "#[tauri::command] fn greet(name: String) -> String { name }",
+///     &GenerateConfig::default(),
+/// )?;
+/// let commands = files.iter().find(|file| file.name == "commands.ts").unwrap();
+/// assert!(commands.content.contains("export async function greet("));
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+#[cfg(feature = "generators")]
+pub fn generate_from_source(
+    source: &str,
+    config: &config::GenerateConfig,
+) -> Result<Vec<GeneratedFile>, Box<dyn std::error::Error>> {
+    config
+        .validate_options()
+        .map_err(|e| Box::new(e) as Box<dyn std::error::Error>)?;
+    let config = in_memory_config(config);
+
+    let mut analyzer = configured_analyzer(&config);
+    let commands = analyzer.analyze_source(source)?;
+    if commands.is_empty() && !config.should_include_all_types() {
+        return Ok(Vec::new());
+    }
+
+    let mut generator = generator_for(&config);
+    let mut file_writer = FileWriter::in_memory();
+    generator.write_models(
+        &mut file_writer,
+        &commands,
+        analyzer.get_discovered_structs(),
+        &analyzer,
+        &config,
+    )?;
+    Ok(file_writer.into_files())
+}
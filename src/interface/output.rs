@@ -1,5 +1,12 @@
+use crate::analysis::dependency_graph::VizFormat;
+use crate::analysis::ipc_size::PayloadDiagnostic;
+use crate::analysis::unsupported::UnsupportedConstruct;
+use crate::interface::messages::{Locale, Message, MessageId};
+#[cfg(feature = "generators")]
 use indicatif::{ProgressBar, ProgressStyle};
 use std::fmt;
+use std::sync::{Arc, Mutex};
+#[cfg(feature = "generators")]
 use std::time::Duration;
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -23,15 +30,148 @@ impl fmt::Display for LogLevel {
     }
 }
 
+/// Highest verbosity level (`-vvv`)
+pub const MAX_VERBOSITY: u8 = 3;
+
+/// Phase of a run whose verbose output can be traced on its own
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Phase {
+    /// Walking the project for Rust files
+    Scan,
+    /// Extracting commands and resolving types
+    Analysis,
+    /// Rendering and writing the bindings, running hooks
+    Generation,
+    /// Checking and saving the generation cache
+    Cache,
+}
+
+impl Phase {
+    pub const NAMES: [&'static str; 4] = ["scan", "analysis", "generation", "cache"];
+
+    pub fn parse(name: &str) -> Option<Self> {
+        match name.trim().to_lowercase().as_str() {
+            "scan" => Some(Phase::Scan),
+            "analysis" => Some(Phase::Analysis),
+            "generation" => Some(Phase::Generation),
+            "cache" => Some(Phase::Cache),
+            _ => None,
+        }
+    }
+}
+
+/// How much verbose output is logged.
+///
+/// Level 1 (`-v`) logs steps and summaries, level 2 (`-vv`) details such as the
+/// discovered types, level 3 (`-vvv`) everything including each file walked.
+/// Traced phases log everything regardless of the level.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Verbosity {
+    level: u8,
+    traced: Vec<Phase>,
+}
+
+impl Verbosity {
+    pub fn new(level: u8, traced: Vec<Phase>) -> Self {
+        Self {
+            level: level.min(MAX_VERBOSITY),
+            traced,
+        }
+    }
+
+    pub fn level(&self) -> u8 {
+        self.level
+    }
+
+    /// Whether anything beyond the default output is logged
+    pub fn is_verbose(&self) -> bool {
+        self.level > 0 || !self.traced.is_empty()
+    }
+
+    /// Whether messages of `phase` at `level` are logged
+    pub fn enabled(&self, phase: Phase, level: u8) -> bool {
+        self.level >= level || self.traced.contains(&phase)
+    }
+}
+
+/// Messages logged through a logger, so tests can check which messages were shown
+/// independent of their wording
+#[derive(Debug, Clone, Default)]
+pub struct MessageLog(Arc<Mutex<Vec<Message>>>);
+
+impl MessageLog {
+    pub fn messages(&self) -> Vec<Message> {
+        self.0.lock().map(|log| log.clone()).unwrap_or_default()
+    }
+
+    pub fn ids(&self) -> Vec<MessageId> {
+        self.messages().iter().map(|message| message.id).collect()
+    }
+
+    fn push(&self, message: &Message) {
+        if let Ok(mut log) = self.0.lock() {
+            log.push(message.clone());
+        }
+    }
+}
+
 #[derive(Debug, Clone)]
 pub struct Logger {
     verbose: bool,
     debug: bool,
+    verbosity: Verbosity,
+    locale: Locale,
+    log: Option<MessageLog>,
 }
 
 impl Logger {
     pub fn new(verbose: bool, debug: bool) -> Self {
-        Self { verbose, debug }
+        Self {
+            verbose,
+            debug,
+            verbosity: Verbosity::new(u8::from(verbose), Vec::new()),
+            locale: Locale::default(),
+            log: None,
+        }
+    }
+
+    /// Logger with a verbosity level and traced phases
+    pub fn with_verbosity(verbosity: Verbosity) -> Self {
+        Self {
+            verbose: verbosity.is_verbose(),
+            debug: false,
+            verbosity,
+            locale: Locale::default(),
+            log: None,
+        }
+    }
+
+    /// Logger showing catalog messages in `locale`
+    pub fn with_locale(mut self, locale: Locale) -> Self {
+        self.locale = locale;
+        self
+    }
+
+    pub fn locale(&self) -> Locale {
+        self.locale
+    }
+
+    /// Record the catalog messages of this logger and its clones from now on
+    pub fn record(&mut self) -> MessageLog {
+        self.log.get_or_insert_with(MessageLog::default).clone()
+    }
+
+    /// Wording of a catalog message in the logger's locale
+    pub fn text(&self, message: Message) -> String {
+        if let Some(ref log) = self.log {
+            log.push(&message);
+        }
+        message.render(self.locale)
+    }
+
+    /// Log a catalog message
+    pub fn message(&self, level: LogLevel, message: Message) {
+        self.log(level, &self.text(message));
     }
 
     pub fn should_log(&self, level: LogLevel) -> bool {
@@ -67,6 +207,34 @@ impl Logger {
         self.log(LogLevel::Warning, message);
     }
 
+    /// Warn about the constructs the bindings do not cover, as one section
+    pub fn unsupported_constructs(&self, constructs: &[UnsupportedConstruct]) {
+        if constructs.is_empty() {
+            return;
+        }
+        self.warning(
+            &self.text(
+                Message::new(MessageId::UnsupportedConstructs).arg("count", constructs.len()),
+            ),
+        );
+        self.info(UnsupportedConstruct::report(constructs).trim_end());
+    }
+
+    /// Point out the command payloads likely to exceed a practical IPC size, as one section
+    pub fn payload_diagnostics(&self, diagnostics: &[PayloadDiagnostic]) {
+        if diagnostics.is_empty() {
+            return;
+        }
+        self.info(
+            &self.text(Message::new(MessageId::LargePayloads).arg("count", diagnostics.len())),
+        );
+        let report: Vec<String> = diagnostics
+            .iter()
+            .map(|diagnostic| format!("• {}", diagnostic))
+            .collect();
+        self.info(&report.join("\n"));
+    }
+
     pub fn info(&self, message: &str) {
         self.log(LogLevel::Info, message);
     }
@@ -79,11 +247,29 @@ impl Logger {
         self.log(LogLevel::Verbose, message);
     }
 
+    /// Log a verbose message of `phase` shown from verbosity `level` on
+    pub fn detail(&self, phase: Phase, level: u8, message: &str) {
+        if self.verbosity.enabled(phase, level) {
+            println!("💬 {}", message);
+        }
+    }
+
+    pub fn verbosity(&self) -> &Verbosity {
+        &self.verbosity
+    }
+
+    /// Replace the verbosity, e.g. once the configuration is loaded
+    pub fn set_verbosity(&mut self, verbosity: Verbosity) {
+        self.verbose = verbosity.is_verbose();
+        self.verbosity = verbosity;
+    }
+
     pub fn is_verbose(&self) -> bool {
         self.verbose
     }
 }
 
+#[cfg(feature = "generators")]
 pub struct ProgressReporter {
     logger: Logger,
     progress_bar: Option<ProgressBar>,
@@ -92,6 +278,7 @@ pub struct ProgressReporter {
     step_name: String,
 }
 
+#[cfg(feature = "generators")]
 impl ProgressReporter {
     pub fn new(logger: Logger, total_steps: usize) -> Self {
         let progress_bar = if !logger.is_verbose() {
@@ -166,6 +353,29 @@ impl ProgressReporter {
         self.logger.verbose(message);
     }
 
+    /// Log a verbose message of `phase` shown from verbosity `level` on
+    pub fn detail(&self, phase: Phase, level: u8, message: &str) {
+        self.logger.detail(phase, level, message);
+    }
+
+    /// Wording of a catalog message in the logger's locale
+    pub fn text(&self, message: Message) -> String {
+        self.logger.text(message)
+    }
+
+    pub fn payload_diagnostics(&self, diagnostics: &[PayloadDiagnostic]) {
+        self.logger.payload_diagnostics(diagnostics);
+    }
+
+    pub fn unsupported_constructs(&self, constructs: &[UnsupportedConstruct]) {
+        self.logger.unsupported_constructs(constructs);
+    }
+
+    /// Replace the verbosity of the logger; the progress display is kept
+    pub fn set_verbosity(&mut self, verbosity: Verbosity) {
+        self.logger.set_verbosity(verbosity);
+    }
+
     pub fn finish(&self, total_message: &str) {
         if let Some(ref pb) = self.progress_bar {
             pb.finish_and_clear();
@@ -174,6 +384,7 @@ impl ProgressReporter {
     }
 }
 
+#[cfg(feature = "generators")]
 impl Drop for ProgressReporter {
     fn drop(&mut self) {
         // Ensure progress bar is cleared when reporter is dropped
@@ -183,34 +394,56 @@ impl Drop for ProgressReporter {
     }
 }
 
-pub fn print_usage_info(output_path: &str, generated_files: &[String], command_count: usize) {
+/// Summary after generation; `import_hint` shows how the frontend imports the bindings
+pub fn print_usage_info(
+    output_path: &str,
+    language: &str,
+    import_hint: Option<&str>,
+    command_count: usize,
+    locale: Locale,
+) {
     println!(
-        "\n✓ Generated TypeScript bindings for {} command{}",
-        command_count,
-        if command_count == 1 { "" } else { "s" }
+        "\n✓ {}",
+        Message::new(MessageId::UsageGenerated)
+            .arg("language", language)
+            .arg("count", command_count)
+            .render(locale)
     );
-    println!("📁 Location: {}", output_path);
-
-    println!("\n💡 Import in your frontend:");
-    for file in generated_files {
-        if file.ends_with("index.ts") || file.ends_with("index.js") {
-            println!(
-                "  import {{ /* commands */ }} from '{}'",
-                output_path.trim_end_matches('/')
-            );
-            break;
-        }
+    println!(
+        "📁 {}",
+        Message::new(MessageId::UsageLocation)
+            .arg("path", output_path)
+            .render(locale)
+    );
+
+    if let Some(import_hint) = import_hint {
+        println!(
+            "\n💡 {}",
+            Message::new(MessageId::UsageImport).render(locale)
+        );
+        println!("  {}", import_hint);
     }
 }
 
-pub fn print_dependency_visualization_info(output_path: &str) {
+pub fn print_dependency_visualization_info(output_path: &str, formats: &[VizFormat]) {
     println!("\n🌐 Dependency visualization generated:");
-    println!("  📄 {}/dependency-graph.txt", output_path);
-    println!("  📄 {}/dependency-graph.dot", output_path);
-    println!(
-        "\n💡 To generate a visual graph: dot -Tpng {}/dependency-graph.dot -o graph.png",
-        output_path
-    );
+    for format in formats {
+        println!("  📄 {}/{}", output_path, format.file_name());
+    }
+    if formats.contains(&VizFormat::Dot) {
+        println!(
+            "\n💡 To generate a visual graph: dot -Tpng {}/{} -o graph.png",
+            output_path,
+            VizFormat::Dot.file_name()
+        );
+    }
+    if formats.contains(&VizFormat::Html) {
+        println!(
+            "\n💡 Open {}/{} in a browser to explore the graph",
+            output_path,
+            VizFormat::Html.file_name()
+        );
+    }
 }
 
 #[cfg(test)]
@@ -244,6 +477,55 @@ mod tests {
     }
 
     #[test]
+    fn test_verbosity_levels() {
+        let verbosity = Verbosity::new(2, vec![]);
+        assert!(verbosity.enabled(Phase::Scan, 1));
+        assert!(verbosity.enabled(Phase::Analysis, 2));
+        assert!(!verbosity.enabled(Phase::Scan, 3));
+        assert_eq!(Verbosity::new(5, vec![]).level(), MAX_VERBOSITY);
+        assert!(!Verbosity::default().is_verbose());
+    }
+
+    #[test]
+    fn test_verbosity_traced_phases() {
+        let verbosity = Verbosity::new(0, vec![Phase::Analysis]);
+        assert!(verbosity.is_verbose());
+        assert!(verbosity.enabled(Phase::Analysis, MAX_VERBOSITY));
+        assert!(!verbosity.enabled(Phase::Scan, 1));
+
+        let logger = Logger::with_verbosity(verbosity);
+        assert!(logger.is_verbose());
+        assert!(logger.should_log(LogLevel::Verbose));
+    }
+
+    #[test]
+    fn test_phase_parse() {
+        assert_eq!(Phase::parse("analysis"), Some(Phase::Analysis));
+        assert_eq!(Phase::parse(" Scan "), Some(Phase::Scan));
+        assert_eq!(Phase::parse("rendering"), None);
+    }
+
+    #[test]
+    fn test_logger_records_message_ids() {
+        let mut logger = Logger::new(false, false).with_locale(Locale::De);
+        let log = logger.record();
+
+        let text = logger.text(Message::new(MessageId::GeneratedFiles).arg("count", 2));
+        logger
+            .clone()
+            .message(LogLevel::Debug, Message::new(MessageId::UpToDate));
+
+        assert_eq!(text, "2 Dateien generiert");
+        // Recorded even when the level is not shown
+        assert_eq!(
+            log.ids(),
+            vec![MessageId::GeneratedFiles, MessageId::UpToDate]
+        );
+        assert_eq!(log.messages()[0].args, vec![("count", "2".to_string())]);
+    }
+
+    #[test]
+    #[cfg(feature = "generators")]
     fn test_progress_reporter() {
         let logger = Logger::new(false, false);
         let mut reporter = ProgressReporter::new(logger, 3);
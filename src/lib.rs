@@ -3,14 +3,14 @@
 //! Automatically generate TypeScript bindings from Tauri commands.
 //!
 //! This library scans Rust source code for `#[tauri::command]` functions and generates
-//! strongly-typed TypeScript interfaces, command functions, and optional Zod schemas
+//! strongly-typed TypeScript interfaces, command functions, and optional Zod or Valibot schemas
 //! with runtime validation.
 //!
 //! ## Features
 //!
 //! - 🔍 **Automatic Discovery**: Scans Rust source for `#[tauri::command]` functions
 //! - 📝 **TypeScript Generation**: Creates TypeScript interfaces for command parameters and return types
-//! - ✅ **Validation Support**: Optional Zod schema generation with runtime validation
+//! - ✅ **Validation Support**: Optional Zod or Valibot schema generation with runtime validation
 //! - 🚀 **Command Bindings**: Strongly-typed frontend functions
 //! - 📡 **Event Support**: Discovers and types `app.emit()` events
 //! - 📞 **Channel Support**: Types for streaming `Channel<T>` parameters
@@ -44,7 +44,12 @@
 //!
 //! ### Programmatic Usage
 //!
+//! The [`api`] module holds the entry points covered by semantic versioning: analyzing,
+//! generating, diffing and watching. The remaining modules are internals of the CLI.
+//!
 //! ```rust,no_run
+//! # #[cfg(feature = "generators")]
+//! # fn main() -> Result<(), Box<dyn std::error::Error>> {
 //! use tauri_typegen::{GenerateConfig, generate_from_config};
 //!
 //! let config = GenerateConfig {
@@ -56,9 +61,19 @@
 //! };
 //!
 //! let files = generate_from_config(&config)?;
-//! # Ok::<(), Box<dyn std::error::Error>>(())
+//! # Ok(())
+//! # }
+//! # #[cfg(not(feature = "generators"))]
+//! # fn main() {}
 //! ```
 //!
+//! ## Cargo Features
+//!
+//! - `cli` (default): the `cargo tauri-typegen` binary; implies `generators`
+//! - `generators`: TypeScript/Zod generation, `BuildSystem` and progress output
+//!
+//! With `default-features = false` the crate is an analysis-only library.
+//!
 //! ## Example
 //!
 //! Given this Rust code:
@@ -104,19 +119,28 @@
 //!       "validation_library": "zod",
 //!       "type_mappings": {
 //!         "DateTime<Utc>": "string",
-//!         "PathBuf": "string"
+//!         "Uuid": "string"
 //!       }
 //!     }
 //!   }
 //! }
 //! ```
 
+// Stable entry points for embedders; the modules below are internals of the CLI
+pub mod api;
+
 // Core library modules for the CLI tool
+#[doc(hidden)]
 pub mod analysis;
+#[cfg(feature = "generators")]
+#[doc(hidden)]
 pub mod build;
 // pub mod commands; // Removed: plugin commands are not used
 mod error;
+#[cfg(feature = "generators")]
+#[doc(hidden)]
 pub mod generators;
+#[doc(hidden)]
 pub mod interface;
 pub mod models;
 
@@ -124,9 +148,18 @@ pub use error::{Error, Result};
 pub use models::*;
 
 // Convenience re-exports for common use cases
-pub use interface::config::GenerateConfig;
-pub use interface::generate_from_config;
-pub use interface::output::{Logger, ProgressReporter};
+#[cfg(feature = "generators")]
+pub use generators::base::file_writer::GeneratedFile;
+pub use interface::config::{
+    AdditionalSourcePath, FieldOverride, GenerateConfig, GenerationHooks, OutputFileNames,
+};
+pub use interface::output::Logger;
+#[cfg(feature = "generators")]
+pub use interface::output::ProgressReporter;
+pub use interface::{analyze_source, AnalysisResult};
+#[cfg(feature = "generators")]
+pub use interface::{generate_from_config, generate_from_source};
 
 // Build system integration
+#[cfg(feature = "generators")]
 pub use build::BuildSystem;
@@ -3,8 +3,9 @@ use serde_rename_rule::RenameRule;
 
 /// Represents the structure of a type for code generation
 /// This allows generators to work with parsed type information instead of string parsing
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
+#[non_exhaustive]
 pub enum TypeStructure {
     /// Primitive types: "string", "number", "boolean", "void"
     Primitive(String),
@@ -30,6 +31,9 @@ pub enum TypeStructure {
     /// Result types: `Result<T, E>` -> `Result(T)` (error type ignored for TS)
     Result(Box<TypeStructure>),
 
+    /// Union types: `Either<A, B>` -> `Union([A, B])`
+    Union(Vec<TypeStructure>),
+
     /// Custom/User-defined types
     Custom(String),
 }
@@ -41,6 +45,9 @@ impl Default for TypeStructure {
     }
 }
 
+#[derive(Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+#[non_exhaustive]
 pub struct CommandInfo {
     pub name: String,
     pub file_path: String,
@@ -53,10 +60,102 @@ pub struct CommandInfo {
     pub channels: Vec<ChannelInfo>,
     /// Serde rename_all attribute: #[serde(rename_all = "...")]
     /// Applied to command function, affects parameter/channel serialization
+    #[serde(with = "rename_rule")]
     pub serde_rename_all: Option<RenameRule>,
+    /// Parameters injected by Tauri instead of sent by the frontend
+    /// (`AppHandle`, `State<T>`, `Window`, channels, ...), as well as parameters
+    /// that cannot cross the IPC boundary (`&mut T`, raw pointers, `std::fs::File`)
+    pub injected_parameters: Vec<InjectedParameter>,
+    /// Returns `tauri::ipc::Response` (or `Result<Response, E>`), which Tauri sends as
+    /// raw bytes instead of JSON. The return type structure is then `Uint8Array`.
+    pub raw_response: bool,
+    /// Calls queued by the binding instead of sent in parallel:
+    /// `#[typegen(serialize_calls)]` or `#[typegen(serialize_calls = "param")]`
+    pub serialize_calls: Option<CallSerialization>,
+    /// Query commands whose cached results a successful call makes stale:
+    /// `#[typegen(invalidates = "get_projects")]`, repeatable
+    pub invalidates: Vec<String>,
+}
+
+/// (De)serializes a serde rename rule by its `rename_all` name, e.g. `"camelCase"`
+mod rename_rule {
+    use serde::de::Error;
+    use serde::{Deserialize, Deserializer, Serialize, Serializer};
+    use serde_rename_rule::RenameRule;
+
+    pub fn serialize<S: Serializer>(
+        rule: &Option<RenameRule>,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        rule.as_ref()
+            .map(RenameRule::to_rename_all_str)
+            .serialize(serializer)
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Option<RenameRule>, D::Error> {
+        Option::<String>::deserialize(deserializer)?
+            .map(|name| {
+                RenameRule::from_rename_all_str(&name)
+                    .map_err(|_| D::Error::custom(format!("unknown rename rule: {}", name)))
+            })
+            .transpose()
+    }
+}
+
+/// How the binding of a command queues concurrent calls
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum CallSerialization {
+    /// One call of the command at a time
+    Command,
+    /// One call at a time per value of the named parameter
+    Key(String),
+}
+
+/// Whether a command reads state or changes it
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum CommandKind {
+    Query,
+    #[default]
+    Mutation,
+}
+
+impl CommandKind {
+    /// First words of commands that only read state, e.g. `get_projects` or `is_enabled`
+    const QUERY_PREFIXES: &'static [&'static str] = &[
+        "get", "list", "fetch", "load", "find", "search", "read", "query", "is", "has", "count",
+    ];
+
+    /// Kind of a command by the first word of its name; anything not known to read
+    /// state is treated as a mutation
+    pub fn infer(command_name: &str) -> Self {
+        let first_word = command_name
+            .trim_start_matches('_')
+            .split('_')
+            .next()
+            .unwrap_or_default();
+        if Self::QUERY_PREFIXES.contains(&first_word.to_lowercase().as_str()) {
+            CommandKind::Query
+        } else {
+            CommandKind::Mutation
+        }
+    }
 }
 
 impl CommandInfo {
+    /// Command `name` taking `parameters` and returning `return_type` (e.g.
+    /// `"Result<User, String>"`), synchronous and without a source location
+    pub fn new(
+        name: impl Into<String>,
+        parameters: Vec<ParameterInfo>,
+        return_type: impl Into<String>,
+    ) -> Self {
+        Self::new_for_test(name, "", 0, parameters, return_type, false, Vec::new())
+    }
+
     /// Helper for tests: Create a CommandInfo
     #[doc(hidden)]
     pub fn new_for_test(
@@ -83,10 +182,27 @@ impl CommandInfo {
             is_async,
             channels,
             serde_rename_all: None,
+            injected_parameters: Vec::new(),
+            raw_response: false,
+            serialize_calls: None,
+            invalidates: Vec::new(),
         }
     }
 }
 
+/// A command parameter supplied by Tauri rather than the invoke payload
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct InjectedParameter {
+    pub name: String,
+    pub rust_type: String,
+    /// Position in the function signature, counting all parameters
+    pub position: usize,
+}
+
+#[derive(Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+#[non_exhaustive]
 pub struct ParameterInfo {
     pub name: String,
     pub rust_type: String,
@@ -98,17 +214,108 @@ pub struct ParameterInfo {
     pub serde_rename: Option<String>,
 }
 
-#[derive(Clone, Debug)]
+impl ParameterInfo {
+    /// Parameter `name` of Rust type `rust_type`, optional if it is an `Option`
+    pub fn new(name: impl Into<String>, rust_type: impl Into<String>) -> Self {
+        let rust_type = rust_type.into();
+        let type_structure =
+            crate::analysis::type_resolver::TypeResolver::new().parse_type_structure(&rust_type);
+        Self {
+            name: name.into(),
+            is_optional: matches!(type_structure, TypeStructure::Optional(_)),
+            rust_type,
+            type_structure,
+            serde_rename: None,
+        }
+    }
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+#[non_exhaustive]
 pub struct StructInfo {
     pub name: String,
     pub fields: Vec<FieldInfo>,
     pub file_path: String,
+    /// Line of the struct or enum name
+    pub line_number: usize,
     pub is_enum: bool,
     /// Serde rename_all attribute: #[serde(rename_all = "...")]
+    #[serde(with = "rename_rule")]
     pub serde_rename_all: Option<RenameRule>,
+    /// Single-field tuple struct wrapping a collection, e.g. `struct Tags(Vec<String>)`.
+    /// Its only field holds the wrapped type and is generated as a type alias.
+    pub is_newtype: bool,
+    /// Serde tag attribute on enums: #[serde(tag = "...")]
+    pub serde_tag: Option<String>,
+    /// Serde content attribute on enums: #[serde(content = "...")]
+    pub serde_content: Option<String>,
+    /// Serde untagged attribute on enums: #[serde(untagged)]
+    pub serde_untagged: bool,
+    /// Serde deny_unknown_fields attribute on structs: #[serde(deny_unknown_fields)]
+    #[serde(default)]
+    pub serde_deny_unknown_fields: bool,
+    /// Progress message of a channel, annotated `#[typegen(progress)]`
+    #[serde(default)]
+    pub is_progress: bool,
 }
 
-#[derive(Clone, Debug)]
+impl StructInfo {
+    /// Struct `name` with `fields`, without serde attributes or a source location.
+    /// Set `is_enum` for an enum, whose fields are then its variants.
+    pub fn new(name: impl Into<String>, fields: Vec<FieldInfo>) -> Self {
+        Self {
+            name: name.into(),
+            fields,
+            file_path: String::new(),
+            line_number: 0,
+            is_enum: false,
+            serde_rename_all: None,
+            is_newtype: false,
+            serde_tag: None,
+            serde_content: None,
+            serde_untagged: false,
+            serde_deny_unknown_fields: false,
+            is_progress: false,
+        }
+    }
+
+    /// Whether this is an adjacently tagged enum: #[serde(tag = "...", content = "...")]
+    pub fn is_adjacently_tagged(&self) -> bool {
+        self.is_enum && self.serde_tag.is_some() && self.serde_content.is_some()
+    }
+
+    /// Whether this is an internally tagged enum: #[serde(tag = "...")]
+    pub fn is_internally_tagged(&self) -> bool {
+        self.is_enum && self.serde_tag.is_some() && self.serde_content.is_none()
+    }
+
+    /// Whether this is an externally tagged enum, serde's default representation:
+    /// unit variants as their name, others as `{ "Variant": payload }`
+    pub fn is_externally_tagged(&self) -> bool {
+        self.is_enum && self.serde_tag.is_none() && !self.serde_untagged
+    }
+
+    /// Whether any variant of this enum carries a payload
+    pub fn has_variant_data(&self) -> bool {
+        self.is_enum
+            && self.fields.iter().any(|variant| {
+                matches!(
+                    variant.rust_type.as_str(),
+                    "enum_variant_tuple" | "enum_variant_struct"
+                )
+            })
+    }
+
+    /// Whether this is an untagged enum: #[serde(untagged)]
+    pub fn is_untagged(&self) -> bool {
+        self.is_enum && self.serde_untagged
+    }
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+#[non_exhaustive]
 pub struct FieldInfo {
     pub name: String,
     pub rust_type: String,
@@ -119,9 +326,72 @@ pub struct FieldInfo {
     pub serde_rename: Option<String>,
     /// Structured representation of the type for generators
     pub type_structure: TypeStructure,
+    /// Payload fields of a tuple or struct enum variant (tuple fields are named "0", "1", ...)
+    pub variant_fields: Vec<FieldInfo>,
+    /// Fixed value from `#[typegen(literal = "...")]`, typed as that string literal
+    #[serde(default)]
+    pub literal: Option<String>,
+    /// Serde flatten attribute: #[serde(flatten)]; the fields of its type are
+    /// serialized next to the other fields of the struct
+    #[serde(default)]
+    pub flatten: bool,
+    /// The key may be absent: `#[serde(default)]` fills it in when deserializing,
+    /// `skip_serializing_if` leaves it out when serializing
+    #[serde(default)]
+    pub omittable: bool,
+    /// `#[serde(default)]` on the field or its struct: filled in when absent from the input
+    #[serde(default)]
+    pub defaulted: bool,
+    /// `#[serde(skip_serializing_if = "...")]`: may be absent from the output
+    #[serde(default)]
+    pub skip_serializing_if: bool,
+    /// Direction serde skips the field in: `#[serde(skip_serializing)]` or
+    /// `#[serde(skip_deserializing)]`
+    #[serde(default)]
+    pub skipped: Option<SkipDirection>,
+    /// Module or function serde converts the field with: `#[serde(with = "...")]`,
+    /// `serialize_with` or `deserialize_with`
+    #[serde(default)]
+    pub serde_with: Option<String>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+impl FieldInfo {
+    /// Public field `name` of Rust type `rust_type`, optional if it is an `Option`
+    pub fn new(name: impl Into<String>, rust_type: impl Into<String>) -> Self {
+        let rust_type = rust_type.into();
+        let type_structure =
+            crate::analysis::type_resolver::TypeResolver::new().parse_type_structure(&rust_type);
+        Self {
+            name: name.into(),
+            is_optional: matches!(type_structure, TypeStructure::Optional(_)),
+            rust_type,
+            is_public: true,
+            validator_attributes: None,
+            serde_rename: None,
+            type_structure,
+            variant_fields: Vec::new(),
+            literal: None,
+            flatten: false,
+            omittable: false,
+            defaulted: false,
+            skip_serializing_if: false,
+            skipped: None,
+            serde_with: None,
+        }
+    }
+}
+
+/// Direction a field is skipped in by serde
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum SkipDirection {
+    /// Left out of the output (command results, events), read from the input
+    Serializing,
+    /// Written to the output, ignored in the input (command parameters)
+    Deserializing,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct ValidatorAttributes {
     pub length: Option<LengthConstraint>,
@@ -148,6 +418,9 @@ pub struct RangeConstraint {
 }
 
 // Event information for frontend event listeners
+#[derive(Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+#[non_exhaustive]
 pub struct EventInfo {
     pub event_name: String,
     pub payload_type: String,
@@ -155,10 +428,69 @@ pub struct EventInfo {
     pub payload_type_structure: TypeStructure,
     pub file_path: String,
     pub line_number: usize,
+    /// Function whose body emits the event
+    #[serde(default)]
+    pub emitted_by: Option<String>,
+}
+
+impl EventInfo {
+    /// Event `event_name` with a payload of Rust type `payload_type`, without a source
+    /// location
+    pub fn new(event_name: impl Into<String>, payload_type: impl Into<String>) -> Self {
+        let payload_type = payload_type.into();
+        Self {
+            event_name: event_name.into(),
+            payload_type_structure: crate::analysis::type_resolver::TypeResolver::new()
+                .parse_type_structure(&payload_type),
+            payload_type,
+            file_path: String::new(),
+            line_number: 0,
+            emitted_by: None,
+        }
+    }
+}
+
+// Constant exported to the frontend via #[typegen(export)]
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+#[non_exhaustive]
+pub struct ConstInfo {
+    pub name: String,
+    pub rust_type: String,
+    /// Value evaluated from the constant's initializer
+    pub value: ConstValue,
+    pub file_path: String,
+    pub line_number: usize,
+}
+
+impl ConstInfo {
+    /// Constant `name` of Rust type `rust_type` with `value`, without a source location
+    pub fn new(name: impl Into<String>, rust_type: impl Into<String>, value: ConstValue) -> Self {
+        Self {
+            name: name.into(),
+            rust_type: rust_type.into(),
+            value,
+            file_path: String::new(),
+            line_number: 0,
+        }
+    }
+}
+
+/// Evaluated value of a Rust constant
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum ConstValue {
+    Bool(bool),
+    Integer(i128),
+    Float(f64),
+    String(String),
+    Array(Vec<ConstValue>),
 }
 
 // Channel information for streaming data from Rust to frontend
-#[derive(Clone)]
+#[derive(Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+#[non_exhaustive]
 pub struct ChannelInfo {
     pub parameter_name: String,
     pub message_type: String,
@@ -173,6 +505,16 @@ pub struct ChannelInfo {
 }
 
 impl ChannelInfo {
+    /// Channel parameter `parameter_name` of command `command_name`, sending messages of
+    /// Rust type `message_type`, without a source location
+    pub fn new(
+        parameter_name: impl Into<String>,
+        message_type: impl Into<String>,
+        command_name: impl Into<String>,
+    ) -> Self {
+        Self::new_for_test(parameter_name, message_type, command_name, "", 0)
+    }
+
     /// Helper for tests: Create a ChannelInfo
     #[doc(hidden)]
     pub fn new_for_test(
@@ -723,6 +1065,14 @@ mod tests {
                 validator_attributes: None,
                 serde_rename: None,
                 type_structure: TypeStructure::Primitive("string".to_string()),
+                variant_fields: Vec::new(),
+                literal: None,
+                flatten: false,
+                omittable: false,
+                defaulted: false,
+                skip_serializing_if: false,
+                skipped: None,
+                serde_with: None,
             };
 
             let struct_info = StructInfo {
@@ -731,6 +1081,13 @@ mod tests {
                 file_path: "src/models.rs".to_string(),
                 is_enum: false,
                 serde_rename_all: None,
+                is_newtype: false,
+                serde_tag: None,
+                serde_content: None,
+                serde_untagged: false,
+                line_number: 0,
+                is_progress: false,
+                serde_deny_unknown_fields: false,
             };
 
             assert_eq!(struct_info.name, "User");
@@ -746,6 +1103,13 @@ mod tests {
                 file_path: "src/types.rs".to_string(),
                 is_enum: true,
                 serde_rename_all: Some(RenameRule::CamelCase),
+                is_newtype: false,
+                serde_tag: None,
+                serde_content: None,
+                serde_untagged: false,
+                line_number: 0,
+                is_progress: false,
+                serde_deny_unknown_fields: false,
             };
 
             assert!(struct_info.is_enum);
@@ -760,6 +1124,13 @@ mod tests {
                 file_path: "src/product.rs".to_string(),
                 is_enum: false,
                 serde_rename_all: None,
+                is_newtype: false,
+                serde_tag: None,
+                serde_content: None,
+                serde_untagged: false,
+                line_number: 0,
+                is_progress: false,
+                serde_deny_unknown_fields: false,
             };
 
             let cloned = original.clone();
@@ -794,6 +1165,14 @@ mod tests {
                 validator_attributes: Some(validator),
                 serde_rename: None,
                 type_structure: TypeStructure::Primitive("string".to_string()),
+                variant_fields: Vec::new(),
+                literal: None,
+                flatten: false,
+                omittable: false,
+                defaulted: false,
+                skip_serializing_if: false,
+                skipped: None,
+                serde_with: None,
             };
 
             assert!(field.validator_attributes.is_some());
@@ -811,6 +1190,14 @@ mod tests {
                 validator_attributes: None,
                 serde_rename: None,
                 type_structure: TypeStructure::Primitive("number".to_string()),
+                variant_fields: Vec::new(),
+                literal: None,
+                flatten: false,
+                omittable: false,
+                defaulted: false,
+                skip_serializing_if: false,
+                skipped: None,
+                serde_with: None,
             };
 
             assert!(!field.is_public);
@@ -828,6 +1215,14 @@ mod tests {
                 type_structure: TypeStructure::Optional(Box::new(TypeStructure::Primitive(
                     "string".to_string(),
                 ))),
+                variant_fields: Vec::new(),
+                literal: None,
+                flatten: false,
+                omittable: false,
+                defaulted: false,
+                skip_serializing_if: false,
+                skipped: None,
+                serde_with: None,
             };
 
             assert_eq!(field.serde_rename, Some("createdAt".to_string()));
@@ -844,6 +1239,14 @@ mod tests {
                 validator_attributes: None,
                 serde_rename: None,
                 type_structure: TypeStructure::Primitive("number".to_string()),
+                variant_fields: Vec::new(),
+                literal: None,
+                flatten: false,
+                omittable: false,
+                defaulted: false,
+                skip_serializing_if: false,
+                skipped: None,
+                serde_with: None,
             };
 
             let cloned = original.clone();
@@ -864,6 +1267,7 @@ mod tests {
                 payload_type_structure: TypeStructure::Custom("User".to_string()),
                 file_path: "src/events.rs".to_string(),
                 line_number: 100,
+                emitted_by: None,
             };
 
             assert_eq!(event.event_name, "user-updated");
@@ -882,6 +1286,7 @@ mod tests {
                 payload_type_structure: TypeStructure::Primitive("number".to_string()),
                 file_path: "src/progress.rs".to_string(),
                 line_number: 50,
+                emitted_by: None,
             };
 
             match event.payload_type_structure {
@@ -890,4 +1295,17 @@ mod tests {
             }
         }
     }
+
+    mod command_kind {
+        use super::*;
+
+        #[test]
+        fn test_infer_from_name() {
+            assert_eq!(CommandKind::infer("get_projects"), CommandKind::Query);
+            assert_eq!(CommandKind::infer("is_enabled"), CommandKind::Query);
+            assert_eq!(CommandKind::infer("search"), CommandKind::Query);
+            assert_eq!(CommandKind::infer("create_project"), CommandKind::Mutation);
+            assert_eq!(CommandKind::infer("getaway"), CommandKind::Mutation);
+        }
+    }
 }
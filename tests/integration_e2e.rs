@@ -86,6 +86,47 @@ fn test_vanilla_typescript_full_pipeline() {
     assert!(index.contains("export * from './commands'"));
 }
 
+#[test]
+fn test_command_examples_full_pipeline() {
+    let project = TestProject::new();
+
+    project.write_file(
+        "main.rs",
+        r#"
+        #[tauri::command]
+        pub fn greet(name: String) -> String {
+            name
+        }
+
+        #[tauri::command]
+        pub fn ping() -> String {
+            String::new()
+        }
+    "#,
+    );
+
+    let (analyzer, commands) = project.analyze();
+    for validation in ["none", "zod", "valibot"] {
+        let generator = TestGenerator::new();
+        generator.generate(
+            &commands,
+            analyzer.get_discovered_structs(),
+            &analyzer,
+            Some(validation),
+            None,
+        );
+        let commands_ts = generator.read_file("commands.ts");
+        assert!(commands_ts.contains("await greet({ name: 'example' });"));
+        // Commands without parameters are documented once
+        assert_eq!(
+            commands_ts
+                .matches("@example\n * const result = await ping();")
+                .count(),
+            1
+        );
+    }
+}
+
 /// Test complete Zod TypeScript generation from Rust to TS with validation
 #[test]
 fn test_zod_typescript_full_pipeline() {
@@ -168,6 +209,78 @@ fn test_zod_typescript_full_pipeline() {
     assert!(commands_file.contains("createProduct") || commands_file.contains("create_product"));
 }
 
+/// Test complete Valibot generation: schemas with validation actions and parse calls
+#[test]
+fn test_valibot_full_pipeline() {
+    let project = TestProject::new();
+
+    project.write_file(
+        "main.rs",
+        r#"
+        use serde::{Deserialize, Serialize};
+        use validator::Validate;
+
+        #[derive(Debug, Clone, Serialize, Deserialize, Validate)]
+        #[serde(rename_all = "camelCase")]
+        pub struct Product {
+            #[validate(length(min = 1, max = 40))]
+            pub name: String,
+            #[validate(range(min = 0))]
+            pub price: f64,
+            pub kind: Kind,
+            pub note: Option<String>,
+        }
+
+        #[derive(Debug, Clone, Serialize, Deserialize)]
+        pub enum Kind {
+            Physical,
+            Digital,
+        }
+
+        #[tauri::command]
+        pub fn create_product(product: Product) -> Result<Product, String> {
+            Ok(product)
+        }
+
+        #[tauri::command]
+        pub fn count_products() -> u32 {
+            0
+        }
+    "#,
+    );
+
+    let (analyzer, commands) = project.analyze();
+    let generator = TestGenerator::new();
+    generator.generate(
+        &commands,
+        analyzer.get_discovered_structs(),
+        &analyzer,
+        Some("valibot"),
+        None,
+    );
+
+    let types = generator.read_file("types.ts");
+    assert!(types.contains("import * as v from 'valibot';"));
+    assert!(!types.contains("z."));
+    assert!(types.contains("name: v.pipe(v.string(), v.minLength(1), v.maxLength(40)),"));
+    assert!(types.contains("price: v.pipe(v.number(), v.minValue(0)),"));
+    assert!(types.contains("kind: KindSchema,"));
+    assert!(types.contains("note: v.optional(v.string()),"));
+    assert!(types.contains("v.picklist([\"Physical\", \"Digital\"])"));
+    assert!(types.contains("export type Product = v.InferOutput<typeof ProductSchema>;"));
+    assert!(types.contains("export const CreateProductParamsSchema"));
+
+    let commands_file = generator.read_file("commands.ts");
+    assert!(commands_file.contains("import * as v from 'valibot';"));
+    assert!(commands_file
+        .contains("onValidationError?: (error: v.ValiError<v.GenericSchema>) => void;"));
+    assert!(commands_file
+        .contains("const result = v.safeParse(types.CreateProductParamsSchema, params);"));
+    assert!(commands_file.contains("await invoke<types.Product>('create_product', result.output)"));
+    assert!(commands_file.contains("if (!(error instanceof v.ValiError))"));
+    assert!(!commands_file.contains("ZodError"));
+}
+
 /// Test complete pipeline with commands, channels, and events together
 #[test]
 fn test_complete_app_with_commands_channels_and_events() {
@@ -511,3 +624,4704 @@ fn test_event_payload_discovery_from_helper_function() {
         events_file
     );
 }
+
+/// Test custom output file names are used for files, imports and re-exports
+#[test]
+fn test_custom_output_file_names() {
+    let project = TestProject::new();
+
+    project.write_file(
+        "main.rs",
+        r#"
+        use serde::{Deserialize, Serialize};
+
+        #[derive(Debug, Clone, Serialize, Deserialize)]
+        pub struct User {
+            pub name: String,
+        }
+
+        #[tauri::command]
+        pub fn get_user(app: tauri::AppHandle) -> User {
+            let user = User { name: "Test".to_string() };
+            app.emit("user-loaded", user.clone()).ok();
+            user
+        }
+    "#,
+    );
+
+    let (analyzer, commands) = project.analyze();
+
+    let config = tauri_typegen::GenerateConfig {
+        output_file_names: Some(tauri_typegen::OutputFileNames {
+            types: "models.ts".to_string(),
+            commands: "ipc.ts".to_string(),
+            ..Default::default()
+        }),
+        ..Default::default()
+    };
+
+    for validation in ["none", "zod"] {
+        let generator = TestGenerator::new();
+        let files = generator.generate(
+            &commands,
+            analyzer.get_discovered_structs(),
+            &analyzer,
+            Some(validation),
+            Some(&config),
+        );
+
+        assert!(files.contains(&"models.ts".to_string()));
+        assert!(files.contains(&"ipc.ts".to_string()));
+        assert!(files.contains(&"events.ts".to_string()));
+        assert!(!generator.file_exists("types.ts"));
+        assert!(!generator.file_exists("commands.ts"));
+
+        let ipc = generator.read_file("ipc.ts");
+        assert!(ipc.contains("import * as types from './models';"));
+
+        let events = generator.read_file("events.ts");
+        assert!(events.contains("import * as types from './models';"));
+
+        let index = generator.read_file("index.ts");
+        assert!(index.contains("export * from './models';"));
+        assert!(index.contains("export * from './ipc';"));
+        assert!(index.contains("export * from './events';"));
+        assert!(!index.contains("'./index'"));
+    }
+}
+
+/// Test newtypes over collections are generated as type aliases
+#[test]
+fn test_collection_newtypes_full_pipeline() {
+    let project = TestProject::new();
+
+    project.write_file(
+        "main.rs",
+        r#"
+        use serde::{Deserialize, Serialize};
+        use std::collections::HashMap;
+
+        #[derive(Serialize, Deserialize)]
+        pub struct Tags(Vec<String>);
+
+        #[derive(Serialize, Deserialize)]
+        pub struct Index(HashMap<String, u32>);
+
+        #[derive(Serialize, Deserialize)]
+        pub struct Item {
+            pub name: String,
+        }
+
+        #[derive(Serialize, Deserialize)]
+        pub struct Items(pub Vec<Item>);
+
+        #[tauri::command]
+        pub fn get_tags(index: Index) -> Tags {
+            Tags(vec![])
+        }
+
+        #[tauri::command]
+        pub fn get_items() -> Items {
+            Items(vec![])
+        }
+    "#,
+    );
+
+    let (analyzer, commands) = project.analyze();
+    assert_eq!(commands.len(), 2);
+
+    let generator = TestGenerator::new();
+    generator.generate(
+        &commands,
+        analyzer.get_discovered_structs(),
+        &analyzer,
+        Some("none"),
+        None,
+    );
+    let types = generator.read_file("types.ts");
+    assert!(types.contains("export type Tags = string[];"));
+    assert!(types.contains("export type Index = Record<string, number>;"));
+    assert!(types.contains("export type Items = Item[];"));
+    assert!(types.contains("export interface Item {"));
+    assert!(!types.contains("export interface Tags"));
+
+    let generator = TestGenerator::new();
+    generator.generate(
+        &commands,
+        analyzer.get_discovered_structs(),
+        &analyzer,
+        Some("zod"),
+        None,
+    );
+    let types = generator.read_file("types.ts");
+    assert!(
+        types.contains("export const TagsSchema = /* @__PURE__ */ (() => z.array(z.string()))();")
+    );
+    assert!(types.contains("export type Tags = z.infer<typeof TagsSchema>;"));
+    assert!(
+        types.contains("export const ItemsSchema = /* @__PURE__ */ (() => z.array(ItemSchema))();")
+    );
+    let item_pos = types.find("export const ItemSchema").unwrap();
+    let items_pos = types.find("export const ItemsSchema").unwrap();
+    assert!(item_pos < items_pos, "Item must be declared before Items");
+}
+
+/// Test collection types from third-party crates map to arrays, records and sets
+#[test]
+fn test_collection_crate_types_full_pipeline() {
+    let project = TestProject::new();
+
+    project.write_file(
+        "main.rs",
+        r#"
+        use indexmap::{IndexMap, IndexSet};
+        use serde::{Deserialize, Serialize};
+        use smallvec::SmallVec;
+
+        #[derive(Serialize, Deserialize)]
+        pub struct Tag {
+            pub label: String,
+        }
+
+        #[derive(Serialize, Deserialize)]
+        pub struct Catalog {
+            pub by_id: IndexMap<String, Tag>,
+            pub names: IndexSet<String>,
+            pub recent: SmallVec<[Tag; 4]>,
+            pub raw: bytes::Bytes,
+        }
+
+        #[tauri::command]
+        pub fn get_catalog() -> Catalog {
+            unimplemented!()
+        }
+    "#,
+    );
+
+    let (analyzer, commands) = project.analyze();
+    assert!(analyzer.get_discovered_structs().contains_key("Tag"));
+
+    let generator = TestGenerator::new();
+    generator.generate(
+        &commands,
+        analyzer.get_discovered_structs(),
+        &analyzer,
+        Some("none"),
+        None,
+    );
+    let types = generator.read_file("types.ts");
+    assert!(types.contains("by_id: Record<string, Tag>;"));
+    assert!(types.contains("names: string[];"));
+    assert!(types.contains("recent: Tag[];"));
+    assert!(types.contains("raw: number[];"));
+
+    let generator = TestGenerator::new();
+    generator.generate(
+        &commands,
+        analyzer.get_discovered_structs(),
+        &analyzer,
+        Some("zod"),
+        None,
+    );
+    let types = generator.read_file("types.ts");
+    assert!(types.contains("by_id: z.record(z.string(), TagSchema)"));
+    assert!(types.contains("recent: z.array(TagSchema)"));
+}
+
+/// Test Either and configured union types generate TypeScript unions
+#[test]
+fn test_union_types_full_pipeline() {
+    let project = TestProject::new();
+
+    project.write_file(
+        "main.rs",
+        r#"
+        use either::Either;
+        use serde::{Deserialize, Serialize};
+
+        #[derive(Serialize, Deserialize)]
+        pub struct User {
+            pub name: String,
+        }
+
+        #[derive(Serialize, Deserialize)]
+        pub struct Guest {
+            pub session: String,
+        }
+
+        #[derive(Serialize, Deserialize)]
+        #[serde(untagged)]
+        pub enum OneOf<A, B> {
+            First(A),
+            Second(B),
+        }
+
+        #[tauri::command]
+        pub fn whoami(id: Either<String, u32>) -> OneOf<User, Guest> {
+            unimplemented!()
+        }
+    "#,
+    );
+
+    let mut analyzer = tauri_typegen::analysis::CommandAnalyzer::new();
+    analyzer.add_union_types(&["OneOf".to_string()]);
+    let commands = analyzer.analyze_project(project.path()).unwrap();
+    assert!(analyzer.get_discovered_structs().contains_key("User"));
+    assert!(analyzer.get_discovered_structs().contains_key("Guest"));
+
+    let generator = TestGenerator::new();
+    generator.generate(
+        &commands,
+        analyzer.get_discovered_structs(),
+        &analyzer,
+        Some("none"),
+        None,
+    );
+    let types = generator.read_file("types.ts");
+    assert!(types.contains("id: string | number;"));
+    let commands_file = generator.read_file("commands.ts");
+    assert!(commands_file.contains("Promise<types.User | types.Guest>"));
+
+    let generator = TestGenerator::new();
+    generator.generate(
+        &commands,
+        analyzer.get_discovered_structs(),
+        &analyzer,
+        Some("zod"),
+        None,
+    );
+    let types = generator.read_file("types.ts");
+    assert!(types.contains("id: z.union([z.string(), z.coerce.number()])"));
+}
+
+/// Test `#[typegen(literal = "...")]` fields typed as string literals
+#[test]
+fn test_literal_fields_full_pipeline() {
+    let project = TestProject::new();
+
+    project.write_file(
+        "main.rs",
+        r#"
+        use serde::{Deserialize, Serialize};
+
+        #[derive(Serialize, Deserialize)]
+        pub struct ImageMedia {
+            #[cfg_attr(any(), typegen(literal = "image"))]
+            pub kind: &'static str,
+            pub width: u32,
+        }
+
+        #[derive(Serialize, Deserialize)]
+        pub struct VideoMedia {
+            #[cfg_attr(any(), typegen(literal = "video"))]
+            pub kind: String,
+            pub seconds: u32,
+        }
+
+        #[derive(Serialize, Deserialize)]
+        #[serde(untagged)]
+        pub enum Media {
+            Image(ImageMedia),
+            Video(VideoMedia),
+        }
+
+        #[tauri::command]
+        pub fn add_media(media: Media) {}
+    "#,
+    );
+
+    let (analyzer, commands) = project.analyze();
+
+    let generator = TestGenerator::new();
+    generator.generate(
+        &commands,
+        analyzer.get_discovered_structs(),
+        &analyzer,
+        Some("none"),
+        None,
+    );
+    let types = generator.read_file("types.ts");
+    assert!(types.contains("kind: \"image\";"));
+    assert!(types.contains("kind: \"video\";"));
+    let commands_file = generator.read_file("commands.ts");
+    assert!(commands_file.contains("addMedia({ media: { kind: 'image', width: 1 } })"));
+
+    let generator = TestGenerator::new();
+    generator.generate(
+        &commands,
+        analyzer.get_discovered_structs(),
+        &analyzer,
+        Some("zod"),
+        None,
+    );
+    let types = generator.read_file("types.ts");
+    assert!(types.contains("kind: z.literal(\"image\"),"));
+    assert!(types.contains("kind: z.literal(\"video\"),"));
+}
+
+/// Test `xWithProgress` wrappers of channels with progress messages
+#[test]
+fn test_progress_channels_full_pipeline() {
+    let project = TestProject::new();
+
+    project.write_file(
+        "main.rs",
+        r#"
+        use serde::Serialize;
+        use tauri::ipc::Channel;
+
+        #[derive(Clone, Serialize)]
+        #[serde(rename_all = "camelCase")]
+        pub struct DownloadProgress {
+            pub file_name: String,
+            pub current: u64,
+            pub total: u64,
+        }
+
+        #[derive(Clone, Serialize)]
+        #[cfg_attr(any(), typegen(progress))]
+        pub struct Upload {
+            pub sent: u64,
+            pub size: u64,
+        }
+
+        #[derive(Clone, Serialize)]
+        pub struct LogLine {
+            pub line: u32,
+        }
+
+        #[tauri::command]
+        pub fn download(url: String, on_progress: Channel<DownloadProgress>) {}
+
+        #[tauri::command]
+        pub fn upload(on_progress: Channel<Upload>) {}
+
+        #[tauri::command]
+        pub fn tail(on_line: Channel<LogLine>) {}
+    "#,
+    );
+
+    let (analyzer, commands) = project.analyze();
+
+    let generator = TestGenerator::new();
+    generator.generate(
+        &commands,
+        analyzer.get_discovered_structs(),
+        &analyzer,
+        Some("zod"),
+        None,
+    );
+    let commands_file = generator.read_file("commands.ts");
+    assert!(commands_file.contains("function progressChannel<T>("));
+    assert!(commands_file.contains(
+        "export function downloadWithProgress(params: Omit<types.DownloadParams, 'onProgress'>, \
+         onProgress: (percent: number, etaMs: number | null, message: types.DownloadProgress) => void, \
+         hooks?: CommandHooks<void>): Promise<void> {"
+    ));
+    assert!(commands_file.contains("(message) => [message.current, message.total]"));
+    assert!(commands_file.contains("export function uploadWithProgress(onProgress: "));
+    assert!(commands_file.contains("(message) => [message.sent, message.size]"));
+    assert!(commands_file.contains("return upload({ onProgress: channel }, hooks);"));
+    assert!(!commands_file.contains("tailWithProgress"));
+}
+
+/// Test `xStream` variants of commands streaming over a channel and returning a result
+#[test]
+fn test_stream_commands_full_pipeline() {
+    let project = TestProject::new();
+
+    project.write_file(
+        "main.rs",
+        r#"
+        use tauri::ipc::Channel;
+
+        #[tauri::command]
+        pub async fn export_rows(table: String, on_row: Channel<String>) -> Result<u32, String> {
+            Ok(0)
+        }
+
+        #[tauri::command]
+        pub fn watch(on_change: Channel<String>) {}
+    "#,
+    );
+
+    let (analyzer, commands) = project.analyze();
+
+    let generator = TestGenerator::new();
+    generator.generate(
+        &commands,
+        analyzer.get_discovered_structs(),
+        &analyzer,
+        Some("none"),
+        None,
+    );
+    let commands_file = generator.read_file("commands.ts");
+    assert!(commands_file.contains("function streamCall<T, R>("));
+    assert!(commands_file.contains(
+        "export function exportRowsStream(params: Omit<types.ExportRowsParams, 'onRow'>): \
+         { stream: AsyncIterable<string>; done: Promise<number> } {"
+    ));
+    assert!(commands_file.contains(
+        "return streamCall((channel: Channel<string>) => exportRows({ ...params, onRow: channel }));"
+    ));
+    // Nothing to wait for besides the stream
+    assert!(!commands_file.contains("watchStream"));
+}
+
+/// Test PathBuf/OsString handling with non-empty path schemas
+#[test]
+fn test_path_types_full_pipeline() {
+    let project = TestProject::new();
+
+    project.write_file(
+        "main.rs",
+        r#"
+        use serde::{Deserialize, Serialize};
+        use std::path::PathBuf;
+
+        #[derive(Serialize, Deserialize)]
+        pub struct Workspace {
+            pub root: PathBuf,
+            pub recent: Vec<PathBuf>,
+        }
+
+        #[tauri::command]
+        pub fn open_workspace(path: PathBuf, label: Option<std::ffi::OsString>) -> Workspace {
+            unimplemented!()
+        }
+    "#,
+    );
+
+    let (analyzer, commands) = project.analyze();
+    assert!(!analyzer.get_discovered_structs().contains_key("PathBuf"));
+
+    let generator = TestGenerator::new();
+    generator.generate(
+        &commands,
+        analyzer.get_discovered_structs(),
+        &analyzer,
+        Some("none"),
+        None,
+    );
+    let types = generator.read_file("types.ts");
+    assert!(types.contains("root: string;"));
+    assert!(types.contains("recent: string[];"));
+    assert!(types.contains("path: string;"));
+    assert!(types.contains("/** Platform-specific path"));
+
+    let config = tauri_typegen::GenerateConfig {
+        non_empty_paths: Some(true),
+        ..Default::default()
+    };
+    let generator = TestGenerator::new();
+    generator.generate(
+        &commands,
+        analyzer.get_discovered_structs(),
+        &analyzer,
+        Some("zod"),
+        Some(&config),
+    );
+    let types = generator.read_file("types.ts");
+    assert!(types.contains("root: z.string().min(1),"));
+    assert!(types.contains("recent: z.array(z.string()),"));
+    assert!(types.contains("path: z.string().min(1),"));
+    assert!(types.contains("label: z.string().min(1).optional()"));
+}
+
+/// Test enum helpers are generated into a separate enums.ts when enabled
+#[test]
+fn test_enum_helpers_full_pipeline() {
+    let project = TestProject::new();
+
+    project.write_file(
+        "main.rs",
+        r#"
+        use serde::{Deserialize, Serialize};
+
+        #[derive(Serialize, Deserialize)]
+        pub enum Status {
+            Active,
+            Archived,
+        }
+
+        #[tauri::command]
+        pub fn set_status(status: Status) -> Status {
+            status
+        }
+    "#,
+    );
+
+    let (analyzer, commands) = project.analyze();
+
+    let generator = TestGenerator::new();
+    let files = generator.generate(
+        &commands,
+        analyzer.get_discovered_structs(),
+        &analyzer,
+        Some("none"),
+        None,
+    );
+    assert!(!files.contains(&"enums.ts".to_string()));
+
+    let config = tauri_typegen::GenerateConfig {
+        enum_helpers: Some(true),
+        ..Default::default()
+    };
+    for validation in ["none", "zod"] {
+        let generator = TestGenerator::new();
+        let files = generator.generate(
+            &commands,
+            analyzer.get_discovered_structs(),
+            &analyzer,
+            Some(validation),
+            Some(&config),
+        );
+        assert!(files.contains(&"enums.ts".to_string()));
+
+        let enums = generator.read_file("enums.ts");
+        assert!(enums.contains("export const StatusValues"));
+        assert!(enums.contains("export function isStatus"));
+        assert!(enums.contains("export function parseStatus"));
+        assert!(enums.contains("export const StatusLabels"));
+        assert!(!generator.read_file("types.ts").contains("isStatus"));
+        assert!(generator
+            .read_file("index.ts")
+            .contains("export * from './enums';"));
+    }
+}
+
+#[test]
+fn test_tagged_enum_helpers_full_pipeline() {
+    let project = TestProject::new();
+
+    project.write_file(
+        "main.rs",
+        r#"
+        use serde::{Deserialize, Serialize};
+
+        #[derive(Serialize, Deserialize)]
+        #[serde(tag = "type", content = "data")]
+        pub enum Message {
+            Text(String),
+            Move { x: i32, y: i32 },
+            Quit,
+        }
+
+        #[derive(Serialize, Deserialize)]
+        pub enum Status {
+            Active,
+            Archived,
+        }
+
+        #[tauri::command]
+        pub fn send_message(message: Message, status: Status) -> Message {
+            message
+        }
+    "#,
+    );
+
+    let (analyzer, commands) = project.analyze();
+
+    for validation in ["none", "zod"] {
+        let generator = TestGenerator::new();
+        generator.generate(
+            &commands,
+            analyzer.get_discovered_structs(),
+            &analyzer,
+            Some(validation),
+            None,
+        );
+
+        let types = generator.read_file("types.ts");
+        assert!(types.contains("export const Message = {"));
+        assert!(types.contains("text: (data: string): Message => ({ type: \"Text\", data }),"));
+        assert!(types.contains("move: (data: { x: number; y: number }): Message"));
+        assert!(types.contains("quit: (): Message => ({ type: \"Quit\" }),"));
+        assert!(types.contains("export function matchMessage<R>("));
+        assert!(types.contains("return handlers[\"Text\"](value.data);"));
+
+        if validation == "zod" {
+            assert!(types.contains("export const MessageSchema = /* @__PURE__ */ (() => z.discriminatedUnion(\"type\", ["));
+            assert!(types.contains("z.object({ type: z.literal(\"Text\"), data: z.string() }),"));
+            assert!(types.contains("export type Message = z.infer<typeof MessageSchema>;"));
+            // Plain enums get an inferred type alias so commands can reference them
+            assert!(types.contains("export type Status = z.infer<typeof StatusSchema>;"));
+        } else {
+            assert!(types.contains("export type Message ="));
+            assert!(types.contains("| { type: \"Text\"; data: string }"));
+            assert!(types.contains("| { type: \"Quit\" }"));
+        }
+    }
+}
+
+#[test]
+fn test_tagged_enum_variant_renames_full_pipeline() {
+    let project = TestProject::new();
+
+    project.write_file(
+        "main.rs",
+        r#"
+        use serde::{Deserialize, Serialize};
+
+        #[derive(Serialize, Deserialize)]
+        #[serde(tag = "type", content = "data", rename_all = "kebab-case")]
+        pub enum Shape {
+            BigCircle { radius: f64 },
+            #[serde(rename = "sq")]
+            Square(f64),
+            NoData,
+        }
+
+        #[derive(Serialize, Deserialize)]
+        #[serde(tag = "kind", content = "payload", rename_all = "SCREAMING_SNAKE_CASE")]
+        pub enum Notice {
+            UserJoined(String),
+            UserLeft,
+        }
+
+        #[tauri::command]
+        pub fn draw(shape: Shape, notice: Notice) {}
+    "#,
+    );
+
+    let (analyzer, commands) = project.analyze();
+
+    let generator = TestGenerator::new();
+    generator.generate(
+        &commands,
+        analyzer.get_discovered_structs(),
+        &analyzer,
+        Some("zod"),
+        None,
+    );
+
+    let types = generator.read_file("types.ts");
+    assert!(types.contains("z.object({ type: z.literal(\"big-circle\"), data: z.object({"));
+    assert!(types.contains("z.object({ type: z.literal(\"sq\"), data: "));
+    assert!(types.contains("z.object({ type: z.literal(\"no-data\") }),"));
+    assert!(types.contains("z.object({ kind: z.literal(\"USER_JOINED\"), payload: z.string() }),"));
+    assert!(types.contains("z.object({ kind: z.literal(\"USER_LEFT\") }),"));
+    assert!(types.contains("userLeft: (): Notice => ({ kind: \"USER_LEFT\" }),"));
+}
+
+#[test]
+fn test_internally_tagged_enum_full_pipeline() {
+    let source = r#"
+        use serde::{Deserialize, Serialize};
+
+        #[derive(Serialize, Deserialize)]
+        pub struct Point {
+            pub x: i32,
+            pub y: i32,
+        }
+
+        #[derive(Serialize, Deserialize)]
+        #[serde(tag = "type", rename_all = "snake_case")]
+        pub enum Shape {
+            Circle { radius: f64, label: Option<String> },
+            Dot(Point),
+            Empty,
+        }
+
+        #[tauri::command]
+        pub fn draw(shape: Shape) -> Shape {
+            shape
+        }
+    "#;
+    let project = TestProject::new();
+    project.write_file("main.rs", source);
+
+    let (analyzer, commands) = project.analyze();
+
+    for validation in ["none", "zod", "valibot"] {
+        let generator = TestGenerator::new();
+        generator.generate(
+            &commands,
+            analyzer.get_discovered_structs(),
+            &analyzer,
+            Some(validation),
+            None,
+        );
+
+        let types = generator.read_file("types.ts");
+        assert!(types.contains(
+            "circle: (value: { radius: number; label?: string | null }): Shape => ({ type: \"circle\", ...value }),"
+        ));
+        assert!(types.contains("dot: (value: Point): Shape => ({ type: \"dot\", ...value }),"));
+        assert!(types.contains("empty: (): Shape => ({ type: \"empty\" }),"));
+        assert!(types.contains("return handlers[\"circle\"](value);"));
+
+        match validation {
+            "zod" => {
+                assert!(types.contains("z.discriminatedUnion(\"type\", ["));
+                assert!(types.contains("z.object({ type: z.literal(\"circle\"), radius: z."));
+                assert!(
+                    types.contains("z.object({ type: z.literal(\"dot\"), ...PointSchema.shape }),")
+                );
+                assert!(types.contains("z.object({ type: z.literal(\"empty\") }),"));
+            }
+            "valibot" => {
+                assert!(types.contains("v.variant(\"type\", ["));
+                assert!(types
+                    .contains("v.object({ type: v.literal(\"dot\"), ...PointSchema.entries }),"));
+            }
+            _ => {
+                assert!(
+                    types.contains("| { type: \"circle\"; radius: number; label?: string | null }")
+                );
+                assert!(types.contains("| ({ type: \"dot\" } & Point)"));
+                assert!(types.contains("| { type: \"empty\" }"));
+            }
+        }
+    }
+
+    // Python models get the tag next to the payload's fields
+    let config: tauri_typegen::GenerateConfig =
+        serde_json::from_str(r#"{ "target": "python" }"#).unwrap();
+    let files = tauri_typegen::generate_from_source(source, &config).unwrap();
+    let types_py = &files
+        .iter()
+        .find(|file| file.name == "types.py")
+        .unwrap()
+        .content;
+    assert!(types_py.contains(
+        "class ShapeDot(BaseModel):\n    model_config = ConfigDict(populate_by_name=True)\n\n    type: Literal[\"dot\"] = Field(default=\"dot\")\n    x: int\n    y: int\n"
+    ));
+    assert!(types_py.contains("Shape = Union[ShapeCircle, ShapeDot, ShapeEmpty]"));
+}
+
+#[test]
+fn test_externally_tagged_enum_full_pipeline() {
+    let project = TestProject::new();
+
+    project.write_file(
+        "main.rs",
+        r#"
+        use serde::{Deserialize, Serialize};
+
+        #[derive(Serialize, Deserialize)]
+        pub struct Point {
+            pub x: i32,
+            pub y: i32,
+        }
+
+        #[derive(Serialize, Deserialize)]
+        #[serde(rename_all = "camelCase")]
+        pub enum Input {
+            Click { x: i32, y: i32 },
+            KeyPress(String),
+            Moved(Point),
+            Pair(i32, String),
+            Quit,
+        }
+
+        #[tauri::command]
+        pub fn handle(input: Input) -> Input {
+            input
+        }
+    "#,
+    );
+
+    let (analyzer, commands) = project.analyze();
+
+    for validation in ["none", "zod", "valibot"] {
+        let generator = TestGenerator::new();
+        generator.generate(
+            &commands,
+            analyzer.get_discovered_structs(),
+            &analyzer,
+            Some(validation),
+            None,
+        );
+
+        let types = generator.read_file("types.ts");
+        match validation {
+            "zod" => {
+                assert!(types.contains("z.union([z.object({ click: z.object({ x: "));
+                assert!(types.contains("z.object({ keyPress: z.string() }), "));
+                assert!(types.contains("z.object({ moved: PointSchema }), "));
+                assert!(types.contains("z.literal(\"quit\")]"));
+            }
+            "valibot" => {
+                assert!(types.contains(
+                    "v.union([v.object({ click: v.object({ x: v.number(), y: v.number() }) }), "
+                ));
+                assert!(types.contains("v.literal(\"quit\")]"));
+            }
+            _ => {
+                assert!(types.contains(
+                    "export type Input =\n  | { click: { x: number; y: number } }\n  | { keyPress: string }\n  | { moved: Point }\n  | { pair: [number, string] }\n  | \"quit\";"
+                ));
+            }
+        }
+    }
+}
+
+#[test]
+fn test_flattened_fields_full_pipeline() {
+    let project = TestProject::new();
+    let source = r#"
+        use serde::{Deserialize, Serialize};
+        use std::collections::HashMap;
+
+        #[derive(Serialize, Deserialize)]
+        #[serde(rename_all = "camelCase")]
+        pub struct Audit {
+            pub created_by: String,
+        }
+
+        #[derive(Serialize, Deserialize)]
+        pub struct Paging {
+            pub page: u32,
+        }
+
+        #[derive(Serialize, Deserialize)]
+        pub struct Doc {
+            pub title: String,
+            #[serde(flatten)]
+            pub audit: Audit,
+            #[serde(flatten)]
+            pub paging: Option<Paging>,
+            #[serde(flatten)]
+            pub extra: HashMap<String, String>,
+        }
+
+        #[derive(Serialize, Deserialize)]
+        pub struct Labels {
+            #[serde(flatten)]
+            pub labels: std::collections::HashMap<String, String>,
+        }
+
+        #[derive(Serialize, Deserialize)]
+        pub struct Counts {
+            pub total: u32,
+            #[serde(flatten)]
+            pub by_kind: std::collections::BTreeMap<String, u32>,
+        }
+
+        #[tauri::command]
+        pub fn save(doc: Doc) -> Doc {
+            doc
+        }
+
+        #[tauri::command]
+        pub fn count(labels: Labels) -> Counts {
+            unimplemented!()
+        }
+    "#;
+    project.write_file("main.rs", source);
+
+    let (analyzer, commands) = project.analyze();
+
+    for validation in ["none", "zod", "valibot"] {
+        let generator = TestGenerator::new();
+        generator.generate(
+            &commands,
+            analyzer.get_discovered_structs(),
+            &analyzer,
+            Some(validation),
+            None,
+        );
+
+        let types = generator.read_file("types.ts");
+        match validation {
+            "zod" => {
+                assert!(types.contains("...AuditSchema.shape,"));
+                assert!(types.contains("...PagingSchema.partial().shape,"));
+                assert!(types.contains("}).catchall(z.unknown())"));
+                assert!(types.contains("export const LabelsSchema = /* @__PURE__ */ (() => z.object({\n}).catchall(z.string()))();"), "{types}");
+            }
+            "valibot" => {
+                assert!(types.contains("v.objectWithRest({"));
+                assert!(types.contains("...AuditSchema.entries,"));
+                assert!(types.contains("...v.partial(PagingSchema).entries,"));
+                assert!(types.contains("}, v.unknown())"));
+                assert!(types.contains("}, v.string())"));
+            }
+            _ => {
+                assert!(types.contains("export type Doc = {\n  title: string;\n} & Audit & Partial<Paging> & Record<string, unknown>;"));
+                assert!(types.contains("export type Labels = {\n} & Record<string, string>;"));
+                assert!(types.contains(
+                    "export type Counts = {\n  total: number;\n} & Record<string, unknown>;"
+                ));
+            }
+        }
+    }
+
+    // Models without intersection types inline the flattened struct's fields
+    let config: tauri_typegen::GenerateConfig =
+        serde_json::from_str(r#"{ "target": "python" }"#).unwrap();
+    let files = tauri_typegen::generate_from_source(source, &config).unwrap();
+    let types_py = &files
+        .iter()
+        .find(|file| file.name == "types.py")
+        .unwrap()
+        .content;
+    assert!(types_py.contains(
+        "    title: str\n    created_by: str = Field(alias=\"createdBy\")\n    page: Optional[int] = Field(default=None)\n"
+    ));
+}
+
+#[test]
+fn test_serde_default_fields_full_pipeline() {
+    let project = TestProject::new();
+
+    project.write_file(
+        "main.rs",
+        r#"
+        use serde::{Deserialize, Serialize};
+
+        #[derive(Serialize, Deserialize)]
+        pub struct Settings {
+            pub name: String,
+            #[serde(default)]
+            pub retries: u32,
+            #[serde(default, skip_serializing_if = "Vec::is_empty")]
+            pub tags: Vec<String>,
+        }
+
+        #[tauri::command]
+        pub fn save(settings: Settings) -> Settings {
+            settings
+        }
+    "#,
+    );
+
+    let (analyzer, commands) = project.analyze();
+
+    for validation in ["none", "zod", "valibot"] {
+        let generator = TestGenerator::new();
+        generator.generate(
+            &commands,
+            analyzer.get_discovered_structs(),
+            &analyzer,
+            Some(validation),
+            None,
+        );
+
+        let types = generator.read_file("types.ts");
+        match validation {
+            "zod" => {
+                assert!(types.contains("name: z.string(),"));
+                assert!(types.contains("retries: z.coerce.number().optional(),"));
+                assert!(types.contains("tags: z.array(z.string()).optional(),"));
+            }
+            "valibot" => {
+                assert!(types.contains("name: v.string(),"));
+                assert!(types.contains("retries: v.optional(v.number()),"));
+                assert!(types.contains("tags: v.optional(v.array(v.string())),"));
+            }
+            _ => {
+                assert!(types.contains("  name: string;\n  retries?: number;\n  tags?: string[];"));
+            }
+        }
+    }
+}
+
+#[test]
+fn test_one_way_skipped_fields_full_pipeline() {
+    let project = TestProject::new();
+
+    project.write_file(
+        "main.rs",
+        r#"
+        use serde::{Deserialize, Serialize};
+
+        #[derive(Serialize, Deserialize)]
+        pub struct Credentials {
+            pub user: String,
+            #[serde(skip_serializing)]
+            pub password: String,
+            #[serde(skip_deserializing)]
+            pub token: String,
+        }
+
+        #[derive(Serialize, Deserialize)]
+        pub struct Account {
+            pub id: u32,
+            #[serde(skip_serializing)]
+            pub password_hash: String,
+            #[serde(skip_deserializing)]
+            pub created_at: String,
+        }
+
+        #[derive(Serialize, Deserialize)]
+        pub struct Profile {
+            pub id: u32,
+            #[serde(skip_serializing)]
+            pub secret: String,
+        }
+
+        #[tauri::command]
+        pub fn login(credentials: Credentials) {}
+
+        #[tauri::command]
+        pub fn account() -> Account {
+            todo!()
+        }
+
+        #[tauri::command]
+        pub fn update(profile: Profile) -> Profile {
+            profile
+        }
+    "#,
+    );
+
+    let (analyzer, commands) = project.analyze();
+    let generator = TestGenerator::new();
+    generator.generate(
+        &commands,
+        analyzer.get_discovered_structs(),
+        &analyzer,
+        None,
+        None,
+    );
+
+    let types = generator.read_file("types.ts");
+    // Parameters are deserialized: fields skipped there are left out
+    assert!(
+        types.contains("export interface Credentials {\n  user: string;\n  password: string;\n}")
+    );
+    // Results are serialized
+    assert!(types.contains("export interface Account {\n  id: number;\n  created_at: string;\n}"));
+    // Types traveling both ways keep them optional
+    assert!(types.contains("export interface Profile {\n  id: number;\n  secret?: string;\n}"));
+}
+
+#[test]
+fn test_separate_input_types_full_pipeline() {
+    let project = TestProject::new();
+
+    project.write_file(
+        "main.rs",
+        r#"
+        use serde::{Deserialize, Serialize};
+
+        #[derive(Serialize, Deserialize)]
+        pub struct User {
+            #[serde(skip_deserializing)]
+            pub id: u32,
+            pub name: String,
+            #[serde(skip_serializing)]
+            pub password: String,
+            #[serde(default)]
+            pub tags: Vec<String>,
+        }
+
+        #[derive(Serialize, Deserialize)]
+        pub struct Team {
+            pub members: Vec<User>,
+        }
+
+        #[tauri::command]
+        pub fn save_user(user: User) -> Result<User, String> {
+            todo!()
+        }
+
+        #[tauri::command]
+        pub fn save_team(team: Team) -> Team {
+            team
+        }
+    "#,
+    );
+
+    let mut analyzer = tauri_typegen::analysis::CommandAnalyzer::new();
+    analyzer.set_separate_input_types(true);
+    let commands = analyzer.analyze_project(project.path()).unwrap();
+
+    let generator = TestGenerator::new();
+    generator.generate(
+        &commands,
+        analyzer.get_discovered_structs(),
+        &analyzer,
+        Some("none"),
+        None,
+    );
+    let types = generator.read_file("types.ts");
+    assert!(types.contains(
+        "export interface UserInput {\n  name: string;\n  password: string;\n  tags?: string[];\n}"
+    ));
+    assert!(types
+        .contains("export interface User {\n  id: number;\n  name: string;\n  tags: string[];\n}"));
+    assert!(types.contains("export interface TeamInput {\n  members: UserInput[];\n}"));
+    assert!(types.contains("export interface SaveUserParams {\n  user: UserInput;"));
+    let commands_ts = generator.read_file("commands.ts");
+    assert!(commands_ts.contains(
+        "export async function saveUser(params: types.SaveUserParams): Promise<types.User> {"
+    ));
+
+    // Schemas of the input types are declared before the schemas using them
+    let generator = TestGenerator::new();
+    generator.generate(
+        &commands,
+        analyzer.get_discovered_structs(),
+        &analyzer,
+        Some("zod"),
+        None,
+    );
+    let types = generator.read_file("types.ts");
+    let user_input = types.find("export const UserInputSchema").unwrap();
+    let team_input = types.find("export const TeamInputSchema").unwrap();
+    assert!(user_input < team_input);
+    assert!(types.contains("  members: z.array(UserInputSchema),"));
+}
+
+#[test]
+fn test_serde_with_mappings_full_pipeline() {
+    let project = TestProject::new();
+
+    project.write_file(
+        "main.rs",
+        r#"
+        use chrono::serde::ts_seconds_option;
+        use serde::{Deserialize, Serialize};
+
+        #[derive(Serialize, Deserialize)]
+        pub struct Meeting {
+            #[serde(with = "chrono::serde::ts_seconds")]
+            pub starts_at: DateTime<Utc>,
+            #[serde(with = "ts_seconds_option")]
+            pub ended_at: Option<DateTime<Utc>>,
+            #[serde(serialize_with = "as_hex")]
+            pub color: u32,
+        }
+
+        #[tauri::command]
+        pub fn schedule(meeting: Meeting) -> Meeting {
+            meeting
+        }
+    "#,
+    );
+
+    let config: tauri_typegen::GenerateConfig = serde_json::from_str(
+        r#"{
+            "serde_with_mappings": {
+                "chrono::serde::ts_seconds": "number",
+                "chrono::serde::ts_seconds_option": "number",
+                "as_hex": "string"
+            }
+        }"#,
+    )
+    .unwrap();
+    let mut analyzer = tauri_typegen::analysis::CommandAnalyzer::new();
+    analyzer.add_serde_with_mappings(config.serde_with_mappings.as_ref().unwrap());
+    let commands = analyzer.analyze_project(project.path()).unwrap();
+
+    let generator = TestGenerator::new();
+    generator.generate(
+        &commands,
+        analyzer.get_discovered_structs(),
+        &analyzer,
+        Some("none"),
+        Some(&config),
+    );
+    let types = generator.read_file("types.ts");
+    assert!(types.contains(
+        "export interface Meeting {\n  starts_at: number;\n  ended_at?: number | null;\n  color: string;\n}"
+    ));
+
+    let generator = TestGenerator::new();
+    generator.generate(
+        &commands,
+        analyzer.get_discovered_structs(),
+        &analyzer,
+        Some("zod"),
+        Some(&config),
+    );
+    let types = generator.read_file("types.ts");
+    assert!(types.contains("  starts_at: z.number(),"));
+    assert!(types.contains("  color: z.string(),"));
+}
+
+#[test]
+fn test_dynamic_parameters_full_pipeline() {
+    let project = TestProject::new();
+
+    project.write_file(
+        "main.rs",
+        r#"
+        use serde::{Deserialize, Serialize};
+        use serde_json::Value;
+
+        #[derive(Serialize, Deserialize)]
+        #[serde(untagged)]
+        pub enum Setting {
+            Flag(bool),
+            Count(i64),
+            Text(String),
+        }
+
+        #[tauri::command]
+        pub fn set_config(key: String, value: Option<serde_json::Value>, setting: Option<Setting>) -> Value {
+            Value::Null
+        }
+    "#,
+    );
+
+    let (analyzer, commands) = project.analyze();
+
+    let generator = TestGenerator::new();
+    generator.generate(
+        &commands,
+        analyzer.get_discovered_structs(),
+        &analyzer,
+        Some("none"),
+        None,
+    );
+    let types = generator.read_file("types.ts");
+    assert!(types.contains("export type JsonValue = string | number | boolean | null"));
+    assert!(types.contains("value?: JsonValue | null;"));
+    assert!(types.contains("export type Setting = boolean | number | string;"));
+    assert!(types.contains("setting?: Setting | null;"));
+    assert!(generator
+        .read_file("commands.ts")
+        .contains("Promise<types.JsonValue>"));
+
+    let generator = TestGenerator::new();
+    generator.generate(
+        &commands,
+        analyzer.get_discovered_structs(),
+        &analyzer,
+        Some("zod"),
+        None,
+    );
+    let types = generator.read_file("types.ts");
+    assert!(types.contains(
+        "export const JsonValueSchema: z.ZodType<JsonValue> = /* @__PURE__ */ (() => z.lazy(() =>"
+    ));
+    assert!(types.contains("value: JsonValueSchema.optional(),"));
+    assert!(types
+        .contains("export const SettingSchema = /* @__PURE__ */ (() => z.union([z.boolean(), z.number(), z.string()]))();"));
+    assert!(types.contains("setting: SettingSchema.optional(),"));
+}
+
+#[test]
+fn test_generic_runtime_commands_full_pipeline() {
+    let project = TestProject::new();
+
+    project.write_file(
+        "main.rs",
+        r#"
+        use tauri::{AppHandle, Runtime, Webview};
+
+        #[tauri::command]
+        pub async fn open_settings<R: Runtime>(app: AppHandle<R>, tab: String) -> Result<(), String> {
+            Ok(())
+        }
+
+        #[tauri::command]
+        pub fn zoom<R>(webview: Webview<R>, factor: f64) -> f64
+        where
+            R: Runtime,
+        {
+            factor
+        }
+    "#,
+    );
+
+    let (analyzer, commands) = project.analyze();
+    assert_eq!(commands.len(), 2);
+
+    for validation in ["none", "zod"] {
+        let generator = TestGenerator::new();
+        generator.generate(
+            &commands,
+            analyzer.get_discovered_structs(),
+            &analyzer,
+            Some(validation),
+            None,
+        );
+
+        let types = generator.read_file("types.ts");
+        assert!(!types.contains("app"));
+        assert!(!types.contains("webview"));
+        assert!(types.contains("tab"));
+        assert!(types.contains("factor"));
+
+        let commands_ts = generator.read_file("commands.ts");
+        assert!(commands_ts.contains("export async function openSettings("));
+        assert!(commands_ts.contains("export async function zoom("));
+    }
+}
+
+#[test]
+fn test_notify_commands_full_pipeline() {
+    let project = TestProject::new();
+
+    project.write_file(
+        "main.rs",
+        r#"
+        #[tauri::command]
+        pub fn log_event(message: String) -> Result<(), String> {
+            Ok(())
+        }
+
+        #[tauri::command]
+        pub fn ping() {}
+
+        #[tauri::command]
+        pub fn get_count() -> u32 {
+            1
+        }
+    "#,
+    );
+
+    let (analyzer, commands) = project.analyze();
+    assert_eq!(commands.len(), 3);
+
+    let generator = TestGenerator::new();
+    generator.generate(
+        &commands,
+        analyzer.get_discovered_structs(),
+        &analyzer,
+        Some("none"),
+        None,
+    );
+    assert!(!generator.read_file("commands.ts").contains("Notify("));
+
+    let config = tauri_typegen::GenerateConfig {
+        notify_commands: Some(true),
+        batch_notifications: Some(true),
+        ..Default::default()
+    };
+    for validation in ["none", "zod"] {
+        let generator = TestGenerator::new();
+        generator.generate(
+            &commands,
+            analyzer.get_discovered_structs(),
+            &analyzer,
+            Some(validation),
+            Some(&config),
+        );
+
+        let commands_ts = generator.read_file("commands.ts");
+        assert!(commands_ts.contains("export async function logEvent("));
+        assert!(commands_ts
+            .contains("export function logEventNotify(params: types.LogEventParams): void {"));
+        assert!(commands_ts.contains("export function pingNotify(): void {"));
+        assert!(!commands_ts.contains("getCountNotify"));
+        assert!(commands_ts.contains("export function setNotifyErrorHandler("));
+        assert!(commands_ts.contains("queueMicrotask("));
+    }
+}
+
+#[test]
+fn test_non_ascii_identifiers_full_pipeline() {
+    let project = TestProject::new();
+
+    project.write_file(
+        "main.rs",
+        r#"
+        use serde::{Deserialize, Serialize};
+
+        #[derive(Serialize, Deserialize)]
+        pub struct Größe {
+            #[serde(rename = "user-id")]
+            pub user_id: u32,
+            pub breite: f64,
+        }
+
+        #[tauri::command]
+        pub fn grüße_senden(größe: Größe) -> Result<(), String> {
+            Ok(())
+        }
+
+        #[tauri::command]
+        pub fn celebrate(app: tauri::AppHandle) {
+            app.emit("🎉-party", 1u32).unwrap();
+        }
+    "#,
+    );
+
+    let (analyzer, commands) = project.analyze();
+
+    for validation in ["none", "zod"] {
+        let generator = TestGenerator::new();
+        generator.generate(
+            &commands,
+            analyzer.get_discovered_structs(),
+            &analyzer,
+            Some(validation),
+            None,
+        );
+
+        let types = generator.read_file("types.ts");
+        assert!(types.contains("\"user-id\":"));
+        assert!(types.contains("GrusseSendenParams"));
+        assert!(types.contains("größe"));
+
+        let commands_ts = generator.read_file("commands.ts");
+        assert!(commands_ts.contains("export async function grusseSenden("));
+        assert!(commands_ts.contains("('grüße_senden'"));
+
+        let events = generator.read_file("events.ts");
+        assert!(events.contains("export async function onU1F389Party("));
+        assert!(events.contains("'🎉-party'"));
+    }
+}
+
+#[test]
+fn test_zod_param_schemas_reuse_struct_schemas() {
+    let project = TestProject::new();
+
+    project.write_file(
+        "main.rs",
+        r#"
+        use serde::{Deserialize, Serialize};
+
+        #[derive(Serialize, Deserialize)]
+        pub struct User {
+            pub name: String,
+            pub email: String,
+        }
+
+        #[tauri::command]
+        pub fn save_users(user: User, others: Vec<User>, external: external::Profile) -> Result<(), String> {
+            Ok(())
+        }
+    "#,
+    );
+
+    let (analyzer, commands) = project.analyze();
+
+    let generator = TestGenerator::new();
+    generator.generate(
+        &commands,
+        analyzer.get_discovered_structs(),
+        &analyzer,
+        Some("zod"),
+        None,
+    );
+
+    let types = generator.read_file("types.ts");
+    assert!(types.contains("user: UserSchema,"));
+    assert!(types.contains("others: z.array(UserSchema),"));
+    // Field definitions exist once, in UserSchema
+    assert_eq!(types.matches("email: z.string()").count(), 1);
+    // Types without an emitted schema are not referenced
+    assert!(!types.contains("ProfileSchema"));
+    assert!(types.contains("external: z.unknown()"));
+}
+
+/// Top-level statements of a generated module must be free of side effects so
+/// bundlers can drop unused exports: declarations, literals, functions, or calls
+/// marked `/* @__PURE__ */`
+fn assert_side_effect_free(module: &str, content: &str) {
+    let mut in_comment = false;
+    for line in content.lines() {
+        if line.starts_with("/*") {
+            in_comment = !line.contains("*/");
+            continue;
+        }
+        if in_comment {
+            in_comment = !line.contains("*/");
+            continue;
+        }
+        // Only top-level statements start at column 0
+        if line.is_empty()
+            || line.starts_with(char::is_whitespace)
+            || line.starts_with(['}', ')', ']'])
+        {
+            continue;
+        }
+
+        let declaration = [
+            "import ",
+            "export * from",
+            "export type ",
+            "export interface ",
+            "type ",
+            "interface ",
+            "export function ",
+            "export async function ",
+            "function ",
+            "async function ",
+        ];
+        if declaration.iter().any(|prefix| line.starts_with(prefix)) {
+            continue;
+        }
+
+        let (_, initializer) = line
+            .split_once(" = ")
+            .unwrap_or_else(|| panic!("{}: unexpected top-level statement: {}", module, line));
+        assert!(
+            line.starts_with("export const ")
+                || line.starts_with("const ")
+                || line.starts_with("let "),
+            "{}: unexpected top-level statement: {}",
+            module,
+            line
+        );
+        let pure = initializer.starts_with("/* @__PURE__ */")
+            || initializer.starts_with(['{', '[', '"', '\''])
+            || initializer.starts_with('(') && initializer.contains(") =>");
+        assert!(
+            pure,
+            "{}: initializer may have side effects: {}",
+            module, line
+        );
+    }
+}
+
+#[test]
+fn test_generated_modules_are_side_effect_free() {
+    let project = TestProject::new();
+
+    project.write_file(
+        "main.rs",
+        r#"
+        use serde::{Deserialize, Serialize};
+
+        #[derive(Serialize, Deserialize)]
+        pub struct Product {
+            pub id: String,
+            pub tags: Vec<String>,
+            pub attributes: Option<serde_json::Value>,
+        }
+
+        #[derive(Serialize, Deserialize)]
+        pub enum Status {
+            Active,
+            Archived,
+        }
+
+        #[derive(Serialize, Deserialize)]
+        #[serde(tag = "type", content = "data")]
+        pub enum Message {
+            Text(String),
+            Ping,
+        }
+
+        #[tauri::command]
+        pub fn get_product(id: String, status: Status) -> Result<Product, String> {
+            todo!()
+        }
+
+        #[tauri::command]
+        pub fn send_message(message: Message) -> Result<(), String> {
+            Ok(())
+        }
+
+        #[tauri::command]
+        pub fn notify_ready(app: tauri::AppHandle) {
+            app.emit("ready", 1u32).unwrap();
+        }
+    "#,
+    );
+
+    let (analyzer, commands) = project.analyze();
+    let config = tauri_typegen::GenerateConfig {
+        enum_helpers: Some(true),
+        notify_commands: Some(true),
+        batch_notifications: Some(true),
+        ..Default::default()
+    };
+
+    for validation in ["none", "zod"] {
+        let generator = TestGenerator::new();
+        let files = generator.generate(
+            &commands,
+            analyzer.get_discovered_structs(),
+            &analyzer,
+            Some(validation),
+            Some(&config),
+        );
+
+        for file in &files {
+            assert_side_effect_free(file, &generator.read_file(file));
+        }
+    }
+}
+
+/// Bundles a single import with esbuild and checks unused commands are dropped.
+/// Run with `cargo test -- --ignored` where `esbuild` is on the PATH.
+#[test]
+#[ignore = "requires esbuild on the PATH"]
+fn test_esbuild_tree_shakes_unused_commands() {
+    assert!(
+        std::process::Command::new("esbuild")
+            .arg("--version")
+            .output()
+            .is_ok(),
+        "esbuild not found on the PATH"
+    );
+
+    let project = TestProject::new();
+    project.write_file(
+        "main.rs",
+        r#"
+        use serde::{Deserialize, Serialize};
+
+        #[derive(Serialize, Deserialize)]
+        pub struct Product {
+            pub id: String,
+        }
+
+        #[derive(Serialize, Deserialize)]
+        pub struct Invoice {
+            pub total: f64,
+        }
+
+        #[tauri::command]
+        pub fn get_product(id: String) -> Result<Product, String> {
+            todo!()
+        }
+
+        #[tauri::command]
+        pub fn create_invoice(invoice: Invoice) -> Result<Invoice, String> {
+            todo!()
+        }
+    "#,
+    );
+    let (analyzer, commands) = project.analyze();
+
+    for validation in ["none", "zod"] {
+        let generator = TestGenerator::new();
+        generator.generate(
+            &commands,
+            analyzer.get_discovered_structs(),
+            &analyzer,
+            Some(validation),
+            None,
+        );
+
+        let output_dir = generator.output_dir.path();
+        std::fs::write(
+            output_dir.join("entry.ts"),
+            "import { getProduct } from './index';\nexport default getProduct;\n",
+        )
+        .unwrap();
+
+        let output = std::process::Command::new("esbuild")
+            .arg(output_dir.join("entry.ts"))
+            .args([
+                "--bundle",
+                "--format=esm",
+                "--external:@tauri-apps/*",
+                "--external:zod",
+            ])
+            .output()
+            .unwrap();
+        assert!(
+            output.status.success(),
+            "{}",
+            String::from_utf8_lossy(&output.stderr)
+        );
+
+        let bundle = String::from_utf8_lossy(&output.stdout);
+        assert!(bundle.contains("get_product"));
+        assert!(!bundle.contains("create_invoice"));
+        assert!(!bundle.contains("InvoiceSchema"));
+    }
+}
+
+#[test]
+fn test_global_declarations_full_pipeline() {
+    let project = TestProject::new();
+
+    project.write_file(
+        "main.rs",
+        r#"
+        use serde::{Deserialize, Serialize};
+
+        #[derive(Serialize, Deserialize)]
+        pub struct User {
+            pub name: String,
+        }
+
+        #[derive(Serialize, Deserialize)]
+        pub struct Event {
+            pub kind: String,
+        }
+
+        #[tauri::command]
+        pub fn save_user(user: User) -> Event {
+            unimplemented!()
+        }
+    "#,
+    );
+
+    let (analyzer, commands) = project.analyze();
+
+    let generator = TestGenerator::new();
+    generator.generate(
+        &commands,
+        analyzer.get_discovered_structs(),
+        &analyzer,
+        Some("none"),
+        None,
+    );
+    assert!(!generator.output_dir.path().join("globals.d.ts").exists());
+
+    let config = tauri_typegen::GenerateConfig {
+        global_declarations: Some(true),
+        ..Default::default()
+    };
+    for validation in ["none", "zod"] {
+        let generator = TestGenerator::new();
+        generator.generate(
+            &commands,
+            analyzer.get_discovered_structs(),
+            &analyzer,
+            Some(validation),
+            Some(&config),
+        );
+
+        let globals = generator.read_file("globals.d.ts");
+        assert!(globals.contains("declare global {"));
+        assert!(globals.contains("  type User = import('./types').User;"));
+        assert!(globals.contains("  type SaveUserParams = import('./types').SaveUserParams;"));
+        assert!(!globals.contains("type Event ="));
+        assert!(globals.contains("clash with built-in types: Event"));
+        assert!(globals.trim_end().ends_with("export {};"));
+        assert!(!globals.contains("function"));
+
+        assert!(!generator.read_file("index.ts").contains("globals"));
+    }
+}
+
+#[test]
+fn test_schema_registry_full_pipeline() {
+    let project = TestProject::new();
+
+    project.write_file(
+        "main.rs",
+        r#"
+        use serde::{Deserialize, Serialize};
+
+        #[derive(Serialize, Deserialize)]
+        pub struct User {
+            pub name: String,
+        }
+
+        #[derive(Serialize, Deserialize)]
+        pub enum Role {
+            Admin,
+            Guest,
+        }
+
+        #[tauri::command]
+        pub fn save_user(user: User, role: Role) -> User {
+            unimplemented!()
+        }
+
+        #[tauri::command]
+        pub fn list_users() -> Vec<User> {
+            unimplemented!()
+        }
+    "#,
+    );
+
+    let (analyzer, commands) = project.analyze();
+    let config = tauri_typegen::GenerateConfig {
+        schema_registry: Some(true),
+        ..Default::default()
+    };
+
+    let generator = TestGenerator::new();
+    generator.generate(
+        &commands,
+        analyzer.get_discovered_structs(),
+        &analyzer,
+        Some("none"),
+        Some(&config),
+    );
+    assert!(!generator
+        .output_dir
+        .path()
+        .join("schemaRegistry.ts")
+        .exists());
+
+    let generator = TestGenerator::new();
+    generator.generate(
+        &commands,
+        analyzer.get_discovered_structs(),
+        &analyzer,
+        Some("zod"),
+        Some(&config),
+    );
+
+    let registry = generator.read_file("schemaRegistry.ts");
+    assert!(registry.contains("import * as types from './types';"));
+    assert!(registry.contains("  Role: types.RoleSchema,\n  User: types.UserSchema,\n} as const;"));
+    assert!(registry.contains("  save_user: types.SaveUserParamsSchema,\n} as const;"));
+    assert!(!registry.contains("list_users"));
+    assert!(registry.contains("export type CommandSchemaName = keyof typeof commandSchemas;"));
+
+    assert!(generator
+        .read_file("index.ts")
+        .contains("export * from './schemaRegistry';"));
+}
+
+#[test]
+fn test_form_hooks_full_pipeline() {
+    let project = TestProject::new();
+
+    project.write_file(
+        "main.rs",
+        r#"
+        use serde::{Deserialize, Serialize};
+
+        #[derive(Serialize, Deserialize)]
+        pub struct User {
+            pub name: String,
+            pub age: u32,
+            pub nickname: Option<String>,
+        }
+
+        #[tauri::command]
+        pub fn create_user(user: User, notify: bool) -> User {
+            unimplemented!()
+        }
+
+        #[tauri::command]
+        pub fn import_users(path: String, on_progress: Channel<u32>) {
+            unimplemented!()
+        }
+
+        #[tauri::command]
+        pub fn list_users() -> Vec<User> {
+            unimplemented!()
+        }
+    "#,
+    );
+
+    let (analyzer, commands) = project.analyze();
+    let config = tauri_typegen::GenerateConfig {
+        form_hooks: Some(true),
+        ..Default::default()
+    };
+
+    let generator = TestGenerator::new();
+    generator.generate(
+        &commands,
+        analyzer.get_discovered_structs(),
+        &analyzer,
+        Some("zod"),
+        Some(&config),
+    );
+
+    let forms = generator.read_file("forms.ts");
+    assert!(forms.contains("import { zodResolver } from '@hookform/resolvers/zod';"));
+    assert!(forms.contains(
+        "export const createUserFormDefaults: DefaultValues<types.CreateUserParams> = { user: { name: '', age: 0 }, notify: false };"
+    ));
+    assert!(forms.contains("export function useCreateUserForm("));
+    assert!(forms.contains("options: CommandFormOptions<types.CreateUserParams, types.User> = {},"));
+    assert!(forms.contains("resolver: zodResolver(types.CreateUserParamsSchema),"));
+    assert!(forms.contains("const result = await commands.createUser(values);"));
+    assert!(!forms.contains("importUsers"));
+    assert!(!forms.contains("listUsers"));
+
+    assert!(generator
+        .read_file("index.ts")
+        .contains("export * from './forms';"));
+}
+
+#[test]
+fn test_xstate_actors_full_pipeline() {
+    let project = TestProject::new();
+
+    project.write_file(
+        "main.rs",
+        r#"
+        use serde::{Deserialize, Serialize};
+
+        #[derive(Serialize, Deserialize, Clone)]
+        pub struct User {
+            pub name: String,
+        }
+
+        #[tauri::command]
+        pub fn get_user(app: AppHandle, id: u32) -> Result<User, String> {
+            let user = User { name: "Ada".to_string() };
+            app.emit("user-loaded", user.clone()).ok();
+            Ok(user)
+        }
+
+        #[tauri::command]
+        pub fn logout() {}
+    "#,
+    );
+
+    let (analyzer, commands) = project.analyze();
+    let config = tauri_typegen::GenerateConfig {
+        xstate_actors: Some(true),
+        ..Default::default()
+    };
+
+    for validation in ["none", "zod"] {
+        let generator = TestGenerator::new();
+        generator.generate(
+            &commands,
+            analyzer.get_discovered_structs(),
+            &analyzer,
+            Some(validation),
+            Some(&config),
+        );
+
+        let actors = generator.read_file("actors.ts");
+        assert!(actors
+            .contains("import { fromPromise, fromCallback, type EventObject } from 'xstate';"));
+        assert!(actors.contains(
+            "export const getUserActor = fromPromise<types.User, types.GetUserParams>(({ input }) => commands.getUser(input));"
+        ));
+        assert!(actors
+            .contains("export const logoutActor = fromPromise<void>(() => commands.logout());"));
+        assert!(actors.contains("  getUser: getUserActor,\n  logout: logoutActor,\n} as const;"));
+        assert!(actors.contains("  | { type: 'user-loaded'; payload: types.User };"));
+        assert!(actors.contains("export const tauriEvents = fromCallback<"));
+
+        assert!(generator
+            .read_file("index.ts")
+            .contains("export * from './actors';"));
+    }
+}
+
+#[test]
+fn test_web_fallback_full_pipeline() {
+    let project = TestProject::new();
+
+    project.write_file(
+        "main.rs",
+        r#"
+        #[tauri::command]
+        pub fn greet(name: String) -> String {
+            format!("Hello {}", name)
+        }
+
+        #[tauri::command]
+        pub fn ping() {}
+    "#,
+    );
+
+    let (analyzer, commands) = project.analyze();
+    let config = tauri_typegen::GenerateConfig {
+        web_fallback: Some(true),
+        ..Default::default()
+    };
+
+    for validation in ["none", "zod"] {
+        let generator = TestGenerator::new();
+        generator.generate(
+            &commands,
+            analyzer.get_discovered_structs(),
+            &analyzer,
+            Some(validation),
+            Some(&config),
+        );
+
+        let runtime = generator.read_file("runtime.ts");
+        assert!(runtime.contains("export type CommandName =\n  | 'greet'\n  | 'ping';"));
+        assert!(runtime.contains("export class TauriNotAvailableError extends Error {"));
+        assert!(runtime.contains("export function configureWebFallback("));
+        assert!(runtime.contains("return tauriInvoke<T>(command, args);"));
+
+        let commands_ts = generator.read_file("commands.ts");
+        assert!(commands_ts.contains("import { invoke } from './runtime';"));
+        assert!(!commands_ts.contains("@tauri-apps/api/core"));
+    }
+
+    let generator = TestGenerator::new();
+    generator.generate(
+        &commands,
+        analyzer.get_discovered_structs(),
+        &analyzer,
+        Some("none"),
+        None,
+    );
+    assert!(generator
+        .read_file("commands.ts")
+        .contains("import { invoke } from '@tauri-apps/api/core';"));
+}
+
+#[test]
+fn test_http_bridge_full_pipeline() {
+    let project = TestProject::new();
+
+    project.write_file(
+        "src/commands.rs",
+        r#"
+        use tauri::{AppHandle, State};
+
+        #[tauri::command]
+        pub async fn get_user(db: State<'_, Database>, user_id: u32) -> Result<String, String> {
+            unimplemented!()
+        }
+
+        #[tauri::command]
+        pub fn restart(app: AppHandle) {}
+
+        #[tauri::command]
+        pub fn focus(window: tauri::Window) {}
+    "#,
+    );
+
+    let (analyzer, commands) = project.analyze();
+    let bridge_path = project.temp_dir.path().join("src/typegen_bridge.rs");
+    let config = tauri_typegen::GenerateConfig {
+        http_bridge: Some(bridge_path.to_string_lossy().to_string()),
+        ..Default::default()
+    };
+
+    let generator = TestGenerator::new();
+    generator.generate(
+        &commands,
+        analyzer.get_discovered_structs(),
+        &analyzer,
+        Some("none"),
+        Some(&config),
+    );
+
+    let bridge = std::fs::read_to_string(&bridge_path).unwrap();
+    assert!(bridge.contains("#![cfg(all(debug_assertions, feature = \"typegen-bridge\"))]"));
+    assert!(bridge.contains("        .route(\"/get_user\", post(get_user))"));
+    assert!(bridge.contains(
+        "let result = crate::commands::get_user(app.state(), arg(&args, \"userId\")?).await;"
+    ));
+    assert!(bridge.contains("let result = crate::commands::restart(app.clone());"));
+    assert!(bridge.contains("// - focus: injects tauri::Window"));
+    assert!(!bridge.contains("post(focus)"));
+
+    assert!(!generator.read_file("index.ts").contains("typegen_bridge"));
+}
+
+#[test]
+fn test_exported_constants_full_pipeline() {
+    let project = TestProject::new();
+
+    project.write_file(
+        "main.rs",
+        r#"
+        const KB: usize = 1024;
+
+        #[typegen(export)]
+        pub const MAX_UPLOAD_SIZE: usize = 10 * KB * KB;
+
+        #[cfg_attr(any(), typegen(export))]
+        pub const USER_LOGIN: &str = "user-login";
+
+        pub const NOT_EXPORTED: u32 = 1;
+
+        #[tauri::command]
+        pub fn upload(app: tauri::AppHandle, data: Vec<u8>) {
+            app.emit(USER_LOGIN, ()).unwrap();
+        }
+    "#,
+    );
+
+    let (analyzer, commands) = project.analyze();
+    assert_eq!(analyzer.get_discovered_constants().len(), 2);
+
+    for validation in ["none", "zod"] {
+        let generator = TestGenerator::new();
+        generator.generate(
+            &commands,
+            analyzer.get_discovered_structs(),
+            &analyzer,
+            Some(validation),
+            None,
+        );
+
+        let constants_ts = generator.read_file("constants.ts");
+        assert!(constants_ts.contains("export const MAX_UPLOAD_SIZE = 10485760;"));
+        assert!(constants_ts.contains("export const USER_LOGIN = \"user-login\";"));
+        assert!(!constants_ts.contains("NOT_EXPORTED"));
+        assert!(!constants_ts.contains("KB ="));
+
+        assert!(generator
+            .read_file("index.ts")
+            .contains("export * from './constants';"));
+    }
+}
+
+#[test]
+fn test_missing_serde_derives_are_reported() {
+    let project = TestProject::new();
+
+    project.write_file(
+        "main.rs",
+        r#"
+        use serde::{Deserialize, Serialize};
+
+        #[derive(Serialize)]
+        pub struct Filter {
+            pub query: String,
+        }
+
+        #[derive(Serialize)]
+        pub struct Report {
+            pub summary: Summary,
+        }
+
+        pub struct Summary {
+            pub total: u32,
+        }
+
+        #[tauri::command]
+        pub fn build_report(filter: Filter) -> Report {
+            unimplemented!()
+        }
+    "#,
+    );
+
+    let (analyzer, _) = project.analyze();
+    let mut diagnostics: Vec<String> = analyzer
+        .get_derive_diagnostics()
+        .iter()
+        .map(|d| format!("{} {}", d.type_name, d.missing))
+        .collect();
+    diagnostics.sort();
+    assert_eq!(diagnostics, vec!["Filter Deserialize", "Summary Serialize"]);
+
+    let mut strict = tauri_typegen::analysis::CommandAnalyzer::new();
+    strict.set_strict_serde_derives(true);
+    match strict.analyze_project(project.path()) {
+        Err(error) => assert!(error.to_string().contains("2 type(s)")),
+        Ok(_) => panic!("strict analysis should fail"),
+    }
+}
+
+#[test]
+fn test_arbitraries_full_pipeline() {
+    let project = TestProject::new();
+
+    project.write_file(
+        "main.rs",
+        r#"
+        use serde::{Deserialize, Serialize};
+
+        #[derive(Serialize, Deserialize)]
+        pub struct Tag {
+            pub label: String,
+        }
+
+        #[derive(Serialize, Deserialize)]
+        pub struct Item {
+            pub quantity: u8,
+            pub tags: Vec<Tag>,
+            pub note: Option<String>,
+        }
+
+        #[tauri::command]
+        pub fn add_item(item: Item, price: f64) -> Result<(), String> {
+            Ok(())
+        }
+
+        #[tauri::command]
+        pub fn clear() {}
+    "#,
+    );
+
+    let (analyzer, commands) = project.analyze();
+    let config = tauri_typegen::GenerateConfig {
+        arbitraries: Some(true),
+        ..Default::default()
+    };
+
+    for (validation, nil) in [("none", "null"), ("zod", "undefined")] {
+        let generator = TestGenerator::new();
+        generator.generate(
+            &commands,
+            analyzer.get_discovered_structs(),
+            &analyzer,
+            Some(validation),
+            Some(&config),
+        );
+
+        let arbitraries = generator.read_file("arbitraries.ts");
+        assert!(arbitraries.contains("import fc from 'fast-check';"));
+        assert!(
+            arbitraries.contains("export const TagArbitrary = fc.record({ label: fc.string() });")
+        );
+        assert!(arbitraries.contains(&format!(
+            "export const ItemArbitrary = fc.record({{ quantity: fc.integer({{ min: 0, max: 255 }}), tags: fc.array(TagArbitrary), note: fc.option(fc.string(), {{ nil: {} }}) }});",
+            nil
+        )));
+        assert!(arbitraries.contains(
+            "export const AddItemParamsArbitrary = fc.record({ item: ItemArbitrary, price: fc.double({ noNaN: true, noDefaultInfinity: true }) });"
+        ));
+        assert!(!arbitraries.contains("ClearParams"));
+
+        // Test-only file, kept out of the index
+        assert!(!generator.read_file("index.ts").contains("arbitraries"));
+    }
+}
+
+#[test]
+fn test_field_overrides_full_pipeline() {
+    let project = TestProject::new();
+
+    project.write_file(
+        "main.rs",
+        r#"
+        use serde::{Deserialize, Serialize};
+
+        #[derive(Serialize, Deserialize)]
+        pub struct Order {
+            pub created_at: String,
+            pub shipped_at: Option<String>,
+            pub total: i64,
+        }
+
+        #[tauri::command]
+        pub fn get_order(id: u32) -> Order {
+            unimplemented!()
+        }
+    "#,
+    );
+
+    let overrides: std::collections::HashMap<String, tauri_typegen::FieldOverride> =
+        serde_json::from_str(
+            r#"{
+                "Order.created_at": "Date",
+                "Order.shipped_at": "Date",
+                "Order.total": { "type": "Money", "from": "../money" }
+            }"#,
+        )
+        .unwrap();
+    let config = tauri_typegen::GenerateConfig {
+        field_overrides: Some(overrides),
+        ..Default::default()
+    };
+
+    let mut analyzer = tauri_typegen::analysis::CommandAnalyzer::new();
+    analyzer.add_field_overrides(config.field_overrides.iter().flat_map(|o| o.keys()));
+    let commands = analyzer.analyze_project(project.path()).unwrap();
+
+    let generator = TestGenerator::new();
+    generator.generate(
+        &commands,
+        analyzer.get_discovered_structs(),
+        &analyzer,
+        Some("none"),
+        Some(&config),
+    );
+    let types = generator.read_file("types.ts");
+    assert!(types.contains("import type { Money } from '../money';"));
+    assert!(types.contains("created_at: Date;"));
+    assert!(types.contains("shipped_at?: Date | null;"));
+    assert!(types.contains("total: Money;"));
+
+    let generator = TestGenerator::new();
+    generator.generate(
+        &commands,
+        analyzer.get_discovered_structs(),
+        &analyzer,
+        Some("zod"),
+        Some(&config),
+    );
+    let types = generator.read_file("types.ts");
+    assert!(types.contains("import type { Money } from '../money';"));
+    assert!(types.contains("created_at: z.custom<Date>((val) => true),"));
+    assert!(types.contains("total: z.custom<Money>((val) => true),"));
+}
+
+#[test]
+fn test_raw_response_commands_full_pipeline() {
+    let project = TestProject::new();
+
+    project.write_file(
+        "main.rs",
+        r#"
+        use tauri::ipc::Response;
+
+        #[tauri::command]
+        pub fn read_image(path: String) -> Response {
+            unimplemented!()
+        }
+
+        #[tauri::command]
+        pub async fn download() -> Result<tauri::ipc::Response, String> {
+            unimplemented!()
+        }
+    "#,
+    );
+
+    let mut analyzer = tauri_typegen::analysis::CommandAnalyzer::new();
+    let commands = analyzer.analyze_project(project.path()).unwrap();
+    assert!(commands.iter().all(|cmd| cmd.raw_response));
+
+    let generator = TestGenerator::new();
+    generator.generate(
+        &commands,
+        analyzer.get_discovered_structs(),
+        &analyzer,
+        Some("none"),
+        None,
+    );
+    let commands_ts = generator.read_file("commands.ts");
+    assert!(commands_ts.contains("function toBytes(data: RawResponse): Uint8Array"));
+    assert!(commands_ts.contains(
+        "export async function readImage(params: types.ReadImageParams): Promise<Uint8Array> {"
+    ));
+    assert!(
+        commands_ts.contains("return toBytes(await invoke<RawResponse>('read_image', params));")
+    );
+    assert!(commands_ts.contains("return toBytes(await invoke<RawResponse>('download'));"));
+    assert!(!commands_ts.contains("types.Response"));
+
+    let generator = TestGenerator::new();
+    generator.generate(
+        &commands,
+        analyzer.get_discovered_structs(),
+        &analyzer,
+        Some("zod"),
+        None,
+    );
+    let commands_ts = generator.read_file("commands.ts");
+    assert!(commands_ts
+        .contains("const data = toBytes(await invoke<RawResponse>('read_image', result.data));"));
+    assert!(commands_ts.contains("hooks?: CommandHooks<Uint8Array>): Promise<Uint8Array>"));
+}
+
+#[test]
+fn test_serialized_calls_full_pipeline() {
+    let project = TestProject::new();
+
+    project.write_file(
+        "main.rs",
+        r#"
+        #[tauri::command]
+        #[cfg_attr(any(), typegen(serialize_calls = "project_id"))]
+        pub fn save_project(project_id: u32, name: String) -> Result<(), String> {
+            unimplemented!()
+        }
+
+        #[tauri::command]
+        #[typegen(serialize_calls)]
+        pub fn reset_database() {}
+
+        #[tauri::command]
+        pub fn compact_database() {}
+    "#,
+    );
+
+    let mut analyzer = tauri_typegen::analysis::CommandAnalyzer::new();
+    let commands = analyzer.analyze_project(project.path()).unwrap();
+
+    let generator = TestGenerator::new();
+    generator.generate(
+        &commands,
+        analyzer.get_discovered_structs(),
+        &analyzer,
+        Some("none"),
+        None,
+    );
+    let commands_ts = generator.read_file("commands.ts");
+    assert!(commands_ts.contains("function enqueueCall<T>(key: string"));
+    assert!(commands_ts.contains(
+        "return enqueueCall(`save_project:${String(params.projectId)}`, () => invoke<void>('save_project', params));"
+    ));
+    assert!(commands_ts
+        .contains("return enqueueCall('reset_database', () => invoke<void>('reset_database'));"));
+    assert!(commands_ts.contains("return invoke('compact_database');"));
+
+    // Configured options take precedence over the attributes
+    let config: tauri_typegen::GenerateConfig = serde_json::from_str(
+        r#"{
+            "command_options": {
+                "reset_database": { "serializeCalls": false },
+                "compact_database": { "serializeCalls": true }
+            }
+        }"#,
+    )
+    .unwrap();
+    let generator = TestGenerator::new();
+    generator.generate(
+        &commands,
+        analyzer.get_discovered_structs(),
+        &analyzer,
+        Some("zod"),
+        Some(&config),
+    );
+    let commands_ts = generator.read_file("commands.ts");
+    assert!(commands_ts.contains(
+        "const data = await enqueueCall(`save_project:${String(params.projectId)}`, () => invoke<void>('save_project', result.data));"
+    ));
+    assert!(commands_ts.contains("const data = await invoke<void>('reset_database');"));
+    assert!(commands_ts.contains(
+        "const data = await enqueueCall('compact_database', () => invoke<void>('compact_database'));"
+    ));
+}
+
+#[test]
+fn test_debounced_and_throttled_commands_full_pipeline() {
+    let project = TestProject::new();
+
+    project.write_file(
+        "main.rs",
+        r#"
+        #[tauri::command]
+        pub fn search(query: String) -> Vec<String> {
+            unimplemented!()
+        }
+
+        #[tauri::command]
+        pub fn track_scroll(offset: f64) {}
+    "#,
+    );
+
+    let mut analyzer = tauri_typegen::analysis::CommandAnalyzer::new();
+    let commands = analyzer.analyze_project(project.path()).unwrap();
+    let config: tauri_typegen::GenerateConfig = serde_json::from_str(
+        r#"{
+            "command_options": {
+                "search": { "debounceMs": 300 },
+                "track_scroll": { "throttleMs": 100 }
+            }
+        }"#,
+    )
+    .unwrap();
+
+    let generator = TestGenerator::new();
+    generator.generate(
+        &commands,
+        analyzer.get_discovered_structs(),
+        &analyzer,
+        Some("none"),
+        Some(&config),
+    );
+    let commands_ts = generator.read_file("commands.ts");
+    assert!(commands_ts.contains("function scheduleCall<T>(key: string"));
+    assert!(!commands_ts.contains("function enqueueCall"));
+    assert!(commands_ts.contains(
+        "return scheduleCall('search', 'debounce', 300, () => invoke<string[]>('search', params));"
+    ));
+    assert!(commands_ts.contains(
+        "return scheduleCall('track_scroll', 'throttle', 100, () => invoke<void>('track_scroll', params));"
+    ));
+
+    let generator = TestGenerator::new();
+    generator.generate(
+        &commands,
+        analyzer.get_discovered_structs(),
+        &analyzer,
+        Some("zod"),
+        Some(&config),
+    );
+    let commands_ts = generator.read_file("commands.ts");
+    assert!(commands_ts.contains(
+        "const data = await scheduleCall('search', 'debounce', 300, () => invoke<string[]>('search', result.data));"
+    ));
+}
+
+#[test]
+fn test_cached_queries_full_pipeline() {
+    let project = TestProject::new();
+
+    project.write_file(
+        "main.rs",
+        r#"
+        #[tauri::command]
+        pub fn get_settings() -> Vec<String> {
+            unimplemented!()
+        }
+
+        #[tauri::command]
+        pub fn get_user(id: u32) -> String {
+            unimplemented!()
+        }
+
+        #[tauri::command]
+        pub fn save_user(id: u32) {}
+    "#,
+    );
+
+    let mut analyzer = tauri_typegen::analysis::CommandAnalyzer::new();
+    let commands = analyzer.analyze_project(project.path()).unwrap();
+    let config: tauri_typegen::GenerateConfig = serde_json::from_str(
+        r#"{
+            "command_options": {
+                "get_settings": { "cacheTtlMs": 60000 },
+                "get_user": { "cacheTtlMs": 5000, "debounceMs": 100 },
+                "save_user": { "cacheTtlMs": 5000 }
+            }
+        }"#,
+    )
+    .unwrap();
+
+    let generator = TestGenerator::new();
+    generator.generate(
+        &commands,
+        analyzer.get_discovered_structs(),
+        &analyzer,
+        Some("none"),
+        Some(&config),
+    );
+    let commands_ts = generator.read_file("commands.ts");
+    assert!(commands_ts
+        .contains("export function invalidate(command: string, params?: unknown): void {"));
+    assert!(commands_ts.contains(
+        "return cachedCall('get_settings', undefined, 60000, () => invoke<string[]>('get_settings'));"
+    ));
+    // The cache is checked before the call is debounced
+    assert!(commands_ts.contains(
+        "return cachedCall('get_user', params, 5000, () => scheduleCall('get_user', 'debounce', 100, () => invoke<string>('get_user', params)));"
+    ));
+    // Mutations are never cached
+    assert!(commands_ts.contains("return invoke('save_user', params);"));
+}
+
+#[test]
+fn test_mutations_invalidate_cached_queries_full_pipeline() {
+    let project = TestProject::new();
+
+    project.write_file(
+        "projects.rs",
+        r#"
+        #[tauri::command]
+        pub fn get_projects() -> Vec<String> {
+            unimplemented!()
+        }
+    "#,
+    );
+    project.write_file(
+        "edit.rs",
+        r#"
+        #[tauri::command]
+        #[cfg_attr(any(), typegen(invalidates = "get_projects"))]
+        pub fn update_project(name: String) -> Result<(), String> {
+            unimplemented!()
+        }
+    "#,
+    );
+
+    let (analyzer, commands) = project.analyze();
+    let mut config: tauri_typegen::GenerateConfig = serde_json::from_str(
+        r#"{ "command_options": { "get_projects": { "cacheTtlMs": 60000 } } }"#,
+    )
+    .unwrap();
+
+    for validation in ["none", "zod"] {
+        let generator = TestGenerator::new();
+        generator.generate(
+            &commands,
+            analyzer.get_discovered_structs(),
+            &analyzer,
+            Some(validation),
+            Some(&config),
+        );
+        let commands_ts = generator.read_file("commands.ts");
+        let (call, indent) = match validation {
+            "none" => ("const data = await invoke('update_project', params);", "  "),
+            _ => (
+                "const data = await invoke<void>('update_project', result.data);",
+                "    ",
+            ),
+        };
+        // The cached results are dropped once the mutation succeeds
+        assert!(commands_ts.contains(&format!(
+            "{indent}{call}\n{indent}invalidate('get_projects');\n"
+        )));
+    }
+
+    // Chunks of mutations import `invalidate` from the shared module
+    config.max_commands_per_file = Some(1);
+    let generator = TestGenerator::new();
+    generator.generate(
+        &commands,
+        analyzer.get_discovered_structs(),
+        &analyzer,
+        Some("none"),
+        Some(&config),
+    );
+    let edit = generator.read_file("commands.edit.ts");
+    assert!(edit.contains("import { invalidate } from './commands.shared';"));
+    assert!(edit.contains("  invalidate('get_projects');\n  return data;"));
+}
+
+#[test]
+fn test_react_query_hooks_full_pipeline() {
+    let project = TestProject::new();
+
+    project.write_file(
+        "main.rs",
+        r#"
+        #[tauri::command]
+        pub fn get_user(id: u32) -> String {
+            unimplemented!()
+        }
+
+        #[tauri::command]
+        pub fn save_user(id: u32, name: String) {}
+
+        #[tauri::command]
+        pub fn download(on_progress: tauri::ipc::Channel<u32>) {}
+    "#,
+    );
+
+    let mut analyzer = tauri_typegen::analysis::CommandAnalyzer::new();
+    let commands = analyzer.analyze_project(project.path()).unwrap();
+    let config: tauri_typegen::GenerateConfig = serde_json::from_str(
+        r#"{
+            "framework": "react-query",
+            "command_options": { "save_user": { "invalidates": ["get_user"] } }
+        }"#,
+    )
+    .unwrap();
+
+    let generator = TestGenerator::new();
+    generator.generate(
+        &commands,
+        analyzer.get_discovered_structs(),
+        &analyzer,
+        Some("zod"),
+        Some(&config),
+    );
+    let hooks_ts = generator.read_file("hooks.ts");
+    assert!(hooks_ts.contains("import { queryKeys } from './queryKeys';"));
+    assert!(hooks_ts.contains(
+        "export function useGetUserQuery(params: types.GetUserParams, options?: CommandQueryOptions<Awaited<ReturnType<typeof commands.getUser>>>) {"
+    ));
+    assert!(hooks_ts.contains("queryKey: queryKeys.getUser(params),"));
+    assert!(hooks_ts.contains("export function useSaveUserMutation("));
+    assert!(hooks_ts
+        .contains("await queryClient.invalidateQueries({ queryKey: queryKeys.getUser.all });"));
+    // Optimistic updates of the invalidated queries are applied while the mutation runs
+    assert!(hooks_ts.contains(
+        "optimistic?: OptimisticUpdates<types.SaveUserParams, { getUser: Awaited<ReturnType<typeof commands.getUser>> }>) {"
+    ));
+    assert!(hooks_ts.contains("        [queryKeys.getUser.all, optimistic.getUser],\n"));
+    assert!(hooks_ts.contains("function withOptimisticUpdates<T, P>("));
+    // Channel commands stream their results and get no hook
+    assert!(!hooks_ts.contains("useDownload"));
+    assert!(generator
+        .read_file("index.ts")
+        .contains("export * from './hooks';"));
+}
+
+#[test]
+fn test_vue_composables_full_pipeline() {
+    let project = TestProject::new();
+
+    project.write_file(
+        "main.rs",
+        r#"
+        #[tauri::command]
+        pub fn get_user(id: u32) -> String {
+            unimplemented!()
+        }
+
+        #[tauri::command]
+        pub fn get_settings() -> Vec<String> {
+            unimplemented!()
+        }
+
+        #[tauri::command]
+        pub fn save_user(id: u32, name: String) {}
+    "#,
+    );
+
+    let mut analyzer = tauri_typegen::analysis::CommandAnalyzer::new();
+    let commands = analyzer.analyze_project(project.path()).unwrap();
+    let config = tauri_typegen::GenerateConfig {
+        framework: Some("vue".to_string()),
+        ..Default::default()
+    };
+
+    let generator = TestGenerator::new();
+    generator.generate(
+        &commands,
+        analyzer.get_discovered_structs(),
+        &analyzer,
+        Some("none"),
+        Some(&config),
+    );
+    let hooks_ts = generator.read_file("hooks.ts");
+    assert!(hooks_ts.contains("from 'vue';"));
+    assert!(hooks_ts.contains(
+        "export function useGetUser(params: MaybeRefOrGetter<types.GetUserParams>, options: CommandQueryOptions = {}) {"
+    ));
+    assert!(hooks_ts.contains("watch(() => toValue(params), (value) => state.execute(value), {"));
+    assert!(
+        hooks_ts.contains("export function useGetSettings(options: CommandQueryOptions = {}) {")
+    );
+    assert!(hooks_ts
+        .contains("export function useSaveUser() {\n  return useCommand(commands.saveUser);"));
+    // Vue composables need no query keys
+    assert!(!hooks_ts.contains("queryKeys"));
+    assert!(!generator.file_exists("queryKeys.ts"));
+}
+
+#[test]
+fn test_framework_hooks_refetch_invalidated_queries_full_pipeline() {
+    let project = TestProject::new();
+
+    project.write_file(
+        "main.rs",
+        r#"
+        #[tauri::command]
+        pub fn get_projects() -> Vec<String> {
+            unimplemented!()
+        }
+
+        #[tauri::command]
+        pub fn get_project(id: u32) -> String {
+            unimplemented!()
+        }
+
+        #[tauri::command]
+        #[cfg_attr(any(), typegen(invalidates = "get_projects", invalidates = "get_project"))]
+        pub fn rename_project(id: u32, name: String) -> Result<(), String> {
+            unimplemented!()
+        }
+    "#,
+    );
+
+    let (analyzer, commands) = project.analyze();
+    for framework in ["vue", "svelte"] {
+        let config = tauri_typegen::GenerateConfig {
+            framework: Some(framework.to_string()),
+            ..Default::default()
+        };
+        let generator = TestGenerator::new();
+        generator.generate(
+            &commands,
+            analyzer.get_discovered_structs(),
+            &analyzer,
+            Some("none"),
+            Some(&config),
+        );
+        let hooks_ts = generator.read_file("hooks.ts");
+
+        // Mutations refetch the invalidated queries once they succeed
+        assert!(hooks_ts.contains(
+            "    const result = await commands.renameProject(params);\n    refetchQueries(['getProjects', 'getProject']);\n"
+        ));
+        match framework {
+            "vue" => {
+                assert!(hooks_ts.contains("import { getCurrentScope, onScopeDispose, "));
+                assert!(hooks_ts.contains("  trackQuery('getProjects', () => state.execute());"));
+                assert!(hooks_ts
+                    .contains("  trackQuery('getProject', () => state.execute(toValue(params)));"));
+            }
+            _ => {
+                assert!(hooks_ts.contains(
+                    "  const store = trackQuery('getProject', commandStore(commands.getProject), params);"
+                ));
+            }
+        }
+    }
+}
+
+#[test]
+fn test_svelte_stores_full_pipeline() {
+    let project = TestProject::new();
+
+    project.write_file(
+        "main.rs",
+        r#"
+        use serde::{Deserialize, Serialize};
+
+        #[derive(Debug, Clone, Serialize, Deserialize)]
+        pub struct User {
+            pub name: String,
+        }
+
+        #[tauri::command]
+        pub fn get_user(app: tauri::AppHandle, id: u32) -> User {
+            let user = User { name: "Test".to_string() };
+            app.emit("user-loaded", user.clone()).ok();
+            user
+        }
+
+        #[tauri::command]
+        pub fn save_user(name: String) {}
+    "#,
+    );
+
+    let (analyzer, commands) = project.analyze();
+    let config = tauri_typegen::GenerateConfig {
+        framework: Some("svelte".to_string()),
+        ..Default::default()
+    };
+
+    let generator = TestGenerator::new();
+    generator.generate(
+        &commands,
+        analyzer.get_discovered_structs(),
+        &analyzer,
+        Some("none"),
+        Some(&config),
+    );
+    let hooks_ts = generator.read_file("hooks.ts");
+    assert!(hooks_ts.contains("import { readable, writable, type Readable } from 'svelte/store';"));
+    assert!(hooks_ts.contains("import * as events from './events';"));
+    assert!(hooks_ts.contains(
+        "export function getUserStore(params: types.GetUserParams, options: CommandQueryOptions = {}) {"
+    ));
+    assert!(hooks_ts
+        .contains("export function saveUserStore() {\n  return commandStore(commands.saveUser);"));
+    assert!(hooks_ts.contains(
+        "export const userLoadedEvent = readable<types.User | undefined>(undefined, (set) => {\n  const unlisten = events.onUserLoaded(set);"
+    ));
+    assert!(!generator.file_exists("queryKeys.ts"));
+}
+
+#[test]
+fn test_pinia_stores_full_pipeline() {
+    let project = TestProject::new();
+
+    project.write_file(
+        "src/commands/user.rs",
+        r#"
+        #[derive(serde::Serialize, serde::Deserialize)]
+        pub struct User {
+            pub name: String,
+        }
+
+        #[tauri::command]
+        pub fn get_user(id: u32) -> Result<User, String> {
+            unimplemented!()
+        }
+
+        #[tauri::command]
+        pub fn save_user(user: User) {}
+    "#,
+    );
+    project.write_file(
+        "src/commands/stats.rs",
+        r#"
+        #[tauri::command]
+        pub fn count_users() -> u32 {
+            0
+        }
+    "#,
+    );
+
+    let (analyzer, commands) = project.analyze();
+    let config = tauri_typegen::GenerateConfig {
+        framework: Some("pinia".to_string()),
+        ..Default::default()
+    };
+
+    let generator = TestGenerator::new();
+    generator.generate(
+        &commands,
+        analyzer.get_discovered_structs(),
+        &analyzer,
+        Some("none"),
+        Some(&config),
+    );
+    let hooks_ts = generator.read_file("hooks.ts");
+    assert!(hooks_ts.contains("import { defineStore } from 'pinia';"));
+    assert!(hooks_ts.contains("export interface StatsResults {\n  countUsers?: number;\n}"));
+    assert!(hooks_ts.contains("export const useStatsStore = defineStore('stats', () => {"));
+    assert!(hooks_ts.contains("export interface UserResults {\n  getUser?: types.User;\n}"));
+    assert!(hooks_ts.contains(
+        "  const getUser = (params: types.GetUserParams) =>\n    run(() => commands.getUser(params), (result) => {\n      results.value.getUser = result;"
+    ));
+    assert!(hooks_ts.contains(
+        "  const saveUser = (params: types.SaveUserParams) =>\n    run(() => commands.saveUser(params));"
+    ));
+    assert!(hooks_ts.contains("  return { results, error, loading, getUser, saveUser };"));
+}
+
+#[test]
+fn test_test_harness_full_pipeline() {
+    let project = TestProject::new();
+
+    project.write_file(
+        "main.rs",
+        r#"
+        #[derive(serde::Serialize)]
+        pub struct User {
+            pub name: String,
+        }
+
+        #[tauri::command]
+        pub fn get_users(team: String) -> Result<Vec<User>, String> {
+            unimplemented!()
+        }
+
+        #[tauri::command]
+        pub fn ping() -> bool {
+            true
+        }
+
+        #[tauri::command]
+        pub fn download(on_progress: tauri::ipc::Channel<u32>) {}
+    "#,
+    );
+
+    let mut analyzer = tauri_typegen::analysis::CommandAnalyzer::new();
+    let commands = analyzer.analyze_project(project.path()).unwrap();
+    let config = tauri_typegen::GenerateConfig {
+        test_harness: Some(true),
+        ..Default::default()
+    };
+
+    let generator = TestGenerator::new();
+    generator.generate(
+        &commands,
+        analyzer.get_discovered_structs(),
+        &analyzer,
+        Some("zod"),
+        Some(&config),
+    );
+    let testing_ts = generator.read_file("testing.ts");
+    assert!(testing_ts.contains("import { mockIPC, clearMocks } from '@tauri-apps/api/mocks';"));
+    assert!(testing_ts.contains("import { UserSchema } from './types';"));
+    assert!(testing_ts.contains(
+        "get_users: (args: types.GetUsersParams) => types.User[] | Promise<types.User[]>;"
+    ));
+    assert!(testing_ts.contains("ping: () => boolean | Promise<boolean>;"));
+    assert!(testing_ts.contains("get_users: z.array(UserSchema),"));
+    assert!(testing_ts.contains("throw new MockResponseError(command, result.error.issues);"));
+    // Channels cannot be mocked through mockIPC
+    assert!(!testing_ts.contains("download"));
+    // Test-only, like the arbitraries
+    assert!(!generator.read_file("index.ts").contains("testing"));
+
+    let generator = TestGenerator::new();
+    generator.generate(
+        &commands,
+        analyzer.get_discovered_structs(),
+        &analyzer,
+        Some("none"),
+        Some(&config),
+    );
+    let testing_ts = generator.read_file("testing.ts");
+    assert!(testing_ts
+        .contains("export function setupTauriMocks(handlers: Partial<CommandHandlers>): void {"));
+    assert!(!testing_ts.contains("returnSchemas"));
+}
+
+#[test]
+fn test_mocks_full_pipeline() {
+    let project = TestProject::new();
+
+    project.write_file(
+        "main.rs",
+        r#"
+        #[derive(serde::Serialize)]
+        pub struct User {
+            pub name: String,
+            pub manager: Box<User>,
+        }
+
+        #[derive(serde::Serialize)]
+        pub struct Team {
+            pub name: String,
+            pub size: u32,
+        }
+
+        #[tauri::command]
+        pub fn get_team(id: u32) -> Result<Team, String> {
+            unimplemented!()
+        }
+
+        #[tauri::command]
+        pub fn get_user() -> User {
+            unimplemented!()
+        }
+
+        #[tauri::command]
+        pub fn reset() {}
+
+        #[tauri::command]
+        pub fn download(on_progress: tauri::ipc::Channel<u32>) {}
+    "#,
+    );
+
+    let mut analyzer = tauri_typegen::analysis::CommandAnalyzer::new();
+    let commands = analyzer.analyze_project(project.path()).unwrap();
+    let config = tauri_typegen::GenerateConfig {
+        mocks: Some(true),
+        ..Default::default()
+    };
+
+    let generator = TestGenerator::new();
+    generator.generate(
+        &commands,
+        analyzer.get_discovered_structs(),
+        &analyzer,
+        Some("zod"),
+        Some(&config),
+    );
+    let mocks_ts = generator.read_file("mocks.ts");
+    assert!(mocks_ts.contains("import { mockIPC, clearMocks } from '@tauri-apps/api/mocks';"));
+    assert!(mocks_ts.contains(
+        "get_team: (_args: types.GetTeamParams): types.Team => ({ name: 'example', size: 1 }),"
+    ));
+    assert!(mocks_ts.contains("reset: (): void => undefined,"));
+    // No value can be synthesized for the recursive type
+    assert!(mocks_ts.contains("get_user: (): types.User => {"));
+    assert!(
+        mocks_ts.contains("export function mockAllCommands(overrides: MockOverrides = {}): void {")
+    );
+    // Channels cannot be mocked through mockIPC
+    assert!(!mocks_ts.contains("download"));
+    // Test-only, like the test harness
+    assert!(!generator.read_file("index.ts").contains("mocks"));
+}
+
+#[test]
+fn test_unknown_type_fallback_full_pipeline() {
+    let source = r#"
+        #[derive(serde::Serialize)]
+        pub struct Report {
+            pub items: Vec<External>,
+        }
+
+        #[tauri::command]
+        pub fn get_report(filter: External) -> Result<Report, String> {
+            unimplemented!()
+        }
+    "#;
+    let config = |fallback: &str| tauri_typegen::GenerateConfig {
+        validation_library: "zod".to_string(),
+        unknown_type_fallback: Some(fallback.to_string()),
+        ..Default::default()
+    };
+    let read = |files: &[tauri_typegen::GeneratedFile], name: &str| {
+        files
+            .iter()
+            .find(|file| file.name == name)
+            .map(|file| file.content.clone())
+            .unwrap()
+    };
+
+    let files = tauri_typegen::generate_from_source(source, &config("unknown")).unwrap();
+    let types_ts = read(&files, "types.ts");
+    assert!(types_ts.contains("items: z.array(z.unknown()),"));
+    assert!(types_ts.contains("filter: z.unknown(),"));
+    assert!(!types_ts.contains("External"));
+
+    let files = tauri_typegen::generate_from_source(source, &config("any")).unwrap();
+    assert!(read(&files, "types.ts").contains("items: z.array(z.any()),"));
+
+    let error = tauri_typegen::generate_from_source(source, &config("error"))
+        .unwrap_err()
+        .to_string();
+    assert!(error.contains("No definition found for referenced types: External"));
+}
+
+#[test]
+fn test_settings_stores_full_pipeline() {
+    let source = r#"
+        #[derive(serde::Serialize, serde::Deserialize)]
+        pub enum Theme { Light, Dark }
+
+        #[derive(serde::Serialize, serde::Deserialize)]
+        #[serde(rename_all = "camelCase")]
+        pub struct AppSettings {
+            pub theme: Theme,
+            pub font_size: u32,
+        }
+
+        #[tauri::command]
+        pub fn ping() {}
+    "#;
+    let config = tauri_typegen::GenerateConfig {
+        validation_library: "zod".to_string(),
+        stores: Some(std::collections::HashMap::from([(
+            "settings.json".to_string(),
+            "AppSettings".to_string(),
+        )])),
+        ..Default::default()
+    };
+
+    let files = tauri_typegen::generate_from_source(source, &config).unwrap();
+    let read = |name: &str| {
+        files
+            .iter()
+            .find(|file| file.name == name)
+            .map(|file| file.content.clone())
+            .unwrap()
+    };
+    // No command uses the store type, it is generated for the store
+    assert!(read("types.ts").contains("export const AppSettingsSchema"));
+    let stores_ts = read("stores.ts");
+    assert!(stores_ts.contains("import { load, type Store } from '@tauri-apps/plugin-store';"));
+    assert!(stores_ts.contains("import { ThemeSchema } from './types';"));
+    assert!(stores_ts.contains(
+        "export const AppSettingsKeySchemas: KeySchemas<types.AppSettings> = {\n  theme: ThemeSchema,\n  fontSize: z.coerce.number(),\n};"
+    ));
+    assert!(stores_ts.contains(
+        "export const appSettingsStore = settingsStore<types.AppSettings>('settings.json', AppSettingsKeySchemas);"
+    ));
+    assert!(read("index.ts").contains("export * from './stores';"));
+}
+
+#[test]
+fn test_compact_codec_full_pipeline() {
+    let project = TestProject::new();
+
+    project.write_file(
+        "src/grid.rs",
+        r#"
+        use crate::compact::Compact;
+
+        #[derive(serde::Serialize, serde::Deserialize)]
+        #[serde(rename_all = "camelCase")]
+        pub struct Grid {
+            pub cell_values: Vec<Vec<f32>>,
+        }
+
+        #[tauri::command]
+        pub fn save_grid(grid_data: Compact<Grid>) {}
+
+        #[tauri::command]
+        pub async fn load_grid(name: String) -> Result<Compact<Grid>, String> {
+            unimplemented!()
+        }
+    "#,
+    );
+
+    let codec_path = project.temp_dir.path().join("src/compact.rs");
+    let config = tauri_typegen::GenerateConfig {
+        validation_library: "zod".to_string(),
+        compact_types: Some(vec!["Grid".to_string()]),
+        compact_codec: Some(codec_path.to_string_lossy().to_string()),
+        ..Default::default()
+    };
+    let mut analyzer = tauri_typegen::analysis::CommandAnalyzer::new();
+    analyzer.add_type_mappings(&config.compact_type_mappings());
+    let commands = analyzer.analyze_project(project.path()).unwrap();
+
+    let generator = TestGenerator::new();
+    generator.generate(
+        &commands,
+        analyzer.get_discovered_structs(),
+        &analyzer,
+        Some("zod"),
+        Some(&config),
+    );
+
+    let codec = std::fs::read_to_string(&codec_path).unwrap();
+    assert!(codec.contains("impl CompactType for crate::grid::Grid {}"));
+    assert!(codec.contains("impl<T: CompactType> IpcResponse for Compact<T> {"));
+
+    let compact_ts = generator.read_file("compact.ts");
+    assert!(compact_ts.contains("import { decode, encode } from '@msgpack/msgpack';"));
+    assert!(compact_ts.contains("  return encode(GridSchema.parse(value));"));
+    assert!(compact_ts.contains(
+        "  save_grid: {\n    encode: (args) => encodeGrid(args['gridData'] as types.Grid),\n  },"
+    ));
+    assert!(compact_ts.contains("  load_grid: {\n    decode: decodeGrid,\n  },"));
+
+    // The parameter and result are typed as the compact type
+    let commands_ts = generator.read_file("commands.ts");
+    assert!(commands_ts.contains("import { invokeCompact } from './compact';"));
+    assert!(
+        commands_ts.contains("const data = await invokeCompact<void>('save_grid', result.data);")
+    );
+    assert!(commands_ts.contains("Promise<types.Grid>"));
+    assert!(generator
+        .read_file("types.ts")
+        .contains("gridData: GridSchema"));
+}
+
+#[test]
+fn test_zod_unknown_fields_full_pipeline() {
+    let project = TestProject::new();
+
+    project.write_file(
+        "main.rs",
+        r#"
+        #[derive(serde::Deserialize)]
+        #[serde(deny_unknown_fields, rename_all = "camelCase")]
+        pub struct Credentials {
+            pub user_name: String,
+        }
+
+        #[derive(serde::Serialize)]
+        pub struct Session {
+            pub token: String,
+        }
+
+        #[tauri::command]
+        pub fn login(credentials: Credentials) -> Session {
+            unimplemented!()
+        }
+    "#,
+    );
+
+    let mut analyzer = tauri_typegen::analysis::CommandAnalyzer::new();
+    let commands = analyzer.analyze_project(project.path()).unwrap();
+    assert!(analyzer.get_discovered_structs()["Credentials"].serde_deny_unknown_fields);
+
+    for (mode, session_schema) in [
+        (None, "  token: z.string(),\n}))();"),
+        (
+            Some("passthrough"),
+            "  token: z.string(),\n}).passthrough())();",
+        ),
+    ] {
+        let config = tauri_typegen::GenerateConfig {
+            zod_object_mode: mode.map(String::from),
+            ..Default::default()
+        };
+        let generator = TestGenerator::new();
+        generator.generate(
+            &commands,
+            analyzer.get_discovered_structs(),
+            &analyzer,
+            Some("zod"),
+            Some(&config),
+        );
+        let types_ts = generator.read_file("types.ts");
+        assert!(types_ts.contains("  userName: z.string(),\n}).strict())();"));
+        assert!(types_ts.contains(session_schema));
+    }
+}
+
+#[test]
+fn test_zero_sized_fields_full_pipeline() {
+    let project = TestProject::new();
+
+    project.write_file(
+        "main.rs",
+        r#"
+        use std::marker::{PhantomData, PhantomPinned};
+
+        #[derive(serde::Serialize, serde::Deserialize)]
+        pub struct UserTag;
+
+        #[derive(serde::Serialize, serde::Deserialize)]
+        pub struct UserId {
+            pub value: u64,
+            pub kind: PhantomData<UserTag>,
+            pub pinned: PhantomPinned,
+            pub nothing: (),
+        }
+
+        #[tauri::command]
+        pub fn rename(id: UserId) -> UserId {
+            unimplemented!()
+        }
+    "#,
+    );
+
+    let mut analyzer = tauri_typegen::analysis::CommandAnalyzer::new();
+    let commands = analyzer.analyze_project(project.path()).unwrap();
+
+    for library in ["none", "zod", "valibot"] {
+        let generator = TestGenerator::new();
+        generator.generate(
+            &commands,
+            analyzer.get_discovered_structs(),
+            &analyzer,
+            Some(library),
+            None,
+        );
+        let types_ts = generator.read_file("types.ts");
+        assert!(types_ts.contains("value"), "{}", types_ts);
+        for marker in ["kind", "pinned", "nothing", "marker", "PhantomData"] {
+            assert!(
+                !types_ts.contains(marker),
+                "{} bindings contain {}:\n{}",
+                library,
+                marker,
+                types_ts
+            );
+        }
+    }
+
+    let mut analyzer = tauri_typegen::analysis::CommandAnalyzer::new();
+    analyzer.set_skip_zero_sized_fields(false);
+    analyzer.analyze_project(project.path()).unwrap();
+    let fields = &analyzer.get_discovered_structs()["UserId"].fields;
+    assert_eq!(fields.len(), 4);
+}
+
+#[test]
+fn test_self_test_full_pipeline() {
+    let project = TestProject::new();
+
+    project.write_file(
+        "src/models.rs",
+        r#"
+        #[derive(serde::Serialize, serde::Deserialize)]
+        #[serde(rename_all = "camelCase")]
+        pub struct User {
+            pub display_name: String,
+            pub age: Option<u8>,
+        }
+
+        #[derive(serde::Deserialize)]
+        pub struct Filter {
+            pub query: String,
+        }
+
+        #[derive(serde::Serialize)]
+        pub struct Stats {
+            pub count: u32,
+        }
+
+        #[tauri::command]
+        pub fn find_users(filter: Filter) -> Vec<User> {
+            unimplemented!()
+        }
+
+        #[tauri::command]
+        pub fn save_user(user: User) -> Stats {
+            unimplemented!()
+        }
+    "#,
+    );
+    // A previously generated command is not part of the bindings
+    project.write_file(
+        "src/typegen_selftest.rs",
+        r#"
+        #[tauri::command]
+        pub fn __typegen_selftest(type_name: String, value: serde_json::Value) {}
+    "#,
+    );
+
+    let command_path = project.temp_dir.path().join("src/typegen_selftest.rs");
+    let config = tauri_typegen::GenerateConfig {
+        validation_library: "zod".to_string(),
+        self_test: Some(command_path.to_string_lossy().to_string()),
+        ..Default::default()
+    };
+    let mut analyzer = tauri_typegen::analysis::CommandAnalyzer::new();
+    let commands = analyzer.analyze_project(project.path()).unwrap();
+    assert_eq!(commands.len(), 2);
+
+    let generator = TestGenerator::new();
+    generator.generate(
+        &commands,
+        analyzer.get_discovered_structs(),
+        &analyzer,
+        Some("zod"),
+        Some(&config),
+    );
+
+    let command = std::fs::read_to_string(&command_path).unwrap();
+    assert!(command.contains("pub fn __typegen_selftest("));
+    assert!(command.contains("\"User\" => round_trip::<crate::models::User>(value),"));
+    assert!(command.contains("\"Filter\" => accept::<crate::models::Filter>(value),"));
+    assert!(!command.contains("Stats"));
+
+    let self_test_ts = generator.read_file("selfTest.ts");
+    assert!(self_test_ts.contains(
+        "{ type: 'User', value: { displayName: 'example' } satisfies types.User, roundTrip: true, schema: UserSchema },"
+    ));
+    assert!(self_test_ts.contains(
+        "{ type: 'Filter', value: { query: 'example' } satisfies types.Filter, roundTrip: false },"
+    ));
+    assert!(self_test_ts
+        .contains("{ type: 'Stats', status: 'skipped', message: 'does not derive Deserialize' },"));
+    assert!(self_test_ts
+        .contains("export async function runBindingsSelfTest(): Promise<SelfTestResult[]> {"));
+    assert!(self_test_ts.contains("sample.schema?.safeParse(returned)"));
+}
+
+#[test]
+fn test_offline_queue_full_pipeline() {
+    let project = TestProject::new();
+
+    project.write_file(
+        "main.rs",
+        r#"
+        #[tauri::command]
+        pub fn get_notes() -> Vec<String> {
+            unimplemented!()
+        }
+
+        #[tauri::command]
+        pub fn add_note(text: String) -> Result<u32, String> {
+            unimplemented!()
+        }
+
+        #[tauri::command]
+        pub fn sync_now() {}
+    "#,
+    );
+
+    let mut analyzer = tauri_typegen::analysis::CommandAnalyzer::new();
+    let commands = analyzer.analyze_project(project.path()).unwrap();
+    let config: tauri_typegen::GenerateConfig = serde_json::from_str(
+        r#"{
+            "offline_queue": true,
+            "command_options": { "sync_now": { "kind": "query" } }
+        }"#,
+    )
+    .unwrap();
+
+    let generator = TestGenerator::new();
+    generator.generate(
+        &commands,
+        analyzer.get_discovered_structs(),
+        &analyzer,
+        Some("none"),
+        Some(&config),
+    );
+    let offline_ts = generator.read_file("offline.ts");
+    assert!(offline_ts.contains("  | { command: 'add_note'; params: types.AddNoteParams };"));
+    assert!(offline_ts.contains(
+        "export function addNoteOffline(params: types.AddNoteParams): Promise<OfflineResult<number>> {"
+    ));
+    assert!(offline_ts.contains("return commands.addNote(mutation.params);"));
+    // Queries and mutations configured as queries are sent as usual
+    assert!(!offline_ts.contains("getNotesOffline"));
+    assert!(!offline_ts.contains("syncNowOffline"));
+    assert!(generator
+        .read_file("index.ts")
+        .contains("export * from './offline';"));
+}
+
+#[test]
+fn test_invalidates_full_pipeline() {
+    let project = TestProject::new();
+
+    project.write_file(
+        "main.rs",
+        r#"
+        #[tauri::command]
+        pub fn get_projects() -> Vec<String> {
+            unimplemented!()
+        }
+
+        #[tauri::command]
+        #[cfg_attr(any(), typegen(invalidates = "get_projects"))]
+        pub fn create_project(name: String) {}
+    "#,
+    );
+
+    let mut analyzer = tauri_typegen::analysis::CommandAnalyzer::new();
+    let commands = analyzer.analyze_project(project.path()).unwrap();
+
+    let generator = TestGenerator::new();
+    generator.generate(
+        &commands,
+        analyzer.get_discovered_structs(),
+        &analyzer,
+        Some("none"),
+        None,
+    );
+    let commands_ts = generator.read_file("commands.ts");
+    assert!(commands_ts.contains(
+        "/**\n * Invalidates {@link getProjects} on success\n *\n * @example\n * await createProject({ name: 'example' });\n */"
+    ));
+}
+
+#[test]
+fn test_query_keys_full_pipeline() {
+    let project = TestProject::new();
+
+    project.write_file(
+        "main.rs",
+        r#"
+        #[tauri::command]
+        pub fn list_users() -> Vec<String> {
+            unimplemented!()
+        }
+
+        #[tauri::command]
+        pub fn get_user(user_id: u32) -> String {
+            unimplemented!()
+        }
+
+        #[tauri::command]
+        pub fn delete_user(user_id: u32) {}
+    "#,
+    );
+
+    let mut analyzer = tauri_typegen::analysis::CommandAnalyzer::new();
+    let commands = analyzer.analyze_project(project.path()).unwrap();
+    let config: tauri_typegen::GenerateConfig =
+        serde_json::from_str(r#"{ "query_keys": true }"#).unwrap();
+
+    let generator = TestGenerator::new();
+    generator.generate(
+        &commands,
+        analyzer.get_discovered_structs(),
+        &analyzer,
+        Some("zod"),
+        Some(&config),
+    );
+    let query_keys_ts = generator.read_file("queryKeys.ts");
+    let get_user = query_keys_ts
+        .find("(params: types.GetUserParams) => ['tauri', 'get_user', params] as const,")
+        .unwrap();
+    let list_users = query_keys_ts
+        .find("() => ['tauri', 'list_users'] as const,")
+        .unwrap();
+    // Ordered by command name, not source order
+    assert!(get_user < list_users);
+    assert!(!query_keys_ts.contains("delete_user"));
+}
+
+#[test]
+fn test_emitted_events_documented_full_pipeline() {
+    let project = TestProject::new();
+
+    project.write_file(
+        "main.rs",
+        r#"
+        use serde::{Deserialize, Serialize};
+        use tauri::{ipc::Channel, Emitter};
+
+        #[derive(Clone, Serialize, Deserialize)]
+        pub struct User {
+            pub name: String,
+        }
+
+        #[tauri::command]
+        pub fn login(app: tauri::AppHandle, user: User) {
+            app.emit("user-login", user.clone()).unwrap();
+            app.emit("user-login", user).unwrap();
+        }
+
+        #[tauri::command]
+        pub fn export_logs(on_line: Channel<String>) {}
+
+        fn tick(app: tauri::AppHandle) {
+            app.emit("tick", 1u32).unwrap();
+        }
+    "#,
+    );
+
+    let (analyzer, commands) = project.analyze();
+    let generator = TestGenerator::new();
+    generator.generate(
+        &commands,
+        analyzer.get_discovered_structs(),
+        &analyzer,
+        Some("none"),
+        None,
+    );
+    let commands_ts = generator.read_file("commands.ts");
+    assert!(commands_ts.contains(
+        "/**\n * Emits `user-login` with a `User` payload, listen with `onUserLogin`\n *\n * @example"
+    ));
+    assert!(commands_ts
+        .contains(" * Sends `string` messages through the `onLine` channel\n *\n * @example"));
+    // Events emitted outside commands are not attributed to them
+    assert!(!commands_ts.contains("Emits `tick`"));
+}
+
+#[test]
+fn test_event_router_full_pipeline() {
+    let project = TestProject::new();
+
+    project.write_file(
+        "main.rs",
+        r#"
+        use serde::{Deserialize, Serialize};
+        use tauri::Emitter;
+
+        #[derive(Clone, Serialize, Deserialize)]
+        pub struct User {
+            pub name: String,
+        }
+
+        #[tauri::command]
+        pub fn login(app: tauri::AppHandle, user: User) {
+            app.emit("user-login", user.clone()).unwrap();
+            app.emit("tick", 1u32).unwrap();
+        }
+
+        #[tauri::command]
+        pub fn logout(app: tauri::AppHandle) {
+            app.emit("tick", 2u32).unwrap();
+        }
+    "#,
+    );
+
+    let (analyzer, commands) = project.analyze();
+    let config: tauri_typegen::GenerateConfig =
+        serde_json::from_str(r#"{ "event_router": true }"#).unwrap();
+
+    for validation in ["none", "zod"] {
+        let generator = TestGenerator::new();
+        generator.generate(
+            &commands,
+            analyzer.get_discovered_structs(),
+            &analyzer,
+            Some(validation),
+            Some(&config),
+        );
+        let events_ts = generator.read_file("events.ts");
+        assert!(events_ts.contains(
+            "export interface EventMap {\n  'user-login': types.User;\n  'tick': number;\n}"
+        ));
+        assert!(events_ts.contains("export function handleEvent<K extends EventName>("));
+        assert!(events_ts.contains("const eventNames: EventName[] = ['user-login', 'tick'];"));
+    }
+
+    let generator = TestGenerator::new();
+    generator.generate(
+        &commands,
+        analyzer.get_discovered_structs(),
+        &analyzer,
+        Some("none"),
+        None,
+    );
+    assert!(!generator.read_file("events.ts").contains("EventMap"));
+}
+
+#[test]
+fn test_sanitize_params_full_pipeline() {
+    let project = TestProject::new();
+
+    project.write_file(
+        "main.rs",
+        r#"
+        #[tauri::command]
+        pub fn update_user(user_id: u32, name: String, active: Option<bool>) {}
+
+        #[tauri::command]
+        pub fn ping() {}
+    "#,
+    );
+
+    let (analyzer, commands) = project.analyze();
+    let config: tauri_typegen::GenerateConfig =
+        serde_json::from_str(r#"{ "sanitize_params": true }"#).unwrap();
+
+    for validation in ["none", "zod"] {
+        let generator = TestGenerator::new();
+        let files = generator.generate(
+            &commands,
+            analyzer.get_discovered_structs(),
+            &analyzer,
+            Some(validation),
+            Some(&config),
+        );
+        assert!(files.contains(&"sanitize.ts".to_string()));
+
+        let sanitize_ts = generator.read_file("sanitize.ts");
+        assert!(sanitize_ts.contains(
+            "'update_user': { 'userId': 'number', 'name': 'string', 'active': 'boolean' },"
+        ));
+        assert!(!sanitize_ts.contains("'ping'"));
+        assert!(sanitize_ts.contains("export function sanitizeParams<P extends object>("));
+
+        let commands_ts = generator.read_file("commands.ts");
+        assert!(commands_ts.contains("import { sanitizeParams } from './sanitize';"));
+        assert!(commands_ts.contains("sanitizeParams('update_user', params)"));
+        assert!(generator
+            .read_file("index.ts")
+            .contains("export * from './sanitize';"));
+    }
+
+    let generator = TestGenerator::new();
+    let files = generator.generate(
+        &commands,
+        analyzer.get_discovered_structs(),
+        &analyzer,
+        Some("none"),
+        None,
+    );
+    assert!(!files.contains(&"sanitize.ts".to_string()));
+    assert!(!generator
+        .read_file("commands.ts")
+        .contains("sanitizeParams"));
+}
+
+#[test]
+fn test_source_trace_full_pipeline() {
+    let project = TestProject::new();
+
+    project.write_file(
+        "main.rs",
+        r#"
+        use serde::{Deserialize, Serialize};
+
+        #[derive(Serialize, Deserialize)]
+        pub struct User {
+            pub name: String,
+        }
+
+        #[tauri::command]
+        pub fn get_user(id: u32) -> User {
+            unimplemented!()
+        }
+    "#,
+    );
+
+    let mut analyzer = tauri_typegen::analysis::CommandAnalyzer::new();
+    let commands = analyzer.analyze_project(project.path()).unwrap();
+    let config: tauri_typegen::GenerateConfig =
+        serde_json::from_str(r#"{ "source_trace": true }"#).unwrap();
+
+    let generator = TestGenerator::new();
+    let files = generator.generate(
+        &commands,
+        analyzer.get_discovered_structs(),
+        &analyzer,
+        Some("none"),
+        Some(&config),
+    );
+    assert!(files.contains(&"trace.json".to_string()));
+    assert!(!generator.read_file("index.ts").contains("trace"));
+
+    let trace: serde_json::Value =
+        serde_json::from_str(&generator.read_file("trace.json")).unwrap();
+    let symbol = |name: &str| {
+        trace["symbols"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .find(|symbol| symbol["symbol"] == name)
+            .unwrap()
+            .clone()
+    };
+    let get_user = symbol("getUser");
+    assert_eq!(get_user["file"], "commands.ts");
+    assert_eq!(get_user["kind"], "command");
+    assert_eq!(get_user["rustName"], "get_user");
+    assert!(get_user["rustFile"].as_str().unwrap().ends_with("main.rs"));
+    assert_eq!(get_user["line"], 10);
+    assert_eq!(symbol("GetUserParams")["kind"], "params");
+    assert_eq!(symbol("User")["kind"], "struct");
+    assert_eq!(symbol("User")["line"], 5);
+}
+
+#[test]
+fn test_macro_records_full_pipeline() {
+    let project = TestProject::new();
+
+    project.write_file(
+        "main.rs",
+        r#"
+        use serde::{Deserialize, Serialize};
+
+        #[derive(Serialize, Deserialize)]
+        pub struct User {
+            pub name: String,
+        }
+
+        macro_rules! getter {
+            ($name:ident, $ty:ty) => {
+                #[tauri::command]
+                pub fn $name(id: u32) -> $ty {
+                    unimplemented!()
+                }
+            };
+        }
+        getter!(get_user, User);
+
+        #[tauri::command]
+        pub fn ping() -> String {
+            unimplemented!()
+        }
+    "#,
+    );
+
+    let records = tempfile::TempDir::new().unwrap();
+    std::fs::write(
+        records.path().join("get_user.json"),
+        r#"{ "name": "get_user", "isAsync": false, "parameters": [{ "name": "id", "rustType": "u32" }], "returnType": "User" }"#,
+    )
+    .unwrap();
+    std::fs::write(
+        records.path().join("ping.json"),
+        r#"{ "name": "ping", "isAsync": false, "parameters": [], "returnType": "String" }"#,
+    )
+    .unwrap();
+
+    let mut analyzer = tauri_typegen::analysis::CommandAnalyzer::new();
+    assert!(analyzer
+        .analyze_project(project.path())
+        .unwrap()
+        .iter()
+        .all(|command| command.name != "get_user"));
+
+    let mut analyzer = tauri_typegen::analysis::CommandAnalyzer::new();
+    analyzer.set_macro_records(Some(records.path().to_str().unwrap()));
+    let commands = analyzer.analyze_project(project.path()).unwrap();
+    let mut names: Vec<&str> = commands
+        .iter()
+        .map(|command| command.name.as_str())
+        .collect();
+    names.sort();
+    assert_eq!(names, ["get_user", "ping"]);
+    assert!(analyzer.get_discovered_structs().contains_key("User"));
+
+    let generator = TestGenerator::new();
+    generator.generate(
+        &commands,
+        analyzer.get_discovered_structs(),
+        &analyzer,
+        Some("none"),
+        None,
+    );
+    let commands_ts = generator.read_file("commands.ts");
+    assert!(commands_ts.contains(
+        "export async function getUser(params: types.GetUserParams): Promise<types.User>"
+    ));
+    assert_eq!(
+        commands_ts.matches("export async function ping(").count(),
+        1
+    );
+}
+
+#[test]
+fn test_additional_source_paths_full_pipeline() {
+    let project = TestProject::new();
+    project.write_file(
+        "main.rs",
+        r#"
+        #[tauri::command]
+        pub fn ping() -> String {
+            unimplemented!()
+        }
+    "#,
+    );
+
+    let vendor = TestProject::new();
+    vendor.write_file(
+        "src/commands.rs",
+        r#"
+        use serde::{Deserialize, Serialize};
+
+        #[derive(Serialize, Deserialize)]
+        pub struct Plugin {
+            pub name: String,
+        }
+
+        #[tauri::command]
+        pub fn list_plugins() -> Vec<Plugin> {
+            unimplemented!()
+        }
+    "#,
+    );
+    vendor.write_file(
+        "src/internal.rs",
+        r#"
+        #[tauri::command]
+        pub fn internal_only() {}
+    "#,
+    );
+
+    let mut analyzer = tauri_typegen::analysis::CommandAnalyzer::new();
+    analyzer.add_source_path(vendor.path(), &["src/commands.rs".to_string()]);
+    let commands = analyzer.analyze_project(project.path()).unwrap();
+    let mut names: Vec<&str> = commands
+        .iter()
+        .map(|command| command.name.as_str())
+        .collect();
+    names.sort();
+    assert_eq!(names, ["list_plugins", "ping"]);
+
+    let generator = TestGenerator::new();
+    generator.generate(
+        &commands,
+        analyzer.get_discovered_structs(),
+        &analyzer,
+        Some("none"),
+        None,
+    );
+    let types_ts = generator.read_file("types.ts");
+    assert!(types_ts.contains("export interface Plugin {"));
+}
+
+#[test]
+fn test_generate_from_source() {
+    let source = r#"
+        use serde::{Deserialize, Serialize};
+
+        #[derive(Serialize, Deserialize)]
+        pub struct Note {
+            pub text: String,
+        }
+
+        #[tauri::command]
+        pub fn add_note(note: Note) -> Result<u32, String> {
+            unimplemented!()
+        }
+    "#;
+
+    let result = tauri_typegen::analyze_source(source).unwrap();
+    assert_eq!(result.commands.len(), 1);
+    assert_eq!(result.commands[0].file_path, "lib.rs");
+    assert!(result.structs.contains_key("Note"));
+
+    let config = tauri_typegen::GenerateConfig {
+        project_path: "/does/not/exist".to_string(),
+        output_path: "/does/not/exist/either".to_string(),
+        validation_library: "zod".to_string(),
+        ..Default::default()
+    };
+    let files = tauri_typegen::generate_from_source(source, &config).unwrap();
+    let names: Vec<&str> = files.iter().map(|file| file.name.as_str()).collect();
+    assert_eq!(names, ["types.ts", "commands.ts", "index.ts"]);
+    assert!(files[0].content.contains("export const NoteSchema"));
+    assert!(!std::path::Path::new(&config.output_path).exists());
+
+    assert!(tauri_typegen::generate_from_source("fn broken(", &config).is_err());
+}
+
+#[test]
+fn test_mixed_command_validation_full_pipeline() {
+    let source = r#"
+        #[tauri::command]
+        pub fn submit_signup(email: String, age: u32) -> Result<(), String> {
+            unimplemented!()
+        }
+
+        #[tauri::command]
+        pub fn get_notes(limit: u32) -> Vec<String> {
+            unimplemented!()
+        }
+    "#;
+
+    // Zod only for the form commands, the rest stay lightweight
+    let config: tauri_typegen::GenerateConfig =
+        serde_json::from_str(r#"{ "command_validation": { "submit_*": "zod" } }"#).unwrap();
+    let files = tauri_typegen::generate_from_source(source, &config).unwrap();
+    let file = |name: &str| {
+        files
+            .iter()
+            .find(|file| file.name == name)
+            .map(|file| file.content.clone())
+            .unwrap()
+    };
+
+    let types_ts = file("types.ts");
+    assert!(types_ts.contains("export const SubmitSignupParamsSchema"));
+    assert!(types_ts.contains("export const GetNotesParamsSchema"));
+
+    let commands_ts = file("commands.ts");
+    assert!(commands_ts.contains("types.SubmitSignupParamsSchema.safeParse(params)"));
+    assert!(commands_ts.contains("await invoke<void>('submit_signup', result.data)"));
+    assert!(!commands_ts.contains("GetNotesParamsSchema"));
+    assert!(commands_ts.contains("await invoke<string[]>('get_notes', params)"));
+
+    // Commands can opt out of a global zod validation as well
+    let config: tauri_typegen::GenerateConfig = serde_json::from_str(
+        r#"{ "validation_library": "zod", "command_validation": { "get_notes": "none" } }"#,
+    )
+    .unwrap();
+    let files = tauri_typegen::generate_from_source(source, &config).unwrap();
+    let commands_ts = &files[1].content;
+    assert!(commands_ts.contains("types.SubmitSignupParamsSchema.safeParse(params)"));
+    assert!(commands_ts.contains("await invoke<string[]>('get_notes', params)"));
+}
+
+#[test]
+fn test_command_aliases_full_pipeline() {
+    let source = r#"
+        #[tauri::command]
+        pub fn fetch_user(user_id: u32) -> String {
+            unimplemented!()
+        }
+
+        #[tauri::command]
+        pub fn ping() {}
+    "#;
+
+    for validation in ["none", "zod"] {
+        let config: tauri_typegen::GenerateConfig = serde_json::from_str(&format!(
+            r#"{{ "validation_library": "{}", "aliases": {{ "get_user": "fetch_user", "ping_server": "ping" }} }}"#,
+            validation
+        ))
+        .unwrap();
+        let files = tauri_typegen::generate_from_source(source, &config).unwrap();
+        let commands_ts = &files[1].content;
+
+        assert!(commands_ts.contains("export const getUser = fetchUser;"));
+        assert!(commands_ts
+            .contains("@deprecated `get_user` was renamed, use {@link fetchUser} instead"));
+        assert!(commands_ts.contains("export type GetUserParams = types.FetchUserParams;"));
+        assert!(commands_ts.contains("export const pingServer = ping;"));
+        assert!(!commands_ts.contains("PingServerParams"));
+    }
+}
+
+#[test]
+fn test_shared_types_full_pipeline() {
+    let project = TestProject::new();
+    project.write_file(
+        "core/lib.rs",
+        r#"
+        use serde::{Deserialize, Serialize};
+
+        #[derive(Serialize, Deserialize)]
+        pub enum Role {
+            Admin,
+            Member,
+        }
+
+        #[derive(Serialize, Deserialize)]
+        pub struct User {
+            pub name: String,
+            pub role: Role,
+        }
+    "#,
+    );
+    project.write_file(
+        "main.rs",
+        r#"
+        use serde::{Deserialize, Serialize};
+
+        #[derive(Serialize, Deserialize)]
+        pub struct Order {
+            pub id: u32,
+            pub user: User,
+        }
+
+        #[tauri::command]
+        pub fn get_order(id: u32) -> Order {
+            unimplemented!()
+        }
+    "#,
+    );
+
+    let (analyzer, commands) = project.analyze();
+    let config: tauri_typegen::GenerateConfig = serde_json::from_value(serde_json::json!({
+        "shared_types": [{ "path": format!("{}/core", project.path()), "package": "@acme/core-types" }]
+    }))
+    .unwrap();
+
+    let generator = TestGenerator::new();
+    generator.generate(
+        &commands,
+        analyzer.get_discovered_structs(),
+        &analyzer,
+        Some("none"),
+        Some(&config),
+    );
+    let types_ts = generator.read_file("types.ts");
+    assert!(types_ts.contains("import type { Role, User } from '@acme/core-types';"));
+    assert!(types_ts.contains("export type { Role, User } from '@acme/core-types';"));
+    assert!(types_ts.contains("export interface Order {"));
+    assert!(!types_ts.contains("export interface User"));
+
+    let generator = TestGenerator::new();
+    generator.generate(
+        &commands,
+        analyzer.get_discovered_structs(),
+        &analyzer,
+        Some("zod"),
+        Some(&config),
+    );
+    let types_ts = generator.read_file("types.ts");
+    assert!(types_ts.contains(
+        "import { RoleSchema, type Role, UserSchema, type User } from '@acme/core-types';"
+    ));
+    assert!(types_ts.contains("user: UserSchema,"));
+    assert!(!types_ts.contains("export const UserSchema"));
+
+    // The package itself is generated from the shared crate with all its types
+    let config = tauri_typegen::GenerateConfig {
+        all_types: Some(true),
+        ..Default::default()
+    };
+    let files = tauri_typegen::generate_from_source(
+        r#"
+        #[derive(serde::Serialize, serde::Deserialize)]
+        pub struct User {
+            pub name: String,
+        }
+    "#,
+        &config,
+    )
+    .unwrap();
+    assert!(files[0].content.contains("export interface User {"));
+}
+
+#[test]
+fn test_binary_helpers_full_pipeline() {
+    let project = TestProject::new();
+
+    project.write_file(
+        "main.rs",
+        r#"
+        #[tauri::command]
+        pub fn save_image(name: String, data: Vec<u8>) -> Result<u32, String> {
+            unimplemented!()
+        }
+
+        #[tauri::command]
+        pub async fn upload_chunk(upload_id: String, chunk: Vec<u8>, offset: u64) -> Result<(), String> {
+            unimplemented!()
+        }
+
+        #[tauri::command]
+        pub fn read_thumbnail() -> Vec<u8> {
+            unimplemented!()
+        }
+
+        #[tauri::command]
+        pub fn ping() -> String {
+            unimplemented!()
+        }
+    "#,
+    );
+
+    let (analyzer, commands) = project.analyze();
+    let config = tauri_typegen::GenerateConfig {
+        binary_helpers: Some(true),
+        ..Default::default()
+    };
+
+    for validation in ["none", "zod"] {
+        let generator = TestGenerator::new();
+        generator.generate(
+            &commands,
+            analyzer.get_discovered_structs(),
+            &analyzer,
+            Some(validation),
+            Some(&config),
+        );
+
+        let binary = generator.read_file("binary.ts");
+        assert!(binary.contains("export async function blobToBytes(blob: Blob): Promise<number[]>"));
+        assert!(binary.contains("params: Omit<types.SaveImageParams, 'data'>,"));
+        assert!(binary
+            .contains("return commands.saveImage({ ...params, data: await blobToBytes(file) });"));
+        assert!(binary.contains("params: Omit<types.UploadChunkParams, 'chunk' | 'offset'>,"));
+        assert!(
+            binary.contains("chunk: await blobToBytes(file.slice(offset, offset + chunkSize)),")
+        );
+        assert!(binary
+            .contains("export async function readThumbnailAsBlob(type?: string): Promise<Blob> {"));
+        assert!(!binary.contains("ping"));
+
+        let index = generator.read_file("index.ts");
+        assert!(index.contains("export * from './binary';"));
+    }
+}
+
+#[test]
+fn test_error_classes_full_pipeline() {
+    let project = TestProject::new();
+
+    project.write_file(
+        "main.rs",
+        r#"
+        use serde::Serialize;
+
+        #[derive(Serialize)]
+        pub struct Quota {
+            pub limit: u32,
+        }
+
+        #[derive(Serialize)]
+        pub enum AppError {
+            NotFound,
+            Io(String),
+            QuotaExceeded { quota: Quota },
+        }
+
+        #[derive(Serialize)]
+        #[serde(tag = "kind", content = "detail")]
+        pub enum AuthError {
+            Expired,
+            Denied(String),
+        }
+
+        #[tauri::command]
+        pub fn read_file(path: String) -> Result<String, AppError> {
+            unimplemented!()
+        }
+
+        #[tauri::command]
+        pub async fn login(user: String) -> Result<(), AuthError> {
+            unimplemented!()
+        }
+    "#,
+    );
+
+    let (analyzer, commands) = project.analyze();
+    let config = tauri_typegen::GenerateConfig {
+        error_classes: Some(true),
+        ..Default::default()
+    };
+
+    for validation in ["none", "zod"] {
+        let generator = TestGenerator::new();
+        generator.generate(
+            &commands,
+            analyzer.get_discovered_structs(),
+            &analyzer,
+            Some(validation),
+            Some(&config),
+        );
+
+        let errors = generator.read_file("errors.ts");
+        assert!(errors.contains("import type { Quota } from './types';"));
+        assert!(errors.contains("export class AppError extends Error {"));
+        assert!(errors.contains("static fromUnknown(error: unknown): AppError {"));
+        assert!(errors.contains("const [variant, payload] = externalVariant(error);"));
+        assert!(errors.contains("export class AppErrorNotFound extends AppError {"));
+        assert!(errors.contains("return new AppErrorIo(error, payload as string);"));
+        assert!(errors.contains("readonly payload: { quota: Quota };"));
+        assert!(
+            errors.contains("const [variant, payload] = taggedVariant(error, 'kind', 'detail');")
+        );
+        assert!(errors.contains("case \"Denied\":\n        return new AuthErrorDenied("));
+
+        // The classes share their names with the error types, so they are not re-exported
+        let index = generator.read_file("index.ts");
+        assert!(!index.contains("./errors"));
+        assert!(generator.read_file("types.ts").contains("Quota"));
+    }
+}
+
+#[test]
+fn test_command_chunks_full_pipeline() {
+    let project = TestProject::new();
+
+    project.write_file(
+        "user.rs",
+        r#"
+        #[tauri::command]
+        pub fn get_user(id: u32) -> String {
+            unimplemented!()
+        }
+
+        #[tauri::command]
+        pub fn delete_user(id: u32) -> Result<(), String> {
+            unimplemented!()
+        }
+    "#,
+    );
+    project.write_file(
+        "auth.rs",
+        r#"
+        #[tauri::command]
+        pub async fn login(name: String) -> Result<(), String> {
+            unimplemented!()
+        }
+    "#,
+    );
+
+    let (analyzer, commands) = project.analyze();
+    let config = tauri_typegen::GenerateConfig {
+        notify_commands: Some(true),
+        max_commands_per_file: Some(2),
+        ..Default::default()
+    };
+
+    for validation in ["none", "zod"] {
+        let generator = TestGenerator::new();
+        generator.generate(
+            &commands,
+            analyzer.get_discovered_structs(),
+            &analyzer,
+            Some(validation),
+            Some(&config),
+        );
+
+        let commands_file = generator.read_file("commands.ts");
+        assert!(commands_file.contains("export * from './commands.auth';"));
+        assert!(commands_file.contains("export * from './commands.user';"));
+        assert!(!commands_file.contains("invoke"));
+
+        let user = generator.read_file("commands.user.ts");
+        assert!(user.contains("export async function getUser("));
+        assert!(user.contains("export async function deleteUser("));
+        assert!(!user.contains("login"));
+        assert!(user.contains("from './commands.shared';"));
+        assert!(generator
+            .read_file("commands.auth.ts")
+            .contains("export async function login("));
+
+        // The notify handler is shared so one `setNotifyErrorHandler` covers every chunk
+        let shared = generator.read_file("commands.shared.ts");
+        assert!(shared.contains("export function setNotifyErrorHandler("));
+        assert!(shared.contains("export function notify("));
+        assert!(commands_file.contains("setNotifyErrorHandler } from './commands.shared';"));
+        assert_eq!(
+            shared.contains("export interface CommandHooks<T>"),
+            validation == "zod"
+        );
+
+        let index = generator.read_file("index.ts");
+        assert!(index.contains("export * from './commands';"));
+        assert!(!index.contains("./commands.user"));
+    }
+}
+
+#[test]
+fn test_admin_panel_full_pipeline() {
+    let project = TestProject::new();
+
+    project.write_file(
+        "main.rs",
+        r#"
+        #[tauri::command]
+        pub fn search_users(query: String, limit: Option<u32>, active: bool) -> Vec<String> {
+            unimplemented!()
+        }
+
+        #[tauri::command]
+        pub fn refresh() {}
+    "#,
+    );
+
+    let (analyzer, commands) = project.analyze();
+    let admin_dir = tempfile::TempDir::new().unwrap();
+    let config = tauri_typegen::GenerateConfig {
+        admin_panel: Some(admin_dir.path().to_string_lossy().to_string()),
+        ..Default::default()
+    };
+    let read = |file: &str| std::fs::read_to_string(admin_dir.path().join(file)).unwrap();
+
+    let generator = TestGenerator::new();
+    let generate = || {
+        generator.generate(
+            &commands,
+            analyzer.get_discovered_structs(),
+            &analyzer,
+            Some("zod"),
+            Some(&config),
+        )
+    };
+    generate();
+
+    let panel = read("SearchUsersPanel.tsx");
+    assert!(panel.contains("export function SearchUsersPanel()"));
+    assert!(panel.contains("await commands.searchUsers("));
+    assert!(panel.contains("if (values[\"limit\"] !== '') params[\"limit\"] = Number("));
+    assert!(panel.contains("type=\"checkbox\""));
+    assert!(read("RefreshPanel.tsx").contains("await commands.refresh()"));
+    assert!(read("AdminPanel.tsx").contains("\"search_users\": SearchUsersPanel,"));
+    assert!(read("ResultView.tsx").contains("export function ResultView("));
+
+    // Edited scaffolds survive regeneration
+    let edited = panel.replace("<h2>search_users</h2>", "<h2>Users</h2>");
+    std::fs::write(admin_dir.path().join("SearchUsersPanel.tsx"), &edited).unwrap();
+    generate();
+    assert_eq!(read("SearchUsersPanel.tsx"), edited);
+}
+
+#[test]
+fn test_python_full_pipeline() {
+    let source = r#"
+        use std::collections::HashMap;
+
+        #[derive(serde::Serialize, serde::Deserialize)]
+        #[serde(rename_all = "camelCase")]
+        pub struct User {
+            pub user_name: String,
+            pub nickname: Option<String>,
+            pub scores: HashMap<String, f64>,
+            pub shape: Shape,
+            pub role: Role,
+        }
+
+        #[derive(serde::Serialize, serde::Deserialize)]
+        pub enum Role { Admin, Guest }
+
+        #[derive(serde::Serialize, serde::Deserialize)]
+        #[serde(tag = "kind", content = "data")]
+        pub enum Shape {
+            Circle(f32),
+            Rect { width: u32, height: u32 },
+        }
+
+        #[tauri::command]
+        pub fn get_user(user_id: u32, from: Option<String>) -> Result<User, String> {
+            unimplemented!()
+        }
+
+        #[tauri::command]
+        pub async fn ping() {}
+
+        #[tauri::command]
+        pub fn watch(on_event: tauri::ipc::Channel<u32>) {}
+    "#;
+
+    let config: tauri_typegen::GenerateConfig =
+        serde_json::from_str(r#"{ "target": "python" }"#).unwrap();
+    let files = tauri_typegen::generate_from_source(source, &config).unwrap();
+    let names: Vec<&str> = files.iter().map(|file| file.name.as_str()).collect();
+    assert_eq!(names, ["types.py", "commands.py", "__init__.py"]);
+    let file = |name: &str| {
+        files
+            .iter()
+            .find(|file| file.name == name)
+            .map(|file| file.content.clone())
+            .unwrap()
+    };
+
+    let types_py = file("types.py");
+    assert!(types_py.contains("class User(BaseModel):"));
+    assert!(types_py.contains("    user_name: str = Field(alias=\"userName\")"));
+    assert!(types_py.contains("    nickname: Optional[str] = Field(default=None)"));
+    assert!(types_py.contains("    scores: dict[str, float]"));
+    assert!(types_py.contains("Role = Literal[\"Admin\", \"Guest\"]"));
+    assert!(types_py.contains("    kind: Literal[\"Rect\"] = Field(default=\"Rect\")"));
+    assert!(types_py.contains("    data: ShapeRectData"));
+    assert!(types_py.contains("    width: int"));
+    assert!(types_py.contains("Shape = Union[ShapeCircle, ShapeRect]"));
+    // Aliases are evaluated on import, after the models they reference
+    assert!(types_py.find("class ShapeRect(").unwrap() < types_py.find("Shape = ").unwrap());
+
+    let commands_py = file("commands.py");
+    assert!(commands_py.contains("from . import types\n"));
+    assert!(commands_py.contains(
+        "    async def get_user(self, user_id: int, from_: Optional[str]) -> types.User:"
+    ));
+    assert!(commands_py.contains("            \"from\": _dump(Optional[str], from_),"));
+    assert!(commands_py.contains("        return _load(types.User, result)"));
+    assert!(commands_py.contains("        await self._invoke(\"ping\", {})"));
+    assert!(!commands_py.contains("async def watch("));
+    assert!(commands_py.contains("left out: watch"));
+
+    assert!(file("__init__.py").contains("from .commands import Commands, Invoke"));
+}
+
+/// Test fields the backend may leave out decode when absent from Python results
+#[test]
+fn test_python_decodes_omitted_fields() {
+    let source = r#"
+        #[derive(serde::Serialize, serde::Deserialize)]
+        pub struct Profile {
+            pub id: u32,
+            #[serde(skip_serializing)]
+            pub secret: String,
+            #[serde(skip_serializing_if = "Vec::is_empty")]
+            pub tags: Vec<String>,
+        }
+
+        #[tauri::command]
+        pub fn update(profile: Profile) -> Profile {
+            profile
+        }
+    "#;
+
+    let config: tauri_typegen::GenerateConfig =
+        serde_json::from_str(r#"{ "target": "python" }"#).unwrap();
+    let files = tauri_typegen::generate_from_source(source, &config).unwrap();
+    let file = |name: &str| {
+        files
+            .iter()
+            .find(|file| file.name == name)
+            .map(|file| file.content.clone())
+            .unwrap()
+    };
+
+    let types_py = file("types.py");
+    assert!(types_py.contains("    id: int\n"));
+    assert!(types_py.contains("    secret: Optional[str] = Field(default=None)"));
+    assert!(types_py.contains("    tags: Optional[list[str]] = Field(default=None)"));
+    // `None` is left out when sent, since serde takes no `null` for these fields
+    assert!(file("commands.py").contains("by_alias=True, exclude_none=True"));
+}
+
+#[test]
+fn test_kotlin_full_pipeline() {
+    let source = r#"
+        #[derive(serde::Serialize, serde::Deserialize)]
+        #[serde(rename_all = "camelCase")]
+        pub struct User {
+            pub user_name: String,
+            #[serde(rename = "created")]
+            pub created_at: Option<String>,
+            pub scores: Vec<f32>,
+            pub role: Role,
+        }
+
+        #[derive(serde::Serialize, serde::Deserialize)]
+        #[serde(rename_all = "lowercase")]
+        pub enum Role { Admin, Guest }
+
+        #[tauri::command]
+        pub fn get_user(user_id: u64, when: Option<String>) -> Result<User, String> {
+            unimplemented!()
+        }
+
+        #[tauri::command]
+        pub async fn ping() {}
+    "#;
+
+    let config: tauri_typegen::GenerateConfig =
+        serde_json::from_str(r#"{ "target": "kotlin", "kotlin_package": "com.example.app" }"#)
+            .unwrap();
+    let files = tauri_typegen::generate_from_source(source, &config).unwrap();
+    let names: Vec<&str> = files.iter().map(|file| file.name.as_str()).collect();
+    assert_eq!(names, ["Types.kt", "Commands.kt"]);
+    let file = |name: &str| {
+        files
+            .iter()
+            .find(|file| file.name == name)
+            .map(|file| file.content.clone())
+            .unwrap()
+    };
+
+    let types_kt = file("Types.kt");
+    assert!(types_kt.contains("package com.example.app\n"));
+    assert!(types_kt.contains("@Serializable\ndata class User("));
+    assert!(types_kt.contains("    val userName: String,"));
+    assert!(types_kt.contains("    @SerialName(\"created\") val createdAt: String? = null,"));
+    assert!(types_kt.contains("    val scores: List<Float>,"));
+    assert!(types_kt.contains("enum class Role {\n    @SerialName(\"admin\") Admin,"));
+
+    let commands_kt = file("Commands.kt");
+    assert!(commands_kt.contains("suspend fun getUser(userId: ULong, `when`: String?): User {"));
+    assert!(commands_kt
+        .contains("            put(\"when\", json.encodeToJsonElement<String?>(`when`))"));
+    assert!(commands_kt
+        .contains("        return json.decodeFromJsonElement<User>(invoke(\"get_user\", args))"));
+    assert!(commands_kt.contains("    suspend fun ping() {"));
+    assert!(commands_kt.contains("        invoke(\"ping\", args)"));
+}
+
+/// Test fields the backend may leave out decode when absent from Kotlin results
+#[test]
+fn test_kotlin_decodes_omitted_fields() {
+    let source = r#"
+        #[derive(serde::Serialize, serde::Deserialize)]
+        pub struct Profile {
+            pub id: u32,
+            #[serde(skip_serializing)]
+            pub secret: String,
+            #[serde(skip_serializing_if = "Vec::is_empty")]
+            pub tags: Vec<String>,
+        }
+
+        #[tauri::command]
+        pub fn update(profile: Profile) -> Profile {
+            profile
+        }
+    "#;
+
+    let config: tauri_typegen::GenerateConfig =
+        serde_json::from_str(r#"{ "target": "kotlin", "kotlin_package": "com.example.app" }"#)
+            .unwrap();
+    let files = tauri_typegen::generate_from_source(source, &config).unwrap();
+    let file = |name: &str| {
+        files
+            .iter()
+            .find(|file| file.name == name)
+            .map(|file| file.content.clone())
+            .unwrap()
+    };
+
+    let types_kt = file("Types.kt");
+    assert!(types_kt.contains("    val id: UInt,\n"));
+    assert!(types_kt.contains("    val secret: String? = null,"));
+    assert!(types_kt.contains("    val tags: List<String>? = null,"));
+    // `null` is left out when sent, since serde takes no `null` for these fields
+    assert!(file("Commands.kt").contains("        explicitNulls = false\n"));
+}
+
+#[test]
+fn test_swift_full_pipeline() {
+    let source = r#"
+        #[derive(serde::Serialize, serde::Deserialize)]
+        #[serde(rename_all = "camelCase")]
+        pub struct User {
+            pub user_name: String,
+            #[serde(rename = "created")]
+            pub created_at: Option<String>,
+            pub scores: Vec<f32>,
+            pub role: Role,
+            pub metadata: serde_json::Value,
+        }
+
+        #[derive(serde::Serialize, serde::Deserialize)]
+        #[serde(rename_all = "lowercase")]
+        pub enum Role { Admin, Guest }
+
+        #[tauri::command]
+        pub fn get_user(user_id: u64, default: Option<String>) -> Result<User, String> {
+            unimplemented!()
+        }
+
+        #[tauri::command]
+        pub async fn ping() {}
+    "#;
+
+    let config: tauri_typegen::GenerateConfig =
+        serde_json::from_str(r#"{ "target": "swift" }"#).unwrap();
+    let files = tauri_typegen::generate_from_source(source, &config).unwrap();
+    let names: Vec<&str> = files.iter().map(|file| file.name.as_str()).collect();
+    assert_eq!(names, ["Types.swift", "Commands.swift"]);
+    let file = |name: &str| {
+        files
+            .iter()
+            .find(|file| file.name == name)
+            .map(|file| file.content.clone())
+            .unwrap()
+    };
+
+    let types_swift = file("Types.swift");
+    assert!(types_swift.contains("public struct User: Codable {"));
+    assert!(types_swift.contains("    public var scores: [Float]"));
+    assert!(types_swift.contains("        case createdAt = \"created\"\n"));
+    assert!(types_swift.contains("createdAt: String? = nil,"));
+    assert!(types_swift.contains("    case admin = \"admin\""));
+    assert!(types_swift.contains("public enum JSONValue: Codable, Hashable {"));
+
+    let commands_swift = file("Commands.swift");
+    assert!(commands_swift.contains(
+        "public func getUser(userId: UInt64, `default`: String?) async throws -> User {"
+    ));
+    assert!(commands_swift.contains("            case `default` = \"default\"\n"));
+    assert!(commands_swift.contains(
+        "return try decoder.decode(User.self, from: try await invoke(\"get_user\", args))"
+    ));
+    assert!(commands_swift.contains("    public func ping() async throws {"));
+    assert!(commands_swift.contains("        _ = try await invoke(\"ping\", args)"));
+}
+
+/// Test fields the backend may leave out decode when absent from Swift results
+#[test]
+fn test_swift_decodes_omitted_fields() {
+    let source = r#"
+        #[derive(serde::Serialize, serde::Deserialize)]
+        pub struct Profile {
+            pub id: u32,
+            #[serde(skip_serializing)]
+            pub secret: String,
+            #[serde(skip_serializing_if = "Vec::is_empty")]
+            pub tags: Vec<String>,
+        }
+
+        #[tauri::command]
+        pub fn update(profile: Profile) -> Profile {
+            profile
+        }
+    "#;
+
+    let config: tauri_typegen::GenerateConfig =
+        serde_json::from_str(r#"{ "target": "swift" }"#).unwrap();
+    let files = tauri_typegen::generate_from_source(source, &config).unwrap();
+    let file = |name: &str| {
+        files
+            .iter()
+            .find(|file| file.name == name)
+            .map(|file| file.content.clone())
+            .unwrap()
+    };
+
+    // Optional properties are decoded with `decodeIfPresent` and encoded only when set
+    let types_swift = file("Types.swift");
+    assert!(types_swift.contains("    public var id: UInt32\n"));
+    assert!(types_swift.contains("    public var secret: String?\n"));
+    assert!(types_swift.contains("    public var tags: [String]?\n"));
+    assert!(types_swift.contains("secret: String? = nil, tags: [String]? = nil)"));
+}
+
+/// Test parameters sent under the same name are declared once
+#[test]
+fn test_colliding_parameters_full_pipeline() {
+    let project = TestProject::new();
+
+    project.write_file(
+        "main.rs",
+        r#"
+        #[tauri::command]
+        #[allow(non_snake_case)]
+        pub fn save(user_id: String, userId: String, name: String) {
+            unimplemented!()
+        }
+    "#,
+    );
+
+    let (analyzer, commands) = project.analyze();
+    for library in ["none", "zod", "valibot"] {
+        let generator = TestGenerator::new();
+        generator.generate(
+            &commands,
+            analyzer.get_discovered_structs(),
+            &analyzer,
+            Some(library),
+            None,
+        );
+        let types = generator.read_file("types.ts");
+        assert_eq!(types.matches("userId").count(), 1, "{library}:\n{types}");
+        assert!(types.contains("name"), "{library}");
+    }
+}